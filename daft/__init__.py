@@ -100,11 +100,14 @@ from daft.io import (
     DataCatalogTable,
     DataCatalogType,
     from_glob_path,
+    read_avro,
     read_csv,
     read_deltalake,
     read_hudi,
     read_iceberg,
+    read_ipc,
     read_json,
+    read_orc,
     read_parquet,
     read_sql,
     read_lance,
@@ -188,12 +191,15 @@ __all__ = [
     "list_tables",
     "lit",
     "planning_config_ctx",
+    "read_avro",
     "read_csv",
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
+    "read_orc",
     "read_parquet",
     "read_sql",
     "read_table",
@@ -145,6 +145,7 @@ def planning_config_ctx(**kwargs):
 def set_planning_config(
     config: PyDaftPlanningConfig | None = None,
     default_io_config: IOConfig | None = None,
+    recursive_cte_max_iterations: int | None = None,
 ) -> DaftContext:
     """Globally sets various configuration parameters which control Daft plan construction behavior.
 
@@ -155,6 +156,8 @@ def set_planning_config(
             that the old (current) config should be used.
         default_io_config: A default IOConfig to use in the absence of one being explicitly passed into any Expression (e.g. `.url.download()`)
             or Dataframe operation (e.g. `daft.read_parquet()`).
+        recursive_cte_max_iterations: The maximum number of iterations of the recursive term of a SQL `WITH RECURSIVE` query before
+            planning fails, which guards against recursions that never reach a fixpoint. Defaults to 100.
     """
     # Replace values in the DaftPlanningConfig with user-specified overrides
     ctx = get_context()
@@ -162,6 +165,7 @@ def set_planning_config(
         old_daft_planning_config = ctx._ctx._daft_planning_config if config is None else config
         new_daft_planning_config = old_daft_planning_config.with_config_values(
             default_io_config=default_io_config,
+            recursive_cte_max_iterations=recursive_cte_max_iterations,
         )
 
         ctx._ctx._daft_planning_config = new_daft_planning_config
@@ -187,6 +191,7 @@ def set_execution_config(
     broadcast_join_size_bytes_threshold: int | None = None,
     parquet_split_row_groups_max_files: int | None = None,
     sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
+    sort_merge_join_presorted_scans: bool | None = None,
     hash_join_partition_size_leniency: float | None = None,
     sample_size_for_sort: int | None = None,
     num_preview_rows: int | None = None,
@@ -228,6 +233,9 @@ def set_execution_config(
         sort_merge_join_sort_with_aligned_boundaries: Whether to use a specialized algorithm for sorting both sides of a
             sort-merge join such that they have aligned boundaries. This can lead to a faster merge-join at the cost of
             more skewed sorted join inputs, increasing the risk of OOMs.
+        sort_merge_join_presorted_scans: Whether the native runner trusts that the scans feeding a sort-merge join are
+            already sorted by the join keys in ascending order with nulls last, so that they are merged without sorting
+            them first. A join fails if a scan turns out not to be sorted. Defaults to False.
         hash_join_partition_size_leniency: If the left side of a hash join is already correctly partitioned and the right side isn't,
             and the ratio between the left and right size is at least this value, then the right side is repartitioned to have an equal
             number of partitions as the left. Defaults to 0.5.
@@ -264,6 +272,7 @@ def set_execution_config(
             broadcast_join_size_bytes_threshold=broadcast_join_size_bytes_threshold,
             parquet_split_row_groups_max_files=parquet_split_row_groups_max_files,
             sort_merge_join_sort_with_aligned_boundaries=sort_merge_join_sort_with_aligned_boundaries,
+            sort_merge_join_presorted_scans=sort_merge_join_presorted_scans,
             hash_join_partition_size_leniency=hash_join_partition_size_leniency,
             sample_size_for_sort=sample_size_for_sort,
             num_preview_rows=num_preview_rows,
@@ -92,6 +92,7 @@ class JoinType(Enum):
     Outer: int
     Semi: int
     Anti: int
+    AsOf: int
 
     @staticmethod
     def from_join_type_str(join_type: str) -> JoinType:
@@ -126,6 +127,22 @@ class JoinSide(Enum):
     Left: int
     Right: int
 
+class AsofDirection(Enum):
+    """Which right row an as-of join matches each left row with."""
+
+    Backward: int
+    Forward: int
+    Nearest: int
+
+    @staticmethod
+    def from_asof_direction_str(direction: str) -> AsofDirection:
+        """Create an AsofDirection from its string representation.
+
+        Args:
+            direction: String representation of the direction, e.g. "backward", "forward", or "nearest".
+        """
+        ...
+
 class CountMode(Enum):
     """Supported count modes for Daft's count aggregation.
 
@@ -175,11 +192,14 @@ class ResourceRequest:
     def __ne__(self, other: ResourceRequest) -> bool: ...  # type: ignore[override]
 
 class FileFormat(Enum):
-    """Format of a file, e.g. Parquet, CSV, and JSON."""
+    """Format of a file, e.g. Parquet, CSV, JSON, Arrow IPC, Avro and ORC."""
 
     Parquet: int
     Csv: int
     Json: int
+    Ipc: int
+    Avro: int
+    Orc: int
 
     def ext(self): ...
 
@@ -209,6 +229,12 @@ class CsvSourceConfig:
     escape_char: str | None
     comment: str | None
     allow_variable_columns: bool
+    encoding: str | None
+    null_values: list[str] | None
+    skip_rows: int
+    skip_rows_after_header: int
+    truncate_ragged_lines: bool
+    datetime_formats: dict[str, str] | None
     buffer_size: int | None
     chunk_size: int | None
 
@@ -221,6 +247,12 @@ class CsvSourceConfig:
         quote: str | None,
         escape_char: str | None,
         comment: str | None,
+        encoding: str | None = None,
+        null_values: list[str] | None = None,
+        skip_rows: int = 0,
+        skip_rows_after_header: int = 0,
+        truncate_ragged_lines: bool = False,
+        datetime_formats: dict[str, str] | None = None,
         buffer_size: int | None = None,
         chunk_size: int | None = None,
     ): ...
@@ -241,6 +273,18 @@ class WarcSourceConfig:
     """Configuration of a Warc data source."""
     def __init__(self): ...
 
+class IpcSourceConfig:
+    """Configuration of an Arrow IPC data source."""
+    def __init__(self): ...
+
+class AvroSourceConfig:
+    """Configuration of an Avro data source."""
+    def __init__(self): ...
+
+class OrcSourceConfig:
+    """Configuration of an ORC data source."""
+    def __init__(self): ...
+
 class DatabaseSourceConfig:
     """Configuration of a database data source."""
 
@@ -250,9 +294,18 @@ class DatabaseSourceConfig:
     def __init__(self, sql: str, conn_factory: SQLConnection): ...
 
 class FileFormatConfig:
-    """Configuration for parsing a particular file format (Parquet, CSV, JSON)."""
+    """Configuration for parsing a particular file format (Parquet, CSV, JSON, Arrow IPC, Avro, ORC)."""
 
-    config: ParquetSourceConfig | CsvSourceConfig | JsonSourceConfig | DatabaseSourceConfig | WarcSourceConfig
+    config: (
+        ParquetSourceConfig
+        | CsvSourceConfig
+        | JsonSourceConfig
+        | DatabaseSourceConfig
+        | WarcSourceConfig
+        | IpcSourceConfig
+        | AvroSourceConfig
+        | OrcSourceConfig
+    )
 
     @staticmethod
     def from_parquet_config(config: ParquetSourceConfig) -> FileFormatConfig:
@@ -274,6 +327,21 @@ class FileFormatConfig:
         """Create a WARC file format config."""
         ...
 
+    @staticmethod
+    def from_ipc_config(config: IpcSourceConfig) -> FileFormatConfig:
+        """Create an Arrow IPC file format config."""
+        ...
+
+    @staticmethod
+    def from_avro_config(config: AvroSourceConfig) -> FileFormatConfig:
+        """Create an Avro file format config."""
+        ...
+
+    @staticmethod
+    def from_orc_config(config: OrcSourceConfig) -> FileFormatConfig:
+        """Create an ORC file format config."""
+        ...
+
     @staticmethod
     def from_database_config(config: DatabaseSourceConfig) -> FileFormatConfig:
         """Create a database file format config."""
@@ -314,6 +382,12 @@ class CsvParseOptions:
     allow_variable_columns: bool
     escape_char: str | None
     comment: str | None
+    encoding: str | None
+    null_values: list[str] | None
+    skip_rows: int
+    skip_rows_after_header: int
+    truncate_ragged_lines: bool
+    datetime_formats: dict[str, str] | None
 
     def __init__(
         self,
@@ -324,6 +398,12 @@ class CsvParseOptions:
         allow_variable_columns: bool = False,
         escape_char: str | None = None,
         comment: str | None = None,
+        encoding: str | None = None,
+        null_values: list[str] | None = None,
+        skip_rows: int = 0,
+        skip_rows_after_header: int = 0,
+        truncate_ragged_lines: bool = False,
+        datetime_formats: dict[str, str] | None = None,
     ): ...
 
 class CsvReadOptions:
@@ -352,9 +432,21 @@ class JsonConvertOptions:
         schema: PySchema | None = None,
     ): ...
 
+class JsonFormat(Enum):
+    """Layout of the records in a JSON file."""
+
+    Auto: int
+    NewlineDelimited: int
+    Documents: int
+
 class JsonParseOptions:
     """Options for parsing JSON files."""
 
+    sample_size: int | None
+    format: JsonFormat
+
+    def __init__(self, format: JsonFormat | None = None): ...
+
 class JsonReadOptions:
     """Options for reading JSON files."""
 
@@ -397,6 +489,22 @@ class HTTPConfig:
 
     def __init__(self, bearer_token: str | None = None): ...
 
+class CacheConfig:
+    """I/O configuration for caching reads from remote object stores on local disk."""
+
+    enabled: bool
+    directory: str | None
+    max_size_bytes: int
+    metadata_ttl_secs: int
+
+    def __init__(
+        self,
+        enabled: bool | None = None,
+        directory: str | None = None,
+        max_size_bytes: int | None = None,
+        metadata_ttl_secs: int | None = None,
+    ): ...
+
 class S3Config:
     """I/O configuration for accessing an S3-compatible system."""
 
@@ -584,6 +692,7 @@ class IOConfig:
     azure: AzureConfig
     gcs: GCSConfig
     http: HTTPConfig
+    cache: CacheConfig
 
     def __init__(
         self,
@@ -591,6 +700,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        cache: CacheConfig | None = None,
     ): ...
     def replace(
         self,
@@ -598,6 +708,7 @@ class IOConfig:
         azure: AzureConfig | None = None,
         gcs: GCSConfig | None = None,
         http: HTTPConfig | None = None,
+        cache: CacheConfig | None = None,
     ) -> IOConfig:
         """Replaces values if provided, returning a new IOConfig."""
         ...
@@ -728,6 +839,7 @@ class Pushdowns:
     filters: PyExpr | None
     partition_filters: PyExpr | None
     limit: int | None
+    aggregations: list[PyExpr] | None
 
     def filter_required_column_names(self) -> list[str]:
         """List of field names that are required by the filter predicate."""
@@ -1177,7 +1289,16 @@ def dt_time(expr: PyExpr) -> PyExpr: ...
 def dt_month(expr: PyExpr) -> PyExpr: ...
 def dt_year(expr: PyExpr) -> PyExpr: ...
 def dt_day_of_week(expr: PyExpr) -> PyExpr: ...
+def dt_day_of_year(expr: PyExpr) -> PyExpr: ...
+def dt_week_of_year(expr: PyExpr) -> PyExpr: ...
+def dt_quarter(expr: PyExpr) -> PyExpr: ...
 def dt_truncate(expr: PyExpr, interval: str, relative_to: PyExpr) -> PyExpr: ...
+def dt_convert_time_zone(expr: PyExpr, time_zone: str) -> PyExpr: ...
+def dt_strftime(expr: PyExpr, format: str | None = None) -> PyExpr: ...
+def dt_to_unix_epoch(expr: PyExpr, time_unit: PyTimeUnit) -> PyExpr: ...
+def dt_from_unix_epoch(expr: PyExpr, time_unit: PyTimeUnit) -> PyExpr: ...
+def dt_add_months(expr: PyExpr, months: PyExpr) -> PyExpr: ...
+def dt_date_diff(unit: str, start: PyExpr, end: PyExpr) -> PyExpr: ...
 
 # ---
 # expr.list namespace
@@ -1515,6 +1636,7 @@ class PyMicroPartition:
         outer_loop_side: JoinSide,
     ) -> PyMicroPartition: ...
     def explode(self, to_explode: list[PyExpr]) -> PyMicroPartition: ...
+    def window(self, window_functions: list[PyExpr]) -> PyMicroPartition: ...
     def unpivot(
         self,
         ids: list[PyExpr],
@@ -1664,6 +1786,12 @@ class LogicalPlanBuilder:
         partition_by: list[PyExpr],
         num_partitions: int | None,
     ) -> LogicalPlanBuilder: ...
+    def range_repartition(
+        self,
+        partition_by: list[PyExpr],
+        descending: list[bool],
+        num_partitions: int | None,
+    ) -> LogicalPlanBuilder: ...
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder: ...
     def into_partitions(self, num_partitions: int) -> LogicalPlanBuilder: ...
     def coalesce(self, num_partitions: int) -> LogicalPlanBuilder: ...
@@ -1688,6 +1816,16 @@ class LogicalPlanBuilder:
         prefix: str | None = None,
         suffix: str | None = None,
     ) -> LogicalPlanBuilder: ...
+    def asof_join(
+        self,
+        right: LogicalPlanBuilder,
+        left_on: list[PyExpr],
+        right_on: list[PyExpr],
+        direction: AsofDirection,
+        tolerance: PyExpr | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder: ...
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder: ...
     def union(self, other: LogicalPlanBuilder, is_all: bool, is_by_name: bool) -> LogicalPlanBuilder: ...
     def intersect(self, other: LogicalPlanBuilder, is_all: bool) -> LogicalPlanBuilder: ...
@@ -1766,6 +1904,7 @@ class PyDaftExecutionConfig:
         broadcast_join_size_bytes_threshold: int | None = None,
         parquet_split_row_groups_max_files: int | None = None,
         sort_merge_join_sort_with_aligned_boundaries: bool | None = None,
+        sort_merge_join_presorted_scans: bool | None = None,
         hash_join_partition_size_leniency: float | None = None,
         sample_size_for_sort: int | None = None,
         num_preview_rows: int | None = None,
@@ -1797,6 +1936,8 @@ class PyDaftExecutionConfig:
     @property
     def sort_merge_join_sort_with_aligned_boundaries(self) -> bool: ...
     @property
+    def sort_merge_join_presorted_scans(self) -> bool: ...
+    @property
     def hash_join_partition_size_leniency(self) -> float: ...
     @property
     def sample_size_for_sort(self) -> int: ...
@@ -1839,9 +1980,12 @@ class PyDaftPlanningConfig:
     def with_config_values(
         self,
         default_io_config: IOConfig | None = None,
+        recursive_cte_max_iterations: int | None = None,
     ) -> PyDaftPlanningConfig: ...
     @property
     def default_io_config(self) -> IOConfig: ...
+    @property
+    def recursive_cte_max_iterations(self) -> int: ...
 
 class PyDaftContext:
     def __init__(self) -> None: ...
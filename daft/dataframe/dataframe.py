@@ -33,7 +33,7 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType
+from daft.daft import AsofDirection, FileFormat, IOConfig, JoinStrategy, JoinType
 from daft.dataframe.preview import DataFramePreview
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
@@ -768,6 +768,157 @@ class DataFrame:
                 }
             )
 
+    @DataframePublicAPI
+    def write_json(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as newline-delimited JSON files, returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names.
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+            Writing JSON is currently only supported by the native runner.
+
+        Args:
+            root_dir (str): root file path to write JSON files to.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Json,
+            io_config=io_config,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+            return result_df
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(root_dir, FileFormat.Json, self.schema(), io_config=io_config)
+
+            return from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
+    @DataframePublicAPI
+    def write_ipc(
+        self,
+        root_dir: Union[str, pathlib.Path],
+        compression: Optional[str] = None,
+        write_mode: Literal["append", "overwrite", "overwrite-partitions"] = "append",
+        partition_cols: Optional[List[ColumnInputType]] = None,
+        io_config: Optional[IOConfig] = None,
+    ) -> "DataFrame":
+        """Writes the DataFrame as Arrow IPC files, returning a new DataFrame with paths to the files that were written.
+
+        Files will be written to ``<root_dir>/*`` with randomly generated UUIDs as the file names, in the Arrow IPC file format (also known as Feather V2).
+
+        .. NOTE::
+            This call is **blocking** and will execute the DataFrame when called
+
+            Writing Arrow IPC is currently only supported by the native runner.
+
+        Args:
+            root_dir (str): root file path to write Arrow IPC files to.
+            compression (str, optional): compression of the record batch buffers, either "lz4" or "zstd". Defaults to None, i.e. uncompressed.
+            write_mode (str, optional): Operation mode of the write. `append` will add new data, `overwrite` will replace the contents of the root directory with new data. `overwrite-partitions` will replace only the contents in the partitions that are being written to. Defaults to "append".
+            partition_cols (Optional[List[ColumnInputType]], optional): How to subpartition each partition further. Defaults to None.
+            io_config (Optional[IOConfig], optional): configurations to use when interacting with remote storage.
+
+        Returns:
+            DataFrame: The filenames that were written out as strings.
+        """
+        if write_mode not in ["append", "overwrite", "overwrite-partitions"]:
+            raise ValueError(
+                f"Only support `append`, `overwrite`, or `overwrite-partitions` mode. {write_mode} is unsupported"
+            )
+        if write_mode == "overwrite-partitions" and partition_cols is None:
+            raise ValueError("Partition columns must be specified to use `overwrite-partitions` mode.")
+
+        io_config = get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+        cols: Optional[List[Expression]] = None
+        if partition_cols is not None:
+            cols = self.__column_input_to_expression(tuple(partition_cols))
+        builder = self._builder.write_tabular(
+            root_dir=root_dir,
+            partition_cols=cols,
+            file_format=FileFormat.Ipc,
+            compression=compression,
+            io_config=io_config,
+        )
+
+        # Block and write, then retrieve data
+        write_df = DataFrame(builder)
+        write_df.collect()
+        assert write_df._result is not None
+
+        if write_mode == "overwrite":
+            overwrite_files(write_df, root_dir, io_config, False)
+        elif write_mode == "overwrite-partitions":
+            overwrite_files(write_df, root_dir, io_config, True)
+
+        if len(write_df) > 0:
+            # Populate and return a new disconnected DataFrame
+            result_df = DataFrame(write_df._builder)
+            result_df._result_cache = write_df._result_cache
+            result_df._preview = write_df._preview
+            return result_df
+        else:
+            from daft import from_pydict
+            from daft.recordbatch.recordbatch_io import write_empty_tabular
+
+            file_path = write_empty_tabular(
+                root_dir, FileFormat.Ipc, self.schema(), compression=compression, io_config=io_config
+            )
+
+            return from_pydict(
+                {
+                    "path": [file_path],
+                }
+            )
+
     @DataframePublicAPI
     def write_iceberg(
         self, table: "pyiceberg.table.Table", mode: str = "append", io_config: Optional[IOConfig] = None
@@ -2013,7 +2164,8 @@ class DataFrame:
             right_on (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys to join on right DataFrame. Defaults to None.
             how (str, optional): what type of join to perform; currently "inner", "left", "right", "outer", "anti", "semi", and "cross" are supported. Defaults to "inner".
             strategy (Optional[str]): The join strategy (algorithm) to use; currently "hash", "sort_merge", "broadcast", and None are supported, where None
-                chooses the join strategy automatically during query optimization. The default is None.
+                chooses the join strategy automatically during query optimization. The default is None. On the native runner, "sort_merge" sorts
+                each side by its join keys unless it is already sorted by them, and merges both sides as they stream in.
             suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
             prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
 
@@ -2060,6 +2212,92 @@ class DataFrame:
         )
         return DataFrame(builder)
 
+    @DataframePublicAPI
+    def join_asof(
+        self,
+        other: "DataFrame",
+        on: Optional[ColumnInputType] = None,
+        left_on: Optional[ColumnInputType] = None,
+        right_on: Optional[ColumnInputType] = None,
+        by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        left_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        right_by: Optional[Union[List[ColumnInputType], ColumnInputType]] = None,
+        direction: Literal["backward", "forward", "nearest"] = "backward",
+        tolerance: Optional[Any] = None,
+        prefix: Optional[str] = None,
+        suffix: Optional[str] = None,
+    ) -> "DataFrame":
+        """As-of join of the current DataFrame with an ``other`` DataFrame, which matches each row with the nearest row of ``other`` on a key such as a timestamp.
+
+        Each row is matched with at most one row of ``other`` among the rows which are equal to it on the ``by`` keys:
+
+        - "backward" matches the last row whose key is less than or equal to the row's key.
+        - "forward" matches the first row whose key is greater than or equal to the row's key.
+        - "nearest" matches the row whose key is closest to the row's key, preferring the backward match on ties.
+
+        Rows without a match are kept, with nulls for the columns of ``other``. Duplicate non-join key column names are handled as in :meth:`DataFrame.join`.
+
+        Example:
+            >>> import daft
+            >>> trades = daft.from_pydict({"time": [1, 5, 10], "ticker": ["a", "a", "b"]})
+            >>> quotes = daft.from_pydict({"time": [0, 4, 9], "ticker": ["a", "a", "b"], "price": [10, 11, 20]})
+            >>> trades.join_asof(quotes, on="time", by="ticker").sort("time").to_pydict()
+            {'time': [1, 5, 10], 'ticker': ['a', 'a', 'b'], 'price': [10, 11, 20]}
+
+        Args:
+            other (DataFrame): the right DataFrame to join on.
+            on (Optional[ColumnInputType], optional): key to match the nearest rows on [use if the keys on the left and right side match.]. Defaults to None.
+            left_on (Optional[ColumnInputType], optional): key to match the nearest rows on of the left DataFrame. Defaults to None.
+            right_on (Optional[ColumnInputType], optional): key to match the nearest rows on of the right DataFrame. Defaults to None.
+            by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys which matching rows must be equal on [use if the keys on the left and right side match.]. Defaults to None.
+            left_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys which matching rows must be equal on of the left DataFrame. Defaults to None.
+            right_by (Optional[Union[List[ColumnInputType], ColumnInputType]], optional): key or keys which matching rows must be equal on of the right DataFrame. Defaults to None.
+            direction (str, optional): which row to match; currently "backward", "forward", and "nearest" are supported. Defaults to "backward".
+            tolerance (Optional[Any], optional): largest distance between the keys of matching rows, such as a number or a ``datetime.timedelta``. Defaults to None, which does not limit the distance.
+            prefix (Optional[str], optional): Prefix to add to the column names in case of a name collision. Defaults to "right.".
+            suffix (Optional[str], optional): Suffix to add to the column names in case of a name collision. Defaults to "".
+
+        Raises:
+            ValueError: if `on` is passed in and `left_on` or `right_on` is not None.
+            ValueError: if `on` is None but both `left_on` and `right_on` are not defined.
+            ValueError: if `by` is passed in and `left_by` or `right_by` is not None.
+
+        Returns:
+            DataFrame: Joined DataFrame.
+        """
+        if on is None:
+            if left_on is None or right_on is None:
+                raise ValueError("If `on` is None then both `left_on` and `right_on` must not be None")
+        else:
+            if left_on is not None or right_on is not None:
+                raise ValueError("If `on` is not None then both `left_on` and `right_on` must be None")
+            left_on = on
+            right_on = on
+
+        if by is None:
+            if (left_by is None) != (right_by is None):
+                raise ValueError("If `by` is None then `left_by` and `right_by` must either both be set or both be None")
+            left_by = left_by if left_by is not None else []
+            right_by = right_by if right_by is not None else []
+        else:
+            if left_by is not None or right_by is not None:
+                raise ValueError("If `by` is not None then both `left_by` and `right_by` must be None")
+            left_by = by
+            right_by = by
+
+        left_keys = (*(left_by if isinstance(left_by, list) else [left_by]), left_on)
+        right_keys = (*(right_by if isinstance(right_by, list) else [right_by]), right_on)
+        builder = self._builder.asof_join(
+            other._builder,
+            left_on=self.__column_input_to_expression(left_keys),
+            right_on=self.__column_input_to_expression(right_keys),
+            direction=AsofDirection.from_asof_direction_str(direction),
+            tolerance=lit(tolerance) if tolerance is not None else None,
+            prefix=prefix,
+            suffix=suffix,
+        )
+        return DataFrame(builder)
+
     @DataframePublicAPI
     def concat(self, other: "DataFrame") -> "DataFrame":
         """Concatenates two DataFrames together in a "vertical" concatenation.
@@ -801,6 +801,27 @@ class Pivot(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class Window(SingleOutputInstruction):
+    window_functions: ExpressionsProjection
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._window(inputs)
+
+    def _window(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        return [input.window(self.window_functions)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        [input_meta] = input_metadatas
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class Unpivot(SingleOutputInstruction):
     ids: ExpressionsProjection
@@ -188,6 +188,21 @@ def pivot(
     )
 
 
+def window(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    window_functions: list[PyExpr],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    window_step = execution_step.Window(
+        window_functions=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in window_functions]),
+    )
+
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=window_step,
+        resource_request=ResourceRequest(),
+    )
+
+
 def sample(
     input: physical_plan.InProgressPhysicalPlan[PartitionT], fraction: float, with_replacement: bool, seed: int | None
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
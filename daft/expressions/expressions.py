@@ -2159,6 +2159,287 @@ class ExpressionDatetimeNamespace(ExpressionNamespace):
         """
         return Expression._from_pyexpr(native.dt_day_of_week(self._expr))
 
+    def day_of_year(self) -> Expression:
+        """Retrieves the day of the year for a datetime column, starting at 1 for January 1st.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "date": [
+            ...             datetime.date(2024, 1, 1),
+            ...             datetime.date(2024, 7, 4),
+            ...             datetime.date(2024, 12, 30),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("day_of_year", df["date"].dt.day_of_year()).collect()
+            ╭────────────┬─────────────╮
+            │ date       ┆ day_of_year │
+            │ ---        ┆ ---         │
+            │ Date       ┆ UInt32      │
+            ╞════════════╪═════════════╡
+            │ 2024-01-01 ┆ 1           │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-04 ┆ 186         │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-12-30 ┆ 365         │
+            ╰────────────┴─────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a UInt32 expression with just the day_of_year extracted from a datetime column
+        """
+        return Expression._from_pyexpr(native.dt_day_of_year(self._expr))
+
+    def week_of_year(self) -> Expression:
+        """Retrieves the ISO 8601 week of the year for a datetime column, where week 1 is the week containing the first Thursday of the year.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "date": [
+            ...             datetime.date(2024, 1, 1),
+            ...             datetime.date(2024, 7, 4),
+            ...             datetime.date(2024, 12, 30),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("week_of_year", df["date"].dt.week_of_year()).collect()
+            ╭────────────┬──────────────╮
+            │ date       ┆ week_of_year │
+            │ ---        ┆ ---          │
+            │ Date       ┆ UInt32       │
+            ╞════════════╪══════════════╡
+            │ 2024-01-01 ┆ 1            │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-04 ┆ 27           │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-12-30 ┆ 1            │
+            ╰────────────┴──────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a UInt32 expression with just the week_of_year extracted from a datetime column
+        """
+        return Expression._from_pyexpr(native.dt_week_of_year(self._expr))
+
+    def quarter(self) -> Expression:
+        """Retrieves the quarter of the year for a datetime column, from 1 to 4.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "date": [
+            ...             datetime.date(2024, 1, 1),
+            ...             datetime.date(2024, 7, 4),
+            ...             datetime.date(2024, 12, 30),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("quarter", df["date"].dt.quarter()).collect()
+            ╭────────────┬─────────╮
+            │ date       ┆ quarter │
+            │ ---        ┆ ---     │
+            │ Date       ┆ UInt32  │
+            ╞════════════╪═════════╡
+            │ 2024-01-01 ┆ 1       │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-04 ┆ 3       │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+            │ 2024-12-30 ┆ 4       │
+            ╰────────────┴─────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Returns:
+            Expression: a UInt32 expression with just the quarter extracted from a datetime column
+        """
+        return Expression._from_pyexpr(native.dt_quarter(self._expr))
+
+    def convert_time_zone(self, time_zone: str) -> Expression:
+        """Converts the datetime column to the specified timezone.
+
+        Timezone-aware datetimes keep the instant they represent and are displayed in the new timezone,
+        while naive datetimes are interpreted as local times in the new timezone.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict({"datetime": [datetime.datetime(2024, 7, 3, 12, 0, 0)]})
+            >>> df = df.with_column("utc", df["datetime"].dt.convert_time_zone("UTC"))
+            >>> df = df.with_column("new_york", df["utc"].dt.convert_time_zone("America/New_York"))
+
+        Args:
+            time_zone: The timezone to convert to, either an offset like "+05:00" or a name like "America/New_York".
+
+        Returns:
+            Expression: a timezone-aware DateTime expression in the specified timezone
+        """
+        return Expression._from_pyexpr(native.dt_convert_time_zone(self._expr, time_zone))
+
+    def strftime(self, format: str | None = None) -> Expression:
+        """Formats the datetime column as a string using a strftime-style format.
+
+        Timezone-aware datetimes are formatted in their timezone. Without a format, dates are formatted as ``%Y-%m-%d``,
+        times as ``%H:%M:%S%.f`` and datetimes in ISO 8601, e.g. ``%Y-%m-%dT%H:%M:%S%.f``.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "datetime": [
+            ...             datetime.datetime(2024, 7, 3, 0, 0, 0),
+            ...             datetime.datetime(2024, 7, 4, 12, 30, 0),
+            ...             datetime.datetime(2024, 7, 5, 23, 59, 59),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("formatted", df["datetime"].dt.strftime("%Y/%m/%d %H:%M")).collect()
+            ╭───────────────────────────────┬──────────────────╮
+            │ datetime                      ┆ formatted        │
+            │ ---                           ┆ ---              │
+            │ Timestamp(Microseconds, None) ┆ Utf8             │
+            ╞═══════════════════════════════╪══════════════════╡
+            │ 2024-07-03 00:00:00           ┆ 2024/07/03 00:00 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-04 12:30:00           ┆ 2024/07/04 12:30 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-05 23:59:59           ┆ 2024/07/05 23:59 │
+            ╰───────────────────────────────┴──────────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            format: The format to use, see the `chrono documentation <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_ for the supported specifiers.
+
+        Returns:
+            Expression: a String expression with the formatted datetimes
+        """
+        return Expression._from_pyexpr(native.dt_strftime(self._expr, format))
+
+    def to_unix_epoch(self, time_unit: str | TimeUnit = "s") -> Expression:
+        """Converts the datetime column to the number of time units since the Unix epoch, rounding down.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "datetime": [
+            ...             datetime.datetime(2024, 7, 3, 0, 0, 0),
+            ...             datetime.datetime(2024, 7, 4, 12, 30, 0),
+            ...             datetime.datetime(2024, 7, 5, 23, 59, 59),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("epoch", df["datetime"].dt.to_unix_epoch()).collect()
+            ╭───────────────────────────────┬────────────╮
+            │ datetime                      ┆ epoch      │
+            │ ---                           ┆ ---        │
+            │ Timestamp(Microseconds, None) ┆ Int64      │
+            ╞═══════════════════════════════╪════════════╡
+            │ 2024-07-03 00:00:00           ┆ 1719964800 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-04 12:30:00           ┆ 1720096200 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-07-05 23:59:59           ┆ 1720223999 │
+            ╰───────────────────────────────┴────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            time_unit: The unit of the result, one of "s", "ms", "us" or "ns". Defaults to seconds.
+
+        Returns:
+            Expression: an Int64 expression with the time since the Unix epoch
+        """
+        if isinstance(time_unit, str):
+            time_unit = TimeUnit.from_str(time_unit)
+        return Expression._from_pyexpr(native.dt_to_unix_epoch(self._expr, time_unit._timeunit))
+
+    def add_months(self, months: int | Expression) -> Expression:
+        """Adds a number of calendar months to the datetime column.
+
+        If the day of the month does not exist in the resulting month, it is clamped to the last day of that month.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "date": [
+            ...             datetime.date(2024, 1, 31),
+            ...             datetime.date(2024, 3, 31),
+            ...             datetime.date(2023, 12, 15),
+            ...         ],
+            ...     }
+            ... )
+            >>> df.with_column("next_month", df["date"].dt.add_months(1)).collect()
+            ╭────────────┬────────────╮
+            │ date       ┆ next_month │
+            │ ---        ┆ ---        │
+            │ Date       ┆ Date       │
+            ╞════════════╪════════════╡
+            │ 2024-01-31 ┆ 2024-02-29 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2024-03-31 ┆ 2024-04-30 │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
+            │ 2023-12-15 ┆ 2024-01-15 │
+            ╰────────────┴────────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            months: The number of months to add, which may be negative.
+
+        Returns:
+            Expression: a datetime expression of the same type shifted by the given number of months
+        """
+        months = Expression._to_expression(months)
+        return Expression._from_pyexpr(native.dt_add_months(self._expr, months._expr))
+
+    def date_diff(self, start: Expression, unit: str = "day") -> Expression:
+        """Counts the number of unit boundaries crossed going from ``start`` to the datetime column.
+
+        For example, the difference in months between January 31st and February 1st is 1. Units of a day or longer are
+        counted in the timezone of the datetimes, and shorter units on the instants they represent.
+
+        Example:
+            >>> import daft, datetime
+            >>> df = daft.from_pydict(
+            ...     {
+            ...         "start": [datetime.date(2024, 1, 31), datetime.date(2023, 7, 1), datetime.date(2024, 3, 1)],
+            ...         "end": [datetime.date(2024, 2, 1), datetime.date(2024, 6, 30), datetime.date(2024, 2, 1)],
+            ...     }
+            ... )
+            >>> df.with_column("months", df["end"].dt.date_diff(df["start"], "month")).collect()
+            ╭────────────┬────────────┬────────╮
+            │ start      ┆ end        ┆ months │
+            │ ---        ┆ ---        ┆ ---    │
+            │ Date       ┆ Date       ┆ Int64  │
+            ╞════════════╪════════════╪════════╡
+            │ 2024-01-31 ┆ 2024-02-01 ┆ 1      │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ 2023-07-01 ┆ 2024-06-30 ┆ 11     │
+            ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+            │ 2024-03-01 ┆ 2024-02-01 ┆ -1     │
+            ╰────────────┴────────────┴────────╯
+            <BLANKLINE>
+            (Showing first 3 of 3 rows)
+
+        Args:
+            start: The datetimes to count from.
+            unit: One of "year", "quarter", "month", "week", "day", "hour", "minute", "second", "millisecond" or
+                "microsecond". Weeks start on Mondays.
+
+        Returns:
+            Expression: an Int64 expression with the number of unit boundaries between the datetimes
+        """
+        start = Expression._to_expression(start)
+        return Expression._from_pyexpr(native.dt_date_diff(unit, start._expr, self._expr))
+
     def truncate(self, interval: str, relative_to: Expression | None = None) -> Expression:
         """Truncates the datetime column to the specified interval.
 
@@ -1,5 +1,5 @@
 from __future__ import annotations
 
-from .functions import monotonically_increasing_id
+from .functions import from_unix_epoch, monotonically_increasing_id
 
-__all__ = ["monotonically_increasing_id"]
+__all__ = ["from_unix_epoch", "monotonically_increasing_id"]
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import daft.daft as native
+from daft.datatype import TimeUnit
 from daft.expressions import Expression
 
 
@@ -34,3 +35,38 @@ def monotonically_increasing_id() -> Expression:
         Expression: An expression that generates monotonically increasing IDs
     """
     return Expression._from_pyexpr(native.monotonically_increasing_id())
+
+
+def from_unix_epoch(expr: Expression, time_unit: str | TimeUnit = "s") -> Expression:
+    """Converts a column of time units since the Unix epoch into timezone-naive datetimes.
+
+    Example:
+        >>> import daft
+        >>> from daft.functions import from_unix_epoch
+        >>> df = daft.from_pydict({"epoch": [0, 1719964800, 1720096200]})
+        >>> df = df.with_column("datetime", from_unix_epoch(df["epoch"]))
+        >>> df.show()
+        ╭────────────┬──────────────────────────╮
+        │ epoch      ┆ datetime                 │
+        │ ---        ┆ ---                      │
+        │ Int64      ┆ Timestamp(Seconds, None) │
+        ╞════════════╪══════════════════════════╡
+        │ 0          ┆ 1970-01-01 00:00:00      │
+        ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+        │ 1719964800 ┆ 2024-07-03 00:00:00      │
+        ├╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+        │ 1720096200 ┆ 2024-07-04 12:30:00      │
+        ╰────────────┴──────────────────────────╯
+
+    Args:
+        expr: A numeric expression of time units since the Unix epoch.
+        time_unit: The unit of the input, one of "s", "ms", "us" or "ns", which is also the unit of the resulting
+            datetimes. Defaults to seconds.
+
+    Returns:
+        Expression: a DateTime expression with the datetimes the input represents
+    """
+    if isinstance(time_unit, str):
+        time_unit = TimeUnit.from_str(time_unit)
+    expr = Expression._to_expression(expr)
+    return Expression._from_pyexpr(native.dt_from_unix_epoch(expr._expr, time_unit._timeunit))
@@ -2,18 +2,22 @@ from __future__ import annotations
 
 from daft.daft import (
     AzureConfig,
+    CacheConfig,
     GCSConfig,
     IOConfig,
     HTTPConfig,
     S3Config,
     S3Credentials,
 )
+from daft.io._avro import read_avro
 from daft.io._csv import read_csv
 from daft.io._deltalake import read_deltalake
 from daft.io._hudi import read_hudi
 from daft.io._iceberg import read_iceberg
+from daft.io._ipc import read_ipc
 from daft.io._json import read_json
 from daft.io._lance import read_lance
+from daft.io._orc import read_orc
 from daft.io._parquet import read_parquet
 from daft.io._sql import read_sql
 from daft.io._warc import read_warc
@@ -22,6 +26,7 @@ from daft.io.file_path import from_glob_path
 
 __all__ = [
     "AzureConfig",
+    "CacheConfig",
     "DataCatalogTable",
     "DataCatalogType",
     "GCSConfig",
@@ -30,12 +35,15 @@ __all__ = [
     "S3Config",
     "S3Credentials",
     "from_glob_path",
+    "read_avro",
     "read_csv",
     "read_deltalake",
     "read_hudi",
     "read_iceberg",
+    "read_ipc",
     "read_json",
     "read_lance",
+    "read_orc",
     "read_parquet",
     "read_sql",
     "read_warc",
@@ -31,6 +31,12 @@ def read_csv(
     file_path_column: Optional[str] = None,
     hive_partitioning: bool = False,
     schema_hints: Optional[Dict[str, DataType]] = None,
+    encoding: Optional[str] = None,
+    null_values: Optional[List[str]] = None,
+    skip_rows: int = 0,
+    skip_rows_after_header: int = 0,
+    truncate_ragged_lines: bool = False,
+    datetime_formats: Optional[Dict[str, str]] = None,
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
 ) -> DataFrame:
@@ -47,7 +53,9 @@ def read_csv(
         infer_schema (bool): Whether to infer the schema of the CSV, defaults to True.
         schema (dict[str, DataType]): A schema that is used as the definitive schema for the CSV if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
         has_headers (bool): Whether the CSV has a header or not, defaults to True
-        delimiter (Str): Delimiter used in the CSV, defaults to ","
+        delimiter (Str): Delimiter used in the CSV, which may be more than one character (e.g. "||"), defaults to ",".
+            Multi-character delimiters are rewritten to the ASCII unit separator (0x1F) before parsing, so CSVs with a
+            multi-character delimiter must not contain that character outside of quoted fields.
         doubled_quote (bool): Whether to support double quote escapes, defaults to True
         escape_char (str): Character to use as the escape character for double quotes, or defaults to `"`
         comment (str): Character to treat as the start of a comment line, or None to not support comments
@@ -55,6 +63,12 @@ def read_csv(
         io_config (IOConfig): Config to be used with the native downloader
         file_path_column: Include the source path(s) as a column with this name. Defaults to None.
         hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+        encoding (str): Text encoding of the CSV, e.g. "latin-1", "windows-1252" or "utf-16". Defaults to UTF-8.
+        null_values (list[str]): Cell values to read as nulls, e.g. ["NA", "\\N"]. Empty cells are always read as nulls for non-string columns.
+        skip_rows (int): Number of lines to skip at the start of each file, before the header. Defaults to 0.
+        skip_rows_after_header (int): Number of rows to skip after the header. Defaults to 0.
+        truncate_ragged_lines (bool): Whether to drop the extra columns of rows that have more columns than the schema instead of raising an error, defaults to False.
+        datetime_formats (dict[str, str]): Mapping of column names to `chrono <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`__ format strings used to infer and parse dates and timestamps in those columns, e.g. {"date": "%d/%m/%Y"}
 
     returns:
         DataFrame: parsed DataFrame
@@ -82,6 +96,12 @@ def read_csv(
         escape_char=escape_char,
         comment=comment,
         allow_variable_columns=allow_variable_columns,
+        encoding=encoding,
+        null_values=null_values,
+        skip_rows=skip_rows,
+        skip_rows_after_header=skip_rows_after_header,
+        truncate_ragged_lines=truncate_ragged_lines,
+        datetime_formats=datetime_formats,
         buffer_size=_buffer_size,
         chunk_size=_chunk_size,
     )
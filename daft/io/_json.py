@@ -27,7 +27,10 @@ def read_json(
     _buffer_size: Optional[int] = None,
     _chunk_size: Optional[int] = None,
 ) -> DataFrame:
-    """Creates a DataFrame from line-delimited JSON file(s).
+    """Creates a DataFrame from JSON file(s).
+
+    Files may contain newline-delimited JSON records, a top-level JSON array of records, or a sequence of
+    (possibly pretty-printed) JSON objects. The layout is detected from the start of each file.
 
     Example:
         >>> df = daft.read_json("/path/to/file.json")
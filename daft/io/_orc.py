@@ -0,0 +1,81 @@
+# isort: dont-add-import: from __future__ import annotations
+
+from typing import Dict, List, Optional, Union
+
+from daft import context
+from daft.api_annotations import PublicAPI
+from daft.daft import (
+    FileFormatConfig,
+    IOConfig,
+    OrcSourceConfig,
+    StorageConfig,
+)
+from daft.dataframe import DataFrame
+from daft.datatype import DataType
+from daft.io.common import get_tabular_files_scan
+
+
+@PublicAPI
+def read_orc(
+    path: Union[str, List[str]],
+    infer_schema: bool = True,
+    schema: Optional[Dict[str, DataType]] = None,
+    io_config: Optional["IOConfig"] = None,
+    file_path_column: Optional[str] = None,
+    hive_partitioning: bool = False,
+    _multithreaded_io: Optional[bool] = None,
+) -> DataFrame:
+    """Creates a DataFrame from ORC file(s).
+
+    Large files are split into multiple scan tasks by stripe, so a single file can be read in parallel. Only primitive,
+    string and binary columns in uncompressed or zlib-compressed files are supported for now.
+
+    Example:
+        >>> df = daft.read_orc("/path/to/file.orc")
+        >>> df = daft.read_orc("/path/to/directory")
+        >>> df = daft.read_orc("/path/to/files-*.orc")
+        >>> df = daft.read_orc("s3://path/to/files-*.orc")
+
+    Args:
+        path (str): Path to ORC files (allows for wildcards)
+        infer_schema (bool): Whether to infer the schema from the first file, defaults to True.
+        schema (dict[str, DataType]): A schema that is used as the definitive schema for the data if infer_schema is False, otherwise it is used as a schema hint that is applied after the schema is inferred.
+        io_config (IOConfig): Config to be used with the native downloader
+        file_path_column: Include the source path(s) as a column with this name. Defaults to None.
+        hive_partitioning: Whether to infer hive_style partitions from file paths and include them as columns in the Dataframe. Defaults to False.
+        _multithreaded_io: Whether to use multithreading for IO threads. Setting this to False can be helpful in reducing
+            the amount of system resources (number of connections and thread contention) when running in the Ray runner.
+            Defaults to None, which will let Daft decide based on the runner it is currently using.
+
+    returns:
+        DataFrame: parsed DataFrame
+    """
+    if isinstance(path, list) and len(path) == 0:
+        raise ValueError("Cannot read DataFrame from from empty list of ORC filepaths")
+
+    if not infer_schema and schema is None:
+        raise ValueError(
+            "Cannot read DataFrame with infer_schema=False and schema=None, please provide a schema or set infer_schema=True"
+        )
+
+    io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
+
+    # If running on Ray, we want to limit the amount of concurrency and requests being made.
+    # This is because each Ray worker process receives its own pool of thread workers and connections.
+    multithreaded_io = (
+        (context.get_context().get_or_create_runner().name != "ray") if _multithreaded_io is None else _multithreaded_io
+    )
+    storage_config = StorageConfig(multithreaded_io, io_config)
+
+    file_format_config = FileFormatConfig.from_orc_config(OrcSourceConfig())
+
+    builder = get_tabular_files_scan(
+        path=path,
+        infer_schema=infer_schema,
+        schema=schema,
+        file_format_config=file_format_config,
+        storage_config=storage_config,
+        file_path_column=file_path_column,
+        hive_partitioning=hive_partitioning,
+    )
+    return DataFrame(builder)
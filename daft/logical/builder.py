@@ -5,6 +5,7 @@ from typing import TYPE_CHECKING, Callable
 
 from daft.context import get_context
 from daft.daft import (
+    AsofDirection,
     CountMode,
     FileFormat,
     IOConfig,
@@ -233,6 +234,13 @@ class LogicalPlanBuilder:
         builder = self._builder.hash_repartition(partition_by_pyexprs, num_partitions=num_partitions)
         return LogicalPlanBuilder(builder)
 
+    def range_repartition(
+        self, num_partitions: int | None, partition_by: list[Expression], descending: list[bool]
+    ) -> LogicalPlanBuilder:
+        partition_by_pyexprs = [expr._expr for expr in partition_by]
+        builder = self._builder.range_repartition(partition_by_pyexprs, descending, num_partitions=num_partitions)
+        return LogicalPlanBuilder(builder)
+
     def random_shuffle(self, num_partitions: int | None) -> LogicalPlanBuilder:
         builder = self._builder.random_shuffle(num_partitions)
         return LogicalPlanBuilder(builder)
@@ -288,6 +296,27 @@ class LogicalPlanBuilder:
         )
         return LogicalPlanBuilder(builder)
 
+    def asof_join(
+        self,
+        right: LogicalPlanBuilder,
+        left_on: list[Expression],
+        right_on: list[Expression],
+        direction: AsofDirection = AsofDirection.Backward,
+        tolerance: Expression | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+    ) -> LogicalPlanBuilder:
+        builder = self._builder.asof_join(
+            right._builder,
+            [expr._expr for expr in left_on],
+            [expr._expr for expr in right_on],
+            direction,
+            tolerance._expr if tolerance is not None else None,
+            prefix,
+            suffix,
+        )
+        return LogicalPlanBuilder(builder)
+
     def concat(self, other: LogicalPlanBuilder) -> LogicalPlanBuilder:  # type: ignore[override]
         builder = self._builder.concat(other._builder)
         return LogicalPlanBuilder(builder)
@@ -324,8 +353,10 @@ class LogicalPlanBuilder:
         partition_cols: list[Expression] | None = None,
         compression: str | None = None,
     ) -> LogicalPlanBuilder:
-        if file_format != FileFormat.Csv and file_format != FileFormat.Parquet:
-            raise ValueError(f"Writing is only supported for Parquet and CSV file formats, but got: {file_format}")
+        if file_format not in (FileFormat.Csv, FileFormat.Parquet, FileFormat.Json, FileFormat.Ipc):
+            raise ValueError(
+                f"Writing is only supported for Parquet, CSV, JSON and Arrow IPC file formats, but got: {file_format}"
+            )
         part_cols_pyexprs = [expr._expr for expr in partition_cols] if partition_cols is not None else None
         builder = self._builder.table_write(str(root_dir), file_format, part_cols_pyexprs, compression, io_config)
         return LogicalPlanBuilder(builder)
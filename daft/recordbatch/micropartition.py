@@ -254,6 +254,11 @@ class MicroPartition:
         to_explode_pyexprs = [e._expr for e in columns]
         return MicroPartition._from_pymicropartition(self._micropartition.explode(to_explode_pyexprs))
 
+    def window(self, window_functions: ExpressionsProjection) -> MicroPartition:
+        """NOTE: Expressions here must be window functions that share the same window spec."""
+        window_function_pyexprs = [e._expr for e in window_functions]
+        return MicroPartition._from_pymicropartition(self._micropartition.window(window_function_pyexprs))
+
     def unpivot(
         self, ids: ExpressionsProjection, values: ExpressionsProjection, variable_name: str, value_name: str
     ) -> MicroPartition:
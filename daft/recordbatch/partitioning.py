@@ -1,4 +1,5 @@
 from typing import Dict, List, Optional, Union
+from urllib.parse import quote
 
 from daft import Series
 from daft.expressions import ExpressionsProjection
@@ -13,7 +14,8 @@ def partition_strings_to_path(
     partition_null_fallback: str = "__HIVE_DEFAULT_PARTITION__",
 ) -> str:
     keys = parts.keys()
-    values = [partition_null_fallback if value is None else value for value in parts.values()]
+    # Values are percent-encoded, which hive-style partition parsing decodes when the files are read.
+    values = [partition_null_fallback if value is None else quote(value, safe="") for value in parts.values()]
     postfix = "/".join(f"{k}={v}" for k, v in zip(keys, values))
     return f"{root_path}/{postfix}"
 
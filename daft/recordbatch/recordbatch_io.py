@@ -619,6 +619,15 @@ def write_empty_tabular(
         elif file_format == FileFormat.Csv:
             output_file = fs.open_output_stream(file_path)
             pacsv.write_csv(table, output_file)
+        elif file_format == FileFormat.Json:
+            # An empty newline-delimited JSON file has no rows to write.
+            fs.open_output_stream(file_path).close()
+        elif file_format == FileFormat.Ipc:
+            options = pa.ipc.IpcWriteOptions(compression=compression)
+            output_file = fs.open_output_stream(file_path)
+            with pa.ipc.new_file(output_file, table.schema, options=options) as writer:
+                writer.write_table(table)
+            output_file.close()
         else:
             raise ValueError(f"Unsupported file format {file_format}")
 
@@ -1,15 +1,18 @@
 use ahash::AHashMap;
 
+use std::collections::VecDeque;
 use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
 
 use crate::array::Array;
 use crate::chunk::Chunk;
-use crate::datatypes::Schema;
+use crate::datatypes::{DataType, Schema};
 use crate::error::{Error, Result};
 use crate::io::ipc::IpcSchema;
 
 use super::super::{ARROW_MAGIC_V1, ARROW_MAGIC_V2, CONTINUATION_MARKER};
 use super::common::*;
+use super::deserialize::skip;
 use super::schema::fb_to_schema;
 use super::Dictionaries;
 use super::OutOfSpecKind;
@@ -30,7 +33,7 @@ pub struct FileMetadata {
     pub blocks: Vec<arrow_format::ipc::Block>,
 
     /// Dictionaries associated to each dict_id
-    pub(crate) dictionaries: Option<Vec<arrow_format::ipc::Block>>,
+    pub dictionaries: Option<Vec<arrow_format::ipc::Block>>,
 
     /// The total size of the file in bytes
     pub size: u64,
@@ -320,3 +323,116 @@ pub fn read_batch<R: Read + Seek>(
         data_scratch,
     )
 }
+
+fn contains_union(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::Union(..) => true,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => contains_union(field.data_type()),
+        DataType::Struct(fields) => fields.iter().any(|field| contains_union(field.data_type())),
+        DataType::Dictionary(_, values, _) => contains_union(values),
+        _ => false,
+    }
+}
+
+/// Returns the byte ranges of the file that [`read_batch`] reads from the body of the record
+/// batch at position `index` when reading `projection`.
+///
+/// `message` holds the first `meta_data_length` bytes of the batch's block, i.e. its length
+/// prefix and flatbuffer message. Together with the returned ranges, these are all the bytes
+/// needed to read the batch, which allows fetching only the projected columns of a batch from
+/// remote storage.
+/// # Panics
+/// This function panics iff `index >= metadata.blocks.len()`
+pub fn read_batch_body_ranges(
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    index: usize,
+    message: &[u8],
+) -> Result<Vec<Range<u64>>> {
+    let block = metadata.blocks[index];
+
+    let offset: u64 = block
+        .offset
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let length: u64 = block
+        .meta_data_length
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let body_length: u64 = block
+        .body_length
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let body_start = offset + length;
+
+    // Skipping over union columns is not supported, so read the whole body instead.
+    if metadata
+        .schema
+        .fields
+        .iter()
+        .any(|field| contains_union(field.data_type()))
+    {
+        let body = body_start..body_start + body_length;
+        return Ok(vec![body]);
+    }
+
+    let mut reader = std::io::Cursor::new(message);
+    let mut meta_buf = [0; 4];
+    reader.read_exact(&mut meta_buf)?;
+    if meta_buf == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_buf)?;
+    }
+    let meta_len: usize = i32::from_le_bytes(meta_buf)
+        .try_into()
+        .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+    let meta_start = reader.position() as usize;
+    let message = message
+        .get(meta_start..meta_start + meta_len)
+        .ok_or_else(|| Error::oos("IPC: the record batch message is truncated"))?;
+
+    let message = arrow_format::ipc::MessageRef::read_as_root(message)
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferMessage(err)))?;
+    let batch = get_record_batch(message)?;
+
+    let buffers = batch
+        .buffers()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferBuffers(err)))?
+        .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageBuffers))?
+        .iter()
+        .collect::<Vec<_>>();
+    let mut remaining_buffers = buffers.iter().copied().collect::<VecDeque<_>>();
+    let mut field_nodes = batch
+        .nodes()
+        .map_err(|err| Error::from(OutOfSpecKind::InvalidFlatbufferNodes(err)))?
+        .ok_or_else(|| Error::from(OutOfSpecKind::MissingMessageNodes))?
+        .iter()
+        .collect::<VecDeque<_>>();
+
+    let mut ranges = vec![];
+    for (i, field) in metadata.schema.fields.iter().enumerate() {
+        let first_buffer = buffers.len() - remaining_buffers.len();
+        skip(&mut field_nodes, field.data_type(), &mut remaining_buffers)?;
+        if projection.map_or(false, |projection| !projection.contains(&i)) {
+            continue;
+        }
+        // The buffers consumed by skipping the column are the ones reading it would read.
+        for buffer in &buffers[first_buffer..buffers.len() - remaining_buffers.len()] {
+            let buffer_offset: u64 = buffer
+                .offset()
+                .try_into()
+                .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+            let buffer_length: u64 = buffer
+                .length()
+                .try_into()
+                .map_err(|_| Error::from(OutOfSpecKind::UnexpectedNegativeInteger))?;
+            if buffer_length > 0 {
+                let start = body_start + buffer_offset;
+                ranges.push(start..start + buffer_length);
+            }
+        }
+    }
+    Ok(ranges)
+}
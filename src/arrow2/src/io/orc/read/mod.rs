@@ -8,6 +8,7 @@ use crate::error::Error;
 use crate::offset::{Offset, Offsets};
 use crate::types::NativeType;
 
+use orc_format::proto::column_encoding::Kind as ColumnEncodingKind;
 use orc_format::proto::stream::Kind;
 use orc_format::proto::{Footer, Type};
 use orc_format::read::decode;
@@ -65,7 +66,12 @@ fn infer_dt(type_: &Type, types: &[Type]) -> Result<DataType, Error> {
 }
 
 fn deserialize_validity(column: &Column, scratch: &mut Vec<u8>) -> Result<Option<Bitmap>, Error> {
-    let stream = column.get_stream(Kind::Present, std::mem::take(scratch))?;
+    // Writers leave out the present stream of columns without nulls.
+    let stream = match column.get_stream(Kind::Present, std::mem::take(scratch)) {
+        Ok(stream) => stream,
+        Err(orc_format::error::Error::InvalidKind(_, Kind::Present)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
 
     let mut stream = decode::BooleanIter::new(stream, column.number_of_rows());
 
@@ -260,9 +266,72 @@ fn try_extend<O: Offset + TryFrom<u64>, I: Iterator<Item = u64>>(
     Ok(())
 }
 
+fn deserialize_binary_dictionary<O: Offset + TryFrom<u64>>(
+    column: &Column,
+) -> Result<(Offsets<O>, Vec<u8>, Option<Bitmap>), Error> {
+    let num_rows = column.number_of_rows();
+    let mut scratch = vec![];
+
+    let validity = deserialize_validity(column, &mut scratch)?;
+
+    // the dictionary is stored as the lengths of its entries followed by their concatenated bytes
+    let dictionary_size = column
+        .dictionary_size()
+        .ok_or(orc_format::error::Error::OutOfSpec)?;
+    let lengths = column.get_stream(Kind::Length, scratch)?;
+    let mut iter = decode::UnsignedRleV2Iter::new(lengths, dictionary_size, vec![]);
+    let mut entry_offsets = Vec::with_capacity(dictionary_size + 1);
+    entry_offsets.push(0usize);
+    for length in iter.by_ref() {
+        let end = entry_offsets[entry_offsets.len() - 1] + length? as usize;
+        entry_offsets.push(end);
+    }
+    let (lengths, _) = iter.into_inner();
+    scratch = lengths.into_inner();
+    let mut dictionary = vec![0; entry_offsets[entry_offsets.len() - 1]];
+    let mut data = column.get_stream(Kind::DictionaryData, scratch)?;
+    data.read_exact(&mut dictionary)?;
+
+    let num_values = validity
+        .as_ref()
+        .map_or(num_rows, |validity| validity.len() - validity.unset_bits());
+    let indices = column.get_stream(Kind::Data, vec![])?;
+    let mut indices = decode::UnsignedRleV2Iter::new(indices, num_values, vec![]);
+
+    let mut offsets = Offsets::with_capacity(num_rows);
+    let mut values = vec![];
+    for row in 0..num_rows {
+        let is_valid = validity
+            .as_ref()
+            .map_or(true, |validity| validity.get_bit(row));
+        if !is_valid {
+            offsets.extend_constant(1);
+            continue;
+        }
+        let index = indices
+            .next()
+            .transpose()?
+            .ok_or(orc_format::error::Error::OutOfSpec)? as usize;
+        let entry = entry_offsets
+            .get(index..index + 2)
+            .and_then(|bounds| dictionary.get(bounds[0]..bounds[1]))
+            .ok_or(orc_format::error::Error::OutOfSpec)?;
+        let length: O = (entry.len() as u64)
+            .try_into()
+            .map_err(|_| Error::Overflow)?;
+        offsets.try_push(length)?;
+        values.extend_from_slice(entry);
+    }
+
+    Ok((offsets, values, validity))
+}
+
 fn deserialize_binary_generic<O: Offset + TryFrom<u64>>(
     column: &Column,
 ) -> Result<(Offsets<O>, Vec<u8>, Option<Bitmap>), Error> {
+    if column.encoding().kind() == ColumnEncodingKind::DictionaryV2 {
+        return deserialize_binary_dictionary(column);
+    }
     let num_rows = column.number_of_rows();
     let mut scratch = vec![];
 
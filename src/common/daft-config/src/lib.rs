@@ -7,10 +7,21 @@ use serde::{Deserialize, Serialize};
 /// 1. Creation of a Dataframe including any file listing and schema inference that needs to happen. Note
 ///    that this does not include the actual scan, which is taken care of by the DaftExecutionConfig.
 /// 2. Building of logical plan nodes
-#[derive(Clone, Serialize, Deserialize, Default, Debug, Eq, PartialEq)]
+#[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct DaftPlanningConfig {
     pub default_io_config: IOConfig,
     pub enable_join_reordering: bool,
+    pub recursive_cte_max_iterations: usize,
+}
+
+impl Default for DaftPlanningConfig {
+    fn default() -> Self {
+        Self {
+            default_io_config: IOConfig::default(),
+            enable_join_reordering: false,
+            recursive_cte_max_iterations: 100,
+        }
+    }
 }
 
 impl DaftPlanningConfig {
@@ -43,6 +54,7 @@ pub struct DaftExecutionConfig {
     pub max_sources_per_scan_task: usize,
     pub broadcast_join_size_bytes_threshold: usize,
     pub sort_merge_join_sort_with_aligned_boundaries: bool,
+    pub sort_merge_join_presorted_scans: bool,
     pub hash_join_partition_size_leniency: f64,
     pub sample_size_for_sort: usize,
     pub parquet_split_row_groups_max_files: usize,
@@ -73,6 +85,7 @@ impl Default for DaftExecutionConfig {
             max_sources_per_scan_task: 10,
             broadcast_join_size_bytes_threshold: 10 * 1024 * 1024, // 10 MiB
             sort_merge_join_sort_with_aligned_boundaries: false,
+            sort_merge_join_presorted_scans: false,
             hash_join_partition_size_leniency: 0.5,
             sample_size_for_sort: 20,
             parquet_split_row_groups_max_files: 10,
@@ -29,14 +29,22 @@ impl PyDaftPlanningConfig {
         }
     }
 
-    #[pyo3(signature = (default_io_config=None))]
-    fn with_config_values(&mut self, default_io_config: Option<PyIOConfig>) -> PyResult<Self> {
+    #[pyo3(signature = (default_io_config=None, recursive_cte_max_iterations=None))]
+    fn with_config_values(
+        &mut self,
+        default_io_config: Option<PyIOConfig>,
+        recursive_cte_max_iterations: Option<usize>,
+    ) -> PyResult<Self> {
         let mut config = self.config.as_ref().clone();
 
         if let Some(default_io_config) = default_io_config {
             config.default_io_config = default_io_config.config;
         }
 
+        if let Some(recursive_cte_max_iterations) = recursive_cte_max_iterations {
+            config.recursive_cte_max_iterations = recursive_cte_max_iterations;
+        }
+
         Ok(Self {
             config: Arc::new(config),
         })
@@ -48,6 +56,11 @@ impl PyDaftPlanningConfig {
             config: self.config.default_io_config.clone(),
         })
     }
+
+    #[getter]
+    fn get_recursive_cte_max_iterations(&self) -> PyResult<usize> {
+        Ok(self.config.recursive_cte_max_iterations)
+    }
 }
 
 impl_bincode_py_state_serialization!(PyDaftPlanningConfig);
@@ -82,6 +95,7 @@ impl PyDaftExecutionConfig {
         broadcast_join_size_bytes_threshold=None,
         parquet_split_row_groups_max_files=None,
         sort_merge_join_sort_with_aligned_boundaries=None,
+        sort_merge_join_presorted_scans=None,
         hash_join_partition_size_leniency=None,
         sample_size_for_sort=None,
         num_preview_rows=None,
@@ -110,6 +124,7 @@ impl PyDaftExecutionConfig {
         broadcast_join_size_bytes_threshold: Option<usize>,
         parquet_split_row_groups_max_files: Option<usize>,
         sort_merge_join_sort_with_aligned_boundaries: Option<bool>,
+        sort_merge_join_presorted_scans: Option<bool>,
         hash_join_partition_size_leniency: Option<f64>,
         sample_size_for_sort: Option<usize>,
         num_preview_rows: Option<usize>,
@@ -153,6 +168,9 @@ impl PyDaftExecutionConfig {
             config.sort_merge_join_sort_with_aligned_boundaries =
                 sort_merge_join_sort_with_aligned_boundaries;
         }
+        if let Some(sort_merge_join_presorted_scans) = sort_merge_join_presorted_scans {
+            config.sort_merge_join_presorted_scans = sort_merge_join_presorted_scans;
+        }
         if let Some(hash_join_partition_size_leniency) = hash_join_partition_size_leniency {
             config.hash_join_partition_size_leniency = hash_join_partition_size_leniency;
         }
@@ -259,6 +277,11 @@ impl PyDaftExecutionConfig {
         Ok(self.config.sort_merge_join_sort_with_aligned_boundaries)
     }
 
+    #[getter]
+    fn get_sort_merge_join_presorted_scans(&self) -> PyResult<bool> {
+        Ok(self.config.sort_merge_join_presorted_scans)
+    }
+
     #[getter]
     fn get_hash_join_partition_size_leniency(&self) -> PyResult<f64> {
         Ok(self.config.hash_join_partition_size_leniency)
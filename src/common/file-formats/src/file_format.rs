@@ -10,7 +10,7 @@ use common_py_serde::impl_bincode_py_state_serialization;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
-/// Format of a file, e.g. Parquet, CSV, JSON.
+/// Format of a file, e.g. Parquet, CSV, JSON, Arrow IPC, Avro, ORC.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
 pub enum FileFormat {
@@ -18,6 +18,9 @@ pub enum FileFormat {
     Csv,
     Json,
     Warc,
+    Ipc,
+    Avro,
+    Orc,
     Database,
     Python,
 }
@@ -31,6 +34,9 @@ impl FileFormat {
             Self::Csv => "csv",
             Self::Json => "json",
             Self::Warc => "warc",
+            Self::Ipc => "arrow",
+            Self::Avro => "avro",
+            Self::Orc => "orc",
             Self::Database => "db",
             Self::Python => "py",
         }
@@ -41,7 +47,7 @@ impl FromStr for FileFormat {
     type Err = DaftError;
 
     fn from_str(file_format: &str) -> DaftResult<Self> {
-        use FileFormat::{Csv, Database, Json, Parquet, Warc};
+        use FileFormat::{Avro, Csv, Database, Ipc, Json, Orc, Parquet, Warc};
 
         if file_format.trim().eq_ignore_ascii_case("parquet") {
             Ok(Parquet)
@@ -51,6 +57,15 @@ impl FromStr for FileFormat {
             Ok(Json)
         } else if file_format.trim().eq_ignore_ascii_case("warc") {
             Ok(Warc)
+        } else if ["ipc", "arrow", "feather"]
+            .iter()
+            .any(|name| file_format.trim().eq_ignore_ascii_case(name))
+        {
+            Ok(Ipc)
+        } else if file_format.trim().eq_ignore_ascii_case("avro") {
+            Ok(Avro)
+        } else if file_format.trim().eq_ignore_ascii_case("orc") {
+            Ok(Orc)
         } else if file_format.trim().eq_ignore_ascii_case("database") {
             Ok(Database)
         } else {
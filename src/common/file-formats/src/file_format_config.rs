@@ -19,6 +19,9 @@ pub enum FileFormatConfig {
     Csv(CsvSourceConfig),
     Json(JsonSourceConfig),
     Warc(WarcSourceConfig),
+    Ipc(IpcSourceConfig),
+    Avro(AvroSourceConfig),
+    Orc(OrcSourceConfig),
     #[cfg(feature = "python")]
     Database(DatabaseSourceConfig),
     #[cfg(feature = "python")]
@@ -38,6 +41,9 @@ impl FileFormatConfig {
             Self::Csv(_) => "Csv",
             Self::Json(_) => "Json",
             Self::Warc(_) => "Warc",
+            Self::Ipc(_) => "Ipc",
+            Self::Avro(_) => "Avro",
+            Self::Orc(_) => "Orc",
             #[cfg(feature = "python")]
             Self::Database(_) => "Database",
             #[cfg(feature = "python")]
@@ -52,6 +58,9 @@ impl FileFormatConfig {
             Self::Csv(source) => source.multiline_display(),
             Self::Json(source) => source.multiline_display(),
             Self::Warc(source) => source.multiline_display(),
+            Self::Ipc(source) => source.multiline_display(),
+            Self::Avro(source) => source.multiline_display(),
+            Self::Orc(source) => source.multiline_display(),
             #[cfg(feature = "python")]
             Self::Database(source) => source.multiline_display(),
             #[cfg(feature = "python")]
@@ -166,13 +175,19 @@ impl_bincode_py_state_serialization!(ParquetSourceConfig);
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
 pub struct CsvSourceConfig {
-    pub delimiter: Option<char>,
+    pub delimiter: Option<String>,
     pub has_headers: bool,
     pub double_quote: bool,
     pub quote: Option<char>,
     pub escape_char: Option<char>,
     pub comment: Option<char>,
     pub allow_variable_columns: bool,
+    pub encoding: Option<String>,
+    pub null_values: Option<Vec<String>>,
+    pub skip_rows: usize,
+    pub skip_rows_after_header: usize,
+    pub truncate_ragged_lines: bool,
+    pub datetime_formats: Option<BTreeMap<String, String>>,
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
 }
@@ -181,7 +196,7 @@ impl CsvSourceConfig {
     #[must_use]
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
-        if let Some(delimiter) = self.delimiter {
+        if let Some(delimiter) = &self.delimiter {
             res.push(format!("Delimiter = {delimiter}"));
         }
         res.push(format!("Has headers = {}", self.has_headers));
@@ -199,6 +214,27 @@ impl CsvSourceConfig {
             "Allow_variable_columns = {}",
             self.allow_variable_columns
         ));
+        if let Some(encoding) = &self.encoding {
+            res.push(format!("Encoding = {encoding}"));
+        }
+        if let Some(null_values) = &self.null_values {
+            res.push(format!("Null values = {null_values:?}"));
+        }
+        if self.skip_rows > 0 {
+            res.push(format!("Skip rows = {}", self.skip_rows));
+        }
+        if self.skip_rows_after_header > 0 {
+            res.push(format!(
+                "Skip rows after header = {}",
+                self.skip_rows_after_header
+            ));
+        }
+        if self.truncate_ragged_lines {
+            res.push("Truncate ragged lines = true".to_string());
+        }
+        if let Some(datetime_formats) = &self.datetime_formats {
+            res.push(format!("Datetime formats = {datetime_formats:?}"));
+        }
         if let Some(buffer_size) = self.buffer_size {
             res.push(format!("Buffer size = {buffer_size}"));
         }
@@ -216,8 +252,14 @@ impl CsvSourceConfig {
     ///
     /// # Arguments
     ///
-    /// * `delimiter` - The character delmiting individual cells in the CSV data.
+    /// * `delimiter` - The string delmiting individual cells in the CSV data, which may be more than one character.
     /// * `has_headers` - Whether the CSV has a header row; if so, it will be skipped during data parsing.
+    /// * `encoding` - The label of the text encoding of the CSV data; defaults to UTF-8.
+    /// * `null_values` - Cell values that should be read as nulls.
+    /// * `skip_rows` - The number of lines to skip at the start of each file, before the header.
+    /// * `skip_rows_after_header` - The number of records to skip after the header.
+    /// * `truncate_ragged_lines` - Whether to drop the extra cells of records that are longer than the schema.
+    /// * `datetime_formats` - Per-column `chrono` format strings used to parse dates and timestamps.
     /// * `buffer_size` - Size of the buffer (in bytes) used by the streaming reader.
     /// * `chunk_size` - Size of the chunks (in bytes) deserialized in parallel by the streaming reader.
     #[allow(clippy::too_many_arguments)]
@@ -230,6 +272,12 @@ impl CsvSourceConfig {
         quote=None,
         escape_char=None,
         comment=None,
+        encoding=None,
+        null_values=None,
+        skip_rows=0,
+        skip_rows_after_header=0,
+        truncate_ragged_lines=false,
+        datetime_formats=None,
         buffer_size=None,
         chunk_size=None
     ))]
@@ -237,10 +285,16 @@ impl CsvSourceConfig {
         has_headers: bool,
         double_quote: bool,
         allow_variable_columns: bool,
-        delimiter: Option<char>,
+        delimiter: Option<String>,
         quote: Option<char>,
         escape_char: Option<char>,
         comment: Option<char>,
+        encoding: Option<String>,
+        null_values: Option<Vec<String>>,
+        skip_rows: usize,
+        skip_rows_after_header: usize,
+        truncate_ragged_lines: bool,
+        datetime_formats: Option<BTreeMap<String, String>>,
         buffer_size: Option<usize>,
         chunk_size: Option<usize>,
     ) -> PyResult<Self> {
@@ -252,6 +306,12 @@ impl CsvSourceConfig {
             escape_char,
             comment,
             allow_variable_columns,
+            encoding,
+            null_values,
+            skip_rows,
+            skip_rows_after_header,
+            truncate_ragged_lines,
+            datetime_formats,
             buffer_size,
             chunk_size,
         })
@@ -402,3 +462,79 @@ impl WarcSourceConfig {
 }
 
 impl_bincode_py_state_serialization!(WarcSourceConfig);
+
+/// Configuration for an Arrow IPC data source, i.e. files in the Arrow IPC file (Feather V2) or
+/// streaming format.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct IpcSourceConfig {}
+
+impl IpcSourceConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl IpcSourceConfig {
+    /// Create a config for an Arrow IPC data source.
+    #[new]
+    #[pyo3(signature = ())]
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl_bincode_py_state_serialization!(IpcSourceConfig);
+
+/// Configuration for an Avro data source, i.e. Avro object container files.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct AvroSourceConfig {}
+
+impl AvroSourceConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AvroSourceConfig {
+    /// Create a config for an Avro data source.
+    #[new]
+    #[pyo3(signature = ())]
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl_bincode_py_state_serialization!(AvroSourceConfig);
+
+/// Configuration for an ORC data source.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
+pub struct OrcSourceConfig {}
+
+impl OrcSourceConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl OrcSourceConfig {
+    /// Create a config for an ORC data source.
+    #[new]
+    #[pyo3(signature = ())]
+    fn new() -> Self {
+        Self {}
+    }
+}
+
+impl_bincode_py_state_serialization!(OrcSourceConfig);
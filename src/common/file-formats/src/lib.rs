@@ -5,7 +5,8 @@ mod file_format_config;
 #[cfg(feature = "python")]
 pub use file_format_config::DatabaseSourceConfig;
 pub use file_format_config::{
-    CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig, WarcSourceConfig,
+    AvroSourceConfig, CsvSourceConfig, FileFormatConfig, IpcSourceConfig, JsonSourceConfig,
+    OrcSourceConfig, ParquetSourceConfig, WarcSourceConfig,
 };
 
 #[cfg(feature = "python")]
@@ -18,6 +19,9 @@ impl From<&FileFormatConfig> for FileFormat {
             FileFormatConfig::Csv(_) => Self::Csv,
             FileFormatConfig::Json(_) => Self::Json,
             FileFormatConfig::Warc(_) => Self::Warc,
+            FileFormatConfig::Ipc(_) => Self::Ipc,
+            FileFormatConfig::Avro(_) => Self::Avro,
+            FileFormatConfig::Orc(_) => Self::Orc,
             #[cfg(feature = "python")]
             FileFormatConfig::Database(_) => Self::Database,
             #[cfg(feature = "python")]
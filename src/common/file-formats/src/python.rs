@@ -5,8 +5,9 @@ use pyo3::{basic::CompareOp, prelude::*};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    file_format_config::DatabaseSourceConfig, CsvSourceConfig, FileFormat, FileFormatConfig,
-    JsonSourceConfig, ParquetSourceConfig, WarcSourceConfig,
+    file_format_config::DatabaseSourceConfig, AvroSourceConfig, CsvSourceConfig, FileFormat,
+    FileFormatConfig, IpcSourceConfig, JsonSourceConfig, OrcSourceConfig, ParquetSourceConfig,
+    WarcSourceConfig,
 };
 
 /// Configuration for parsing a particular file format.
@@ -44,6 +45,24 @@ impl PyFileFormatConfig {
         Self(Arc::new(FileFormatConfig::Warc(config)))
     }
 
+    /// Create an Arrow IPC file format config.
+    #[staticmethod]
+    fn from_ipc_config(config: IpcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Ipc(config)))
+    }
+
+    /// Create an Avro file format config.
+    #[staticmethod]
+    fn from_avro_config(config: AvroSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Avro(config)))
+    }
+
+    /// Create an ORC file format config.
+    #[staticmethod]
+    fn from_orc_config(config: OrcSourceConfig) -> Self {
+        Self(Arc::new(FileFormatConfig::Orc(config)))
+    }
+
     /// Create a Database file format config.
     #[staticmethod]
     fn from_database_config(config: DatabaseSourceConfig) -> Self {
@@ -70,6 +89,18 @@ impl PyFileFormatConfig {
                 .clone()
                 .into_pyobject(py)
                 .map(|c| c.unbind().into_any()),
+            FileFormatConfig::Ipc(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
+            FileFormatConfig::Avro(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
+            FileFormatConfig::Orc(config) => config
+                .clone()
+                .into_pyobject(py)
+                .map(|c| c.unbind().into_any()),
             FileFormatConfig::Database(config) => config
                 .clone()
                 .into_pyobject(py)
@@ -0,0 +1,45 @@
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the local disk cache for byte ranges read from remote object stores.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[display(
+    "CacheConfig
+    enabled: {enabled}
+    directory: {directory:?}
+    max_size_bytes: {max_size_bytes}
+    metadata_ttl_secs: {metadata_ttl_secs}"
+)]
+pub struct CacheConfig {
+    pub enabled: bool,
+    /// Directory of the cache, defaults to `daft-io-cache` in the system temporary directory.
+    pub directory: Option<String>,
+    /// Size above which the least recently used entries are evicted.
+    pub max_size_bytes: u64,
+    /// How long the size and ETag of an object are trusted before they are requested again.
+    pub metadata_ttl_secs: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: None,
+            max_size_bytes: 10 * 1024 * 1024 * 1024,
+            metadata_ttl_secs: 60,
+        }
+    }
+}
+
+impl CacheConfig {
+    #[must_use]
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Enabled = {}", self.enabled)];
+        if let Some(directory) = &self.directory {
+            res.push(format!("Directory = {directory}"));
+        }
+        res.push(format!("Max size bytes = {}", self.max_size_bytes));
+        res.push(format!("Metadata TTL secs = {}", self.metadata_ttl_secs));
+        res
+    }
+}
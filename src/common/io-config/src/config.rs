@@ -2,13 +2,14 @@ use std::fmt::{Display, Formatter};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{AzureConfig, GCSConfig, HTTPConfig, S3Config};
+use crate::{AzureConfig, CacheConfig, GCSConfig, HTTPConfig, S3Config};
 #[derive(Clone, Default, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct IOConfig {
     pub s3: S3Config,
     pub azure: AzureConfig,
     pub gcs: GCSConfig,
     pub http: HTTPConfig,
+    pub cache: CacheConfig,
 }
 
 impl IOConfig {
@@ -31,6 +32,10 @@ impl IOConfig {
             "HTTP config = {{ {} }}",
             self.http.multiline_display().join(", ")
         ));
+        res.push(format!(
+            "Cache config = {{ {} }}",
+            self.cache.multiline_display().join(", ")
+        ));
         res
     }
 }
@@ -43,8 +48,9 @@ impl Display for IOConfig {
 {}
 {}
 {}
+{}
 {}",
-            self.s3, self.azure, self.gcs, self.http,
+            self.s3, self.azure, self.gcs, self.http, self.cache,
         )
     }
 }
@@ -4,6 +4,7 @@
 pub mod python;
 
 mod azure;
+mod cache;
 mod config;
 mod gcs;
 mod http;
@@ -19,6 +20,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 pub use crate::{
     azure::AzureConfig,
+    cache::CacheConfig,
     config::IOConfig,
     gcs::GCSConfig,
     http::HTTPConfig,
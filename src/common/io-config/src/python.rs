@@ -131,6 +131,7 @@ pub struct GCSConfig {
 ///     s3: Configuration to use when accessing URLs with the `s3://` scheme
 ///     azure: Configuration to use when accessing URLs with the `az://` or `abfs://` scheme
 ///     gcs: Configuration to use when accessing URLs with the `gs://` or `gcs://` scheme
+///     cache: Configuration of the local disk cache for reads from remote object stores
 /// Example:
 ///     >>> io_config = IOConfig(s3=S3Config(key_id="xxx", access_key="xxx", num_tries=10), azure=AzureConfig(anonymous=True), gcs=GCSConfig(...))
 ///     >>> daft.read_parquet(["s3://some-path", "az://some-other-path", "gs://path3"], io_config=io_config)
@@ -155,16 +156,37 @@ pub struct HTTPConfig {
     pub config: crate::HTTPConfig,
 }
 
+/// Create configurations for caching the byte ranges read from remote object stores on local disk.
+///
+/// Cached ranges are keyed by their URL, the size of the object and the range, so a modified object
+/// is read again. Only reads from remote stores such as S3, GCS, Azure and HTTP are cached.
+///
+/// Args:
+///     enabled (bool, optional): Whether to cache reads, defaults to False
+///     directory (str, optional): Directory of the cache, defaults to `daft-io-cache` in the system temporary directory
+///     max_size_bytes (int, optional): Size above which the least recently used ranges are evicted, defaults to 10GiB
+///     metadata_ttl_secs (int, optional): How long the size and ETag of an object are trusted before they are requested again, defaults to 60
+///
+/// Example:
+///     >>> io_config = IOConfig(cache=CacheConfig(enabled=True, directory="/tmp/daft-cache"))
+///     >>> daft.read_parquet("s3://some-path", io_config=io_config)
+#[derive(Clone, Default)]
+#[pyclass]
+pub struct CacheConfig {
+    pub config: crate::CacheConfig,
+}
+
 #[pymethods]
 impl IOConfig {
     #[new]
     #[must_use]
-    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None))]
+    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None, cache=None))]
     pub fn new(
         s3: Option<S3Config>,
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        cache: Option<CacheConfig>,
     ) -> Self {
         Self {
             config: config::IOConfig {
@@ -172,18 +194,20 @@ impl IOConfig {
                 azure: azure.unwrap_or_default().config,
                 gcs: gcs.unwrap_or_default().config,
                 http: http.unwrap_or_default().config,
+                cache: cache.unwrap_or_default().config,
             },
         }
     }
 
     #[must_use]
-    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None))]
+    #[pyo3(signature = (s3=None, azure=None, gcs=None, http=None, cache=None))]
     pub fn replace(
         &self,
         s3: Option<S3Config>,
         azure: Option<AzureConfig>,
         gcs: Option<GCSConfig>,
         http: Option<HTTPConfig>,
+        cache: Option<CacheConfig>,
     ) -> Self {
         Self {
             config: config::IOConfig {
@@ -199,6 +223,9 @@ impl IOConfig {
                 http: http
                     .map(|http| http.config)
                     .unwrap_or_else(|| self.config.http.clone()),
+                cache: cache
+                    .map(|cache| cache.config)
+                    .unwrap_or_else(|| self.config.cache.clone()),
             },
         }
     }
@@ -239,6 +266,14 @@ impl IOConfig {
         })
     }
 
+    /// Configuration of the local disk cache for reads from remote object stores
+    #[getter]
+    pub fn cache(&self) -> PyResult<CacheConfig> {
+        Ok(CacheConfig {
+            config: self.config.cache.clone(),
+        })
+    }
+
     pub fn __hash__(&self) -> PyResult<u64> {
         use std::{collections::hash_map::DefaultHasher, hash::Hash};
 
@@ -1048,11 +1083,59 @@ impl HTTPConfig {
     }
 }
 
+#[pymethods]
+impl CacheConfig {
+    #[new]
+    #[must_use]
+    #[pyo3(signature = (enabled=None, directory=None, max_size_bytes=None, metadata_ttl_secs=None))]
+    pub fn new(
+        enabled: Option<bool>,
+        directory: Option<String>,
+        max_size_bytes: Option<u64>,
+        metadata_ttl_secs: Option<u64>,
+    ) -> Self {
+        let default = crate::CacheConfig::default();
+        Self {
+            config: crate::CacheConfig {
+                enabled: enabled.unwrap_or(default.enabled),
+                directory,
+                max_size_bytes: max_size_bytes.unwrap_or(default.max_size_bytes),
+                metadata_ttl_secs: metadata_ttl_secs.unwrap_or(default.metadata_ttl_secs),
+            },
+        }
+    }
+
+    pub fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("{}", self.config))
+    }
+
+    #[getter]
+    pub fn enabled(&self) -> PyResult<bool> {
+        Ok(self.config.enabled)
+    }
+
+    #[getter]
+    pub fn directory(&self) -> PyResult<Option<String>> {
+        Ok(self.config.directory.clone())
+    }
+
+    #[getter]
+    pub fn max_size_bytes(&self) -> PyResult<u64> {
+        Ok(self.config.max_size_bytes)
+    }
+
+    #[getter]
+    pub fn metadata_ttl_secs(&self) -> PyResult<u64> {
+        Ok(self.config.metadata_ttl_secs)
+    }
+}
+
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<AzureConfig>()?;
     parent.add_class::<GCSConfig>()?;
     parent.add_class::<S3Config>()?;
     parent.add_class::<HTTPConfig>()?;
+    parent.add_class::<CacheConfig>()?;
     parent.add_class::<S3Credentials>()?;
     parent.add_class::<IOConfig>()?;
     Ok(())
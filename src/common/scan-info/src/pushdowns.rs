@@ -15,11 +15,14 @@ pub struct Pushdowns {
     pub columns: Option<Arc<Vec<String>>>,
     /// Optional number of rows to read.
     pub limit: Option<usize>,
+    /// Optional ungrouped aggregations to compute over the source data, in which case each scan task yields a single
+    /// row of partial aggregation results instead of the data itself.
+    pub aggregations: Option<Arc<Vec<ExprRef>>>,
 }
 
 impl Default for Pushdowns {
     fn default() -> Self {
-        Self::new(None, None, None, None)
+        Self::new(None, None, None, None, None)
     }
 }
 
@@ -30,12 +33,14 @@ impl Pushdowns {
         partition_filters: Option<ExprRef>,
         columns: Option<Arc<Vec<String>>>,
         limit: Option<usize>,
+        aggregations: Option<Arc<Vec<ExprRef>>>,
     ) -> Self {
         Self {
             filters,
             partition_filters,
             columns,
             limit,
+            aggregations,
         }
     }
 
@@ -45,6 +50,7 @@ impl Pushdowns {
             && self.partition_filters.is_none()
             && self.columns.is_none()
             && self.limit.is_none()
+            && self.aggregations.is_none()
     }
 
     #[must_use]
@@ -54,6 +60,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit,
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -64,6 +71,7 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns: self.columns.clone(),
             limit: self.limit,
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -74,6 +82,7 @@ impl Pushdowns {
             partition_filters,
             columns: self.columns.clone(),
             limit: self.limit,
+            aggregations: self.aggregations.clone(),
         }
     }
 
@@ -84,6 +93,18 @@ impl Pushdowns {
             partition_filters: self.partition_filters.clone(),
             columns,
             limit: self.limit,
+            aggregations: self.aggregations.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_aggregations(&self, aggregations: Option<Arc<Vec<ExprRef>>>) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            partition_filters: self.partition_filters.clone(),
+            columns: self.columns.clone(),
+            limit: self.limit,
+            aggregations,
         }
     }
 
@@ -102,6 +123,16 @@ impl Pushdowns {
         if let Some(limit) = self.limit {
             res.push(format!("Limit pushdown = {limit}"));
         }
+        if let Some(aggregations) = &self.aggregations {
+            res.push(format!(
+                "Aggregation pushdown = [{}]",
+                aggregations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         res
     }
 
@@ -133,6 +164,16 @@ impl DisplayAs for Pushdowns {
                 if let Some(limit) = self.limit {
                     sub_items.push(format!("limit: {limit}"));
                 }
+                if let Some(aggregations) = &self.aggregations {
+                    sub_items.push(format!(
+                        "aggregation: [{}]",
+                        aggregations
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
                 s.push_str(&sub_items.join(", "));
                 s.push('}');
                 s
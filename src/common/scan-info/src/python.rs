@@ -127,6 +127,15 @@ pub mod pylib {
             self.0.columns.as_deref().cloned()
         }
 
+        #[getter]
+        #[must_use]
+        pub fn aggregations(&self) -> Option<Vec<PyExpr>> {
+            self.0
+                .aggregations
+                .as_ref()
+                .map(|aggs| aggs.iter().map(|e| PyExpr { expr: e.clone() }).collect())
+        }
+
         pub fn filter_required_column_names(&self) -> Option<Vec<String>> {
             self.0
                 .filters
@@ -30,6 +30,9 @@ pub trait ScanOperator: Send + Sync + Debug {
     fn can_absorb_filter(&self) -> bool;
     fn can_absorb_select(&self) -> bool;
     fn can_absorb_limit(&self) -> bool;
+    /// Whether scan tasks can compute the ungrouped count/min/max aggregations in [`Pushdowns::aggregations`]
+    /// themselves, yielding one row of partial results per task.
+    fn can_absorb_aggregation(&self) -> bool;
     fn multiline_display(&self) -> Vec<String>;
 
     /// If cfg provided, `to_scan_tasks` should apply the appropriate transformations
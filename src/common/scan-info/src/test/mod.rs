@@ -25,6 +25,7 @@ pub struct DummyScanOperator {
     pub schema: SchemaRef,
     pub num_scan_tasks: u32,
     pub num_rows_per_task: Option<usize>,
+    pub can_absorb_aggregation: bool,
 }
 
 #[typetag::serde]
@@ -127,6 +128,10 @@ impl ScanOperator for DummyScanOperator {
         false
     }
 
+    fn can_absorb_aggregation(&self) -> bool {
+        self.can_absorb_aggregation
+    }
+
     fn multiline_display(&self) -> Vec<String> {
         vec!["DummyScanOperator".to_string()]
     }
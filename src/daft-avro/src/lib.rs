@@ -0,0 +1,29 @@
+//! Reader for Avro object container files.
+//!
+//! Files can be read whole or in byte ranges: a block is read by the range in which the sync marker
+//! preceding it starts, so a set of ranges that tiles a file reads every block exactly once without
+//! having to know where the blocks are when the ranges are planned.
+mod read;
+mod schema;
+
+use common_error::DaftError;
+pub use read::{read_avro_bulk, stream_avro, AvroConvertOptions};
+pub use schema::read_avro_schema;
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::JoinError { source } => Self::External(Box::new(source)),
+        }
+    }
+}
+
+/// Length of the sync marker that ends the file header and every block.
+const SYNC_MARKER_LEN: usize = 16;
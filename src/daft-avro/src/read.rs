@@ -0,0 +1,575 @@
+use std::{io::Cursor, ops::Range, sync::Arc};
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::avro::{
+        avro_schema::{self, file::FileMetadata},
+        read::{infer_schema, Reader},
+    },
+};
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+    utils::arrow::cast_array_for_daft_if_needed,
+};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::futures::try_future::TryFutureExt;
+
+use crate::{
+    schema::{read_avro_header, AvroHeader},
+    JoinSnafu, SYNC_MARKER_LEN,
+};
+
+/// Maximum encoded length of an Avro long.
+const MAX_LONG_LEN: usize = 10;
+
+/// Minimum number of bytes requested at a time when walking the blocks of a byte range, so that
+/// small blocks don't each cost a request.
+const MIN_FETCH_SIZE: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, Default)]
+pub struct AvroConvertOptions {
+    pub limit: Option<usize>,
+    pub include_columns: Option<Vec<String>>,
+    pub predicate: Option<ExprRef>,
+}
+
+/// Streams the blocks of a single Avro file as record batches, with the projection, predicate and
+/// limit in `convert_options` applied.
+///
+/// If `byte_range` is given, only the blocks whose preceding sync marker starts within that range are read.
+pub async fn stream_avro(
+    uri: String,
+    byte_range: Option<Range<usize>>,
+    convert_options: AvroConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let (metadata, blocks) = fetch_avro(&uri, byte_range, io_client, io_stats).await?;
+    let reader = AvroRecordBatchReader::try_new(metadata, blocks, convert_options)?;
+    Ok(futures::stream::iter(reader).boxed())
+}
+
+pub fn read_avro_bulk(
+    uris: &[&str],
+    byte_ranges: Option<Vec<Option<Range<usize>>>>,
+    convert_options: AvroConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (uri, byte_range, convert_options, io_client, io_stats) = (
+                (*uri).to_string(),
+                byte_ranges
+                    .as_ref()
+                    .and_then(|byte_ranges| byte_ranges[i].clone()),
+                convert_options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_avro_single_into_table(uri, byte_range, convert_options, io_client, io_stats)
+                    .await
+            })
+            .context(JoinSnafu {})
+        }));
+        let mut remaining_rows = convert_options.limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_avro_single_into_table(
+    uri: String,
+    byte_range: Option<Range<usize>>,
+    convert_options: AvroConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<RecordBatch> {
+    let (metadata, blocks) = fetch_avro(&uri, byte_range, io_client, io_stats).await?;
+    let reader = AvroRecordBatchReader::try_new(metadata, blocks, convert_options)?;
+    let schema = reader.output_schema.clone();
+    let tables = reader.collect::<DaftResult<Vec<_>>>()?;
+    if tables.is_empty() {
+        RecordBatch::empty(Some(schema))
+    } else {
+        RecordBatch::concat(&tables)
+    }
+}
+
+/// Fetches the header of the Avro file at `uri` along with its data blocks, either all of them or only
+/// those belonging to `byte_range`. The returned cursor is positioned at the first block.
+async fn fetch_avro(
+    uri: &str,
+    byte_range: Option<Range<usize>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(FileMetadata, Cursor<Bytes>)> {
+    match byte_range {
+        None => {
+            let bytes = io_client
+                .single_url_get(uri.to_string(), None, io_stats)
+                .await?
+                .bytes()
+                .await?;
+            let mut cursor = Cursor::new(bytes);
+            let metadata = avro_schema::read::read_metadata(&mut cursor)
+                .map_err(arrow2::error::Error::from)?;
+            Ok((metadata, cursor))
+        }
+        Some(byte_range) => {
+            let header = read_avro_header(uri, io_client.clone(), io_stats.clone()).await?;
+            let blocks =
+                fetch_blocks_in_range(uri, byte_range, &header, io_client, io_stats).await?;
+            Ok((header.metadata, Cursor::new(blocks)))
+        }
+    }
+}
+
+/// Contents of a file fetched incrementally from a fixed offset onwards.
+struct FileBuffer {
+    uri: String,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    file_size: usize,
+    offset: usize,
+    bytes: Vec<u8>,
+}
+
+impl FileBuffer {
+    fn end(&self) -> usize {
+        self.offset + self.bytes.len()
+    }
+
+    /// Extends the buffer to cover the file up to `end`, or up to the end of the file if that comes first.
+    async fn fill_to(&mut self, end: usize) -> DaftResult<()> {
+        let end = end.min(self.file_size);
+        if end > self.end() {
+            let fetch_end = end.max(self.end() + MIN_FETCH_SIZE).min(self.file_size);
+            let bytes = self
+                .io_client
+                .single_url_get(
+                    self.uri.clone(),
+                    Some(self.end()..fetch_end),
+                    self.io_stats.clone(),
+                )
+                .await?
+                .bytes()
+                .await?;
+            self.bytes.extend_from_slice(&bytes);
+        }
+        Ok(())
+    }
+
+    /// Returns the buffered bytes in `range`, cut short if the range extends past the buffer.
+    fn slice(&self, range: Range<usize>) -> &[u8] {
+        let end = range.end.clamp(range.start, self.end());
+        &self.bytes[range.start - self.offset..end - self.offset]
+    }
+}
+
+/// Fetches the raw blocks of the Avro file at `uri` whose preceding sync marker starts within `byte_range`.
+///
+/// The first such marker is found by scanning the range, after which the blocks are walked using
+/// the sizes in their headers until a block's trailing marker falls past the end of the range.
+async fn fetch_blocks_in_range(
+    uri: &str,
+    byte_range: Range<usize>,
+    header: &AvroHeader,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Bytes> {
+    let marker = header.metadata.marker;
+    // The header ends with a sync marker, which precedes the first block.
+    let search_start = byte_range.start.max(header.len - SYNC_MARKER_LEN);
+    let search_end = byte_range.end.min(header.file_size);
+    if search_start >= search_end {
+        return Ok(Bytes::new());
+    }
+    let mut buffer = FileBuffer {
+        uri: uri.to_string(),
+        io_client,
+        io_stats,
+        file_size: header.file_size,
+        offset: search_start,
+        bytes: vec![],
+    };
+    buffer.fill_to(search_end + SYNC_MARKER_LEN - 1).await?;
+    let Some(marker_pos) = buffer
+        .slice(search_start..search_end + SYNC_MARKER_LEN - 1)
+        .windows(SYNC_MARKER_LEN)
+        .position(|window| window == marker)
+    else {
+        return Ok(Bytes::new());
+    };
+
+    let first_block = search_start + marker_pos + SYNC_MARKER_LEN;
+    let mut block_start = first_block;
+    while block_start < header.file_size {
+        let corrupt = || {
+            DaftError::ValueError(format!(
+                "Unable to read Avro file {uri}: corrupt block at offset {block_start}"
+            ))
+        };
+        buffer.fill_to(block_start + 2 * MAX_LONG_LEN).await?;
+        let block_header = buffer.slice(block_start..block_start + 2 * MAX_LONG_LEN);
+        let (_, rows_len) = decode_long(block_header).ok_or_else(corrupt)?;
+        let (data_len, size_len) = decode_long(&block_header[rows_len..]).ok_or_else(corrupt)?;
+        let data_len = usize::try_from(data_len).map_err(|_| corrupt())?;
+        let marker_start = block_start + rows_len + size_len + data_len;
+        buffer.fill_to(marker_start + SYNC_MARKER_LEN).await?;
+        if buffer.slice(marker_start..marker_start + SYNC_MARKER_LEN) != marker {
+            return Err(corrupt());
+        }
+        block_start = marker_start + SYNC_MARKER_LEN;
+        // The block following this marker belongs to the next range.
+        if marker_start >= byte_range.end {
+            break;
+        }
+    }
+    let offset = buffer.offset;
+    Ok(Bytes::from(buffer.bytes).slice(first_block - offset..block_start - offset))
+}
+
+/// Decodes a zigzag-encoded variable-length Avro long from the front of `bytes`, returning the value
+/// and the number of bytes it took up.
+fn decode_long(bytes: &[u8]) -> Option<(i64, usize)> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().take(MAX_LONG_LEN).enumerate() {
+        value |= u64::from(byte & 0x7F) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Some((((value >> 1) as i64) ^ -((value & 1) as i64), i + 1));
+        }
+    }
+    None
+}
+
+/// Decodes the blocks of an Avro file into [`RecordBatch`]es, one per block.
+///
+/// Only the columns needed for `include_columns` and the predicate are decoded.
+struct AvroRecordBatchReader {
+    // Not set when there are no blocks to read, e.g. for a byte range that holds no sync marker.
+    chunks: Option<Reader<Cursor<Bytes>>>,
+    // Schema of the decoded columns, in file order.
+    read_schema: SchemaRef,
+    // Schema of the emitted tables, after the predicate columns have been dropped again.
+    output_schema: SchemaRef,
+    include_columns: Option<Vec<String>>,
+    predicate: Option<ExprRef>,
+    remaining_rows: Option<usize>,
+}
+
+impl AvroRecordBatchReader {
+    fn try_new(
+        metadata: FileMetadata,
+        blocks: Cursor<Bytes>,
+        convert_options: AvroConvertOptions,
+    ) -> DaftResult<Self> {
+        let AvroConvertOptions {
+            limit,
+            include_columns,
+            predicate,
+        } = convert_options;
+        let arrow_schema = infer_schema(&metadata.record)?;
+
+        let column_index = |name: &str| {
+            arrow_schema
+                .fields
+                .iter()
+                .position(|field| field.name == name)
+        };
+        // Columns missing from this file are left out here and filled with nulls when the table is cast
+        // to the scan's schema.
+        let include_columns = include_columns.map(|include_columns| {
+            include_columns
+                .into_iter()
+                .filter(|name| column_index(name).is_some())
+                .collect::<Vec<_>>()
+        });
+        let projection = match &include_columns {
+            Some(include_columns) => {
+                let mut projection = vec![false; arrow_schema.fields.len()];
+                let mut required = include_columns.clone();
+                if let Some(predicate) = &predicate {
+                    required.extend(get_required_columns(predicate));
+                }
+                for name in &required {
+                    let index = column_index(name).ok_or_else(|| {
+                        DaftError::FieldNotFound(format!("Column {name} not found in Avro schema"))
+                    })?;
+                    projection[index] = true;
+                }
+                // Keep a single column around when nothing is selected so that the decoded chunks still
+                // carry their row counts.
+                if !projection.contains(&true) && !projection.is_empty() {
+                    projection[0] = true;
+                }
+                projection
+            }
+            None => vec![true; arrow_schema.fields.len()],
+        };
+        let read_schema = Arc::new(Schema::try_from(&ArrowSchema::from(
+            arrow_schema
+                .fields
+                .iter()
+                .zip(&projection)
+                .filter(|(_, selected)| **selected)
+                .map(|(field, _)| field.clone())
+                .collect::<Vec<_>>(),
+        ))?);
+        let output_schema = match &include_columns {
+            Some(include_columns) => Arc::new(Schema::new(
+                include_columns
+                    .iter()
+                    .map(|name| read_schema.get_field(name).cloned())
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )?),
+            None => read_schema.clone(),
+        };
+
+        // The compressed block readers fail on input that ends right away, rather than yielding nothing.
+        let has_blocks = blocks.position() < blocks.get_ref().len() as u64;
+        let chunks = has_blocks
+            .then(|| Reader::new(blocks, metadata, arrow_schema.fields, Some(projection)));
+        Ok(Self {
+            chunks,
+            read_schema,
+            output_schema,
+            include_columns,
+            predicate,
+            remaining_rows: limit,
+        })
+    }
+
+    fn chunk_to_table(&self, chunk: Chunk<Box<dyn Array>>) -> DaftResult<RecordBatch> {
+        let num_rows = chunk.len();
+        let columns = chunk
+            .into_arrays()
+            .into_iter()
+            .zip(self.read_schema.fields.values())
+            .map(|(array, field)| {
+                Series::try_from_field_and_arrow_array(
+                    Arc::new(field.clone()),
+                    cast_array_for_daft_if_needed(array),
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mut table = RecordBatch::new_with_size(self.read_schema.clone(), columns, num_rows)?;
+        if let Some(predicate) = &self.predicate {
+            table = table.filter(&[predicate.clone()])?;
+        }
+        if let Some(include_columns) = &self.include_columns {
+            table = table.get_columns(include_columns)?;
+        }
+        Ok(table)
+    }
+}
+
+impl Iterator for AvroRecordBatchReader {
+    type Item = DaftResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_rows == Some(0) {
+            return None;
+        }
+        let table = self
+            .chunks
+            .as_mut()?
+            .next()?
+            .map_err(DaftError::from)
+            .and_then(|chunk| self.chunk_to_table(chunk))
+            .and_then(|table| match self.remaining_rows {
+                Some(remaining) if table.len() > remaining => table.head(remaining),
+                _ => Ok(table),
+            });
+        if let (Ok(table), Some(remaining)) = (&table, &mut self.remaining_rows) {
+            *remaining -= table.len();
+        }
+        Some(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ops::Range, sync::Arc};
+
+    use arrow2::{
+        array::{Array, Int64Array, Utf8Array},
+        datatypes::{DataType, Field, Schema},
+        io::avro::{
+            avro_schema::{
+                file::{Block, CompressedBlock, Compression},
+                write::{compress, write_block, write_metadata},
+            },
+            write::{new_serializer, serialize, to_record},
+        },
+    };
+    use common_error::DaftResult;
+    use daft_core::prelude::AsArrow;
+    use daft_dsl::{lit, resolved_col};
+    use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
+
+    use super::{decode_long, read_avro_single_into_table, AvroConvertOptions};
+
+    const NUM_BLOCKS: i64 = 5;
+    const ROWS_PER_BLOCK: i64 = 4;
+
+    fn write_file(compression: Option<Compression>) -> tempfile::NamedTempFile {
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let record = to_record(&schema).unwrap();
+        let mut bytes = vec![];
+        write_metadata(&mut bytes, record.clone(), compression).unwrap();
+        for i in 0..NUM_BLOCKS {
+            let a = Int64Array::from_vec((i * ROWS_PER_BLOCK..(i + 1) * ROWS_PER_BLOCK).collect());
+            let b =
+                Utf8Array::<i32>::from_iter_values((0..ROWS_PER_BLOCK).map(|j| format!("{i}-{j}")));
+            let arrays: [&dyn Array; 2] = [&a, &b];
+            let mut serializers = arrays
+                .iter()
+                .zip(&record.fields)
+                .map(|(array, field)| new_serializer(*array, &field.schema))
+                .collect::<Vec<_>>();
+            let mut block = Block::new(ROWS_PER_BLOCK as usize, vec![]);
+            serialize(&mut serializers, &mut block);
+            let mut compressed = CompressedBlock::default();
+            compress(&mut block, &mut compressed, compression).unwrap();
+            write_block(&mut bytes, &compressed).unwrap();
+        }
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), bytes).unwrap();
+        file
+    }
+
+    async fn read(
+        file: &tempfile::NamedTempFile,
+        byte_range: Option<Range<usize>>,
+        convert_options: AvroConvertOptions,
+    ) -> DaftResult<RecordBatch> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let uri = file.path().to_str().unwrap().to_string();
+        read_avro_single_into_table(uri, byte_range, convert_options, io_client, None).await
+    }
+
+    fn column_a(table: &RecordBatch) -> DaftResult<Vec<i64>> {
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
+
+    #[test]
+    fn test_decode_long() {
+        assert_eq!(decode_long(&[0x00]), Some((0, 1)));
+        assert_eq!(decode_long(&[0x01]), Some((-1, 1)));
+        assert_eq!(decode_long(&[0x80, 0x01]), Some((64, 2)));
+        assert_eq!(decode_long(&[0x80]), None);
+    }
+
+    #[tokio::test]
+    async fn test_read_whole_file() -> DaftResult<()> {
+        for compression in [None, Some(Compression::Deflate), Some(Compression::Snappy)] {
+            let file = write_file(compression);
+            let table = read(&file, None, AvroConvertOptions::default()).await?;
+            assert_eq!(table.schema.names(), vec!["a".to_string(), "b".to_string()]);
+            assert_eq!(
+                column_a(&table)?,
+                (0..NUM_BLOCKS * ROWS_PER_BLOCK).collect::<Vec<_>>()
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_byte_ranges_read_every_block_once() -> DaftResult<()> {
+        for compression in [None, Some(Compression::Deflate)] {
+            let file = write_file(compression);
+            let file_size = std::fs::metadata(file.path())?.len() as usize;
+            for chunk_size in [1, 7, 30, 100, file_size] {
+                let mut values = vec![];
+                for start in (0..file_size).step_by(chunk_size) {
+                    let range = start..(start + chunk_size).min(file_size);
+                    let table = read(&file, Some(range), AvroConvertOptions::default()).await?;
+                    values.extend(column_a(&table)?);
+                }
+                assert_eq!(
+                    values,
+                    (0..NUM_BLOCKS * ROWS_PER_BLOCK).collect::<Vec<_>>(),
+                    "chunk size {chunk_size}"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_projection_predicate_and_limit() -> DaftResult<()> {
+        let file = write_file(None);
+        let table = read(
+            &file,
+            None,
+            AvroConvertOptions {
+                limit: Some(2),
+                include_columns: Some(vec!["b".to_string()]),
+                predicate: Some(resolved_col("a").gt_eq(lit(5i64))),
+            },
+        )
+        .await?;
+        assert_eq!(table.schema.names(), vec!["b".to_string()]);
+        let b = table.get_column("b")?.utf8()?.as_arrow().clone();
+        assert_eq!(b.values_iter().collect::<Vec<_>>(), vec!["1-1", "1-2"]);
+
+        let table = read(
+            &file,
+            None,
+            AvroConvertOptions {
+                include_columns: Some(vec![]),
+                ..Default::default()
+            },
+        )
+        .await?;
+        assert_eq!(table.num_columns(), 0);
+        assert_eq!(table.len(), (NUM_BLOCKS * ROWS_PER_BLOCK) as usize);
+        Ok(())
+    }
+}
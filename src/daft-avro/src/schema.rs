@@ -0,0 +1,66 @@
+use std::{io::Cursor, sync::Arc};
+
+use arrow2::io::avro::{avro_schema::file::FileMetadata, read::infer_schema};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::Schema;
+use daft_io::{IOClient, IOStatsRef};
+
+/// Number of bytes fetched up front when reading the file header, which covers the embedded schema
+/// of all but very wide records. The prefix is grown and refetched if the header turns out to be larger.
+const INITIAL_HEADER_FETCH_SIZE: usize = 64 * 1024;
+
+/// The decoded header of an Avro file.
+pub(crate) struct AvroHeader {
+    pub metadata: FileMetadata,
+    /// Offset of the first data block, just past the sync marker that ends the header.
+    pub len: usize,
+    pub file_size: usize,
+}
+
+/// Fetches and decodes the header of the Avro file at `uri`, without touching its data blocks.
+pub(crate) async fn read_avro_header(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<AvroHeader> {
+    let file_size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    let mut fetch_size = INITIAL_HEADER_FETCH_SIZE;
+    loop {
+        let prefix_len = file_size.min(fetch_size);
+        let prefix = io_client
+            .single_url_get(uri.to_string(), Some(0..prefix_len), io_stats.clone())
+            .await?
+            .bytes()
+            .await?;
+        let mut cursor = Cursor::new(&prefix);
+        match arrow2::io::avro::avro_schema::read::read_metadata(&mut cursor) {
+            Ok(metadata) => {
+                return Ok(AvroHeader {
+                    metadata,
+                    len: cursor.position() as usize,
+                    file_size,
+                })
+            }
+            // The header may simply be cut off by the end of the prefix, in which case we retry with more bytes.
+            Err(_) if prefix_len < file_size => fetch_size *= 4,
+            Err(err) => {
+                return Err(DaftError::ValueError(format!(
+                    "Unable to read Avro header from {uri}: {err}"
+                )))
+            }
+        }
+    }
+}
+
+/// Reads the schema of the Avro file at `uri` from its header.
+pub async fn read_avro_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let header = read_avro_header(uri, io_client, io_stats).await?;
+    let arrow_schema = infer_schema(&header.metadata.record)?;
+    Schema::try_from(&arrow_schema)
+}
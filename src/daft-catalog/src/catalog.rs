@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::{bindings::Bindings, error::Result, Identifier, Table};
+use crate::{bindings::Bindings, error::Result, Identifier, Table, TableSource};
 
 /// Catalog implementation reference.
 pub type CatalogRef = Arc<dyn Catalog>;
@@ -13,9 +13,27 @@ pub trait Catalog: Sync + Send + std::fmt::Debug {
     /// Returns the catalog name.
     fn name(&self) -> String;
 
+    /// Creates a namespace, err if it already exists.
+    fn create_namespace(&self, ident: &Identifier) -> Result<()>;
+
+    /// Drops the namespace, err if it does not exist.
+    fn drop_namespace(&self, ident: &Identifier) -> Result<()>;
+
+    /// Lists all namespaces matching the pattern, such as a namespace prefix.
+    fn list_namespaces(&self, pattern: Option<&str>) -> Result<Vec<Identifier>>;
+
+    /// Creates a table from the source, err if it already exists.
+    fn create_table(&self, ident: &Identifier, source: &TableSource) -> Result<Box<dyn Table>>;
+
+    /// Drops the table, err if it does not exist.
+    fn drop_table(&self, ident: &Identifier) -> Result<()>;
+
     /// Returns the given table if it exists.
     fn get_table(&self, ident: &Identifier) -> Result<Option<Box<dyn Table>>>;
 
+    /// Lists all tables matching the pattern, such as a namespace prefix.
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>>;
+
     /// Leverage dynamic dispatch to return the inner object for a PyCatalogImpl (generics?)
     #[cfg(feature = "python")]
     fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -24,3 +42,8 @@ pub trait Catalog: Sync + Send + std::fmt::Debug {
         )
     }
 }
+
+/// Returns true if the name matches the listing pattern, which is a prefix like in the python catalogs.
+pub(crate) fn matches_pattern(name: &str, pattern: Option<&str>) -> bool {
+    pattern.is_none_or(|pattern| name.starts_with(pattern))
+}
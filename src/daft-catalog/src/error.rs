@@ -31,6 +31,12 @@ pub enum Error {
     #[snafu(display("{message}"))]
     Unsupported { message: String },
 
+    #[snafu(display("Daft error: {}", source))]
+    DaftError { source: common_error::DaftError },
+
+    #[snafu(display("IO error: {}", source))]
+    IoError { source: std::io::Error },
+
     #[cfg(feature = "python")]
     #[snafu(display("Python error: {}", source))]
     PythonError { source: pyo3::PyErr },
@@ -69,6 +75,18 @@ impl Error {
     }
 }
 
+impl From<common_error::DaftError> for Error {
+    fn from(value: common_error::DaftError) -> Self {
+        Error::DaftError { source: value }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::IoError { source: value }
+    }
+}
+
 impl From<Error> for common_error::DaftError {
     fn from(err: Error) -> Self {
         common_error::DaftError::CatalogError(err.to_string())
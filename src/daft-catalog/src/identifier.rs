@@ -6,7 +6,7 @@ use crate::error::{Error, Result};
 pub type Qualifier = Vec<String>;
 
 /// A reference to a catalog object.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Identifier {
     pub qualifier: Qualifier,
     pub name: String,
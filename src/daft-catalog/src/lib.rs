@@ -1,11 +1,15 @@
 mod bindings;
 mod catalog;
 mod identifier;
+mod local;
+mod memory;
 mod table;
 
 pub use bindings::*;
 pub use catalog::*;
 pub use identifier::*;
+pub use local::*;
+pub use memory::*;
 pub use table::*;
 
 #[cfg(feature = "python")]
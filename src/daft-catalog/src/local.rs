@@ -0,0 +1,527 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use common_file_formats::FileFormat;
+use daft_core::prelude::{Schema, SchemaRef};
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
+use uuid::Uuid;
+
+use crate::{
+    catalog::matches_pattern,
+    error::{Error, Result},
+    table::parquet_scan,
+    Catalog, Identifier, Table, TableSource,
+};
+
+/// The file in a table's directory which holds the table schema, this also marks the directory as a table.
+const SCHEMA_FILE: &str = "_schema.json";
+
+/// A catalog backed by a local directory.
+///
+/// Namespaces are nested directories and each table is a directory of Parquet files along with
+/// its schema, so `ns.t` is stored as `<root>/ns/t/*.parquet`. Names starting with a dot are
+/// reserved for the staging directories of writes.
+#[derive(Debug, Clone)]
+pub struct LocalCatalog {
+    name: String,
+    root: PathBuf,
+}
+
+impl LocalCatalog {
+    /// Creates a catalog over the root directory, creating it if it does not exist.
+    pub fn new(name: impl Into<String>, root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        let root = root.canonicalize()?;
+        Ok(Self {
+            name: name.into(),
+            root,
+        })
+    }
+
+    pub fn arced(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+
+    /// Returns the directory of the namespace or table.
+    fn path_of(&self, ident: &Identifier) -> Result<PathBuf> {
+        self.resolve(ident, ident.qualifier.iter().chain([&ident.name]))
+    }
+
+    /// Returns the directory of the identifier's namespace, err if it does not exist.
+    fn namespace_path_of(&self, ident: &Identifier) -> Result<PathBuf> {
+        let path = self.resolve(ident, &ident.qualifier)?;
+        if !is_namespace(&path) {
+            let namespace = Identifier::from_path(ident.qualifier.clone())?;
+            return Err(Error::obj_not_found("Namespace", &namespace));
+        }
+        Ok(path)
+    }
+
+    /// Joins the components of the identifier to the root, err if a component is not a plain
+    /// directory name or the path leaves the root, e.g. through a symlink.
+    fn resolve<'a>(
+        &self,
+        ident: &Identifier,
+        components: impl IntoIterator<Item = &'a String>,
+    ) -> Result<PathBuf> {
+        let mut path = self.root.clone();
+        for component in components {
+            if component.is_empty() || component.starts_with('.') || component.contains(['/', '\\'])
+            {
+                return Err(Error::invalid_identifier(ident.to_string()));
+            }
+            path.push(component);
+        }
+        let existing = path
+            .ancestors()
+            .find(|ancestor| ancestor.exists())
+            .unwrap_or(&self.root);
+        if !existing.canonicalize()?.starts_with(&self.root) {
+            return Err(Error::invalid_identifier(ident.to_string()));
+        }
+        Ok(path)
+    }
+}
+
+impl Catalog for LocalCatalog {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn create_namespace(&self, ident: &Identifier) -> Result<()> {
+        self.namespace_path_of(ident)?;
+        let path = self.path_of(ident)?;
+        if path.exists() {
+            return Err(Error::obj_already_exists("Namespace", ident));
+        }
+        fs::create_dir(path)?;
+        Ok(())
+    }
+
+    fn drop_namespace(&self, ident: &Identifier) -> Result<()> {
+        let path = self.path_of(ident)?;
+        if !is_namespace(&path) {
+            return Err(Error::obj_not_found("Namespace", ident));
+        }
+        if fs::read_dir(&path)?.next().is_some() {
+            return Err(Error::unsupported(format!(
+                "Cannot drop namespace {ident} because it is not empty"
+            )));
+        }
+        fs::remove_dir(path)?;
+        Ok(())
+    }
+
+    fn list_namespaces(&self, pattern: Option<&str>) -> Result<Vec<Identifier>> {
+        let mut namespaces = vec![];
+        walk(&self.root, &[], &mut |ident, is_table| {
+            if !is_table && matches_pattern(&ident.to_string(), pattern) {
+                namespaces.push(ident);
+            }
+        })?;
+        namespaces.sort_by_key(|namespace| namespace.to_string());
+        Ok(namespaces)
+    }
+
+    fn create_table(&self, ident: &Identifier, source: &TableSource) -> Result<Box<dyn Table>> {
+        self.namespace_path_of(ident)?;
+        let path = self.path_of(ident)?;
+        if path.exists() {
+            return Err(Error::obj_already_exists("Table", ident));
+        }
+        let schema = match source {
+            TableSource::Schema(schema) => schema.clone(),
+            TableSource::View(plan) => plan.schema(),
+        };
+        fs::create_dir(&path)?;
+        let table = LocalTable {
+            path,
+            schema: schema.clone(),
+        };
+        // write the schema last so that a partially created table is never listed
+        let created = match source {
+            TableSource::Schema(_) => Ok(()),
+            TableSource::View(plan) => table.append(plan.clone()),
+        }
+        .and_then(|_| {
+            let schema = serde_json::to_vec(schema.as_ref()).map_err(std::io::Error::from)?;
+            Ok(fs::write(table.path.join(SCHEMA_FILE), schema)?)
+        });
+        if let Err(err) = created {
+            fs::remove_dir_all(&table.path)?;
+            return Err(err);
+        }
+        Ok(Box::new(table))
+    }
+
+    fn drop_table(&self, ident: &Identifier) -> Result<()> {
+        let path = self.path_of(ident)?;
+        if !is_table(&path) {
+            return Err(Error::obj_not_found("Table", ident));
+        }
+        fs::remove_dir_all(path)?;
+        Ok(())
+    }
+
+    fn get_table(&self, ident: &Identifier) -> Result<Option<Box<dyn Table>>> {
+        let path = self.path_of(ident)?;
+        if !is_table(&path) {
+            return Ok(None);
+        }
+        let schema = fs::read(path.join(SCHEMA_FILE))?;
+        let schema: Schema = serde_json::from_slice(&schema).map_err(std::io::Error::from)?;
+        Ok(Some(Box::new(LocalTable {
+            path,
+            schema: Arc::new(schema),
+        })))
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        let mut tables = vec![];
+        walk(&self.root, &[], &mut |ident, is_table| {
+            let ident = ident.to_string();
+            if is_table && matches_pattern(&ident, pattern) {
+                tables.push(ident);
+            }
+        })?;
+        tables.sort();
+        Ok(tables)
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{types::PyAnyMethods, IntoPyObject};
+
+        use crate::python::PyCatalog;
+        PyCatalog::from(self.clone().arced() as crate::CatalogRef)
+            .into_pyobject(py)?
+            .extract()
+    }
+}
+
+/// Walks the namespace directories below the root, calling `visit` for each namespace and table.
+fn walk(dir: &Path, qualifier: &[String], visit: &mut impl FnMut(Identifier, bool)) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let ident = Identifier::new(qualifier.to_vec(), name);
+        if is_table(&path) {
+            visit(ident, true);
+        } else {
+            let mut qualifier = qualifier.to_vec();
+            qualifier.push(ident.name.clone());
+            visit(ident, false);
+            walk(&path, &qualifier, visit)?;
+        }
+    }
+    Ok(())
+}
+
+fn is_table(path: &Path) -> bool {
+    path.join(SCHEMA_FILE).is_file()
+}
+
+fn is_namespace(path: &Path) -> bool {
+    path.is_dir() && !is_table(path)
+}
+
+/// A table of a [`LocalCatalog`], which is a directory of Parquet files.
+#[derive(Debug, Clone)]
+pub struct LocalTable {
+    path: PathBuf,
+    schema: SchemaRef,
+}
+
+impl LocalTable {
+    /// Returns the paths of the table's data files.
+    fn data_files(&self) -> Result<Vec<String>> {
+        let mut files = vec![];
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "parquet") {
+                files.push(path.to_string_lossy().to_string());
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Writes the output of the plan as Parquet files to the directory.
+    fn write(&self, plan: LogicalPlanRef, dir: &Path) -> Result<()> {
+        if plan.schema() != self.schema {
+            return Err(Error::unsupported(format!(
+                "Cannot write data with schema {} to table with schema {}",
+                plan.schema().short_string(),
+                self.schema.short_string()
+            )));
+        }
+        let root_dir = dir.to_string_lossy();
+        let write = LogicalPlanBuilder::from(plan).table_write(
+            &root_dir,
+            FileFormat::Parquet,
+            None,
+            None,
+            None,
+        )?;
+        execute(write)
+    }
+
+    /// Adds the files that `write` writes to a staging directory to the table, so that a failed
+    /// write leaves the table unchanged.
+    fn append_with(&self, write: impl FnOnce(&Path) -> Result<()>) -> Result<()> {
+        // the staging directory is within the table, where it isn't read as a data file.
+        let staging = self.path.join(format!(".append-{}", Uuid::new_v4()));
+        fs::create_dir(&staging)?;
+        let appended = write(&staging).and_then(|_| {
+            for entry in fs::read_dir(&staging)? {
+                let path = entry?.path();
+                fs::rename(&path, self.path.join(path.file_name().unwrap()))?;
+            }
+            Ok(())
+        });
+        fs::remove_dir_all(&staging)?;
+        appended
+    }
+
+    /// Replaces the table's files with the files that `write` writes to a staging directory, which
+    /// is renamed to the table's directory once complete, so that a failed write leaves the table
+    /// unchanged.
+    fn overwrite_with(&self, write: impl FnOnce(&Path) -> Result<()>) -> Result<()> {
+        let parent = self.path.parent().unwrap();
+        let name = self.path.file_name().unwrap().to_string_lossy();
+        let id = Uuid::new_v4();
+        let staging = parent.join(format!(".{name}.overwrite-{id}"));
+        fs::create_dir(&staging)?;
+        let written = write(&staging).and_then(|_| {
+            fs::copy(self.path.join(SCHEMA_FILE), staging.join(SCHEMA_FILE))?;
+            Ok(())
+        });
+        if let Err(err) = written {
+            fs::remove_dir_all(&staging)?;
+            return Err(err);
+        }
+        // a directory can't be renamed over a non-empty one, so the previous one is moved aside.
+        let previous = parent.join(format!(".{name}.previous-{id}"));
+        if let Err(err) = fs::rename(&self.path, &previous) {
+            fs::remove_dir_all(&staging)?;
+            return Err(err.into());
+        }
+        if let Err(err) = fs::rename(&staging, &self.path) {
+            fs::rename(&previous, &self.path)?;
+            fs::remove_dir_all(&staging)?;
+            return Err(err.into());
+        }
+        fs::remove_dir_all(previous)?;
+        Ok(())
+    }
+}
+
+impl Table for LocalTable {
+    fn get_schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn get_logical_plan(&self) -> Result<LogicalPlanRef> {
+        parquet_scan(self.data_files()?, self.schema.clone())
+    }
+
+    fn append(&self, plan: LogicalPlanRef) -> Result<()> {
+        self.append_with(|dir| self.write(plan, dir))
+    }
+
+    fn overwrite(&self, plan: LogicalPlanRef) -> Result<()> {
+        self.overwrite_with(|dir| self.write(plan, dir))
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{types::PyAnyMethods, IntoPyObject};
+
+        use crate::python::PyTable;
+        PyTable::new(Arc::new(self.clone()))
+            .into_pyobject(py)?
+            .extract()
+    }
+}
+
+/// Executes the plan with the context's runner.
+#[cfg(feature = "python")]
+fn execute(plan: LogicalPlanBuilder) -> Result<()> {
+    let runner = daft_context::get_context().get_or_create_runner()?;
+    pyo3::Python::with_gil(|py| {
+        for result in runner.run_iter_tables(py, plan, None)? {
+            result?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(not(feature = "python"))]
+fn execute(_: LogicalPlanBuilder) -> Result<()> {
+    Err(Error::unsupported(
+        "Writing to a local catalog table requires a runner, which needs the python feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+
+    use super::*;
+
+    fn mock_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new("id", DataType::Int64)]).unwrap())
+    }
+
+    #[test]
+    fn test_namespaces() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let catalog = LocalCatalog::new("local", dir.path())?;
+        let ns = Identifier::simple("ns");
+        catalog.create_namespace(&ns)?;
+        catalog.create_namespace(&Identifier::from_path(["ns", "inner"])?)?;
+        assert!(catalog.create_namespace(&ns).is_err());
+        // parent namespaces must exist
+        assert!(catalog
+            .create_namespace(&Identifier::from_path(["missing", "inner"])?)
+            .is_err());
+        assert_eq!(
+            catalog.list_namespaces(None)?,
+            vec![ns.clone(), Identifier::from_path(["ns", "inner"])?]
+        );
+        assert!(catalog.drop_namespace(&ns).is_err());
+        catalog.drop_namespace(&Identifier::from_path(["ns", "inner"])?)?;
+        catalog.drop_namespace(&ns)?;
+        assert!(catalog.list_namespaces(None)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tables() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let catalog = LocalCatalog::new("local", dir.path())?;
+        catalog.create_namespace(&"ns".into())?;
+        let ident = Identifier::from_path(["ns", "t"])?;
+        catalog.create_table(&ident, &TableSource::Schema(mock_schema()))?;
+        catalog.create_table(&"u".into(), &TableSource::Schema(mock_schema()))?;
+        assert!(catalog
+            .create_table(&ident, &TableSource::Schema(mock_schema()))
+            .is_err());
+        assert_eq!(catalog.list_tables(None)?, vec!["ns.t", "u"]);
+        assert_eq!(catalog.list_tables(Some("ns"))?, vec!["ns.t"]);
+        // tables are not namespaces
+        assert_eq!(
+            catalog.list_namespaces(None)?,
+            vec![Identifier::simple("ns")]
+        );
+
+        // tables are reloaded from disk
+        let catalog = LocalCatalog::new("local", dir.path())?;
+        let table = catalog.get_table(&ident)?.unwrap();
+        assert_eq!(table.get_schema(), mock_schema());
+        assert_eq!(table.get_logical_plan()?.schema(), mock_schema());
+
+        catalog.drop_table(&ident)?;
+        assert!(catalog.get_table(&ident)?.is_none());
+        assert!(catalog.drop_table(&ident).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_identifiers() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let catalog = LocalCatalog::new("local", dir.path().join("root"))?;
+        let schema = TableSource::Schema(mock_schema());
+        for name in ["", ".", "..", ".hidden", "a/b", "a\\b"] {
+            assert!(catalog.create_namespace(&Identifier::simple(name)).is_err());
+            assert!(catalog
+                .create_table(&Identifier::simple(name), &schema)
+                .is_err());
+            let qualified = Identifier::new(vec![name.to_string()], "t".to_string());
+            assert!(catalog.create_table(&qualified, &schema).is_err());
+        }
+        // paths can't leave the root through a symlink either
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(dir.path(), dir.path().join("root").join("link"))?;
+            let escaped = Identifier::from_path(["link", "t"])?;
+            assert!(catalog.create_table(&escaped, &schema).is_err());
+            assert!(!dir.path().join("t").exists());
+        }
+        assert!(catalog.list_tables(None)?.is_empty());
+        Ok(())
+    }
+
+    /// Returns the file names of the table's data files.
+    fn data_file_names(table: &LocalTable) -> Result<Vec<String>> {
+        Ok(table
+            .data_files()?
+            .iter()
+            .map(|file| {
+                Path::new(file)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect())
+    }
+
+    /// Returns a writer of empty files with the given names.
+    fn write_files(names: &'static [&'static str]) -> impl FnOnce(&Path) -> Result<()> {
+        move |dir| {
+            for name in names {
+                fs::write(dir.join(name), b"")?;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_append_and_overwrite() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let catalog = LocalCatalog::new("local", dir.path())?;
+        let ident = Identifier::simple("t");
+        catalog.create_table(&ident, &TableSource::Schema(mock_schema()))?;
+        let table = LocalTable {
+            path: catalog.path_of(&ident)?,
+            schema: mock_schema(),
+        };
+
+        table.append_with(write_files(&["a.parquet"]))?;
+        table.append_with(write_files(&["b.parquet"]))?;
+        assert_eq!(data_file_names(&table)?, vec!["a.parquet", "b.parquet"]);
+
+        // failed writes leave the table unchanged
+        let failed = |dir: &Path| {
+            write_files(&["c.parquet"])(dir)?;
+            Err(Error::unsupported("failed write"))
+        };
+        assert!(table.append_with(failed).is_err());
+        assert!(table.overwrite_with(failed).is_err());
+        assert_eq!(data_file_names(&table)?, vec!["a.parquet", "b.parquet"]);
+
+        table.overwrite_with(write_files(&["c.parquet"]))?;
+        assert_eq!(data_file_names(&table)?, vec!["c.parquet"]);
+        assert_eq!(
+            catalog.get_table(&ident)?.unwrap().get_schema(),
+            mock_schema()
+        );
+
+        // staging directories are removed once the writes complete
+        assert_eq!(fs::read_dir(dir.path())?.count(), 1);
+        assert_eq!(fs::read_dir(&table.path)?.count(), 2);
+        assert_eq!(catalog.list_tables(None)?, vec!["t"]);
+        Ok(())
+    }
+}
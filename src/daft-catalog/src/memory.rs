@@ -0,0 +1,289 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+};
+
+use daft_core::prelude::SchemaRef;
+use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
+
+use crate::{
+    catalog::matches_pattern,
+    error::{Error, Result},
+    table::parquet_scan,
+    Catalog, Identifier, Table, TableSource,
+};
+
+/// An in-memory catalog whose tables hold logical plans rather than data.
+#[derive(Debug, Clone)]
+pub struct MemoryCatalog {
+    name: String,
+    state: Arc<RwLock<MemoryCatalogState>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryCatalogState {
+    namespaces: HashSet<Identifier>,
+    tables: HashMap<Identifier, MemoryTable>,
+}
+
+impl MemoryCatalog {
+    /// Creates an empty in-memory catalog.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            state: Arc::default(),
+        }
+    }
+
+    pub fn arced(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+}
+
+impl Catalog for MemoryCatalog {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn create_namespace(&self, ident: &Identifier) -> Result<()> {
+        let mut state = self.state.write().unwrap();
+        if !state.namespaces.insert(ident.clone()) {
+            return Err(Error::obj_already_exists("Namespace", ident));
+        }
+        Ok(())
+    }
+
+    fn drop_namespace(&self, ident: &Identifier) -> Result<()> {
+        let mut state = self.state.write().unwrap();
+        if !state.namespaces.contains(ident) {
+            return Err(Error::obj_not_found("Namespace", ident));
+        }
+        if state
+            .tables
+            .keys()
+            .any(|table| namespace_of(table).as_ref() == Some(ident))
+        {
+            return Err(Error::unsupported(format!(
+                "Cannot drop namespace {ident} because it is not empty"
+            )));
+        }
+        state.namespaces.remove(ident);
+        Ok(())
+    }
+
+    fn list_namespaces(&self, pattern: Option<&str>) -> Result<Vec<Identifier>> {
+        let state = self.state.read().unwrap();
+        let mut namespaces: Vec<Identifier> = state
+            .namespaces
+            .iter()
+            .filter(|namespace| matches_pattern(&namespace.to_string(), pattern))
+            .cloned()
+            .collect();
+        namespaces.sort_by_key(|namespace| namespace.to_string());
+        Ok(namespaces)
+    }
+
+    fn create_table(&self, ident: &Identifier, source: &TableSource) -> Result<Box<dyn Table>> {
+        let mut state = self.state.write().unwrap();
+        if let Some(namespace) = namespace_of(ident) {
+            if !state.namespaces.contains(&namespace) {
+                return Err(Error::obj_not_found("Namespace", &namespace));
+            }
+        }
+        if state.tables.contains_key(ident) {
+            return Err(Error::obj_already_exists("Table", ident));
+        }
+        let plan = match source {
+            TableSource::Schema(schema) => parquet_scan(vec![], schema.clone())?,
+            TableSource::View(plan) => plan.clone(),
+        };
+        let table = MemoryTable::new(plan);
+        state.tables.insert(ident.clone(), table.clone());
+        Ok(Box::new(table))
+    }
+
+    fn drop_table(&self, ident: &Identifier) -> Result<()> {
+        let mut state = self.state.write().unwrap();
+        if state.tables.remove(ident).is_none() {
+            return Err(Error::obj_not_found("Table", ident));
+        }
+        Ok(())
+    }
+
+    fn get_table(&self, ident: &Identifier) -> Result<Option<Box<dyn Table>>> {
+        let state = self.state.read().unwrap();
+        Ok(state
+            .tables
+            .get(ident)
+            .map(|table| Box::new(table.clone()) as Box<dyn Table>))
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        let state = self.state.read().unwrap();
+        let mut tables: Vec<String> = state
+            .tables
+            .keys()
+            .map(|table| table.to_string())
+            .filter(|table| matches_pattern(table, pattern))
+            .collect();
+        tables.sort();
+        Ok(tables)
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{types::PyAnyMethods, IntoPyObject};
+
+        use crate::python::PyCatalog;
+        PyCatalog::from(self.clone().arced() as crate::CatalogRef)
+            .into_pyobject(py)?
+            .extract()
+    }
+}
+
+/// Returns the namespace of a qualified table identifier.
+fn namespace_of(ident: &Identifier) -> Option<Identifier> {
+    Identifier::from_path(ident.qualifier.clone()).ok()
+}
+
+/// A table of a [`MemoryCatalog`], writes replace its plan so they are only executed on read.
+#[derive(Debug, Clone)]
+pub struct MemoryTable {
+    plan: Arc<RwLock<LogicalPlanRef>>,
+}
+
+impl MemoryTable {
+    fn new(plan: LogicalPlanRef) -> Self {
+        Self {
+            plan: Arc::new(RwLock::new(plan)),
+        }
+    }
+}
+
+impl Table for MemoryTable {
+    fn get_schema(&self) -> SchemaRef {
+        self.plan.read().unwrap().schema()
+    }
+
+    fn get_logical_plan(&self) -> Result<LogicalPlanRef> {
+        Ok(self.plan.read().unwrap().clone())
+    }
+
+    fn append(&self, plan: LogicalPlanRef) -> Result<()> {
+        let mut curr = self.plan.write().unwrap();
+        let appended = LogicalPlanBuilder::from(curr.clone()).concat(&plan.into())?;
+        *curr = appended.build();
+        Ok(())
+    }
+
+    fn overwrite(&self, plan: LogicalPlanRef) -> Result<()> {
+        if plan.schema() != self.get_schema() {
+            return Err(Error::unsupported(format!(
+                "Cannot overwrite table with schema {} using schema {}",
+                self.get_schema().short_string(),
+                plan.schema().short_string()
+            )));
+        }
+        *self.plan.write().unwrap() = plan;
+        Ok(())
+    }
+
+    #[cfg(feature = "python")]
+    fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
+        use pyo3::{types::PyAnyMethods, IntoPyObject};
+
+        use crate::python::PyTable;
+        PyTable::new(Arc::new(self.clone()))
+            .into_pyobject(py)?
+            .extract()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_logical_plan::{
+        ops::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan, SourceInfo,
+    };
+
+    use super::*;
+
+    fn mock_plan() -> LogicalPlanRef {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64)]).unwrap());
+        LogicalPlan::Source(Source::new(
+            schema.clone(),
+            Arc::new(SourceInfo::PlaceHolder(PlaceHolderInfo {
+                source_schema: schema,
+                clustering_spec: Arc::new(ClusteringSpec::unknown()),
+            })),
+        ))
+        .arced()
+    }
+
+    #[test]
+    fn test_namespaces() -> Result<()> {
+        let catalog = MemoryCatalog::new("memory");
+        let ns = Identifier::simple("ns");
+        catalog.create_namespace(&ns)?;
+        catalog.create_namespace(&Identifier::from_path(["ns", "inner"])?)?;
+        assert!(catalog.create_namespace(&ns).is_err());
+        assert_eq!(catalog.list_namespaces(None)?.len(), 2);
+        assert_eq!(catalog.list_namespaces(Some("ns.i"))?.len(), 1);
+
+        let table = Identifier::from_path(["ns", "t"])?;
+        catalog.create_table(&table, &TableSource::View(mock_plan()))?;
+        // namespaces with tables can't be dropped
+        assert!(catalog.drop_namespace(&ns).is_err());
+        catalog.drop_table(&table)?;
+        catalog.drop_namespace(&ns)?;
+        assert!(catalog.drop_namespace(&ns).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tables() -> Result<()> {
+        let catalog = MemoryCatalog::new("memory");
+        catalog.create_table(&"t1".into(), &TableSource::View(mock_plan()))?;
+        catalog.create_table(&"t2".into(), &TableSource::Schema(mock_plan().schema()))?;
+        assert!(catalog
+            .create_table(&"t1".into(), &TableSource::View(mock_plan()))
+            .is_err());
+        // tables can't be created in missing namespaces
+        assert!(catalog
+            .create_table(
+                &Identifier::from_path(["ns", "t"])?,
+                &TableSource::View(mock_plan())
+            )
+            .is_err());
+        assert_eq!(catalog.list_tables(None)?, vec!["t1", "t2"]);
+        assert_eq!(catalog.list_tables(Some("t2"))?, vec!["t2"]);
+
+        catalog.drop_table(&"t1".into())?;
+        assert!(catalog.get_table(&"t1".into())?.is_none());
+        assert!(catalog.drop_table(&"t1".into()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_and_overwrite() -> Result<()> {
+        let catalog = MemoryCatalog::new("memory");
+        let ident = Identifier::simple("t");
+        catalog.create_table(&ident, &TableSource::View(mock_plan()))?;
+
+        let table = catalog.get_table(&ident)?.unwrap();
+        table.append(mock_plan())?;
+        // writes are visible through every handle of the table
+        let plan = catalog.get_table(&ident)?.unwrap().get_logical_plan()?;
+        assert!(matches!(plan.as_ref(), LogicalPlan::Concat(_)));
+
+        table.overwrite(mock_plan())?;
+        let plan = catalog.get_table(&ident)?.unwrap().get_logical_plan()?;
+        assert!(matches!(plan.as_ref(), LogicalPlan::Source(_)));
+
+        let other = Arc::new(Schema::new(vec![Field::new("other", DataType::Utf8)]).unwrap());
+        let other = parquet_scan(vec![], other)?;
+        assert!(table.append(other.clone()).is_err());
+        assert!(table.overwrite(other).is_err());
+        Ok(())
+    }
+}
@@ -64,6 +64,88 @@ impl Catalog for PyCatalogWrapper {
         })
     }
 
+    fn create_namespace(&self, ident: &Identifier) -> Result<()> {
+        Python::with_gil(|py| {
+            // catalog.create_namespace(ident)
+            self.0
+                .bind(py)
+                .call_method1(intern!(py, "create_namespace"), (ident.to_string(),))?;
+            Ok(())
+        })
+    }
+
+    fn drop_namespace(&self, ident: &Identifier) -> Result<()> {
+        Python::with_gil(|py| {
+            // catalog.drop_namespace(ident)
+            self.0
+                .bind(py)
+                .call_method1(intern!(py, "drop_namespace"), (ident.to_string(),))?;
+            Ok(())
+        })
+    }
+
+    fn list_namespaces(&self, pattern: Option<&str>) -> Result<Vec<Identifier>> {
+        Python::with_gil(|py| {
+            // namespaces = catalog.list_namespaces(pattern)
+            let namespaces = self
+                .0
+                .bind(py)
+                .call_method1(intern!(py, "list_namespaces"), (pattern,))?;
+            // each namespace is a `daft.catalog.Identifier` which holds a PyIdentifier
+            namespaces
+                .try_iter()?
+                .map(|namespace| {
+                    let namespace = namespace?.getattr(intern!(py, "_ident"))?;
+                    Ok(namespace.extract::<PyIdentifier>()?.0)
+                })
+                .collect()
+        })
+    }
+
+    fn create_table(&self, ident: &Identifier, source: &TableSource) -> Result<Box<dyn Table>> {
+        Python::with_gil(|py| {
+            // catalog = 'python catalog object'
+            let catalog = self.0.bind(py);
+            // source = DataFrame | Schema
+            let source = match source {
+                TableSource::Schema(schema) => py
+                    .import(intern!(py, "daft.logical.schema"))?
+                    .getattr(intern!(py, "Schema"))?
+                    .call_method1(
+                        intern!(py, "_from_pyschema"),
+                        (PySchema::from(schema.clone()),),
+                    )?,
+                TableSource::View(plan) => to_py_dataframe(py, plan.clone())?,
+            };
+            // table = catalog.create_table(ident, source)
+            let table =
+                catalog.call_method1(intern!(py, "create_table"), (ident.to_string(), source))?;
+            let table = PyTableWrapper::from(table.unbind());
+            Ok(Box::new(table) as Box<dyn Table>)
+        })
+    }
+
+    fn drop_table(&self, ident: &Identifier) -> Result<()> {
+        Python::with_gil(|py| {
+            // catalog.drop_table(ident)
+            self.0
+                .bind(py)
+                .call_method1(intern!(py, "drop_table"), (ident.to_string(),))?;
+            Ok(())
+        })
+    }
+
+    fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
+        Python::with_gil(|py| {
+            // tables = catalog.list_tables(pattern)
+            let tables = self
+                .0
+                .bind(py)
+                .call_method1(intern!(py, "list_tables"), (pattern,))?;
+            Ok(tables.extract()?)
+        })
+    }
+
     fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
         self.0.extract(py)
     }
@@ -154,22 +236,25 @@ impl PyTable {
     fn read(&self, py: Python<'_>) -> PyResult<PyObject> {
         // builder = 'compiled plan'
         let builder = self.0.get_logical_plan()?;
-        let builder = PyLogicalPlanBuilder::new(builder.into());
-        // builder = LogicalPlanBuilder.__init__(builder)
-        let builder = py
-            .import(intern!(py, "daft.logical.builder"))?
-            .getattr(intern!(py, "LogicalPlanBuilder"))?
-            .call1((builder,))?;
-        // df = DataFrame.__init__(builder)
-        let df = py
-            .import(intern!(py, "daft.dataframe"))?
-            .getattr(intern!(py, "DataFrame"))?
-            .call1((builder,))?;
         // df as object
-        df.extract()
+        to_py_dataframe(py, builder)?.extract()
     }
 }
 
+/// Creates a python DataFrame for the logical plan.
+fn to_py_dataframe(py: Python<'_>, plan: LogicalPlanRef) -> PyResult<Bound<'_, PyAny>> {
+    let builder = PyLogicalPlanBuilder::new(plan.into());
+    // builder = LogicalPlanBuilder.__init__(builder)
+    let builder = py
+        .import(intern!(py, "daft.logical.builder"))?
+        .getattr(intern!(py, "LogicalPlanBuilder"))?
+        .call1((builder,))?;
+    // df = DataFrame.__init__(builder)
+    py.import(intern!(py, "daft.dataframe"))?
+        .getattr(intern!(py, "DataFrame"))?
+        .call1((builder,))
+}
+
 /// PyTableWrapper wraps a `daft.catalog.Table` implementation (py->rust).
 #[derive(Debug)]
 pub struct PyTableWrapper(PyObject);
@@ -208,6 +293,26 @@ impl Table for PyTableWrapper {
         })
     }
 
+    fn append(&self, plan: LogicalPlanRef) -> Result<()> {
+        Python::with_gil(|py| {
+            // table.append(df)
+            let df = to_py_dataframe(py, plan)?;
+            self.0.bind(py).call_method1(intern!(py, "append"), (df,))?;
+            Ok(())
+        })
+    }
+
+    fn overwrite(&self, plan: LogicalPlanRef) -> Result<()> {
+        Python::with_gil(|py| {
+            // table.overwrite(df)
+            let df = to_py_dataframe(py, plan)?;
+            self.0
+                .bind(py)
+                .call_method1(intern!(py, "overwrite"), (df,))?;
+            Ok(())
+        })
+    }
+
     fn to_py(&self, py: Python<'_>) -> PyResult<PyObject> {
         self.0.extract(py)
     }
@@ -1,9 +1,15 @@
 use std::sync::Arc;
 
+use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
+use common_scan_info::ScanOperatorRef;
 use daft_core::prelude::SchemaRef;
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
+use daft_scan::{storage_config::StorageConfig, AnonymousScanOperator};
 
-use crate::{bindings::Bindings, error::Result};
+use crate::{
+    bindings::Bindings,
+    error::{Error, Result},
+};
 
 /// Table implementation reference.
 pub type TableRef = Arc<dyn Table>;
@@ -46,6 +52,17 @@ pub trait Table: Sync + Send + std::fmt::Debug {
     /// Returns a logical plan for this table.
     fn get_logical_plan(&self) -> Result<LogicalPlanRef>;
 
+    /// Appends the output of the plan to this table.
+    fn append(&self, plan: LogicalPlanRef) -> Result<()>;
+
+    /// Replaces the contents of this table with the output of the plan.
+    fn overwrite(&self, plan: LogicalPlanRef) -> Result<()>;
+
+    /// Returns true iff this table is a view, i.e. an immutable table over a logical plan.
+    fn is_view(&self) -> bool {
+        false
+    }
+
     /// Leverage dynamic dispatch to return the inner object for a PyTableImpl (generics?)
     #[cfg(feature = "python")]
     fn to_py(&self, _: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -86,6 +103,22 @@ impl Table for View {
         Ok(self.0.clone())
     }
 
+    fn append(&self, _: LogicalPlanRef) -> Result<()> {
+        Err(Error::unsupported(
+            "Cannot append to a view, views are immutable",
+        ))
+    }
+
+    fn overwrite(&self, _: LogicalPlanRef) -> Result<()> {
+        Err(Error::unsupported(
+            "Cannot overwrite a view, views are immutable",
+        ))
+    }
+
+    fn is_view(&self) -> bool {
+        true
+    }
+
     /// This is a little ugly .. it creates a PyObject which implements the daft.catalog.Table ABC
     #[cfg(feature = "python")]
     fn to_py(&self, py: pyo3::Python<'_>) -> pyo3::PyResult<pyo3::PyObject> {
@@ -97,3 +130,15 @@ impl Table for View {
             .extract()
     }
 }
+
+/// Returns a plan which scans the parquet files with the given schema, which is empty without files.
+pub(crate) fn parquet_scan(files: Vec<String>, schema: SchemaRef) -> Result<LogicalPlanRef> {
+    let operator = AnonymousScanOperator::new(
+        files,
+        schema,
+        Arc::new(FileFormatConfig::Parquet(ParquetSourceConfig::default())),
+        Arc::new(StorageConfig::new_internal(true, None)),
+    );
+    let plan = LogicalPlanBuilder::table_scan(ScanOperatorRef(Arc::new(operator)), None)?;
+    Ok(plan.build())
+}
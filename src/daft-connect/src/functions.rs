@@ -19,6 +19,7 @@ mod datetime;
 mod math;
 mod partition_transform;
 mod string;
+mod window;
 
 pub(crate) static CONNECT_FUNCTIONS: LazyLock<SparkFunctions> = LazyLock::new(|| {
     let mut functions = SparkFunctions::new();
@@ -28,6 +29,7 @@ pub(crate) static CONNECT_FUNCTIONS: LazyLock<SparkFunctions> = LazyLock::new(||
     functions.register::<math::MathFunctions>();
     functions.register::<partition_transform::PartitionTransformFunctions>();
     functions.register::<string::StringFunctions>();
+    functions.register::<window::WindowFunctions>();
     functions
 });
 
@@ -1,5 +1,6 @@
 use daft_core::count_mode::CountMode;
 use daft_dsl::unresolved_col;
+use daft_functions::grouping::grouping_id;
 use daft_schema::dtype::DataType;
 use spark_connect::Expression;
 
@@ -18,6 +19,8 @@ impl FunctionModule for AggregateFunctions {
         parent.add_fn("min", UnaryFunction(|arg| arg.min()));
         parent.add_fn("max", UnaryFunction(|arg| arg.max()));
         parent.add_fn("sum", UnaryFunction(|arg| arg.sum()));
+        parent.add_fn("grouping", UnaryFunction(|arg| grouping_id(vec![arg])));
+        parent.add_fn("grouping_id", GroupingIdFunction);
     }
 }
 
@@ -43,3 +46,15 @@ impl SparkFunction for CountFunction {
         }
     }
 }
+
+struct GroupingIdFunction;
+
+impl SparkFunction for GroupingIdFunction {
+    fn to_expr(&self, args: &[Expression]) -> ConnectResult<daft_dsl::ExprRef> {
+        let args = args
+            .iter()
+            .map(analyze_expr)
+            .collect::<ConnectResult<_>>()?;
+        Ok(grouping_id(args))
+    }
+}
@@ -1,36 +1,53 @@
 use daft_core::datatypes::TimeUnit;
-use daft_functions::temporal::{Day, DayOfWeek, Hour, Minute, Month, Second, Year};
+use daft_dsl::LiteralValue;
+use daft_functions::temporal::{
+    add_months::AddMonths,
+    date_diff::dt_date_diff,
+    strftime::dt_strftime,
+    unix_epoch::{dt_from_unix_epoch, dt_to_unix_epoch},
+    Day, DayOfWeek, DayOfYear, Hour, Minute, Month, Quarter, Second, WeekOfYear, Year,
+};
 use daft_schema::dtype::DataType;
+use spark_connect::Expression;
 
-use super::{FunctionModule, UnaryFunction, TODO_FUNCTION};
+use super::{BinaryFunction, FunctionModule, SparkFunction, UnaryFunction, TODO_FUNCTION};
+use crate::{
+    error::ConnectResult, invalid_argument_err, spark_analyzer::expr_analyzer::analyze_expr,
+};
 
 /// https://spark.apache.org/docs/latest/api/python/reference/pyspark.sql/functions.html#datetime-functions
 pub struct DatetimeFunctions;
 
 impl FunctionModule for DatetimeFunctions {
     fn register(parent: &mut super::SparkFunctions) {
-        parent.add_fn("add_months", TODO_FUNCTION);
+        parent.add_fn("add_months", AddMonths);
         parent.add_fn("convert_timezone", TODO_FUNCTION);
         parent.add_fn("curdate", TODO_FUNCTION);
         parent.add_fn("current_date", TODO_FUNCTION);
         parent.add_fn("current_timestamp", TODO_FUNCTION);
         parent.add_fn("current_timezone", TODO_FUNCTION);
         parent.add_fn("date_add", TODO_FUNCTION);
-        parent.add_fn("date_diff", TODO_FUNCTION);
-        parent.add_fn("date_format", TODO_FUNCTION);
+        parent.add_fn(
+            "date_diff",
+            BinaryFunction(|end, start| dt_date_diff("day", start, end)),
+        );
+        parent.add_fn("date_format", DateFormat);
         parent.add_fn("date_from_unix_date", TODO_FUNCTION);
         parent.add_fn("date_part", TODO_FUNCTION);
         parent.add_fn("date_sub", TODO_FUNCTION);
         parent.add_fn("date_trunc", TODO_FUNCTION);
         parent.add_fn("dateadd", TODO_FUNCTION);
-        parent.add_fn("datediff", TODO_FUNCTION);
+        parent.add_fn(
+            "datediff",
+            BinaryFunction(|end, start| dt_date_diff("day", start, end)),
+        );
         parent.add_fn("datepart", TODO_FUNCTION);
         parent.add_fn("day", Day);
-        parent.add_fn("dayofmonth", TODO_FUNCTION);
+        parent.add_fn("dayofmonth", Day);
         parent.add_fn("dayofweek", DayOfWeek);
-        parent.add_fn("dayofyear", TODO_FUNCTION);
+        parent.add_fn("dayofyear", DayOfYear);
         parent.add_fn("extract", TODO_FUNCTION);
-        parent.add_fn("from_unixtime", TODO_FUNCTION);
+        parent.add_fn("from_unixtime", FromUnixTime);
         parent.add_fn("from_utc_timestamp", TODO_FUNCTION);
         parent.add_fn("hour", Hour);
         parent.add_fn("last_day", TODO_FUNCTION);
@@ -47,12 +64,21 @@ impl FunctionModule for DatetimeFunctions {
         parent.add_fn("months_between", TODO_FUNCTION);
         parent.add_fn("next_day", TODO_FUNCTION);
         parent.add_fn("now", TODO_FUNCTION);
-        parent.add_fn("quarter", TODO_FUNCTION);
+        parent.add_fn("quarter", Quarter);
         parent.add_fn("second", Second);
         parent.add_fn("session_window", TODO_FUNCTION);
-        parent.add_fn("timestamp_micros", TODO_FUNCTION);
-        parent.add_fn("timestamp_millis", TODO_FUNCTION);
-        parent.add_fn("timestamp_seconds", TODO_FUNCTION);
+        parent.add_fn(
+            "timestamp_micros",
+            UnaryFunction(|arg| dt_from_unix_epoch(arg, TimeUnit::Microseconds)),
+        );
+        parent.add_fn(
+            "timestamp_millis",
+            UnaryFunction(|arg| dt_from_unix_epoch(arg, TimeUnit::Milliseconds)),
+        );
+        parent.add_fn(
+            "timestamp_seconds",
+            UnaryFunction(|arg| dt_from_unix_epoch(arg, TimeUnit::Seconds)),
+        );
         parent.add_fn("to_date", UnaryFunction(|arg| arg.cast(&DataType::Date)));
         parent.add_fn(
             "to_timestamp",
@@ -60,19 +86,173 @@ impl FunctionModule for DatetimeFunctions {
         );
         parent.add_fn("to_timestamp_ltz", TODO_FUNCTION);
         parent.add_fn("to_timestamp_ntz", TODO_FUNCTION);
-        parent.add_fn("to_unix_timestamp", TODO_FUNCTION);
+        parent.add_fn(
+            "to_unix_timestamp",
+            UnaryFunction(|arg| dt_to_unix_epoch(arg, TimeUnit::Seconds)),
+        );
         parent.add_fn("to_utc_timestamp", TODO_FUNCTION);
         parent.add_fn("trunc", TODO_FUNCTION);
         parent.add_fn("try_to_timestamp", TODO_FUNCTION);
         parent.add_fn("unix_date", TODO_FUNCTION);
-        parent.add_fn("unix_micros", TODO_FUNCTION);
-        parent.add_fn("unix_millis", TODO_FUNCTION);
-        parent.add_fn("unix_seconds", TODO_FUNCTION);
-        parent.add_fn("unix_timestamp", TODO_FUNCTION);
-        parent.add_fn("weekday", TODO_FUNCTION);
-        parent.add_fn("weekofyear", TODO_FUNCTION);
+        parent.add_fn(
+            "unix_micros",
+            UnaryFunction(|arg| dt_to_unix_epoch(arg, TimeUnit::Microseconds)),
+        );
+        parent.add_fn(
+            "unix_millis",
+            UnaryFunction(|arg| dt_to_unix_epoch(arg, TimeUnit::Milliseconds)),
+        );
+        parent.add_fn(
+            "unix_seconds",
+            UnaryFunction(|arg| dt_to_unix_epoch(arg, TimeUnit::Seconds)),
+        );
+        parent.add_fn(
+            "unix_timestamp",
+            UnaryFunction(|arg| dt_to_unix_epoch(arg, TimeUnit::Seconds)),
+        );
+        parent.add_fn("weekday", DayOfWeek);
+        parent.add_fn("weekofyear", WeekOfYear);
         parent.add_fn("window", TODO_FUNCTION);
         parent.add_fn("window_time", TODO_FUNCTION);
         parent.add_fn("year", Year);
     }
 }
+
+/// `date_format(timestamp, format)`, where the format is a Spark datetime pattern.
+struct DateFormat;
+
+impl SparkFunction for DateFormat {
+    fn to_expr(&self, args: &[Expression]) -> ConnectResult<daft_dsl::ExprRef> {
+        let [input, format] = args else {
+            invalid_argument_err!("date_format requires exactly 2 arguments");
+        };
+        let input = analyze_expr(input)?;
+        let format = analyze_expr(format)?;
+        let Some(format) = format.as_literal().and_then(LiteralValue::as_str) else {
+            invalid_argument_err!("date_format format must be a string literal");
+        };
+        Ok(dt_strftime(
+            input,
+            Some(&spark_pattern_to_strftime(format)?),
+        ))
+    }
+}
+
+/// `from_unixtime(seconds[, format])`, which formats the timestamp as a string.
+struct FromUnixTime;
+
+impl SparkFunction for FromUnixTime {
+    fn to_expr(&self, args: &[Expression]) -> ConnectResult<daft_dsl::ExprRef> {
+        let (input, format) = match args {
+            [input] => (analyze_expr(input)?, "yyyy-MM-dd HH:mm:ss".to_string()),
+            [input, format] => {
+                let format = analyze_expr(format)?;
+                let Some(format) = format.as_literal().and_then(LiteralValue::as_str) else {
+                    invalid_argument_err!("from_unixtime format must be a string literal");
+                };
+                (analyze_expr(input)?, format.to_string())
+            }
+            _ => invalid_argument_err!("from_unixtime requires 1 or 2 arguments"),
+        };
+        let timestamp = dt_from_unix_epoch(input, TimeUnit::Seconds);
+        Ok(dt_strftime(
+            timestamp,
+            Some(&spark_pattern_to_strftime(&format)?),
+        ))
+    }
+}
+
+/// Translates a Spark datetime pattern such as `yyyy-MM-dd HH:mm:ss` into a strftime format.
+///
+/// See https://spark.apache.org/docs/latest/sql-ref-datetime-pattern.html
+fn spark_pattern_to_strftime(pattern: &str) -> ConnectResult<String> {
+    let mut format = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // quoted text is literal, and two quotes are an escaped quote
+            if chars.next_if_eq(&'\'').is_some() {
+                format.push('\'');
+                continue;
+            }
+            loop {
+                match chars.next() {
+                    Some('\'') if chars.next_if_eq(&'\'').is_some() => format.push('\''),
+                    Some('\'') => break,
+                    Some('%') => format.push_str("%%"),
+                    Some(c) => format.push(c),
+                    None => {
+                        invalid_argument_err!("Unterminated quote in datetime pattern: {pattern}")
+                    }
+                }
+            }
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            match c {
+                '%' => format.push_str("%%"),
+                c => format.push(c),
+            }
+            continue;
+        }
+
+        let mut count = 1;
+        while chars.next_if_eq(&c).is_some() {
+            count += 1;
+        }
+        let specifier = match (c, count) {
+            ('y', 2) => "%y",
+            ('y', _) => "%Y",
+            ('M', 1) => "%-m",
+            ('M', 2) => "%m",
+            ('M', 3) => "%b",
+            ('M', _) => "%B",
+            ('d', 1) => "%-d",
+            ('d', _) => "%d",
+            ('D', 1) => "%-j",
+            ('D', _) => "%j",
+            ('H', 1) => "%-H",
+            ('H', _) => "%H",
+            ('h', 1) => "%-I",
+            ('h', _) => "%I",
+            ('m', 1) => "%-M",
+            ('m', _) => "%M",
+            ('s', 1) => "%-S",
+            ('s', _) => "%S",
+            ('S', 3) => "%3f",
+            ('S', 6) => "%6f",
+            ('S', 9) => "%9f",
+            ('a', _) => "%p",
+            ('E', 1..=3) => "%a",
+            ('E', _) => "%A",
+            ('z', _) => "%Z",
+            ('Z' | 'x', _) => "%z",
+            ('X', _) => "%:z",
+            _ => invalid_argument_err!(
+                "Unsupported datetime pattern '{}' in {pattern}",
+                c.to_string().repeat(count)
+            ),
+        };
+        format.push_str(specifier);
+    }
+    Ok(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::spark_pattern_to_strftime;
+
+    #[test]
+    fn test_spark_pattern_to_strftime() {
+        let translate = |pattern| spark_pattern_to_strftime(pattern).unwrap();
+
+        assert_eq!(translate("yyyy-MM-dd HH:mm:ss"), "%Y-%m-%d %H:%M:%S");
+        assert_eq!(translate("yy/M/d h:m a"), "%y/%-m/%-d %-I:%-M %p");
+        assert_eq!(translate("EEEE, MMMM dd"), "%A, %B %d");
+        assert_eq!(translate("HH:mm:ss.SSS"), "%H:%M:%S.%3f");
+        assert_eq!(translate("yyyy-MM-dd'T'HH:mm"), "%Y-%m-%dT%H:%M");
+        assert_eq!(translate("'o''clock' 100%"), "o'clock 100%%");
+        assert!(spark_pattern_to_strftime("QQ").is_err());
+        assert!(spark_pattern_to_strftime("'unterminated").is_err());
+    }
+}
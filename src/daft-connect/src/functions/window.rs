@@ -0,0 +1,64 @@
+use daft_dsl::Expr;
+use spark_connect::Expression;
+
+use super::{FunctionModule, SparkFunction};
+use crate::{
+    error::ConnectResult, invalid_argument_err, spark_analyzer::expr_analyzer::analyze_expr,
+};
+
+pub struct WindowFunctions;
+
+impl FunctionModule for WindowFunctions {
+    fn register(parent: &mut super::SparkFunctions) {
+        parent.add_fn("row_number", RankingFunction(Expr::row_number));
+        parent.add_fn("rank", RankingFunction(Expr::rank));
+        parent.add_fn("dense_rank", RankingFunction(Expr::dense_rank));
+        parent.add_fn("lag", OffsetFunction { lead: false });
+        parent.add_fn("lead", OffsetFunction { lead: true });
+    }
+}
+
+struct RankingFunction(fn() -> daft_dsl::ExprRef);
+
+impl SparkFunction for RankingFunction {
+    fn to_expr(&self, args: &[Expression]) -> ConnectResult<daft_dsl::ExprRef> {
+        if !args.is_empty() {
+            invalid_argument_err!("requires no arguments");
+        }
+        Ok(self.0())
+    }
+}
+
+struct OffsetFunction {
+    lead: bool,
+}
+
+impl SparkFunction for OffsetFunction {
+    fn to_expr(&self, args: &[Expression]) -> ConnectResult<daft_dsl::ExprRef> {
+        let (input, offset, default) = match args {
+            [input] => (input, None, None),
+            [input, offset] => (input, Some(offset), None),
+            [input, offset, default] => (input, Some(offset), Some(default)),
+            _ => invalid_argument_err!("requires between one and three arguments"),
+        };
+        let input = analyze_expr(input)?;
+        let offset = match offset {
+            Some(offset) => {
+                let offset = analyze_expr(offset)?;
+                let Some(offset) = offset.as_literal().and_then(|lit| lit.as_i64()) else {
+                    invalid_argument_err!("offset must be an integer literal, got {offset}");
+                };
+                offset
+            }
+            None => 1,
+        };
+        let default = default.map(analyze_expr).transpose()?;
+
+        // A negative offset looks in the opposite direction.
+        Ok(if self.lead == (offset >= 0) {
+            input.lead(offset.unsigned_abs(), default)
+        } else {
+            input.lag(offset.unsigned_abs(), default)
+        })
+    }
+}
@@ -6,8 +6,16 @@ pub(crate) mod expr_analyzer;
 use std::{collections::HashMap, io::Cursor, rc::Rc, sync::Arc};
 
 use arrow2::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
-use daft_core::{join::JoinSide, series::Series};
-use daft_dsl::{unresolved_col, Column, Expr, ExprRef, Operator, PlanRef, UnresolvedColumn};
+use daft_core::{count_mode::CountMode, join::JoinSide, series::Series};
+use daft_dsl::{
+    binary_op, common_treenode::TreeNode, lit, unresolved_col, Column, Expr, ExprRef, Operator,
+    PlanRef, UnresolvedColumn,
+};
+use daft_functions::{
+    float::not_nan,
+    grouping::{cube, resolve_grouping_id, rollup, GROUPING_ID_COLUMN},
+    hash::hash,
+};
 use daft_logical_plan::{
     ops::{SetQuantifier, UnionStrategy},
     JoinOptions, JoinType, LogicalPlanBuilder, PyLogicalPlanBuilder,
@@ -15,15 +23,19 @@ use daft_logical_plan::{
 use daft_micropartition::{self, python::PyMicroPartition, MicroPartition};
 use daft_recordbatch::RecordBatch;
 use daft_scan::builder::{delta_scan, CsvScanBuilder, JsonScanBuilder, ParquetScanBuilder};
-use daft_schema::schema::{Schema, SchemaRef};
+use daft_schema::{
+    dtype::DataType,
+    schema::{Schema, SchemaRef},
+};
 use daft_sql::SQLPlanner;
 use datatype::to_daft_datatype;
 pub use datatype::to_spark_datatype;
-use expr_analyzer::analyze_expr;
+use expr_analyzer::{analyze_expr, to_daft_literal};
+use futures::TryStreamExt;
 use itertools::zip_eq;
 use pyo3::{intern, prelude::*};
 use spark_connect::{
-    aggregate::GroupType,
+    aggregate::{GroupType, Pivot},
     data_type::StructField,
     expression::{
         sort_order::{NullOrdering, SortDirection},
@@ -33,7 +45,8 @@ use spark_connect::{
     read::ReadType,
     relation::RelType,
     set_operation::SetOpType,
-    Deduplicate, Expression, Join, Limit, Range, Relation, SetOperation, Sort, Sql,
+    Deduplicate, Expression, Join, Limit, NaDrop, NaFill, Offset, Range, Relation,
+    RepartitionByExpression, Sample, SetOperation, Sort, Sql, Tail,
 };
 use tracing::debug;
 
@@ -120,6 +133,16 @@ impl SparkAnalyzer<'_> {
             RelType::Sql(sql) => self.sql(sql).await,
             RelType::SetOp(set_op) => self.set_op(*set_op).await,
             RelType::Join(join) => self.join(*join).await,
+            RelType::Sample(sample) => self.sample(*sample).await,
+            RelType::Offset(offset) => self.offset(*offset).await,
+            RelType::Tail(tail) => self.tail(*tail).await,
+            RelType::Repartition(repartition) => self.repartition(*repartition).await,
+            RelType::RepartitionByExpression(repartition) => {
+                self.repartition_by_expression(*repartition).await
+            }
+            RelType::FillNa(fill_na) => self.fill_na(*fill_na).await,
+            RelType::DropNa(drop_na) => self.drop_na(*drop_na).await,
+            RelType::Unpivot(unpivot) => self.unpivot(*unpivot).await,
             plan => not_yet_implemented!(r#"relation type: "{}""#, rel_name(&plan)),
         }?;
         let plan_id = common.plan_id.required("plan_id")?;
@@ -136,6 +159,280 @@ impl SparkAnalyzer<'_> {
         plan.limit(i64::from(limit), false).map_err(Into::into)
     }
 
+    async fn offset(&self, offset: Offset) -> ConnectResult<LogicalPlanBuilder> {
+        let Offset { input, offset } = offset;
+        let input = input.required("input")?;
+        ensure!(offset >= 0, "offset must be non-negative; got {}", offset);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        plan.offset(offset as u64).map_err(Into::into)
+    }
+
+    async fn tail(&self, tail: Tail) -> ConnectResult<LogicalPlanBuilder> {
+        let Tail { input, limit } = tail;
+        let input = input.required("input")?;
+        ensure!(limit >= 0, "limit must be non-negative; got {}", limit);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // skip all but the last `limit` rows, which needs the number of rows up front
+        let num_rows = self.count_rows(&plan).await?;
+        let limit = limit as u64;
+        Ok(plan
+            .offset(num_rows.saturating_sub(limit))?
+            .limit(limit as i64, false)?)
+    }
+
+    /// Runs the plan to count its rows.
+    async fn count_rows(&self, plan: &LogicalPlanBuilder) -> ConnectResult<u64> {
+        const COUNT_COLUMN: &str = "count";
+
+        let schema = plan.schema();
+        let Some(first_column) = schema.names().into_iter().next() else {
+            not_yet_implemented!("Counting the rows of a plan without columns");
+        };
+        let count = unresolved_col(first_column)
+            .count(CountMode::All)
+            .alias(COUNT_COLUMN);
+        let plan = plan.aggregate(vec![count], vec![])?;
+
+        let results = self.session.run_query(plan).await?;
+        let results = results.try_collect::<Vec<_>>().await?;
+        let mut num_rows = 0;
+        for partition in results {
+            for table in partition.get_tables()?.iter() {
+                let counts = table.get_column(COUNT_COLUMN)?.cast(&DataType::UInt64)?;
+                num_rows += counts.u64()?.into_iter().flatten().sum::<u64>();
+            }
+        }
+        Ok(num_rows)
+    }
+
+    async fn sample(&self, sample: Sample) -> ConnectResult<LogicalPlanBuilder> {
+        let Sample {
+            input,
+            lower_bound,
+            upper_bound,
+            with_replacement,
+            seed,
+            deterministic_order: _,
+        } = sample;
+        let input = input.required("input")?;
+
+        ensure!(
+            (0.0..=1.0).contains(&lower_bound) && (lower_bound..=1.0).contains(&upper_bound),
+            "bounds must satisfy 0 <= lower_bound <= upper_bound <= 1; got {} and {}",
+            lower_bound,
+            upper_bound
+        );
+        let with_replacement = with_replacement.unwrap_or(false);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let seed = seed.map(|seed| seed as u64);
+
+        if lower_bound == 0.0 {
+            return Ok(plan.sample(upper_bound, with_replacement, seed)?);
+        }
+        // a non-zero lower bound is only used by `randomSplit` without replacement
+        if with_replacement {
+            not_yet_implemented!("Sample with replacement and a lower bound of {lower_bound}");
+        }
+        random_split(&plan, lower_bound, upper_bound, seed)
+    }
+
+    async fn repartition(
+        &self,
+        repartition: spark_connect::Repartition,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let spark_connect::Repartition {
+            input,
+            num_partitions,
+            shuffle,
+        } = repartition;
+        let input = input.required("input")?;
+        ensure!(num_partitions > 0, "num_partitions must be positive");
+        let num_partitions = num_partitions as usize;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // `coalesce` does not shuffle, so it only splits or merges the existing partitions
+        if shuffle.unwrap_or(false) {
+            Ok(plan.random_shuffle(Some(num_partitions))?)
+        } else {
+            Ok(plan.into_partitions(num_partitions)?)
+        }
+    }
+
+    async fn repartition_by_expression(
+        &self,
+        repartition: RepartitionByExpression,
+    ) -> ConnectResult<LogicalPlanBuilder> {
+        let RepartitionByExpression {
+            input,
+            partition_exprs,
+            num_partitions,
+        } = repartition;
+        let input = input.required("input")?;
+        if let Some(num_partitions) = num_partitions {
+            ensure!(num_partitions > 0, "num_partitions must be positive");
+        }
+        let num_partitions = num_partitions.map(|n| n as usize);
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        // `repartitionByRange` sends its columns as sort orders
+        if partition_exprs
+            .iter()
+            .any(|expr| matches!(expr.expr_type, Some(ExprType::SortOrder(_))))
+        {
+            let mut partition_by = Vec::with_capacity(partition_exprs.len());
+            let mut descending = Vec::with_capacity(partition_exprs.len());
+            for expr in partition_exprs {
+                // columns without an explicit order are sorted ascending
+                let (expr, desc) = match expr.expr_type {
+                    Some(ExprType::SortOrder(sort_order)) => {
+                        let (expr, desc, _) = analyze_sort_order(*sort_order)?;
+                        (expr, desc)
+                    }
+                    _ => (analyze_expr(&expr)?, false),
+                };
+                partition_by.push(expr);
+                descending.push(desc);
+            }
+            return Ok(plan.range_repartition(num_partitions, partition_by, descending)?);
+        }
+        let partition_by: Vec<_> = partition_exprs.iter().map(analyze_expr).try_collect()?;
+
+        if partition_by.is_empty() {
+            Ok(plan.random_shuffle(num_partitions)?)
+        } else {
+            Ok(plan.hash_repartition(num_partitions, partition_by)?)
+        }
+    }
+
+    async fn fill_na(&self, fill_na: NaFill) -> ConnectResult<LogicalPlanBuilder> {
+        let NaFill {
+            input,
+            cols,
+            values,
+        } = fill_na;
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let schema = plan.schema();
+
+        let values: Vec<_> = values.iter().map(to_daft_literal).try_collect()?;
+        let fills: Vec<(String, ExprRef)> = match values.as_slice() {
+            [] => invalid_argument_err!("values must contain at least one item"),
+            [value] if cols.is_empty() => schema
+                .names()
+                .into_iter()
+                .map(|name| (name, value.clone()))
+                .collect(),
+            [value] => cols.into_iter().map(|col| (col, value.clone())).collect(),
+            values => {
+                ensure!(
+                    values.len() == cols.len(),
+                    "values and cols must have the same length; got {} values and {} cols",
+                    values.len(),
+                    cols.len()
+                );
+                cols.into_iter().zip(values.iter().cloned()).collect()
+            }
+        };
+
+        let mut fill_exprs = Vec::with_capacity(fills.len());
+        for (name, value) in fills {
+            let field = schema.get_field(&name)?;
+            let value_dtype = value.get_type(&Schema::empty())?;
+            // like spark, columns whose type does not match the value are left as is
+            if !is_fill_compatible(&field.dtype, &value_dtype) {
+                continue;
+            }
+            fill_exprs.push(
+                unresolved_col(name.as_str())
+                    .fill_null(value.cast(&field.dtype))
+                    .alias(name),
+            );
+        }
+
+        if fill_exprs.is_empty() {
+            return Ok(plan);
+        }
+        Ok(plan.with_columns(fill_exprs)?)
+    }
+
+    async fn drop_na(&self, drop_na: NaDrop) -> ConnectResult<LogicalPlanBuilder> {
+        let NaDrop {
+            input,
+            cols,
+            min_non_nulls,
+        } = drop_na;
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let schema = plan.schema();
+
+        let cols = if cols.is_empty() {
+            schema.names()
+        } else {
+            cols
+        };
+
+        // a value counts if it is neither null nor NaN
+        let mut is_valid = Vec::with_capacity(cols.len());
+        for name in &cols {
+            let field = schema.get_field(name)?;
+            let col = unresolved_col(name.as_str());
+            is_valid.push(if field.dtype.is_floating() {
+                col.clone().not_null().and(not_nan(col))
+            } else {
+                col.not_null()
+            });
+        }
+
+        let predicate = match min_non_nulls {
+            // 'any': every column must be valid
+            None => is_valid.into_iter().reduce(|acc, expr| acc.and(expr)),
+            Some(min_non_nulls) => is_valid
+                .into_iter()
+                .map(|expr| {
+                    // null is treated as invalid, since NaN checks produce nulls for null inputs
+                    expr.fill_null(lit(false)).cast(&DataType::Int32)
+                })
+                .reduce(|acc, expr| binary_op(Operator::Plus, acc, expr))
+                .map(|count| count.gt_eq(lit(min_non_nulls))),
+        };
+
+        match predicate {
+            Some(predicate) => Ok(plan.filter(predicate)?),
+            None => Ok(plan),
+        }
+    }
+
+    async fn unpivot(&self, unpivot: spark_connect::Unpivot) -> ConnectResult<LogicalPlanBuilder> {
+        let spark_connect::Unpivot {
+            input,
+            ids,
+            values,
+            variable_column_name,
+            value_column_name,
+        } = unpivot;
+        let input = input.required("input")?;
+
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
+
+        let ids: Vec<_> = ids.iter().map(analyze_expr).try_collect()?;
+        // without values, all non-id columns are unpivoted
+        let values: Vec<_> = values
+            .map(|values| values.values.iter().map(analyze_expr).try_collect())
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(plan.unpivot(ids, values, variable_column_name, value_column_name)?)
+    }
+
     async fn deduplicate(&self, deduplicate: Deduplicate) -> ConnectResult<LogicalPlanBuilder> {
         let Deduplicate {
             input,
@@ -177,34 +474,8 @@ impl SparkAnalyzer<'_> {
         let mut descending = Vec::with_capacity(order.len());
         let mut nulls_first = Vec::with_capacity(order.len());
 
-        for SortOrder {
-            child,
-            direction,
-            null_ordering,
-        } in order
-        {
-            let expr = child.required("child")?;
-            let expr = analyze_expr(&expr)?;
-
-            let sort_direction = SortDirection::try_from(direction).map_err(|e| {
-                ConnectError::invalid_relation(format!("Unknown sort direction: {e}"))
-            })?;
-
-            let desc = match sort_direction {
-                SortDirection::Ascending => false,
-                SortDirection::Descending | SortDirection::Unspecified => true,
-            };
-
-            let null_ordering = NullOrdering::try_from(null_ordering).map_err(|e| {
-                ConnectError::invalid_relation(format!("Unknown null ordering: {e}"))
-            })?;
-
-            let nf = match null_ordering {
-                NullOrdering::SortNullsUnspecified => desc,
-                NullOrdering::SortNullsFirst => true,
-                NullOrdering::SortNullsLast => false,
-            };
-
+        for sort_order in order {
+            let (expr, desc, nf) = analyze_sort_order(sort_order)?;
             sort_by.push(expr);
             descending.push(desc);
             nulls_first.push(nf);
@@ -345,10 +616,18 @@ impl SparkAnalyzer<'_> {
                 // https://spark.apache.org/docs/latest/sql-data-sources-csv.html
                 let mut builder = CsvScanBuilder::new(paths);
 
-                if let Some(sep) = options.remove("sep").and_then(|s| s.chars().next()) {
+                if let Some(sep) = options.remove("sep") {
                     builder = builder.delimiter(sep);
                 }
 
+                if let Some(encoding) = options.remove("encoding") {
+                    builder = builder.encoding(encoding);
+                }
+
+                if let Some(null_value) = options.remove("nullValue") {
+                    builder = builder.null_values(vec![null_value]);
+                }
+
                 // spark sets this to false by default, so we'll do the same
                 let header = options
                     .remove("header")
@@ -434,28 +713,6 @@ impl SparkAnalyzer<'_> {
         &self,
         aggregate: spark_connect::Aggregate,
     ) -> ConnectResult<LogicalPlanBuilder> {
-        fn check_grouptype(group_type: GroupType) -> ConnectResult<()> {
-            match group_type {
-                GroupType::Groupby => {}
-                GroupType::Unspecified => {
-                    invalid_argument_err!("GroupType must be specified; got Unspecified");
-                }
-                GroupType::Rollup => {
-                    not_yet_implemented!("GroupType.Rollup not yet supported");
-                }
-                GroupType::Cube => {
-                    not_yet_implemented!("GroupType.Cube");
-                }
-                GroupType::Pivot => {
-                    not_yet_implemented!("GroupType.Pivot");
-                }
-                GroupType::GroupingSets => {
-                    not_yet_implemented!("GroupType.GroupingSets");
-                }
-            }
-            Ok(())
-        }
-
         let spark_connect::Aggregate {
             input,
             group_type,
@@ -467,20 +724,10 @@ impl SparkAnalyzer<'_> {
 
         let input = input.required("input")?;
 
-        let mut plan = Box::pin(self.to_logical_plan(*input)).await?;
+        let plan = Box::pin(self.to_logical_plan(*input)).await?;
 
         let group_type = GroupType::try_from(group_type).wrap_err("Invalid group type")?;
 
-        check_grouptype(group_type)?;
-
-        if let Some(pivot) = pivot {
-            not_yet_implemented!("Pivot not yet supported; got {pivot:?}");
-        }
-
-        if !grouping_sets.is_empty() {
-            not_yet_implemented!("Grouping sets not yet supported; got {grouping_sets:?}");
-        }
-
         let grouping_expressions: Vec<_> = grouping_expressions
             .iter()
             .map(analyze_expr)
@@ -491,9 +738,60 @@ impl SparkAnalyzer<'_> {
             .map(analyze_expr)
             .try_collect()?;
 
-        plan = plan.aggregate(aggregate_expressions, grouping_expressions)?;
+        let grouping_sets = match group_type {
+            GroupType::Unspecified => {
+                invalid_argument_err!("GroupType must be specified; got Unspecified");
+            }
+            GroupType::Groupby => {
+                return Ok(plan.aggregate(aggregate_expressions, grouping_expressions)?);
+            }
+            GroupType::Pivot => {
+                let pivot = pivot.required("pivot")?;
+                return pivot_aggregate(plan, grouping_expressions, aggregate_expressions, pivot);
+            }
+            GroupType::Rollup => {
+                let elements: Vec<_> = grouping_expressions
+                    .iter()
+                    .map(|e| vec![e.clone()])
+                    .collect();
+                rollup(&elements)
+            }
+            GroupType::Cube => {
+                let elements: Vec<_> = grouping_expressions
+                    .iter()
+                    .map(|e| vec![e.clone()])
+                    .collect();
+                cube(&elements)
+            }
+            GroupType::GroupingSets => grouping_sets
+                .iter()
+                .map(|set| set.grouping_set.iter().map(analyze_expr).try_collect())
+                .try_collect()?,
+        };
 
-        Ok(plan)
+        // grouping functions are evaluated from the grouping id after the aggregation
+        let aggs = aggregate_expressions
+            .iter()
+            .filter(|expr| !has_grouping_fn(expr))
+            .cloned()
+            .collect();
+
+        let plan =
+            plan.grouping_sets_aggregate(aggs, grouping_expressions.clone(), grouping_sets)?;
+
+        let mut projection: Vec<_> = plan.schema().names()[..grouping_expressions.len()]
+            .iter()
+            .map(|name| unresolved_col(name.as_str()))
+            .collect();
+        for expr in aggregate_expressions {
+            if has_grouping_fn(&expr) {
+                projection.push(resolve_grouping_id(expr, &grouping_expressions)?);
+            } else {
+                projection.push(unresolved_col(expr.name()));
+            }
+        }
+
+        Ok(plan.select(projection)?)
     }
 
     async fn drop(&self, drop: spark_connect::Drop) -> ConnectResult<LogicalPlanBuilder> {
@@ -1010,6 +1308,145 @@ impl SparkAnalyzer<'_> {
     }
 }
 
+/// Translates a sort order into its expression and whether it is descending and nulls first.
+fn analyze_sort_order(sort_order: SortOrder) -> ConnectResult<(ExprRef, bool, bool)> {
+    let SortOrder {
+        child,
+        direction,
+        null_ordering,
+    } = sort_order;
+
+    let expr = child.required("child")?;
+    let expr = analyze_expr(&expr)?;
+
+    let sort_direction = SortDirection::try_from(direction)
+        .map_err(|e| ConnectError::invalid_relation(format!("Unknown sort direction: {e}")))?;
+
+    let desc = match sort_direction {
+        SortDirection::Ascending => false,
+        SortDirection::Descending | SortDirection::Unspecified => true,
+    };
+
+    let null_ordering = NullOrdering::try_from(null_ordering)
+        .map_err(|e| ConnectError::invalid_relation(format!("Unknown null ordering: {e}")))?;
+
+    let nf = match null_ordering {
+        NullOrdering::SortNullsUnspecified => desc,
+        NullOrdering::SortNullsFirst => true,
+        NullOrdering::SortNullsLast => false,
+    };
+
+    Ok((expr, desc, nf))
+}
+
+/// Keeps the rows whose seeded random value falls in `[lower_bound, upper_bound)`.
+///
+/// The value is a hash of the row's position rather than a fresh random number, so the splits of
+/// `randomSplit`, which share a seed, are disjoint and cover every row as long as the input is
+/// produced in the same order each time.
+fn random_split(
+    plan: &LogicalPlanBuilder,
+    lower_bound: f64,
+    upper_bound: f64,
+    seed: Option<u64>,
+) -> ConnectResult<LogicalPlanBuilder> {
+    const ROW_ID_COLUMN: &str = "__row_id__";
+
+    let columns: Vec<_> = plan
+        .schema()
+        .names()
+        .into_iter()
+        .map(unresolved_col)
+        .collect();
+
+    let value = binary_op(
+        Operator::TrueDivide,
+        hash(unresolved_col(ROW_ID_COLUMN), seed.map(lit)).cast(&DataType::Float64),
+        lit(u64::MAX as f64),
+    );
+    let mut predicate = value.clone().gt_eq(lit(lower_bound));
+    // the last split keeps the rows whose value rounds up to 1
+    if upper_bound < 1.0 {
+        predicate = predicate.and(value.lt(lit(upper_bound)));
+    }
+
+    let plan = plan
+        .add_monotonically_increasing_id(Some(ROW_ID_COLUMN))?
+        .filter(predicate)?;
+    Ok(plan.select(columns)?)
+}
+
+/// Pivots the aggregation of each group over the given values of the pivot column.
+fn pivot_aggregate(
+    plan: LogicalPlanBuilder,
+    group_by: Vec<ExprRef>,
+    aggregate_expressions: Vec<ExprRef>,
+    pivot: Pivot,
+) -> ConnectResult<LogicalPlanBuilder> {
+    let Pivot { col, values } = pivot;
+
+    let pivot_column = analyze_expr(&col.required("col")?)?;
+
+    let [agg_expr] = aggregate_expressions.as_slice() else {
+        not_yet_implemented!(
+            "Pivot with {} aggregations; only a single aggregation is supported",
+            aggregate_expressions.len()
+        );
+    };
+    // the pivoted columns are named after the values, so the alias of the aggregation is unused
+    let agg_expr = match agg_expr.as_ref() {
+        Expr::Alias(inner, _) => inner.clone(),
+        _ => agg_expr.clone(),
+    };
+    ensure!(
+        matches!(agg_expr.as_ref(), Expr::Agg(_)),
+        "Pivot requires an aggregation; got {}",
+        agg_expr
+    );
+    let value_column = unresolved_col(agg_expr.name());
+
+    if values.is_empty() {
+        not_yet_implemented!("Pivot without values");
+    }
+    // pivot values are matched by their string representation
+    let names: Vec<String> = values
+        .iter()
+        .map(|value| -> ConnectResult<String> {
+            let value = to_daft_literal(value)?;
+            let Some(value) = value.as_literal() else {
+                invalid_argument_err!("Pivot values must be literals; got {value}");
+            };
+            let name = value.to_series().to_str_values()?;
+            let Some(name) = name.utf8()?.get(0) else {
+                not_yet_implemented!("Pivot on null values");
+            };
+            Ok(name.to_string())
+        })
+        .try_collect()?;
+
+    Ok(plan.pivot(group_by, pivot_column, value_column, agg_expr, names)?)
+}
+
+/// Whether the expression uses `grouping` or `grouping_id`, which can only be evaluated after
+/// a grouping sets aggregation.
+fn has_grouping_fn(expr: &ExprRef) -> bool {
+    expr.exists(|e| match e.as_ref() {
+        Expr::ScalarFunction(func) => func.name() == "grouping_id",
+        Expr::Column(Column::Unresolved(UnresolvedColumn { name, .. })) => {
+            name.as_ref() == GROUPING_ID_COLUMN
+        }
+        _ => false,
+    })
+}
+
+/// Whether nulls of a column may be filled with a value, which follows spark in only filling
+/// numeric columns with numbers, string columns with strings, and boolean columns with booleans.
+fn is_fill_compatible(column: &DataType, value: &DataType) -> bool {
+    (column.is_numeric() && value.is_numeric())
+        || (column.is_string() && value.is_string())
+        || (column.is_boolean() && value.is_boolean())
+}
+
 fn rel_name(rel: &RelType) -> &str {
     match rel {
         RelType::Read(_) => "Read",
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
 use daft_core::datatypes::IntervalValue;
-use daft_dsl::{PlanRef, UnresolvedColumn};
+use daft_dsl::{
+    PlanRef, UnresolvedColumn, WindowBoundary, WindowFrame, WindowFrameType, WindowSpec,
+};
 use spark_connect::{
     expression::{
         self as spark_expr,
         cast::{CastToType, EvalMode},
         literal::LiteralType,
         sort_order::{NullOrdering, SortDirection},
+        window::window_frame::{frame_boundary::Boundary, FrameBoundary, FrameType},
         Literal, UnresolvedFunction,
     },
     Expression,
@@ -144,10 +147,114 @@ pub(crate) fn analyze_expr(expression: &Expression) -> ConnectResult<daft_dsl::E
 
             not_yet_implemented!("Sort order expressions not yet supported");
         }
+        spark_expr::ExprType::Window(w) => process_window(w),
         other => not_yet_implemented!("expression type: {other:?}"),
     }
 }
 
+fn process_window(w: &spark_expr::Window) -> ConnectResult<daft_dsl::ExprRef> {
+    let spark_expr::Window {
+        window_function,
+        partition_spec,
+        order_spec,
+        frame_spec,
+    } = w;
+
+    let Some(window_function) = window_function else {
+        invalid_argument_err!("Window function is required");
+    };
+    let window_function = analyze_expr(window_function)?;
+
+    let partition_by = partition_spec
+        .iter()
+        .map(analyze_expr)
+        .collect::<ConnectResult<Vec<_>>>()?;
+    let mut window_spec = WindowSpec::new(partition_by);
+
+    if !order_spec.is_empty() {
+        let mut order_by = Vec::with_capacity(order_spec.len());
+        let mut descending = Vec::with_capacity(order_spec.len());
+        let mut nulls_first = Vec::with_capacity(order_spec.len());
+        for spark_expr::SortOrder {
+            child,
+            direction,
+            null_ordering,
+        } in order_spec
+        {
+            let Some(child) = child else {
+                invalid_argument_err!("Sort order child is required");
+            };
+
+            let sort_direction = SortDirection::try_from(*direction).map_err(|e| {
+                ConnectError::invalid_relation(format!("Unknown sort direction: {e}"))
+            })?;
+            let desc = matches!(sort_direction, SortDirection::Descending);
+
+            let null_ordering = NullOrdering::try_from(*null_ordering).map_err(|e| {
+                ConnectError::invalid_relation(format!("Unknown null ordering: {e}"))
+            })?;
+            let nf = match null_ordering {
+                NullOrdering::SortNullsUnspecified => desc,
+                NullOrdering::SortNullsFirst => true,
+                NullOrdering::SortNullsLast => false,
+            };
+
+            order_by.push(analyze_expr(child)?);
+            descending.push(desc);
+            nulls_first.push(nf);
+        }
+        window_spec = window_spec.with_order_by(order_by, descending, nulls_first);
+    }
+
+    if let Some(frame) = frame_spec {
+        let frame_type = match FrameType::try_from(frame.frame_type)
+            .map_err(|e| ConnectError::invalid_relation(format!("Unknown frame type: {e}")))?
+        {
+            FrameType::Row => WindowFrameType::Rows,
+            FrameType::Range => WindowFrameType::Range,
+            FrameType::Undefined => invalid_argument_err!("Window frame type is required"),
+        };
+        let Some(lower) = &frame.lower else {
+            invalid_argument_err!("Window frame lower bound is required");
+        };
+        let Some(upper) = &frame.upper else {
+            invalid_argument_err!("Window frame upper bound is required");
+        };
+        let start = to_window_boundary(lower, WindowBoundary::UnboundedPreceding)?;
+        let end = to_window_boundary(upper, WindowBoundary::UnboundedFollowing)?;
+        window_spec = window_spec.with_frame(WindowFrame::try_new(frame_type, start, end)?);
+    }
+
+    Ok(window_function.over(window_spec)?)
+}
+
+/// Converts a Spark frame boundary, whose values are signed offsets from the current row.
+fn to_window_boundary(
+    boundary: &FrameBoundary,
+    unbounded: WindowBoundary,
+) -> ConnectResult<WindowBoundary> {
+    let Some(boundary) = &boundary.boundary else {
+        invalid_argument_err!("Window frame boundary is required");
+    };
+    match boundary {
+        Boundary::CurrentRow(_) => Ok(WindowBoundary::CurrentRow),
+        Boundary::Unbounded(_) => Ok(unbounded),
+        Boundary::Value(value) => {
+            let value = analyze_expr(value)?;
+            let Some(offset) = value.as_literal().and_then(|lit| lit.as_i64()) else {
+                invalid_argument_err!(
+                    "Window frame boundary must be an integer literal, got {value}"
+                );
+            };
+            Ok(match offset {
+                0 => WindowBoundary::CurrentRow,
+                n if n < 0 => WindowBoundary::Preceding(n.unsigned_abs()),
+                n => WindowBoundary::Following(n as u64),
+            })
+        }
+    }
+}
+
 fn process_function(f: &UnresolvedFunction) -> ConnectResult<daft_dsl::ExprRef> {
     let UnresolvedFunction {
         function_name,
@@ -34,3 +34,50 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow2::array::{ord, PrimitiveArray};
+
+    use super::*;
+
+    fn sorted_indices(descending: bool, nulls_first: bool) -> Vec<Option<u64>> {
+        let array = PrimitiveArray::<i32>::from([Some(3), None, Some(1), Some(2), None]);
+        indices_sorted_unstable_by::<u64, _, _>(&array, ord::total_cmp, descending, nulls_first)
+            .iter()
+            .map(|idx| idx.copied())
+            .collect()
+    }
+
+    #[test]
+    fn ascending_nulls_first() {
+        assert_eq!(
+            sorted_indices(false, true),
+            [Some(1), Some(4), Some(2), Some(3), Some(0)]
+        );
+    }
+
+    #[test]
+    fn ascending_nulls_last() {
+        assert_eq!(
+            sorted_indices(false, false),
+            [Some(2), Some(3), Some(0), Some(1), Some(4)]
+        );
+    }
+
+    #[test]
+    fn descending_nulls_first() {
+        assert_eq!(
+            sorted_indices(true, true),
+            [Some(1), Some(4), Some(0), Some(3), Some(2)]
+        );
+    }
+
+    #[test]
+    fn descending_nulls_last() {
+        assert_eq!(
+            sorted_indices(true, false),
+            [Some(0), Some(3), Some(2), Some(1), Some(4)]
+        );
+    }
+}
@@ -7,9 +7,13 @@ use arrow2::{
         ArraySub,
     },
     datatypes::ArrowDataType,
-    types::months_days_ns,
+    types::{months_days_ns, NativeType},
+};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Datelike, Duration, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike,
 };
-use chrono::{Duration, NaiveDate, NaiveTime, Timelike};
 use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
@@ -101,6 +105,104 @@ impl DateArray {
         let day_arr = arrow2::compute::temporal::weekday(&input_array)?;
         Ok((self.name(), Box::new(day_arr.sub(&1))).into())
     }
+
+    pub fn day_of_year(&self) -> DaftResult<UInt32Array> {
+        Ok(self.date_component(|date| date.ordinal()))
+    }
+
+    /// The ISO 8601 week of the year, where the first week contains the year's first Thursday.
+    pub fn week_of_year(&self) -> DaftResult<UInt32Array> {
+        Ok(self.date_component(|date| date.iso_week().week()))
+    }
+
+    pub fn quarter(&self) -> DaftResult<UInt32Array> {
+        Ok(self.date_component(|date| date.month0() / 3 + 1))
+    }
+
+    /// The number of seconds since the Unix epoch.
+    pub fn epoch(&self) -> DaftResult<Float64Array> {
+        let epoch_arr = arrow2::array::PrimitiveArray::<f64>::from_iter(
+            self.physical
+                .as_arrow()
+                .iter()
+                .map(|days| days.map(|days| f64::from(*days) * 86_400.0)),
+        );
+        Ok((self.name(), Box::new(epoch_arr)).into())
+    }
+
+    /// Formats the dates with a strftime-style format string, defaulting to `%Y-%m-%d`.
+    pub fn strftime(&self, format: Option<&str>) -> DaftResult<Utf8Array> {
+        let format = format.unwrap_or("%Y-%m-%d");
+        let items = strftime_items(format)?;
+        let formatted = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|days| {
+                days.map(|days| {
+                    let date = arrow2::temporal_conversions::date32_to_date(*days);
+                    format_temporal(date.format_with_items(items.iter()), format)
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Utf8Array::from_iter(self.name(), formatted.into_iter()))
+    }
+
+    /// The number of `time_unit`s since the Unix epoch.
+    pub fn to_unix_epoch(&self, time_unit: TimeUnit) -> DaftResult<Int64Array> {
+        let units_per_day = 86_400 * time_unit.to_scale_factor();
+        let epoch_arr = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|days| {
+                days.map(|days| {
+                    i64::from(*days).checked_mul(units_per_day).ok_or_else(|| {
+                        DaftError::ValueError(format!(
+                            "Date {} is out of range for {time_unit} since the Unix epoch",
+                            arrow2::temporal_conversions::date32_to_date(*days)
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<PrimitiveArray<i64>>>()?;
+        Ok((self.name(), Box::new(epoch_arr)).into())
+    }
+
+    /// Adds a number of calendar months to the dates, clamping the day to the end of the month.
+    pub fn add_months(&self, months: &Int64Array) -> DaftResult<Self> {
+        let physical = broadcast_pairs("add_months", self.physical.as_arrow(), months.as_arrow())?
+            .map(|pair| match pair {
+                (Some(days), Some(months)) => {
+                    let date = arrow2::temporal_conversions::date32_to_date(days);
+                    let shifted = add_months(date, months).ok_or_else(|| {
+                        DaftError::ValueError(format!(
+                            "Adding {months} months to {date} is out of range"
+                        ))
+                    })?;
+                    Ok(Some(
+                        shifted.num_days_from_ce()
+                            - arrow2::temporal_conversions::EPOCH_DAYS_FROM_CE,
+                    ))
+                }
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<PrimitiveArray<i32>>>()?;
+        Ok(Self::new(
+            self.field.clone(),
+            Int32Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
+    fn date_component(&self, f: impl Fn(NaiveDate) -> u32) -> UInt32Array {
+        let component_arr =
+            arrow2::array::PrimitiveArray::<u32>::from_iter(self.physical.as_arrow().iter().map(
+                |days| days.map(|days| f(arrow2::temporal_conversions::date32_to_date(*days))),
+            ));
+        (self.name(), Box::new(component_arr)).into()
+    }
 }
 
 impl TimestampArray {
@@ -368,6 +470,235 @@ impl TimestampArray {
         ))
     }
 
+    /// The number of seconds since the Unix epoch.
+    pub fn epoch(&self) -> DaftResult<Float64Array> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let units_per_second = match timeunit {
+            TimeUnit::Seconds => 1.0,
+            TimeUnit::Milliseconds => 1_000.0,
+            TimeUnit::Microseconds => 1_000_000.0,
+            TimeUnit::Nanoseconds => 1_000_000_000.0,
+        };
+        let epoch_arr = arrow2::array::PrimitiveArray::<f64>::from_iter(
+            self.physical
+                .as_arrow()
+                .iter()
+                .map(|ts| ts.map(|ts| *ts as f64 / units_per_second)),
+        );
+        Ok((self.name(), Box::new(epoch_arr)).into())
+    }
+
+    /// Converts the timestamps to the given timezone.
+    ///
+    /// Timezone-aware timestamps keep the instant they represent and are only displayed in the new
+    /// timezone, while naive timestamps are interpreted as local times in the new timezone.
+    pub fn convert_time_zone(&self, time_zone: &str) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+
+        fn localize<T: TimeZone>(
+            physical: &PrimitiveArray<i64>,
+            timeunit: TimeUnit,
+            tz: &T,
+        ) -> DaftResult<PrimitiveArray<i64>> {
+            physical
+                .iter()
+                .map(|ts| {
+                    ts.map(|ts| {
+                        let naive = arrow2::temporal_conversions::timestamp_to_naive_datetime(
+                            *ts,
+                            timeunit.to_arrow(),
+                        );
+                        let local = tz.from_local_datetime(&naive).earliest().ok_or_else(|| {
+                            DaftError::ValueError(format!(
+                                "Local time {naive} does not exist in the target timezone"
+                            ))
+                        })?;
+                        datetime_to_timestamp(&local, timeunit)
+                    })
+                    .transpose()
+                })
+                .collect()
+        }
+
+        let physical = self.physical.as_arrow();
+        let physical = match (tz, arrow2::temporal_conversions::parse_offset(time_zone)) {
+            (None, Ok(offset)) => localize(physical, *timeunit, &offset)?,
+            (None, Err(_)) => match arrow2::temporal_conversions::parse_offset_tz(time_zone) {
+                Ok(tz) => localize(physical, *timeunit, &tz)?,
+                Err(_) => return Err(invalid_time_zone(time_zone)),
+            },
+            (Some(_), Ok(_)) => physical.clone(),
+            (Some(_), Err(_)) => {
+                arrow2::temporal_conversions::parse_offset_tz(time_zone)
+                    .map_err(|_| invalid_time_zone(time_zone))?;
+                physical.clone()
+            }
+        };
+
+        Ok(Self::new(
+            Field::new(
+                self.name(),
+                DataType::Timestamp(*timeunit, Some(time_zone.to_string())),
+            ),
+            Int64Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
+    /// Formats the timestamps in their timezone with a strftime-style format string, defaulting
+    /// to ISO 8601.
+    pub fn strftime(&self, format: Option<&str>) -> DaftResult<Utf8Array> {
+        let DataType::Timestamp(_, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let format = format.unwrap_or(match tz {
+            Some(_) => "%Y-%m-%dT%H:%M:%S%.f%:z",
+            None => "%Y-%m-%dT%H:%M:%S%.f",
+        });
+        let items = strftime_items(format)?;
+        let formatted = self
+            .datetimes()?
+            .map(|dt| {
+                dt.map(|dt| format_temporal(dt.format_with_items(items.iter()), format))
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Utf8Array::from_iter(self.name(), formatted.into_iter()))
+    }
+
+    /// The number of `time_unit`s since the Unix epoch, rounded down.
+    pub fn to_unix_epoch(&self, time_unit: TimeUnit) -> DaftResult<Int64Array> {
+        let DataType::Timestamp(timeunit, _) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let (from, to) = (timeunit.to_scale_factor(), time_unit.to_scale_factor());
+        let epoch_arr = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| {
+                    if to >= from {
+                        ts.checked_mul(to / from).ok_or_else(|| {
+                            DaftError::ValueError(format!(
+                                "Timestamp {ts} is out of range for {time_unit} since the Unix epoch"
+                            ))
+                        })
+                    } else {
+                        Ok(ts.div_euclid(from / to))
+                    }
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<PrimitiveArray<i64>>>()?;
+        Ok((self.name(), Box::new(epoch_arr)).into())
+    }
+
+    /// Adds a number of calendar months to the timestamps in their timezone, clamping the day to
+    /// the end of the month.
+    pub fn add_months(&self, months: &Int64Array) -> DaftResult<Self> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tz = ArrayTimeZone::parse(tz.as_deref())?;
+        let physical = broadcast_pairs("add_months", self.physical.as_arrow(), months.as_arrow())?
+            .map(|pair| match pair {
+                (Some(ts), Some(months)) => {
+                    let local = tz.to_datetime(ts, timeunit.to_arrow()).naive_local();
+                    let shifted = add_months(local.date(), months)
+                        .map(|date| NaiveDateTime::new(date, local.time()))
+                        .and_then(|shifted| tz.localize(&shifted))
+                        .ok_or_else(|| {
+                            DaftError::ValueError(format!(
+                                "Adding {months} months to {local} is out of range"
+                            ))
+                        })?;
+                    datetime_to_timestamp(&shifted, *timeunit).map(Some)
+                }
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<PrimitiveArray<i64>>>()?;
+        Ok(Self::new(
+            self.field.clone(),
+            Int64Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
+    /// The number of `unit` boundaries crossed going from `start` to each timestamp.
+    ///
+    /// Calendar units (`day` and longer) are counted in the timezone of each timestamp, while
+    /// shorter units are counted on the instants they represent.
+    pub fn date_diff(&self, start: &Self, unit: &str) -> DaftResult<Int64Array> {
+        let diff: fn(&DateTime<FixedOffset>, &DateTime<FixedOffset>) -> i64 = match unit {
+            "year" | "years" => |start, end| i64::from(end.year() - start.year()),
+            "quarter" | "quarters" => |start, end| {
+                let quarters =
+                    |dt: &DateTime<FixedOffset>| i64::from(dt.year()) * 4 + i64::from(dt.month0() / 3);
+                quarters(end) - quarters(start)
+            },
+            "month" | "months" => |start, end| {
+                let months =
+                    |dt: &DateTime<FixedOffset>| i64::from(dt.year()) * 12 + i64::from(dt.month0());
+                months(end) - months(start)
+            },
+            "week" | "weeks" => |start, end| {
+                // 0001-01-01 is a Monday, so this counts weeks starting on Mondays
+                let weeks = |dt: &DateTime<FixedOffset>| {
+                    i64::from(dt.date_naive().num_days_from_ce() - 1).div_euclid(7)
+                };
+                weeks(end) - weeks(start)
+            },
+            "day" | "days" => |start, end| (end.date_naive() - start.date_naive()).num_days(),
+            "hour" | "hours" => {
+                |start, end| end.timestamp().div_euclid(3600) - start.timestamp().div_euclid(3600)
+            }
+            "minute" | "minutes" => {
+                |start, end| end.timestamp().div_euclid(60) - start.timestamp().div_euclid(60)
+            }
+            "second" | "seconds" => |start, end| end.timestamp() - start.timestamp(),
+            "millisecond" | "milliseconds" => {
+                |start, end| end.timestamp_millis() - start.timestamp_millis()
+            }
+            "microsecond" | "microseconds" => {
+                |start, end| end.timestamp_micros() - start.timestamp_micros()
+            }
+            _ => return Err(DaftError::ValueError(format!(
+                "Invalid date_diff unit: {unit}. Expected one of: year, quarter, month, week, day, hour, minute, second, millisecond, microsecond"
+            ))),
+        };
+
+        let (end, start) = (self.datetimes()?, start.datetimes()?);
+        let (end, start) = (end.collect::<Vec<_>>(), start.collect::<Vec<_>>());
+        let len = broadcast_len("date_diff", end.len(), start.len())?;
+        let get = |values: &[Option<DateTime<FixedOffset>>], i: usize| {
+            values[if values.len() == 1 { 0 } else { i }]
+        };
+        let diff_arr = PrimitiveArray::<i64>::from_iter((0..len).map(|i| {
+            match (get(&start, i), get(&end, i)) {
+                (Some(start), Some(end)) => Some(diff(&start, &end)),
+                _ => None,
+            }
+        }));
+        Ok((self.name(), Box::new(diff_arr)).into())
+    }
+
+    /// The timestamps as datetimes in their timezone, or in UTC for naive timestamps.
+    fn datetimes(&self) -> DaftResult<impl Iterator<Item = Option<DateTime<FixedOffset>>> + '_> {
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let tz = ArrayTimeZone::parse(tz.as_deref())?;
+        let tu = timeunit.to_arrow();
+        Ok(self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(move |ts| ts.map(|ts| tz.to_datetime(*ts, tu))))
+    }
+
     pub fn add_interval(&self, interval: &IntervalArray) -> DaftResult<Self> {
         self.interval_helper(interval, add_interval)
     }
@@ -409,6 +740,174 @@ impl TimestampArray {
     }
 }
 
+fn invalid_time_zone(time_zone: &str) -> DaftError {
+    DaftError::ValueError(format!(
+        "Invalid timezone: {time_zone}. Expected an offset like +05:00 or a name like America/New_York"
+    ))
+}
+
+fn datetime_to_timestamp<T: TimeZone>(dt: &DateTime<T>, timeunit: TimeUnit) -> DaftResult<i64> {
+    match timeunit {
+        TimeUnit::Seconds => Ok(dt.timestamp()),
+        TimeUnit::Milliseconds => Ok(dt.timestamp_millis()),
+        TimeUnit::Microseconds => Ok(dt.timestamp_micros()),
+        TimeUnit::Nanoseconds => dt.timestamp_nanos_opt().ok_or_else(|| {
+            DaftError::ValueError(format!(
+                "Timestamp {} is out of range for nanoseconds",
+                dt.naive_utc()
+            ))
+        }),
+    }
+}
+
+/// The timezone of a timestamp array, where naive timestamps are treated as UTC.
+enum ArrayTimeZone {
+    Offset(FixedOffset),
+    Named(chrono_tz::Tz),
+}
+
+impl ArrayTimeZone {
+    fn parse(tz: Option<&str>) -> DaftResult<Self> {
+        match tz {
+            None => Ok(Self::Offset(FixedOffset::east_opt(0).unwrap())),
+            Some(tz) => {
+                if let Ok(offset) = arrow2::temporal_conversions::parse_offset(tz) {
+                    Ok(Self::Offset(offset))
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+                    Ok(Self::Named(tz))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Cannot parse timezone in Timestamp datatype: {tz}"
+                    )))
+                }
+            }
+        }
+    }
+
+    fn to_datetime(&self, ts: i64, tu: arrow2::datatypes::TimeUnit) -> DateTime<FixedOffset> {
+        match self {
+            Self::Offset(offset) => {
+                arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, offset)
+            }
+            Self::Named(tz) => {
+                arrow2::temporal_conversions::timestamp_to_datetime(ts, tu, tz).fixed_offset()
+            }
+        }
+    }
+
+    fn localize(&self, naive: &NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Self::Offset(offset) => offset.from_local_datetime(naive).earliest(),
+            Self::Named(tz) => tz
+                .from_local_datetime(naive)
+                .earliest()
+                .map(|dt| dt.fixed_offset()),
+        }
+    }
+}
+
+fn add_months(date: NaiveDate, months: i64) -> Option<NaiveDate> {
+    let abs_months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months >= 0 {
+        date.checked_add_months(abs_months)
+    } else {
+        date.checked_sub_months(abs_months)
+    }
+}
+
+fn strftime_items(format: &str) -> DaftResult<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.contains(&Item::Error) {
+        return Err(DaftError::ValueError(format!(
+            "Invalid strftime format: {format}"
+        )));
+    }
+    Ok(items)
+}
+
+/// Renders a chrono `format` result, which fails when the format refers to fields the value
+/// does not have, such as the hour of a date.
+fn format_temporal(formatted: impl std::fmt::Display, format: &str) -> DaftResult<String> {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    write!(out, "{formatted}").map_err(|_| {
+        DaftError::ValueError(format!(
+            "Cannot format value with strftime format: {format}"
+        ))
+    })?;
+    Ok(out)
+}
+
+fn broadcast_len(op_name: &str, lhs: usize, rhs: usize) -> DaftResult<usize> {
+    match (lhs, rhs) {
+        (l, r) if l == r => Ok(l),
+        (l, 1) => Ok(l),
+        (1, r) => Ok(r),
+        (l, r) => Err(DaftError::ValueError(format!(
+            "Cannot apply {op_name} to arrays of different lengths: {l} vs {r}"
+        ))),
+    }
+}
+
+/// Pairs up the values of two arrays of the same length, broadcasting a side of length 1.
+fn broadcast_pairs<'a, L: NativeType, R: NativeType>(
+    op_name: &str,
+    lhs: &'a PrimitiveArray<L>,
+    rhs: &'a PrimitiveArray<R>,
+) -> DaftResult<impl Iterator<Item = (Option<L>, Option<R>)> + 'a> {
+    fn get<T: NativeType>(array: &PrimitiveArray<T>, i: usize) -> Option<T> {
+        let i = if array.len() == 1 { 0 } else { i };
+        array.is_valid(i).then(|| array.value(i))
+    }
+
+    let len = broadcast_len(op_name, lhs.len(), rhs.len())?;
+    Ok((0..len).map(move |i| (get(lhs, i), get(rhs, i))))
+}
+
+impl IntervalArray {
+    pub fn add_interval(&self, other: &Self) -> DaftResult<Self> {
+        self.interval_op(other, "+", i64::checked_add)
+    }
+
+    pub fn sub_interval(&self, other: &Self) -> DaftResult<Self> {
+        self.interval_op(other, "-", i64::checked_sub)
+    }
+
+    /// Applies `op` to each of the months, days and nanoseconds of the intervals, broadcasting
+    /// a side of length 1.
+    fn interval_op(
+        &self,
+        other: &Self,
+        op_name: &str,
+        op: impl Fn(i64, i64) -> Option<i64>,
+    ) -> DaftResult<Self> {
+        let apply = |lhs: &months_days_ns, rhs: &months_days_ns| {
+            let months = op(lhs.months().into(), rhs.months().into())
+                .and_then(|months| i32::try_from(months).ok());
+            let days =
+                op(lhs.days().into(), rhs.days().into()).and_then(|days| i32::try_from(days).ok());
+            let nanoseconds = op(lhs.ns(), rhs.ns());
+            match (months, days, nanoseconds) {
+                (Some(months), Some(days), Some(nanoseconds)) => {
+                    Ok(months_days_ns::new(months, days, nanoseconds))
+                }
+                _ => Err(DaftError::ComputeError(format!(
+                    "Interval overflow in {lhs:?} {op_name} {rhs:?}"
+                ))),
+            }
+        };
+
+        let result = broadcast_pairs(op_name, self.as_arrow(), other.as_arrow())?
+            .map(|pair| match pair {
+                (Some(l), Some(r)) => apply(&l, &r).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Self::from_iter(self.name(), result.into_iter()))
+    }
+}
+
 impl TimeArray {
     pub fn hour(&self) -> DaftResult<UInt32Array> {
         let physical = self.physical.as_arrow();
@@ -484,4 +983,28 @@ impl TimeArray {
             Box::new(PrimitiveArray::from(date_arrow)),
         )
     }
+    /// Formats the times with a strftime-style format string, defaulting to `%H:%M:%S%.f`.
+    pub fn strftime(&self, format: Option<&str>) -> DaftResult<Utf8Array> {
+        let tu = match self.data_type() {
+            DataType::Time(time_unit) => time_unit.to_arrow(),
+            _ => unreachable!("TimeArray must have Time datatype"),
+        };
+        let format = format.unwrap_or("%H:%M:%S%.f");
+        let items = strftime_items(format)?;
+        let formatted = self
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|ts| {
+                ts.map(|ts| {
+                    let naive_time =
+                        arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &chrono::Utc)
+                            .time();
+                    format_temporal(naive_time.format_with_items(items.iter()), format)
+                })
+                .transpose()
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(Utf8Array::from_iter(self.name(), formatted.into_iter()))
+    }
 }
@@ -271,7 +271,8 @@ impl Add for InferDataType<'_> {
                         )),
                     }
                 },
-                // ---- Interval + temporal ----
+                // ---- Interval + Interval or temporal ----
+                (DataType::Interval, DataType::Interval) => Ok(DataType::Interval),
                 (DataType::Interval, dtype) | (dtype, DataType::Interval) if dtype.is_temporal() => Ok(dtype.clone()),
                 // ---- Boolean + other ----
                 (DataType::Boolean, other) | (other, DataType::Boolean)
@@ -357,6 +358,7 @@ impl Sub for InferDataType<'_> {
                         Ok(DataType::Decimal128(p_prime, s_max))
                     }
                 }
+                (DataType::Interval, DataType::Interval) => Ok(DataType::Interval),
                 (DataType::Interval, dtype) | (dtype, DataType::Interval) if dtype.is_temporal() => Ok(dtype.clone()),
                 _ => Err(DaftError::TypeError(
                     format!("Cannot subtract types: {}, {}", self, other)
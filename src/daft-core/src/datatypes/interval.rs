@@ -201,6 +201,18 @@ impl IntervalValue {
     }
 }
 
+impl std::ops::Neg for IntervalValue {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            months: -self.months,
+            days: -self.days,
+            nanoseconds: -self.nanoseconds,
+        }
+    }
+}
+
 impl From<months_days_ns> for IntervalValue {
     fn from(value: months_days_ns) -> Self {
         Self {
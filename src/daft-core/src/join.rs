@@ -17,6 +17,9 @@ pub enum JoinType {
     Outer,
     Anti,
     Semi,
+    /// Matches each left row with the nearest right row on the last join key, among the right rows
+    /// equal on the other join keys, keeping left rows without a match.
+    AsOf,
 }
 
 #[cfg(feature = "python")]
@@ -39,13 +42,14 @@ impl_bincode_py_state_serialization!(JoinType);
 
 impl JoinType {
     pub fn iterator() -> std::slice::Iter<'static, Self> {
-        static JOIN_TYPES: [JoinType; 6] = [
+        static JOIN_TYPES: [JoinType; 7] = [
             JoinType::Inner,
             JoinType::Left,
             JoinType::Right,
             JoinType::Outer,
             JoinType::Anti,
             JoinType::Semi,
+            JoinType::AsOf,
         ];
         JOIN_TYPES.iter()
     }
@@ -63,6 +67,7 @@ impl FromStr for JoinType {
             "outer" => Ok(Self::Outer),
             "anti" => Ok(Self::Anti),
             "semi" => Ok(Self::Semi),
+            "asof" => Ok(Self::AsOf),
             _ => Err(DaftError::TypeError(format!(
                 "Join type {} is not supported; only the following types are supported: {:?}",
                 join_type,
@@ -128,6 +133,65 @@ impl FromStr for JoinStrategy {
     }
 }
 
+/// Which right row an as-of join matches each left row with.
+#[derive(Clone, Copy, Debug, Default, Display, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
+pub enum AsofDirection {
+    /// The last right row whose key is less than or equal to the left key.
+    #[default]
+    Backward,
+    /// The first right row whose key is greater than or equal to the left key.
+    Forward,
+    /// The right row whose key is closest to the left key, preferring the backward match on ties.
+    Nearest,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl AsofDirection {
+    /// Create an AsofDirection from its string representation.
+    ///
+    /// Args:
+    ///     direction: String representation of the direction, e.g. "backward", "forward", or "nearest".
+    #[staticmethod]
+    pub fn from_asof_direction_str(direction: &str) -> PyResult<Self> {
+        Self::from_str(direction).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+impl_bincode_py_state_serialization!(AsofDirection);
+
+impl AsofDirection {
+    pub fn iterator() -> std::slice::Iter<'static, Self> {
+        static DIRECTIONS: [AsofDirection; 3] = [
+            AsofDirection::Backward,
+            AsofDirection::Forward,
+            AsofDirection::Nearest,
+        ];
+        DIRECTIONS.iter()
+    }
+}
+
+impl FromStr for AsofDirection {
+    type Err = DaftError;
+
+    fn from_str(direction: &str) -> DaftResult<Self> {
+        match direction {
+            "backward" => Ok(Self::Backward),
+            "forward" => Ok(Self::Forward),
+            "nearest" => Ok(Self::Nearest),
+            _ => Err(DaftError::TypeError(format!(
+                "As-of join direction {} is not supported; only the following directions are supported: {:?}",
+                direction,
+                Self::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
 pub enum JoinSide {
@@ -130,6 +130,10 @@ impl Add for &Series {
                         let ts = ts.timestamp()?.add_interval(self.interval()?)?;
                         ts.cast(output_type)
                     }
+                    (DataType::Interval, DataType::Interval) => Ok(self
+                        .interval()?
+                        .add_interval(rhs.interval()?)?
+                        .into_series()),
 
                     _ => arithmetic_op_not_implemented!(self, "+", rhs, output_type),
                 }
@@ -227,6 +231,10 @@ impl Sub for &Series {
                         let ts = ts.timestamp()?.sub_interval(self.interval()?)?;
                         ts.cast(output_type)
                     }
+                    (DataType::Interval, DataType::Interval) => Ok(self
+                        .interval()?
+                        .sub_interval(rhs.interval()?)?
+                        .into_series()),
                     _ => arithmetic_op_not_implemented!(self, "-", rhs, output_type),
                 }
             }
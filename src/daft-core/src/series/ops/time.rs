@@ -1,7 +1,8 @@
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    datatypes::{DataType, TimeUnit},
+    datatypes::{DataType, Field, TimeUnit},
+    prelude::TimestampArray,
     series::{array_impl::IntoSeries, Series},
 };
 
@@ -169,6 +170,161 @@ impl Series {
         }
     }
 
+    pub fn dt_day_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.day_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.day_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run day_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_week_of_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.week_of_year()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.week_of_year()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run week_of_year() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_quarter(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => {
+                let downcasted = self.date()?;
+                Ok(downcasted.quarter()?.into_series())
+            }
+            DataType::Timestamp(..) => {
+                let ts_array = self.timestamp()?;
+                Ok(ts_array.date()?.quarter()?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run quarter() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_epoch(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.epoch()?.into_series()),
+            DataType::Timestamp(..) => Ok(self.timestamp()?.epoch()?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run epoch() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_convert_time_zone(&self, time_zone: &str) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => self
+                .cast(&DataType::Timestamp(TimeUnit::Microseconds, None))?
+                .dt_convert_time_zone(time_zone),
+            DataType::Timestamp(..) => Ok(self
+                .timestamp()?
+                .convert_time_zone(time_zone)?
+                .into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run convert_time_zone() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_strftime(&self, format: Option<&str>) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.strftime(format)?.into_series()),
+            DataType::Timestamp(..) => Ok(self.timestamp()?.strftime(format)?.into_series()),
+            DataType::Time(..) => Ok(self.time()?.strftime(format)?.into_series()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run strftime() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_to_unix_epoch(&self, time_unit: TimeUnit) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.to_unix_epoch(time_unit)?.into_series()),
+            DataType::Timestamp(..) => {
+                Ok(self.timestamp()?.to_unix_epoch(time_unit)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run to_unix_epoch() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    pub fn dt_from_unix_epoch(&self, time_unit: TimeUnit) -> DaftResult<Self> {
+        if !self.data_type().is_numeric() {
+            return Err(DaftError::ComputeError(format!(
+                "Can only run from_unix_epoch() operation on numeric types, got {}",
+                self.data_type()
+            )));
+        }
+        let physical = self.cast(&DataType::Int64)?;
+        Ok(TimestampArray::new(
+            Field::new(self.name(), DataType::Timestamp(time_unit, None)),
+            physical.i64()?.clone(),
+        )
+        .into_series())
+    }
+
+    pub fn dt_add_months(&self, months: &Self) -> DaftResult<Self> {
+        if !months.data_type().is_integer() && !months.data_type().is_null() {
+            return Err(DaftError::ComputeError(format!(
+                "Expected months in add_months() to be an integer, got {}",
+                months.data_type()
+            )));
+        }
+        let months = months.cast(&DataType::Int64)?;
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.add_months(months.i64()?)?.into_series()),
+            DataType::Timestamp(..) => {
+                Ok(self.timestamp()?.add_months(months.i64()?)?.into_series())
+            }
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run add_months() operation on temporal types, got {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// The number of `unit` boundaries crossed going from `start` to `self`.
+    pub fn dt_date_diff(&self, start: &Self, unit: &str) -> DaftResult<Self> {
+        let as_timestamp = |series: &Self| match series.data_type() {
+            DataType::Date => series.cast(&DataType::Timestamp(TimeUnit::Microseconds, None)),
+            DataType::Timestamp(..) => Ok(series.clone()),
+            _ => Err(DaftError::ComputeError(format!(
+                "Can only run date_diff() operation on temporal types, got {}",
+                series.data_type()
+            ))),
+        };
+        let (end, start) = (as_timestamp(self)?, as_timestamp(start)?);
+        Ok(end
+            .timestamp()?
+            .date_diff(start.timestamp()?, unit)?
+            .into_series())
+    }
+
     pub fn dt_truncate(&self, interval: &str, relative_to: &Self) -> DaftResult<Self> {
         match (self.data_type(), relative_to.data_type()) {
             (DataType::Timestamp(self_tu,self_tz), DataType::Timestamp(start_tu,start_tz)) if self_tu == start_tu && self_tz == start_tz => {
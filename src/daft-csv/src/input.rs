@@ -0,0 +1,230 @@
+//! Preprocessing of raw CSV bytes for the parse options that the tokenizer can't handle natively: the data is
+//! decoded to UTF-8, leading lines are skipped, and multi-byte delimiters are rewritten to a single byte.
+
+use bytes::Bytes;
+use encoding_rs::Decoder;
+use futures::{stream::BoxStream, StreamExt};
+use tokio::io::AsyncRead;
+use tokio_util::io::{ReaderStream, StreamReader};
+
+use crate::{options::MULTI_BYTE_DELIMITER_REPLACEMENT, CsvParseOptions};
+
+/// Wraps `reader` so that it yields preprocessed bytes, or returns it as-is if `parse_options` don't require
+/// any preprocessing.
+pub(crate) fn preprocess_reader<R>(
+    reader: R,
+    parse_options: &CsvParseOptions,
+) -> Box<dyn AsyncRead + Unpin + Send>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    if !parse_options.requires_preprocessing() {
+        return Box::new(reader);
+    }
+    let mut preprocessor = Preprocessor::new(parse_options);
+    let mut chunks = ReaderStream::new(reader);
+    let stream: BoxStream<'static, std::io::Result<Bytes>> = Box::pin(async_stream::try_stream! {
+        while let Some(chunk) = chunks.next().await {
+            let out = preprocessor.push(&chunk?, false)?;
+            if !out.is_empty() {
+                yield Bytes::from(out);
+            }
+        }
+        let out = preprocessor.push(&[], true)?;
+        if !out.is_empty() {
+            yield Bytes::from(out);
+        }
+    });
+    Box::new(StreamReader::new(stream))
+}
+
+struct Preprocessor {
+    decoder: Option<Decoder>,
+    lines_to_skip: usize,
+    delimiter: Option<DelimiterRewriter>,
+}
+
+impl Preprocessor {
+    fn new(parse_options: &CsvParseOptions) -> Self {
+        Self {
+            // Sniff for a BOM so that e.g. big-endian UTF-16 is decoded correctly when labelled as `utf-16`.
+            decoder: parse_options
+                .text_encoding()
+                .map(encoding_rs::Encoding::new_decoder),
+            lines_to_skip: parse_options.skip_rows,
+            delimiter: (parse_options.delimiter.len() > 1).then(|| DelimiterRewriter {
+                delimiter: parse_options.delimiter.clone(),
+                quote: parse_options.quote,
+                escape_char: parse_options.escape_char,
+                in_quotes: false,
+                escaped: false,
+                pending: vec![],
+            }),
+        }
+    }
+
+    /// Preprocesses the next chunk of input, where `last` indicates that the input has been exhausted.
+    fn push(&mut self, input: &[u8], last: bool) -> std::io::Result<Vec<u8>> {
+        let decoded;
+        let mut bytes = match &mut self.decoder {
+            Some(decoder) => {
+                decoded = decode(decoder, input, last);
+                decoded.as_slice()
+            }
+            None => input,
+        };
+        while self.lines_to_skip > 0 && !bytes.is_empty() {
+            match memchr::memchr(b'\n', bytes) {
+                Some(pos) => {
+                    bytes = &bytes[pos + 1..];
+                    self.lines_to_skip -= 1;
+                }
+                None => bytes = &[],
+            }
+        }
+        match &mut self.delimiter {
+            Some(rewriter) => rewriter.push(bytes, last),
+            None => Ok(bytes.to_vec()),
+        }
+    }
+}
+
+fn decode(decoder: &mut Decoder, input: &[u8], last: bool) -> Vec<u8> {
+    let mut out = vec![
+        0;
+        decoder
+            .max_utf8_buffer_length(input.len())
+            .expect("decoded CSV chunk length overflowed usize")
+    ];
+    // Malformed sequences are replaced with U+FFFD rather than failing the read.
+    let (_, read, written, _) = decoder.decode_to_utf8(input, &mut out, last);
+    debug_assert_eq!(read, input.len());
+    out.truncate(written);
+    out
+}
+
+/// Rewrites unquoted occurrences of a multi-byte delimiter to [`MULTI_BYTE_DELIMITER_REPLACEMENT`].
+///
+/// An unquoted [`MULTI_BYTE_DELIMITER_REPLACEMENT`] already in the data would be indistinguishable from a
+/// delimiter afterwards, so it fails the read instead.
+struct DelimiterRewriter {
+    delimiter: Vec<u8>,
+    quote: u8,
+    escape_char: Option<u8>,
+    in_quotes: bool,
+    escaped: bool,
+    /// A trailing prefix of the delimiter, held back until the next chunk shows whether it completes.
+    pending: Vec<u8>,
+}
+
+impl DelimiterRewriter {
+    fn push(&mut self, input: &[u8], last: bool) -> std::io::Result<Vec<u8>> {
+        let mut bytes = std::mem::take(&mut self.pending);
+        bytes.extend_from_slice(input);
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let byte = bytes[i];
+            if self.escaped {
+                self.escaped = false;
+            } else if self.in_quotes && Some(byte) == self.escape_char {
+                self.escaped = true;
+            } else if byte == self.quote {
+                // Doubled quotes toggle twice, so they leave the quoting state unchanged.
+                self.in_quotes = !self.in_quotes;
+            } else if !self.in_quotes {
+                if byte == self.delimiter[0] {
+                    let rest = &bytes[i..];
+                    if rest.starts_with(&self.delimiter) {
+                        out.push(MULTI_BYTE_DELIMITER_REPLACEMENT);
+                        i += self.delimiter.len();
+                        continue;
+                    }
+                    if !last && self.delimiter.starts_with(rest) {
+                        self.pending = rest.to_vec();
+                        break;
+                    }
+                }
+                if byte == MULTI_BYTE_DELIMITER_REPLACEMENT {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!(
+                            "CSV data contains the byte {MULTI_BYTE_DELIMITER_REPLACEMENT:#04X} outside of a quoted field, which is not supported with the multi-byte delimiter {:?}",
+                            String::from_utf8_lossy(&self.delimiter)
+                        ),
+                    ));
+                }
+            }
+            out.push(byte);
+            i += 1;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Preprocessor;
+    use crate::CsvParseOptions;
+
+    fn preprocess(parse_options: &CsvParseOptions, chunks: &[&[u8]]) -> std::io::Result<Vec<u8>> {
+        let mut preprocessor = Preprocessor::new(parse_options);
+        let mut out = vec![];
+        for chunk in chunks {
+            out.extend(preprocessor.push(chunk, false)?);
+        }
+        out.extend(preprocessor.push(&[], true)?);
+        Ok(out)
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter_split_across_chunks() {
+        let parse_options = CsvParseOptions::default().with_delimiter(b"||");
+        let out = preprocess(
+            &parse_options,
+            &[b"a|", b"|b||\"c||d\"\n1|", b"|2|", b"|3|"],
+        )
+        .unwrap();
+        assert_eq!(out, b"a\x1fb\x1f\"c||d\"\n1\x1f2\x1f3|");
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter_rejects_unquoted_unit_separator() {
+        let parse_options = CsvParseOptions::default().with_delimiter(b"||");
+        // The unit separator is kept as-is inside quotes, where the tokenizer doesn't split on it.
+        assert_eq!(
+            preprocess(&parse_options, &[b"a||\"b\x1fc\"\n"]).unwrap(),
+            b"a\x1f\"b\x1fc\"\n"
+        );
+        let err = preprocess(&parse_options, &[b"a||b\x1fc\n"]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("0x1F"), "{err}");
+    }
+
+    #[test]
+    fn test_decode_and_skip_rows() -> crate::Result<()> {
+        let parse_options = CsvParseOptions::default()
+            .with_encoding(Some("latin-1"))?
+            .with_skip_rows(2);
+        let out = preprocess(
+            &parse_options,
+            &[b"# gen\xe9rated\n#", b" preamble\nna", b"me\ncaf\xe9\n"],
+        )
+        .unwrap();
+        assert_eq!(out, "name\ncafé\n".as_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_utf16_with_bom() -> crate::Result<()> {
+        let parse_options = CsvParseOptions::default().with_encoding(Some("utf-16"))?;
+        let mut input = vec![0xFE, 0xFF];
+        input.extend("a,b\n".encode_utf16().flat_map(u16::to_be_bytes));
+        // Split the input in the middle of a code unit.
+        assert_eq!(
+            preprocess(&parse_options, &[&input[..3], &input[3..]]).unwrap(),
+            b"a,b\n"
+        );
+        Ok(())
+    }
+}
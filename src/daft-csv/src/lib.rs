@@ -6,6 +6,7 @@ extern crate test;
 use common_error::DaftError;
 use snafu::Snafu;
 
+mod input;
 pub mod local;
 pub mod metadata;
 pub mod options;
@@ -31,6 +32,16 @@ pub enum Error {
         source: std::char::TryFromCharError,
         val: char,
     },
+    #[snafu(display("Invalid CSV parse option: {message}"))]
+    InvalidParseOption { message: String },
+    #[snafu(display(
+        "CSV record on line {line} has {found} fields but {expected} were expected; set allow_variable_columns to fill missing fields with nulls"
+    ))]
+    TooFewFields {
+        line: u64,
+        found: usize,
+        expected: usize,
+    },
     #[snafu(display("{source}"))]
     ArrowError { source: arrow2::error::Error },
     #[snafu(display("Error joining spawned task: {}", source))]
@@ -48,6 +59,7 @@ impl From<Error> for DaftError {
     fn from(err: Error) -> Self {
         match err {
             Error::IOError { source } => source.into(),
+            Error::InvalidParseOption { .. } => Self::ValueError(err.to_string()),
             _ => Self::External(err.into()),
         }
     }
@@ -13,7 +13,7 @@ use daft_core::{
     prelude::{Schema, Series},
     utils::arrow::cast_array_for_daft_if_needed,
 };
-use daft_decoding::deserialize::deserialize_column;
+use daft_decoding::deserialize::deserialize_column_with_options;
 use daft_dsl::{optimization::get_required_columns, Expr};
 use daft_io::{IOClient, IOStatsRef};
 use daft_recordbatch::RecordBatch;
@@ -553,7 +553,7 @@ fn stream_csv_as_tables(
     let csv_validator = CsvValidator::new(
         num_fields,
         parse_options.quote,
-        parse_options.delimiter,
+        parse_options.field_delimiter(),
         parse_options.escape_char,
         parse_options.double_quote,
     );
@@ -837,17 +837,18 @@ where
 {
     let rdr = ReaderBuilder::new()
         .has_headers(has_header)
-        .delimiter(parse_options.delimiter)
+        .delimiter(parse_options.field_delimiter())
         .double_quote(parse_options.double_quote)
         .quote(parse_options.quote)
         .escape(parse_options.escape_char)
         .comment(parse_options.comment)
-        .flexible(parse_options.allow_variable_columns)
+        .flexible(parse_options.flexible())
         .from_reader(byte_reader);
     // The header should not count towards the limit.
     let limit = limit.map(|limit| limit + (has_header as usize));
     parse_csv_chunk(
         rdr,
+        parse_options,
         projection_indices,
         fields,
         read_daft_fields,
@@ -863,6 +864,7 @@ where
 #[allow(clippy::too_many_arguments)]
 fn parse_csv_chunk<R>(
     mut reader: Reader<R>,
+    parse_options: &CsvParseOptions,
     projection_indices: Arc<Vec<usize>>,
     fields: Vec<arrow2::datatypes::Field>,
     read_daft_fields: Arc<Vec<Arc<daft_core::datatypes::Field>>>,
@@ -885,11 +887,13 @@ where
             .par_iter()
             .enumerate()
             .map(|(i, proj_idx)| {
-                let deserialized_col = deserialize_column(
+                let field = &fields[*proj_idx];
+                let deserialized_col = deserialize_column_with_options(
                     &csv_buffer.buffer[0..rows_read],
                     *proj_idx,
-                    fields[*proj_idx].data_type().clone(),
+                    field.data_type().clone(),
                     0,
+                    &parse_options.decode_options(&field.name),
                 );
                 Series::try_from_field_and_arrow_array(
                     read_daft_fields[i].clone(),
@@ -6,7 +6,7 @@ use common_error::DaftResult;
 use csv_async::ByteRecord;
 use daft_compression::CompressionCodec;
 use daft_core::prelude::Schema;
-use daft_decoding::inference::infer;
+use daft_decoding::inference::infer_with_options;
 use daft_io::{GetResult, IOClient, IOStatsRef};
 use futures::{StreamExt, TryStreamExt};
 use snafu::ResultExt;
@@ -16,7 +16,7 @@ use tokio::{
 };
 use tokio_util::io::StreamReader;
 
-use crate::{schema::merge_schema, CsvParseOptions};
+use crate::{input::preprocess_reader, schema::merge_schema, CsvParseOptions};
 
 const DEFAULT_COLUMN_PREFIX: &str = "column_";
 
@@ -169,7 +169,7 @@ async fn read_csv_schema_from_uncompressed_reader<R>(
     max_bytes: Option<usize>,
 ) -> DaftResult<(Schema, CsvReadStats)>
 where
-    R: AsyncRead + Unpin + Send,
+    R: AsyncRead + Unpin + Send + 'static,
 {
     let (schema, read_stats) =
         read_csv_arrow_schema_from_uncompressed_reader(reader, parse_options, max_bytes).await?;
@@ -183,20 +183,20 @@ async fn read_csv_arrow_schema_from_uncompressed_reader<R>(
     max_bytes: Option<usize>,
 ) -> DaftResult<(arrow2::datatypes::Schema, CsvReadStats)>
 where
-    R: AsyncRead + Unpin + Send,
+    R: AsyncRead + Unpin + Send + 'static,
 {
+    let reader = preprocess_reader(reader, &parse_options);
     let mut reader = AsyncReaderBuilder::new()
         .has_headers(parse_options.has_header)
-        .delimiter(parse_options.delimiter)
+        .delimiter(parse_options.field_delimiter())
         .double_quote(parse_options.double_quote)
         .quote(parse_options.quote)
         .escape(parse_options.escape_char)
         .comment(parse_options.comment)
         .buffer_capacity(max_bytes.unwrap_or(1 << 20).min(1 << 20))
-        .flexible(parse_options.allow_variable_columns)
+        .flexible(parse_options.flexible())
         .create_reader(reader.compat());
-    let (fields, read_stats) =
-        infer_schema(&mut reader, None, max_bytes, parse_options.has_header).await?;
+    let (fields, read_stats) = infer_schema(&mut reader, None, max_bytes, &parse_options).await?;
     Ok((fields.into(), read_stats))
 }
 
@@ -204,12 +204,19 @@ async fn infer_schema<R>(
     reader: &mut AsyncReader<R>,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
-    has_header: bool,
+    parse_options: &CsvParseOptions,
 ) -> arrow2::error::Result<(Vec<arrow2::datatypes::Field>, CsvReadStats)>
 where
     R: futures::AsyncRead + Unpin + Send,
 {
     let mut record = ByteRecord::new();
+    let has_header = parse_options.has_header;
+    // The reader consumes the header, if any, before returning the first record.
+    for _ in 0..parse_options.skip_rows_after_header {
+        if !reader.read_byte_record(&mut record).await? {
+            break;
+        }
+    }
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
     let (headers, did_read_record): (Vec<String>, bool) = if has_header {
@@ -235,6 +242,10 @@ where
             true,
         )
     };
+    let decode_options = headers
+        .iter()
+        .map(|name| parse_options.decode_options(name))
+        .collect::<Vec<_>>();
     // keep track of inferred field types
     let mut column_types: Vec<HashSet<arrow2::datatypes::DataType>> =
         vec![HashSet::new(); headers.len()];
@@ -252,7 +263,7 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_with_options(string, &decode_options[i]));
             }
         }
     }
@@ -271,7 +282,7 @@ where
         m2 += delta * delta2;
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
-                column.insert(infer(string));
+                column.insert(infer_with_options(string, &decode_options[i]));
             }
         }
     }
@@ -361,7 +372,7 @@ mod tests {
 
         let (schema, read_stats) = read_csv_schema(
             file.as_ref(),
-            Some(CsvParseOptions::default().with_delimiter(b'|')),
+            Some(CsvParseOptions::default().with_delimiter(b"|")),
             None,
             io_client,
             None,
@@ -383,6 +394,98 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_csv_schema_local_encoding_multi_byte_delimiter_skip_rows() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_latin1_multi_byte_delimiter.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let (schema, read_stats) = read_csv_schema(
+            file.as_ref(),
+            Some(
+                CsvParseOptions::default()
+                    .with_delimiter(b"||")
+                    .with_encoding(Some("latin-1"))?
+                    .with_skip_rows(2),
+            ),
+            None,
+            io_client,
+            None,
+        )
+        .await?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?,
+        );
+        assert_eq!(read_stats.total_records_read, 20);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_csv_schema_local_null_values_datetime_formats() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/null_values_datetime_formats.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let parse_options = CsvParseOptions::default()
+            .with_null_values(Some(vec!["NA".to_string(), "\\N".to_string()]))
+            .with_datetime_formats(Some(
+                [
+                    ("born".to_string(), "%d/%m/%Y".to_string()),
+                    ("seen".to_string(), "%Y/%m/%d %H:%M:%S".to_string()),
+                ]
+                .into(),
+            ));
+        let (schema, _) = read_csv_schema(
+            file.as_ref(),
+            Some(parse_options.clone()),
+            None,
+            io_client.clone(),
+            None,
+        )
+        .await?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+                Field::new("born", DataType::Date),
+                Field::new("seen", DataType::Timestamp(TimeUnit::Seconds, None)),
+            ])?,
+        );
+
+        // Without the options, the null markers and non-ISO dates fall back to strings.
+        let (schema, _) = read_csv_schema(file.as_ref(), None, None, io_client, None).await?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Utf8),
+                Field::new("name", DataType::Utf8),
+                Field::new("born", DataType::Utf8),
+                Field::new("seen", DataType::Utf8),
+            ])?,
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_csv_schema_local_read_stats() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny.csv", env!("CARGO_MANIFEST_DIR"),);
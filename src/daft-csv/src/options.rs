@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use common_py_serde::impl_bincode_py_state_serialization;
 use daft_core::prelude::SchemaRef;
+use daft_decoding::deserialize::DecodeOptions;
 use daft_dsl::ExprRef;
+use encoding_rs::{Encoding, UTF_8};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "python")]
 use {
@@ -154,24 +158,41 @@ impl CsvConvertOptions {
 }
 impl_bincode_py_state_serialization!(CsvConvertOptions);
 
+/// The byte that multi-byte delimiters are rewritten to before tokenizing (the ASCII unit separator). Data read
+/// with a multi-byte delimiter must not contain it outside of quoted fields.
+pub(crate) const MULTI_BYTE_DELIMITER_REPLACEMENT: u8 = 0x1F;
+
 /// Options for parsing CSV files.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
 pub struct CsvParseOptions {
     pub has_header: bool,
-    pub delimiter: u8,
+    /// The bytes delimiting individual cells, which may span more than one byte.
+    pub delimiter: Vec<u8>,
     pub double_quote: bool,
     pub quote: u8,
     pub escape_char: Option<u8>,
     pub comment: Option<u8>,
     pub allow_variable_columns: bool,
+    /// The label of the text encoding of the data (e.g. `latin-1` or `utf-16`), or `None` for UTF-8.
+    pub encoding: Option<String>,
+    /// Cell values that are read as nulls.
+    pub null_values: Option<Vec<String>>,
+    /// The number of lines to skip at the start of the data, before the header.
+    pub skip_rows: usize,
+    /// The number of records to skip after the header.
+    pub skip_rows_after_header: usize,
+    /// Whether to drop the extra cells of records that are longer than the schema instead of failing.
+    pub truncate_ragged_lines: bool,
+    /// Per-column `chrono` format strings used to parse dates and timestamps.
+    pub datetime_formats: Option<BTreeMap<String, String>>,
 }
 
 impl CsvParseOptions {
     #[must_use]
     pub fn new_internal(
         has_header: bool,
-        delimiter: u8,
+        delimiter: Vec<u8>,
         double_quote: bool,
         quote: u8,
         allow_variable_columns: bool,
@@ -186,21 +207,36 @@ impl CsvParseOptions {
             escape_char,
             comment,
             allow_variable_columns,
+            encoding: None,
+            null_values: None,
+            skip_rows: 0,
+            skip_rows_after_header: 0,
+            truncate_ragged_lines: false,
+            datetime_formats: None,
         }
     }
 
     pub fn new_with_defaults(
         has_header: bool,
-        delimiter: Option<char>,
+        delimiter: Option<&str>,
         double_quote: bool,
         quote: Option<char>,
         allow_variable_columns: bool,
         escape_char: Option<char>,
         comment: Option<char>,
     ) -> super::Result<Self> {
+        let delimiter = match delimiter {
+            Some("") => {
+                return Err(super::Error::InvalidParseOption {
+                    message: "delimiter must not be empty".to_string(),
+                })
+            }
+            Some(delimiter) => delimiter.as_bytes().to_vec(),
+            None => vec![b','],
+        };
         Ok(Self::new_internal(
             has_header,
-            char_to_byte(delimiter)?.unwrap_or(b','),
+            delimiter,
             double_quote,
             char_to_byte(quote)?.unwrap_or(b'"'),
             allow_variable_columns,
@@ -215,8 +251,11 @@ impl CsvParseOptions {
     }
 
     #[must_use]
-    pub fn with_delimiter(self, delimiter: u8) -> Self {
-        Self { delimiter, ..self }
+    pub fn with_delimiter(self, delimiter: impl Into<Vec<u8>>) -> Self {
+        Self {
+            delimiter: delimiter.into(),
+            ..self
+        }
     }
 
     #[must_use]
@@ -252,6 +291,110 @@ impl CsvParseOptions {
             ..self
         }
     }
+
+    pub fn with_encoding(self, encoding: Option<&str>) -> super::Result<Self> {
+        if let Some(label) = encoding
+            && lookup_encoding(label).is_none()
+        {
+            return Err(super::Error::InvalidParseOption {
+                message: format!("unknown encoding: {label}"),
+            });
+        }
+        Ok(Self {
+            encoding: encoding.map(str::to_string),
+            ..self
+        })
+    }
+
+    #[must_use]
+    pub fn with_null_values(self, null_values: Option<Vec<String>>) -> Self {
+        Self {
+            null_values,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_skip_rows(self, skip_rows: usize) -> Self {
+        Self { skip_rows, ..self }
+    }
+
+    #[must_use]
+    pub fn with_skip_rows_after_header(self, skip_rows_after_header: usize) -> Self {
+        Self {
+            skip_rows_after_header,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_truncate_ragged_lines(self, truncate_ragged_lines: bool) -> Self {
+        Self {
+            truncate_ragged_lines,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_datetime_formats(self, datetime_formats: Option<BTreeMap<String, String>>) -> Self {
+        Self {
+            datetime_formats,
+            ..self
+        }
+    }
+
+    /// The single byte that the tokenizer splits cells on.
+    ///
+    /// Multi-byte delimiters are rewritten to the ASCII unit separator before tokenizing.
+    #[must_use]
+    pub fn field_delimiter(&self) -> u8 {
+        match self.delimiter.as_slice() {
+            [delimiter] => *delimiter,
+            _ => MULTI_BYTE_DELIMITER_REPLACEMENT,
+        }
+    }
+
+    /// The encoding the data must be decoded from, or `None` if the data is already UTF-8.
+    pub(crate) fn text_encoding(&self) -> Option<&'static Encoding> {
+        self.encoding
+            .as_deref()
+            .and_then(lookup_encoding)
+            .filter(|encoding| *encoding != UTF_8)
+    }
+
+    /// Whether the raw bytes must be rewritten before they can be tokenized.
+    pub(crate) fn requires_preprocessing(&self) -> bool {
+        self.delimiter.len() != 1 || self.text_encoding().is_some() || self.skip_rows > 0
+    }
+
+    /// Whether the parallel local reader, which tokenizes raw slabs of the file, can honor these options.
+    pub(crate) fn supports_local_reader(&self) -> bool {
+        !self.requires_preprocessing()
+            && self.skip_rows_after_header == 0
+            && !self.truncate_ragged_lines
+    }
+
+    /// The number of fields a record must have, if records shorter than the schema are an error.
+    pub(crate) fn min_record_len(&self, num_fields: usize) -> Option<usize> {
+        (self.truncate_ragged_lines && !self.allow_variable_columns).then_some(num_fields)
+    }
+
+    /// Whether records of differing lengths are accepted by the tokenizer.
+    pub(crate) fn flexible(&self) -> bool {
+        self.allow_variable_columns || self.truncate_ragged_lines
+    }
+
+    /// The options used to decode the values of `column`.
+    pub(crate) fn decode_options(&self, column: &str) -> DecodeOptions<'_> {
+        DecodeOptions {
+            null_values: self.null_values.as_deref().unwrap_or_default(),
+            temporal_format: self
+                .datetime_formats
+                .as_ref()
+                .and_then(|formats| formats.get(column))
+                .map(String::as_str),
+        }
+    }
 }
 
 impl Default for CsvParseOptions {
@@ -268,22 +411,49 @@ impl CsvParseOptions {
     /// # Arguments:
     ///
     /// * `has_headers` - Whether the CSV has a header row; if so, it will be skipped during data parsing.
-    /// * `delimiter` - The character delmiting individual cells in the CSV data.
+    /// * `delimiter` - The string delmiting individual cells in the CSV data, which may be more than one character.
     /// * `double_quote` - Whether double-quote escapes are enabled.
     /// * `quote` - The character to use for quoting strings.
     /// * `escape_char` - The character to use as an escape character.
     /// * `comment` - The character at the start of a line that indicates that the rest of the line is a comment,
     ///   which should be ignored while parsing.
+    /// * `encoding` - The label of the text encoding of the CSV data, e.g. `latin-1` or `utf-16`; defaults to UTF-8.
+    /// * `null_values` - Cell values that should be read as nulls.
+    /// * `skip_rows` - The number of lines to skip at the start of the CSV data, before the header.
+    /// * `skip_rows_after_header` - The number of records to skip after the header.
+    /// * `truncate_ragged_lines` - Whether to drop the extra cells of records that are longer than the schema.
+    /// * `datetime_formats` - Per-column `chrono` format strings used to parse dates and timestamps.
+    #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (has_header=true, delimiter=None, double_quote=false, quote=None, allow_variable_columns=false, escape_char=None, comment=None))]
+    #[pyo3(signature = (
+        has_header=true,
+        delimiter=None,
+        double_quote=false,
+        quote=None,
+        allow_variable_columns=false,
+        escape_char=None,
+        comment=None,
+        encoding=None,
+        null_values=None,
+        skip_rows=0,
+        skip_rows_after_header=0,
+        truncate_ragged_lines=false,
+        datetime_formats=None
+    ))]
     pub fn new(
         has_header: bool,
-        delimiter: Option<char>,
+        delimiter: Option<&str>,
         double_quote: bool,
         quote: Option<char>,
         allow_variable_columns: bool,
         escape_char: Option<char>,
         comment: Option<char>,
+        encoding: Option<&str>,
+        null_values: Option<Vec<String>>,
+        skip_rows: usize,
+        skip_rows_after_header: usize,
+        truncate_ragged_lines: bool,
+        datetime_formats: Option<BTreeMap<String, String>>,
     ) -> PyResult<Self> {
         Ok(Self::new_with_defaults(
             has_header,
@@ -293,7 +463,13 @@ impl CsvParseOptions {
             allow_variable_columns,
             escape_char,
             comment,
-        )?)
+        )?
+        .with_encoding(encoding)?
+        .with_null_values(null_values)
+        .with_skip_rows(skip_rows)
+        .with_skip_rows_after_header(skip_rows_after_header)
+        .with_truncate_ragged_lines(truncate_ragged_lines)
+        .with_datetime_formats(datetime_formats))
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
@@ -309,6 +485,13 @@ impl CsvParseOptions {
     }
 }
 
+/// Looks up an encoding by its WHATWG label, also accepting Python-style spellings such as `latin-1` or `utf_16`.
+fn lookup_encoding(label: &str) -> Option<&'static Encoding> {
+    Encoding::for_label(label.as_bytes())
+        .or_else(|| Encoding::for_label(label.replace('_', "-").as_bytes()))
+        .or_else(|| Encoding::for_label(label.replace(['-', '_'], "").as_bytes()))
+}
+
 pub fn char_to_byte(c: Option<char>) -> Result<Option<u8>, super::Error> {
     match c.map(u8::try_from).transpose() {
         Ok(b) => Ok(b),
@@ -14,7 +14,7 @@ use common_runtime::get_io_runtime;
 use csv_async::AsyncReader;
 use daft_compression::CompressionCodec;
 use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
-use daft_decoding::deserialize::deserialize_column;
+use daft_decoding::deserialize::deserialize_column_with_options;
 use daft_dsl::optimization::get_required_columns;
 use daft_io::{parse_url, GetResult, IOClient, IOStatsRef, SourceType};
 use daft_recordbatch::RecordBatch;
@@ -35,8 +35,8 @@ use tokio::{
 use tokio_util::io::StreamReader;
 
 use crate::{
-    metadata::read_csv_schema_single, ArrowSnafu, CsvConvertOptions, CsvParseOptions,
-    CsvReadOptions,
+    input::preprocess_reader, metadata::read_csv_schema_single, ArrowSnafu, CSVSnafu,
+    CsvConvertOptions, CsvParseOptions, CsvReadOptions,
 };
 
 trait ByteRecordChunkStream: Stream<Item = super::Result<Vec<read_async::ByteRecord>>> {}
@@ -158,7 +158,12 @@ pub async fn stream_csv(
     let uri = uri.as_str();
     let (source_type, _) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File)
+        && !is_compressed
+        && parse_options
+            .as_ref()
+            .is_none_or(CsvParseOptions::supports_local_reader)
+    {
         let stream = stream_csv_local(
             uri,
             convert_options,
@@ -235,7 +240,12 @@ async fn read_csv_single_into_table(
 ) -> DaftResult<RecordBatch> {
     let (source_type, _) = parse_url(uri)?;
     let is_compressed = CompressionCodec::from_uri(uri).is_some();
-    if matches!(source_type, SourceType::File) && !is_compressed {
+    if matches!(source_type, SourceType::File)
+        && !is_compressed
+        && parse_options
+            .as_ref()
+            .is_none_or(CsvParseOptions::supports_local_reader)
+    {
         return read_csv_local(
             uri,
             convert_options,
@@ -530,19 +540,22 @@ async fn read_csv_single_into_stream(
         Some(compression) => Box::new(compression.to_decoder(reader)),
         None => reader,
     };
+    let reader = preprocess_reader(reader, &parse_options);
     let reader = AsyncReaderBuilder::new()
         .has_headers(parse_options.has_header)
-        .delimiter(parse_options.delimiter)
+        .delimiter(parse_options.field_delimiter())
         .double_quote(parse_options.double_quote)
         .quote(parse_options.quote)
         .escape(parse_options.escape_char)
         .comment(parse_options.comment)
         .buffer_capacity(buffer_size)
-        .flexible(parse_options.allow_variable_columns)
+        .flexible(parse_options.flexible())
         .create_reader(reader.compat());
     let read_stream = read_into_byterecord_chunk_stream(
         reader,
         schema.fields.len(),
+        parse_options.skip_rows_after_header,
+        parse_options.min_record_len(schema.fields.len()),
         convert_options.limit,
         chunk_size,
         estimated_mean_row_size,
@@ -556,14 +569,18 @@ async fn read_csv_single_into_stream(
         read_stream,
         Arc::new(fields.clone()),
         projection_indices,
+        Arc::new(parse_options),
     )?;
 
     Ok((stream, fields))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn read_into_byterecord_chunk_stream<R>(
     mut reader: AsyncReader<Compat<R>>,
     num_fields: usize,
+    skip_rows_after_header: usize,
+    min_record_len: Option<usize>,
     num_rows: Option<usize>,
     chunk_size: usize,
     estimated_mean_row_size: Option<f64>,
@@ -577,6 +594,12 @@ where
     let mut estimated_std_row_size = estimated_std_row_size.unwrap_or(20f64);
     // Stream of unparsed CSV byte record chunks.
     async_stream::try_stream! {
+        let mut skipped_record = read_async::ByteRecord::new();
+        for _ in 0..skip_rows_after_header {
+            if !reader.read_byte_record(&mut skipped_record).await.context(CSVSnafu {})? {
+                break;
+            }
+        }
         // Number of rows read in last read.
         let mut rows_read = 1;
         // Total number of rows read across all reads.
@@ -614,6 +637,15 @@ where
             estimated_std_row_size = (m2 / ((total_rows_read - 1) as f64)).sqrt();
 
             chunk_buffer.truncate(rows_read);
+            if let Some(min_record_len) = min_record_len
+                && let Some(record) = chunk_buffer.iter().find(|record| record.len() < min_record_len)
+            {
+                Err(super::Error::TooFewFields {
+                    line: record.position().map_or(0, csv_async::Position::line),
+                    found: record.len(),
+                    expected: min_record_len,
+                })?;
+            }
             if rows_read > 0 {
                 yield chunk_buffer;
             }
@@ -625,6 +657,7 @@ fn parse_into_column_array_chunk_stream(
     stream: impl ByteRecordChunkStream + Send,
     fields: Arc<Vec<arrow2::datatypes::Field>>,
     projection_indices: Arc<Vec<usize>>,
+    parse_options: Arc<CsvParseOptions>,
 ) -> DaftResult<impl TableStream + Send> {
     // Parsing stream: we spawn background tokio + rayon tasks so we can pipeline chunk parsing with chunk reading, and
     // we further parse each chunk column in parallel on the rayon threadpool.
@@ -644,6 +677,7 @@ fn parse_into_column_array_chunk_stream(
 
     Ok(stream.map_ok(move |record| {
         let (fields, projection_indices) = (fields.clone(), projection_indices.clone());
+        let parse_options = parse_options.clone();
         let read_schema = read_schema.clone();
         let read_daft_fields = read_daft_fields.clone();
         tokio::spawn(async move {
@@ -654,11 +688,13 @@ fn parse_into_column_array_chunk_stream(
                         .par_iter()
                         .enumerate()
                         .map(|(i, proj_idx)| {
-                            let deserialized_col = deserialize_column(
+                            let field = &fields[*proj_idx];
+                            let deserialized_col = deserialize_column_with_options(
                                 record.as_slice(),
                                 *proj_idx,
-                                fields[*proj_idx].data_type().clone(),
+                                field.data_type().clone(),
                                 0,
+                                &parse_options.decode_options(&field.name),
                             );
                             Series::try_from_field_and_arrow_array(
                                 read_daft_fields[i].clone(),
@@ -921,7 +957,7 @@ mod tests {
         let table = read_csv(
             file.as_ref(),
             None,
-            Some(CsvParseOptions::default().with_delimiter(b'|')),
+            Some(CsvParseOptions::default().with_delimiter(b"|")),
             None,
             io_client,
             None,
@@ -957,6 +993,197 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_csv_read_local_encoding_multi_byte_delimiter_skip_rows() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_latin1_multi_byte_delimiter.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(
+                CsvParseOptions::default()
+                    .with_delimiter(b"||")
+                    .with_encoding(Some("latin-1"))?
+                    .with_skip_rows(2),
+            ),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 20);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("sepal.length", DataType::Float64),
+                Field::new("sepal.width", DataType::Float64),
+                Field::new("petal.length", DataType::Float64),
+                Field::new("petal.width", DataType::Float64),
+                Field::new("variety", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(table.get_column("variety")?.utf8()?.get(0), Some("Sétosa"));
+        assert_eq!(table.get_column("petal.width")?.f64()?.get(0), Some(0.2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_null_values_datetime_formats() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/null_values_datetime_formats.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(
+                CsvParseOptions::default()
+                    .with_null_values(Some(vec!["NA".to_string(), "\\N".to_string()]))
+                    .with_datetime_formats(Some(
+                        [
+                            ("born".to_string(), "%d/%m/%Y".to_string()),
+                            ("seen".to_string(), "%Y/%m/%d %H:%M:%S".to_string()),
+                        ]
+                        .into(),
+                    )),
+            ),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table.len(), 4);
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+                Field::new("born", DataType::Date),
+                Field::new("seen", DataType::Timestamp(TimeUnit::Seconds, None)),
+            ])?
+            .into(),
+        );
+        assert_eq!(table.get_column("id")?.to_arrow().null_count(), 2);
+        // Empty strings are only read as nulls for non-string types.
+        assert_eq!(table.get_column("name")?.to_arrow().null_count(), 1);
+        assert_eq!(table.get_column("born")?.to_arrow().null_count(), 2);
+        assert_eq!(table.get_column("seen")?.to_arrow().null_count(), 2);
+        // 2024-01-03
+        assert_eq!(
+            table.get_column("born")?.date()?.physical.get(0),
+            Some(19725)
+        );
+        // 2023-12-25T00:00:00
+        assert_eq!(
+            table.get_column("seen")?.timestamp()?.physical.get(2),
+            Some(1_703_462_400)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_skip_rows_after_header_truncate_ragged_lines() -> DaftResult<()> {
+        let file = format!("{}/test/ragged_lines.csv", env!("CARGO_MANIFEST_DIR"),);
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let table = read_csv(
+            file.as_ref(),
+            None,
+            Some(
+                CsvParseOptions::default()
+                    .with_skip_rows_after_header(1)
+                    .with_truncate_ragged_lines(true),
+            ),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(
+            table.schema,
+            Schema::new(vec![
+                Field::new("id", DataType::Int64),
+                Field::new("name", DataType::Utf8),
+            ])?
+            .into(),
+        );
+        assert_eq!(
+            table
+                .get_column("id")?
+                .i64()?
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![Some(&1), Some(&2), Some(&3)]
+        );
+        assert_eq!(
+            table
+                .get_column("name")?
+                .utf8()?
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![Some("alice"), Some("bob"), Some("carol")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_read_local_truncate_ragged_lines_too_few_fields() -> DaftResult<()> {
+        let file = format!(
+            "{}/test/iris_tiny_invalid_header_cols_mismatch.csv",
+            env!("CARGO_MANIFEST_DIR"),
+        );
+
+        let mut io_config = IOConfig::default();
+        io_config.s3.anonymous = true;
+
+        let io_client = Arc::new(IOClient::new(io_config.into())?);
+
+        let err = read_csv(
+            file.as_ref(),
+            None,
+            Some(CsvParseOptions::default().with_truncate_ragged_lines(true)),
+            None,
+            io_client,
+            None,
+            true,
+            None,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("CSV record on line 2 has 4 fields but 5 were expected"),
+            "{}",
+            err
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_csv_read_local_double_quote() -> DaftResult<()> {
         let file = format!(
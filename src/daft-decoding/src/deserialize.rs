@@ -32,6 +32,29 @@ pub(crate) const ALL_NAIVE_TIMESTAMP_FMTS: &[&str] = &[
 pub(crate) const ALL_TIMESTAMP_FMTS: &[&str] = &[ISO8601, RFC3339_WITH_SPACE];
 pub(crate) const ALL_NAIVE_DATE_FMTS: &[&str] = &[ISO8601_DATE, ISO8601_DATE_SLASHES];
 
+/// Options that control how the raw bytes of a column are decoded.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DecodeOptions<'a> {
+    /// Values that are decoded as null, in addition to empty fields for non-string types.
+    pub null_values: &'a [String],
+    /// A [`chrono`] format string used to parse dates and timestamps instead of the default formats.
+    pub temporal_format: Option<&'a str>,
+}
+
+impl DecodeOptions<'_> {
+    /// Whether `bytes` matches one of the configured null values.
+    #[inline]
+    #[must_use]
+    pub fn is_null_value(&self, bytes: &[u8]) -> bool {
+        self.null_values.iter().any(|v| v.as_bytes() == bytes)
+    }
+
+    #[inline]
+    fn field<'r, B: ByteRecordGeneric>(&self, row: &'r B, column: usize) -> Option<&'r [u8]> {
+        row.get(column).filter(|bytes| !self.is_null_value(bytes))
+    }
+}
+
 // Ideally this trait should not be needed and both `csv` and `csv_async` crates would share
 // the same `ByteRecord` struct. Unfortunately, they do not and thus we must use generics
 // over this trait and materialize the generics for each struct.
@@ -63,13 +86,14 @@ fn deserialize_primitive<T, B: ByteRecordGeneric, F>(
     rows: &[B],
     column: usize,
     datatype: DataType,
+    options: &DecodeOptions,
     mut op: F,
 ) -> Box<dyn Array>
 where
     T: NativeType,
     F: FnMut(&[u8]) -> Option<T>,
 {
-    let iter = rows.iter().map(|row| match row.get(column) {
+    let iter = rows.iter().map(|row| match options.field(row, column) {
         Some(bytes) => {
             if bytes.is_empty() {
                 return None;
@@ -126,12 +150,17 @@ fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i
 }
 
 #[inline]
-fn deserialize_boolean<B, F>(rows: &[B], column: usize, op: F) -> Box<dyn Array>
+fn deserialize_boolean<B, F>(
+    rows: &[B],
+    column: usize,
+    options: &DecodeOptions,
+    op: F,
+) -> Box<dyn Array>
 where
     B: ByteRecordGeneric,
     F: Fn(&[u8]) -> Option<bool>,
 {
-    let iter = rows.iter().map(|row| match row.get(column) {
+    let iter = rows.iter().map(|row| match options.field(row, column) {
         Some(bytes) => {
             if bytes.is_empty() {
                 return None;
@@ -144,7 +173,11 @@ where
 }
 
 #[inline]
-fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize) -> Box<dyn Array> {
+fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    options: &DecodeOptions,
+) -> Box<dyn Array> {
     let expected_size = rows
         .iter()
         .map(|row| match row.get(column) {
@@ -153,7 +186,7 @@ fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize)
         })
         .sum::<usize>();
 
-    let iter = rows.iter().map(|row| match row.get(column) {
+    let iter = rows.iter().map(|row| match options.field(row, column) {
         Some(bytes) => to_utf8(bytes),
         None => None,
     });
@@ -167,6 +200,7 @@ fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize)
 fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
+    options: &DecodeOptions,
 ) -> Box<dyn Array> {
     let expected_size = rows
         .iter()
@@ -176,7 +210,7 @@ fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
         })
         .sum::<usize>();
 
-    let iter = rows.iter().map(|row| row.get(column));
+    let iter = rows.iter().map(|row| options.field(row, column));
     let mut mu = MutableBinaryArray::<O>::with_capacities(rows.len(), expected_size);
     mu.extend_trusted_len(iter);
     let array: BinaryArray<O> = mu.into();
@@ -239,13 +273,67 @@ pub fn deserialize_datetime<T: chrono::TimeZone>(
     None
 }
 
+#[inline]
+pub fn deserialize_naive_date_with_format(string: &str, fmt: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(string, fmt).ok()
+}
+
+/// Parses `string` as a naive datetime using `fmt`, falling back to midnight if `fmt` only describes a date.
+#[inline]
+pub fn deserialize_naive_datetime_with_format(
+    string: &str,
+    fmt: &str,
+) -> Option<chrono::NaiveDateTime> {
+    chrono::NaiveDateTime::parse_from_str(string, fmt)
+        .ok()
+        .or_else(|| {
+            deserialize_naive_date_with_format(string, fmt)
+                .map(|date| date.and_time(chrono::NaiveTime::MIN))
+        })
+}
+
+/// Parses `string` as a datetime using `fmt`, interpreting it in `tz` if `fmt` has no offset.
+#[inline]
+pub fn deserialize_datetime_with_format<T: chrono::TimeZone>(
+    string: &str,
+    tz: &T,
+    fmt: &str,
+) -> Option<chrono::DateTime<T>> {
+    chrono::DateTime::parse_from_str(string, fmt)
+        .ok()
+        .map(|dt| dt.with_timezone(tz))
+        .or_else(|| {
+            deserialize_naive_datetime_with_format(string, fmt)
+                .and_then(|dt| tz.from_local_datetime(&dt).single())
+        })
+}
+
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
 #[inline]
 pub fn deserialize_column<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+) -> Result<Box<dyn Array>> {
+    deserialize_column_with_options(
+        rows,
+        column,
+        datatype,
+        line_number,
+        &DecodeOptions::default(),
+    )
+}
+
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, honoring the null values
+/// and temporal format in `options`.
+#[inline]
+pub fn deserialize_column_with_options<B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
     datatype: DataType,
     _line_number: usize,
+    options: &DecodeOptions,
 ) -> Result<Box<dyn Array>> {
     use DataType::{
         Binary, Boolean, Date32, Date64, Decimal, Float32, Float64, Int16, Int32, Int64, Int8,
@@ -253,7 +341,7 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
         Utf8,
     };
     Ok(match datatype {
-        Boolean => deserialize_boolean(rows, column, |bytes| {
+        Boolean => deserialize_boolean(rows, column, options, |bytes| {
             if bytes.eq_ignore_ascii_case(b"false") {
                 Some(false)
             } else if bytes.eq_ignore_ascii_case(b"true") {
@@ -262,49 +350,55 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
                 None
             }
         }),
-        Int8 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int8 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<i8>(bytes).ok()
         }),
-        Int16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int16 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<i16>(bytes).ok()
         }),
-        Int32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int32 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<i32>(bytes).ok()
         }),
-        Int64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int64 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<i64>(bytes).ok()
         }),
-        UInt8 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt8 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<u8>(bytes).ok()
         }),
-        UInt16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt16 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<u16>(bytes).ok()
         }),
-        UInt32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt32 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<u32>(bytes).ok()
         }),
-        UInt64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt64 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             atoi_simd::parse_skipped::<u64>(bytes).ok()
         }),
-        Float32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float32 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             fast_float::parse::<f32, _>(bytes).ok()
         }),
-        Float64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float64 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             fast_float::parse::<f64, _>(bytes).ok()
         }),
-        Date32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Date32 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             let mut last_fmt_idx = 0;
             to_utf8(bytes)
-                .and_then(|x| deserialize_naive_date(x, &mut last_fmt_idx))
+                .and_then(|x| match options.temporal_format {
+                    Some(fmt) => deserialize_naive_date_with_format(x, fmt),
+                    None => deserialize_naive_date(x, &mut last_fmt_idx),
+                })
                 .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE)
         }),
-        Date64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Date64 => deserialize_primitive(rows, column, datatype, options, |bytes| {
             let mut last_fmt_idx = 0;
             to_utf8(bytes)
-                .and_then(|x| deserialize_naive_datetime(x, &mut last_fmt_idx))
+                .and_then(|x| match options.temporal_format {
+                    Some(fmt) => deserialize_naive_datetime_with_format(x, fmt),
+                    None => deserialize_naive_datetime(x, &mut last_fmt_idx),
+                })
                 .map(|x| x.and_utc().timestamp_millis())
         }),
-        Time32(time_unit) => deserialize_primitive(rows, column, datatype, |bytes| {
+        Time32(time_unit) => deserialize_primitive(rows, column, datatype, options, |bytes| {
             let factor = get_factor_from_timeunit(time_unit);
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveTime>().ok())
@@ -315,7 +409,7 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
                         + x.nanosecond() / (1_000_000_000 / factor)) as i32
                 })
         }),
-        Time64(time_unit) => deserialize_primitive(rows, column, datatype, |bytes| {
+        Time64(time_unit) => deserialize_primitive(rows, column, datatype, options, |bytes| {
             let factor: u64 = get_factor_from_timeunit(time_unit).into();
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveTime>().ok())
@@ -329,9 +423,12 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
         }),
         Timestamp(time_unit, None) => {
             let mut last_fmt_idx = 0;
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_primitive(rows, column, datatype, options, |bytes| {
                 to_utf8(bytes)
-                    .and_then(|s| deserialize_naive_datetime(s, &mut last_fmt_idx))
+                    .and_then(|s| match options.temporal_format {
+                        Some(fmt) => deserialize_naive_datetime_with_format(s, fmt),
+                        None => deserialize_naive_datetime(s, &mut last_fmt_idx),
+                    })
                     .and_then(|dt| match time_unit {
                         TimeUnit::Second => Some(dt.and_utc().timestamp()),
                         TimeUnit::Millisecond => Some(dt.and_utc().timestamp_millis()),
@@ -343,9 +440,12 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
         Timestamp(time_unit, Some(ref tz)) => {
             let tz = temporal_conversions::parse_offset(tz)?;
             let mut last_fmt_idx = 0;
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_primitive(rows, column, datatype, options, |bytes| {
                 to_utf8(bytes)
-                    .and_then(|x| deserialize_datetime(x, &tz, &mut last_fmt_idx))
+                    .and_then(|x| match options.temporal_format {
+                        Some(fmt) => deserialize_datetime_with_format(x, &tz, fmt),
+                        None => deserialize_datetime(x, &tz, &mut last_fmt_idx),
+                    })
                     .and_then(|dt| match time_unit {
                         TimeUnit::Second => Some(dt.timestamp()),
                         TimeUnit::Millisecond => Some(dt.timestamp_millis()),
@@ -354,13 +454,13 @@ pub fn deserialize_column<B: ByteRecordGeneric>(
                     })
             })
         }
-        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
+        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, options, |x| {
             deserialize_decimal(x, precision, scale)
         }),
-        Utf8 => deserialize_utf8::<i32, _>(rows, column),
-        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
-        Binary => deserialize_binary::<i32, _>(rows, column),
-        LargeBinary => deserialize_binary::<i64, _>(rows, column),
+        Utf8 => deserialize_utf8::<i32, _>(rows, column, options),
+        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column, options),
+        Binary => deserialize_binary::<i32, _>(rows, column, options),
+        LargeBinary => deserialize_binary::<i64, _>(rows, column, options),
         Null => deserialize_null(rows, column),
         other => {
             return Err(Error::NotYetImplemented(format!(
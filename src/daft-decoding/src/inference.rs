@@ -1,7 +1,9 @@
 use arrow2::datatypes::{DataType, TimeUnit};
 use chrono::Timelike;
 
-use crate::deserialize::{ALL_NAIVE_DATE_FMTS, ALL_NAIVE_TIMESTAMP_FMTS, ALL_TIMESTAMP_FMTS};
+use crate::deserialize::{
+    DecodeOptions, ALL_NAIVE_DATE_FMTS, ALL_NAIVE_TIMESTAMP_FMTS, ALL_TIMESTAMP_FMTS,
+};
 
 /// Infers [`DataType`] from `bytes`
 /// # Implementation
@@ -33,6 +35,40 @@ pub fn infer(bytes: &[u8]) -> arrow2::datatypes::DataType {
     }
 }
 
+/// Infers [`DataType`] from `bytes` like [`infer`], honoring the null values and temporal format in `options`.
+/// # Implementation
+/// * configured null values are mapped to [`DataType::Null`]
+/// * parsable with the temporal format as a datetime with an offset is mapped to [`DataType::Timestamp`] of the parsed offset
+/// * parsable with the temporal format as a naive datetime is mapped to [`DataType::Timestamp`] without a timezone
+/// * parsable with the temporal format as a date is mapped to [`DataType::Date32`]
+/// * anything else is inferred by [`infer`]
+#[must_use]
+pub fn infer_with_options(bytes: &[u8], options: &DecodeOptions) -> DataType {
+    if options.is_null_value(bytes) {
+        return DataType::Null;
+    }
+    options
+        .temporal_format
+        .zip(simdutf8::basic::from_utf8(bytes).ok())
+        .and_then(|(fmt, string)| infer_temporal_with_format(string, fmt))
+        .unwrap_or_else(|| infer(bytes))
+}
+
+fn infer_temporal_with_format(string: &str, fmt: &str) -> Option<DataType> {
+    if let Ok(dt) = chrono::DateTime::parse_from_str(string, fmt) {
+        let time_unit = nanoseconds_to_time_unit(dt.nanosecond());
+        return Some(DataType::Timestamp(time_unit, Some(format_offset(&dt))));
+    }
+    if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(string, fmt) {
+        let time_unit = nanoseconds_to_time_unit(dt.nanosecond());
+        return Some(DataType::Timestamp(time_unit, None));
+    }
+    if chrono::NaiveDate::parse_from_str(string, fmt).is_ok() {
+        return Some(DataType::Date32);
+    }
+    None
+}
+
 #[must_use]
 pub fn infer_string(string: &str) -> DataType {
     if is_date(string) {
@@ -103,16 +139,20 @@ fn is_naive_datetime(string: &str) -> Option<TimeUnit> {
 fn is_datetime(string: &str) -> Option<(TimeUnit, String)> {
     for fmt in ALL_TIMESTAMP_FMTS {
         if let Ok(dt) = chrono::DateTime::parse_from_str(string, fmt) {
-            let offset = dt.offset().local_minus_utc();
-            let hours = offset / 60 / 60;
-            let minutes = offset / 60 - hours * 60;
             let time_unit = nanoseconds_to_time_unit(dt.nanosecond());
-            return Some((time_unit, format!("{hours:+03}:{minutes:02}")));
+            return Some((time_unit, format_offset(&dt)));
         }
     }
     None
 }
 
+fn format_offset(dt: &chrono::DateTime<chrono::FixedOffset>) -> String {
+    let offset = dt.offset().local_minus_utc();
+    let hours = offset / 60 / 60;
+    let minutes = offset / 60 - hours * 60;
+    format!("{hours:+03}:{minutes:02}")
+}
+
 fn nanoseconds_to_time_unit(ns: u32) -> TimeUnit {
     if ns % 1_000 != 0 {
         TimeUnit::Nanosecond
@@ -1,6 +1,7 @@
 mod display;
 #[cfg(test)]
 mod tests;
+mod window;
 
 use std::{
     any::Any,
@@ -14,7 +15,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use common_hashable_float_wrapper::FloatWrapper;
-use common_treenode::TreeNode;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::{
     datatypes::{
         try_mean_aggregation_supertype, try_stddev_aggregation_supertype, try_sum_supertype,
@@ -26,6 +27,7 @@ use daft_core::{
 };
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
+pub use window::{WindowBoundary, WindowExpr, WindowFrame, WindowFrameType, WindowSpec};
 
 use super::functions::FunctionExpr;
 use crate::{
@@ -241,6 +243,13 @@ pub enum Expr {
 
     #[display("exists {_0}")]
     Exists(Subquery),
+
+    /// A window function that has not been bound to a window with [`Expr::over`] yet.
+    #[display("{_0}")]
+    WindowFunction(WindowExpr),
+
+    #[display("{_0} over ({_1})")]
+    Over(WindowExpr, WindowSpec),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
@@ -331,6 +340,15 @@ pub fn resolved_col<S: Into<Arc<str>>>(name: S) -> ExprRef {
     Expr::Column(Column::Resolved(ResolvedColumn::Basic(name.into()))).into()
 }
 
+/// Resolved column which refers to either the left or right input of a join
+pub fn join_side_col<S: Into<Arc<str>>>(name: S, side: JoinSide) -> ExprRef {
+    Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(
+        name.into(),
+        side,
+    )))
+    .into()
+}
+
 pub fn binary_op(op: Operator, left: ExprRef, right: ExprRef) -> ExprRef {
     Expr::BinaryOp { op, left, right }.into()
 }
@@ -823,6 +841,72 @@ impl Expr {
         Self::InSubquery(self, subquery).into()
     }
 
+    pub fn row_number() -> ExprRef {
+        Self::WindowFunction(WindowExpr::RowNumber).into()
+    }
+
+    pub fn rank() -> ExprRef {
+        Self::WindowFunction(WindowExpr::Rank).into()
+    }
+
+    pub fn dense_rank() -> ExprRef {
+        Self::WindowFunction(WindowExpr::DenseRank).into()
+    }
+
+    pub fn lag(self: ExprRef, offset: u64, default: Option<ExprRef>) -> ExprRef {
+        Self::WindowFunction(WindowExpr::Offset {
+            input: self,
+            offset: -(offset as i64),
+            default,
+        })
+        .into()
+    }
+
+    pub fn lead(self: ExprRef, offset: u64, default: Option<ExprRef>) -> ExprRef {
+        Self::WindowFunction(WindowExpr::Offset {
+            input: self,
+            offset: offset as i64,
+            default,
+        })
+        .into()
+    }
+
+    /// Evaluates the window functions and aggregations in this expression over `window_spec`.
+    ///
+    /// Wrapping expressions such as aliases and casts are kept outside of the window, so
+    /// `sum(x).cast(int64).over(w)` becomes `sum(x).over(w).cast(int64)`.
+    pub fn over(self: ExprRef, window_spec: WindowSpec) -> DaftResult<ExprRef> {
+        let mut found = false;
+        let transformed = self.clone().transform_down(|e| match e.as_ref() {
+            Self::Agg(agg_expr) => {
+                found = true;
+                Ok(Transformed::new(
+                    Self::Over(WindowExpr::Agg(agg_expr.clone()), window_spec.clone()).into(),
+                    true,
+                    TreeNodeRecursion::Jump,
+                ))
+            }
+            Self::WindowFunction(window_expr) => {
+                found = true;
+                Ok(Transformed::new(
+                    Self::Over(window_expr.clone(), window_spec.clone()).into(),
+                    true,
+                    TreeNodeRecursion::Jump,
+                ))
+            }
+            Self::Over(..) => Err(DaftError::ValueError(format!(
+                "Window functions cannot be nested, found: {e}"
+            ))),
+            _ => Ok(Transformed::no(e)),
+        })?;
+        if !found {
+            return Err(DaftError::ValueError(format!(
+                "Expected a window function or aggregation to evaluate over a window, found: {self}"
+            )));
+        }
+        Ok(transformed.data)
+    }
+
     pub fn semantic_id(&self, schema: &Schema) -> FieldID {
         match self {
             // Base case - anonymous column reference.
@@ -934,6 +1018,12 @@ impl Expr {
 
                 FieldID::new(format!("(EXISTS {subquery_id})"))
             }
+            Self::WindowFunction(window_expr) => window_expr.semantic_id(schema),
+            Self::Over(window_expr, window_spec) => {
+                let child_id = window_expr.semantic_id(schema);
+                let spec_id = window_spec.semantic_id(schema);
+                FieldID::new(format!("{child_id}.over({spec_id})"))
+            }
         }
     }
 
@@ -972,6 +1062,12 @@ impl Expr {
             }
             Self::FillNull(expr, fill_value) => vec![expr.clone(), fill_value.clone()],
             Self::ScalarFunction(sf) => sf.inputs.clone(),
+            Self::WindowFunction(window_expr) => window_expr.children(),
+            Self::Over(window_expr, window_spec) => {
+                let mut children = window_expr.children();
+                children.extend(window_spec.children());
+                children
+            }
         }
     }
 
@@ -1070,6 +1166,17 @@ impl Expr {
                     inputs: children,
                 })
             }
+            Self::WindowFunction(window_expr) => {
+                Self::WindowFunction(window_expr.with_new_children(children))
+            }
+            Self::Over(window_expr, window_spec) => {
+                let mut expr_children = children;
+                let spec_children = expr_children.split_off(window_expr.children().len());
+                Self::Over(
+                    window_expr.with_new_children(expr_children),
+                    window_spec.with_new_children(spec_children),
+                )
+            }
         }
     }
 
@@ -1267,6 +1374,11 @@ impl Expr {
             }
             Self::InSubquery(expr, _) => Ok(Field::new(expr.name(), DataType::Boolean)),
             Self::Exists(_) => Ok(Field::new("exists", DataType::Boolean)),
+            Self::WindowFunction(window_expr) => window_expr.to_field(schema),
+            Self::Over(window_expr, window_spec) => {
+                window_spec.validate(window_expr, schema)?;
+                window_expr.to_field(schema)
+            }
         }
     }
 
@@ -1306,6 +1418,7 @@ impl Expr {
             Self::Subquery(subquery) => subquery.name(),
             Self::InSubquery(expr, _) => expr.name(),
             Self::Exists(subquery) => subquery.name(),
+            Self::WindowFunction(window_expr) | Self::Over(window_expr, _) => window_expr.name(),
         }
     }
 
@@ -1385,6 +1498,8 @@ impl Expr {
                 | Expr::Subquery(..)
                 | Expr::InSubquery(..)
                 | Expr::Exists(..)
+                | Expr::WindowFunction(..)
+                | Expr::Over(..)
                 | Expr::Column(..) => Err(io::Error::other(
                     "Unsupported expression for SQL translation",
                 )),
@@ -1438,6 +1553,7 @@ impl Expr {
             } => if_true.has_compute() || if_false.has_compute() || predicate.has_compute(),
             Self::InSubquery(expr, _) => expr.has_compute(),
             Self::List(..) => true,
+            Self::WindowFunction(..) | Self::Over(..) => true,
         }
     }
 
@@ -1547,6 +1663,10 @@ pub fn has_agg(expr: &ExprRef) -> bool {
     expr.exists(|e| matches!(e.as_ref(), Expr::Agg(_)))
 }
 
+pub fn has_window(expr: &ExprRef) -> bool {
+    expr.exists(|e| matches!(e.as_ref(), Expr::Over(..) | Expr::WindowFunction(_)))
+}
+
 #[inline]
 pub fn is_actor_pool_udf(expr: &ExprRef) -> bool {
     matches!(
@@ -1645,7 +1765,9 @@ pub fn estimated_selectivity(expr: &Expr, schema: &Schema) -> f64 {
         | Expr::Function { .. }
         | Expr::Column(_)
         | Expr::IfElse { .. }
-        | Expr::FillNull(_, _) => match expr.to_field(schema) {
+        | Expr::FillNull(_, _)
+        | Expr::WindowFunction(_)
+        | Expr::Over(..) => match expr.to_field(schema) {
             Ok(field) if field.dtype == DataType::Boolean => 0.2,
             _ => 1.0,
         },
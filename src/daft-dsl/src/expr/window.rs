@@ -0,0 +1,399 @@
+use std::fmt::{Display, Formatter};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use super::{AggExpr, ExprRef};
+
+/// A function evaluated over a window of rows, e.g. `sum(x) OVER (PARTITION BY k ORDER BY t)`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowExpr {
+    /// An aggregation evaluated over the window frame of each row.
+    Agg(AggExpr),
+    /// The 1-based position of the row within its partition.
+    RowNumber,
+    /// The rank of the row within its partition, with gaps for ties.
+    Rank,
+    /// The rank of the row within its partition, without gaps for ties.
+    DenseRank,
+    /// The value of `input` at `offset` rows from the current row within its partition.
+    ///
+    /// A negative offset looks backwards (`lag`), a positive offset looks forwards (`lead`).
+    /// Rows that fall outside of the partition evaluate to `default`, or null if it isn't set.
+    Offset {
+        input: ExprRef,
+        offset: i64,
+        default: Option<ExprRef>,
+    },
+}
+
+impl Display for WindowExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Agg(agg_expr) => write!(f, "{agg_expr}"),
+            Self::RowNumber => write!(f, "row_number()"),
+            Self::Rank => write!(f, "rank()"),
+            Self::DenseRank => write!(f, "dense_rank()"),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let (name, offset) = if *offset < 0 {
+                    ("lag", -offset)
+                } else {
+                    ("lead", *offset)
+                };
+                match default {
+                    Some(default) => write!(f, "{name}({input}, {offset}, {default})"),
+                    None => write!(f, "{name}({input}, {offset})"),
+                }
+            }
+        }
+    }
+}
+
+impl WindowExpr {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.name(),
+            Self::RowNumber => "row_number",
+            Self::Rank => "rank",
+            Self::DenseRank => "dense_rank",
+            Self::Offset { input, .. } => input.name(),
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> FieldID {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.semantic_id(schema),
+            Self::RowNumber => FieldID::new("row_number()"),
+            Self::Rank => FieldID::new("rank()"),
+            Self::DenseRank => FieldID::new("dense_rank()"),
+            Self::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let child_id = input.semantic_id(schema);
+                let default_id = default
+                    .as_ref()
+                    .map(|d| d.semantic_id(schema).id.to_string())
+                    .unwrap_or_default();
+                FieldID::new(format!("{child_id}.offset({offset},{default_id})"))
+            }
+        }
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.children(),
+            Self::RowNumber | Self::Rank | Self::DenseRank => vec![],
+            Self::Offset { input, default, .. } => {
+                std::iter::once(input).chain(default).cloned().collect()
+            }
+        }
+    }
+
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        match self {
+            Self::Agg(agg_expr) => Self::Agg(agg_expr.with_new_children(children)),
+            Self::RowNumber | Self::Rank | Self::DenseRank => {
+                assert!(children.is_empty(), "Should have no children");
+                self.clone()
+            }
+            Self::Offset {
+                offset, default, ..
+            } => {
+                assert_eq!(
+                    children.len(),
+                    1 + usize::from(default.is_some()),
+                    "Should have same number of children"
+                );
+                let mut children = children.into_iter();
+                Self::Offset {
+                    input: children.next().unwrap(),
+                    offset: *offset,
+                    default: children.next(),
+                }
+            }
+        }
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.to_field(schema),
+            Self::RowNumber | Self::Rank | Self::DenseRank => {
+                Ok(Field::new(self.name(), DataType::UInt64))
+            }
+            Self::Offset { input, default, .. } => {
+                let field = input.to_field(schema)?;
+                if let Some(default) = default {
+                    let default_field = default.to_field(schema)?;
+                    if try_get_supertype(&field.dtype, &default_field.dtype).is_err() {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected default value of {self} to be castable to {}, but received {}",
+                            field.dtype, default_field.dtype
+                        )));
+                    }
+                }
+                Ok(field)
+            }
+        }
+    }
+
+    /// Whether this function is only defined over an ordered window, i.e. it is a ranking or offset function.
+    pub fn requires_order_by(&self) -> bool {
+        !matches!(self, Self::Agg(_))
+    }
+}
+
+/// Whether a window frame counts physical rows or a range of values of the order by column.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowFrameType {
+    Rows,
+    Range,
+}
+
+/// One end of a window frame, relative to the current row.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum WindowBoundary {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl Display for WindowBoundary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnboundedPreceding => write!(f, "unbounded preceding"),
+            Self::Preceding(n) => write!(f, "{n} preceding"),
+            Self::CurrentRow => write!(f, "current row"),
+            Self::Following(n) => write!(f, "{n} following"),
+            Self::UnboundedFollowing => write!(f, "unbounded following"),
+        }
+    }
+}
+
+impl WindowBoundary {
+    /// Signed distance from the current row, with `None` for unbounded boundaries.
+    fn position(&self) -> Option<i128> {
+        match self {
+            Self::UnboundedPreceding | Self::UnboundedFollowing => None,
+            Self::Preceding(n) => Some(-i128::from(*n)),
+            Self::CurrentRow => Some(0),
+            Self::Following(n) => Some(i128::from(*n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowFrame {
+    pub frame_type: WindowFrameType,
+    pub start: WindowBoundary,
+    pub end: WindowBoundary,
+}
+
+impl WindowFrame {
+    pub fn try_new(
+        frame_type: WindowFrameType,
+        start: WindowBoundary,
+        end: WindowBoundary,
+    ) -> DaftResult<Self> {
+        let frame = Self {
+            frame_type,
+            start,
+            end,
+        };
+        let valid =
+            match (start, end) {
+                (WindowBoundary::UnboundedFollowing, _)
+                | (_, WindowBoundary::UnboundedPreceding) => false,
+                (WindowBoundary::UnboundedPreceding, _)
+                | (_, WindowBoundary::UnboundedFollowing) => true,
+                (start, end) => start.position() <= end.position(),
+            };
+        if !valid {
+            return Err(DaftError::ValueError(format!(
+                "Invalid window frame: {frame}, the start of the frame must not come after its end"
+            )));
+        }
+        Ok(frame)
+    }
+
+    /// Whether every row of a partition sees the entire partition.
+    pub fn is_unbounded(&self) -> bool {
+        self.start == WindowBoundary::UnboundedPreceding
+            && self.end == WindowBoundary::UnboundedFollowing
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let frame_type = match self.frame_type {
+            WindowFrameType::Rows => "rows",
+            WindowFrameType::Range => "range",
+        };
+        write!(f, "{frame_type} between {} and {}", self.start, self.end)
+    }
+}
+
+/// The `OVER (...)` clause of a window function.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WindowSpec {
+    pub partition_by: Vec<ExprRef>,
+    pub order_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    /// The frame of each row, defaulting to the whole partition when there is no `ORDER BY`
+    /// and to `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` otherwise.
+    pub frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    pub fn new(partition_by: Vec<ExprRef>) -> Self {
+        Self {
+            partition_by,
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_order_by(
+        mut self,
+        order_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+    ) -> Self {
+        self.order_by = order_by;
+        self.descending = descending;
+        self.nulls_first = nulls_first;
+        self
+    }
+
+    #[must_use]
+    pub fn with_frame(mut self, frame: WindowFrame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    pub fn children(&self) -> Vec<ExprRef> {
+        self.partition_by
+            .iter()
+            .chain(self.order_by.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn with_new_children(&self, children: Vec<ExprRef>) -> Self {
+        assert_eq!(
+            children.len(),
+            self.partition_by.len() + self.order_by.len(),
+            "Should have same number of children"
+        );
+        let mut partition_by = children;
+        let order_by = partition_by.split_off(self.partition_by.len());
+        Self {
+            partition_by,
+            order_by,
+            descending: self.descending.clone(),
+            nulls_first: self.nulls_first.clone(),
+            frame: self.frame,
+        }
+    }
+
+    pub fn semantic_id(&self, schema: &Schema) -> String {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|e| e.semantic_id(schema).id)
+            .join(",");
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(self.descending.iter().zip(self.nulls_first.iter()))
+            .map(|(e, (desc, nulls_first))| {
+                format!("{}:{desc}:{nulls_first}", e.semantic_id(schema).id)
+            })
+            .join(",");
+        let frame = self.frame.map(|f| f.to_string()).unwrap_or_default();
+        format!("partition_by=[{partition_by}],order_by=[{order_by}],frame=[{frame}]")
+    }
+
+    /// Validates that this spec can be used to evaluate `window_expr`.
+    pub fn validate(&self, window_expr: &WindowExpr, schema: &Schema) -> DaftResult<()> {
+        if self.order_by.len() != self.descending.len()
+            || self.order_by.len() != self.nulls_first.len()
+        {
+            return Err(DaftError::ValueError(format!(
+                "Expected the same number of window order by expressions and sort directions, got {} vs {} vs {}",
+                self.order_by.len(),
+                self.descending.len(),
+                self.nulls_first.len()
+            )));
+        }
+        for expr in self.partition_by.iter().chain(self.order_by.iter()) {
+            expr.to_field(schema)?;
+        }
+        if window_expr.requires_order_by() {
+            if self.order_by.is_empty() {
+                return Err(DaftError::ValueError(format!(
+                    "Window function {window_expr} requires an ORDER BY in its window specification"
+                )));
+            }
+            if self.frame.is_some() {
+                return Err(DaftError::ValueError(format!(
+                    "Window function {window_expr} does not support a window frame"
+                )));
+            }
+        }
+        if let Some(frame) = &self.frame
+            && frame.frame_type == WindowFrameType::Range
+            && (frame.start.position().is_some_and(|p| p != 0)
+                || frame.end.position().is_some_and(|p| p != 0))
+        {
+            let [order_by] = self.order_by.as_slice() else {
+                return Err(DaftError::ValueError(format!(
+                    "Window frame {frame} with an offset requires exactly one ORDER BY expression"
+                )));
+            };
+            let dtype = order_by.get_type(schema)?;
+            if !dtype.is_numeric() {
+                return Err(DaftError::TypeError(format!(
+                    "Window frame {frame} with an offset requires a numeric ORDER BY expression, got {dtype}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if !self.partition_by.is_empty() {
+            parts.push(format!(
+                "partition by {}",
+                self.partition_by.iter().join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            parts.push(format!(
+                "order by {}",
+                self.order_by
+                    .iter()
+                    .zip(self.descending.iter())
+                    .map(|(e, desc)| format!("{e} {}", if *desc { "desc" } else { "asc" }))
+                    .join(", ")
+            ));
+        }
+        if let Some(frame) = &self.frame {
+            parts.push(frame.to_string());
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
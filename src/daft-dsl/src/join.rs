@@ -1,8 +1,16 @@
 use common_error::DaftResult;
-use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_core::{
+    join::{AsofDirection, JoinSide},
+    prelude::*,
+    utils::supertype::try_get_supertype,
+};
 use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
 
-use crate::{deduplicate_expr_names, ExprRef};
+use crate::{
+    deduplicate_expr_names, resolved_col, Column, Expr, ExprRef, LiteralValue, ResolvedColumn,
+};
 
 pub fn get_common_join_cols<'a>(
     left_schema: &'a SchemaRef,
@@ -34,7 +42,7 @@ pub fn infer_join_schema(
 
                 Ok(match join_type {
                     JoinType::Inner => left_field.clone(),
-                    JoinType::Left => left_field.clone(),
+                    JoinType::Left | JoinType::AsOf => left_field.clone(),
                     JoinType::Right => right_field.clone(),
                     JoinType::Outer => {
                         let supertype = try_get_supertype(&left_field.dtype, &right_field.dtype)?;
@@ -97,3 +105,85 @@ pub fn normalize_join_keys(
 
     Ok((left_on, right_on))
 }
+
+/// Name of the column a join side column of a join predicate is bound to, see [`bind_join_predicate`].
+fn join_predicate_column_name(name: &str, side: JoinSide) -> String {
+    format!("{side}.{name}")
+}
+
+/// Returns the columns of the left and right inputs which the join predicate references.
+pub fn join_predicate_columns(predicate: &ExprRef) -> (IndexSet<String>, IndexSet<String>) {
+    let mut left = IndexSet::new();
+    let mut right = IndexSet::new();
+    predicate
+        .apply(|e| {
+            if let Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, side))) = e.as_ref()
+            {
+                match side {
+                    JoinSide::Left => left.insert(name.to_string()),
+                    JoinSide::Right => right.insert(name.to_string()),
+                };
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .expect("visiting a join predicate should not fail");
+    (left, right)
+}
+
+/// Returns the schema of the columns which a join predicate bound by [`bind_join_predicate`] is
+/// evaluated against, erroring if the predicate references a column which does not exist.
+pub fn join_predicate_schema(
+    predicate: &ExprRef,
+    left_schema: &Schema,
+    right_schema: &Schema,
+) -> DaftResult<Schema> {
+    let (left, right) = join_predicate_columns(predicate);
+    let left_fields = left.iter().map(|name| (name, JoinSide::Left, left_schema));
+    let right_fields = right
+        .iter()
+        .map(|name| (name, JoinSide::Right, right_schema));
+    let fields = left_fields
+        .chain(right_fields)
+        .map(|(name, side, schema)| {
+            let field = schema.get_field(name)?;
+            Ok(field.rename(join_predicate_column_name(name, side)))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    Schema::new(fields)
+}
+
+/// Rewrites the join side columns of a join predicate into basic columns, so that the predicate
+/// can be evaluated on a batch of the referenced columns of both sides, named as in [`join_predicate_schema`].
+pub fn bind_join_predicate(predicate: ExprRef) -> ExprRef {
+    predicate
+        .transform(|e| {
+            if let Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(name, side))) = e.as_ref()
+            {
+                Ok(Transformed::yes(resolved_col(join_predicate_column_name(
+                    name, *side,
+                ))))
+            } else {
+                Ok(Transformed::no(e))
+            }
+        })
+        .expect("binding a join predicate should not fail")
+        .data
+}
+
+/// Options of an as-of join, which matches rows on the nearness of their last join key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AsofJoinOptions {
+    pub direction: AsofDirection,
+    /// Largest distance between the keys of a left row and its match, no limit if none.
+    pub tolerance: Option<LiteralValue>,
+}
+
+impl std::fmt::Display for AsofJoinOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Direction = {}", self.direction)?;
+        if let Some(tolerance) = &self.tolerance {
+            write!(f, ", Tolerance = {tolerance}")?;
+        }
+        Ok(())
+    }
+}
@@ -15,9 +15,10 @@ mod treenode;
 pub use common_treenode;
 pub use expr::{
     binary_op, count_actor_pool_udfs, deduplicate_expr_names, estimated_selectivity,
-    exprs_to_schema, has_agg, is_actor_pool_udf, is_partition_compatible, resolved_col,
-    unresolved_col, AggExpr, ApproxPercentileParams, Column, Expr, ExprRef, Operator, PlanRef,
-    ResolvedColumn, SketchType, Subquery, SubqueryPlan, UnresolvedColumn,
+    exprs_to_schema, has_agg, has_window, is_actor_pool_udf, is_partition_compatible,
+    join_side_col, resolved_col, unresolved_col, AggExpr, ApproxPercentileParams, Column, Expr,
+    ExprRef, Operator, PlanRef, ResolvedColumn, SketchType, Subquery, SubqueryPlan,
+    UnresolvedColumn, WindowBoundary, WindowExpr, WindowFrame, WindowFrameType, WindowSpec,
 };
 pub use lit::{lit, literal_value, literals_to_series, null_lit, Literal, LiteralValue};
 #[cfg(feature = "python")]
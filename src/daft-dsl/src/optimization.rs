@@ -36,7 +36,9 @@ pub fn requires_computation(e: &Expr) -> bool {
         | Expr::IfElse { .. }
         | Expr::Subquery { .. }
         | Expr::InSubquery { .. }
-        | Expr::Exists(..) => true,
+        | Expr::Exists(..)
+        | Expr::WindowFunction(..)
+        | Expr::Over(..) => true,
     }
 }
 
@@ -0,0 +1,118 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::{DataType, Field, Schema},
+    series::Series,
+};
+use daft_dsl::{
+    binary_op,
+    common_treenode::{Transformed, TreeNode},
+    functions::{ScalarFunction, ScalarUDF},
+    lit, unresolved_col, Expr, ExprRef, Operator,
+};
+use serde::{Deserialize, Serialize};
+
+/// Name of the column which identifies the grouping set of each row of a grouping sets aggregation.
+///
+/// Each bit is set when the row is not grouped by the corresponding grouping expression, with the
+/// first grouping expression as the most significant bit.
+pub const GROUPING_ID_COLUMN: &str = "__grouping_id__";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct GroupingId {}
+
+#[typetag::serde]
+impl ScalarUDF for GroupingId {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "grouping_id"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [] => Err(DaftError::SchemaMismatch(
+                "Expected at least 1 input args, got 0".to_string(),
+            )),
+            [input, ..] => Ok(Field::new(input.to_field(schema)?.name, DataType::Int64)),
+        }
+    }
+
+    fn evaluate(&self, _: &[Series]) -> DaftResult<Series> {
+        Err(DaftError::ValueError(
+            "grouping_id can only be used with grouping sets, rollup or cube".to_string(),
+        ))
+    }
+}
+
+/// Returns which of the inputs a row is not grouped by, as a bitmask with the first input as the
+/// most significant bit, or the bitmask of all grouping expressions when there are no inputs.
+#[must_use]
+pub fn grouping_id(inputs: Vec<ExprRef>) -> ExprRef {
+    if inputs.is_empty() {
+        unresolved_col(GROUPING_ID_COLUMN)
+    } else {
+        ScalarFunction::new(GroupingId {}, inputs).into()
+    }
+}
+
+/// Rewrites the [`grouping_id`] calls of the expression into reads of [`GROUPING_ID_COLUMN`], so
+/// the expression can be evaluated on the output of a grouping sets aggregation over `group_by`.
+pub fn resolve_grouping_id(expr: ExprRef, group_by: &[ExprRef]) -> DaftResult<ExprRef> {
+    let expr = expr.transform(|e| {
+        let Expr::ScalarFunction(func) = e.as_ref() else {
+            return Ok(Transformed::no(e));
+        };
+        if func.name() != "grouping_id" {
+            return Ok(Transformed::no(e));
+        }
+        let grouping_id = unresolved_col(GROUPING_ID_COLUMN);
+        // collect the bits of the inputs, keeping them in the order of the inputs
+        let mut resolved = lit(0i64);
+        for input in &func.inputs {
+            let Some(index) = group_by.iter().position(|g| g == input) else {
+                return Err(DaftError::ValueError(format!(
+                    "grouping_id argument {input} must be one of the grouping expressions"
+                )));
+            };
+            let shift = (group_by.len() - 1 - index) as i64;
+            let bit = binary_op(
+                Operator::Modulus,
+                binary_op(Operator::ShiftRight, grouping_id.clone(), lit(shift)),
+                lit(2i64),
+            );
+            resolved = binary_op(
+                Operator::Plus,
+                binary_op(Operator::ShiftLeft, resolved, lit(1i64)),
+                bit,
+            );
+        }
+        Ok(Transformed::yes(resolved.alias(e.name())))
+    })?;
+    Ok(expr.data)
+}
+
+/// Returns the grouping sets of `ROLLUP (e1, e2, ..)`, which are the prefixes of the elements
+/// from longest to shortest.
+pub fn rollup(elements: &[Vec<ExprRef>]) -> Vec<Vec<ExprRef>> {
+    (0..=elements.len())
+        .rev()
+        .map(|len| elements[..len].concat())
+        .collect()
+}
+
+/// Returns the grouping sets of `CUBE (e1, e2, ..)`, which are all the subsets of the elements.
+pub fn cube(elements: &[Vec<ExprRef>]) -> Vec<Vec<ExprRef>> {
+    // each bit of the mask drops an element, so the sets go from all elements to none
+    (0..1usize << elements.len())
+        .map(|mask| {
+            elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (elements.len() - 1 - i)) == 0)
+                .flat_map(|(_, element)| element.iter().cloned())
+                .collect()
+        })
+        .collect()
+}
@@ -3,6 +3,7 @@ pub mod coalesce;
 pub mod count_matches;
 pub mod distance;
 pub mod float;
+pub mod grouping;
 pub mod hash;
 pub mod image;
 pub mod list;
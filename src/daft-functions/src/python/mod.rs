@@ -119,13 +119,22 @@ pub fn register(parent: &Bound<PyModule>) -> PyResult<()> {
     add!(temporal::dt_date);
     add!(temporal::dt_day);
     add!(temporal::dt_day_of_week);
+    add!(temporal::dt_day_of_year);
     add!(temporal::dt_hour);
     add!(temporal::dt_minute);
     add!(temporal::dt_month);
+    add!(temporal::dt_quarter);
     add!(temporal::dt_second);
     add!(temporal::dt_time);
+    add!(temporal::dt_week_of_year);
     add!(temporal::dt_year);
     add!(temporal::dt_truncate);
+    add!(temporal::dt_convert_time_zone);
+    add!(temporal::dt_strftime);
+    add!(temporal::dt_to_unix_epoch);
+    add!(temporal::dt_from_unix_epoch);
+    add!(temporal::dt_add_months);
+    add!(temporal::dt_date_diff);
 
     add!(tokenize::tokenize_encode);
     add!(tokenize::tokenize_decode);
@@ -0,0 +1,59 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct AddMonths;
+
+#[typetag::serde]
+impl ScalarUDF for AddMonths {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "add_months"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input, months] => {
+                let input_field = input.to_field(schema)?;
+                let months_field = months.to_field(schema)?;
+                if input_field.dtype.is_temporal()
+                    && (months_field.dtype.is_integer() || months_field.dtype.is_null())
+                {
+                    Ok(input_field)
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected temporal input and integer months, got {} and {}",
+                        input_field.dtype, months_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input, months] => input.dt_add_months(months),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_add_months(input: ExprRef, months: ExprRef) -> ExprRef {
+    ScalarFunction::new(AddMonths, vec![input, months]).into()
+}
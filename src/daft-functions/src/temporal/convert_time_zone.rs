@@ -0,0 +1,70 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ConvertTimeZone {
+    pub(super) time_zone: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for ConvertTimeZone {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "convert_time_zone"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                let timeunit = match input_field.dtype {
+                    DataType::Timestamp(timeunit, _) => timeunit,
+                    DataType::Date => TimeUnit::Microseconds,
+                    _ => {
+                        return Err(DaftError::TypeError(format!(
+                            "Expected input to convert_time_zone to be temporal, got {}",
+                            input_field.dtype
+                        )))
+                    }
+                };
+                Ok(Field::new(
+                    input_field.name,
+                    DataType::Timestamp(timeunit, Some(self.time_zone.clone())),
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_convert_time_zone(&self.time_zone),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_convert_time_zone<S: Into<String>>(input: ExprRef, time_zone: S) -> ExprRef {
+    ScalarFunction::new(
+        ConvertTimeZone {
+            time_zone: time_zone.into(),
+        },
+        vec![input],
+    )
+    .into()
+}
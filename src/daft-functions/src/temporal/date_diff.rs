@@ -0,0 +1,60 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DateDiff {
+    pub(super) unit: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for DateDiff {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "date_diff"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [start, end] => {
+                let start_field = start.to_field(schema)?;
+                let end_field = end.to_field(schema)?;
+                if start_field.dtype.is_temporal() && end_field.dtype.is_temporal() {
+                    Ok(Field::new(end_field.name, DataType::Int64))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected temporal input args, got {} and {}",
+                        start_field.dtype, end_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [start, end] => end.dt_date_diff(start, &self.unit),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 2 input args, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+/// The number of `unit` boundaries crossed going from `start` to `end`.
+#[must_use]
+pub fn dt_date_diff<S: Into<String>>(unit: S, start: ExprRef, end: ExprRef) -> ExprRef {
+    ScalarFunction::new(DateDiff { unit: unit.into() }, vec![start, end]).into()
+}
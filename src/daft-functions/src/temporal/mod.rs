@@ -1,4 +1,9 @@
+pub mod add_months;
+pub mod convert_time_zone;
+pub mod date_diff;
+pub mod strftime;
 pub mod truncate;
+pub mod unix_epoch;
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
@@ -70,9 +75,13 @@ impl_temporal!(Date, dt_date, "dt_date", Date);
 impl_temporal!(Day, dt_day, "dt_day", UInt32);
 impl_temporal!(Hour, dt_hour, "dt_hour", UInt32);
 impl_temporal!(DayOfWeek, dt_day_of_week, "dt_day_of_week", UInt32);
+impl_temporal!(DayOfYear, dt_day_of_year, "dt_day_of_year", UInt32);
+impl_temporal!(Epoch, dt_epoch, "dt_epoch", Float64);
 impl_temporal!(Minute, dt_minute, "dt_minute", UInt32);
 impl_temporal!(Month, dt_month, "dt_month", UInt32);
+impl_temporal!(Quarter, dt_quarter, "dt_quarter", UInt32);
 impl_temporal!(Second, dt_second, "dt_second", UInt32);
+impl_temporal!(WeekOfYear, dt_week_of_year, "dt_week_of_year", UInt32);
 impl_temporal!(Year, dt_year, "dt_year", Int32);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -134,7 +143,14 @@ pub fn dt_time(input: ExprRef) -> ExprRef {
 mod test {
     use std::sync::Arc;
 
-    use super::truncate::Truncate;
+    use super::{
+        add_months::AddMonths,
+        convert_time_zone::ConvertTimeZone,
+        date_diff::DateDiff,
+        strftime::Strftime,
+        truncate::Truncate,
+        unix_epoch::{FromUnixEpoch, ToUnixEpoch},
+    };
 
     #[test]
     fn test_fn_name() {
@@ -144,10 +160,14 @@ mod test {
             (Arc::new(Day), "day"),
             (Arc::new(Hour), "hour"),
             (Arc::new(DayOfWeek), "day_of_week"),
+            (Arc::new(DayOfYear), "day_of_year"),
+            (Arc::new(Epoch), "epoch"),
             (Arc::new(Minute), "minute"),
             (Arc::new(Month), "month"),
+            (Arc::new(Quarter), "quarter"),
             (Arc::new(Second), "second"),
             (Arc::new(Time), "time"),
+            (Arc::new(WeekOfYear), "week_of_year"),
             (Arc::new(Year), "year"),
             (
                 Arc::new(Truncate {
@@ -155,6 +175,32 @@ mod test {
                 }),
                 "truncate",
             ),
+            (
+                Arc::new(ConvertTimeZone {
+                    time_zone: String::new(),
+                }),
+                "convert_time_zone",
+            ),
+            (Arc::new(AddMonths), "add_months"),
+            (
+                Arc::new(DateDiff {
+                    unit: String::new(),
+                }),
+                "date_diff",
+            ),
+            (Arc::new(Strftime { format: None }), "strftime"),
+            (
+                Arc::new(ToUnixEpoch {
+                    time_unit: TimeUnit::Seconds,
+                }),
+                "to_unix_epoch",
+            ),
+            (
+                Arc::new(FromUnixEpoch {
+                    time_unit: TimeUnit::Seconds,
+                }),
+                "from_unix_epoch",
+            ),
         ];
 
         for (f, name) in cases {
@@ -0,0 +1,65 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Strftime {
+    pub(super) format: Option<String>,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Strftime {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if input_field.dtype.is_temporal() || matches!(input_field.dtype, DataType::Time(_))
+                {
+                    Ok(Field::new(input_field.name, DataType::Utf8))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to strftime to be temporal, got {}",
+                        input_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_strftime(self.format.as_deref()),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_strftime(input: ExprRef, format: Option<&str>) -> ExprRef {
+    ScalarFunction::new(
+        Strftime {
+            format: format.map(ToString::to_string),
+        },
+        vec![input],
+    )
+    .into()
+}
@@ -0,0 +1,112 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct ToUnixEpoch {
+    pub(super) time_unit: TimeUnit,
+}
+
+#[typetag::serde]
+impl ScalarUDF for ToUnixEpoch {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "to_unix_epoch"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if input_field.dtype.is_temporal() {
+                    Ok(Field::new(input_field.name, DataType::Int64))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to to_unix_epoch to be temporal, got {}",
+                        input_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_to_unix_epoch(self.time_unit),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FromUnixEpoch {
+    pub(super) time_unit: TimeUnit,
+}
+
+#[typetag::serde]
+impl ScalarUDF for FromUnixEpoch {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "from_unix_epoch"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let input_field = input.to_field(schema)?;
+                if input_field.dtype.is_numeric() {
+                    Ok(Field::new(
+                        input_field.name,
+                        DataType::Timestamp(self.time_unit, None),
+                    ))
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Expected input to from_unix_epoch to be numeric, got {}",
+                        input_field.dtype
+                    )))
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_from_unix_epoch(self.time_unit),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_to_unix_epoch(input: ExprRef, time_unit: TimeUnit) -> ExprRef {
+    ScalarFunction::new(ToUnixEpoch { time_unit }, vec![input]).into()
+}
+
+#[must_use]
+pub fn dt_from_unix_epoch(input: ExprRef, time_unit: TimeUnit) -> ExprRef {
+    ScalarFunction::new(FromUnixEpoch { time_unit }, vec![input]).into()
+}
@@ -15,7 +15,7 @@ use futures::{stream::BoxStream, StreamExt, TryStreamExt};
 use snafu::{IntoError, ResultExt, Snafu};
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult, ObjectSource},
+    object_io::{FileMetadata, FileType, LSResult, ObjectMetadata, ObjectSource},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, GetResult,
@@ -482,6 +482,7 @@ impl AzureBlobSource {
             filepath: format!("{protocol}://{}/", &container.name),
             size: None,
             filetype: FileType::Directory,
+            validator: None,
         }
     }
 
@@ -496,11 +497,13 @@ impl AzureBlobSource {
                 filepath: format!("{protocol}://{}/{}", container_name, &blob.name),
                 size: Some(blob.properties.content_length),
                 filetype: FileType::File,
+                validator: Some(blob.properties.etag.to_string()),
             },
             BlobItem::BlobPrefix(prefix) => FileMetadata {
                 filepath: format!("{protocol}://{}/{}", container_name, &prefix.name),
                 size: None,
                 filetype: FileType::Directory,
+                validator: None,
             },
         }
     }
@@ -567,6 +570,14 @@ impl ObjectSource for AzureBlobSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.get_metadata(uri, io_stats).await?.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
         let parsed_uri = parse_azure_uri(uri)?;
         let (container, key) = parsed_uri
             .container_and_key
@@ -589,7 +600,10 @@ impl ObjectSource for AzureBlobSource {
             is.mark_head_requests(1);
         }
 
-        Ok(metadata.blob.properties.content_length as usize)
+        Ok(ObjectMetadata {
+            size: metadata.blob.properties.content_length as usize,
+            validator: Some(metadata.blob.properties.etag.to_string()),
+        })
     }
 
     async fn glob(
@@ -0,0 +1,645 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common_io_config::CacheConfig;
+use futures::{stream::BoxStream, StreamExt};
+use snafu::ResultExt;
+use tokio::sync::OnceCell;
+
+use crate::{
+    object_io::{
+        FileMetadata, FileType, GetResult, LSResult, MultipartUpload, ObjectMetadata, ObjectSource,
+        StreamingRetryParams,
+    },
+    stats::IOStatsRef,
+    Error, FileFormat, UnableToCreateDirSnafu,
+};
+
+/// Suffix of the files which are still being written, these are never read or indexed.
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// The caches which were opened in this process, by directory.
+///
+/// Sharing the cache of a directory keeps a single index of its entries, so that all clients
+/// evict from the same accounting. Opening a directory with a different maximum size is an error.
+static DISK_CACHES: LazyLock<Mutex<HashMap<PathBuf, Arc<DiskCache>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// A content-addressed cache of byte ranges on local disk with least recently used eviction.
+///
+/// Each entry is a file named after the hash of its key, which is written to a partial file first
+/// and renamed once complete so that readers never see a partially written entry.
+pub(crate) struct DiskCache {
+    directory: PathBuf,
+    max_size_bytes: u64,
+    index: Mutex<CacheIndex>,
+}
+
+#[derive(Default)]
+struct CacheIndex {
+    /// The size and last use of each entry.
+    entries: HashMap<String, (u64, u64)>,
+    /// The entries by their last use, which is a logical clock.
+    by_last_use: BTreeMap<u64, String>,
+    total_size: u64,
+    clock: u64,
+}
+
+impl CacheIndex {
+    fn touch(&mut self, key: &str) -> bool {
+        let Some((_, last_use)) = self.entries.get_mut(key) else {
+            return false;
+        };
+        self.by_last_use.remove(last_use);
+        self.clock += 1;
+        *last_use = self.clock;
+        self.by_last_use.insert(self.clock, key.to_string());
+        true
+    }
+
+    fn insert(&mut self, key: String, size: u64) {
+        if self.touch(&key) {
+            return;
+        }
+        self.clock += 1;
+        self.entries.insert(key.clone(), (size, self.clock));
+        self.by_last_use.insert(self.clock, key);
+        self.total_size += size;
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((size, last_use)) = self.entries.remove(key) {
+            self.by_last_use.remove(&last_use);
+            self.total_size -= size;
+        }
+    }
+
+    /// Removes the least recently used entries until the cache fits in `max_size_bytes`.
+    fn evict(&mut self, max_size_bytes: u64) -> Vec<String> {
+        let mut evicted = vec![];
+        while self.total_size > max_size_bytes
+            && let Some((_, key)) = self.by_last_use.pop_first()
+        {
+            let (size, _) = self.entries.remove(&key).unwrap();
+            self.total_size -= size;
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+impl DiskCache {
+    /// Returns the cache of the configured directory, indexing the existing entries on first use.
+    pub(crate) fn open(config: &CacheConfig) -> super::Result<Arc<Self>> {
+        let directory = config
+            .directory
+            .as_ref()
+            .map_or_else(|| std::env::temp_dir().join("daft-io-cache"), PathBuf::from);
+
+        let mut caches = DISK_CACHES.lock().unwrap();
+        if let Some(cache) = caches.get(&directory) {
+            if cache.max_size_bytes != config.max_size_bytes {
+                return Err(Error::InvalidArgument {
+                    msg: format!(
+                        "The disk cache in {} is already open with a maximum size of {} bytes, not {} bytes",
+                        directory.display(),
+                        cache.max_size_bytes,
+                        config.max_size_bytes
+                    ),
+                });
+            }
+            return Ok(cache.clone());
+        }
+
+        std::fs::create_dir_all(&directory).context(UnableToCreateDirSnafu {
+            path: directory.to_string_lossy(),
+        })?;
+        let cache = Arc::new(Self {
+            index: Mutex::new(Self::index_existing(&directory)),
+            directory: directory.clone(),
+            max_size_bytes: config.max_size_bytes,
+        });
+        cache.evict();
+        caches.insert(directory, cache.clone());
+        Ok(cache)
+    }
+
+    /// Indexes the entries left by previous processes, ordered by when they were last modified.
+    fn index_existing(directory: &Path) -> CacheIndex {
+        let mut existing = vec![];
+        if let Ok(entries) = std::fs::read_dir(directory) {
+            for entry in entries.flatten() {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let key = entry.file_name().to_string_lossy().to_string();
+                if !metadata.is_file() || key.ends_with(PARTIAL_SUFFIX) {
+                    continue;
+                }
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                existing.push((modified, key, metadata.len()));
+            }
+        }
+        existing.sort();
+
+        let mut index = CacheIndex::default();
+        for (_, key, size) in existing {
+            index.insert(key, size);
+        }
+        index
+    }
+
+    fn key(uri: &str, metadata: &ObjectMetadata, range: &Range<usize>) -> String {
+        let ObjectMetadata { size, validator } = metadata;
+        let validator = validator.as_deref().unwrap_or_default();
+        let key = format!("{uri}\n{size}\n{validator}\n{}-{}", range.start, range.end);
+        format!("{:032x}", xxhash_rust::xxh3::xxh3_128(key.as_bytes()))
+    }
+
+    async fn get(&self, key: &str) -> Option<Bytes> {
+        if !self.index.lock().unwrap().touch(key) {
+            return None;
+        }
+        match tokio::fs::read(self.directory.join(key)).await {
+            Ok(bytes) => Some(bytes.into()),
+            Err(err) => {
+                // the entry was removed from outside of the cache
+                log::warn!("Unable to read disk cache entry {key}, ignoring it: {err}");
+                self.index.lock().unwrap().remove(key);
+                None
+            }
+        }
+    }
+
+    async fn put(&self, key: &str, bytes: &Bytes) {
+        if bytes.len() as u64 > self.max_size_bytes {
+            return;
+        }
+        let path = self.directory.join(key);
+        let partial = self
+            .directory
+            .join(format!("{key}.{}{PARTIAL_SUFFIX}", rand::random::<u64>()));
+        let written = match tokio::fs::write(&partial, bytes).await {
+            Ok(()) => tokio::fs::rename(&partial, &path).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = written {
+            // caching is best effort, so the read still succeeds
+            log::warn!("Unable to write disk cache entry {key}: {err}");
+            let _ = tokio::fs::remove_file(&partial).await;
+            return;
+        }
+        self.index
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), bytes.len() as u64);
+        self.evict();
+    }
+
+    fn evict(&self) {
+        let evicted = self.index.lock().unwrap().evict(self.max_size_bytes);
+        for key in evicted {
+            if let Err(err) = std::fs::remove_file(self.directory.join(&key)) {
+                log::warn!("Unable to evict disk cache entry {key}: {err}");
+            }
+        }
+    }
+}
+
+type MetadataCell = Arc<OnceCell<ObjectMetadata>>;
+
+/// An [`ObjectSource`] which caches the byte ranges read from the wrapped source in a [`DiskCache`].
+///
+/// Entries are keyed by the URI, the size and validator of the object and the range, so that a
+/// modified object is read again from the source. The metadata of each object is reused for the
+/// configured TTL rather than requested before every read, and is taken from listings when they
+/// include a validator. Whole objects and ranges which could never fit in the cache are streamed
+/// from the source without being cached.
+pub(crate) struct CachedSource {
+    source: Arc<dyn ObjectSource>,
+    cache: Arc<DiskCache>,
+    /// When the metadata of each object was requested, and the pending or completed request.
+    metadata: Mutex<HashMap<String, (Instant, MetadataCell)>>,
+    metadata_ttl: Duration,
+}
+
+impl CachedSource {
+    pub(crate) fn new(
+        source: Arc<dyn ObjectSource>,
+        cache: Arc<DiskCache>,
+        metadata_ttl: Duration,
+    ) -> Self {
+        Self {
+            source,
+            cache,
+            metadata: Mutex::new(HashMap::new()),
+            metadata_ttl,
+        }
+    }
+
+    /// Returns the metadata of the object, requesting it at most once per TTL even when the object
+    /// is read concurrently.
+    async fn object_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
+        let cell = {
+            let now = Instant::now();
+            let mut metadata = self.metadata.lock().unwrap();
+            match metadata.get(uri) {
+                Some((requested, cell)) if now.duration_since(*requested) < self.metadata_ttl => {
+                    cell.clone()
+                }
+                _ => {
+                    metadata.retain(|_, (requested, _)| {
+                        now.duration_since(*requested) < self.metadata_ttl
+                    });
+                    let cell = Arc::new(OnceCell::new());
+                    metadata.insert(uri.to_string(), (now, cell.clone()));
+                    cell
+                }
+            }
+        };
+        cell.get_or_try_init(|| self.source.get_metadata(uri, io_stats))
+            .await
+            .cloned()
+    }
+
+    /// Reuses the size and validator of a listed file, so that reading it needs no metadata request.
+    fn remember_listed(&self, file: &FileMetadata) {
+        let (FileType::File, Some(size), Some(validator)) =
+            (&file.filetype, file.size, &file.validator)
+        else {
+            return;
+        };
+        let metadata = ObjectMetadata {
+            size: size as usize,
+            validator: Some(validator.clone()),
+        };
+        self.metadata.lock().unwrap().insert(
+            file.filepath.clone(),
+            (Instant::now(), Arc::new(OnceCell::new_with(Some(metadata)))),
+        );
+    }
+}
+
+#[async_trait]
+impl ObjectSource for CachedSource {
+    async fn get(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<GetResult> {
+        let range = match range {
+            Some(range) if range.len() as u64 <= self.cache.max_size_bytes => range,
+            // whole objects and ranges which could never be cached are streamed from the source
+            range => return self.source.get(uri, range, io_stats).await,
+        };
+        let metadata = self.object_metadata(uri, io_stats.clone()).await?;
+        let key = DiskCache::key(uri, &metadata, &range);
+
+        let bytes = if let Some(bytes) = self.cache.get(&key).await {
+            if let Some(io_stats) = &io_stats {
+                io_stats.mark_cache_hits(1);
+            }
+            bytes
+        } else {
+            if let Some(io_stats) = &io_stats {
+                io_stats.mark_cache_misses(1);
+            }
+            let bytes = self
+                .source
+                .get(uri, Some(range.clone()), io_stats.clone())
+                .await?
+                .with_retry(StreamingRetryParams::new(
+                    self.source.clone(),
+                    uri.to_string(),
+                    Some(range),
+                    io_stats,
+                ))
+                .bytes()
+                .await?;
+            self.cache.put(&key, &bytes).await;
+            bytes
+        };
+
+        let size = bytes.len();
+        Ok(GetResult::Stream(
+            futures::stream::once(async { Ok(bytes) }).boxed(),
+            Some(size),
+            None,
+            None,
+        ))
+    }
+
+    async fn put(
+        &self,
+        uri: &str,
+        data: bytes::Bytes,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<()> {
+        self.source.put(uri, data, io_stats).await
+    }
+
+    async fn create_multipart_upload(
+        self: Arc<Self>,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Box<dyn MultipartUpload>>> {
+        self.source
+            .clone()
+            .create_multipart_upload(uri, io_stats)
+            .await
+    }
+
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.object_metadata(uri, io_stats).await?.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
+        self.object_metadata(uri, io_stats).await
+    }
+
+    async fn glob(
+        self: Arc<Self>,
+        glob_path: &str,
+        fanout_limit: Option<usize>,
+        page_size: Option<i32>,
+        limit: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+        file_format: Option<FileFormat>,
+    ) -> super::Result<BoxStream<'static, super::Result<FileMetadata>>> {
+        let files = self
+            .source
+            .clone()
+            .glob(
+                glob_path,
+                fanout_limit,
+                page_size,
+                limit,
+                io_stats,
+                file_format,
+            )
+            .await?;
+        Ok(files
+            .inspect(move |file| {
+                if let Ok(file) = file {
+                    self.remember_listed(file);
+                }
+            })
+            .boxed())
+    }
+
+    async fn ls(
+        &self,
+        path: &str,
+        posix: bool,
+        continuation_token: Option<&str>,
+        page_size: Option<i32>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<LSResult> {
+        let result = self
+            .source
+            .ls(path, posix, continuation_token, page_size, io_stats)
+            .await?;
+        for file in &result.files {
+            self.remember_listed(file);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{local::LocalSource, IOStatsContext, Result};
+
+    fn cache_config(directory: &Path, max_size_bytes: u64) -> CacheConfig {
+        CacheConfig {
+            enabled: true,
+            directory: Some(directory.to_string_lossy().to_string()),
+            max_size_bytes,
+            ..Default::default()
+        }
+    }
+
+    async fn cached_source(config: &CacheConfig, metadata_ttl: Duration) -> Result<CachedSource> {
+        Ok(CachedSource::new(
+            LocalSource::get_client().await?,
+            DiskCache::open(config)?,
+            metadata_ttl,
+        ))
+    }
+
+    async fn read(
+        source: &CachedSource,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: &IOStatsRef,
+    ) -> Result<Bytes> {
+        source
+            .get(uri, range, Some(io_stats.clone()))
+            .await?
+            .bytes()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_hits_and_misses() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+        let uri = format!("file://{}", file.path().to_str().unwrap());
+
+        // the metadata is requested on every read, so that the changes to the file are seen
+        let source = cached_source(&cache_config(cache_dir.path(), 1024), Duration::ZERO).await?;
+        let io_stats = IOStatsContext::new("test_disk_cache_hits_and_misses");
+
+        assert_eq!(
+            read(&source, &uri, Some(0..11), &io_stats).await?,
+            "hello world"
+        );
+        assert_eq!(read(&source, &uri, Some(0..5), &io_stats).await?, "hello");
+        assert_eq!(read(&source, &uri, Some(0..5), &io_stats).await?, "hello");
+        assert_eq!(
+            read(&source, &uri, Some(0..11), &io_stats).await?,
+            "hello world"
+        );
+        assert_eq!(io_stats.load_cache_misses(), 2);
+        assert_eq!(io_stats.load_cache_hits(), 2);
+
+        // a resized object is read again
+        file.write_all(b"!").unwrap();
+        assert_eq!(
+            read(&source, &uri, Some(0..12), &io_stats).await?,
+            "hello world!"
+        );
+        assert_eq!(read(&source, &uri, Some(0..5), &io_stats).await?, "hello");
+        assert_eq!(io_stats.load_cache_misses(), 4);
+
+        // so is an object of the same size which was modified
+        std::fs::write(file.path(), b"HELLO WORLD!").unwrap();
+        file.as_file()
+            .set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(
+            read(&source, &uri, Some(0..12), &io_stats).await?,
+            "HELLO WORLD!"
+        );
+        assert_eq!(io_stats.load_cache_misses(), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_streams_uncacheable_reads() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+        let uri = format!("file://{}", file.path().to_str().unwrap());
+
+        let source = cached_source(&cache_config(cache_dir.path(), 8), Duration::ZERO).await?;
+        let io_stats = IOStatsContext::new("test_disk_cache_streams_uncacheable_reads");
+
+        // whole objects and ranges larger than the cache are read from the source
+        assert_eq!(read(&source, &uri, None, &io_stats).await?, "hello world");
+        assert_eq!(
+            read(&source, &uri, Some(0..9), &io_stats).await?,
+            "hello wor"
+        );
+        assert_eq!(io_stats.load_cache_misses(), 0);
+        assert_eq!(io_stats.load_cache_hits(), 0);
+        assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 0);
+
+        assert_eq!(
+            read(&source, &uri, Some(0..8), &io_stats).await?,
+            "hello wo"
+        );
+        assert_eq!(io_stats.load_cache_misses(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_conflicting_max_size() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::open(&cache_config(cache_dir.path(), 1024))?;
+        assert!(Arc::ptr_eq(
+            &cache,
+            &DiskCache::open(&cache_config(cache_dir.path(), 1024))?
+        ));
+        assert!(matches!(
+            DiskCache::open(&cache_config(cache_dir.path(), 2048)),
+            Err(Error::InvalidArgument { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_reuses_listed_metadata() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let data_dir = tempfile::tempdir().unwrap();
+        let path = data_dir.path().join("data");
+        std::fs::write(&path, b"hello world").unwrap();
+        let uri = format!("file://{}", path.to_str().unwrap());
+
+        let source = cached_source(&cache_config(cache_dir.path(), 1024), Duration::MAX).await?;
+        let dir_uri = format!("file://{}", data_dir.path().to_str().unwrap());
+        source.ls(&dir_uri, true, None, None, None).await?;
+
+        // the listed size is used rather than requesting the metadata of the modified file
+        std::fs::write(&path, b"hello world!").unwrap();
+        assert_eq!(source.get_size(&uri, None).await?, 11);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_reuses_metadata() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+        let uri = format!("file://{}", file.path().to_str().unwrap());
+
+        let config = cache_config(cache_dir.path(), 1024);
+        let source = cached_source(&config, Duration::from_secs(config.metadata_ttl_secs)).await?;
+        let io_stats = IOStatsContext::new("test_disk_cache_reuses_metadata");
+        assert_eq!(
+            read(&source, &uri, Some(0..11), &io_stats).await?,
+            "hello world"
+        );
+
+        // the metadata of the first read is reused within the TTL, so the entry is still served
+        file.write_all(b"!").unwrap();
+        assert_eq!(
+            read(&source, &uri, Some(0..11), &io_stats).await?,
+            "hello world"
+        );
+        assert_eq!(io_stats.load_cache_hits(), 1);
+        assert_eq!(source.get_size(&uri, None).await?, 11);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_eviction() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"0123456789").unwrap();
+        let uri = format!("file://{}", file.path().to_str().unwrap());
+
+        // only two of the ranges fit in the cache
+        let source = cached_source(&cache_config(cache_dir.path(), 8), Duration::MAX).await?;
+        let io_stats = IOStatsContext::new("test_disk_cache_eviction");
+
+        read(&source, &uri, Some(0..4), &io_stats).await?;
+        read(&source, &uri, Some(4..8), &io_stats).await?;
+        // touch the first range, so that the second is the least recently used
+        read(&source, &uri, Some(0..4), &io_stats).await?;
+        read(&source, &uri, Some(6..10), &io_stats).await?;
+        assert_eq!(std::fs::read_dir(cache_dir.path()).unwrap().count(), 2);
+        assert_eq!(io_stats.load_cache_hits(), 1);
+
+        read(&source, &uri, Some(0..4), &io_stats).await?;
+        assert_eq!(io_stats.load_cache_hits(), 2);
+        assert_eq!(read(&source, &uri, Some(4..8), &io_stats).await?, "4567");
+        assert_eq!(io_stats.load_cache_misses(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_reopen() -> Result<()> {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+        let uri = format!("file://{}", file.path().to_str().unwrap());
+
+        let config = cache_config(cache_dir.path(), 1024);
+        let source = cached_source(&config, Duration::MAX).await?;
+        read(&source, &uri, Some(0..11), &IOStatsContext::new("first")).await?;
+
+        // the entries of previous processes are indexed when the directory is first opened
+        DISK_CACHES.lock().unwrap().remove(cache_dir.path());
+        let cache = DiskCache::open(&config)?;
+        assert_eq!(cache.index.lock().unwrap().total_size, 11);
+        let source = CachedSource::new(LocalSource::get_client().await?, cache, Duration::MAX);
+        let io_stats = IOStatsContext::new("second");
+        assert_eq!(
+            read(&source, &uri, Some(0..11), &io_stats).await?,
+            "hello world"
+        );
+        assert_eq!(io_stats.load_cache_hits(), 1);
+        Ok(())
+    }
+}
@@ -17,7 +17,7 @@ use snafu::{IntoError, ResultExt, Snafu};
 use tokio::sync::Semaphore;
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult, ObjectSource},
+    object_io::{FileMetadata, FileType, LSResult, ObjectMetadata, ObjectSource},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, GetResult,
@@ -221,7 +221,11 @@ impl GCSClientWrapper {
         ))
     }
 
-    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
         let (bucket, key) = parse_raw_uri(uri)?;
         if key.is_empty() {
             return Err(Error::NotAFile { path: uri.into() }.into());
@@ -249,7 +253,10 @@ impl GCSClientWrapper {
         if let Some(is) = io_stats.as_ref() {
             is.mark_head_requests(1);
         }
-        Ok(response.size as usize)
+        Ok(ObjectMetadata {
+            size: response.size as usize,
+            validator: Some(response.etag),
+        })
     }
     #[allow(clippy::too_many_arguments)]
     async fn ls_impl(
@@ -290,11 +297,13 @@ impl GCSClientWrapper {
             filepath: format!("{GCS_SCHEME}://{}/{}", bucket, obj.name),
             size: Some(obj.size as u64),
             filetype: FileType::File,
+            validator: Some(obj.etag.clone()),
         });
         let dirs = response_prefixes.iter().map(|pref| FileMetadata {
             filepath: format!("{GCS_SCHEME}://{bucket}/{pref}"),
             size: None,
             filetype: FileType::Directory,
+            validator: None,
         });
         Ok(LSResult {
             files: files.chain(dirs).collect(),
@@ -517,7 +526,15 @@ impl ObjectSource for GCSSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        self.client.get_size(uri, io_stats).await
+        Ok(self.client.get_metadata(uri, io_stats).await?.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
+        self.client.get_metadata(uri, io_stats).await
     }
 
     async fn glob(
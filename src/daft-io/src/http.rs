@@ -10,13 +10,13 @@ use common_io_config::HTTPConfig;
 use futures::{stream::BoxStream, TryStreamExt};
 use hyper::header;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::header::{CONTENT_LENGTH, ETAG, LAST_MODIFIED, RANGE};
 use snafu::{IntoError, ResultExt, Snafu};
 use url::Position;
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, ObjectMetadata},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat,
@@ -133,6 +133,7 @@ fn get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<File
                 // for populating `size` if necessary
                 size: None,
                 filetype,
+                validator: None,
             }))
         })
         .collect::<super::Result<Vec<_>>>()?;
@@ -242,6 +243,14 @@ impl ObjectSource for HttpSource {
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.get_metadata(uri, io_stats).await?.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
         let request = self.client.head(uri);
         let response = request
             .send()
@@ -256,18 +265,24 @@ impl ObjectSource for HttpSource {
         }
 
         let headers = response.headers();
-        match headers.get(CONTENT_LENGTH) {
+        let size = match headers.get(CONTENT_LENGTH) {
             Some(v) => {
                 let size_bytes = String::from_utf8(v.as_bytes().to_vec()).with_context(|_| {
                     UnableToParseUtf8HeaderSnafu::<String> { path: uri.into() }
                 })?;
 
-                Ok(size_bytes
+                size_bytes
                     .parse()
-                    .with_context(|_| UnableToParseIntegerSnafu::<String> { path: uri.into() })?)
+                    .with_context(|_| UnableToParseIntegerSnafu::<String> { path: uri.into() })?
             }
-            None => Err(Error::UnableToDetermineSize { path: uri.into() }.into()),
-        }
+            None => return Err(Error::UnableToDetermineSize { path: uri.into() }.into()),
+        };
+        let validator = headers
+            .get(ETAG)
+            .or_else(|| headers.get(LAST_MODIFIED))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        Ok(ObjectMetadata { size, validator })
     }
 
     async fn glob(
@@ -341,6 +356,7 @@ impl ObjectSource for HttpSource {
                     filepath: path.to_string(),
                     filetype: FileType::File,
                     size: response.content_length(),
+                    validator: None,
                 }],
                 continuation_token: None,
             }),
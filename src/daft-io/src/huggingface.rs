@@ -478,6 +478,7 @@ impl ObjectSource for HFSource {
                     filepath,
                     size,
                     filetype,
+                    validator: None,
                 }
             })
             .collect();
@@ -548,6 +549,7 @@ async fn try_parquet_api(
                     filepath: uri,
                     size: None,
                     filetype: FileType::File,
+                    validator: None,
                 })
             });
 
@@ -2,10 +2,12 @@
 #![feature(io_error_more)]
 #![feature(if_let_guard)]
 mod azure_blob;
+mod disk_cache;
 mod google_cloud;
 mod http;
 mod huggingface;
 mod local;
+mod multipart;
 mod object_io;
 mod object_store_glob;
 mod s3_like;
@@ -16,6 +18,7 @@ use std::sync::LazyLock;
 
 use azure_blob::AzureBlobSource;
 use common_file_formats::FileFormat;
+use disk_cache::{CachedSource, DiskCache};
 use google_cloud::GCSSource;
 use huggingface::HFSource;
 #[cfg(feature = "python")]
@@ -24,8 +27,9 @@ pub mod python;
 use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
-pub use common_io_config::{AzureConfig, IOConfig, S3Config};
+pub use common_io_config::{AzureConfig, CacheConfig, IOConfig, S3Config};
 use futures::stream::BoxStream;
+pub use multipart::MultipartWriter;
 use object_io::StreamingRetryParams;
 pub use object_io::{FileMetadata, GetResult};
 #[cfg(feature = "python")]
@@ -227,6 +231,17 @@ impl IOClient {
                 HFSource::get_client(&self.config.http).await? as Arc<dyn ObjectSource>
             }
         };
+        // local files are read directly, caching them would only duplicate them
+        let new_source = if self.config.cache.enabled && source_type != SourceType::File {
+            let cache = DiskCache::open(&self.config.cache)?;
+            Arc::new(CachedSource::new(
+                new_source,
+                cache,
+                std::time::Duration::from_secs(self.config.cache.metadata_ttl_secs),
+            )) as Arc<dyn ObjectSource>
+        } else {
+            new_source
+        };
 
         if w_handle.get(&source_type).is_none() {
             w_handle.insert(source_type, new_source.clone());
@@ -282,6 +297,17 @@ impl IOClient {
         source.put(path.as_ref(), data, io_stats.clone()).await
     }
 
+    /// Starts writing an object, which is uploaded in parts if its source supports multipart uploads.
+    pub async fn multipart_writer(
+        &self,
+        dest: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<MultipartWriter> {
+        let (_, path) = parse_url(dest)?;
+        let source = self.get_source(dest).await?;
+        Ok(MultipartWriter::new(source, path.to_string(), io_stats))
+    }
+
     pub async fn single_url_get_size(
         &self,
         input: String,
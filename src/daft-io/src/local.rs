@@ -17,7 +17,7 @@ use super::{
     Result,
 };
 use crate::{
-    object_io::{self, FileMetadata, LSResult},
+    object_io::{self, FileMetadata, LSResult, ObjectMetadata},
     stats::IOStatsRef,
     FileFormat,
 };
@@ -28,6 +28,14 @@ use crate::{
 /// as long as there is no "mix" of "\" and "/".
 const PATH_SEGMENT_DELIMITER: &str = "/";
 
+/// The last modified time of a file in nanoseconds, which changes whenever the file does.
+fn modified_validator(meta: &std::fs::Metadata) -> Option<String> {
+    meta.modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|modified| modified.as_nanos().to_string())
+}
+
 pub struct LocalSource {}
 
 #[derive(Debug, Snafu)]
@@ -177,7 +185,15 @@ impl ObjectSource for LocalSource {
         }
     }
 
-    async fn get_size(&self, uri: &str, _io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+    async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        Ok(self.get_metadata(uri, io_stats).await?.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
         const LOCAL_PROTOCOL: &str = "file://";
         let Some(uri) = uri.strip_prefix(LOCAL_PROTOCOL) else {
             return Err(Error::InvalidFilePath { path: uri.into() }.into());
@@ -193,7 +209,10 @@ impl ObjectSource for LocalSource {
                 path: uri.to_owned(),
             })
         } else {
-            Ok(meta.len() as usize)
+            Ok(ObjectMetadata {
+                size: meta.len() as usize,
+                validator: modified_validator(&meta),
+            })
         }
     }
 
@@ -283,6 +302,7 @@ impl ObjectSource for LocalSource {
                 filepath: format!("{LOCAL_PROTOCOL}{uri}"),
                 size: Some(meta.len()),
                 filetype: object_io::FileType::File,
+                validator: modified_validator(&meta),
             })])
             .boxed());
         }
@@ -330,6 +350,7 @@ impl ObjectSource for LocalSource {
                             path: entry.path().to_string_lossy().to_string(),
                         }
                     })?,
+                    validator: modified_validator(&meta),
                 })
             }
         });
@@ -379,6 +400,7 @@ pub async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 mod tests {
     use std::{default, io::Write};
 
+    use super::modified_validator;
     use crate::{
         object_io::{FileMetadata, FileType, ObjectSource},
         HttpSource, LocalSource, Result,
@@ -476,6 +498,7 @@ mod tests {
                 ),
                 size: Some(file1.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                validator: modified_validator(&file1.as_file().metadata().unwrap()),
             },
             FileMetadata {
                 filepath: format!(
@@ -485,6 +508,7 @@ mod tests {
                 ),
                 size: Some(file2.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                validator: modified_validator(&file2.as_file().metadata().unwrap()),
             },
             FileMetadata {
                 filepath: format!(
@@ -494,6 +518,7 @@ mod tests {
                 ),
                 size: Some(file3.as_file().metadata().unwrap().len()),
                 filetype: FileType::File,
+                validator: modified_validator(&file3.as_file().metadata().unwrap()),
             },
         ];
         expected.sort_by(|a, b| a.filepath.cmp(&b.filepath));
@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use crate::{
+    object_io::{MultipartUpload, ObjectSource},
+    stats::IOStatsRef,
+};
+
+/// Size of the parts that objects are uploaded in, above the 5MiB minimum of S3.
+const MULTIPART_PART_SIZE: usize = 8 * 1024 * 1024;
+
+enum UploadState {
+    /// No part has been filled yet.
+    NotStarted,
+    Started(Box<dyn MultipartUpload>),
+    /// The source can only upload whole objects.
+    Unsupported,
+}
+
+/// Writes an object to an object store, uploading it in parts as they are filled.
+///
+/// Objects smaller than a part, and objects written to sources which do not support multipart
+/// uploads, are uploaded with a single put once finished.
+pub struct MultipartWriter {
+    source: Arc<dyn ObjectSource>,
+    uri: String,
+    buffer: Vec<u8>,
+    upload: UploadState,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl MultipartWriter {
+    pub(crate) fn new(
+        source: Arc<dyn ObjectSource>,
+        uri: String,
+        io_stats: Option<IOStatsRef>,
+    ) -> Self {
+        Self {
+            source,
+            uri,
+            buffer: Vec::new(),
+            upload: UploadState::NotStarted,
+            io_stats,
+        }
+    }
+
+    /// Buffers `data`, returning whether a full part is buffered that should be uploaded with [`Self::upload_part`].
+    pub fn write(&mut self, data: &[u8]) -> bool {
+        self.buffer.extend_from_slice(data);
+        self.buffer.len() >= MULTIPART_PART_SIZE && !matches!(self.upload, UploadState::Unsupported)
+    }
+
+    /// Uploads the buffered data as the next part, starting the multipart upload if needed.
+    pub async fn upload_part(&mut self) -> super::Result<()> {
+        if matches!(self.upload, UploadState::NotStarted) {
+            self.upload = match self
+                .source
+                .clone()
+                .create_multipart_upload(&self.uri, self.io_stats.clone())
+                .await?
+            {
+                Some(upload) => UploadState::Started(upload),
+                None => UploadState::Unsupported,
+            };
+        }
+        let UploadState::Started(upload) = &mut self.upload else {
+            // the whole object is put once finished instead
+            return Ok(());
+        };
+        let part = std::mem::take(&mut self.buffer);
+        if let Err(err) = upload.upload_part(part.into()).await {
+            if let UploadState::Started(upload) =
+                std::mem::replace(&mut self.upload, UploadState::NotStarted)
+            {
+                if let Err(abort_err) = upload.abort().await {
+                    log::warn!("Unable to abort the upload of {}: {abort_err}", self.uri);
+                }
+            }
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Uploads the rest of the buffered data and completes the upload.
+    pub async fn finish(mut self) -> super::Result<()> {
+        match std::mem::replace(&mut self.upload, UploadState::NotStarted) {
+            UploadState::Started(mut upload) => {
+                if !self.buffer.is_empty() {
+                    let part = std::mem::take(&mut self.buffer);
+                    if let Err(err) = upload.upload_part(part.into()).await {
+                        if let Err(abort_err) = upload.abort().await {
+                            log::warn!("Unable to abort the upload of {}: {abort_err}", self.uri);
+                        }
+                        return Err(err);
+                    }
+                }
+                upload.complete().await
+            }
+            UploadState::NotStarted | UploadState::Unsupported => {
+                self.source
+                    .put(&self.uri, self.buffer.into(), self.io_stats)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ops::Range,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use futures::stream::BoxStream;
+
+    use super::{MultipartWriter, MULTIPART_PART_SIZE};
+    use crate::{
+        object_io::{FileMetadata, GetResult, LSResult, MultipartUpload, ObjectSource},
+        stats::IOStatsRef,
+        FileFormat, Result,
+    };
+
+    /// A source which records the requests made to it instead of storing anything.
+    struct RecordingSource {
+        supports_multipart: bool,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct RecordingUpload {
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl MultipartUpload for RecordingUpload {
+        async fn upload_part(&mut self, data: Bytes) -> Result<()> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("part {}", data.len()));
+            Ok(())
+        }
+
+        async fn complete(self: Box<Self>) -> Result<()> {
+            self.requests.lock().unwrap().push("complete".to_string());
+            Ok(())
+        }
+
+        async fn abort(self: Box<Self>) -> Result<()> {
+            self.requests.lock().unwrap().push("abort".to_string());
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ObjectSource for RecordingSource {
+        async fn get(
+            &self,
+            _uri: &str,
+            _range: Option<Range<usize>>,
+            _io_stats: Option<IOStatsRef>,
+        ) -> Result<GetResult> {
+            unimplemented!()
+        }
+
+        async fn put(&self, _uri: &str, data: Bytes, _io_stats: Option<IOStatsRef>) -> Result<()> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("put {}", data.len()));
+            Ok(())
+        }
+
+        async fn create_multipart_upload(
+            self: Arc<Self>,
+            _uri: &str,
+            _io_stats: Option<IOStatsRef>,
+        ) -> Result<Option<Box<dyn MultipartUpload>>> {
+            Ok(self.supports_multipart.then(|| {
+                Box::new(RecordingUpload {
+                    requests: self.requests.clone(),
+                }) as Box<dyn MultipartUpload>
+            }))
+        }
+
+        async fn get_size(&self, _uri: &str, _io_stats: Option<IOStatsRef>) -> Result<usize> {
+            unimplemented!()
+        }
+
+        async fn glob(
+            self: Arc<Self>,
+            _glob_path: &str,
+            _fanout_limit: Option<usize>,
+            _page_size: Option<i32>,
+            _limit: Option<usize>,
+            _io_stats: Option<IOStatsRef>,
+            _file_format: Option<FileFormat>,
+        ) -> Result<BoxStream<'static, Result<FileMetadata>>> {
+            unimplemented!()
+        }
+
+        async fn ls(
+            &self,
+            _path: &str,
+            _posix: bool,
+            _continuation_token: Option<&str>,
+            _page_size: Option<i32>,
+            _io_stats: Option<IOStatsRef>,
+        ) -> Result<LSResult> {
+            unimplemented!()
+        }
+    }
+
+    /// Writes a full part followed by `tail_size` bytes and returns the requests made.
+    async fn write_object(supports_multipart: bool, tail_size: usize) -> Result<Vec<String>> {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let source = Arc::new(RecordingSource {
+            supports_multipart,
+            requests: requests.clone(),
+        });
+        let mut writer = MultipartWriter::new(source, "s3://bucket/key".to_string(), None);
+        assert!(!writer.write(&vec![0; MULTIPART_PART_SIZE - 1]));
+        if writer.write(&[0]) {
+            writer.upload_part().await?;
+        }
+        assert!(!writer.write(&vec![0; tail_size]));
+        writer.finish().await?;
+        let requests = requests.lock().unwrap().clone();
+        Ok(requests)
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_uploads_full_parts() -> Result<()> {
+        assert_eq!(
+            write_object(true, 10).await?,
+            vec![
+                format!("part {MULTIPART_PART_SIZE}"),
+                "part 10".to_string(),
+                "complete".to_string()
+            ]
+        );
+        assert_eq!(
+            write_object(true, 0).await?,
+            vec![
+                format!("part {MULTIPART_PART_SIZE}"),
+                "complete".to_string()
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_puts_without_multipart_support() -> Result<()> {
+        assert_eq!(
+            write_object(false, 10).await?,
+            vec![format!("put {}", MULTIPART_PART_SIZE + 10)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_writer_puts_small_objects() -> Result<()> {
+        let requests = Arc::new(Mutex::new(vec![]));
+        let source = Arc::new(RecordingSource {
+            supports_multipart: true,
+            requests: requests.clone(),
+        });
+        let mut writer = MultipartWriter::new(source, "s3://bucket/key".to_string(), None);
+        assert!(!writer.write(b"hello"));
+        writer.finish().await?;
+        assert_eq!(*requests.lock().unwrap(), vec!["put 5".to_string()]);
+        Ok(())
+    }
+}
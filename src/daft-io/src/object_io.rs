@@ -15,6 +15,19 @@ use crate::{
     FileFormat,
 };
 
+/// An object being uploaded in parts, which becomes visible once the upload is completed.
+#[async_trait]
+pub trait MultipartUpload: Send + Sync {
+    /// Uploads the next part of the object.
+    async fn upload_part(&mut self, data: Bytes) -> super::Result<()>;
+
+    /// Completes the upload, assembling the object from its parts in the order they were uploaded.
+    async fn complete(self: Box<Self>) -> super::Result<()>;
+
+    /// Abandons the upload, discarding the parts uploaded so far.
+    async fn abort(self: Box<Self>) -> super::Result<()>;
+}
+
 pub struct StreamingRetryParams {
     source: Arc<dyn ObjectSource>,
     input: String,
@@ -164,7 +177,17 @@ pub struct FileMetadata {
     pub filepath: String,
     pub size: Option<u64>,
     pub filetype: FileType,
+    /// The validator of the file as returned by [`ObjectSource::get_metadata`], if it was listed.
+    pub validator: Option<String>,
+}
+/// The size of an object along with a validator which changes whenever the object does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectMetadata {
+    pub size: usize,
+    /// The ETag or last modified time of the object, if the source provides one.
+    pub validator: Option<String>,
 }
+
 #[derive(Debug)]
 pub struct LSResult {
     pub files: Vec<FileMetadata>,
@@ -189,8 +212,31 @@ pub trait ObjectSource: Sync + Send {
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<()>;
 
+    /// Starts an upload of an object in parts, or returns `None` if the source can only upload whole objects.
+    async fn create_multipart_upload(
+        self: Arc<Self>,
+        _uri: &str,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Box<dyn MultipartUpload>>> {
+        Ok(None)
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    /// Returns the size and validator of an object with a single request.
+    ///
+    /// Sources which can't tell whether an object changed only return its size.
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
+        Ok(ObjectMetadata {
+            size: self.get_size(uri, io_stats).await?,
+            validator: None,
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -402,9 +402,9 @@ pub async fn glob(
             if !glob.ends_with(GLOB_DELIMITER) {
                 attempt_as_dir = false;
                 // If doesn't have a glob character and doesn't end with a delimiter, assume its a file first.
-                let maybe_size = source.get_size(&glob, io_stats.clone()).await;
-                match maybe_size {
-                    Ok(size_bytes) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(size_bytes as u64), filetype: FileType::File  }),
+                let maybe_metadata = source.get_metadata(&glob, io_stats.clone()).await;
+                match maybe_metadata {
+                    Ok(metadata) => yield Ok(FileMetadata{filepath: glob.clone(), size: Some(metadata.size as u64), filetype: FileType::File, validator: metadata.validator }),
                     Err(crate::Error::NotAFile {..} | crate::Error::NotFound { .. } | crate::Error::UnableToDetermineSize { .. }) => {attempt_as_dir = true;},
                     Err(err) => yield Err(err),
                 }
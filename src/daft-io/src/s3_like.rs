@@ -35,7 +35,7 @@ use url::{ParseError, Position};
 
 use super::object_io::{GetResult, ObjectSource};
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    object_io::{FileMetadata, FileType, LSResult, MultipartUpload, ObjectMetadata},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
     FileFormat, InvalidArgumentSnafu, SourceType,
@@ -69,6 +69,9 @@ enum Error {
         source: SdkError<PutObjectError, Response>,
     },
 
+    #[snafu(display("Unable to upload the parts of {}: {}", path, msg))]
+    UnableToUploadParts { path: String, msg: String },
+
     #[snafu(display("Unable to head {}: {}", path, s3::error::DisplayErrorContext(source)))]
     UnableToHeadFile {
         path: String,
@@ -727,7 +730,7 @@ impl S3LikeSource {
         permit: SemaphorePermit<'async_recursion>,
         uri: &str,
         region: &Region,
-    ) -> super::Result<usize> {
+    ) -> super::Result<ObjectMetadata> {
         log::debug!("S3 head at {uri} in region: {region}");
         let (_scheme, bucket, key) = parse_url(uri)?;
 
@@ -773,7 +776,13 @@ impl S3LikeSource {
             };
 
             match response {
-                Ok(v) => Ok(v.content_length() as usize),
+                Ok(v) => Ok(ObjectMetadata {
+                    size: v.content_length() as usize,
+                    validator: v.e_tag().map(str::to_string).or_else(|| {
+                        v.last_modified()
+                            .map(|modified| modified.as_nanos().to_string())
+                    }),
+                }),
                 Err(SdkError::ServiceError(err)) => {
                     let bad_response = err.raw().http();
                     match bad_response.status() {
@@ -893,6 +902,7 @@ impl S3LikeSource {
                             ),
                             size: None,
                             filetype: FileType::Directory,
+                            validator: None,
                         };
                         all_files.push(fmeta);
                     }
@@ -906,6 +916,10 @@ impl S3LikeSource {
                             ),
                             size: Some(f.size() as u64),
                             filetype: FileType::File,
+                            validator: f.e_tag().map(str::to_string).or_else(|| {
+                                f.last_modified()
+                                    .map(|modified| modified.as_nanos().to_string())
+                            }),
                         };
                         all_files.push(fmeta);
                     }
@@ -1002,6 +1016,104 @@ impl S3LikeSource {
     }
 }
 
+/// A multipart upload to S3, whose parts are uploaded one at a time.
+struct S3MultipartUpload {
+    client: Arc<s3::Client>,
+    connection_pool_sema: Arc<tokio::sync::Semaphore>,
+    uri: String,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    request_payer: Option<s3::types::RequestPayer>,
+    parts: Vec<s3::types::CompletedPart>,
+    io_stats: Option<IOStatsRef>,
+}
+
+impl S3MultipartUpload {
+    fn upload_error<E: std::error::Error + Send + Sync + 'static>(
+        &self,
+        err: SdkError<E, Response>,
+    ) -> super::Error {
+        Error::UnableToUploadParts {
+            path: self.uri.clone(),
+            msg: DisplayErrorContext(err).to_string(),
+        }
+        .into()
+    }
+}
+
+#[async_trait]
+impl MultipartUpload for S3MultipartUpload {
+    async fn upload_part(&mut self, data: bytes::Bytes) -> super::Result<()> {
+        let data_len = data.len();
+        let part_number = self.parts.len() as i32 + 1;
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let response = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .set_request_payer(self.request_payer.clone())
+            .body(data.into())
+            .send()
+            .await
+            .map_err(|err| self.upload_error(err))?;
+        self.parts.push(
+            s3::types::CompletedPart::builder()
+                .set_e_tag(response.e_tag().map(str::to_string))
+                .part_number(part_number)
+                .build(),
+        );
+        if let Some(io_stats) = &self.io_stats {
+            io_stats.mark_put_requests(1);
+            io_stats.mark_bytes_uploaded(data_len);
+        }
+        Ok(())
+    }
+
+    async fn complete(self: Box<Self>) -> super::Result<()> {
+        let _permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        self.client
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .set_request_payer(self.request_payer.clone())
+            .multipart_upload(
+                s3::types::CompletedMultipartUpload::builder()
+                    .set_parts(Some(self.parts.clone()))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(|err| self.upload_error(err))?;
+        Ok(())
+    }
+
+    async fn abort(self: Box<Self>) -> super::Result<()> {
+        self.client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id)
+            .set_request_payer(self.request_payer.clone())
+            .send()
+            .await
+            .map_err(|err| self.upload_error(err))?;
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl ObjectSource for S3LikeSource {
     async fn get(
@@ -1063,7 +1175,79 @@ impl ObjectSource for S3LikeSource {
         Ok(())
     }
 
+    async fn create_multipart_upload(
+        self: Arc<Self>,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<Box<dyn MultipartUpload>>> {
+        if self.anonymous {
+            return Err(Error::UploadsCannotBeAnonymous {}.into());
+        }
+        let (_scheme, bucket, key) = parse_url(uri)?;
+        if key.is_empty() {
+            return Err(Error::NotAFile { path: uri.into() }.into());
+        }
+        let client = self.get_s3_client(&self.default_region).await?;
+        let request_payer = self
+            .s3_config
+            .requester_pays
+            .then_some(s3::types::RequestPayer::Requester);
+        let upload = {
+            let _permit = self
+                .connection_pool_sema
+                .acquire()
+                .await
+                .context(UnableToGrabSemaphoreSnafu)?;
+            client
+                .create_multipart_upload()
+                .bucket(&bucket)
+                .key(&key)
+                .set_request_payer(request_payer.clone())
+                .send()
+                .await
+                .map_err(|err| Error::UnableToUploadParts {
+                    path: uri.into(),
+                    msg: DisplayErrorContext(err).to_string(),
+                })?
+        };
+        let Some(upload_id) = upload.upload_id() else {
+            return Err(Error::UnableToUploadParts {
+                path: uri.into(),
+                msg: "S3 did not return an upload id".to_string(),
+            }
+            .into());
+        };
+        Ok(Some(Box::new(S3MultipartUpload {
+            client,
+            connection_pool_sema: self.connection_pool_sema.clone(),
+            uri: uri.to_string(),
+            bucket,
+            key,
+            upload_id: upload_id.to_string(),
+            request_payer,
+            parts: vec![],
+            io_stats,
+        })))
+    }
+
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
+        let permit = self
+            .connection_pool_sema
+            .acquire()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        let head_result = self.head_impl(permit, uri, &self.default_region).await?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_head_requests(1);
+        }
+        Ok(head_result.size)
+    }
+
+    async fn get_metadata(
+        &self,
+        uri: &str,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<ObjectMetadata> {
         let permit = self
             .connection_pool_sema
             .acquire()
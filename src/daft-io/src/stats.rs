@@ -17,6 +17,9 @@ pub struct IOStatsContext {
     num_put_requests: atomic::AtomicUsize,
     bytes_read: atomic::AtomicUsize,
     bytes_uploaded: atomic::AtomicUsize,
+    bytes_pruned: atomic::AtomicUsize,
+    num_cache_hits: atomic::AtomicUsize,
+    num_cache_misses: atomic::AtomicUsize,
 }
 
 impl Drop for IOStatsContext {
@@ -28,7 +31,7 @@ impl Drop for IOStatsContext {
         let mean_get_size = (bytes_read as f64) / (num_gets as f64);
         let mean_put_size = (bytes_uploaded as f64) / (num_puts as f64);
         log::info!(
-            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}",
+            "IOStatsContext: {}, Gets: {}, Heads: {}, Lists: {}, BytesRead: {}, AvgGetSize: {}, BytesUploaded: {}, AvgPutSize: {}, BytesPruned: {}, CacheHits: {}, CacheMisses: {}",
             self.name,
             num_gets,
             self.load_head_requests(),
@@ -37,6 +40,9 @@ impl Drop for IOStatsContext {
             mean_get_size as i64,
             bytes_uploaded,
             mean_put_size as i64,
+            self.load_bytes_pruned(),
+            self.load_cache_hits(),
+            self.load_cache_misses(),
         );
     }
 }
@@ -57,6 +63,9 @@ impl IOStatsContext {
             num_put_requests: atomic::AtomicUsize::new(0),
             bytes_read: atomic::AtomicUsize::new(0),
             bytes_uploaded: atomic::AtomicUsize::new(0),
+            bytes_pruned: atomic::AtomicUsize::new(0),
+            num_cache_hits: atomic::AtomicUsize::new(0),
+            num_cache_misses: atomic::AtomicUsize::new(0),
         })
     }
 
@@ -116,6 +125,25 @@ impl IOStatsContext {
             .fetch_add(bytes_uploaded, atomic::Ordering::Relaxed);
     }
 
+    /// Marks bytes that a reader skipped instead of fetching, e.g. with file statistics or indexes.
+    #[inline]
+    pub fn mark_bytes_pruned(&self, bytes_pruned: usize) {
+        self.bytes_pruned
+            .fetch_add(bytes_pruned, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_cache_hits(&self, num_hits: usize) {
+        self.num_cache_hits
+            .fetch_add(num_hits, atomic::Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn mark_cache_misses(&self, num_misses: usize) {
+        self.num_cache_misses
+            .fetch_add(num_misses, atomic::Ordering::Relaxed);
+    }
+
     #[inline]
     pub fn load_bytes_read(&self) -> usize {
         self.bytes_read.load(atomic::Ordering::Acquire)
@@ -125,6 +153,21 @@ impl IOStatsContext {
     pub fn load_bytes_uploaded(&self) -> usize {
         self.bytes_uploaded.load(atomic::Ordering::Acquire)
     }
+
+    #[inline]
+    pub fn load_bytes_pruned(&self) -> usize {
+        self.bytes_pruned.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_cache_hits(&self) -> usize {
+        self.num_cache_hits.load(atomic::Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn load_cache_misses(&self) -> usize {
+        self.num_cache_misses.load(atomic::Ordering::Acquire)
+    }
 }
 
 impl IOStatsByteStreamContextHandle {
@@ -0,0 +1,36 @@
+//! Reader for the Arrow IPC file and stream formats.
+//!
+//! Both flavours are accepted wherever a path is given: files are recognised by their `ARROW1`
+//! magic bytes and everything else is read as a bare IPC stream.
+mod read;
+mod schema;
+
+use common_error::DaftError;
+pub use read::{read_ipc_bulk, stream_ipc, IpcConvertOptions};
+pub use schema::read_ipc_schema;
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::JoinError { source } => Self::External(Box::new(source)),
+        }
+    }
+}
+
+/// Magic bytes at the start of an Arrow IPC file; a bare IPC stream has no such header.
+const ARROW_MAGIC: &[u8] = b"ARROW1";
+
+/// Length of the file header, i.e. the magic bytes padded to an 8-byte boundary. The IPC
+/// stream, beginning with the schema message, follows right after it.
+const FILE_HEADER_LEN: usize = 8;
+
+fn is_ipc_file(bytes: &[u8]) -> bool {
+    bytes.starts_with(ARROW_MAGIC)
+}
@@ -0,0 +1,872 @@
+use std::{
+    io::{BufReader, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::ipc::read::{
+        read_batch, read_batch_body_ranges, read_file_dictionaries, read_file_metadata,
+        read_stream_metadata, Dictionaries, FileMetadata, StreamReader, StreamState,
+    },
+};
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+    utils::arrow::cast_array_for_daft_if_needed,
+};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{GetResult, IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::{futures::try_future::TryFutureExt, ResultExt};
+use tokio_util::io::{StreamReader as ByteStreamReader, SyncIoBridge};
+
+use crate::{is_ipc_file, JoinSnafu, FILE_HEADER_LEN};
+
+/// Number of bytes fetched from the end of an IPC file to read its footer, which covers the footer
+/// of all but very wide files or files with very many record batches. A second request is only
+/// made if the footer turns out to be larger.
+const INITIAL_FOOTER_FETCH_SIZE: usize = 64 * 1024;
+
+/// Length of the file trailer, i.e. the footer length followed by the magic bytes.
+const FILE_TRAILER_LEN: usize = 10;
+
+/// Gaps of up to this many bytes between the columns read from a record batch are fetched along
+/// with them rather than split into separate requests.
+const MAX_HOLE_SIZE: usize = 1024 * 1024;
+
+type TableStream = BoxStream<'static, DaftResult<RecordBatch>>;
+
+#[derive(Debug, Clone, Default)]
+pub struct IpcConvertOptions {
+    pub limit: Option<usize>,
+    pub include_columns: Option<Vec<String>>,
+    pub predicate: Option<ExprRef>,
+}
+
+/// Streams the record batches of a single Arrow IPC file or stream, with the projection, predicate
+/// and limit in `convert_options` applied.
+pub async fn stream_ipc(
+    uri: String,
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<TableStream> {
+    let (_, tables) = open_ipc(uri, convert_options, io_client, io_stats).await?;
+    Ok(tables)
+}
+
+pub fn read_ipc_bulk(
+    uris: &[&str],
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().map(|uri| {
+            let (uri, convert_options, io_client, io_stats) = (
+                (*uri).to_string(),
+                convert_options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_ipc_single_into_table(uri, convert_options, io_client, io_stats).await
+            })
+            .context(JoinSnafu {})
+        }));
+        let mut remaining_rows = convert_options.limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_ipc_single_into_table(
+    uri: String,
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<RecordBatch> {
+    let (schema, tables) = open_ipc(uri, convert_options, io_client, io_stats).await?;
+    let tables = tables.try_collect::<Vec<_>>().await?;
+    if tables.is_empty() {
+        RecordBatch::empty(Some(schema))
+    } else {
+        RecordBatch::concat(&tables)
+    }
+}
+
+/// Opens the Arrow IPC file or stream at `uri`, returning the schema of the tables it produces
+/// along with a stream of the tables.
+async fn open_ipc(
+    uri: String,
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(SchemaRef, TableStream)> {
+    let size = io_client
+        .single_url_get_size(uri.clone(), io_stats.clone())
+        .await?;
+    if size == 0 {
+        return Err(DaftError::ValueError(format!(
+            "Unable to read Arrow IPC data from {uri}: file is empty"
+        )));
+    }
+    let header = get_range(
+        &io_client,
+        &uri,
+        0..size.min(FILE_HEADER_LEN),
+        io_stats.clone(),
+    )
+    .await?;
+    if is_ipc_file(&header) {
+        IpcFileReader::try_new(uri, size, header, io_client, io_stats)
+            .await?
+            .into_stream(convert_options)
+    } else {
+        open_ipc_stream(uri, convert_options, io_client, io_stats).await
+    }
+}
+
+async fn get_range(
+    io_client: &IOClient,
+    uri: &str,
+    range: Range<usize>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Bytes> {
+    Ok(io_client
+        .single_url_get(uri.to_string(), Some(range), io_stats)
+        .await?
+        .bytes()
+        .await?)
+}
+
+/// Converts the offset and length of a block in an IPC file footer into a byte range.
+fn block_range(uri: &str, offset: i64, length: i64) -> DaftResult<Range<usize>> {
+    match (usize::try_from(offset), usize::try_from(length)) {
+        (Ok(offset), Ok(length)) => Ok(offset..offset + length),
+        _ => Err(DaftError::ValueError(format!(
+            "Unable to read Arrow IPC file {uri}: footer has a block at offset {offset} with length {length}"
+        ))),
+    }
+}
+
+/// Merges byte ranges that are at most [`MAX_HOLE_SIZE`] apart, dropping empty ones.
+fn coalesce_ranges(mut ranges: Vec<Range<u64>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| range.start);
+    let mut coalesced: Vec<Range<usize>> = vec![];
+    for range in ranges {
+        let range = range.start as usize..range.end as usize;
+        if range.is_empty() {
+            continue;
+        }
+        match coalesced.last_mut() {
+            Some(last) if range.start <= last.end + MAX_HOLE_SIZE => {
+                last.end = last.end.max(range.end);
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+/// The byte ranges fetched from an IPC file, exposed as a reader over the whole file so that the
+/// arrow2 IPC file functions can decode from them. Reading bytes that were not fetched fails.
+struct FetchedRanges {
+    ranges: Vec<(usize, Bytes)>,
+    size: usize,
+    position: usize,
+}
+
+impl FetchedRanges {
+    fn new(size: usize) -> Self {
+        Self {
+            ranges: vec![],
+            size,
+            position: 0,
+        }
+    }
+
+    fn insert(&mut self, start: usize, bytes: Bytes) {
+        self.ranges.push((start, bytes));
+    }
+}
+
+impl Read for FetchedRanges {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.size {
+            return Ok(0);
+        }
+        let position = self.position;
+        let (start, bytes) = self
+            .ranges
+            .iter()
+            .find(|(start, bytes)| *start <= position && position < start + bytes.len())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("byte {position} of the Arrow IPC file was not fetched"),
+                )
+            })?;
+        let available = &bytes[position - start..];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+impl Seek for FetchedRanges {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => usize::try_from(offset).ok(),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset as isize),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset as isize),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position as u64)
+    }
+}
+
+/// Reads the record batches of an Arrow IPC file.
+///
+/// The footer is fetched first, and then only the record batches that are read, and of those only
+/// the byte ranges of the projected columns.
+struct IpcFileReader {
+    uri: String,
+    size: usize,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    metadata: FileMetadata,
+    dictionaries: Dictionaries,
+}
+
+impl IpcFileReader {
+    async fn try_new(
+        uri: String,
+        size: usize,
+        header: Bytes,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let mut file = FetchedRanges::new(size);
+        file.insert(0, header);
+
+        // The file ends with the footer, followed by the footer length and the magic bytes.
+        let tail_start = size.saturating_sub(INITIAL_FOOTER_FETCH_SIZE);
+        let tail = get_range(&io_client, &uri, tail_start..size, io_stats.clone()).await?;
+        let footer_start = tail
+            .len()
+            .checked_sub(FILE_TRAILER_LEN)
+            .and_then(|trailer_start| {
+                let footer_len =
+                    i32::from_le_bytes(tail[trailer_start..trailer_start + 4].try_into().unwrap());
+                (tail_start + trailer_start).checked_sub(usize::try_from(footer_len).ok()?)
+            })
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "Unable to read Arrow IPC file {uri}: invalid footer length"
+                ))
+            })?;
+        if footer_start < tail_start {
+            let footer =
+                get_range(&io_client, &uri, footer_start..tail_start, io_stats.clone()).await?;
+            file.insert(footer_start, footer);
+        }
+        file.insert(tail_start, tail);
+        let metadata = read_file_metadata(&mut file)?;
+
+        let mut reader = Self {
+            uri,
+            size,
+            io_client,
+            io_stats,
+            metadata,
+            dictionaries: Dictionaries::default(),
+        };
+        // Dictionaries are shared by all record batches, so they are fetched and decoded up front.
+        if let Some(blocks) = &reader.metadata.dictionaries {
+            let ranges = blocks
+                .iter()
+                .map(|block| {
+                    block_range(
+                        &reader.uri,
+                        block.offset,
+                        i64::from(block.meta_data_length) + block.body_length,
+                    )
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            for (start, bytes) in reader.get_ranges(ranges).await? {
+                file.insert(start, bytes);
+            }
+            reader.dictionaries = read_file_dictionaries(&mut file, &reader.metadata, &mut vec![])?;
+        }
+        Ok(reader)
+    }
+
+    async fn get_ranges(&self, ranges: Vec<Range<usize>>) -> DaftResult<Vec<(usize, Bytes)>> {
+        futures::future::try_join_all(ranges.into_iter().map(|range| async move {
+            let start = range.start;
+            let bytes = get_range(&self.io_client, &self.uri, range, self.io_stats.clone()).await?;
+            Ok((start, bytes))
+        }))
+        .await
+    }
+
+    /// Reads the record batch at position `index`, fetching its message and then the byte ranges
+    /// of the columns in `projection`.
+    async fn read_batch(
+        &self,
+        index: usize,
+        projection: Option<&[usize]>,
+        limit: Option<usize>,
+    ) -> DaftResult<Chunk<Box<dyn Array>>> {
+        let block = self.metadata.blocks[index];
+        let message_range =
+            block_range(&self.uri, block.offset, i64::from(block.meta_data_length))?;
+        let message_start = message_range.start;
+        let message = get_range(
+            &self.io_client,
+            &self.uri,
+            message_range,
+            self.io_stats.clone(),
+        )
+        .await?;
+        let body_ranges = read_batch_body_ranges(&self.metadata, projection, index, &message)?;
+
+        let mut file = FetchedRanges::new(self.size);
+        file.insert(message_start, message);
+        for (start, bytes) in self.get_ranges(coalesce_ranges(body_ranges)).await? {
+            file.insert(start, bytes);
+        }
+        Ok(read_batch(
+            &mut file,
+            &self.dictionaries,
+            &self.metadata,
+            projection,
+            limit,
+            index,
+            &mut vec![],
+            &mut vec![],
+        )?)
+    }
+
+    fn into_stream(
+        self,
+        convert_options: IpcConvertOptions,
+    ) -> DaftResult<(SchemaRef, TableStream)> {
+        let (converter, projection) =
+            ChunkConverter::try_new(&self.metadata.schema, convert_options)?;
+        let schema = converter.output_schema.clone();
+        let reader = Arc::new(self);
+        // Record batches are read one at a time as the stream is polled, so that nothing past the
+        // limit is fetched.
+        let tables = futures::stream::try_unfold((converter, 0), move |(mut converter, index)| {
+            let reader = reader.clone();
+            let projection = projection.clone();
+            async move {
+                if index >= reader.metadata.blocks.len() || converter.is_done() {
+                    return Ok(None);
+                }
+                let chunk = reader
+                    .read_batch(index, projection.as_deref(), converter.decode_limit())
+                    .await?;
+                let table = converter.convert(chunk)?;
+                Ok(Some((table, (converter, index + 1))))
+            }
+        });
+        Ok((schema, tables.boxed()))
+    }
+}
+
+/// Decodes the Arrow IPC stream at `uri` incrementally as it is downloaded.
+///
+/// arrow2 only has a blocking reader for projected IPC streams, so decoding runs on a blocking
+/// thread that reads from the download and sends the tables back over a channel.
+async fn open_ipc_stream(
+    uri: String,
+    convert_options: IpcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<(SchemaRef, TableStream)> {
+    let reader: Box<dyn Read + Send> = match io_client.single_url_get(uri, None, io_stats).await? {
+        GetResult::File(file) => Box::new(std::fs::File::open(file.path)?),
+        GetResult::Stream(stream, ..) => Box::new(SyncIoBridge::new(ByteStreamReader::new(stream))),
+    };
+    let (schema_tx, schema_rx) = tokio::sync::oneshot::channel();
+    let (table_tx, table_rx) = tokio::sync::mpsc::channel(1);
+    let handle = tokio::task::spawn_blocking(move || {
+        let mut reader = BufReader::new(reader);
+        let opened = read_stream_metadata(&mut reader)
+            .map_err(DaftError::from)
+            .and_then(|metadata| {
+                let (converter, projection) =
+                    ChunkConverter::try_new(&metadata.schema, convert_options)?;
+                Ok((converter, StreamReader::new(reader, metadata, projection)))
+            });
+        let (mut converter, mut chunks) = match opened {
+            Ok((converter, chunks)) => {
+                let _ = schema_tx.send(Ok(converter.output_schema.clone()));
+                (converter, chunks)
+            }
+            Err(err) => {
+                let _ = schema_tx.send(Err(err));
+                return;
+            }
+        };
+        while !converter.is_done() {
+            let table = match chunks.next() {
+                Some(Ok(StreamState::Some(chunk))) => converter.convert(chunk),
+                // The download has ended, with or without an end-of-stream marker.
+                Some(Ok(StreamState::Waiting)) | None => break,
+                Some(Err(err)) => Err(err.into()),
+            };
+            let failed = table.is_err();
+            // Stop once the consumer has dropped the stream or an error has been sent.
+            if table_tx.blocking_send(table).is_err() || failed {
+                break;
+            }
+        }
+    });
+    let schema = match schema_rx.await {
+        Ok(schema) => schema?,
+        Err(_) => {
+            handle.await.context(JoinSnafu {})?;
+            unreachable!("the schema is sent before the decoding thread returns")
+        }
+    };
+    let tables = futures::stream::unfold(table_rx, |mut table_rx| async move {
+        table_rx.recv().await.map(|table| (table, table_rx))
+    })
+    // Surface a panic of the decoding thread rather than silently ending the stream.
+    .chain(
+        futures::stream::once(handle).filter_map(|result| async move {
+            result
+                .context(JoinSnafu {})
+                .err()
+                .map(|err| Err(err.into()))
+        }),
+    );
+    Ok((schema, tables.boxed()))
+}
+
+/// Converts the chunks decoded from an Arrow IPC file or stream into [`RecordBatch`]es, applying
+/// the predicate, projection and limit.
+///
+/// Only the columns needed for `include_columns` and the predicate are decoded.
+struct ChunkConverter {
+    // Schema of the decoded columns, in file order.
+    read_schema: SchemaRef,
+    // Schema of the emitted tables, after the predicate columns have been dropped again.
+    output_schema: SchemaRef,
+    include_columns: Option<Vec<String>>,
+    predicate: Option<ExprRef>,
+    remaining_rows: Option<usize>,
+}
+
+impl ChunkConverter {
+    /// Returns the converter along with the indices of the columns to decode.
+    fn try_new(
+        arrow_schema: &ArrowSchema,
+        convert_options: IpcConvertOptions,
+    ) -> DaftResult<(Self, Option<Vec<usize>>)> {
+        let IpcConvertOptions {
+            limit,
+            include_columns,
+            predicate,
+        } = convert_options;
+
+        let column_index = |name: &str| {
+            arrow_schema
+                .fields
+                .iter()
+                .position(|field| field.name == name)
+        };
+        // Columns missing from this file are left out here and filled with nulls when the table is cast
+        // to the scan's schema.
+        let include_columns = include_columns.map(|include_columns| {
+            include_columns
+                .into_iter()
+                .filter(|name| column_index(name).is_some())
+                .collect::<Vec<_>>()
+        });
+        let projection = match &include_columns {
+            Some(include_columns) => {
+                let mut required = include_columns.clone();
+                if let Some(predicate) = &predicate {
+                    required.extend(get_required_columns(predicate));
+                }
+                let mut projection = required
+                    .iter()
+                    .map(|name| {
+                        column_index(name).ok_or_else(|| {
+                            DaftError::FieldNotFound(format!(
+                                "Column {name} not found in Arrow IPC schema"
+                            ))
+                        })
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                projection.sort_unstable();
+                projection.dedup();
+                // Keep a single column around when nothing is selected so that the decoded chunks still
+                // carry their row counts.
+                if projection.is_empty() && !arrow_schema.fields.is_empty() {
+                    projection.push(0);
+                }
+                Some(projection)
+            }
+            None => None,
+        };
+        let read_schema = Arc::new(Schema::try_from(&ArrowSchema::from(match &projection {
+            Some(projection) => projection
+                .iter()
+                .map(|i| arrow_schema.fields[*i].clone())
+                .collect(),
+            None => arrow_schema.fields.clone(),
+        }))?);
+        let output_schema = match &include_columns {
+            Some(include_columns) => Arc::new(Schema::new(
+                include_columns
+                    .iter()
+                    .map(|name| read_schema.get_field(name).cloned())
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )?),
+            None => read_schema.clone(),
+        };
+        let converter = Self {
+            read_schema,
+            output_schema,
+            include_columns,
+            predicate,
+            remaining_rows: limit,
+        };
+        Ok((converter, projection))
+    }
+
+    /// Whether the limit has been reached, so that no more chunks need to be decoded.
+    fn is_done(&self) -> bool {
+        self.remaining_rows == Some(0)
+    }
+
+    /// The number of rows left to decode. With a predicate the limit only applies to the rows
+    /// that pass it, so it can't be handed to the decoder.
+    fn decode_limit(&self) -> Option<usize> {
+        if self.predicate.is_none() {
+            self.remaining_rows
+        } else {
+            None
+        }
+    }
+
+    fn convert(&mut self, chunk: Chunk<Box<dyn Array>>) -> DaftResult<RecordBatch> {
+        let num_rows = chunk.len();
+        let columns = chunk
+            .into_arrays()
+            .into_iter()
+            .zip(self.read_schema.fields.values())
+            .map(|(array, field)| {
+                Series::try_from_field_and_arrow_array(
+                    Arc::new(field.clone()),
+                    cast_array_for_daft_if_needed(array),
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let mut table = RecordBatch::new_with_size(self.read_schema.clone(), columns, num_rows)?;
+        if let Some(predicate) = &self.predicate {
+            table = table.filter(&[predicate.clone()])?;
+        }
+        if let Some(include_columns) = &self.include_columns {
+            table = table.get_columns(include_columns)?;
+        }
+        if let Some(remaining) = &mut self.remaining_rows {
+            if table.len() > *remaining {
+                table = table.head(*remaining)?;
+            }
+            *remaining -= table.len();
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, sync::Arc};
+
+    use arrow2::{
+        array::{
+            Array, DictionaryArray, Int64Array, MutableDictionaryArray, MutableUtf8Array,
+            TryExtend, Utf8Array,
+        },
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+        io::ipc::{
+            read::{read_batch_body_ranges, read_file_metadata},
+            write::{FileWriter, StreamWriter, WriteOptions},
+        },
+    };
+    use bytes::Bytes;
+    use common_error::DaftResult;
+    use daft_core::prelude::AsArrow;
+    use daft_dsl::{lit, resolved_col};
+    use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
+
+    use super::{read_ipc_single_into_table, IpcConvertOptions};
+
+    fn chunks() -> (Schema, Vec<Chunk<Box<dyn Array>>>) {
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let chunks = (0..3)
+            .map(|i| {
+                Chunk::new(vec![
+                    Int64Array::from_vec((i * 4..i * 4 + 4).collect()).boxed(),
+                    Utf8Array::<i32>::from_iter_values((0..4).map(|j| format!("{i}-{j}"))).boxed(),
+                ])
+            })
+            .collect();
+        (schema, chunks)
+    }
+
+    fn write_file() -> Bytes {
+        let (schema, chunks) = chunks();
+        let mut writer = FileWriter::try_new(
+            Cursor::new(vec![]),
+            schema,
+            None,
+            WriteOptions { compression: None },
+        )
+        .unwrap();
+        for chunk in &chunks {
+            writer.write(chunk, None).unwrap();
+        }
+        writer.finish().unwrap();
+        writer.into_inner().into_inner().into()
+    }
+
+    fn write_stream() -> Bytes {
+        let (schema, chunks) = chunks();
+        let mut writer = StreamWriter::new(Cursor::new(vec![]), WriteOptions { compression: None });
+        writer.start(&schema, None).unwrap();
+        for chunk in &chunks {
+            writer.write(chunk, None).unwrap();
+        }
+        writer.finish().unwrap();
+        writer.into_inner().into_inner().into()
+    }
+
+    async fn read(bytes: Bytes, convert_options: IpcConvertOptions) -> DaftResult<RecordBatch> {
+        let file = tempfile::NamedTempFile::new()?;
+        std::fs::write(file.path(), bytes)?;
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let uri = file.path().to_str().unwrap().to_string();
+        read_ipc_single_into_table(uri, convert_options, io_client, None).await
+    }
+
+    fn column_a(table: &RecordBatch) -> DaftResult<Vec<i64>> {
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn read_file_and_stream() -> DaftResult<()> {
+        for bytes in [write_file(), write_stream()] {
+            let table = read(bytes, IpcConvertOptions::default()).await?;
+            assert_eq!(table.len(), 12);
+            assert_eq!(table.schema.names(), vec!["a".to_string(), "b".to_string()]);
+            assert_eq!(column_a(&table)?, (0..12).collect::<Vec<_>>());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_with_projection_and_limit() -> DaftResult<()> {
+        for bytes in [write_file(), write_stream()] {
+            let table = read(
+                bytes,
+                IpcConvertOptions {
+                    limit: Some(6),
+                    include_columns: Some(vec!["a".to_string()]),
+                    predicate: None,
+                },
+            )
+            .await?;
+            assert_eq!(table.schema.names(), vec!["a".to_string()]);
+            assert_eq!(column_a(&table)?, (0..6).collect::<Vec<_>>());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_with_predicate_on_unselected_column() -> DaftResult<()> {
+        for bytes in [write_file(), write_stream()] {
+            let table = read(
+                bytes,
+                IpcConvertOptions {
+                    limit: Some(2),
+                    include_columns: Some(vec!["b".to_string()]),
+                    predicate: Some(resolved_col("a").gt_eq(lit(5i64))),
+                },
+            )
+            .await?;
+            assert_eq!(table.schema.names(), vec!["b".to_string()]);
+            let b = table.get_column("b")?.utf8()?.as_arrow().clone();
+            assert_eq!(b.values_iter().collect::<Vec<_>>(), vec!["1-1", "1-2"]);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_without_columns_keeps_row_count() -> DaftResult<()> {
+        let table = read(
+            write_file(),
+            IpcConvertOptions {
+                limit: None,
+                include_columns: Some(vec![]),
+                predicate: None,
+            },
+        )
+        .await?;
+        assert_eq!(table.num_columns(), 0);
+        assert_eq!(table.len(), 12);
+        Ok(())
+    }
+
+    #[test]
+    fn batch_body_ranges_cover_only_projected_columns() -> DaftResult<()> {
+        let bytes = write_file();
+        let metadata = read_file_metadata(&mut Cursor::new(&bytes))?;
+        let block = metadata.blocks[0];
+        let message_start = block.offset as usize;
+        let message = &bytes[message_start..message_start + block.meta_data_length as usize];
+        let range_len = |projection: Option<&[usize]>| -> DaftResult<u64> {
+            Ok(read_batch_body_ranges(&metadata, projection, 0, message)?
+                .iter()
+                .map(|range| range.end - range.start)
+                .sum())
+        };
+        let body_start = (block.offset + i64::from(block.meta_data_length)) as u64;
+        let (a_len, b_len, all_len) = (
+            range_len(Some(&[0]))?,
+            range_len(Some(&[1]))?,
+            range_len(None)?,
+        );
+        assert!(a_len > 0 && b_len > 0);
+        assert_eq!(a_len + b_len, all_len);
+        assert!(all_len <= block.body_length as u64);
+        for range in read_batch_body_ranges(&metadata, Some(&[1]), 0, message)? {
+            assert!(range.start >= body_start);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn read_file_with_large_footer() -> DaftResult<()> {
+        // Enough record batches that the footer doesn't fit in the initial fetch.
+        let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+        let mut writer = FileWriter::try_new(
+            Cursor::new(vec![]),
+            schema,
+            None,
+            WriteOptions { compression: None },
+        )
+        .unwrap();
+        for i in 0..3000 {
+            let chunk = Chunk::new(vec![Int64Array::from_vec(vec![i]).boxed()]);
+            writer.write(&chunk, None).unwrap();
+        }
+        writer.finish().unwrap();
+        let bytes: Bytes = writer.into_inner().into_inner().into();
+        let table = read(
+            bytes,
+            IpcConvertOptions {
+                limit: Some(2500),
+                include_columns: None,
+                predicate: None,
+            },
+        )
+        .await?;
+        assert_eq!(column_a(&table)?, (0..2500).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    /// Daft has no dictionary type, but the file's dictionaries are still decoded up front when
+    /// reading its other columns.
+    #[tokio::test]
+    async fn read_file_with_dictionary_column() -> DaftResult<()> {
+        let mut values = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+        values.try_extend(["x", "y", "x"].map(Some)).unwrap();
+        let values: DictionaryArray<i32> = values.into();
+        let schema = Schema::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("d", values.data_type().clone(), true),
+        ]);
+        let mut writer = FileWriter::try_new(
+            Cursor::new(vec![]),
+            schema,
+            None,
+            WriteOptions { compression: None },
+        )
+        .unwrap();
+        let chunk = Chunk::new(vec![
+            Int64Array::from_vec(vec![0, 1, 2]).boxed(),
+            values.boxed(),
+        ]);
+        writer.write(&chunk, None).unwrap();
+        writer.finish().unwrap();
+        let table = read(
+            writer.into_inner().into_inner().into(),
+            IpcConvertOptions {
+                limit: None,
+                include_columns: Some(vec!["a".to_string()]),
+                predicate: None,
+            },
+        )
+        .await?;
+        assert_eq!(column_a(&table)?, vec![0, 1, 2]);
+        Ok(())
+    }
+}
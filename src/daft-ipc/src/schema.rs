@@ -0,0 +1,78 @@
+use std::{io::Cursor, sync::Arc};
+
+use arrow2::io::ipc::read::read_stream_metadata;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::Schema;
+use daft_io::{IOClient, IOStatsRef};
+
+use crate::{is_ipc_file, FILE_HEADER_LEN};
+
+/// Number of bytes fetched up front when inferring a schema, which covers the schema message of
+/// all but very wide tables. A second request is only made if the message turns out to be larger.
+const INITIAL_SCHEMA_FETCH_SIZE: usize = 64 * 1024;
+
+/// Reads the schema of an Arrow IPC file or stream at `uri`.
+///
+/// Only the schema message at the start of the data is fetched, not the record batches.
+pub async fn read_ipc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    if size == 0 {
+        return Err(DaftError::ValueError(format!(
+            "Unable to read Arrow IPC schema from {uri}: file is empty"
+        )));
+    }
+    let fetch_prefix = |len: usize| {
+        let io_client = io_client.clone();
+        let io_stats = io_stats.clone();
+        async move {
+            io_client
+                .single_url_get(uri.to_string(), Some(0..len), io_stats)
+                .await?
+                .bytes()
+                .await
+        }
+    };
+
+    let mut prefix = fetch_prefix(size.min(INITIAL_SCHEMA_FETCH_SIZE)).await?;
+    let schema_end = schema_message_end(&prefix).ok_or_else(|| {
+        DaftError::ValueError(format!(
+            "Unable to read Arrow IPC schema from {uri}: not an Arrow IPC file or stream"
+        ))
+    })?;
+    if schema_end > prefix.len() && prefix.len() < size {
+        prefix = fetch_prefix(size.min(schema_end)).await?;
+    }
+
+    let offset = if is_ipc_file(&prefix) {
+        FILE_HEADER_LEN
+    } else {
+        0
+    };
+    let metadata = read_stream_metadata(&mut Cursor::new(&prefix[offset..]))?;
+    Schema::try_from(&metadata.schema)
+}
+
+/// Returns the offset just past the schema message, given the first bytes of an IPC file or stream.
+fn schema_message_end(prefix: &[u8]) -> Option<usize> {
+    let offset = if is_ipc_file(prefix) {
+        FILE_HEADER_LEN
+    } else {
+        0
+    };
+    let header = prefix.get(offset..offset + 8)?;
+    let first = u32::from_le_bytes(header[..4].try_into().ok()?);
+    if first == u32::MAX {
+        // A continuation marker followed by the message length.
+        let len = i32::from_le_bytes(header[4..].try_into().ok()?);
+        Some(offset + 8 + usize::try_from(len).ok()?)
+    } else {
+        // Legacy streams (pre Arrow 0.15) start directly with the message length.
+        Some(offset + 4 + first as usize)
+    }
+}
@@ -13,10 +13,11 @@ pub mod options;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
+mod records;
 pub mod schema;
 
 // pub use metadata::read_json_schema_bulk;
-pub use options::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
+pub use options::{JsonConvertOptions, JsonFormat, JsonParseOptions, JsonReadOptions};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 pub use read::{read_json, read_json_bulk};
@@ -68,6 +69,7 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<JsonConvertOptions>()?;
+    parent.add_class::<JsonFormat>()?;
     parent.add_class::<JsonParseOptions>()?;
     parent.add_class::<JsonReadOptions>()?;
     parent.add_function(wrap_pyfunction!(python::pylib::read_json, parent)?)?;
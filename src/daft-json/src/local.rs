@@ -15,8 +15,9 @@ use crate::{
     deserializer::Value,
     inference::{column_types_map_to_fields, infer_records_schema},
     read::tables_concat,
-    ArrowSnafu, JsonConvertOptions, JsonParseOptions, JsonReadOptions, RayonThreadPoolSnafu,
-    StdIOSnafu,
+    records::DocumentRecords,
+    ArrowSnafu, JsonConvertOptions, JsonFormat, JsonParseOptions, JsonReadOptions,
+    RayonThreadPoolSnafu, StdIOSnafu,
 };
 
 const NEWLINE: u8 = b'\n';
@@ -48,6 +49,7 @@ pub fn read_json_local(
 
 struct JsonReader<'a> {
     bytes: &'a [u8],
+    format: JsonFormat,
     schema: SchemaRef,
     n_threads: usize,
     predicate: Option<Arc<Expr>>,
@@ -69,6 +71,11 @@ impl<'a> JsonReader<'a> {
             .as_ref()
             .and_then(|options| options.sample_size)
             .unwrap_or(1024);
+        let format = parse_options
+            .as_ref()
+            .map(|options| options.format)
+            .unwrap_or_default()
+            .resolve(bytes);
         let n_rows = convert_options.as_ref().and_then(|options| options.limit);
         let chunk_size = read_options.as_ref().and_then(|options| options.chunk_size);
         let predicate = convert_options
@@ -84,7 +91,7 @@ impl<'a> JsonReader<'a> {
             .and_then(|options| options.schema.as_ref())
         {
             Some(schema) => schema.clone(),
-            None => Arc::new(Schema::try_from(&infer_schema(bytes, None, None)?)?),
+            None => Arc::new(Schema::try_from(&infer_schema(bytes, format, None, None)?)?),
         };
 
         let pool = if let Some(max_in_flight) = max_chunks_in_flight {
@@ -101,6 +108,7 @@ impl<'a> JsonReader<'a> {
 
         Ok(Self {
             bytes,
+            format,
             schema: projected_schema,
             predicate,
             n_threads,
@@ -112,6 +120,9 @@ impl<'a> JsonReader<'a> {
     }
 
     pub fn finish(&self) -> DaftResult<RecordBatch> {
+        if self.format == JsonFormat::Documents {
+            return self.finish_documents();
+        }
         let mut bytes = self.bytes;
         let mut n_threads = self.n_threads;
         let mut total_rows = 128;
@@ -146,7 +157,7 @@ impl<'a> JsonReader<'a> {
                 .into_par_iter()
                 .map(|(start, stop)| {
                     let chunk = &bytes[start..stop];
-                    self.parse_json_chunk(chunk, chunk_size)
+                    self.parse_json_chunk(newline_delimited_records(chunk), chunk_size)
                 })
                 .collect::<DaftResult<Vec<RecordBatch>>>()
         })?;
@@ -162,7 +173,45 @@ impl<'a> JsonReader<'a> {
         Ok(tbl)
     }
 
-    fn parse_json_chunk(&self, bytes: &[u8], chunk_size: usize) -> DaftResult<RecordBatch> {
+    /// Reads a sequence of JSON documents, whose record boundaries can't be found by looking for
+    /// newlines. The records are located with a sequential scan and then parsed in parallel.
+    fn finish_documents(&self) -> DaftResult<RecordBatch> {
+        // The limit can only be applied to the records up front if they aren't filtered afterwards.
+        let max_records = match (self.n_rows, &self.predicate) {
+            (Some(n_rows), None) => n_rows,
+            _ => usize::MAX,
+        };
+        let records = DocumentRecords::new(self.bytes)
+            .take(max_records)
+            .collect::<crate::Result<Vec<_>>>()?;
+        let records_per_chunk = self
+            .chunk_size
+            .unwrap_or_else(|| records.len().div_ceil(self.n_threads))
+            .max(1);
+
+        let tbls = self.pool.install(|| {
+            records
+                .par_chunks(records_per_chunk)
+                .map(|chunk| {
+                    self.parse_json_chunk(chunk.iter().copied().map(Ok), records_per_chunk)
+                })
+                .collect::<DaftResult<Vec<RecordBatch>>>()
+        })?;
+        if tbls.is_empty() {
+            return RecordBatch::empty(Some(self.schema.clone()));
+        }
+        let tbl = tables_concat(tbls)?;
+        match self.n_rows {
+            Some(limit) if tbl.len() > limit => tbl.head(limit),
+            _ => Ok(tbl),
+        }
+    }
+
+    fn parse_json_chunk<'b>(
+        &self,
+        records: impl Iterator<Item = crate::Result<&'b [u8]>>,
+        chunk_size: usize,
+    ) -> DaftResult<RecordBatch> {
         let mut scratch = vec![];
         let scratch = &mut scratch;
 
@@ -170,13 +219,6 @@ impl<'a> JsonReader<'a> {
 
         let arrow_schema = self.schema.to_arrow()?;
 
-        // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
-        // This is a trick to use the line-based deserializer from serde_json to iterate over the lines
-        // This is more accurate than using a `Lines` iterator.
-        // Ideally, we would instead use a line-based deserializer from simd_json, but that is not available.
-        let iter =
-            serde_json::Deserializer::from_slice(bytes).into_iter::<&serde_json::value::RawValue>();
-
         let mut columns = arrow_schema
             .fields
             .iter()
@@ -189,11 +231,8 @@ impl<'a> JsonReader<'a> {
             .collect::<IndexMap<_, _>>();
 
         let mut num_rows = 0;
-        for record in iter {
-            let value = record.map_err(|e| super::Error::JsonDeserializationError {
-                string: e.to_string(),
-            })?;
-            let v = parse_raw_value(value, scratch)?;
+        for record in records {
+            let v = parse_record(record?, scratch)?;
 
             match v {
                 Value::Object(record) => {
@@ -279,6 +318,7 @@ impl<'a> JsonReader<'a> {
 // TODO: there should be much more shared code between this and the async version
 fn infer_schema(
     bytes: &[u8],
+    format: JsonFormat,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
 ) -> DaftResult<arrow2::datatypes::Schema> {
@@ -291,15 +331,18 @@ fn infer_schema(
     let mut scratch = Vec::new();
     let scratch = &mut scratch;
 
-    let iter =
-        serde_json::Deserializer::from_slice(bytes).into_iter::<&serde_json::value::RawValue>();
+    let records: Box<dyn Iterator<Item = crate::Result<&[u8]>>> = match format {
+        JsonFormat::Documents => Box::new(DocumentRecords::new(bytes)),
+        JsonFormat::Auto | JsonFormat::NewlineDelimited => {
+            Box::new(newline_delimited_records(bytes))
+        }
+    };
 
-    for value in iter.take(max_records) {
-        let value = value?;
-        let bytes = value.get().as_bytes();
-        total_bytes += bytes.len();
+    for record in records.take(max_records) {
+        let record = record?;
+        total_bytes += record.len();
 
-        let v = parse_raw_value(value, scratch)?;
+        let v = parse_record(record, scratch)?;
 
         let inferred_schema = infer_records_schema(&v).context(ArrowSnafu)?;
         for field in inferred_schema.fields {
@@ -404,15 +447,28 @@ fn calculate_chunks_and_size(n_threads: usize, chunk_size: usize, total: usize)
     (max_divisible_chunks, chunk_size)
 }
 
+/// Iterates over the records of newline-delimited JSON.
+fn newline_delimited_records(bytes: &[u8]) -> impl Iterator<Item = crate::Result<&[u8]>> {
+    // The `RawValue` is a pointer to the original JSON string and does not perform any deserialization.
+    // This is a trick to use the line-based deserializer from serde_json to iterate over the lines
+    // This is more accurate than using a `Lines` iterator.
+    // Ideally, we would instead use a line-based deserializer from simd_json, but that is not available.
+    serde_json::Deserializer::from_slice(bytes)
+        .into_iter::<&RawValue>()
+        .map(|record| {
+            record.map(|value| value.get().as_bytes()).map_err(|e| {
+                super::Error::JsonDeserializationError {
+                    string: e.to_string(),
+                }
+            })
+        })
+}
+
 #[inline(always)]
-fn parse_raw_value<'a>(
-    raw_value: &'a RawValue,
-    scratch: &'a mut Vec<u8>,
-) -> crate::Result<Value<'a>> {
-    let bytes = raw_value.get().as_bytes();
+fn parse_record<'a>(bytes: &[u8], scratch: &'a mut Vec<u8>) -> crate::Result<Value<'a>> {
     scratch.clear();
     // We need to clone the bytes here because the deserializer expects a mutable slice
-    // and `RawValue` only provides an immutable slice.
+    // and the mmapped file only provides an immutable slice.
     scratch.extend_from_slice(bytes);
     crate::deserializer::to_value(scratch).map_err(|e| super::Error::JsonDeserializationError {
         string: e.to_string(),
@@ -449,7 +505,7 @@ mod tests {
 {"floats": 3.0, "utf8": "!", "bools": true}
 "#;
 
-        let result = infer_schema(json.as_bytes(), None, None);
+        let result = infer_schema(json.as_bytes(), JsonFormat::NewlineDelimited, None, None);
         let expected_schema = ArrowSchema::from(vec![
             ArrowField::new("floats", ArrowDataType::Float64, true),
             ArrowField::new("utf8", ArrowDataType::Utf8, true),
@@ -462,7 +518,7 @@ mod tests {
     fn test_infer_schema_empty() {
         let json = r"";
 
-        let result = infer_schema(json.as_bytes(), None, None);
+        let result = infer_schema(json.as_bytes(), JsonFormat::NewlineDelimited, None, None);
         let expected_schema = ArrowSchema::from(vec![]);
         assert_eq!(result.unwrap(), expected_schema);
     }
@@ -477,4 +533,57 @@ mod tests {
         let reader = JsonReader::try_new(json.as_bytes(), None, None, None, None).unwrap();
         let _result = reader.finish();
     }
+
+    #[test]
+    fn test_read_json_documents_from_str() -> DaftResult<()> {
+        let json = r#"
+[
+  {
+    "floats": 1.0,
+    "utf8": "hello"
+  },
+  {"floats": 2.0, "utf8": "world\n"},
+  {"floats": 3.0, "utf8": "]"}
+]
+"#;
+        let expected_schema = ArrowSchema::from(vec![
+            ArrowField::new("floats", ArrowDataType::Float64, true),
+            ArrowField::new("utf8", ArrowDataType::Utf8, true),
+        ]);
+        assert_eq!(
+            infer_schema(json.as_bytes(), JsonFormat::Documents, None, None)?,
+            expected_schema
+        );
+
+        for chunk_size in [None, Some(1), Some(2)] {
+            let reader = JsonReader::try_new(
+                json.as_bytes(),
+                None,
+                None,
+                Some(JsonReadOptions::default().with_chunk_size(chunk_size)),
+                None,
+            )?;
+            assert_eq!(reader.format, JsonFormat::Documents);
+            let table = reader.finish()?;
+            assert_eq!(table.len(), 3);
+            assert_eq!(
+                table
+                    .get_column("utf8")?
+                    .utf8()?
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                vec![Some("hello"), Some("world\n"), Some("]")]
+            );
+        }
+
+        let reader = JsonReader::try_new(
+            json.as_bytes(),
+            Some(JsonConvertOptions::default().with_limit(Some(2))),
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(reader.finish()?.len(), 2);
+        Ok(())
+    }
 }
@@ -117,16 +117,39 @@ impl JsonConvertOptions {
 }
 impl_bincode_py_state_serialization!(JsonConvertOptions);
 
+/// Layout of the records in a JSON file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft", eq, eq_int))]
+pub enum JsonFormat {
+    /// Detect the layout from the start of each file: a leading `[`, or a first object that spans
+    /// multiple lines, selects `Documents`; anything else is read as `NewlineDelimited`.
+    #[default]
+    Auto,
+    /// One JSON object per line (JSON Lines / NDJSON).
+    NewlineDelimited,
+    /// A sequence of top-level JSON documents separated by whitespace, where each document is
+    /// either a record object or an array of record objects. Objects may span multiple lines.
+    Documents,
+}
+
 /// Options for parsing JSON files.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft", get_all))]
 pub struct JsonParseOptions {
     pub sample_size: Option<usize>,
+    pub format: JsonFormat,
 }
 
 impl JsonParseOptions {
     pub fn new_internal() -> Self {
-        Self { sample_size: None }
+        Self {
+            sample_size: None,
+            format: JsonFormat::default(),
+        }
+    }
+
+    pub fn with_format(self, format: JsonFormat) -> Self {
+        Self { format, ..self }
     }
 }
 
@@ -140,9 +163,14 @@ impl Default for JsonParseOptions {
 #[pymethods]
 impl JsonParseOptions {
     /// Create parsing options for the JSON reader.
+    ///
+    /// # Arguments:
+    ///
+    /// * `format` - Layout of the records in the file; detected from the file contents by default.
     #[new]
-    pub fn new() -> PyResult<Self> {
-        Ok(Self::new_internal())
+    #[pyo3(signature = (format=None))]
+    pub fn new(format: Option<JsonFormat>) -> PyResult<Self> {
+        Ok(Self::new_internal().with_format(format.unwrap_or_default()))
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
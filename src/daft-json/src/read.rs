@@ -7,7 +7,10 @@ use daft_core::{prelude::*, utils::arrow::cast_array_for_daft_if_needed};
 use daft_dsl::optimization::get_required_columns;
 use daft_io::{parse_url, GetResult, IOClient, IOStatsRef, SourceType};
 use daft_recordbatch::RecordBatch;
-use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+use futures::{
+    stream::{BoxStream, TryChunksError},
+    Stream, StreamExt, TryStreamExt,
+};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use snafu::{
     futures::{try_future::Context, TryFutureExt, TryStreamExt as _},
@@ -21,8 +24,11 @@ use tokio::{
 use tokio_util::io::StreamReader;
 
 use crate::{
-    decoding::deserialize_records, local::read_json_local, schema::read_json_schema_single,
-    ArrowSnafu, ChunkSnafu, JsonConvertOptions, JsonParseOptions, JsonReadOptions,
+    decoding::deserialize_records,
+    local::read_json_local,
+    records::{read_document_records, resolve_format},
+    schema::read_json_schema_single,
+    ArrowSnafu, ChunkSnafu, JsonConvertOptions, JsonFormat, JsonParseOptions, JsonReadOptions,
 };
 
 type TableChunkResult =
@@ -449,14 +455,16 @@ async fn read_json_single_into_stream(
             ),
         };
     // If file is compressed, wrap stream in decoding stream.
-    let reader: Box<dyn AsyncBufRead + Unpin + Send> = match CompressionCodec::from_uri(uri) {
+    let mut reader: Box<dyn AsyncBufRead + Unpin + Send> = match CompressionCodec::from_uri(uri) {
         Some(compression) => Box::new(tokio::io::BufReader::with_capacity(
             buffer_size,
             compression.to_decoder(reader),
         )),
         None => reader,
     };
-    let read_stream = read_into_line_chunk_stream(reader, convert_options.limit, chunk_size);
+    let format = resolve_format(&mut reader, parse_options.format).await?;
+    let read_stream =
+        read_into_line_chunk_stream(reader, format, convert_options.limit, chunk_size);
     let (projected_schema, schema_is_projection) = match convert_options.include_columns {
         Some(projection) => {
             let mut field_map = schema
@@ -484,6 +492,7 @@ async fn read_json_single_into_stream(
 
 fn read_into_line_chunk_stream<R>(
     reader: R,
+    format: JsonFormat,
     num_rows: Option<usize>,
     chunk_size: usize,
 ) -> impl LineChunkStream + Send
@@ -492,11 +501,21 @@ where
 {
     let num_rows = num_rows.unwrap_or(usize::MAX);
     // Stream of unparsed json string record chunks.
-    let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
-    line_stream
-        .take(num_rows)
-        .try_chunks(chunk_size)
-        .context(ChunkSnafu)
+    match format {
+        JsonFormat::Documents => read_document_records(reader)
+            .take(num_rows)
+            .try_chunks(chunk_size)
+            .map_err(|TryChunksError(_, e)| e)
+            .left_stream(),
+        JsonFormat::Auto | JsonFormat::NewlineDelimited => {
+            let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
+            line_stream
+                .take(num_rows)
+                .try_chunks(chunk_size)
+                .context(ChunkSnafu)
+                .right_stream()
+        }
+    }
 }
 
 fn parse_into_column_array_chunk_stream(
@@ -954,6 +973,66 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_json_read_local_documents(
+        #[values(
+            "iris_tiny_array.json",
+            // Compressed files go through the streaming reader.
+            "iris_tiny_array.json.gz",
+            "iris_tiny_multiline.json"
+        )]
+        file_name: &str,
+        #[values(None, Some(3))] chunk_size: Option<usize>,
+    ) -> DaftResult<()> {
+        let file = format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), file_name);
+        let expected_file = format!("{}/test/iris_tiny.jsonl", env!("CARGO_MANIFEST_DIR"));
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let read_options = Some(JsonReadOptions::default().with_chunk_size(chunk_size));
+
+        let table = read_json(
+            file.as_ref(),
+            None,
+            None,
+            read_options.clone(),
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        let expected = read_json(
+            expected_file.as_ref(),
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(table, expected);
+
+        let convert_options = JsonConvertOptions::default()
+            .with_limit(Some(5))
+            .with_include_columns(Some(vec!["species".to_string(), "petalWidth".to_string()]));
+        let table = read_json(
+            file.as_ref(),
+            Some(convert_options),
+            None,
+            read_options,
+            io_client,
+            None,
+            true,
+            None,
+        )?;
+        assert_eq!(
+            table,
+            expected.head(5)?.get_columns(&["species", "petalWidth"])?
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_json_read_local_nulls() -> DaftResult<()> {
         let file = format!("{}/test/iris_tiny_nulls.jsonl", env!("CARGO_MANIFEST_DIR"),);
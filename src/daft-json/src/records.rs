@@ -0,0 +1,455 @@
+//! Record framing for JSON files that are not newline-delimited.
+//!
+//! A [`RecordScanner`] finds the byte ranges of the record objects in a sequence of top-level JSON
+//! documents, where each document is either a record object or an array of record objects, e.g.
+//! `[{"a": 1}, {"a": 2}]` or pretty-printed objects separated by whitespace. The scanner only tracks
+//! nesting and string state; the records themselves are parsed by the regular JSON deserializer.
+//! It is incremental, so the streaming reader never needs to hold more than one record (plus the
+//! current read buffer) in memory, regardless of how large a top-level array is.
+
+use std::ops::Range;
+
+use futures::Stream;
+use snafu::ResultExt;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::{JsonFormat, StdIOSnafu};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+impl JsonFormat {
+    /// Resolves [`JsonFormat::Auto`] using the first bytes of a file.
+    pub(crate) fn resolve(self, prefix: &[u8]) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+        let prefix = prefix.strip_prefix(UTF8_BOM).unwrap_or(prefix);
+        let Some(start) = prefix.iter().position(|b| !b.is_ascii_whitespace()) else {
+            return Self::NewlineDelimited;
+        };
+        match prefix[start] {
+            b'[' => Self::Documents,
+            b'{' => {
+                // Newlines can't appear unescaped inside JSON strings, so a newline before the end
+                // of the first record means that records span multiple lines.
+                let mut pos = start;
+                let end = match RecordScanner::new().next_record(prefix, &mut pos) {
+                    Ok(Some(record)) => record.end,
+                    _ => prefix.len(),
+                };
+                if memchr::memchr(b'\n', &prefix[start..end]).is_some() {
+                    Self::Documents
+                } else {
+                    Self::NewlineDelimited
+                }
+            }
+            _ => Self::NewlineDelimited,
+        }
+    }
+}
+
+/// Resolves [`JsonFormat::Auto`] by peeking at the reader's buffer, without consuming any input.
+pub(crate) async fn resolve_format<R>(
+    reader: &mut R,
+    format: JsonFormat,
+) -> crate::Result<JsonFormat>
+where
+    R: AsyncBufRead + Unpin,
+{
+    if format != JsonFormat::Auto {
+        return Ok(format);
+    }
+    let prefix = reader.fill_buf().await.context(StdIOSnafu)?;
+    Ok(format.resolve(prefix))
+}
+
+/// Where the scanner is between records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Position {
+    /// Between top-level documents.
+    TopLevel,
+    /// Right after the `[` that opens an array.
+    ArrayStart,
+    /// After a `,` inside an array, where another record must follow.
+    ArrayValue,
+    /// After a record inside an array, where a `,` or `]` must follow.
+    ArrayNext,
+}
+
+impl Position {
+    fn expected(self) -> &'static str {
+        match self {
+            Self::TopLevel => "'{' or '['",
+            Self::ArrayStart => "'{' or ']'",
+            Self::ArrayValue => "'{'",
+            Self::ArrayNext => "',' or ']'",
+        }
+    }
+}
+
+/// A record whose closing brace hasn't been seen yet.
+#[derive(Debug)]
+struct PartialRecord {
+    start: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl PartialRecord {
+    /// Advances `pos` through `bytes`, returning the end of the record once its closing brace is found.
+    fn scan(&mut self, bytes: &[u8], pos: &mut usize) -> Option<usize> {
+        while *pos < bytes.len() {
+            if self.in_string && !self.escaped {
+                // Skip string contents up to the next quote or escape.
+                match memchr::memchr2(b'"', b'\\', &bytes[*pos..]) {
+                    Some(offset) => *pos += offset,
+                    None => {
+                        *pos = bytes.len();
+                        return None;
+                    }
+                }
+            }
+            let byte = bytes[*pos];
+            *pos += 1;
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        return Some(*pos);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Incremental scanner that finds the byte ranges of records in a sequence of JSON documents.
+#[derive(Debug)]
+pub(crate) struct RecordScanner {
+    position: Position,
+    in_array: bool,
+    record: Option<PartialRecord>,
+    /// Number of bytes dropped from the front of the buffer so far, used to report file offsets.
+    offset: usize,
+}
+
+impl RecordScanner {
+    pub fn new() -> Self {
+        Self {
+            position: Position::TopLevel,
+            in_array: false,
+            record: None,
+            offset: 0,
+        }
+    }
+
+    /// Scans `bytes` from `pos` for the next complete record, returning its range in `bytes`.
+    ///
+    /// Returns `None` once `bytes` is exhausted; more input can then be appended to `bytes` and the
+    /// scan resumed, as long as everything from [`Self::pending_start`] onwards is kept.
+    pub fn next_record(
+        &mut self,
+        bytes: &[u8],
+        pos: &mut usize,
+    ) -> crate::Result<Option<Range<usize>>> {
+        while *pos < bytes.len() {
+            if let Some(record) = &mut self.record {
+                let Some(end) = record.scan(bytes, pos) else {
+                    break;
+                };
+                let start = record.start;
+                self.record = None;
+                self.position = if self.in_array {
+                    Position::ArrayNext
+                } else {
+                    Position::TopLevel
+                };
+                return Ok(Some(start..end));
+            }
+            let byte = bytes[*pos];
+            match (self.position, byte) {
+                (_, b) if b.is_ascii_whitespace() => {}
+                (Position::TopLevel | Position::ArrayStart | Position::ArrayValue, b'{') => {
+                    self.in_array = self.position != Position::TopLevel;
+                    self.record = Some(PartialRecord {
+                        start: *pos,
+                        depth: 1,
+                        in_string: false,
+                        escaped: false,
+                    });
+                }
+                (Position::TopLevel, b'[') => self.position = Position::ArrayStart,
+                (Position::ArrayStart | Position::ArrayNext, b']') => {
+                    self.position = Position::TopLevel;
+                }
+                (Position::ArrayNext, b',') => self.position = Position::ArrayValue,
+                (Position::TopLevel, _)
+                    if self.offset + *pos == 0 && bytes.starts_with(UTF8_BOM) =>
+                {
+                    *pos += UTF8_BOM.len();
+                    continue;
+                }
+                (position, b) => {
+                    return Err(crate::Error::JsonDeserializationError {
+                        string: format!(
+                            "Unexpected {} at byte {} of JSON document; expected {}",
+                            describe_byte(b),
+                            self.offset + *pos,
+                            position.expected()
+                        ),
+                    })
+                }
+            }
+            *pos += 1;
+        }
+        Ok(None)
+    }
+
+    /// Start of the record that is currently being scanned, if any.
+    pub fn pending_start(&self) -> Option<usize> {
+        self.record.as_ref().map(|record| record.start)
+    }
+
+    /// Notifies the scanner that the first `n` bytes of the buffer were dropped.
+    pub fn rebase(&mut self, n: usize) {
+        if let Some(record) = &mut self.record {
+            record.start -= n;
+        }
+        self.offset += n;
+    }
+
+    /// Checks that the input didn't end in the middle of a record or array.
+    pub fn finish(&self) -> crate::Result<()> {
+        let unterminated = if self.record.is_some() {
+            "JSON object"
+        } else if self.position != Position::TopLevel {
+            "JSON array"
+        } else {
+            return Ok(());
+        };
+        Err(crate::Error::JsonDeserializationError {
+            string: format!("Unexpected end of input inside a {unterminated}"),
+        })
+    }
+}
+
+fn describe_byte(byte: u8) -> String {
+    if byte.is_ascii_graphic() {
+        format!("'{}'", byte as char)
+    } else {
+        format!("byte 0x{byte:02x}")
+    }
+}
+
+/// Iterator over the records of an in-memory sequence of JSON documents.
+pub(crate) struct DocumentRecords<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    scanner: RecordScanner,
+    done: bool,
+}
+
+impl<'a> DocumentRecords<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            scanner: RecordScanner::new(),
+            done: false,
+        }
+    }
+}
+
+impl<'a> Iterator for DocumentRecords<'a> {
+    type Item = crate::Result<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.scanner.next_record(self.bytes, &mut self.pos) {
+            Ok(Some(range)) => Some(Ok(&self.bytes[range])),
+            Ok(None) => {
+                self.done = true;
+                self.scanner.finish().err().map(Err)
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Reads records from a sequence of JSON documents, buffering at most one record at a time.
+struct DocumentReader<R> {
+    reader: R,
+    scanner: RecordScanner,
+    buf: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl<R> DocumentReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    async fn next_record(&mut self) -> crate::Result<Option<String>> {
+        loop {
+            if let Some(range) = self.scanner.next_record(&self.buf, &mut self.pos)? {
+                let record = String::from_utf8(self.buf[range].to_vec()).map_err(|e| {
+                    crate::Error::JsonDeserializationError {
+                        string: e.to_string(),
+                    }
+                })?;
+                return Ok(Some(record));
+            }
+            if self.eof {
+                self.scanner.finish()?;
+                return Ok(None);
+            }
+            // Drop everything before the pending record, then read more input.
+            let keep_from = self.scanner.pending_start().unwrap_or(self.pos);
+            self.buf.drain(..keep_from);
+            self.pos -= keep_from;
+            self.scanner.rebase(keep_from);
+            let chunk = self.reader.fill_buf().await.context(StdIOSnafu)?;
+            if chunk.is_empty() {
+                self.eof = true;
+            } else {
+                let len = chunk.len();
+                self.buf.extend_from_slice(chunk);
+                self.reader.consume(len);
+            }
+        }
+    }
+}
+
+/// Stream of unparsed records from a sequence of JSON documents.
+pub(crate) fn read_document_records<R>(
+    reader: R,
+) -> impl Stream<Item = crate::Result<String>> + Send
+where
+    R: AsyncBufRead + Unpin + Send,
+{
+    let state = DocumentReader {
+        reader,
+        scanner: RecordScanner::new(),
+        buf: Vec::new(),
+        pos: 0,
+        eof: false,
+    };
+    futures::stream::try_unfold(state, |mut state| async move {
+        Ok(state.next_record().await?.map(|record| (record, state)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    fn split(json: &str) -> crate::Result<Vec<&str>> {
+        DocumentRecords::new(json.as_bytes())
+            .map(|record| record.map(|bytes| std::str::from_utf8(bytes).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_documents() -> crate::Result<()> {
+        let json = r#"
+[
+  {"a": 1, "b": {"c": [1, 2]}},
+  {"a": 2, "b": "}]\"{["}
+]
+{
+  "a": 3
+}
+[] [{"a": 4}]
+"#;
+        assert_eq!(
+            split(json)?,
+            vec![
+                r#"{"a": 1, "b": {"c": [1, 2]}}"#,
+                r#"{"a": 2, "b": "}]\"{["}"#,
+                "{\n  \"a\": 3\n}",
+                r#"{"a": 4}"#,
+            ]
+        );
+        assert_eq!(split("\u{feff}[{\"a\": 1}]")?, vec![r#"{"a": 1}"#]);
+        assert_eq!(split(" \n ")?, Vec::<&str>::new());
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_documents_errors() {
+        for json in [
+            r#"[{"a": 1} {"a": 2}]"#,
+            r#"[{"a": 1},]"#,
+            "[1, 2]",
+            r#"{"a": 1} x"#,
+            r#"[{"a": 1}"#,
+            r#"{"a": "}"#,
+        ] {
+            assert!(split(json).is_err(), "expected an error for {json}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_format() {
+        let resolve = |json: &str| JsonFormat::Auto.resolve(json.as_bytes());
+        assert_eq!(resolve("  [{\"a\": 1}]"), JsonFormat::Documents);
+        assert_eq!(resolve("{\n  \"a\": 1\n}\n"), JsonFormat::Documents);
+        assert_eq!(
+            resolve("{\"a\": 1}\n{\"a\": 2}\n"),
+            JsonFormat::NewlineDelimited
+        );
+        assert_eq!(resolve("{\"a\": \"truncated"), JsonFormat::NewlineDelimited);
+        assert_eq!(resolve(""), JsonFormat::NewlineDelimited);
+        assert_eq!(
+            JsonFormat::NewlineDelimited.resolve(b"[{\"a\": 1}]"),
+            JsonFormat::NewlineDelimited
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_document_records_across_buffers() -> crate::Result<()> {
+        let json = r#"[{"a": "x\"}"}, {"a": [{"b": 1}]},
+            {"a": null}] {"a": 2}"#;
+        // A tiny buffer makes every record straddle several reads.
+        let reader = tokio::io::BufReader::with_capacity(3, json.as_bytes());
+        let records = read_document_records(reader)
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(
+            records,
+            vec![
+                r#"{"a": "x\"}"}"#,
+                r#"{"a": [{"b": 1}]}"#,
+                r#"{"a": null}"#,
+                r#"{"a": 2}"#,
+            ]
+        );
+
+        let reader = tokio::io::BufReader::with_capacity(3, br#"[{"a": 1}, {"a""#.as_slice());
+        let result = read_document_records(reader).try_collect::<Vec<_>>().await;
+        assert!(result.is_err());
+        Ok(())
+    }
+}
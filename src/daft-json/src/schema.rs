@@ -16,7 +16,8 @@ use tokio_util::io::StreamReader;
 
 use crate::{
     inference::{column_types_map_to_fields, infer_records_schema},
-    ArrowSnafu, JsonParseOptions, StdIOSnafu,
+    records::{read_document_records, resolve_format},
+    ArrowSnafu, JsonFormat, JsonParseOptions, StdIOSnafu,
 };
 
 #[derive(Debug, Clone)]
@@ -105,7 +106,7 @@ pub async fn read_json_schema_bulk(
 
 pub(crate) async fn read_json_schema_single(
     uri: &str,
-    _: JsonParseOptions,
+    parse_options: JsonParseOptions,
     max_bytes: Option<usize>,
     io_client: Arc<IOClient>,
     io_stats: Option<IOStatsRef>,
@@ -125,17 +126,19 @@ pub(crate) async fn read_json_schema_single(
         ),
     };
     // If file is compressed, wrap stream in decoding stream.
-    let reader: Box<dyn AsyncBufRead + Unpin + Send> = match CompressionCodec::from_uri(uri) {
+    let mut reader: Box<dyn AsyncBufRead + Unpin + Send> = match CompressionCodec::from_uri(uri) {
         Some(compression) => Box::new(tokio::io::BufReader::new(compression.to_decoder(reader))),
         None => reader,
     };
-    let arrow_schema = infer_schema(reader, None, max_bytes).await?;
+    let format = resolve_format(&mut reader, parse_options.format).await?;
+    let arrow_schema = infer_schema(reader, format, None, max_bytes).await?;
     let schema = Schema::try_from(&arrow_schema)?;
     Ok(schema)
 }
 
 async fn infer_schema<R>(
     reader: R,
+    format: JsonFormat,
     max_rows: Option<usize>,
     max_bytes: Option<usize>,
 ) -> DaftResult<arrow2::datatypes::Schema>
@@ -146,8 +149,15 @@ where
     let max_bytes = max_bytes.unwrap_or(usize::MAX);
     let mut total_bytes = 0;
     // Stream of unparsed JSON string records.
-    let line_stream = tokio_stream::wrappers::LinesStream::new(reader.lines());
-    let mut schema_stream = line_stream
+    let record_stream = match format {
+        JsonFormat::Documents => read_document_records(reader).left_stream(),
+        JsonFormat::Auto | JsonFormat::NewlineDelimited => {
+            tokio_stream::wrappers::LinesStream::new(reader.lines())
+                .map(|record| record.context(StdIOSnafu))
+                .right_stream()
+        }
+    };
+    let mut schema_stream = std::pin::pin!(record_stream
         .try_take_while(|record| {
             // Terminate scan if we've exceeded our max_bytes threshold with the last-read line.
             if total_bytes >= max_bytes {
@@ -159,7 +169,7 @@ where
         })
         .take(max_records)
         .map(|record| {
-            let mut record = record.context(StdIOSnafu)?;
+            let mut record = record?;
 
             // Parse record into a JSON Value, then infer the schema.
             let parsed_record = crate::deserializer::to_value(unsafe { record.as_bytes_mut() })
@@ -167,7 +177,7 @@ where
                     string: e.to_string(),
                 })?;
             infer_records_schema(&parsed_record).context(ArrowSnafu)
-        });
+        }));
     // Collect all inferred dtypes for each column.
     let mut column_types: IndexMap<String, HashSet<arrow2::datatypes::DataType>> = IndexMap::new();
     while let Some(schema) = schema_stream.next().await.transpose()? {
@@ -252,6 +262,35 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[tokio::test]
+    async fn test_json_schema_local_documents(
+        #[values(
+            "iris_tiny_array.json",
+            "iris_tiny_array.json.gz",
+            "iris_tiny_multiline.json"
+        )]
+        file_name: &str,
+    ) -> DaftResult<()> {
+        let file = format!("{}/test/{}", env!("CARGO_MANIFEST_DIR"), file_name);
+
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+
+        let schema = read_json_schema(file.as_ref(), None, None, io_client, None).await?;
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("sepalLength", DataType::Float64),
+                Field::new("sepalWidth", DataType::Float64),
+                Field::new("petalLength", DataType::Float64),
+                Field::new("petalWidth", DataType::Float64),
+                Field::new("species", DataType::Utf8),
+            ])?,
+        );
+
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_json_schema_local_dtypes() -> DaftResult<()> {
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{join::AsofJoinOptions, ExprRef};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::{AsofJoinRight, RecordBatch};
+use itertools::Itertools;
+use tokio::sync::OnceCell;
+use tracing::{instrument, Span};
+
+use super::intermediate_op::{
+    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
+    IntermediateOperatorResult,
+};
+use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+
+struct AsofJoinState {
+    bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+    // The collected right side as a single table with its sorted join keys, which is prepared on
+    // first use and shared by the states of all workers.
+    right: Arc<OnceCell<AsofJoinRight>>,
+}
+
+impl AsofJoinState {
+    fn new(
+        bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+        right: Arc<OnceCell<AsofJoinRight>>,
+    ) -> Self {
+        Self { bridge, right }
+    }
+}
+
+impl IntermediateOpState for AsofJoinState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct AsofJoinParams {
+    left_on: Vec<ExprRef>,
+    right_on: Vec<ExprRef>,
+    asof_options: AsofJoinOptions,
+    left_schema: SchemaRef,
+    right_schema: SchemaRef,
+    output_schema: SchemaRef,
+}
+
+/// Joins each streamed left morsel with the collected right side of an as-of join.
+pub struct AsofJoinOperator {
+    params: Arc<AsofJoinParams>,
+    state_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+    right: Arc<OnceCell<AsofJoinRight>>,
+}
+
+impl AsofJoinOperator {
+    pub(crate) fn new(
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        asof_options: AsofJoinOptions,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        output_schema: SchemaRef,
+        state_bridge: BroadcastStateBridgeRef<Vec<RecordBatch>>,
+    ) -> Self {
+        Self {
+            params: Arc::new(AsofJoinParams {
+                left_on,
+                right_on,
+                asof_options,
+                left_schema,
+                right_schema,
+                output_schema,
+            }),
+            state_bridge,
+            right: Arc::new(OnceCell::new()),
+        }
+    }
+}
+
+impl IntermediateOperator for AsofJoinOperator {
+    #[instrument(skip_all, name = "AsofJoinOperator::execute")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn IntermediateOpState>,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> IntermediateOpExecuteResult {
+        let params = self.params.clone();
+
+        if input.is_empty() {
+            let empty = Arc::new(MicroPartition::empty(Some(params.output_schema.clone())));
+            return Ok((
+                state,
+                IntermediateOperatorResult::NeedMoreInput(Some(empty)),
+            ))
+            .into();
+        }
+
+        task_spawner
+            .spawn(
+                async move {
+                    let asof_join_state = state
+                        .as_any_mut()
+                        .downcast_mut::<AsofJoinState>()
+                        .expect("AsofJoinState should be used with AsofJoinOperator");
+
+                    let right = asof_join_state
+                        .right
+                        .get_or_try_init(|| async {
+                            let right_tables = asof_join_state.bridge.get_state().await;
+                            let right_table = if right_tables.is_empty() {
+                                RecordBatch::empty(Some(params.right_schema.clone()))?
+                            } else {
+                                RecordBatch::concat(&right_tables)?
+                            };
+                            AsofJoinRight::try_new(
+                                right_table,
+                                &params.right_on,
+                                &params.left_schema,
+                                &params.left_on,
+                            )
+                        })
+                        .await?;
+
+                    let output_tables = input
+                        .get_tables()?
+                        .iter()
+                        .map(|left_table| {
+                            left_table.asof_join_sorted(
+                                right,
+                                &params.left_on,
+                                &params.asof_options,
+                            )
+                        })
+                        .collect::<DaftResult<Vec<_>>>()?;
+
+                    let output_morsel = Arc::new(MicroPartition::new_loaded(
+                        params.output_schema.clone(),
+                        Arc::new(output_tables),
+                        None,
+                    ));
+                    Ok((
+                        state,
+                        IntermediateOperatorResult::NeedMoreInput(Some(output_morsel)),
+                    ))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "AsofJoin"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![
+            "AsofJoin:".to_string(),
+            format!(
+                "Left on: [{}]",
+                self.params.left_on.iter().map(|e| e.to_string()).join(", ")
+            ),
+            format!(
+                "Right on: [{}]",
+                self.params
+                    .right_on
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ),
+            self.params.asof_options.to_string(),
+        ]
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
+        Ok(Box::new(AsofJoinState::new(
+            self.state_bridge.clone(),
+            self.right.clone(),
+        )))
+    }
+}
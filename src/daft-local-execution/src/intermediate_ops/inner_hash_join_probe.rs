@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use common_error::DaftResult;
 use daft_core::prelude::SchemaRef;
@@ -10,25 +10,30 @@ use itertools::Itertools;
 use tracing::{info_span, instrument, Span};
 
 use super::intermediate_op::{
-    IntermediateOpExecuteResult, IntermediateOpState, IntermediateOperator,
-    IntermediateOperatorResult,
+    IntermediateOpExecuteResult, IntermediateOpFinalizeResult, IntermediateOpState,
+    IntermediateOperator, IntermediateOperatorResult,
+};
+use crate::{
+    sinks::hash_join_build::{HashJoinBuild, SpilledBuildSide},
+    spill::SpillWriter,
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionTaskSpawner, StreamingOutputs,
 };
-use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
 
 enum InnerHashJoinProbeState {
-    Building(BroadcastStateBridgeRef<ProbeState>),
-    Probing(Arc<ProbeState>),
+    Building(BroadcastStateBridgeRef<HashJoinBuild>),
+    Probing(Arc<HashJoinBuild>),
 }
 
 impl InnerHashJoinProbeState {
-    async fn get_or_await_probe_state(&mut self) -> Arc<ProbeState> {
+    async fn get_or_await_build(&mut self) -> Arc<HashJoinBuild> {
         match self {
             Self::Building(bridge) => {
-                let probe_state = bridge.get_state().await;
-                *self = Self::Probing(probe_state.clone());
-                probe_state
+                let build = bridge.get_state().await;
+                *self = Self::Probing(build.clone());
+                build
             }
-            Self::Probing(probeable) => probeable.clone(),
+            Self::Probing(build) => build.clone(),
         }
     }
 }
@@ -41,6 +46,7 @@ impl IntermediateOpState for InnerHashJoinProbeState {
 
 struct InnerHashJoinParams {
     probe_on: Vec<ExprRef>,
+    residual_predicate: Option<ExprRef>,
     common_join_keys: Vec<String>,
     left_non_join_columns: Vec<String>,
     right_non_join_columns: Vec<String>,
@@ -50,20 +56,25 @@ struct InnerHashJoinParams {
 pub struct InnerHashJoinProbeOperator {
     params: Arc<InnerHashJoinParams>,
     output_schema: SchemaRef,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
+    // If the build side was spilled, the probe side is partitioned the same way and spilled here,
+    // shared by all workers, to be joined once all of it is seen.
+    spilled_probe_partitions: Arc<Mutex<Vec<SpillWriter>>>,
 }
 
 impl InnerHashJoinProbeOperator {
     const DEFAULT_GROWABLE_SIZE: usize = 20;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         probe_on: Vec<ExprRef>,
+        residual_predicate: Option<ExprRef>,
         left_schema: &SchemaRef,
         right_schema: &SchemaRef,
         build_on_left: bool,
         common_join_keys: IndexSet<String>,
         output_schema: &SchemaRef,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
     ) -> Self {
         let left_non_join_columns = left_schema
             .fields
@@ -81,6 +92,7 @@ impl InnerHashJoinProbeOperator {
         Self {
             params: Arc::new(InnerHashJoinParams {
                 probe_on,
+                residual_predicate,
                 common_join_keys,
                 left_non_join_columns,
                 right_non_join_columns,
@@ -88,19 +100,36 @@ impl InnerHashJoinProbeOperator {
             }),
             output_schema: output_schema.clone(),
             probe_state_bridge,
+            spilled_probe_partitions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Partitions the probe side input like the spilled build side, and spills it.
+    fn spill_partitioned(
+        input: &MicroPartition,
+        spilled_build: &SpilledBuildSide,
+        spilled_probe_partitions: &Mutex<Vec<SpillWriter>>,
+        params: &InnerHashJoinParams,
+    ) -> DaftResult<()> {
+        let partitioned =
+            input.partition_by_hash(&params.probe_on, spilled_build.num_partitions())?;
+        let mut writers = spilled_probe_partitions.lock().unwrap();
+        if writers.is_empty() {
+            *writers = (0..spilled_build.num_partitions())
+                .map(|_| SpillWriter::try_new(input.schema()))
+                .collect::<DaftResult<_>>()?;
+        }
+        for (p, writer) in partitioned.iter().zip(writers.iter_mut()) {
+            writer.write(p)?;
         }
+        Ok(())
     }
 
     fn probe_inner(
         input: &Arc<MicroPartition>,
         probe_state: &Arc<ProbeState>,
-        probe_on: &[ExprRef],
-        common_join_keys: &[String],
-        left_non_join_columns: &[String],
-        right_non_join_columns: &[String],
-        build_on_left: bool,
+        params: &InnerHashJoinParams,
     ) -> DaftResult<Arc<MicroPartition>> {
-        let probe_table = probe_state.get_probeable();
         let tables = probe_state.get_tables();
 
         let _growables = info_span!("InnerHashJoinOperator::build_growables").entered();
@@ -124,17 +153,18 @@ impl InnerHashJoinProbeOperator {
             let _loop = info_span!("InnerHashJoinOperator::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
                 // we should emit one table at a time when this is streaming
-                let join_keys = table.eval_expression_list(probe_on)?;
-                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+                let join_keys = table.eval_expression_list(&params.probe_on)?;
+                let matches = probe_state.probe_matches(
+                    table,
+                    &join_keys,
+                    params.residual_predicate.as_ref(),
+                    params.build_on_left,
+                )?;
 
-                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                for (probe_row_idx, inner_iter) in matches.iter().enumerate() {
                     if let Some(inner_iter) = inner_iter {
-                        for (build_side_table_idx, build_row_idx) in inner_iter {
-                            build_side_growable.extend(
-                                build_side_table_idx as usize,
-                                build_row_idx as usize,
-                                1,
-                            );
+                        for &(build_side_table_idx, build_row_idx) in inner_iter {
+                            build_side_growable.extend(build_side_table_idx, build_row_idx, 1);
                             // we can perform run length compression for this to make this more efficient
                             probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
                         }
@@ -145,15 +175,15 @@ impl InnerHashJoinProbeOperator {
         let build_side_table = build_side_growable.build()?;
         let probe_side_table = probe_side_growable.build()?;
 
-        let (left_table, right_table) = if build_on_left {
+        let (left_table, right_table) = if params.build_on_left {
             (build_side_table, probe_side_table)
         } else {
             (probe_side_table, build_side_table)
         };
 
-        let join_keys_table = left_table.get_columns(common_join_keys)?;
-        let left_non_join_columns = left_table.get_columns(left_non_join_columns)?;
-        let right_non_join_columns = right_table.get_columns(right_non_join_columns)?;
+        let join_keys_table = left_table.get_columns(&params.common_join_keys)?;
+        let left_non_join_columns = left_table.get_columns(&params.left_non_join_columns)?;
+        let right_non_join_columns = right_table.get_columns(&params.right_non_join_columns)?;
         let final_table = join_keys_table
             .union(&left_non_join_columns)?
             .union(&right_non_join_columns)?;
@@ -184,6 +214,7 @@ impl IntermediateOperator for InnerHashJoinProbeOperator {
         }
 
         let params = self.params.clone();
+        let spilled_probe_partitions = self.spilled_probe_partitions.clone();
         task_spawner
             .spawn(
                 async move {
@@ -193,17 +224,64 @@ impl IntermediateOperator for InnerHashJoinProbeOperator {
                     .expect(
                         "InnerHashJoinProbeState should be used with InnerHashJoinProbeOperator",
                     );
-                    let probe_state = inner_join_state.get_or_await_probe_state().await;
-                    let res = Self::probe_inner(
-                        &input,
-                        &probe_state,
-                        &params.probe_on,
-                        &params.common_join_keys,
-                        &params.left_non_join_columns,
-                        &params.right_non_join_columns,
-                        params.build_on_left,
-                    );
-                    Ok((state, IntermediateOperatorResult::NeedMoreInput(Some(res?))))
+                    match &*inner_join_state.get_or_await_build().await {
+                        HashJoinBuild::InMemory(probe_state) => {
+                            let res = Self::probe_inner(&input, probe_state, &params);
+                            Ok((state, IntermediateOperatorResult::NeedMoreInput(Some(res?))))
+                        }
+                        HashJoinBuild::Spilled(spilled_build) => {
+                            Self::spill_partitioned(
+                                &input,
+                                spilled_build,
+                                &spilled_probe_partitions,
+                                &params,
+                            )?;
+                            Ok((state, IntermediateOperatorResult::NeedMoreInput(None)))
+                        }
+                    }
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "InnerHashJoinOperator::finalize")]
+    fn finalize(&self, task_spawner: &ExecutionTaskSpawner) -> IntermediateOpFinalizeResult {
+        let params = self.params.clone();
+        let bridge = self.probe_state_bridge.clone();
+        let spilled_probe_partitions = self.spilled_probe_partitions.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let build = bridge.get_state().await;
+                    let HashJoinBuild::Spilled(spilled_build) = &*build else {
+                        return Ok(None);
+                    };
+                    let probe_partitions =
+                        std::mem::take(&mut *spilled_probe_partitions.lock().unwrap())
+                            .into_iter()
+                            .map(SpillWriter::finish)
+                            .collect::<DaftResult<Vec<_>>>()?;
+                    let partitions = spilled_build
+                        .take_partitions()
+                        .into_iter()
+                        .zip(probe_partitions)
+                        .filter(|(build_partition, probe_partition)| {
+                            build_partition.num_rows() > 0 && probe_partition.num_rows() > 0
+                        });
+                    // Each pair of partitions is joined as it is consumed, so that only one
+                    // partition of the build side is in memory at a time.
+                    let outputs =
+                        partitions.map(move |(mut build_partition, mut probe_partition)| {
+                            let HashJoinBuild::Spilled(spilled_build) = &*build else {
+                                unreachable!("The build side should be spilled");
+                            };
+                            let probe_state =
+                                Arc::new(spilled_build.build_probe_state(&mut build_partition)?);
+                            let input = Arc::new(probe_partition.read()?);
+                            Self::probe_inner(&input, &probe_state, &params)
+                        });
+                    Ok(Some(Box::new(outputs) as StreamingOutputs))
                 },
                 Span::current(),
             )
@@ -225,6 +303,9 @@ impl IntermediateOperator for InnerHashJoinProbeOperator {
                 .map(|e| e.to_string())
                 .join(", ")
         ));
+        if let Some(residual_predicate) = &self.params.residual_predicate {
+            res.push(format!("Residual predicate: {residual_predicate}"));
+        }
         res.push(format!("Build on left: {}", self.params.build_on_left));
         res
     }
@@ -19,7 +19,7 @@ use crate::{
     resource_manager::MemoryManager,
     runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
     ExecutionRuntimeContext, ExecutionTaskSpawner, OperatorOutput, PipelineExecutionSnafu,
-    NUM_CPUS,
+    StreamingOutputs, NUM_CPUS,
 };
 
 pub(crate) trait IntermediateOpState: Send + Sync {
@@ -40,6 +40,7 @@ pub enum IntermediateOperatorResult {
 
 pub(crate) type IntermediateOpExecuteResult =
     OperatorOutput<DaftResult<(Box<dyn IntermediateOpState>, IntermediateOperatorResult)>>;
+pub(crate) type IntermediateOpFinalizeResult = OperatorOutput<DaftResult<Option<StreamingOutputs>>>;
 pub trait IntermediateOperator: Send + Sync {
     fn execute(
         &self,
@@ -47,6 +48,11 @@ pub trait IntermediateOperator: Send + Sync {
         state: Box<dyn IntermediateOpState>,
         task_spawner: &ExecutionTaskSpawner,
     ) -> IntermediateOpExecuteResult;
+    /// Produces any output that can only be computed once all input has been executed, e.g. the
+    /// join of data that was spilled to disk. Most operators have none.
+    fn finalize(&self, _task_spawner: &ExecutionTaskSpawner) -> IntermediateOpFinalizeResult {
+        Ok(None).into()
+    }
     fn name(&self) -> &'static str;
     fn multiline_display(&self) -> Vec<String>;
     fn make_state(&self) -> DaftResult<Box<dyn IntermediateOpState>> {
@@ -258,12 +264,14 @@ impl PipelineNode for IntermediateNode {
             self.name(),
         );
 
+        let memory_manager = runtime_handle.memory_manager();
         let mut output_receiver = self.spawn_workers(
             spawned_dispatch_result.worker_receivers,
             runtime_handle,
             maintain_order,
-            runtime_handle.memory_manager(),
+            memory_manager.clone(),
         );
+        let runtime_stats = self.runtime_stats.clone();
         runtime_handle.spawn(
             async move {
                 while let Some(morsel) = output_receiver.recv().await {
@@ -271,9 +279,28 @@ impl PipelineNode for IntermediateNode {
                         return Ok(());
                     }
                 }
+
+                let spawner = ExecutionTaskSpawner::new(
+                    get_compute_runtime(),
+                    memory_manager,
+                    runtime_stats,
+                    info_span!("IntermediateOp::finalize"),
+                );
+                if let Some(mut outputs) = op.finalize(&spawner).await?? {
+                    loop {
+                        let (next, rest) = spawner.next_output(outputs).await?;
+                        outputs = rest;
+                        let Some(morsel) = next else {
+                            break;
+                        };
+                        if counting_sender.send(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+                }
                 Ok(())
             },
-            op.name(),
+            self.name(),
         );
         Ok(destination_receiver)
     }
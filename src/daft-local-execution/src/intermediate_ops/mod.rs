@@ -1,4 +1,5 @@
 pub mod actor_pool_project;
+pub mod asof_join;
 pub mod cross_join;
 pub mod explode;
 pub mod filter;
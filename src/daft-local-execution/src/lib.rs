@@ -6,11 +6,14 @@ mod dispatcher;
 mod intermediate_ops;
 mod pipeline;
 mod progress_bar;
+mod recursive_cte;
 mod resource_manager;
 mod run;
 mod runtime_stats;
 mod sinks;
+mod sort_merge_join;
 mod sources;
+mod spill;
 mod state_bridge;
 
 use std::{
@@ -22,6 +25,7 @@ use std::{
 
 use common_error::{DaftError, DaftResult};
 use common_runtime::{RuntimeRef, RuntimeTask};
+use daft_micropartition::MicroPartition;
 use progress_bar::{OperatorProgressBar, ProgressBarColor, ProgressBarManager};
 use resource_manager::MemoryManager;
 pub use run::{ExecutionEngineResult, NativeExecutor};
@@ -198,6 +202,11 @@ impl Drop for ExecutionRuntimeContext {
     }
 }
 
+/// Outputs that are produced one at a time as they are sent downstream, for operators whose
+/// output is too large to hold in memory all at once.
+pub(crate) type StreamingOutputs =
+    Box<dyn Iterator<Item = DaftResult<Arc<MicroPartition>>> + Send + Sync>;
+
 pub(crate) struct ExecutionTaskSpawner {
     runtime_ref: RuntimeRef,
     memory_manager: Arc<MemoryManager>,
@@ -243,6 +252,10 @@ impl ExecutionTaskSpawner {
         })
     }
 
+    pub fn memory_manager(&self) -> Arc<MemoryManager> {
+        self.memory_manager.clone()
+    }
+
     pub fn spawn<F, O>(&self, future: F, inner_span: tracing::Span) -> RuntimeTask<DaftResult<O>>
     where
         F: Future<Output = DaftResult<O>> + Send + 'static,
@@ -256,6 +269,22 @@ impl ExecutionTaskSpawner {
         );
         self.runtime_ref.spawn(timed_fut)
     }
+
+    /// Produces the next of the streaming `outputs` on the compute runtime, since producing an
+    /// output can be expensive, e.g. reading back and sorting a spilled partition.
+    pub async fn next_output(
+        &self,
+        mut outputs: StreamingOutputs,
+    ) -> DaftResult<(Option<Arc<MicroPartition>>, StreamingOutputs)> {
+        self.spawn(
+            async move {
+                let next = outputs.next().transpose()?;
+                Ok((next, outputs))
+            },
+            tracing::Span::current(),
+        )
+        .await?
+    }
 }
 
 #[cfg(feature = "python")]
@@ -12,9 +12,10 @@ use common_file_formats::FileFormat;
 use daft_core::{join::JoinSide, prelude::Schema};
 use daft_dsl::{join::get_common_join_cols, resolved_col};
 use daft_local_plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, PhysicalWrite, Pivot,
-    Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AsofJoin, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, MonotonicallyIncreasingId, Offset,
+    PhysicalWrite, Pivot, Project, RecursiveCte, Repartition, Sample, Sort, SortMergeJoin, TopN,
+    UnGroupedAggregate, Unpivot, Window,
 };
 use daft_logical_plan::{stats::StatsState, JoinType};
 use daft_micropartition::{
@@ -29,11 +30,12 @@ use snafu::ResultExt;
 use crate::{
     channel::Receiver,
     intermediate_ops::{
-        actor_pool_project::ActorPoolProjectOperator, cross_join::CrossJoinOperator,
-        explode::ExplodeOperator, filter::FilterOperator,
+        actor_pool_project::ActorPoolProjectOperator, asof_join::AsofJoinOperator,
+        cross_join::CrossJoinOperator, explode::ExplodeOperator, filter::FilterOperator,
         inner_hash_join_probe::InnerHashJoinProbeOperator, intermediate_op::IntermediateNode,
         project::ProjectOperator, sample::SampleOperator, unpivot::UnpivotOperator,
     },
+    recursive_cte::{RecordingPartitionSetCache, RecursiveCteNode, RecursiveCteParams},
     sinks::{
         aggregate::AggregateSink,
         anti_semi_hash_join_probe::AntiSemiProbeSink,
@@ -44,12 +46,17 @@ use crate::{
         hash_join_build::HashJoinBuildSink,
         limit::LimitSink,
         monotonically_increasing_id::MonotonicallyIncreasingIdSink,
+        offset::OffsetSink,
         outer_hash_join_probe::OuterHashJoinProbeSink,
         pivot::PivotSink,
+        repartition::RepartitionSink,
         sort::SortSink,
         streaming_sink::StreamingSinkNode,
+        top_n::TopNSink,
+        window::WindowSink,
         write::{WriteFormat, WriteSink},
     },
+    sort_merge_join::SortMergeJoinNode,
     sources::{empty_scan::EmptyScanSource, in_memory::InMemorySource, source::SourceNode},
     state_bridge::BroadcastStateBridge,
     ExecutionRuntimeContext, PipelineCreationSnafu,
@@ -203,6 +210,16 @@ pub fn physical_plan_to_pipeline(
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             StreamingSinkNode::new(Arc::new(sink), vec![child_node], stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::Offset(Offset {
+            input,
+            num_rows,
+            stats_state,
+            ..
+        }) => {
+            let sink = OffsetSink::new(*num_rows as usize);
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            StreamingSinkNode::new(Arc::new(sink), vec![child_node], stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::Concat(Concat {
             input,
             other,
@@ -219,6 +236,38 @@ pub fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::RecursiveCte(RecursiveCte {
+            name,
+            anchor,
+            recursive,
+            working_table,
+            all_rows_table,
+            max_iterations,
+            schema,
+            stats_state,
+        }) => {
+            let anchor_node = physical_plan_to_pipeline(anchor, psets, cfg)?;
+            // Every iteration builds a new pipeline of the recursive term, so the partition sets it
+            // reads are recorded to be provided again along with those of the working table.
+            let recording_psets = RecordingPartitionSetCache::new(psets);
+            let recursive_node = physical_plan_to_pipeline(
+                recursive,
+                &recording_psets
+                    as &dyn PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>,
+                cfg,
+            )?;
+            let params = RecursiveCteParams {
+                name: name.clone(),
+                recursive_plan: recursive.clone(),
+                working_table: working_table.clone(),
+                all_rows_table: all_rows_table.clone(),
+                max_iterations: *max_iterations,
+                schema: schema.clone(),
+                cfg: cfg.clone(),
+                partition_sets: recording_psets.into_recorded(),
+            };
+            RecursiveCteNode::new(anchor_node, recursive_node, params, stats_state.clone()).boxed()
+        }
         LocalPhysicalPlan::UnGroupedAggregate(UnGroupedAggregate {
             input,
             aggregations,
@@ -296,10 +345,62 @@ pub fn physical_plan_to_pipeline(
             stats_state,
             ..
         }) => {
-            let sort_sink = SortSink::new(sort_by.clone(), descending.clone(), nulls_first.clone());
+            let sort_sink = SortSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                cfg.sample_size_for_sort,
+            );
             let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
             BlockingSinkNode::new(Arc::new(sort_sink), child_node, stats_state.clone()).boxed()
         }
+        LocalPhysicalPlan::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            stats_state,
+        }) => {
+            let top_n_sink = TopNSink::new(
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                *limit as usize,
+                schema.clone(),
+            );
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            BlockingSinkNode::new(Arc::new(top_n_sink), child_node, stats_state.clone()).boxed()
+        }
+        LocalPhysicalPlan::Window(Window {
+            input,
+            window_functions,
+            window_spec,
+            schema,
+            stats_state,
+        }) => {
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let window_sink = WindowSink::new(window_functions, window_spec, schema);
+            BlockingSinkNode::new(Arc::new(window_sink), child_node, stats_state.clone()).boxed()
+        }
+        LocalPhysicalPlan::Repartition(Repartition {
+            input,
+            repartition_spec,
+            num_partitions,
+            schema,
+            stats_state,
+        }) => {
+            let child_node = physical_plan_to_pipeline(input, psets, cfg)?;
+            let repartition_sink = RepartitionSink::new(
+                repartition_spec.clone(),
+                *num_partitions,
+                schema.clone(),
+                cfg.sample_size_for_sort,
+            );
+            BlockingSinkNode::new(Arc::new(repartition_sink), child_node, stats_state.clone())
+                .boxed()
+        }
         LocalPhysicalPlan::MonotonicallyIncreasingId(MonotonicallyIncreasingId {
             input,
             column_name,
@@ -323,6 +424,7 @@ pub fn physical_plan_to_pipeline(
             left_on,
             right_on,
             null_equals_null,
+            residual_predicate,
             join_type,
             schema,
             stats_state,
@@ -414,6 +516,7 @@ pub fn physical_plan_to_pipeline(
                     // Else, default to building on the right
                     _ => false,
                 },
+                JoinType::AsOf => unreachable!("As-of joins are not planned as hash joins"),
             };
             let (build_on, probe_on, build_child, probe_child) = match build_on_left {
                 true => (left_on, right_on, left, right),
@@ -446,16 +549,21 @@ pub fn physical_plan_to_pipeline(
 
                 // we should move to a builder pattern
                 let probe_state_bridge = BroadcastStateBridge::new();
+                // anti and semi joins building on the right only need to know whether rows match,
+                // unless the residual predicate has to be evaluated on the matching rows
                 let track_indices = if matches!(join_type, JoinType::Anti | JoinType::Semi) {
-                    build_on_left
+                    build_on_left || residual_predicate.is_some()
                 } else {
                     true
                 };
+                // Only the inner join probe can join a spilled build side one partition at a time.
+                let spillable = matches!(join_type, JoinType::Inner);
                 let build_sink = HashJoinBuildSink::new(
                     key_schema,
                     build_on.clone(),
                     null_equals_null.clone(),
                     track_indices,
+                    spillable,
                     probe_state_bridge.clone(),
                 )?;
                 let build_child_node = physical_plan_to_pipeline(build_child, psets, cfg)?;
@@ -472,6 +580,7 @@ pub fn physical_plan_to_pipeline(
                     JoinType::Anti | JoinType::Semi => Ok(StreamingSinkNode::new(
                         Arc::new(AntiSemiProbeSink::new(
                             probe_on.clone(),
+                            residual_predicate.clone(),
                             join_type,
                             schema,
                             probe_state_bridge,
@@ -484,6 +593,7 @@ pub fn physical_plan_to_pipeline(
                     JoinType::Inner => Ok(IntermediateNode::new(
                         Arc::new(InnerHashJoinProbeOperator::new(
                             probe_on.clone(),
+                            residual_predicate.clone(),
                             left_schema,
                             right_schema,
                             build_on_left,
@@ -499,6 +609,7 @@ pub fn physical_plan_to_pipeline(
                         Ok(StreamingSinkNode::new(
                             Arc::new(OuterHashJoinProbeSink::new(
                                 probe_on.clone(),
+                                residual_predicate.clone(),
                                 left_schema,
                                 right_schema,
                                 *join_type,
@@ -512,6 +623,7 @@ pub fn physical_plan_to_pipeline(
                         )
                         .boxed())
                     }
+                    JoinType::AsOf => unreachable!("As-of joins are not planned as hash joins"),
                 }
             }()
             .with_context(|_| PipelineCreationSnafu {
@@ -576,6 +688,77 @@ pub fn physical_plan_to_pipeline(
             )
             .boxed()
         }
+        LocalPhysicalPlan::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            asof_options,
+            schema,
+            stats_state,
+        }) => {
+            // The right side is collected so that the left side can be streamed, since each left
+            // row is matched independently of the others.
+            let left_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let right_node = physical_plan_to_pipeline(right, psets, cfg)?;
+
+            let state_bridge = BroadcastStateBridge::new();
+            let collect_node = BlockingSinkNode::new(
+                Arc::new(CrossJoinCollectSink::new(state_bridge.clone())),
+                right_node,
+                right.get_stats_state().clone(),
+            )
+            .boxed();
+
+            IntermediateNode::new(
+                Arc::new(AsofJoinOperator::new(
+                    left_on.clone(),
+                    right_on.clone(),
+                    asof_options.clone(),
+                    left.schema().clone(),
+                    right.schema().clone(),
+                    schema.clone(),
+                    state_bridge,
+                )),
+                vec![collect_node, left_node],
+                stats_state.clone(),
+            )
+            .boxed()
+        }
+        LocalPhysicalPlan::SortMergeJoin(SortMergeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            join_type,
+            schema,
+            stats_state,
+        }) => {
+            if *join_type != JoinType::Inner {
+                return Err(DaftError::not_implemented(format!(
+                    "Sort-merge join type: {join_type}"
+                )))
+                .with_context(|_| PipelineCreationSnafu {
+                    plan_name: physical_plan.name(),
+                });
+            }
+            let left_node = physical_plan_to_pipeline(left, psets, cfg)?;
+            let right_node = physical_plan_to_pipeline(right, psets, cfg)?;
+            SortMergeJoinNode::try_new(
+                left_node,
+                right_node,
+                left_on.clone(),
+                right_on.clone(),
+                left.schema().clone(),
+                right.schema().clone(),
+                schema.clone(),
+                stats_state.clone(),
+            )
+            .with_context(|_| PipelineCreationSnafu {
+                plan_name: physical_plan.name(),
+            })?
+            .boxed()
+        }
         LocalPhysicalPlan::PhysicalWrite(PhysicalWrite {
             input,
             file_info,
@@ -590,6 +773,10 @@ pub fn physical_plan_to_pipeline(
                 (FileFormat::Parquet, false) => WriteFormat::Parquet,
                 (FileFormat::Csv, true) => WriteFormat::PartitionedCsv,
                 (FileFormat::Csv, false) => WriteFormat::Csv,
+                (FileFormat::Json, true) => WriteFormat::PartitionedJson,
+                (FileFormat::Json, false) => WriteFormat::Json,
+                (FileFormat::Ipc, true) => WriteFormat::PartitionedIpc,
+                (FileFormat::Ipc, false) => WriteFormat::Ipc,
                 (_, _) => panic!("Unsupported file format"),
             };
             let write_sink = WriteSink::new(
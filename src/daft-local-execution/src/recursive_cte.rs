@@ -0,0 +1,493 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use common_daft_config::DaftExecutionConfig;
+use common_display::tree::TreeDisplay;
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::SchemaRef;
+use daft_local_plan::LocalPhysicalPlanRef;
+use daft_logical_plan::stats::StatsState;
+use daft_micropartition::{
+    partitioning::{MicroPartitionSet, PartitionSetCache, PartitionSetRef},
+    MicroPartition, MicroPartitionRef,
+};
+
+use crate::{
+    channel::{create_channel, Receiver},
+    pipeline::{physical_plan_to_pipeline, PipelineNode},
+    progress_bar::{OperatorProgressBar, ProgressBarColor},
+    resource_manager::MemoryManager,
+    runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+    Error, ExecutionRuntimeContext,
+};
+
+/// Records the partition sets which are looked up while building the pipeline of a recursive term,
+/// so that the pipeline can be built again for every iteration without the original cache.
+#[derive(Debug)]
+pub(crate) struct RecordingPartitionSetCache<'a, C: ?Sized> {
+    inner: &'a C,
+    recorded: Mutex<HashMap<String, PartitionSetRef<MicroPartitionRef>>>,
+}
+
+impl<'a, C> RecordingPartitionSetCache<'a, C>
+where
+    C: PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized,
+{
+    pub(crate) fn new(inner: &'a C) -> Self {
+        Self {
+            inner,
+            recorded: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn into_recorded(self) -> HashMap<String, PartitionSetRef<MicroPartitionRef>> {
+        self.recorded.into_inner().unwrap()
+    }
+}
+
+impl<C> PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>>
+    for RecordingPartitionSetCache<'_, C>
+where
+    C: PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> + ?Sized,
+{
+    fn get_partition_set(&self, key: &str) -> Option<PartitionSetRef<MicroPartitionRef>> {
+        let pset = self.inner.get_partition_set(key)?;
+        self.recorded
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), pset.clone());
+        Some(pset)
+    }
+
+    fn get_all_partition_sets(&self) -> Vec<PartitionSetRef<MicroPartitionRef>> {
+        self.inner.get_all_partition_sets()
+    }
+
+    fn put_partition_set(&self, key: &str, partition_set: &Arc<MicroPartitionSet>) {
+        self.inner.put_partition_set(key, partition_set);
+    }
+
+    fn rm_partition_set(&self, key: &str) {
+        self.inner.rm_partition_set(key);
+    }
+
+    fn clear(&self) {
+        self.inner.clear();
+    }
+}
+
+/// The partition sets read by one iteration of a recursive term, which own their partitions for
+/// as long as the iteration's pipeline is built from them.
+#[derive(Debug)]
+struct IterationPartitionSetCache {
+    partition_sets: Mutex<HashMap<String, PartitionSetRef<MicroPartitionRef>>>,
+}
+
+impl IterationPartitionSetCache {
+    fn new(partition_sets: HashMap<String, PartitionSetRef<MicroPartitionRef>>) -> Self {
+        Self {
+            partition_sets: Mutex::new(partition_sets),
+        }
+    }
+}
+
+impl PartitionSetCache<MicroPartitionRef, Arc<MicroPartitionSet>> for IterationPartitionSetCache {
+    fn get_partition_set(&self, key: &str) -> Option<PartitionSetRef<MicroPartitionRef>> {
+        self.partition_sets.lock().unwrap().get(key).cloned()
+    }
+
+    fn get_all_partition_sets(&self) -> Vec<PartitionSetRef<MicroPartitionRef>> {
+        self.partition_sets
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn put_partition_set(&self, key: &str, partition_set: &Arc<MicroPartitionSet>) {
+        self.partition_sets
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), partition_set.clone());
+    }
+
+    fn rm_partition_set(&self, key: &str) {
+        self.partition_sets.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        self.partition_sets.lock().unwrap().clear();
+    }
+}
+
+pub(crate) struct RecursiveCteParams {
+    pub name: Arc<str>,
+    pub recursive_plan: LocalPhysicalPlanRef,
+    pub working_table: String,
+    pub all_rows_table: Option<String>,
+    pub max_iterations: usize,
+    pub schema: SchemaRef,
+    pub cfg: Arc<DaftExecutionConfig>,
+    // The partition sets other than the working table which the recursive term reads.
+    pub partition_sets: HashMap<String, PartitionSetRef<MicroPartitionRef>>,
+}
+
+/// Evaluates a recursive CTE by running a new pipeline of its recursive term on the rows produced
+/// by the previous iteration, starting with the rows of the anchor, until an iteration produces no
+/// rows. Rows are sent downstream as soon as they are produced, so that iterating stops once the
+/// consumer has stopped receiving.
+pub(crate) struct RecursiveCteNode {
+    params: Arc<RecursiveCteParams>,
+    anchor: Box<dyn PipelineNode>,
+    // The pipeline of the recursive term, which is only used for display since every iteration
+    // builds its own pipeline.
+    recursive: Box<dyn PipelineNode>,
+    runtime_stats: Arc<RuntimeStatsContext>,
+    plan_stats: StatsState,
+}
+
+impl RecursiveCteNode {
+    pub(crate) fn new(
+        anchor: Box<dyn PipelineNode>,
+        recursive: Box<dyn PipelineNode>,
+        params: RecursiveCteParams,
+        plan_stats: StatsState,
+    ) -> Self {
+        Self {
+            params: Arc::new(params),
+            anchor,
+            recursive,
+            runtime_stats: RuntimeStatsContext::new(),
+            plan_stats,
+        }
+    }
+
+    pub(crate) fn boxed(self) -> Box<dyn PipelineNode> {
+        Box::new(self)
+    }
+
+    /// Sends the non-empty morsels of `receiver` downstream and returns them, or `None` if the
+    /// consumer has stopped receiving.
+    async fn forward(
+        receiver: CountingReceiver,
+        sender: &CountingSender,
+    ) -> Option<Vec<Arc<MicroPartition>>> {
+        let mut output = vec![];
+        while let Some(morsel) = receiver.recv().await {
+            if morsel.is_empty() {
+                continue;
+            }
+            if sender.send(morsel.clone()).await.is_err() {
+                return None;
+            }
+            output.push(morsel);
+        }
+        Some(output)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn run_fixpoint(
+        params: Arc<RecursiveCteParams>,
+        anchor: CountingReceiver,
+        sender: CountingSender,
+        maintain_order: bool,
+        memory_manager: Arc<MemoryManager>,
+        runtime_stats: Arc<RuntimeStatsContext>,
+        progress_bar: Option<Arc<OperatorProgressBar>>,
+    ) -> DaftResult<()> {
+        let Some(mut working) = Self::forward(anchor, &sender).await else {
+            return Ok(());
+        };
+        let mut has_output = !working.is_empty();
+        let mut all_rows = match params.all_rows_table {
+            Some(_) => working.clone(),
+            None => vec![],
+        };
+
+        let mut num_iterations = 0;
+        while !working.is_empty() {
+            if num_iterations == params.max_iterations {
+                return Err(DaftError::ComputeError(format!(
+                    "Recursive CTE {} did not terminate within {} iterations, the limit can be raised with `daft.set_planning_config(recursive_cte_max_iterations=...)`",
+                    params.name, params.max_iterations
+                )));
+            }
+            num_iterations += 1;
+
+            let psets = IterationPartitionSetCache::new(params.partition_sets.clone());
+            psets.put_partition_set(
+                &params.working_table,
+                &Arc::new(MicroPartitionSet::from(std::mem::take(&mut working))),
+            );
+            if let Some(all_rows_table) = &params.all_rows_table {
+                psets.put_partition_set(
+                    all_rows_table,
+                    &Arc::new(MicroPartitionSet::from(all_rows.clone())),
+                );
+            }
+            let pipeline = physical_plan_to_pipeline(&params.recursive_plan, &psets, &params.cfg)?;
+
+            let mut runtime_handle = ExecutionRuntimeContext::new(
+                params.cfg.default_morsel_size,
+                memory_manager.clone(),
+                None,
+            );
+            let receiver = CountingReceiver::new(
+                pipeline.start(maintain_order, &mut runtime_handle)?,
+                runtime_stats.clone(),
+                progress_bar.clone(),
+            );
+            let Some(output) = Self::forward(receiver, &sender).await else {
+                runtime_handle.shutdown().await;
+                return Ok(());
+            };
+            while let Some(result) = runtime_handle.join_next().await {
+                match result {
+                    Ok(Err(e)) => {
+                        runtime_handle.shutdown().await;
+                        return Err(e.into());
+                    }
+                    Err(e) => {
+                        runtime_handle.shutdown().await;
+                        return Err(Error::JoinError { source: e }.into());
+                    }
+                    _ => {}
+                }
+            }
+
+            has_output |= !output.is_empty();
+            if params.all_rows_table.is_some() {
+                all_rows.extend(output.iter().cloned());
+            }
+            working = output;
+        }
+
+        if !has_output {
+            let empty = Arc::new(MicroPartition::empty(Some(params.schema.clone())));
+            let _ = sender.send(empty).await;
+        }
+        Ok(())
+    }
+}
+
+impl TreeDisplay for RecursiveCteNode {
+    fn display_as(&self, level: common_display::DisplayLevel) -> String {
+        use std::fmt::Write;
+        let mut display = String::new();
+
+        use common_display::DisplayLevel;
+        match level {
+            DisplayLevel::Compact => {
+                writeln!(display, "{}", self.name()).unwrap();
+            }
+            level => {
+                writeln!(display, "RecursiveCte:").unwrap();
+                writeln!(display, "Name = {}", self.params.name).unwrap();
+                writeln!(
+                    display,
+                    "Union all = {}",
+                    self.params.all_rows_table.is_none()
+                )
+                .unwrap();
+                writeln!(display, "Max iterations = {}", self.params.max_iterations).unwrap();
+                if let StatsState::Materialized(stats) = &self.plan_stats {
+                    writeln!(display, "Stats = {}", stats).unwrap();
+                }
+                if matches!(level, DisplayLevel::Verbose) {
+                    writeln!(display).unwrap();
+                    let rt_result = self.runtime_stats.result();
+                    rt_result.display(&mut display, true, true, true).unwrap();
+                }
+            }
+        }
+        display
+    }
+
+    fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+        vec![
+            self.anchor.as_tree_display(),
+            self.recursive.as_tree_display(),
+        ]
+    }
+}
+
+impl PipelineNode for RecursiveCteNode {
+    fn children(&self) -> Vec<&dyn PipelineNode> {
+        vec![self.anchor.as_ref(), self.recursive.as_ref()]
+    }
+
+    fn name(&self) -> &'static str {
+        "RecursiveCte"
+    }
+
+    fn start(
+        &self,
+        maintain_order: bool,
+        runtime_handle: &mut ExecutionRuntimeContext,
+    ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        let progress_bar = runtime_handle.make_progress_bar(
+            self.name(),
+            ProgressBarColor::Magenta,
+            true,
+            self.runtime_stats.clone(),
+        );
+        let anchor_receiver = CountingReceiver::new(
+            self.anchor.start(maintain_order, runtime_handle)?,
+            self.runtime_stats.clone(),
+            progress_bar.clone(),
+        );
+        let (destination_sender, destination_receiver) = create_channel(0);
+        let counting_sender = CountingSender::new(
+            destination_sender,
+            self.runtime_stats.clone(),
+            progress_bar.clone(),
+        );
+
+        runtime_handle.spawn(
+            Self::run_fixpoint(
+                self.params.clone(),
+                anchor_receiver,
+                counting_sender,
+                maintain_order,
+                runtime_handle.memory_manager(),
+                self.runtime_stats.clone(),
+                progress_bar,
+            ),
+            self.name(),
+        );
+        Ok(destination_receiver)
+    }
+
+    fn as_tree_display(&self) -> &dyn TreeDisplay {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{binary_op, lit, resolved_col, Operator};
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_micropartition::{
+        partitioning::{
+            InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSetCache,
+        },
+        MicroPartition,
+    };
+    use daft_recordbatch::RecordBatch;
+
+    use crate::NativeExecutor;
+
+    /// Runs a recursive CTE whose anchor is the single row `n = 1` and whose recursive term maps
+    /// every row of the previous iteration with `next`, returning the sorted values of `n`.
+    fn run_recursive_cte(
+        next: impl FnOnce(LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder>,
+        union_all: bool,
+        max_iterations: usize,
+    ) -> DaftResult<Vec<i64>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64)])?);
+        let anchor = Int64Array::from(("n", vec![1])).into_series();
+        let anchor = MicroPartition::new_loaded(
+            schema.clone(),
+            Arc::new(vec![RecordBatch::from_nonempty_columns(vec![anchor])?]),
+            None,
+        );
+        let pset = Arc::new(MicroPartitionSet::from(vec![Arc::new(anchor)]));
+        let psets = InMemoryPartitionSetCache::empty();
+        psets.put_partition_set("anchor", &pset);
+
+        let anchor = LogicalPlanBuilder::in_memory_scan(
+            "anchor",
+            PartitionCacheEntry::new_rust("anchor".to_string(), pset.clone()),
+            schema.clone(),
+            1,
+            0,
+            1,
+        )?;
+        let working = LogicalPlanBuilder::working_table_scan("working", schema.clone());
+        let mut recursive = next(working)?;
+        let mut all_rows_table = None;
+        if !union_all {
+            let all_rows = LogicalPlanBuilder::working_table_scan("all_rows", schema);
+            recursive = recursive.distinct()?.except(&all_rows, false)?;
+            all_rows_table = Some("all_rows".to_string());
+        }
+        let plan = anchor.recursive_cte(
+            "nums",
+            &recursive,
+            "working".to_string(),
+            all_rows_table,
+            max_iterations,
+        )?;
+
+        // dropping the executor cancels its execution, so it's kept until all results are received.
+        let executor = NativeExecutor::new();
+        let result = executor.run(
+            &plan,
+            &psets,
+            Arc::new(DaftExecutionConfig::default()),
+            None,
+        )?;
+        let mut values = vec![];
+        for morsel in result {
+            for table in morsel?.get_tables()?.iter() {
+                let column = table.get_column("n")?.i64()?;
+                values.extend((0..column.len()).filter_map(|i| column.get(i)));
+            }
+        }
+        values.sort_unstable();
+        Ok(values)
+    }
+
+    fn increment_below_five(working: LogicalPlanBuilder) -> DaftResult<LogicalPlanBuilder> {
+        working
+            .filter(resolved_col("n").lt(lit(5i64)))?
+            .select(vec![binary_op(
+                Operator::Plus,
+                resolved_col("n"),
+                lit(1i64),
+            )
+            .alias("n")])
+    }
+
+    #[test]
+    fn test_recursive_cte_iterates_until_no_rows() -> DaftResult<()> {
+        let values = run_recursive_cte(increment_below_five, true, 100)?;
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recursive_cte_union_terminates_on_cycles() -> DaftResult<()> {
+        // n -> n % 3 + 1 cycles through 1, 2, 3 forever, unless rows already produced are dropped.
+        let values = run_recursive_cte(
+            |working| {
+                working.select(vec![binary_op(
+                    Operator::Plus,
+                    binary_op(Operator::Modulus, resolved_col("n"), lit(3i64)),
+                    lit(1i64),
+                )
+                .alias("n")])
+            },
+            false,
+            100,
+        )?;
+        assert_eq!(values, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recursive_cte_fails_after_max_iterations() {
+        let err = run_recursive_cte(increment_below_five, true, 2).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Recursive CTE nums did not terminate within 2 iterations"));
+    }
+}
@@ -6,6 +6,9 @@ use tokio::sync::Notify;
 
 pub(crate) static MEMORY_MANAGER: OnceLock<Arc<MemoryManager>> = OnceLock::new();
 
+/// The fraction of system memory that the memory manager hands out by default.
+const DEFAULT_MEMORY_FRACTION: f64 = 0.8;
+
 fn custom_memory_limit() -> Option<u64> {
     let memory_limit_var_name = "DAFT_MEMORY_LIMIT";
     if let Ok(val) = std::env::var(memory_limit_var_name) {
@@ -16,6 +19,18 @@ fn custom_memory_limit() -> Option<u64> {
     None
 }
 
+fn memory_fraction() -> f64 {
+    let memory_fraction_var_name = "DAFT_MEMORY_FRACTION";
+    if let Ok(val) = std::env::var(memory_fraction_var_name) {
+        if let Ok(val) = val.parse::<f64>() {
+            if val > 0.0 && val <= 1.0 {
+                return val;
+            }
+        }
+    }
+    DEFAULT_MEMORY_FRACTION
+}
+
 pub(crate) fn get_or_init_memory_manager() -> &'static Arc<MemoryManager> {
     MEMORY_MANAGER.get_or_init(|| Arc::new(MemoryManager::new()))
 }
@@ -26,6 +41,24 @@ pub(crate) struct MemoryPermit<'a> {
 }
 
 impl Drop for MemoryPermit<'_> {
+    fn drop(&mut self) {
+        {
+            let mut state = self.manager.state.lock().unwrap();
+            state.available_bytes += self.bytes;
+            state.num_permits -= 1;
+        } // lock is released here
+        self.manager.notify.notify_waiters();
+    }
+}
+
+/// Memory held on to across operator calls, e.g. for data buffered by a blocking sink.
+/// Unlike a [`MemoryPermit`], a reservation owns a handle to its manager so it can be stored in operator state.
+pub(crate) struct MemoryReservation {
+    bytes: u64,
+    manager: Arc<MemoryManager>,
+}
+
+impl Drop for MemoryReservation {
     fn drop(&mut self) {
         if self.bytes > 0 {
             {
@@ -37,8 +70,10 @@ impl Drop for MemoryPermit<'_> {
     }
 }
 
+/// Permits and reservations are taken from the same budget.
 struct MemoryState {
     available_bytes: u64,
+    num_permits: usize,
 }
 
 pub(crate) struct MemoryManager {
@@ -51,39 +86,31 @@ impl Default for MemoryManager {
     fn default() -> Self {
         let system_info = SystemInfo::default();
         let total_mem = system_info.calculate_total_memory();
-        Self {
-            total_bytes: total_mem,
-            state: Mutex::new(MemoryState {
-                available_bytes: total_mem,
-            }),
-            notify: Notify::new(),
-        }
+        Self::with_limit((total_mem as f64 * memory_fraction()) as u64)
     }
 }
 
 impl MemoryManager {
     pub fn new() -> Self {
         if let Some(custom_limit) = custom_memory_limit() {
-            Self {
-                total_bytes: custom_limit,
-                state: Mutex::new(MemoryState {
-                    available_bytes: custom_limit,
-                }),
-                notify: Notify::new(),
-            }
+            Self::with_limit(custom_limit)
         } else {
             Self::default()
         }
     }
 
-    pub async fn request_bytes(&self, bytes: u64) -> DaftResult<MemoryPermit> {
-        if bytes == 0 {
-            return Ok(MemoryPermit {
-                bytes: 0,
-                manager: self,
-            });
+    pub fn with_limit(total_bytes: u64) -> Self {
+        Self {
+            total_bytes,
+            state: Mutex::new(MemoryState {
+                available_bytes: total_bytes,
+                num_permits: 0,
+            }),
+            notify: Notify::new(),
         }
+    }
 
+    pub async fn request_bytes(&self, bytes: u64) -> DaftResult<MemoryPermit> {
         if bytes > self.total_bytes {
             return Err(DaftError::ComputeError(format!(
                 "Cannot request {} bytes, only {} available",
@@ -92,10 +119,32 @@ impl MemoryManager {
         }
 
         loop {
-            if let Some(permit) = self.try_request_bytes(bytes) {
+            // Register for notifications before checking, so that memory released in between is not missed.
+            let notified = self.notify.notified();
+            let mut notified = std::pin::pin!(notified);
+            notified.as_mut().enable();
+            if let Some(permit) = self
+                .try_request_bytes(bytes)
+                .or_else(|| self.try_request_reserved_bytes(bytes))
+            {
                 return Ok(permit);
             }
-            self.notify.notified().await;
+            notified.await;
+        }
+    }
+
+    /// Reserves `bytes` without waiting, returning `None` if there is not enough memory left.
+    /// Operators should spill the data they buffer to disk when this fails.
+    pub fn try_reserve(self: &Arc<Self>, bytes: u64) -> Option<MemoryReservation> {
+        let mut state = self.state.lock().unwrap();
+        if state.available_bytes >= bytes {
+            state.available_bytes -= bytes;
+            Some(MemoryReservation {
+                bytes,
+                manager: self.clone(),
+            })
+        } else {
+            None
         }
     }
 
@@ -103,6 +152,25 @@ impl MemoryManager {
         let mut state = self.state.lock().unwrap();
         if state.available_bytes >= bytes {
             state.available_bytes -= bytes;
+            state.num_permits += 1;
+            Some(MemoryPermit {
+                bytes,
+                manager: self,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Grants a permit with whatever memory is left if nothing but reservations holds the rest.
+    /// Reservations are only released once their operators spill or finish, both of which need more input,
+    /// so waiting on them could stall the pipeline. Operators spill once they can no longer reserve memory.
+    fn try_request_reserved_bytes(&self, bytes: u64) -> Option<MemoryPermit> {
+        let mut state = self.state.lock().unwrap();
+        if state.num_permits == 0 {
+            let bytes = bytes.min(state.available_bytes);
+            state.available_bytes -= bytes;
+            state.num_permits += 1;
             Some(MemoryPermit {
                 bytes,
                 manager: self,
@@ -247,4 +315,54 @@ mod tests {
 
         task_set.join_all().await;
     }
+
+    #[test]
+    fn test_reservations() {
+        let manager = Arc::new(MemoryManager::with_limit(100));
+
+        let reservation = manager.try_reserve(60).unwrap();
+        assert!(manager.try_reserve(60).is_none());
+        let _other = manager.try_reserve(40).unwrap();
+        assert!(manager.try_reserve(1).is_none());
+
+        // Reservations take away from the memory available to permits.
+        assert!(manager.try_request_bytes(1).is_none());
+
+        // Dropping a reservation releases it.
+        drop(reservation);
+        assert!(manager.try_reserve(60).is_some());
+    }
+
+    #[test]
+    fn test_permits_take_away_from_reservations() {
+        let manager = Arc::new(MemoryManager::with_limit(100));
+
+        let permit = manager.try_request_bytes(60).unwrap();
+        assert!(manager.try_reserve(60).is_none());
+        drop(permit);
+        assert!(manager.try_reserve(60).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reservations_do_not_block_permits_forever() {
+        let manager = Arc::new(MemoryManager::with_limit(100));
+        let reservation = manager.try_reserve(80).unwrap();
+
+        // With no other permits to wait for, a permit gets what the reservations left.
+        let permit = manager.request_bytes(50).await.unwrap();
+        assert_eq!(permit.bytes, 20);
+        assert!(manager.try_reserve(1).is_none());
+
+        // A second permit waits for memory to be released.
+        let manager_clone = manager.clone();
+        let wait_handle = tokio::spawn(async move {
+            let _permit = manager_clone.request_bytes(50).await.unwrap();
+        });
+        time::sleep(Duration::from_millis(50)).await;
+        assert!(!wait_handle.is_finished());
+
+        drop(permit);
+        drop(reservation);
+        wait_handle.await.unwrap();
+    }
 }
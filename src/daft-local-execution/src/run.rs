@@ -196,7 +196,7 @@ impl NativeExecutor {
         results_buffer_size: Option<usize>,
     ) -> DaftResult<ExecutionEngineResult> {
         let logical_plan = logical_plan_builder.build();
-        let physical_plan = translate(&logical_plan)?;
+        let physical_plan = translate(&logical_plan, &cfg)?;
         refresh_chrome_trace();
         let cancel = self.cancel.clone();
         let pipeline = physical_plan_to_pipeline(&physical_plan, psets, &cfg)?;
@@ -295,7 +295,7 @@ impl NativeExecutor {
         simple: bool,
     ) -> String {
         let logical_plan = logical_plan_builder.build();
-        let physical_plan = translate(&logical_plan).unwrap();
+        let physical_plan = translate(&logical_plan, &cfg).unwrap();
         let pipeline_node =
             physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
                 .unwrap();
@@ -310,7 +310,7 @@ impl NativeExecutor {
         options: MermaidDisplayOptions,
     ) -> String {
         let logical_plan = logical_plan_builder.build();
-        let physical_plan = translate(&logical_plan).unwrap();
+        let physical_plan = translate(&logical_plan, &cfg).unwrap();
         let pipeline_node =
             physical_plan_to_pipeline(&physical_plan, &InMemoryPartitionSetCache::empty(), &cfg)
                 .unwrap();
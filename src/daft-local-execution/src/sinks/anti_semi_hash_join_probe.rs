@@ -5,12 +5,13 @@ use daft_core::{prelude::SchemaRef, series::IntoSeries};
 use daft_dsl::ExprRef;
 use daft_logical_plan::JoinType;
 use daft_micropartition::MicroPartition;
-use daft_recordbatch::{GrowableRecordBatch, ProbeState, Probeable, RecordBatch};
+use daft_recordbatch::{GrowableRecordBatch, ProbeState, RecordBatch};
 use futures::{stream, StreamExt};
 use itertools::Itertools;
 use tracing::{info_span, instrument, Span};
 
 use super::{
+    hash_join_build::HashJoinBuild,
     outer_hash_join_probe::IndexBitmapBuilder,
     streaming_sink::{
         StreamingSink, StreamingSinkExecuteResult, StreamingSinkOutput, StreamingSinkState,
@@ -23,7 +24,7 @@ use crate::{
 };
 
 enum AntiSemiProbeState {
-    Building(BroadcastStateBridgeRef<ProbeState>),
+    Building(BroadcastStateBridgeRef<HashJoinBuild>),
     Probing(Arc<ProbeState>, Option<IndexBitmapBuilder>),
 }
 
@@ -31,9 +32,9 @@ impl AntiSemiProbeState {
     async fn get_or_await_probe_state(
         &mut self,
         needs_bitmap: bool,
-    ) -> (&mut Arc<ProbeState>, &mut Option<IndexBitmapBuilder>) {
+    ) -> DaftResult<(&mut Arc<ProbeState>, &mut Option<IndexBitmapBuilder>)> {
         if let Self::Building(bridge) = self {
-            let probe_state = bridge.get_state().await;
+            let probe_state = bridge.get_state().await.probe_state()?;
             let builder = if needs_bitmap {
                 Some(IndexBitmapBuilder::new(probe_state.get_tables()))
             } else {
@@ -42,7 +43,7 @@ impl AntiSemiProbeState {
             *self = Self::Probing(probe_state, builder);
         }
         match self {
-            Self::Probing(probe_state, builder) => (probe_state, builder),
+            Self::Probing(probe_state, builder) => Ok((probe_state, builder)),
             _ => unreachable!(),
         }
     }
@@ -56,13 +57,14 @@ impl StreamingSinkState for AntiSemiProbeState {
 
 struct AntiSemiJoinParams {
     probe_on: Vec<ExprRef>,
+    residual_predicate: Option<ExprRef>,
     is_semi: bool,
 }
 
 pub(crate) struct AntiSemiProbeSink {
     params: Arc<AntiSemiJoinParams>,
     output_schema: SchemaRef,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
     build_on_left: bool,
 }
 
@@ -71,14 +73,16 @@ impl AntiSemiProbeSink {
 
     pub fn new(
         probe_on: Vec<ExprRef>,
+        residual_predicate: Option<ExprRef>,
         join_type: &JoinType,
         output_schema: &SchemaRef,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
         build_on_left: bool,
     ) -> Self {
         Self {
             params: Arc::new(AntiSemiJoinParams {
                 probe_on,
+                residual_predicate,
                 is_semi: *join_type == JoinType::Semi,
             }),
             output_schema: output_schema.clone(),
@@ -91,7 +95,8 @@ impl AntiSemiProbeSink {
     // on the right side and are streaming the left side.
     fn probe_anti_semi(
         probe_on: &[ExprRef],
-        probe_set: &Arc<dyn Probeable>,
+        residual_predicate: Option<&ExprRef>,
+        probe_state: &ProbeState,
         input: &Arc<MicroPartition>,
         is_semi: bool,
     ) -> DaftResult<Arc<MicroPartition>> {
@@ -110,7 +115,18 @@ impl AntiSemiProbeSink {
             let _loop = info_span!("AntiSemiOperator::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
                 let join_keys = table.eval_expression_list(probe_on)?;
-                let iter = probe_set.probe_exists(&join_keys)?;
+                // rows only match if one of their matches satisfies the residual predicate
+                let iter: Box<dyn Iterator<Item = bool>> = match residual_predicate {
+                    Some(predicate) => Box::new(
+                        probe_state
+                            .probe_matches(table, &join_keys, Some(predicate), false)?
+                            .iter()
+                            .map(|matches| matches.is_some())
+                            .collect::<Vec<_>>()
+                            .into_iter(),
+                    ),
+                    None => probe_state.get_probeable().probe_exists(&join_keys)?,
+                };
 
                 for (probe_row_idx, matched) in iter.enumerate() {
                     // 1. If this is a semi join, we keep the row if it matches.
@@ -137,7 +153,8 @@ impl AntiSemiProbeSink {
     // emit a final result at the end.
     fn probe_anti_semi_with_bitmap(
         probe_on: &[ExprRef],
-        probe_set: &Arc<dyn Probeable>,
+        residual_predicate: Option<&ExprRef>,
+        probe_state: &ProbeState,
         bitmap_builder: &mut IndexBitmapBuilder,
         input: &Arc<MicroPartition>,
     ) -> DaftResult<()> {
@@ -146,11 +163,11 @@ impl AntiSemiProbeSink {
         let _loop = info_span!("AntiSemiOperator::eval_and_probe").entered();
         for table in input_tables.iter() {
             let join_keys = table.eval_expression_list(probe_on)?;
-            let idx_mapper = probe_set.probe_indices(&join_keys)?;
+            let matches = probe_state.probe_matches(table, &join_keys, residual_predicate, true)?;
 
-            for inner_iter in idx_mapper.make_iter().flatten() {
-                for (build_side_table_idx, build_row_idx) in inner_iter {
-                    bitmap_builder.mark_used(build_side_table_idx as usize, build_row_idx as usize);
+            for inner_iter in matches.iter().flatten() {
+                for &(build_side_table_idx, build_row_idx) in inner_iter {
+                    bitmap_builder.mark_used(build_side_table_idx, build_row_idx);
                 }
             }
         }
@@ -170,7 +187,7 @@ impl AntiSemiProbeSink {
             .downcast_mut::<AntiSemiProbeState>()
             .expect("state should be AntiSemiProbeState");
         let (first_probe_state, first_bitmap_builder) =
-            first_state.get_or_await_probe_state(true).await;
+            first_state.get_or_await_probe_state(true).await?;
         let tables = first_probe_state.get_tables();
         let first_bitmap = first_bitmap_builder
             .take()
@@ -178,22 +195,24 @@ impl AntiSemiProbeSink {
             .build();
 
         let mut merged_bitmap = {
-            let bitmaps = stream::once(async move { first_bitmap })
+            let bitmaps = stream::once(async move { Ok(first_bitmap) })
                 .chain(stream::iter(states_iter).then(|s| async move {
                     let state = s
                         .as_any_mut()
                         .downcast_mut::<AntiSemiProbeState>()
                         .expect("state should be AntiSemiProbeState");
-                    state
+                    Ok(state
                         .get_or_await_probe_state(true)
-                        .await
+                        .await?
                         .1
                         .take()
                         .expect("bitmap should be set")
-                        .build()
+                        .build())
                 }))
-                .collect::<Vec<_>>()
-                .await;
+                .collect::<Vec<DaftResult<_>>>()
+                .await
+                .into_iter()
+                .collect::<DaftResult<Vec<_>>>()?;
 
             bitmaps.into_iter().fold(None, |acc, x| match acc {
                 None => Some(x),
@@ -246,11 +265,12 @@ impl StreamingSink for AntiSemiProbeSink {
                         .downcast_mut::<AntiSemiProbeState>()
                         .expect("AntiSemiProbeState should be used with AntiSemiProbeSink");
                     let (ps, bitmap_builder) =
-                        probe_state.get_or_await_probe_state(build_on_left).await;
+                        probe_state.get_or_await_probe_state(build_on_left).await?;
                     if let Some(bm_builder) = bitmap_builder {
                         Self::probe_anti_semi_with_bitmap(
                             &params.probe_on,
-                            ps.get_probeable(),
+                            params.residual_predicate.as_ref(),
+                            ps,
                             bm_builder,
                             &input,
                         )?;
@@ -258,7 +278,8 @@ impl StreamingSink for AntiSemiProbeSink {
                     } else {
                         let res = Self::probe_anti_semi(
                             &params.probe_on,
-                            ps.get_probeable(),
+                            params.residual_predicate.as_ref(),
+                            ps,
                             &input,
                             params.is_semi,
                         );
@@ -295,6 +316,9 @@ impl StreamingSink for AntiSemiProbeSink {
                     .join(", ")
             ));
         }
+        if let Some(residual_predicate) = &self.params.residual_predicate {
+            res.push(format!("Residual predicate: {residual_predicate}"));
+        }
         res.push(format!("Build on left: {}", self.build_on_left));
         res
     }
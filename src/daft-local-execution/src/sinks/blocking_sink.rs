@@ -15,7 +15,8 @@ use crate::{
     progress_bar::ProgressBarColor,
     resource_manager::MemoryManager,
     runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
-    ExecutionRuntimeContext, ExecutionTaskSpawner, JoinSnafu, OperatorOutput, TaskSet,
+    ExecutionRuntimeContext, ExecutionTaskSpawner, JoinSnafu, OperatorOutput, StreamingOutputs,
+    TaskSet,
 };
 pub trait BlockingSinkState: Send + Sync {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
@@ -28,8 +29,18 @@ pub enum BlockingSinkStatus {
 }
 
 pub(crate) type BlockingSinkSinkResult = OperatorOutput<DaftResult<BlockingSinkStatus>>;
-pub(crate) type BlockingSinkFinalizeResult =
-    OperatorOutput<DaftResult<Option<Arc<MicroPartition>>>>;
+pub enum BlockingSinkFinalizeOutput {
+    Finished(Vec<Arc<MicroPartition>>),
+    Streaming(StreamingOutputs),
+}
+
+impl From<Vec<Arc<MicroPartition>>> for BlockingSinkFinalizeOutput {
+    fn from(parts: Vec<Arc<MicroPartition>>) -> Self {
+        Self::Finished(parts)
+    }
+}
+
+pub(crate) type BlockingSinkFinalizeResult = OperatorOutput<DaftResult<BlockingSinkFinalizeOutput>>;
 pub trait BlockingSink: Send + Sync {
     fn sink(
         &self,
@@ -228,9 +239,24 @@ impl PipelineNode for BlockingSinkNode {
                     runtime_stats.clone(),
                     info_span!("BlockingSink::Finalize"),
                 );
-                let finalized_result = op.finalize(finished_states, &spawner).await??;
-                if let Some(res) = finalized_result {
-                    let _ = counting_sender.send(res).await;
+                match op.finalize(finished_states, &spawner).await?? {
+                    BlockingSinkFinalizeOutput::Finished(finalized_result) => {
+                        for res in finalized_result {
+                            if counting_sender.send(res).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    BlockingSinkFinalizeOutput::Streaming(mut outputs) => loop {
+                        let (next, rest) = spawner.next_output(outputs).await?;
+                        outputs = rest;
+                        let Some(res) = next else {
+                            break;
+                        };
+                        if counting_sender.send(res).await.is_err() {
+                            break;
+                        }
+                    },
                 }
                 Ok(())
             },
@@ -16,7 +16,11 @@ use super::blocking_sink::{
     BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
     BlockingSinkStatus,
 };
-use crate::{ExecutionTaskSpawner, NUM_CPUS};
+use crate::{
+    resource_manager::{MemoryManager, MemoryReservation},
+    spill::{can_spill, SpillFile, SpillWriter},
+    ExecutionTaskSpawner, NUM_CPUS,
+};
 
 #[derive(Clone)]
 enum AggStrategy {
@@ -108,6 +112,45 @@ struct SinglePartitionAggregateState {
     partially_aggregated: Vec<MicroPartition>,
     unaggregated: Vec<MicroPartition>,
     unaggregated_size: usize,
+    spilled_partially_aggregated: Vec<SpillFile>,
+    spilled_unaggregated: Vec<SpillFile>,
+}
+
+impl SinglePartitionAggregateState {
+    fn spill(&mut self) -> DaftResult<()> {
+        Self::spill_parts(
+            &mut self.partially_aggregated,
+            &mut self.spilled_partially_aggregated,
+        )?;
+        if Self::spill_parts(&mut self.unaggregated, &mut self.spilled_unaggregated)? {
+            self.unaggregated_size = 0;
+        }
+        Ok(())
+    }
+
+    /// Writes `parts` to a new spill file, returning whether they were spilled.
+    fn spill_parts(
+        parts: &mut Vec<MicroPartition>,
+        spilled: &mut Vec<SpillFile>,
+    ) -> DaftResult<bool> {
+        let Some(first) = parts.first() else {
+            return Ok(false);
+        };
+        let schema = first.schema();
+        if !can_spill(&schema) {
+            return Ok(false);
+        }
+        let mut writer = SpillWriter::try_new(schema)?;
+        for part in parts.drain(..) {
+            writer.write(&part)?;
+        }
+        spilled.push(writer.finish()?);
+        Ok(true)
+    }
+
+    fn has_spilled(&self) -> bool {
+        !self.spilled_partially_aggregated.is_empty() || !self.spilled_unaggregated.is_empty()
+    }
 }
 
 enum GroupedAggregateState {
@@ -116,6 +159,8 @@ enum GroupedAggregateState {
         strategy: Option<AggStrategy>,
         partial_agg_threshold: usize,
         high_cardinality_threshold_ratio: f64,
+        // Memory reserved for the data buffered in `inner_states`.
+        reservations: Vec<MemoryReservation>,
     },
     Done,
 }
@@ -132,6 +177,7 @@ impl GroupedAggregateState {
             strategy: None,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            reservations: Vec::new(),
         }
     }
 
@@ -140,17 +186,31 @@ impl GroupedAggregateState {
         input: Arc<MicroPartition>,
         params: &GroupedAggregateParams,
         global_strategy_lock: &Arc<Mutex<Option<AggStrategy>>>,
+        memory_manager: &Arc<MemoryManager>,
     ) -> DaftResult<()> {
         let Self::Accumulating {
             ref mut inner_states,
             strategy,
             partial_agg_threshold,
             high_cardinality_threshold_ratio,
+            reservations,
         } = self
         else {
             panic!("GroupedAggregateSink should be in Accumulating state");
         };
 
+        // If there is no memory left to buffer the input, spill the partitions buffered so far.
+        let size_bytes = input.size_bytes()?.unwrap_or(0) as u64;
+        if let Some(reservation) = memory_manager.try_reserve(size_bytes) {
+            reservations.push(reservation);
+        } else {
+            for state in inner_states.iter_mut().flatten() {
+                state.spill()?;
+            }
+            reservations.clear();
+            reservations.extend(memory_manager.try_reserve(size_bytes));
+        }
+
         // If we have determined a strategy, execute it.
         if let Some(strategy) = strategy {
             strategy.execute_strategy(inner_states, input, params)?;
@@ -306,6 +366,51 @@ impl GroupedAggregateSink {
     fn num_partitions(&self) -> usize {
         *NUM_CPUS
     }
+
+    fn finalize_partition(
+        per_partition_state: Vec<Option<SinglePartitionAggregateState>>,
+        params: &GroupedAggregateParams,
+    ) -> DaftResult<MicroPartition> {
+        let mut unaggregated = vec![];
+        let mut partially_aggregated = vec![];
+        for state in per_partition_state.into_iter().flatten() {
+            unaggregated.extend(state.unaggregated);
+            partially_aggregated.extend(state.partially_aggregated);
+            for mut spill_file in state.spilled_unaggregated {
+                unaggregated.push(spill_file.read()?);
+            }
+            for mut spill_file in state.spilled_partially_aggregated {
+                partially_aggregated.push(spill_file.read()?);
+            }
+        }
+
+        // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
+        if partially_aggregated.is_empty() {
+            let concated = MicroPartition::concat(&unaggregated)?;
+            let agged = concated.agg(&params.original_aggregations, &params.group_by)?;
+            Ok(agged)
+        }
+        // If we have no unaggregated partitions, finalize the partially aggregated partitions
+        else if unaggregated.is_empty() {
+            let concated = MicroPartition::concat(&partially_aggregated)?;
+            let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+            let projected = agged.eval_expression_list(&params.final_projections)?;
+            Ok(projected)
+        }
+        // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
+        else {
+            let leftover_partial_agg = MicroPartition::concat(&unaggregated)?
+                .agg(&params.partial_agg_exprs, &params.group_by)?;
+            let concated = MicroPartition::concat(
+                partially_aggregated
+                    .iter()
+                    .chain(std::iter::once(&leftover_partial_agg)),
+            )?;
+            let agged = concated.agg(&params.final_agg_exprs, &params.final_group_by)?;
+            let projected = agged.eval_expression_list(&params.final_projections)?;
+            Ok(projected)
+        }
+    }
 }
 
 impl BlockingSink for GroupedAggregateSink {
@@ -318,6 +423,7 @@ impl BlockingSink for GroupedAggregateSink {
     ) -> BlockingSinkSinkResult {
         let params = self.grouped_aggregate_params.clone();
         let strategy_lock = self.global_strategy_lock.clone();
+        let memory_manager = spawner.memory_manager();
         spawner
             .spawn(
                 async move {
@@ -326,7 +432,7 @@ impl BlockingSink for GroupedAggregateSink {
                         .downcast_mut::<GroupedAggregateState>()
                         .expect("GroupedAggregateSink should have GroupedAggregateState");
 
-                    agg_state.push(input, &params, &strategy_lock)?;
+                    agg_state.push(input, &params, &strategy_lock, &memory_manager)?;
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 Span::current(),
@@ -358,6 +464,7 @@ impl BlockingSink for GroupedAggregateSink {
                         .collect::<Vec<_>>();
 
                     let mut per_partition_finalize_tasks = tokio::task::JoinSet::new();
+                    let mut spilled_results = vec![];
                     for _ in 0..num_partitions {
                         let per_partition_state = state_iters
                             .iter_mut()
@@ -367,56 +474,29 @@ impl BlockingSink for GroupedAggregateSink {
                             )
                             })
                             .collect::<Vec<_>>();
+                        // Partitions with spilled data are finalized one at a time to bound memory usage.
+                        if per_partition_state
+                            .iter()
+                            .flatten()
+                            .any(|s| s.has_spilled())
+                        {
+                            spilled_results
+                                .push(Self::finalize_partition(per_partition_state, &params)?);
+                            continue;
+                        }
                         let params = params.clone();
                         per_partition_finalize_tasks.spawn(async move {
-                            let mut unaggregated = vec![];
-                            let mut partially_aggregated = vec![];
-                            for state in per_partition_state.into_iter().flatten() {
-                                unaggregated.extend(state.unaggregated);
-                                partially_aggregated.extend(state.partially_aggregated);
-                            }
-
-                            // If we have no partially aggregated partitions, aggregate the unaggregated partitions using the original aggregations
-                            if partially_aggregated.is_empty() {
-                                let concated = MicroPartition::concat(&unaggregated)?;
-                                let agged = concated
-                                    .agg(&params.original_aggregations, &params.group_by)?;
-                                Ok(agged)
-                            }
-                            // If we have no unaggregated partitions, finalize the partially aggregated partitions
-                            else if unaggregated.is_empty() {
-                                let concated = MicroPartition::concat(&partially_aggregated)?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
-                            // Otherwise, partially aggregate the unaggregated partitions, concatenate them with the partially aggregated partitions, and finalize the result.
-                            else {
-                                let leftover_partial_agg =
-                                    MicroPartition::concat(&unaggregated)?
-                                        .agg(&params.partial_agg_exprs, &params.group_by)?;
-                                let concated = MicroPartition::concat(
-                                    partially_aggregated
-                                        .iter()
-                                        .chain(std::iter::once(&leftover_partial_agg)),
-                                )?;
-                                let agged = concated
-                                    .agg(&params.final_agg_exprs, &params.final_group_by)?;
-                                let projected =
-                                    agged.eval_expression_list(&params.final_projections)?;
-                                Ok(projected)
-                            }
+                            Self::finalize_partition(per_partition_state, &params)
                         });
                     }
                     let results = per_partition_finalize_tasks
                         .join_all()
                         .await
                         .into_iter()
+                        .chain(spilled_results.into_iter().map(Ok))
                         .collect::<DaftResult<Vec<_>>>()?;
                     let concated = MicroPartition::concat(&results)?;
-                    Ok(Some(Arc::new(concated)))
+                    Ok(vec![Arc::new(concated)].into())
                 },
                 Span::current(),
             )
@@ -460,3 +540,89 @@ impl BlockingSink for GroupedAggregateSink {
         )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{GroupedAggregateSink, SinglePartitionAggregateState};
+
+    fn make_part(groups: Vec<i64>, values: Vec<i64>) -> DaftResult<MicroPartition> {
+        let g = Int64Array::from(("g", groups)).into_series();
+        let v = Int64Array::from(("v", values)).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![g, v])?;
+        Ok(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        ))
+    }
+
+    fn sorted_rows(part: &MicroPartition) -> DaftResult<RecordBatch> {
+        let sorted = part.sort(&[resolved_col("g")], &[false], &[false])?;
+        let tables = sorted.get_tables()?;
+        RecordBatch::concat(tables.as_slice())
+    }
+
+    /// Tests that finalizing a partition whose buffered data was spilled, both partially aggregated
+    /// and not, gives the same result as finalizing it in memory.
+    #[test]
+    fn spilled_partition_matches_in_memory_partition() -> DaftResult<()> {
+        let schema = make_part(vec![], vec![])?.schema();
+        let sink = GroupedAggregateSink::new(
+            &[
+                resolved_col("v").sum().alias("sum"),
+                resolved_col("v").mean().alias("mean"),
+            ],
+            &[resolved_col("g")],
+            &schema,
+            &DaftExecutionConfig::default(),
+        )?;
+        let params = sink.grouped_aggregate_params;
+
+        let make_state = || -> DaftResult<SinglePartitionAggregateState> {
+            let mut state = SinglePartitionAggregateState::default();
+            for i in 0..4 {
+                let part = make_part(
+                    (0..10).map(|j| (i + j) % 3).collect(),
+                    (0..10).map(|j| i * 10 + j).collect(),
+                )?;
+                if i % 2 == 0 {
+                    state
+                        .partially_aggregated
+                        .push(part.agg(&params.partial_agg_exprs, &params.group_by)?);
+                } else {
+                    state.unaggregated_size += part.len();
+                    state.unaggregated.push(part);
+                }
+            }
+            Ok(state)
+        };
+
+        let extra = || make_part(vec![0, 1, 5], vec![100, 200, 300]);
+
+        let mut in_memory = make_state()?;
+        in_memory.unaggregated.push(extra()?);
+        let in_memory = GroupedAggregateSink::finalize_partition(vec![Some(in_memory)], &params)?;
+
+        let mut spilled = make_state()?;
+        spilled.spill()?;
+        assert!(spilled.has_spilled());
+        assert!(spilled.partially_aggregated.is_empty() && spilled.unaggregated.is_empty());
+        assert_eq!(spilled.unaggregated_size, 0);
+        // Data buffered after spilling is finalized along with the spilled data.
+        spilled.unaggregated.push(extra()?);
+        let spilled = GroupedAggregateSink::finalize_partition(vec![Some(spilled)], &params)?;
+
+        assert_eq!(sorted_rows(&spilled)?.len(), 4);
+        assert_eq!(sorted_rows(&spilled)?, sorted_rows(&in_memory)?);
+        Ok(())
+    }
+}
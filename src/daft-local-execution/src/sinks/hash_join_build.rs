@@ -1,6 +1,6 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 use daft_core::prelude::SchemaRef;
 use daft_dsl::ExprRef;
 use daft_micropartition::MicroPartition;
@@ -9,16 +9,86 @@ use itertools::Itertools;
 use tracing::{info_span, instrument};
 
 use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
 };
-use crate::{state_bridge::BroadcastStateBridgeRef, ExecutionTaskSpawner};
+use crate::{
+    resource_manager::{MemoryManager, MemoryReservation},
+    spill::{can_spill, SpillFile, SpillWriter},
+    state_bridge::BroadcastStateBridgeRef,
+    ExecutionTaskSpawner,
+};
+
+/// Number of partitions the build side of a hash join is hash partitioned into when it spills.
+/// Each partition is joined on its own, so its probe table has to fit in memory.
+const NUM_SPILL_PARTITIONS: usize = 32;
+
+/// The build side of a hash join, as handed from the build sink to the probe operator.
+pub(crate) enum HashJoinBuild {
+    /// A probe table over the whole build side, held in memory.
+    InMemory(Arc<ProbeState>),
+    /// The build side hash partitioned by its join keys and spilled to disk. The probe side is
+    /// partitioned the same way, and each pair of partitions is joined once all input is seen.
+    Spilled(SpilledBuildSide),
+}
+
+impl HashJoinBuild {
+    /// The in-memory probe table, for the join types that cannot probe a spilled build side.
+    /// The build sink only spills when it is created as spillable, which is done for inner joins alone.
+    pub(crate) fn probe_state(&self) -> DaftResult<Arc<ProbeState>> {
+        match self {
+            Self::InMemory(probe_state) => Ok(probe_state.clone()),
+            Self::Spilled(_) => Err(DaftError::InternalError(
+                "Only inner hash joins can probe a spilled build side".to_string(),
+            )),
+        }
+    }
+}
+
+pub(crate) struct SpilledBuildSide {
+    partitions: Mutex<Vec<SpillFile>>,
+    key_schema: SchemaRef,
+    projection: Vec<ExprRef>,
+    nulls_equal_aware: Option<Vec<bool>>,
+    track_indices: bool,
+}
+
+impl SpilledBuildSide {
+    pub(crate) fn num_partitions(&self) -> usize {
+        NUM_SPILL_PARTITIONS
+    }
+
+    /// Takes the spilled partitions, which can only be done once.
+    pub(crate) fn take_partitions(&self) -> Vec<SpillFile> {
+        std::mem::take(&mut *self.partitions.lock().unwrap())
+    }
+
+    /// Reads a spilled partition back and builds a probe table over it.
+    pub(crate) fn build_probe_state(&self, partition: &mut SpillFile) -> DaftResult<ProbeState> {
+        let mut builder = make_probeable_builder(
+            self.key_schema.clone(),
+            self.nulls_equal_aware.as_ref(),
+            self.track_indices,
+        )?;
+        let tables = partition.read_tables()?.collect::<DaftResult<Vec<_>>>()?;
+        for table in &tables {
+            builder.add_table(&table.eval_expression_list(&self.projection)?)?;
+        }
+        Ok(ProbeState::new(builder.build(), tables.into()))
+    }
+}
 
 enum ProbeTableState {
     Building {
         probe_table_builder: Option<Box<dyn ProbeableBuilder>>,
         projection: Vec<ExprRef>,
         tables: Vec<RecordBatch>,
+        // Memory reserved for `tables`.
+        reservations: Vec<MemoryReservation>,
+    },
+    Spilling {
+        projection: Vec<ExprRef>,
+        partitions: Vec<SpillWriter>,
     },
     Done,
 }
@@ -38,49 +108,109 @@ impl ProbeTableState {
             )?),
             projection,
             tables: Vec::new(),
+            reservations: Vec::new(),
         })
     }
 
-    fn add_tables(&mut self, input: &Arc<MicroPartition>) -> DaftResult<()> {
-        if let Self::Building {
-            ref mut probe_table_builder,
-            projection,
-            tables,
-        } = self
-        {
-            let probe_table_builder = probe_table_builder.as_mut().unwrap();
-            let input_tables = input.get_tables()?;
-            if input_tables.is_empty() {
-                tables.push(RecordBatch::empty(Some(input.schema()))?);
-                return Ok(());
-            }
-            for table in input_tables.iter() {
-                tables.push(table.clone());
-                let join_keys = table.eval_expression_list(projection)?;
+    fn add_tables(
+        &mut self,
+        input: &Arc<MicroPartition>,
+        memory_manager: &Arc<MemoryManager>,
+        spillable: bool,
+    ) -> DaftResult<()> {
+        match self {
+            Self::Building {
+                ref mut probe_table_builder,
+                projection,
+                tables,
+                reservations,
+            } => {
+                let size_bytes = input.size_bytes()?.unwrap_or(0) as u64;
+                if let Some(reservation) = memory_manager.try_reserve(size_bytes) {
+                    reservations.push(reservation);
+                } else if spillable && can_spill(&input.schema()) {
+                    // There is no memory left for the build side, so spill it, partitioned by its join keys.
+                    let buffered = MicroPartition::new_loaded(
+                        input.schema(),
+                        Arc::new(std::mem::take(tables)),
+                        None,
+                    );
+                    let mut partitions = (0..NUM_SPILL_PARTITIONS)
+                        .map(|_| SpillWriter::try_new(input.schema()))
+                        .collect::<DaftResult<Vec<_>>>()?;
+                    Self::spill_partitioned(&buffered, projection, &mut partitions)?;
+                    Self::spill_partitioned(input, projection, &mut partitions)?;
+                    *self = Self::Spilling {
+                        projection: std::mem::take(projection),
+                        partitions,
+                    };
+                    return Ok(());
+                }
+
+                let probe_table_builder = probe_table_builder.as_mut().unwrap();
+                let input_tables = input.get_tables()?;
+                if input_tables.is_empty() {
+                    tables.push(RecordBatch::empty(Some(input.schema()))?);
+                    return Ok(());
+                }
+                for table in input_tables.iter() {
+                    tables.push(table.clone());
+                    let join_keys = table.eval_expression_list(projection)?;
 
-                probe_table_builder.add_table(&join_keys)?;
+                    probe_table_builder.add_table(&join_keys)?;
+                }
+                Ok(())
             }
-            Ok(())
-        } else {
-            panic!("add_tables can only be used during the Building Phase")
+            Self::Spilling {
+                projection,
+                partitions,
+            } => Self::spill_partitioned(input, projection, partitions),
+            Self::Done => panic!("add_tables can only be used during the Building Phase"),
         }
     }
-    fn finalize(&mut self) -> ProbeState {
-        if let Self::Building {
-            probe_table_builder,
-            tables,
-            ..
-        } = self
+
+    fn spill_partitioned(
+        input: &MicroPartition,
+        projection: &[ExprRef],
+        partitions: &mut [SpillWriter],
+    ) -> DaftResult<()> {
+        for (p, writer) in input
+            .partition_by_hash(projection, partitions.len())?
+            .iter()
+            .zip(partitions.iter_mut())
         {
-            let ptb = std::mem::take(probe_table_builder).expect("should be set in building mode");
-            let pt = ptb.build();
-
-            let ps = ProbeState::new(pt, tables.clone().into());
-            *self = Self::Done;
-            ps
-        } else {
-            panic!("finalize can only be used during the Building Phase")
+            writer.write(p)?;
         }
+        Ok(())
+    }
+
+    fn finalize(&mut self, sink: &HashJoinBuildSink) -> DaftResult<HashJoinBuild> {
+        let build = match std::mem::replace(self, Self::Done) {
+            Self::Building {
+                probe_table_builder,
+                tables,
+                ..
+            } => {
+                let pt = probe_table_builder
+                    .expect("should be set in building mode")
+                    .build();
+                HashJoinBuild::InMemory(Arc::new(ProbeState::new(pt, tables.into())))
+            }
+            Self::Spilling { partitions, .. } => HashJoinBuild::Spilled(SpilledBuildSide {
+                partitions: Mutex::new(
+                    partitions
+                        .into_iter()
+                        .map(SpillWriter::finish)
+                        .collect::<DaftResult<Vec<_>>>()?,
+                ),
+                key_schema: sink.key_schema.clone(),
+                projection: sink.projection.clone(),
+                nulls_equal_aware: sink.nulls_equal_aware.clone(),
+                track_indices: sink.track_indices,
+            }),
+            Self::Done => panic!("finalize can only be used during the Building Phase"),
+        };
+        Ok(build)
     }
 }
 
@@ -95,7 +225,9 @@ pub struct HashJoinBuildSink {
     projection: Vec<ExprRef>,
     nulls_equal_aware: Option<Vec<bool>>,
     track_indices: bool,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    // Whether the build side may be spilled to disk, which the probe side has to support.
+    spillable: bool,
+    probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
 }
 
 impl HashJoinBuildSink {
@@ -104,13 +236,15 @@ impl HashJoinBuildSink {
         projection: Vec<ExprRef>,
         nulls_equal_aware: Option<Vec<bool>>,
         track_indices: bool,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        spillable: bool,
+        probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
     ) -> DaftResult<Self> {
         Ok(Self {
             key_schema,
             projection,
             nulls_equal_aware,
             track_indices,
+            spillable,
             probe_state_bridge,
         })
     }
@@ -141,6 +275,8 @@ impl BlockingSink for HashJoinBuildSink {
         mut state: Box<dyn BlockingSinkState>,
         spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
+        let memory_manager = spawner.memory_manager();
+        let spillable = self.spillable;
         spawner
             .spawn(
                 async move {
@@ -148,7 +284,7 @@ impl BlockingSink for HashJoinBuildSink {
                         .as_any_mut()
                         .downcast_mut::<ProbeTableState>()
                         .expect("HashJoinBuildSink should have ProbeTableState");
-                    probe_table_state.add_tables(&input)?;
+                    probe_table_state.add_tables(&input, &memory_manager, spillable)?;
                     Ok(BlockingSinkStatus::NeedMoreInput(state))
                 },
                 info_span!("HashJoinBuildSink::sink"),
@@ -168,10 +304,12 @@ impl BlockingSink for HashJoinBuildSink {
             .as_any_mut()
             .downcast_mut::<ProbeTableState>()
             .expect("State type mismatch");
-        let finalized_probe_state = probe_table_state.finalize();
-        self.probe_state_bridge
-            .set_state(finalized_probe_state.into());
-        Ok(None).into()
+        let build = match probe_table_state.finalize(self) {
+            Ok(build) => build,
+            Err(e) => return Err(e).into(),
+        };
+        self.probe_state_bridge.set_state(build.into());
+        Ok(BlockingSinkFinalizeOutput::Finished(vec![])).into()
     }
 
     fn max_concurrency(&self) -> usize {
@@ -187,3 +325,97 @@ impl BlockingSink for HashJoinBuildSink {
         )?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{HashJoinBuild, HashJoinBuildSink, ProbeTableState};
+    use crate::{resource_manager::MemoryManager, state_bridge::BroadcastStateBridge};
+
+    fn make_part(name: &str, keys: Vec<i64>) -> DaftResult<Arc<MicroPartition>> {
+        let keys = Int64Array::from((name, keys)).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![keys])?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    /// Tests that a spilled build side is partitioned so that each probe side row only has to be
+    /// probed against the build side partition with the same index to find all of its matches.
+    #[test]
+    fn spilled_build_side_partitions_match_probe_side() -> DaftResult<()> {
+        let build_keys = (0..60).map(|i| i % 13).collect::<Vec<_>>();
+        let probe_keys = (0..50).map(|i| i % 17).collect::<Vec<_>>();
+
+        let key_schema = Arc::new(Schema::new(vec![Field::new("k", DataType::Int64)])?);
+        let sink = HashJoinBuildSink::new(
+            key_schema.clone(),
+            vec![resolved_col("k")],
+            None,
+            true,
+            true,
+            BroadcastStateBridge::new(),
+        )?;
+        let mut state = ProbeTableState::new(&key_schema, vec![resolved_col("k")], None, true)?;
+        // Too little memory to buffer anything, so the build side is spilled.
+        let memory_manager = Arc::new(MemoryManager::with_limit(1));
+        for chunk in build_keys.chunks(10) {
+            state.add_tables(&make_part("k", chunk.to_vec())?, &memory_manager, true)?;
+        }
+        let build = state.finalize(&sink)?;
+        // Only the inner join probe can join a spilled build side, the others get an error.
+        assert!(build.probe_state().is_err());
+        let HashJoinBuild::Spilled(spilled) = build else {
+            panic!("The build side should have been spilled");
+        };
+
+        let probe = make_part("pk", probe_keys.clone())?;
+        let probe_partitions =
+            probe.partition_by_hash(&[resolved_col("pk")], spilled.num_partitions())?;
+        let mut num_matches = 0;
+        for (mut build_partition, probe_partition) in
+            spilled.take_partitions().into_iter().zip(probe_partitions)
+        {
+            let probe_state = spilled.build_probe_state(&mut build_partition)?;
+            for table in probe_partition.get_tables()?.iter() {
+                let join_keys = table.eval_expression_list(&[resolved_col("pk")])?;
+                let matches = probe_state.probe_matches(table, &join_keys, None, false)?;
+                num_matches += matches.iter().flatten().map(<[_]>::len).sum::<usize>();
+            }
+        }
+
+        let expected = probe_keys
+            .iter()
+            .map(|p| build_keys.iter().filter(|b| *b == p).count())
+            .sum::<usize>();
+        assert_eq!(num_matches, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn build_side_stays_in_memory_unless_spillable() -> DaftResult<()> {
+        let key_schema = Arc::new(Schema::new(vec![Field::new("k", DataType::Int64)])?);
+        let sink = HashJoinBuildSink::new(
+            key_schema.clone(),
+            vec![resolved_col("k")],
+            None,
+            true,
+            false,
+            BroadcastStateBridge::new(),
+        )?;
+        let mut state = ProbeTableState::new(&key_schema, vec![resolved_col("k")], None, true)?;
+        let memory_manager = Arc::new(MemoryManager::with_limit(1));
+        state.add_tables(&make_part("k", vec![1, 2, 3])?, &memory_manager, false)?;
+        assert!(matches!(state.finalize(&sink)?, HashJoinBuild::InMemory(_)));
+        Ok(())
+    }
+}
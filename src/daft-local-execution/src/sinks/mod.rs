@@ -7,8 +7,12 @@ pub mod grouped_aggregate;
 pub mod hash_join_build;
 pub mod limit;
 pub mod monotonically_increasing_id;
+pub mod offset;
 pub mod outer_hash_join_probe;
 pub mod pivot;
+pub mod repartition;
 pub mod sort;
 pub mod streaming_sink;
+pub mod top_n;
+pub mod window;
 pub mod write;
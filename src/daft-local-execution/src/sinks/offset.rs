@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use daft_micropartition::MicroPartition;
+use tracing::{instrument, Span};
+
+use super::streaming_sink::{
+    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkOutput,
+    StreamingSinkState,
+};
+use crate::{
+    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
+
+struct OffsetSinkState {
+    to_skip: usize,
+}
+
+impl OffsetSinkState {
+    fn new(to_skip: usize) -> Self {
+        Self { to_skip }
+    }
+
+    fn get_to_skip_mut(&mut self) -> &mut usize {
+        &mut self.to_skip
+    }
+}
+
+impl StreamingSinkState for OffsetSinkState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+pub struct OffsetSink {
+    offset: usize,
+}
+
+impl OffsetSink {
+    pub fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+}
+
+impl StreamingSink for OffsetSink {
+    #[instrument(skip_all, name = "OffsetSink::sink")]
+    fn execute(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn StreamingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkExecuteResult {
+        let input_num_rows = input.len();
+
+        let to_skip = state
+            .as_any_mut()
+            .downcast_mut::<OffsetSinkState>()
+            .expect("Offset sink should have OffsetSinkState")
+            .get_to_skip_mut();
+        if *to_skip == 0 {
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(Some(input)))).into();
+        }
+        if input_num_rows <= *to_skip {
+            *to_skip -= input_num_rows;
+            return Ok((state, StreamingSinkOutput::NeedMoreInput(None))).into();
+        }
+        let start = *to_skip;
+        *to_skip = 0;
+        spawner
+            .spawn(
+                async move {
+                    let rest = input.slice(start, input_num_rows)?;
+                    Ok((state, StreamingSinkOutput::NeedMoreInput(Some(rest.into()))))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Offset"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        vec![format!("Offset: {}", self.offset)]
+    }
+
+    fn finalize(
+        &self,
+        _states: Vec<Box<dyn StreamingSinkState>>,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> StreamingSinkFinalizeResult {
+        Ok(None).into()
+    }
+
+    fn make_state(&self) -> Box<dyn StreamingSinkState> {
+        Box::new(OffsetSinkState::new(self.offset))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        1
+    }
+
+    fn dispatch_spawner(
+        &self,
+        _runtime_handle: &ExecutionRuntimeContext,
+        _maintain_order: bool,
+    ) -> Arc<dyn DispatchSpawner> {
+        // Offsets skip rows in the order they arrive, so like limits they run on a single worker
+        // without buffering any input.
+        Arc::new(UnorderedDispatcher::new(None))
+    }
+}
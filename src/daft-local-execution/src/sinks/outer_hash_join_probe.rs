@@ -16,9 +16,12 @@ use indexmap::IndexSet;
 use itertools::Itertools;
 use tracing::{info_span, instrument, Span};
 
-use super::streaming_sink::{
-    StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult, StreamingSinkOutput,
-    StreamingSinkState,
+use super::{
+    hash_join_build::HashJoinBuild,
+    streaming_sink::{
+        StreamingSink, StreamingSinkExecuteResult, StreamingSinkFinalizeResult,
+        StreamingSinkOutput, StreamingSinkState,
+    },
 };
 use crate::{
     dispatcher::{DispatchSpawner, RoundRobinDispatcher, UnorderedDispatcher},
@@ -82,36 +85,36 @@ impl IndexBitmap {
 }
 
 enum OuterHashJoinState {
-    Building(BroadcastStateBridgeRef<ProbeState>, bool),
+    Building(BroadcastStateBridgeRef<HashJoinBuild>, bool),
     Probing(Arc<ProbeState>, Option<IndexBitmapBuilder>),
 }
 
 impl OuterHashJoinState {
-    async fn get_or_build_probe_state(&mut self) -> Arc<ProbeState> {
+    async fn get_or_build_probe_state(&mut self) -> DaftResult<Arc<ProbeState>> {
         match self {
             Self::Building(bridge, needs_bitmap) => {
-                let probe_state = bridge.get_state().await;
+                let probe_state = bridge.get_state().await.probe_state()?;
                 let builder =
                     needs_bitmap.then(|| IndexBitmapBuilder::new(probe_state.get_tables()));
                 *self = Self::Probing(probe_state.clone(), builder);
-                probe_state
+                Ok(probe_state)
             }
-            Self::Probing(probe_state, _) => probe_state.clone(),
+            Self::Probing(probe_state, _) => Ok(probe_state.clone()),
         }
     }
 
-    async fn get_or_build_bitmap(&mut self) -> &mut Option<IndexBitmapBuilder> {
+    async fn get_or_build_bitmap(&mut self) -> DaftResult<&mut Option<IndexBitmapBuilder>> {
         match self {
             Self::Building(bridge, _) => {
-                let probe_state = bridge.get_state().await;
+                let probe_state = bridge.get_state().await.probe_state()?;
                 let builder = IndexBitmapBuilder::new(probe_state.get_tables());
                 *self = Self::Probing(probe_state, Some(builder));
                 match self {
-                    Self::Probing(_, builder) => builder,
+                    Self::Probing(_, builder) => Ok(builder),
                     _ => unreachable!(),
                 }
             }
-            Self::Probing(_, builder) => builder,
+            Self::Probing(_, builder) => Ok(builder),
         }
     }
 }
@@ -124,6 +127,7 @@ impl StreamingSinkState for OuterHashJoinState {
 
 struct OuterHashJoinParams {
     probe_on: Vec<ExprRef>,
+    residual_predicate: Option<ExprRef>,
     common_join_cols: Vec<String>,
     left_non_join_columns: Vec<String>,
     right_non_join_columns: Vec<String>,
@@ -138,20 +142,21 @@ pub(crate) struct OuterHashJoinProbeSink {
     params: Arc<OuterHashJoinParams>,
     needs_bitmap: bool,
     output_schema: SchemaRef,
-    probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+    probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
 }
 
 #[allow(clippy::too_many_arguments)]
 impl OuterHashJoinProbeSink {
     pub(crate) fn new(
         probe_on: Vec<ExprRef>,
+        residual_predicate: Option<ExprRef>,
         left_schema: &SchemaRef,
         right_schema: &SchemaRef,
         join_type: JoinType,
         build_on_left: bool,
         common_join_cols: IndexSet<String>,
         output_schema: &SchemaRef,
-        probe_state_bridge: BroadcastStateBridgeRef<ProbeState>,
+        probe_state_bridge: BroadcastStateBridgeRef<HashJoinBuild>,
     ) -> DaftResult<Self> {
         let needs_bitmap = join_type == JoinType::Outer
             || join_type == JoinType::Right && !build_on_left
@@ -193,6 +198,7 @@ impl OuterHashJoinProbeSink {
         Ok(Self {
             params: Arc::new(OuterHashJoinParams {
                 probe_on,
+                residual_predicate,
                 common_join_cols,
                 left_non_join_columns,
                 right_non_join_columns,
@@ -214,11 +220,12 @@ impl OuterHashJoinProbeSink {
         probe_state: &ProbeState,
         join_type: JoinType,
         probe_on: &[ExprRef],
+        residual_predicate: Option<&ExprRef>,
         common_join_cols: &[String],
         left_non_join_columns: &[String],
         right_non_join_columns: &[String],
+        build_on_left: bool,
     ) -> DaftResult<Arc<MicroPartition>> {
-        let probe_table = probe_state.get_probeable();
         let tables = probe_state.get_tables();
 
         let _growables = info_span!("OuterHashJoinProbeSink::build_growables").entered();
@@ -237,18 +244,18 @@ impl OuterHashJoinProbeSink {
             let _loop = info_span!("OuterHashJoinProbeSink::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
                 let join_keys = table.eval_expression_list(probe_on)?;
-                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+                let matches = probe_state.probe_matches(
+                    table,
+                    &join_keys,
+                    residual_predicate,
+                    build_on_left,
+                )?;
 
-                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                for (probe_row_idx, inner_iter) in matches.iter().enumerate() {
                     if let Some(inner_iter) = inner_iter {
-                        for (build_side_table_idx, build_row_idx) in inner_iter {
-                            bitmap_builder
-                                .mark_used(build_side_table_idx as usize, build_row_idx as usize);
-                            build_side_growable.extend(
-                                build_side_table_idx as usize,
-                                build_row_idx as usize,
-                                1,
-                            );
+                        for &(build_side_table_idx, build_row_idx) in inner_iter {
+                            bitmap_builder.mark_used(build_side_table_idx, build_row_idx);
+                            build_side_growable.extend(build_side_table_idx, build_row_idx, 1);
                             probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
                         }
                     }
@@ -281,11 +288,12 @@ impl OuterHashJoinProbeSink {
         probe_state: &ProbeState,
         join_type: JoinType,
         probe_on: &[ExprRef],
+        residual_predicate: Option<&ExprRef>,
         common_join_cols: &[String],
         left_non_join_columns: &[String],
         right_non_join_columns: &[String],
+        build_on_left: bool,
     ) -> DaftResult<Arc<MicroPartition>> {
-        let probe_table = probe_state.get_probeable();
         let tables = probe_state.get_tables();
 
         let _growables = info_span!("OuterHashJoinProbeSink::build_growables").entered();
@@ -304,16 +312,17 @@ impl OuterHashJoinProbeSink {
             let _loop = info_span!("OuterHashJoinProbeSink::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
                 let join_keys = table.eval_expression_list(probe_on)?;
-                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+                let matches = probe_state.probe_matches(
+                    table,
+                    &join_keys,
+                    residual_predicate,
+                    build_on_left,
+                )?;
 
-                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                for (probe_row_idx, inner_iter) in matches.iter().enumerate() {
                     if let Some(inner_iter) = inner_iter {
-                        for (build_side_table_idx, build_row_idx) in inner_iter {
-                            build_side_growable.extend(
-                                build_side_table_idx as usize,
-                                build_row_idx as usize,
-                                1,
-                            );
+                        for &(build_side_table_idx, build_row_idx) in inner_iter {
+                            build_side_growable.extend(build_side_table_idx, build_row_idx, 1);
                             probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
                         }
                     } else {
@@ -351,13 +360,13 @@ impl OuterHashJoinProbeSink {
         probe_state: &ProbeState,
         bitmap_builder: &mut IndexBitmapBuilder,
         probe_on: &[ExprRef],
+        residual_predicate: Option<&ExprRef>,
         common_join_cols: &[String],
         outer_common_col_schema: &SchemaRef,
         left_non_join_columns: &[String],
         right_non_join_columns: &[String],
         build_on_left: bool,
     ) -> DaftResult<Arc<MicroPartition>> {
-        let probe_table = probe_state.get_probeable();
         let tables = probe_state.get_tables();
 
         let _growables = info_span!("OuterHashJoinProbeSink::build_growables").entered();
@@ -377,13 +386,16 @@ impl OuterHashJoinProbeSink {
             let _loop = info_span!("OuterHashJoinProbeSink::eval_and_probe").entered();
             for (probe_side_table_idx, table) in input_tables.iter().enumerate() {
                 let join_keys = table.eval_expression_list(probe_on)?;
-                let idx_mapper = probe_table.probe_indices(&join_keys)?;
+                let matches = probe_state.probe_matches(
+                    table,
+                    &join_keys,
+                    residual_predicate,
+                    build_on_left,
+                )?;
 
-                for (probe_row_idx, inner_iter) in idx_mapper.make_iter().enumerate() {
+                for (probe_row_idx, inner_iter) in matches.iter().enumerate() {
                     if let Some(inner_iter) = inner_iter {
-                        for (build_side_table_idx, build_row_idx) in inner_iter {
-                            let build_side_table_idx = build_side_table_idx as usize;
-                            let build_row_idx = build_row_idx as usize;
+                        for &(build_side_table_idx, build_row_idx) in inner_iter {
                             bitmap_builder.mark_used(build_side_table_idx, build_row_idx);
                             build_side_growable.extend(build_side_table_idx, build_row_idx, 1);
                             probe_side_growable.extend(probe_side_table_idx, probe_row_idx, 1);
@@ -430,32 +442,34 @@ impl OuterHashJoinProbeSink {
             .expect("OuterHashJoinProbeSink state should be OuterHashJoinProbeState");
         let tables = first_state
             .get_or_build_probe_state()
-            .await
+            .await?
             .get_tables()
             .clone();
         let first_bitmap = first_state
             .get_or_build_bitmap()
-            .await
+            .await?
             .take()
             .expect("bitmap should be set")
             .build();
 
         let merged_bitmap = {
-            let bitmaps = stream::once(async move { first_bitmap })
+            let bitmaps = stream::once(async move { Ok(first_bitmap) })
                 .chain(stream::iter(states_iter).then(|s| async move {
                     let state = s
                         .as_any_mut()
                         .downcast_mut::<OuterHashJoinState>()
                         .expect("OuterHashJoinProbeSink state should be OuterHashJoinProbeState");
-                    state
+                    Ok(state
                         .get_or_build_bitmap()
-                        .await
+                        .await?
                         .take()
                         .expect("bitmap should be set")
-                        .build()
+                        .build())
                 }))
-                .collect::<Vec<_>>()
-                .await;
+                .collect::<Vec<DaftResult<_>>>()
+                .await
+                .into_iter()
+                .collect::<DaftResult<Vec<_>>>()?;
 
             bitmaps.into_iter().fold(None, |acc, x| match acc {
                 None => Some(x),
@@ -585,22 +599,24 @@ impl StreamingSink for OuterHashJoinProbeSink {
                         .as_any_mut()
                         .downcast_mut::<OuterHashJoinState>()
                         .expect("OuterHashJoinProbeSink should have OuterHashJoinProbeState");
-                    let probe_state = outer_join_state.get_or_build_probe_state().await;
+                    let probe_state = outer_join_state.get_or_build_probe_state().await?;
                     let out = match params.join_type {
                         JoinType::Left | JoinType::Right if needs_bitmap => {
                             Self::probe_left_right_with_bitmap(
                                 &input,
                                 outer_join_state
                                     .get_or_build_bitmap()
-                                    .await
+                                    .await?
                                     .as_mut()
                                     .expect("bitmap should be set"),
                                 &probe_state,
                                 params.join_type,
                                 &params.probe_on,
+                                params.residual_predicate.as_ref(),
                                 &params.common_join_cols,
                                 &params.left_non_join_columns,
                                 &params.right_non_join_columns,
+                                params.build_on_left,
                             )
                         }
                         JoinType::Left | JoinType::Right => Self::probe_left_right(
@@ -608,14 +624,16 @@ impl StreamingSink for OuterHashJoinProbeSink {
                             &probe_state,
                             params.join_type,
                             &params.probe_on,
+                            params.residual_predicate.as_ref(),
                             &params.common_join_cols,
                             &params.left_non_join_columns,
                             &params.right_non_join_columns,
+                            params.build_on_left,
                         ),
                         JoinType::Outer => {
                             let bitmap_builder = outer_join_state
                                 .get_or_build_bitmap()
-                                .await
+                                .await?
                                 .as_mut()
                                 .expect("bitmap should be set");
                             Self::probe_outer(
@@ -623,6 +641,7 @@ impl StreamingSink for OuterHashJoinProbeSink {
                                 &probe_state,
                                 bitmap_builder,
                                 &params.probe_on,
+                                params.residual_predicate.as_ref(),
                                 &params.common_join_cols,
                                 &params.outer_common_col_schema,
                                 &params.left_non_join_columns,
@@ -663,6 +682,9 @@ impl StreamingSink for OuterHashJoinProbeSink {
                 .map(|e| e.to_string())
                 .join(", ")
         ));
+        if let Some(residual_predicate) = &self.params.residual_predicate {
+            res.push(format!("Residual predicate: {residual_predicate}"));
+        }
         res.push(format!("Build on left: {}", self.params.build_on_left));
         res
     }
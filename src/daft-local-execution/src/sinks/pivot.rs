@@ -125,7 +125,7 @@ impl BlockingSink for PivotSink {
                         pivot_params.value_column.clone(),
                         pivot_params.names.clone(),
                     )?);
-                    Ok(Some(pivoted))
+                    Ok(vec![pivoted].into())
                 },
                 Span::current(),
             )
@@ -0,0 +1,434 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{resolved_col, ExprRef};
+use daft_local_plan::RepartitionSpec;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use tracing::{instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{
+    dispatcher::{DispatchSpawner, UnorderedDispatcher},
+    ExecutionRuntimeContext, ExecutionTaskSpawner, NUM_CPUS,
+};
+
+enum RepartitionState {
+    Accumulating(Vec<Arc<MicroPartition>>),
+    Done,
+}
+
+impl RepartitionState {
+    fn push(&mut self, part: Arc<MicroPartition>) {
+        if let Self::Accumulating(parts) = self {
+            parts.push(part);
+        } else {
+            panic!("RepartitionSink should be in Accumulating state");
+        }
+    }
+
+    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
+        let res = if let Self::Accumulating(parts) = self {
+            std::mem::take(parts)
+        } else {
+            panic!("RepartitionSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for RepartitionState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct RepartitionParams {
+    repartition_spec: RepartitionSpec,
+    num_partitions: Option<usize>,
+    schema: SchemaRef,
+    sample_size: usize,
+}
+
+impl RepartitionParams {
+    /// Computes the boundaries of `num_partitions` ranges of `by` from a sample of the input.
+    fn range_boundaries(
+        &self,
+        inputs: &[Arc<MicroPartition>],
+        by: &[ExprRef],
+        descending: &[bool],
+        num_partitions: usize,
+    ) -> DaftResult<RecordBatch> {
+        let samples = inputs
+            .iter()
+            .map(|input| {
+                input
+                    .sample_by_size(self.sample_size, false, None)?
+                    .eval_expression_list(by)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let sample_keys = by
+            .iter()
+            .map(|e| resolved_col(e.name()))
+            .collect::<Vec<_>>();
+        // Range partitioning places nulls first when descending and last otherwise.
+        let boundaries = MicroPartition::concat(&samples)?
+            .sort(&sample_keys, descending, descending)?
+            .quantiles(num_partitions)?;
+        match boundaries.get_tables()?.as_slice() {
+            [] => RecordBatch::empty(Some(boundaries.schema())),
+            tables => RecordBatch::concat(tables),
+        }
+    }
+
+    fn repartition(
+        &self,
+        inputs: Vec<Arc<MicroPartition>>,
+    ) -> DaftResult<Vec<Arc<MicroPartition>>> {
+        let num_partitions = self.num_partitions.unwrap_or(inputs.len()).max(1);
+        if inputs.is_empty() {
+            return Ok((0..num_partitions)
+                .map(|_| Arc::new(MicroPartition::empty(Some(self.schema.clone()))))
+                .collect());
+        }
+
+        let partitioned = match &self.repartition_spec {
+            RepartitionSpec::Hash { by } => inputs
+                .iter()
+                .map(|input| input.partition_by_hash(by, num_partitions))
+                .collect::<DaftResult<Vec<_>>>()?,
+            RepartitionSpec::Random => inputs
+                .iter()
+                .enumerate()
+                .map(|(i, input)| input.partition_by_random(num_partitions, i as u64))
+                .collect::<DaftResult<Vec<_>>>()?,
+            RepartitionSpec::Range { by, descending } => {
+                let boundaries = self.range_boundaries(&inputs, by, descending, num_partitions)?;
+                inputs
+                    .iter()
+                    .map(|input| input.partition_by_range(by, &boundaries, descending))
+                    .collect::<DaftResult<Vec<_>>>()?
+            }
+            RepartitionSpec::IntoPartitions => {
+                let concated = MicroPartition::concat(&inputs)?;
+                let len = concated.len();
+                let split = (0..num_partitions)
+                    .map(|i| {
+                        concated.slice(i * len / num_partitions, (i + 1) * len / num_partitions)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                vec![split]
+            }
+        };
+
+        // Gather the pieces of each output partition. Range partitioning yields fewer partitions
+        // when the sample has fewer distinct boundaries, so the remaining partitions are empty.
+        (0..num_partitions)
+            .map(|i| {
+                let pieces = partitioned
+                    .iter()
+                    .filter_map(|parts| parts.get(i))
+                    .collect::<Vec<_>>();
+                if pieces.is_empty() {
+                    Ok(Arc::new(MicroPartition::empty(Some(self.schema.clone()))))
+                } else {
+                    Ok(Arc::new(MicroPartition::concat(pieces)?))
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct RepartitionSink {
+    params: Arc<RepartitionParams>,
+}
+
+impl RepartitionSink {
+    pub fn new(
+        repartition_spec: RepartitionSpec,
+        num_partitions: Option<usize>,
+        schema: SchemaRef,
+        sample_size: usize,
+    ) -> Self {
+        Self {
+            params: Arc::new(RepartitionParams {
+                repartition_spec,
+                num_partitions,
+                schema,
+                sample_size,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for RepartitionSink {
+    #[instrument(skip_all, name = "RepartitionSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        state
+            .as_any_mut()
+            .downcast_mut::<RepartitionState>()
+            .expect("RepartitionSink should have RepartitionState")
+            .push(input);
+        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+    }
+
+    #[instrument(skip_all, name = "RepartitionSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let inputs = states
+                        .into_iter()
+                        .flat_map(|mut state| {
+                            state
+                                .as_any_mut()
+                                .downcast_mut::<RepartitionState>()
+                                .expect("RepartitionSink should have RepartitionState")
+                                .finalize()
+                        })
+                        .collect();
+                    Ok(params.repartition(inputs)?.into())
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Repartition"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let spec = match &self.params.repartition_spec {
+            RepartitionSpec::Hash { by } => format!(
+                "Hash: {}",
+                by.iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            RepartitionSpec::Random => "Random".to_string(),
+            RepartitionSpec::Range { by, descending } => format!(
+                "Range: {}",
+                by.iter()
+                    .zip(descending.iter())
+                    .map(|(e, d)| format!("({e}, {})", if *d { "descending" } else { "ascending" }))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            RepartitionSpec::IntoPartitions => "Into partitions".to_string(),
+        };
+        let mut lines = vec![format!("Repartition: {spec}")];
+        if let Some(num_partitions) = self.params.num_partitions {
+            lines.push(format!("Num partitions = {num_partitions}"));
+        }
+        lines
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(RepartitionState::Accumulating(vec![])))
+    }
+
+    fn dispatch_spawner(
+        &self,
+        _runtime_handle: &ExecutionRuntimeContext,
+    ) -> Arc<dyn DispatchSpawner> {
+        // Inputs are kept whole so that their count can stand in for the number of input partitions.
+        Arc::new(UnorderedDispatcher::new(None))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_local_plan::RepartitionSpec;
+    use daft_logical_plan::LogicalPlanBuilder;
+    use daft_micropartition::{
+        partitioning::{
+            InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSetCache,
+        },
+        MicroPartition,
+    };
+    use daft_recordbatch::RecordBatch;
+
+    use super::RepartitionParams;
+    use crate::NativeExecutor;
+
+    fn make_part(values: impl Iterator<Item = i64>) -> DaftResult<Arc<MicroPartition>> {
+        let a = Int64Array::from(("a", values.collect::<Vec<_>>())).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a])?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    fn values(part: &MicroPartition) -> DaftResult<Vec<i64>> {
+        let tables = part.get_tables()?;
+        if tables.is_empty() {
+            return Ok(vec![]);
+        }
+        let table = RecordBatch::concat(tables.as_slice())?;
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
+
+    fn repartition(
+        repartition_spec: RepartitionSpec,
+        num_partitions: Option<usize>,
+    ) -> DaftResult<Vec<Vec<i64>>> {
+        let inputs = (0..4)
+            .map(|i| make_part((0..25).map(move |j| i * 25 + j)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let params = RepartitionParams {
+            repartition_spec,
+            num_partitions,
+            schema: inputs[0].schema(),
+            sample_size: 20,
+        };
+        params
+            .repartition(inputs)?
+            .iter()
+            .map(|part| values(part))
+            .collect()
+    }
+
+    fn assert_all_rows(partitions: &[Vec<i64>]) {
+        let mut all = partitions.concat();
+        all.sort_unstable();
+        assert_eq!(all, (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_hash_repartition() -> DaftResult<()> {
+        let partitions = repartition(
+            RepartitionSpec::Hash {
+                by: vec![resolved_col("a")],
+            },
+            Some(3),
+        )?;
+        assert_eq!(partitions.len(), 3);
+        assert_all_rows(&partitions);
+        // Repartitioning is deterministic, so every row lands in the same partition again.
+        assert_eq!(
+            partitions,
+            repartition(
+                RepartitionSpec::Hash {
+                    by: vec![resolved_col("a")],
+                },
+                Some(3),
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_repartition_defaults_to_input_partitions() -> DaftResult<()> {
+        let partitions = repartition(RepartitionSpec::Random, None)?;
+        assert_eq!(partitions.len(), 4);
+        assert_all_rows(&partitions);
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_repartition() -> DaftResult<()> {
+        let partitions = repartition(
+            RepartitionSpec::Range {
+                by: vec![resolved_col("a")],
+                descending: vec![true],
+            },
+            Some(3),
+        )?;
+        assert_eq!(partitions.len(), 3);
+        assert_all_rows(&partitions);
+        // Each partition only holds values smaller than those of the partitions before it.
+        for pair in partitions.windows(2) {
+            if let (Some(min), Some(max)) = (pair[0].iter().min(), pair[1].iter().max()) {
+                assert!(min > max);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_partitions() -> DaftResult<()> {
+        let partitions = repartition(RepartitionSpec::IntoPartitions, Some(3))?;
+        assert_eq!(
+            partitions.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![33, 33, 34]
+        );
+        assert_all_rows(&partitions);
+        Ok(())
+    }
+
+    /// Tests that a logical range repartition is translated and run by the native executor.
+    #[test]
+    fn test_range_repartition_plan() -> DaftResult<()> {
+        let inputs = (0..4)
+            .map(|i| make_part((0..25).map(move |j| (i * 25 + j) * 37 % 100)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let schema = inputs[0].schema();
+        let pset = Arc::new(MicroPartitionSet::from(inputs));
+        let psets = InMemoryPartitionSetCache::empty();
+        psets.put_partition_set("range_repartition", &pset);
+
+        let builder = LogicalPlanBuilder::in_memory_scan(
+            "range_repartition",
+            PartitionCacheEntry::new_rust("range_repartition".to_string(), pset),
+            schema,
+            4,
+            0,
+            100,
+        )?
+        .range_repartition(Some(3), vec![resolved_col("a")], vec![false])?;
+        let partitions = NativeExecutor::new()
+            .run(
+                &builder,
+                &psets,
+                Arc::new(DaftExecutionConfig::default()),
+                None,
+            )?
+            .into_iter()
+            .map(|part| values(part?.as_ref()))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        assert_eq!(partitions.len(), 3);
+        assert_all_rows(&partitions);
+        for pair in partitions.windows(2) {
+            if let (Some(max), Some(min)) = (pair[0].iter().max(), pair[1].iter().min()) {
+                assert!(max < min);
+            }
+        }
+        Ok(())
+    }
+}
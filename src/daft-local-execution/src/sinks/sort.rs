@@ -1,34 +1,59 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 use common_error::DaftResult;
-use daft_dsl::ExprRef;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{resolved_col, ExprRef};
 use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
 use itertools::Itertools;
 use tracing::{instrument, Span};
 
 use super::blocking_sink::{
-    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
-    BlockingSinkStatus,
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::{
+    resource_manager::MemoryManager,
+    spill::{SpillFile, SpillWriter, SpillableBuffer},
+    ExecutionTaskSpawner, NUM_CPUS,
 };
-use crate::{ExecutionTaskSpawner, NUM_CPUS};
 
 enum SortState {
-    Building(Vec<Arc<MicroPartition>>),
+    Building {
+        buffer: SpillableBuffer,
+        // Samples of the spill keys of each spilled run.
+        samples: Vec<MicroPartition>,
+    },
     Done,
 }
 
 impl SortState {
-    fn push(&mut self, part: Arc<MicroPartition>) {
-        if let Self::Building(ref mut parts) = self {
-            parts.push(part);
+    fn push(
+        &mut self,
+        part: Arc<MicroPartition>,
+        params: &SortParams,
+        memory_manager: &Arc<MemoryManager>,
+    ) -> DaftResult<()> {
+        if let Self::Building { buffer, samples } = self {
+            if let Some(run) = buffer.push(part, memory_manager)? {
+                samples.push(params.sample_spill_keys(&run, samples.len() as u64)?);
+            }
+            Ok(())
         } else {
             panic!("SortSink should be in Building state");
         }
     }
 
-    fn finalize(&mut self) -> Vec<Arc<MicroPartition>> {
-        let res = if let Self::Building(ref mut parts) = self {
-            std::mem::take(parts)
+    fn finalize(
+        &mut self,
+    ) -> (
+        Vec<Arc<MicroPartition>>,
+        Vec<SpillFile>,
+        Vec<MicroPartition>,
+    ) {
+        let res = if let Self::Building { buffer, samples } = self {
+            let (parts, spilled) = std::mem::take(buffer).finish();
+            (parts, spilled, std::mem::take(samples))
         } else {
             panic!("SortSink should be in Building state");
         };
@@ -47,18 +72,220 @@ struct SortParams {
     sort_by: Vec<ExprRef>,
     descending: Vec<bool>,
     nulls_first: Vec<bool>,
+    // Spilled runs are range partitioned by these keys, which order rows the same way as the sort,
+    // with an explicit null indicator ahead of each sort key so that nulls land in the right range.
+    spill_keys: Vec<ExprRef>,
+    spill_descending: Vec<bool>,
+    sample_size: usize,
+}
+
+impl SortParams {
+    /// Samples the spill keys of a run. Seeding the sample keeps sorts reproducible, and the seed
+    /// differs between runs so that the same positions are not sampled from each of them.
+    fn sample_spill_keys(&self, run: &MicroPartition, seed: u64) -> DaftResult<MicroPartition> {
+        run.sample_by_size(self.sample_size, false, Some(seed))?
+            .eval_expression_list(&self.spill_keys)
+    }
+
+    /// Sorts data that was partially spilled by range partitioning all of it into buckets
+    /// based on the sampled spill keys, then sorting the buckets one at a time as they are consumed.
+    fn sort_spilled(
+        self: &Arc<Self>,
+        parts: Vec<Arc<MicroPartition>>,
+        spilled: Vec<SpillFile>,
+        mut samples: Vec<MicroPartition>,
+    ) -> DaftResult<SortedBuckets> {
+        let schema = spilled[0].schema();
+        // Each spilled run is what fit in memory at once, so buckets are sorted in chunks of up to that size.
+        let max_bucket_bytes = spilled.iter().map(SpillFile::size_bytes).max().unwrap_or(0);
+        let num_buckets = spilled.len() + 1;
+        let in_memory = if parts.is_empty() {
+            None
+        } else {
+            let in_memory = MicroPartition::concat(&parts)?;
+            samples.push(self.sample_spill_keys(&in_memory, samples.len() as u64)?);
+            Some(in_memory)
+        };
+        drop(parts);
+
+        let inputs = in_memory
+            .into_iter()
+            .map(Ok)
+            .chain(spilled.into_iter().map(|mut spill_file| spill_file.read()));
+        let buckets = self.partition_into_buckets(schema, inputs, &samples, num_buckets)?;
+        Ok(SortedBuckets {
+            params: self.clone(),
+            buckets: buckets.into(),
+            max_bucket_bytes,
+        })
+    }
+
+    /// Range partitions `inputs` into `num_buckets` spilled buckets, using boundaries taken
+    /// from the sampled spill keys.
+    fn partition_into_buckets(
+        &self,
+        schema: SchemaRef,
+        inputs: impl Iterator<Item = DaftResult<MicroPartition>>,
+        samples: &[MicroPartition],
+        num_buckets: usize,
+    ) -> DaftResult<Vec<SpillFile>> {
+        let sample_keys = self
+            .spill_keys
+            .iter()
+            .map(|e| resolved_col(e.name()))
+            .collect::<Vec<_>>();
+        let boundaries = MicroPartition::concat(samples)?
+            .sort(
+                &sample_keys,
+                &self.spill_descending,
+                &vec![false; sample_keys.len()],
+            )?
+            .quantiles(num_buckets)?;
+        let boundaries = match boundaries.get_tables()?.as_slice() {
+            [] => RecordBatch::empty(Some(boundaries.schema()))?,
+            tables => RecordBatch::concat(tables)?,
+        };
+
+        let mut buckets = (0..=boundaries.len())
+            .map(|_| SpillWriter::try_new(schema.clone()))
+            .collect::<DaftResult<Vec<_>>>()?;
+        for part in inputs {
+            let partitioned =
+                part?.partition_by_range(&self.spill_keys, &boundaries, &self.spill_descending)?;
+            for (p, bucket) in partitioned.iter().zip(buckets.iter_mut()) {
+                bucket.write(p)?;
+            }
+        }
+        buckets.into_iter().map(SpillWriter::finish).collect()
+    }
+
+    /// Range partitions a bucket that is too large to sort in memory into smaller buckets.
+    /// Returns `None` if it cannot be split, i.e. if all of its rows have the same sort keys.
+    fn split_bucket(
+        &self,
+        bucket: &mut SpillFile,
+        max_bucket_bytes: usize,
+    ) -> DaftResult<Option<Vec<SpillFile>>> {
+        let schema = bucket.schema();
+        let samples = bucket
+            .read_tables()?
+            .enumerate()
+            .map(|(i, table)| {
+                let table = table?;
+                self.sample_spill_keys(
+                    &MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None),
+                    i as u64,
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let num_buckets = bucket.size_bytes().div_ceil(max_bucket_bytes.max(1)).max(2);
+        let num_rows = bucket.num_rows();
+        let tables = bucket.read_tables()?.map(|table| {
+            let table = table?;
+            Ok(MicroPartition::new_loaded(
+                table.schema.clone(),
+                Arc::new(vec![table]),
+                None,
+            ))
+        });
+        let split = self.partition_into_buckets(schema, tables, &samples, num_buckets)?;
+        if split.iter().any(|b| b.num_rows() == num_rows) {
+            return Ok(None);
+        }
+        Ok(Some(split))
+    }
+}
+
+/// The sorted output of spilled data, produced one range partitioned bucket at a time.
+/// Buckets larger than what fits in memory are split further, and small neighbouring
+/// buckets are sorted together, so that each output is up to `max_bucket_bytes` in size.
+struct SortedBuckets {
+    params: Arc<SortParams>,
+    buckets: VecDeque<SpillFile>,
+    max_bucket_bytes: usize,
+}
+
+impl SortedBuckets {
+    fn next_sorted(&mut self) -> DaftResult<Option<MicroPartition>> {
+        let Some(mut bucket) = self.buckets.pop_front() else {
+            return Ok(None);
+        };
+        let mut to_sort = vec![];
+        if bucket.size_bytes() > self.max_bucket_bytes {
+            match self
+                .params
+                .split_bucket(&mut bucket, self.max_bucket_bytes)?
+            {
+                Some(split) => {
+                    for b in split.into_iter().rev() {
+                        self.buckets.push_front(b);
+                    }
+                    return self.next_sorted();
+                }
+                None => to_sort.push(bucket),
+            }
+        } else {
+            let mut size_bytes = bucket.size_bytes();
+            to_sort.push(bucket);
+            while let Some(next) = self.buckets.front() {
+                if size_bytes + next.size_bytes() > self.max_bucket_bytes {
+                    break;
+                }
+                size_bytes += next.size_bytes();
+                to_sort.extend(self.buckets.pop_front());
+            }
+        }
+        let parts = to_sort
+            .iter_mut()
+            .map(SpillFile::read)
+            .collect::<DaftResult<Vec<_>>>()?;
+        let sorted = MicroPartition::concat(&parts)?.sort(
+            &self.params.sort_by,
+            &self.params.descending,
+            &self.params.nulls_first,
+        )?;
+        Ok(Some(sorted))
+    }
+}
+
+impl Iterator for SortedBuckets {
+    type Item = DaftResult<Arc<MicroPartition>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sorted().map(|p| p.map(Arc::new)).transpose()
+    }
 }
+
 pub struct SortSink {
     params: Arc<SortParams>,
 }
 
 impl SortSink {
-    pub fn new(sort_by: Vec<ExprRef>, descending: Vec<bool>, nulls_first: Vec<bool>) -> Self {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        sample_size: usize,
+    ) -> Self {
+        let (spill_keys, spill_descending) = sort_by
+            .iter()
+            .zip(descending.iter().zip(nulls_first.iter()))
+            .enumerate()
+            .flat_map(|(i, (e, (desc, nf)))| {
+                [
+                    (e.clone().is_null().alias(format!("__is_null_{i}")), *nf),
+                    (e.clone().alias(format!("__key_{i}")), *desc),
+                ]
+            })
+            .unzip();
         Self {
             params: Arc::new(SortParams {
                 sort_by,
                 descending,
                 nulls_first,
+                spill_keys,
+                spill_descending,
+                sample_size,
             }),
         }
     }
@@ -70,14 +297,23 @@ impl BlockingSink for SortSink {
         &self,
         input: Arc<MicroPartition>,
         mut state: Box<dyn BlockingSinkState>,
-        _spawner: &ExecutionTaskSpawner,
+        spawner: &ExecutionTaskSpawner,
     ) -> BlockingSinkSinkResult {
-        state
-            .as_any_mut()
-            .downcast_mut::<SortState>()
-            .expect("SortSink should have sort state")
-            .push(input);
-        Ok(BlockingSinkStatus::NeedMoreInput(state)).into()
+        let params = self.params.clone();
+        let memory_manager = spawner.memory_manager();
+        spawner
+            .spawn(
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<SortState>()
+                        .expect("SortSink should have sort state")
+                        .push(input, &params, &memory_manager)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
     }
 
     #[instrument(skip_all, name = "SortSink::finalize")]
@@ -90,20 +326,31 @@ impl BlockingSink for SortSink {
         spawner
             .spawn(
                 async move {
-                    let parts = states.into_iter().flat_map(|mut state| {
-                        let state = state
+                    let mut parts = vec![];
+                    let mut spilled = vec![];
+                    let mut samples = vec![];
+                    for mut state in states {
+                        let (state_parts, state_spilled, state_samples) = state
                             .as_any_mut()
                             .downcast_mut::<SortState>()
-                            .expect("State type mismatch");
-                        state.finalize()
-                    });
+                            .expect("State type mismatch")
+                            .finalize();
+                        parts.extend(state_parts);
+                        spilled.extend(state_spilled);
+                        samples.extend(state_samples);
+                    }
+                    if !spilled.is_empty() {
+                        return Ok(BlockingSinkFinalizeOutput::Streaming(Box::new(
+                            params.sort_spilled(parts, spilled, samples)?,
+                        )));
+                    }
                     let concated = MicroPartition::concat(parts)?;
                     let sorted = Arc::new(concated.sort(
                         &params.sort_by,
                         &params.descending,
                         &params.nulls_first,
                     )?);
-                    Ok(Some(sorted))
+                    Ok(vec![sorted].into())
                 },
                 Span::current(),
             )
@@ -137,10 +384,143 @@ impl BlockingSink for SortSink {
     }
 
     fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
-        Ok(Box::new(SortState::Building(Vec::new())))
+        Ok(Box::new(SortState::Building {
+            buffer: SpillableBuffer::default(),
+            samples: Vec::new(),
+        }))
     }
 
     fn max_concurrency(&self) -> usize {
         *NUM_CPUS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+    use itertools::Itertools;
+
+    use super::{SortSink, SortState};
+    use crate::{resource_manager::MemoryManager, spill::SpillableBuffer};
+
+    fn make_part(values: Vec<Option<i64>>) -> DaftResult<Arc<MicroPartition>> {
+        let a = Int64Array::from_iter(Field::new("a", DataType::Int64), values.into_iter())
+            .into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a])?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    fn values(part: &MicroPartition) -> DaftResult<Vec<Option<i64>>> {
+        let tables = part.get_tables()?;
+        let table = RecordBatch::concat(tables.as_slice())?;
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    /// Tests that sorting data spilled to disk matches sorting it in memory, nulls included.
+    #[test]
+    fn spilled_sort_matches_in_memory_sort() -> DaftResult<()> {
+        let parts = (0..10)
+            .map(|i| {
+                make_part(
+                    (0..10)
+                        .map(|j| (j != i).then_some((i * 37 + j * 11) % 23))
+                        .collect(),
+                )
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let sink = SortSink::new(
+                vec![resolved_col("a")],
+                vec![descending],
+                vec![nulls_first],
+                5,
+            );
+            let params = sink.params;
+            // Too little memory to buffer anything, so every part is spilled.
+            let memory_manager = Arc::new(MemoryManager::with_limit(1));
+            let mut state = SortState::Building {
+                buffer: SpillableBuffer::default(),
+                samples: vec![],
+            };
+            for part in &parts {
+                state.push(part.clone(), &params, &memory_manager)?;
+            }
+            let (in_memory, spilled, samples) = state.finalize();
+            assert_eq!(spilled.len(), parts.len());
+            let sorted = MicroPartition::concat(
+                params
+                    .sort_spilled(in_memory, spilled, samples)?
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )?;
+
+            let mut expected = parts
+                .iter()
+                .map(|part| values(part))
+                .flatten_ok()
+                .collect::<DaftResult<Vec<_>>>()?;
+            expected.sort_by(|a, b| match (a, b) {
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                (Some(a), Some(b)) => a.cmp(b),
+                _ => (a.is_none() == nulls_first)
+                    .cmp(&(b.is_none() == nulls_first))
+                    .reverse(),
+            });
+            assert_eq!(values(&sorted)?, expected);
+        }
+        Ok(())
+    }
+
+    /// Tests that buckets too large to sort in memory, here due to a poor sample of the sort keys,
+    /// are split further, and that the sorted output is streamed in chunks no larger than a run.
+    #[test]
+    fn spilled_sort_splits_oversized_buckets() -> DaftResult<()> {
+        let parts = (0..20)
+            .map(|i| make_part((0..10).map(|j| Some((j * 20 + i) * 7 % 200)).collect()))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        // Sampling a single key per run makes for unevenly sized buckets.
+        let sink = SortSink::new(vec![resolved_col("a")], vec![false], vec![false], 1);
+        let params = sink.params;
+        let memory_manager = Arc::new(MemoryManager::with_limit(1));
+        let mut state = SortState::Building {
+            buffer: SpillableBuffer::default(),
+            samples: vec![],
+        };
+        for part in &parts {
+            state.push(part.clone(), &params, &memory_manager)?;
+        }
+        let (in_memory, spilled, samples) = state.finalize();
+        let outputs = params
+            .sort_spilled(in_memory, spilled, samples)?
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        assert!(outputs.len() >= parts.len());
+        assert!(outputs.iter().all(|part| part.len() <= 10));
+        let sorted = outputs
+            .iter()
+            .map(|part| values(part))
+            .flatten_ok()
+            .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(sorted, (0..200).map(Some).collect::<Vec<_>>());
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::ExprRef;
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeResult, BlockingSinkSinkResult, BlockingSinkState,
+    BlockingSinkStatus,
+};
+use crate::{ExecutionTaskSpawner, NUM_CPUS};
+
+struct TopNState {
+    // Partitions holding the candidates for the first `limit` rows in sort order out of everything
+    // seen by this state so far.
+    candidates: Vec<Arc<MicroPartition>>,
+    num_candidate_rows: usize,
+}
+
+impl TopNState {
+    fn new() -> Self {
+        Self {
+            candidates: vec![],
+            num_candidate_rows: 0,
+        }
+    }
+
+    fn push(&mut self, part: Arc<MicroPartition>, params: &TopNParams) -> DaftResult<()> {
+        // Only the first `limit` rows of a morsel can make it into the result, so larger morsels
+        // are cut down on their own before being merged with the other candidates.
+        let survivors = if part.len() > params.limit {
+            Arc::new(params.top_n(&part)?)
+        } else {
+            part
+        };
+        if survivors.is_empty() {
+            return Ok(());
+        }
+        self.num_candidate_rows += survivors.len();
+        self.candidates.push(survivors);
+        // Merging once the candidates have doubled keeps the work per row logarithmic in `limit`
+        // while buffering at most three times `limit` rows.
+        if self.num_candidate_rows >= 2 * params.limit {
+            let top = params.top_n(&MicroPartition::concat(&self.candidates)?)?;
+            self.num_candidate_rows = top.len();
+            self.candidates = vec![Arc::new(top)];
+        }
+        Ok(())
+    }
+}
+
+impl BlockingSinkState for TopNState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct TopNParams {
+    sort_by: Vec<ExprRef>,
+    descending: Vec<bool>,
+    nulls_first: Vec<bool>,
+    limit: usize,
+    schema: SchemaRef,
+}
+
+impl TopNParams {
+    fn top_n(&self, part: &MicroPartition) -> DaftResult<MicroPartition> {
+        part.sort(&self.sort_by, &self.descending, &self.nulls_first)?
+            .head(self.limit)
+    }
+}
+
+/// Sort followed by a limit, which only ever buffers `limit` rows per worker rather than
+/// the whole input.
+pub struct TopNSink {
+    params: Arc<TopNParams>,
+}
+
+impl TopNSink {
+    pub fn new(
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: usize,
+        schema: SchemaRef,
+    ) -> Self {
+        Self {
+            params: Arc::new(TopNParams {
+                sort_by,
+                descending,
+                nulls_first,
+                limit,
+                schema,
+            }),
+        }
+    }
+}
+
+impl BlockingSink for TopNSink {
+    #[instrument(skip_all, name = "TopNSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<TopNState>()
+                        .expect("TopNSink should have TopNState")
+                        .push(input, &params)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "TopNSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.params.clone();
+        spawner
+            .spawn(
+                async move {
+                    let candidates = states
+                        .into_iter()
+                        .flat_map(|mut state| {
+                            std::mem::take(
+                                &mut state
+                                    .as_any_mut()
+                                    .downcast_mut::<TopNState>()
+                                    .expect("TopNSink should have TopNState")
+                                    .candidates,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let result = if candidates.is_empty() {
+                        MicroPartition::empty(Some(params.schema.clone()))
+                    } else {
+                        params.top_n(&MicroPartition::concat(&candidates)?)?
+                    };
+                    Ok(vec![Arc::new(result)].into())
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    fn name(&self) -> &'static str {
+        "TopN"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let pairs = self
+            .params
+            .sort_by
+            .iter()
+            .zip(self.params.descending.iter())
+            .zip(self.params.nulls_first.iter())
+            .map(|((sb, d), nf)| {
+                format!(
+                    "({}, {}, {})",
+                    sb,
+                    if *d { "descending" } else { "ascending" },
+                    if *nf { "nulls first" } else { "nulls last" }
+                )
+            })
+            .join(", ");
+        vec![format!(
+            "TopN: Sort by = {}, Limit = {}",
+            pairs, self.params.limit
+        )]
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(TopNState::new()))
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{TopNSink, TopNState};
+
+    fn make_part(values: Vec<Option<i64>>) -> DaftResult<MicroPartition> {
+        let a = Int64Array::from_iter(Field::new("a", DataType::Int64), values.into_iter())
+            .into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a])?;
+        Ok(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        ))
+    }
+
+    fn values(part: &MicroPartition) -> DaftResult<Vec<Option<i64>>> {
+        let tables = part.get_tables()?;
+        if tables.is_empty() {
+            return Ok(vec![]);
+        }
+        let table = RecordBatch::concat(tables.as_slice())?;
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    /// Tests that merging the per-morsel top rows gives the same result as sorting everything, while
+    /// buffering a bounded number of candidate rows.
+    #[test]
+    fn top_n_matches_sort_then_head() -> DaftResult<()> {
+        let parts = (0..10)
+            .map(|i| {
+                make_part(
+                    (0..10)
+                        .map(|j| (j != i).then_some((i * 37 + j * 11) % 23))
+                        .collect(),
+                )
+                .map(Arc::new)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let schema = parts[0].schema();
+
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            for limit in [0, 1, 7, 15, 200] {
+                let sink = TopNSink::new(
+                    vec![resolved_col("a")],
+                    vec![descending],
+                    vec![nulls_first],
+                    limit,
+                    schema.clone(),
+                );
+                let params = sink.params;
+                let mut state = TopNState::new();
+                for part in &parts {
+                    state.push(part.clone(), &params)?;
+                    assert!(state.num_candidate_rows < 3 * limit.max(1));
+                }
+
+                let expected = MicroPartition::concat(&parts)?
+                    .sort(&[resolved_col("a")], &[descending], &[nulls_first])?
+                    .head(limit)?;
+                let top = if state.candidates.is_empty() {
+                    MicroPartition::empty(Some(schema.clone()))
+                } else {
+                    params.top_n(&MicroPartition::concat(&state.candidates)?)?
+                };
+                assert_eq!(values(&top)?, values(&expected)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_core::prelude::SchemaRef;
+use daft_dsl::{ExprRef, WindowSpec};
+use daft_micropartition::MicroPartition;
+use itertools::Itertools;
+use tracing::{instrument, Span};
+
+use super::blocking_sink::{
+    BlockingSink, BlockingSinkFinalizeOutput, BlockingSinkFinalizeResult, BlockingSinkSinkResult,
+    BlockingSinkState, BlockingSinkStatus,
+};
+use crate::{ExecutionTaskSpawner, NUM_CPUS};
+
+enum WindowState {
+    Accumulating {
+        // One list of micropartitions per hash partition of the window's partition by expressions.
+        inner_states: Vec<Vec<Arc<MicroPartition>>>,
+    },
+    Done,
+}
+
+impl WindowState {
+    fn new(num_partitions: usize) -> Self {
+        Self::Accumulating {
+            inner_states: (0..num_partitions).map(|_| vec![]).collect(),
+        }
+    }
+
+    fn push(&mut self, input: Arc<MicroPartition>, params: &WindowParams) -> DaftResult<()> {
+        let Self::Accumulating { inner_states } = self else {
+            panic!("WindowSink should be in Accumulating state");
+        };
+        if params.window_spec.partition_by.is_empty() {
+            // Without partition by expressions the whole input is a single window partition.
+            inner_states[0].push(input);
+        } else {
+            let partitioned =
+                input.partition_by_hash(&params.window_spec.partition_by, inner_states.len())?;
+            for (p, state) in partitioned.into_iter().zip(inner_states.iter_mut()) {
+                state.push(Arc::new(p));
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Vec<Vec<Arc<MicroPartition>>> {
+        let res = if let Self::Accumulating { inner_states } = self {
+            std::mem::take(inner_states)
+        } else {
+            panic!("WindowSink should be in Accumulating state");
+        };
+        *self = Self::Done;
+        res
+    }
+}
+
+impl BlockingSinkState for WindowState {
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+struct WindowParams {
+    window_functions: Vec<ExprRef>,
+    window_spec: WindowSpec,
+    schema: SchemaRef,
+}
+
+pub struct WindowSink {
+    window_params: Arc<WindowParams>,
+}
+
+impl WindowSink {
+    pub fn new(window_functions: &[ExprRef], window_spec: &WindowSpec, schema: &SchemaRef) -> Self {
+        Self {
+            window_params: Arc::new(WindowParams {
+                window_functions: window_functions.to_vec(),
+                window_spec: window_spec.clone(),
+                schema: schema.clone(),
+            }),
+        }
+    }
+
+    fn num_partitions(&self) -> usize {
+        if self.window_params.window_spec.partition_by.is_empty() {
+            1
+        } else {
+            *NUM_CPUS
+        }
+    }
+}
+
+impl BlockingSink for WindowSink {
+    #[instrument(skip_all, name = "WindowSink::sink")]
+    fn sink(
+        &self,
+        input: Arc<MicroPartition>,
+        mut state: Box<dyn BlockingSinkState>,
+        spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkSinkResult {
+        let params = self.window_params.clone();
+        spawner
+            .spawn(
+                async move {
+                    state
+                        .as_any_mut()
+                        .downcast_mut::<WindowState>()
+                        .expect("WindowSink should have WindowState")
+                        .push(input, &params)?;
+                    Ok(BlockingSinkStatus::NeedMoreInput(state))
+                },
+                Span::current(),
+            )
+            .into()
+    }
+
+    #[instrument(skip_all, name = "WindowSink::finalize")]
+    fn finalize(
+        &self,
+        states: Vec<Box<dyn BlockingSinkState>>,
+        _spawner: &ExecutionTaskSpawner,
+    ) -> BlockingSinkFinalizeResult {
+        let params = self.window_params.clone();
+        let mut state_iters = states
+            .into_iter()
+            .map(|mut state| {
+                state
+                    .as_any_mut()
+                    .downcast_mut::<WindowState>()
+                    .expect("WindowSink should have WindowState")
+                    .finalize()
+                    .into_iter()
+            })
+            .collect::<Vec<_>>();
+        let per_partition_states = (0..self.num_partitions())
+            .map(|_| {
+                state_iters
+                    .iter_mut()
+                    .flat_map(|state| state.next().expect("WindowState should have a partition"))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|per_partition_state| !per_partition_state.is_empty())
+            .collect::<Vec<_>>();
+        if per_partition_states.is_empty() {
+            return Ok(vec![Arc::new(MicroPartition::empty(Some(params.schema.clone())))].into())
+                .into();
+        }
+
+        // Rows of a window partition all hash to the same partition, so each one is evaluated and sent
+        // downstream on its own, releasing its input before the next one is evaluated.
+        let outputs = per_partition_states
+            .into_iter()
+            .map(move |per_partition_state| {
+                let windowed = MicroPartition::concat(&per_partition_state)?
+                    .window(&params.window_functions, &params.window_spec)?;
+                Ok(Arc::new(windowed))
+            });
+        Ok(BlockingSinkFinalizeOutput::Streaming(Box::new(outputs))).into()
+    }
+
+    fn name(&self) -> &'static str {
+        "Window"
+    }
+
+    fn multiline_display(&self) -> Vec<String> {
+        let mut display = vec![];
+        display.push(format!(
+            "Window: {}",
+            self.window_params
+                .window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        if !self.window_params.window_spec.partition_by.is_empty() {
+            display.push(format!(
+                "Partition by: {}",
+                self.window_params
+                    .window_spec
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        display
+    }
+
+    fn max_concurrency(&self) -> usize {
+        *NUM_CPUS
+    }
+
+    fn make_state(&self) -> DaftResult<Box<dyn BlockingSinkState>> {
+        Ok(Box::new(WindowState::new(self.num_partitions())))
+    }
+}
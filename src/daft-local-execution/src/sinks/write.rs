@@ -23,6 +23,10 @@ pub enum WriteFormat {
     PartitionedParquet,
     Csv,
     PartitionedCsv,
+    Json,
+    PartitionedJson,
+    Ipc,
+    PartitionedIpc,
     Iceberg,
     PartitionedIceberg,
     Deltalake,
@@ -120,7 +124,7 @@ impl BlockingSink for WriteSink {
                         results.into(),
                         None,
                     ));
-                    Ok(Some(mp))
+                    Ok(vec![mp].into())
                 },
                 Span::current(),
             )
@@ -133,6 +137,10 @@ impl BlockingSink for WriteSink {
             WriteFormat::PartitionedParquet => "PartitionedParquetSink",
             WriteFormat::Csv => "CsvSink",
             WriteFormat::PartitionedCsv => "PartitionedCsvSink",
+            WriteFormat::Json => "JsonSink",
+            WriteFormat::PartitionedJson => "PartitionedJsonSink",
+            WriteFormat::Ipc => "IpcSink",
+            WriteFormat::PartitionedIpc => "PartitionedIpcSink",
             WriteFormat::Iceberg => "IcebergSink",
             WriteFormat::PartitionedIceberg => "PartitionedIcebergSink",
             WriteFormat::Deltalake => "DeltalakeSink",
@@ -0,0 +1,656 @@
+use std::sync::Arc;
+
+use common_display::tree::TreeDisplay;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_compute_runtime;
+use daft_core::{prelude::SchemaRef, utils::supertype::try_get_supertype};
+use daft_dsl::ExprRef;
+use daft_logical_plan::stats::StatsState;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+use itertools::Itertools;
+use tracing::{info_span, Span};
+
+use crate::{
+    channel::{create_channel, Receiver},
+    pipeline::PipelineNode,
+    progress_bar::ProgressBarColor,
+    runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+    ExecutionRuntimeContext, ExecutionTaskSpawner,
+};
+
+struct SortMergeJoinParams {
+    left_on: Vec<ExprRef>,
+    right_on: Vec<ExprRef>,
+    // The join keys of each side cast to their common type, so that they can be compared.
+    left_key_exprs: Vec<ExprRef>,
+    right_key_exprs: Vec<ExprRef>,
+    left_schema: SchemaRef,
+    right_schema: SchemaRef,
+    output_schema: SchemaRef,
+}
+
+impl SortMergeJoinParams {
+    fn try_new(
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        output_schema: SchemaRef,
+    ) -> DaftResult<Self> {
+        let mut left_key_exprs = Vec::with_capacity(left_on.len());
+        let mut right_key_exprs = Vec::with_capacity(right_on.len());
+        for (l, r) in left_on.iter().zip(right_on.iter()) {
+            let key_type = try_get_supertype(
+                &l.to_field(&left_schema)?.dtype,
+                &r.to_field(&right_schema)?.dtype,
+            )?;
+            left_key_exprs.push(l.clone().cast(&key_type));
+            right_key_exprs.push(r.clone().cast(&key_type));
+        }
+        Ok(Self {
+            left_on,
+            right_on,
+            left_key_exprs,
+            right_key_exprs,
+            left_schema,
+            right_schema,
+            output_schema,
+        })
+    }
+}
+
+/// The rows received from one input of a sort-merge join which may still match rows of the other.
+struct MergeSide {
+    name: &'static str,
+    receiver: CountingReceiver,
+    key_exprs: Vec<ExprRef>,
+    table: RecordBatch,
+    keys: RecordBatch,
+    // The join keys of the last row received, to check that the next morsel continues in order.
+    last_key: Option<RecordBatch>,
+    done: bool,
+}
+
+impl MergeSide {
+    fn try_new(
+        name: &'static str,
+        receiver: CountingReceiver,
+        key_exprs: Vec<ExprRef>,
+        schema: SchemaRef,
+    ) -> DaftResult<Self> {
+        let table = RecordBatch::empty(Some(schema))?;
+        let keys = table.eval_expression_list(&key_exprs)?;
+        Ok(Self {
+            name,
+            receiver,
+            key_exprs,
+            table,
+            keys,
+            last_key: None,
+            done: false,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    fn last_row_keys(&self) -> DaftResult<RecordBatch> {
+        self.keys.slice(self.keys.len() - 1, self.keys.len())
+    }
+
+    /// Receives the next morsel of this side, checking that it continues the order of the rows
+    /// received so far.
+    async fn pull(&mut self, task_spawner: &ExecutionTaskSpawner) -> DaftResult<()> {
+        let Some(morsel) = self.receiver.recv().await else {
+            self.done = true;
+            return Ok(());
+        };
+        if morsel.is_empty() {
+            return Ok(());
+        }
+
+        let name = self.name;
+        let key_exprs = self.key_exprs.clone();
+        let last_key = self.last_key.clone();
+        let (table, keys) = task_spawner
+            .spawn(
+                async move {
+                    let table = RecordBatch::concat(morsel.get_tables()?.as_slice())?;
+                    let keys = table.eval_expression_list(&key_exprs)?;
+                    let is_in_order = keys.keys_are_sorted()?
+                        && match &last_key {
+                            Some(last_key) => keys.sorted_keys_num_rows_before(last_key, 0)? == 0,
+                            None => true,
+                        };
+                    if !is_in_order {
+                        return Err(DaftError::ValueError(format!(
+                            "Sort-merge join requires its {name} input to be sorted by the join keys in ascending order with nulls last, \
+                            so it should be sorted first, joined with the hash join strategy instead, or \
+                            read without setting `sort_merge_join_presorted_scans`"
+                        )));
+                    }
+                    Ok((table, keys))
+                },
+                Span::current(),
+            )
+            .await??;
+
+        self.last_key = Some(keys.slice(keys.len() - 1, keys.len())?);
+        self.table = RecordBatch::concat(&[&self.table, &table])?;
+        self.keys = RecordBatch::concat(&[&self.keys, &keys])?;
+        Ok(())
+    }
+
+    /// Removes and returns the first `num_rows` rows of this side.
+    fn split_off_front(&mut self, num_rows: usize) -> DaftResult<RecordBatch> {
+        let front = self.table.head(num_rows)?;
+        self.table = self.table.slice(num_rows, self.table.len())?;
+        self.keys = self.keys.slice(num_rows, self.keys.len())?;
+        Ok(front)
+    }
+}
+
+/// Joins two inputs which are sorted by their join keys by merging their streams of morsels, so
+/// that only the rows which may still have matches need to be kept in memory.
+pub(crate) struct SortMergeJoinNode {
+    params: Arc<SortMergeJoinParams>,
+    left: Box<dyn PipelineNode>,
+    right: Box<dyn PipelineNode>,
+    runtime_stats: Arc<RuntimeStatsContext>,
+    plan_stats: StatsState,
+}
+
+impl SortMergeJoinNode {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_new(
+        left: Box<dyn PipelineNode>,
+        right: Box<dyn PipelineNode>,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        left_schema: SchemaRef,
+        right_schema: SchemaRef,
+        output_schema: SchemaRef,
+        plan_stats: StatsState,
+    ) -> DaftResult<Self> {
+        let params = SortMergeJoinParams::try_new(
+            left_on,
+            right_on,
+            left_schema,
+            right_schema,
+            output_schema,
+        )?;
+        Ok(Self {
+            params: Arc::new(params),
+            left,
+            right,
+            runtime_stats: RuntimeStatsContext::new(),
+            plan_stats,
+        })
+    }
+
+    pub(crate) fn boxed(self) -> Box<dyn PipelineNode> {
+        Box::new(self)
+    }
+
+    async fn run_merge(
+        params: Arc<SortMergeJoinParams>,
+        mut left: MergeSide,
+        mut right: MergeSide,
+        sender: CountingSender,
+        task_spawner: ExecutionTaskSpawner,
+    ) -> DaftResult<()> {
+        let mut has_output = false;
+        loop {
+            while left.is_empty() && !left.done {
+                left.pull(&task_spawner).await?;
+            }
+            while right.is_empty() && !right.done {
+                right.pull(&task_spawner).await?;
+            }
+            // Once either side has run out of rows, none of the remaining rows can match.
+            if left.is_empty() || right.is_empty() {
+                break;
+            }
+
+            // Every row less than the last row of the side which ends lower has all of its matches
+            // buffered, since both sides are sorted. If a side is done, its last row is no bound.
+            let left_is_bound = match (left.done, right.done) {
+                (true, true) => {
+                    let joined = Self::join_sorted(
+                        &params,
+                        left.table.clone(),
+                        right.table.clone(),
+                        &task_spawner,
+                    )
+                    .await?;
+                    if let Some(morsel) = joined {
+                        has_output = true;
+                        let _ = sender.send(morsel).await;
+                    }
+                    break;
+                }
+                (true, false) => false,
+                (false, true) => true,
+                (false, false) => {
+                    right
+                        .keys
+                        .sorted_keys_num_rows_before(&left.keys, left.keys.len() - 1)?
+                        < right.keys.len()
+                }
+            };
+            let bound = if left_is_bound {
+                left.last_row_keys()?
+            } else {
+                right.last_row_keys()?
+            };
+            let num_left = left.keys.sorted_keys_num_rows_before(&bound, 0)?;
+            let num_right = right.keys.sorted_keys_num_rows_before(&bound, 0)?;
+            let left_front = left.split_off_front(num_left)?;
+            let right_front = right.split_off_front(num_right)?;
+            if num_left > 0 && num_right > 0 {
+                let joined =
+                    Self::join_sorted(&params, left_front, right_front, &task_spawner).await?;
+                if let Some(morsel) = joined {
+                    has_output = true;
+                    if sender.send(morsel).await.is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if left_is_bound {
+                left.pull(&task_spawner).await?;
+            } else {
+                right.pull(&task_spawner).await?;
+            }
+        }
+
+        if !has_output {
+            let empty = Arc::new(MicroPartition::empty(Some(params.output_schema.clone())));
+            let _ = sender.send(empty).await;
+        }
+        Ok(())
+    }
+
+    /// Joins sorted rows of each side which have all of their matches among each other, returning
+    /// the joined rows if there are any.
+    async fn join_sorted(
+        params: &Arc<SortMergeJoinParams>,
+        left_table: RecordBatch,
+        right_table: RecordBatch,
+        task_spawner: &ExecutionTaskSpawner,
+    ) -> DaftResult<Option<Arc<MicroPartition>>> {
+        let params = params.clone();
+        task_spawner
+            .spawn(
+                async move {
+                    let joined = left_table.sort_merge_join(
+                        &right_table,
+                        &params.left_on,
+                        &params.right_on,
+                        true,
+                    )?;
+                    if joined.is_empty() {
+                        return Ok(None);
+                    }
+                    Ok(Some(Arc::new(MicroPartition::new_loaded(
+                        params.output_schema.clone(),
+                        Arc::new(vec![joined]),
+                        None,
+                    ))))
+                },
+                Span::current(),
+            )
+            .await?
+    }
+}
+
+impl TreeDisplay for SortMergeJoinNode {
+    fn display_as(&self, level: common_display::DisplayLevel) -> String {
+        use std::fmt::Write;
+        let mut display = String::new();
+
+        use common_display::DisplayLevel;
+        match level {
+            DisplayLevel::Compact => {
+                writeln!(display, "{}", self.name()).unwrap();
+            }
+            level => {
+                writeln!(display, "SortMergeJoin:").unwrap();
+                writeln!(
+                    display,
+                    "Left on: [{}]",
+                    self.params.left_on.iter().map(|e| e.to_string()).join(", ")
+                )
+                .unwrap();
+                writeln!(
+                    display,
+                    "Right on: [{}]",
+                    self.params
+                        .right_on
+                        .iter()
+                        .map(|e| e.to_string())
+                        .join(", ")
+                )
+                .unwrap();
+                if let StatsState::Materialized(stats) = &self.plan_stats {
+                    writeln!(display, "Stats = {}", stats).unwrap();
+                }
+                if matches!(level, DisplayLevel::Verbose) {
+                    writeln!(display).unwrap();
+                    let rt_result = self.runtime_stats.result();
+                    rt_result.display(&mut display, true, true, true).unwrap();
+                }
+            }
+        }
+        display
+    }
+
+    fn get_children(&self) -> Vec<&dyn TreeDisplay> {
+        vec![self.left.as_tree_display(), self.right.as_tree_display()]
+    }
+}
+
+impl PipelineNode for SortMergeJoinNode {
+    fn children(&self) -> Vec<&dyn PipelineNode> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
+
+    fn name(&self) -> &'static str {
+        "SortMergeJoin"
+    }
+
+    fn start(
+        &self,
+        _maintain_order: bool,
+        runtime_handle: &mut ExecutionRuntimeContext,
+    ) -> crate::Result<Receiver<Arc<MicroPartition>>> {
+        let progress_bar = runtime_handle.make_progress_bar(
+            self.name(),
+            ProgressBarColor::Magenta,
+            true,
+            self.runtime_stats.clone(),
+        );
+        // Both inputs must keep their order for their join keys to stay sorted.
+        let left_receiver = CountingReceiver::new(
+            self.left.start(true, runtime_handle)?,
+            self.runtime_stats.clone(),
+            progress_bar.clone(),
+        );
+        let right_receiver = CountingReceiver::new(
+            self.right.start(true, runtime_handle)?,
+            self.runtime_stats.clone(),
+            progress_bar.clone(),
+        );
+        let (destination_sender, destination_receiver) = create_channel(0);
+        let counting_sender =
+            CountingSender::new(destination_sender, self.runtime_stats.clone(), progress_bar);
+
+        let params = self.params.clone();
+        let task_spawner = ExecutionTaskSpawner::new(
+            get_compute_runtime(),
+            runtime_handle.memory_manager(),
+            self.runtime_stats.clone(),
+            info_span!("SortMergeJoin::merge"),
+        );
+        runtime_handle.spawn(
+            async move {
+                let left = MergeSide::try_new(
+                    "left",
+                    left_receiver,
+                    params.left_key_exprs.clone(),
+                    params.left_schema.clone(),
+                )?;
+                let right = MergeSide::try_new(
+                    "right",
+                    right_receiver,
+                    params.right_key_exprs.clone(),
+                    params.right_schema.clone(),
+                )?;
+                Self::run_merge(params, left, right, counting_sender, task_spawner).await
+            },
+            self.name(),
+        );
+        Ok(destination_receiver)
+    }
+
+    fn as_tree_display(&self) -> &dyn TreeDisplay {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_daft_config::DaftExecutionConfig;
+    use common_error::DaftResult;
+    use common_runtime::get_compute_runtime;
+    use daft_core::prelude::*;
+    use daft_dsl::{join::infer_join_schema, resolved_col, unresolved_col};
+    use daft_logical_plan::{JoinOptions, LogicalPlanBuilder};
+    use daft_micropartition::{
+        partitioning::{
+            InMemoryPartitionSetCache, MicroPartitionSet, PartitionCacheEntry, PartitionSetCache,
+        },
+        MicroPartition,
+    };
+    use daft_recordbatch::RecordBatch;
+    use tracing::Span;
+
+    use super::{MergeSide, SortMergeJoinNode, SortMergeJoinParams};
+    use crate::{
+        channel::create_channel,
+        resource_manager::MemoryManager,
+        runtime_stats::{CountingReceiver, CountingSender, RuntimeStatsContext},
+        ExecutionTaskSpawner, NativeExecutor,
+    };
+
+    fn make_table(value_name: &str, keys: &[Option<i64>], offset: i64) -> DaftResult<RecordBatch> {
+        let a = Int64Array::from_iter(Field::new("a", DataType::Int64), keys.iter().copied())
+            .into_series();
+        let values = Int64Array::from_iter(
+            Field::new(value_name, DataType::Int64),
+            (0..keys.len())
+                .map(|i| Some(offset + i as i64))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+        .into_series();
+        RecordBatch::from_nonempty_columns(vec![a, values])
+    }
+
+    /// Splits the keys into morsels of the given sizes, numbering the rows across morsels.
+    fn make_morsels(
+        value_name: &str,
+        keys: &[Option<i64>],
+        sizes: &[usize],
+    ) -> DaftResult<Vec<RecordBatch>> {
+        let mut start = 0;
+        sizes
+            .iter()
+            .map(|size| {
+                let table = make_table(value_name, &keys[start..start + size], start as i64);
+                start += size;
+                table
+            })
+            .collect()
+    }
+
+    async fn merge_join(
+        left: Vec<RecordBatch>,
+        right: Vec<RecordBatch>,
+    ) -> DaftResult<Vec<RecordBatch>> {
+        let left_schema = left[0].schema.clone();
+        let right_schema = right[0].schema.clone();
+        let output_schema = infer_join_schema(&left_schema, &right_schema, JoinType::Inner)?;
+        let params = Arc::new(SortMergeJoinParams::try_new(
+            vec![resolved_col("a")],
+            vec![resolved_col("a")],
+            left_schema.clone(),
+            right_schema.clone(),
+            output_schema,
+        )?);
+
+        let runtime_stats = RuntimeStatsContext::new();
+        let make_side = |name, tables: Vec<RecordBatch>, key_exprs, schema| {
+            let (sender, receiver) = create_channel(tables.len());
+            let side = MergeSide::try_new(
+                name,
+                CountingReceiver::new(receiver, runtime_stats.clone(), None),
+                key_exprs,
+                schema,
+            );
+            (sender, tables, side)
+        };
+        let (left_sender, left_tables, left_side) =
+            make_side("left", left, params.left_key_exprs.clone(), left_schema);
+        let (right_sender, right_tables, right_side) =
+            make_side("right", right, params.right_key_exprs.clone(), right_schema);
+        for (sender, tables) in [(left_sender, left_tables), (right_sender, right_tables)] {
+            for table in tables {
+                let morsel =
+                    MicroPartition::new_loaded(table.schema.clone(), Arc::new(vec![table]), None);
+                sender.send(Arc::new(morsel)).await.unwrap();
+            }
+        }
+
+        let (output_sender, output_receiver) = create_channel(0);
+        let task_spawner = ExecutionTaskSpawner::new(
+            get_compute_runtime(),
+            Arc::new(MemoryManager::new()),
+            runtime_stats.clone(),
+            Span::none(),
+        );
+        let merge = tokio::spawn(SortMergeJoinNode::run_merge(
+            params,
+            left_side?,
+            right_side?,
+            CountingSender::new(output_sender, runtime_stats, None),
+            task_spawner,
+        ));
+        let mut outputs = vec![];
+        while let Some(morsel) = output_receiver.recv().await {
+            outputs.extend(morsel.get_tables()?.iter().cloned());
+        }
+        merge.await.unwrap()?;
+        Ok(outputs)
+    }
+
+    fn sorted_rows(tables: &[RecordBatch]) -> DaftResult<Vec<Vec<Option<i64>>>> {
+        let mut rows = vec![];
+        for table in tables {
+            for i in 0..table.len() {
+                rows.push(
+                    (0..table.num_columns())
+                        .map(|c| Ok(table.get_column_by_index(c)?.i64()?.get(i)))
+                        .collect::<DaftResult<Vec<_>>>()?,
+                );
+            }
+        }
+        rows.sort();
+        Ok(rows)
+    }
+
+    #[tokio::test]
+    async fn test_merge_join_matches_join_of_whole_inputs() -> DaftResult<()> {
+        let left_keys = [1, 1, 2, 2, 2, 4, 5, 5, 7, 9]
+            .map(Some)
+            .into_iter()
+            .chain([None, None])
+            .collect::<Vec<_>>();
+        let right_keys = [0, 1, 2, 2, 5, 5, 5, 5, 6, 7]
+            .map(Some)
+            .into_iter()
+            .chain([None])
+            .collect::<Vec<_>>();
+        let left = make_table("b", &left_keys, 0)?;
+        let right = make_table("c", &right_keys, 0)?;
+        let key = [resolved_col("a")];
+        let expected =
+            sorted_rows(&[left.hash_join(&right, &key, &key, &[false], JoinType::Inner)?])?;
+
+        for (left_sizes, right_sizes) in [
+            (vec![12], vec![11]),
+            (vec![1; 12], vec![1; 11]),
+            (vec![3, 1, 5, 3], vec![2, 6, 3]),
+            (vec![5, 7], vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]),
+        ] {
+            let left = make_morsels("b", &left_keys, &left_sizes)?;
+            let right = make_morsels("c", &right_keys, &right_sizes)?;
+            let outputs = merge_join(left, right).await?;
+            assert_eq!(sorted_rows(&outputs)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_merge_join_sorts_unsorted_inputs() -> DaftResult<()> {
+        let psets = InMemoryPartitionSetCache::empty();
+        let scan = |name: &str, table: RecordBatch| -> DaftResult<LogicalPlanBuilder> {
+            let schema = table.schema.clone();
+            let partition = MicroPartition::new_loaded(schema.clone(), Arc::new(vec![table]), None);
+            let pset = Arc::new(MicroPartitionSet::from(vec![Arc::new(partition)]));
+            psets.put_partition_set(name, &pset);
+            LogicalPlanBuilder::in_memory_scan(
+                name,
+                PartitionCacheEntry::new_rust(name.to_string(), pset),
+                schema,
+                1,
+                0,
+                0,
+            )
+        };
+        let left = scan(
+            "left",
+            make_table("b", &[Some(3), None, Some(1), Some(2), Some(1)], 0)?,
+        )?;
+        let right = scan(
+            "right",
+            make_table("c", &[Some(2), Some(1), None, Some(3)], 0)?,
+        )?
+        .select(vec![resolved_col("a").alias("a_right"), resolved_col("c")])?;
+        let plan = left.join(
+            right,
+            vec![unresolved_col("a")],
+            vec![unresolved_col("a_right")],
+            JoinType::Inner,
+            Some(JoinStrategy::SortMerge),
+            JoinOptions::default(),
+        )?;
+
+        // dropping the executor cancels its execution, so it's kept until all results are received.
+        let executor = NativeExecutor::new();
+        let result = executor.run(
+            &plan,
+            &psets,
+            Arc::new(DaftExecutionConfig::default()),
+            None,
+        )?;
+        let mut outputs = vec![];
+        for morsel in result {
+            outputs.extend(morsel?.get_tables()?.iter().cloned());
+        }
+        assert_eq!(
+            sorted_rows(&outputs)?,
+            vec![
+                vec![Some(1), Some(2), Some(1), Some(1)],
+                vec![Some(1), Some(4), Some(1), Some(1)],
+                vec![Some(2), Some(3), Some(2), Some(0)],
+                vec![Some(3), Some(0), Some(3), Some(3)],
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_merge_join_rejects_unsorted_input() -> DaftResult<()> {
+        let left = make_morsels("b", &[Some(1), Some(3), Some(2), Some(4)], &[2, 2])?;
+        let right = make_morsels("c", &[Some(1), Some(2), Some(3), Some(4)], &[4])?;
+        let err = merge_join(left, right).await.unwrap_err();
+        assert!(err.to_string().contains("left input to be sorted"));
+        Ok(())
+    }
+}
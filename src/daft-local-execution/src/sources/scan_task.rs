@@ -10,15 +10,20 @@ use common_error::DaftResult;
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
 use common_runtime::get_io_runtime;
 use common_scan_info::{Pushdowns, ScanTaskLike};
+use daft_avro::AvroConvertOptions;
 use daft_core::prelude::{AsArrow, Int64Array, SchemaRef, Utf8Array};
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
 use daft_io::IOStatsRef;
+use daft_ipc::IpcConvertOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
-use daft_micropartition::MicroPartition;
+use daft_micropartition::{
+    aggregate_scan_task_from_metadata, aggregate_scanned_data, MicroPartition,
+};
+use daft_orc::OrcConvertOptions;
 use daft_parquet::read::{read_parquet_bulk_async, ParquetSchemaInferenceOptions};
 use daft_scan::{ChunkSpec, ScanTask};
 use daft_warc::WarcConvertOptions;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use snafu::ResultExt;
 use tracing::instrument;
 
@@ -100,7 +105,7 @@ impl Source for ScanTaskSource {
             futures::stream::iter(self.scan_tasks.clone().into_iter().map(move |scan_task| {
                 let io_stats = io_stats.clone();
                 let delete_map = delete_map.clone();
-                io_runtime.spawn(stream_scan_task(
+                io_runtime.spawn(stream_scan_task_or_aggregations(
                     scan_task,
                     io_stats,
                     delete_map,
@@ -297,6 +302,45 @@ async fn get_delete_map(
         .await?
 }
 
+/// Streams the data of `scan_task`, or the partial results of its aggregation pushdown if it has one.
+async fn stream_scan_task_or_aggregations(
+    scan_task: Arc<ScanTask>,
+    io_stats: IOStatsRef,
+    delete_map: Option<Arc<HashMap<String, Vec<i64>>>>,
+    maintain_order: bool,
+) -> DaftResult<BoxStream<'static, DaftResult<Arc<MicroPartition>>>> {
+    if scan_task.pushdowns.aggregations.is_none() {
+        return Ok(
+            stream_scan_task(scan_task, io_stats, delete_map, maintain_order)
+                .await?
+                .boxed(),
+        );
+    }
+    let table = match aggregate_scan_task_from_metadata(&scan_task, Some(io_stats.clone())).await? {
+        Some(table) => table,
+        None => {
+            let data_scan_task = Arc::new(scan_task.without_aggregations());
+            let data =
+                stream_scan_task(data_scan_task.clone(), io_stats, delete_map, maintain_order)
+                    .await?
+                    .try_collect::<Vec<_>>()
+                    .await?;
+            let data = if data.is_empty() {
+                MicroPartition::empty(Some(data_scan_task.materialized_schema()))
+            } else {
+                MicroPartition::concat(data)?
+            };
+            aggregate_scanned_data(&scan_task, &data)?
+        }
+    };
+    let mp = Arc::new(MicroPartition::new_loaded(
+        scan_task.materialized_schema(),
+        Arc::new(vec![table]),
+        None,
+    ));
+    Ok(futures::stream::once(async { Ok(mp) }).boxed())
+}
+
 async fn stream_scan_task(
     scan_task: Arc<ScanTask>,
     io_stats: IOStatsRef,
@@ -338,6 +382,10 @@ async fn stream_scan_task(
     }
     let source = scan_task.sources.first().unwrap();
     let url = source.get_path();
+    let byte_range = match source.get_chunk_spec() {
+        Some(ChunkSpec::Bytes { start, end }) => Some(*start..*end),
+        _ => None,
+    };
     let io_config = Arc::new(
         scan_task
             .storage_config
@@ -409,13 +457,19 @@ async fn stream_scan_task(
             );
             let parse_options = CsvParseOptions::new_with_defaults(
                 cfg.has_headers,
-                cfg.delimiter,
+                cfg.delimiter.as_deref(),
                 cfg.double_quote,
                 cfg.quote,
                 cfg.allow_variable_columns,
                 cfg.escape_char,
                 cfg.comment,
-            )?;
+            )?
+            .with_encoding(cfg.encoding.as_deref())?
+            .with_null_values(cfg.null_values.clone())
+            .with_skip_rows(cfg.skip_rows)
+            .with_skip_rows_after_header(cfg.skip_rows_after_header)
+            .with_truncate_ragged_lines(cfg.truncate_ragged_lines)
+            .with_datetime_formats(cfg.datetime_formats.clone());
             let read_options = CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
             daft_csv::stream_csv(
                 url.to_string(),
@@ -463,6 +517,51 @@ async fn stream_scan_task(
             };
             daft_warc::stream_warc(url, io_client, Some(io_stats), convert_options, None).await?
         }
+        FileFormatConfig::Ipc(_) => {
+            let convert_options = IpcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            daft_ipc::stream_ipc(url.to_string(), convert_options, io_client, Some(io_stats))
+                .await?
+        }
+        FileFormatConfig::Avro(_) => {
+            let convert_options = AvroConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            daft_avro::stream_avro(
+                url.to_string(),
+                byte_range,
+                convert_options,
+                io_client,
+                Some(io_stats),
+            )
+            .await?
+        }
+        FileFormatConfig::Orc(_) => {
+            let convert_options = OrcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            daft_orc::stream_orc(
+                url.to_string(),
+                byte_range,
+                convert_options,
+                io_client,
+                Some(io_stats),
+            )
+            .await?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(common_file_formats::DatabaseSourceConfig { sql, conn }) => {
             use pyo3::Python;
@@ -0,0 +1,242 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Seek, SeekFrom},
+    sync::Arc,
+};
+
+use arrow2::io::ipc::{read, write};
+use common_error::DaftResult;
+use daft_core::{prelude::SchemaRef, series::Series};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::resource_manager::{MemoryManager, MemoryReservation};
+
+/// Whether data of the given schema can be spilled, i.e. whether all of its types can be written as Arrow.
+pub(crate) fn can_spill(schema: &SchemaRef) -> bool {
+    schema.to_arrow().is_ok()
+}
+
+/// Writes micropartitions to an anonymous local temp file in the Arrow IPC file format.
+pub(crate) struct SpillWriter {
+    schema: SchemaRef,
+    writer: write::FileWriter<BufWriter<File>>,
+    num_rows: usize,
+    size_bytes: usize,
+}
+
+impl SpillWriter {
+    pub fn try_new(schema: SchemaRef) -> DaftResult<Self> {
+        let file = tempfile::tempfile()?;
+        let writer = write::FileWriter::try_new(
+            BufWriter::new(file),
+            schema.to_arrow()?,
+            None,
+            write::WriteOptions { compression: None },
+        )?;
+        Ok(Self {
+            schema,
+            writer,
+            num_rows: 0,
+            size_bytes: 0,
+        })
+    }
+
+    pub fn write(&mut self, part: &MicroPartition) -> DaftResult<()> {
+        for table in part.get_tables()?.iter() {
+            self.write_table(table)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_table(&mut self, table: &RecordBatch) -> DaftResult<()> {
+        if table.is_empty() {
+            return Ok(());
+        }
+        self.num_rows += table.len();
+        self.size_bytes += table.size_bytes()?;
+        let chunk = arrow2::chunk::Chunk::new(table.get_inner_arrow_arrays().collect());
+        self.writer.write(&chunk, None)?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> DaftResult<SpillFile> {
+        self.writer.finish()?;
+        let file = self
+            .writer
+            .into_inner()
+            .into_inner()
+            .map_err(|e| e.into_error())?;
+        Ok(SpillFile {
+            schema: self.schema,
+            file,
+            num_rows: self.num_rows,
+            size_bytes: self.size_bytes,
+        })
+    }
+}
+
+/// Spilled data, deleted once dropped.
+pub(crate) struct SpillFile {
+    schema: SchemaRef,
+    file: File,
+    num_rows: usize,
+    size_bytes: usize,
+}
+
+impl SpillFile {
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The in-memory size of the spilled data.
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    /// Reads the spilled data back one record batch at a time, in the order it was written.
+    pub fn read_tables(
+        &mut self,
+    ) -> DaftResult<impl Iterator<Item = DaftResult<RecordBatch>> + '_> {
+        self.file.seek(SeekFrom::Start(0))?;
+        let mut reader = BufReader::new(&self.file);
+        let metadata = read::read_file_metadata(&mut reader)?;
+        let schema = self.schema.clone();
+        Ok(
+            read::FileReader::new(reader, metadata, None, None).map(move |chunk| {
+                let chunk = chunk?;
+                let num_rows = chunk.len();
+                let columns = schema
+                    .fields
+                    .values()
+                    .zip(chunk.into_arrays())
+                    .map(|(field, array)| Series::from_arrow(Arc::new(field.clone()), array))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                RecordBatch::new_with_size(schema.clone(), columns, num_rows)
+            }),
+        )
+    }
+
+    /// Reads the spilled data back into memory.
+    pub fn read(&mut self) -> DaftResult<MicroPartition> {
+        let tables = self.read_tables()?.collect::<DaftResult<Vec<_>>>()?;
+        Ok(MicroPartition::new_loaded(
+            self.schema.clone(),
+            Arc::new(tables),
+            None,
+        ))
+    }
+}
+
+/// Buffers micropartitions in memory while the memory manager allows it, spilling them to disk otherwise.
+#[derive(Default)]
+pub(crate) struct SpillableBuffer {
+    parts: Vec<Arc<MicroPartition>>,
+    reservations: Vec<MemoryReservation>,
+    spilled: Vec<SpillFile>,
+}
+
+impl SpillableBuffer {
+    /// Buffers `part`. If there is no memory left for it, everything buffered so far is written
+    /// to a new spill file, and the in-memory data that was spilled is returned.
+    pub fn push(
+        &mut self,
+        part: Arc<MicroPartition>,
+        memory_manager: &Arc<MemoryManager>,
+    ) -> DaftResult<Option<MicroPartition>> {
+        let size_bytes = part.size_bytes()?.unwrap_or(0) as u64;
+        if let Some(reservation) = memory_manager.try_reserve(size_bytes) {
+            self.reservations.push(reservation);
+            self.parts.push(part);
+            return Ok(None);
+        }
+        self.parts.push(part);
+        if !can_spill(&self.parts[0].schema()) {
+            // Data that cannot be written as Arrow is kept in memory regardless of the budget.
+            return Ok(None);
+        }
+        let run = MicroPartition::concat(std::mem::take(&mut self.parts))?;
+        let mut writer = SpillWriter::try_new(run.schema())?;
+        writer.write(&run)?;
+        self.spilled.push(writer.finish()?);
+        self.reservations.clear();
+        Ok(Some(run))
+    }
+
+    /// Returns the buffered micropartitions and spill files, releasing the buffer's memory reservations.
+    pub fn finish(self) -> (Vec<Arc<MicroPartition>>, Vec<SpillFile>) {
+        (self.parts, self.spilled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::{SpillWriter, SpillableBuffer};
+    use crate::resource_manager::MemoryManager;
+
+    fn make_part(a: Vec<i64>, b: &[&str]) -> DaftResult<Arc<MicroPartition>> {
+        let a = Int64Array::from(("a", a)).into_series();
+        let b = Utf8Array::from(("b", b)).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a, b])?;
+        Ok(Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        )))
+    }
+
+    #[test]
+    fn test_spill_round_trip() -> DaftResult<()> {
+        let first = make_part(vec![1, 2, 3], &["x", "y", "z"])?;
+        let second = make_part(vec![4], &["w"])?;
+
+        let mut writer = SpillWriter::try_new(first.schema())?;
+        writer.write(&first)?;
+        writer.write(&second)?;
+        let mut spill_file = writer.finish()?;
+
+        let read = spill_file.read()?;
+        assert_eq!(read.schema(), first.schema());
+        let expected = MicroPartition::concat([first, second])?;
+        let tables = read.get_tables()?;
+        let expected_tables = expected.get_tables()?;
+        assert_eq!(
+            RecordBatch::concat(tables.as_slice())?,
+            RecordBatch::concat(expected_tables.as_slice())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_buffer_spills_over_memory_limit() -> DaftResult<()> {
+        let part = make_part(vec![1, 2, 3], &["x", "y", "z"])?;
+        let size_bytes = part.size_bytes()?.unwrap() as u64;
+        let memory_manager = Arc::new(MemoryManager::with_limit(size_bytes * 2));
+
+        let mut buffer = SpillableBuffer::default();
+        assert!(buffer.push(part.clone(), &memory_manager)?.is_none());
+        assert!(buffer.push(part.clone(), &memory_manager)?.is_none());
+        // The third part does not fit, so all three are spilled together.
+        let run = buffer.push(part.clone(), &memory_manager)?.unwrap();
+        assert_eq!(run.len(), 9);
+        // Spilling released the buffer's reservations.
+        assert!(buffer.push(part, &memory_manager)?.is_none());
+
+        let (parts, mut spilled) = buffer.finish();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(spilled.len(), 1);
+        assert_eq!(spilled[0].read()?.len(), 9);
+        Ok(())
+    }
+}
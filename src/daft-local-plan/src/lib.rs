@@ -7,8 +7,10 @@ pub use plan::CatalogWrite;
 #[cfg(feature = "python")]
 pub use plan::LanceWrite;
 pub use plan::{
-    ActorPoolProject, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate, HashJoin,
-    InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef, MonotonicallyIncreasingId,
-    PhysicalScan, PhysicalWrite, Pivot, Project, Sample, Sort, UnGroupedAggregate, Unpivot,
+    ActorPoolProject, AsofJoin, Concat, CrossJoin, EmptyScan, Explode, Filter, HashAggregate,
+    HashJoin, InMemoryScan, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef,
+    MonotonicallyIncreasingId, Offset, PhysicalScan, PhysicalWrite, Pivot, Project, RecursiveCte,
+    Repartition, RepartitionSpec, Sample, Sort, SortMergeJoin, TopN, UnGroupedAggregate, Unpivot,
+    Window,
 };
 pub use translate::translate;
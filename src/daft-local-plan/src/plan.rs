@@ -3,7 +3,7 @@ use std::sync::Arc;
 use common_resource_request::ResourceRequest;
 use common_scan_info::{Pushdowns, ScanTaskLikeRef};
 use daft_core::prelude::*;
-use daft_dsl::{AggExpr, ExprRef};
+use daft_dsl::{join::AsofJoinOptions, AggExpr, ExprRef, WindowSpec};
 use daft_logical_plan::{
     stats::{PlanStats, StatsState},
     InMemoryInfo, OutputFileInfo,
@@ -19,12 +19,16 @@ pub enum LocalPhysicalPlan {
     ActorPoolProject(ActorPoolProject),
     Filter(Filter),
     Limit(Limit),
+    Offset(Offset),
     Explode(Explode),
     Unpivot(Unpivot),
     Sort(Sort),
+    TopN(TopN),
     // Split(Split),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
+    Repartition(Repartition),
     // Coalesce(Coalesce),
     // Flatten(Flatten),
     // FanoutRandom(FanoutRandom),
@@ -35,9 +39,11 @@ pub enum LocalPhysicalPlan {
     HashAggregate(HashAggregate),
     Pivot(Pivot),
     Concat(Concat),
+    RecursiveCte(RecursiveCte),
     HashJoin(HashJoin),
     CrossJoin(CrossJoin),
-    // SortMergeJoin(SortMergeJoin),
+    AsofJoin(AsofJoin),
+    SortMergeJoin(SortMergeJoin),
     // BroadcastJoin(BroadcastJoin),
     PhysicalWrite(PhysicalWrite),
     // TabularWriteJson(TabularWriteJson),
@@ -69,17 +75,24 @@ impl LocalPhysicalPlan {
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
             | Self::Filter(Filter { stats_state, .. })
             | Self::Limit(Limit { stats_state, .. })
+            | Self::Offset(Offset { stats_state, .. })
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
             | Self::Sort(Sort { stats_state, .. })
+            | Self::TopN(TopN { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
+            | Self::Repartition(Repartition { stats_state, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { stats_state, .. })
             | Self::HashAggregate(HashAggregate { stats_state, .. })
             | Self::Pivot(Pivot { stats_state, .. })
             | Self::Concat(Concat { stats_state, .. })
+            | Self::RecursiveCte(RecursiveCte { stats_state, .. })
             | Self::HashJoin(HashJoin { stats_state, .. })
             | Self::CrossJoin(CrossJoin { stats_state, .. })
+            | Self::AsofJoin(AsofJoin { stats_state, .. })
+            | Self::SortMergeJoin(SortMergeJoin { stats_state, .. })
             | Self::PhysicalWrite(PhysicalWrite { stats_state, .. }) => stats_state,
             #[cfg(feature = "python")]
             Self::CatalogWrite(CatalogWrite { stats_state, .. })
@@ -151,6 +164,21 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn offset(
+        input: LocalPhysicalPlanRef,
+        num_rows: u64,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::Offset(Offset {
+            input,
+            num_rows,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn explode(
         input: LocalPhysicalPlanRef,
         to_explode: Vec<ExprRef>,
@@ -292,6 +320,27 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn top_n(
+        input: LocalPhysicalPlanRef,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: u64,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::TopN(TopN {
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn sample(
         input: LocalPhysicalPlanRef,
         fraction: f64,
@@ -326,6 +375,40 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn window(
+        input: LocalPhysicalPlanRef,
+        window_functions: Vec<ExprRef>,
+        window_spec: WindowSpec,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::Window(Window {
+            input,
+            window_functions,
+            window_spec,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    pub(crate) fn repartition(
+        input: LocalPhysicalPlanRef,
+        repartition_spec: RepartitionSpec,
+        num_partitions: Option<usize>,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = input.schema().clone();
+        Self::Repartition(Repartition {
+            input,
+            repartition_spec,
+            num_partitions,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn hash_join(
         left: LocalPhysicalPlanRef,
@@ -333,6 +416,7 @@ impl LocalPhysicalPlan {
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
         null_equals_null: Option<Vec<bool>>,
+        residual_predicate: Option<ExprRef>,
         join_type: JoinType,
         schema: SchemaRef,
         stats_state: StatsState,
@@ -343,6 +427,7 @@ impl LocalPhysicalPlan {
             left_on,
             right_on,
             null_equals_null,
+            residual_predicate,
             join_type,
             schema,
             stats_state,
@@ -365,6 +450,48 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn asof_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        asof_options: AsofJoinOptions,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::AsofJoin(AsofJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            asof_options,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
+    pub(crate) fn sort_merge_join(
+        left: LocalPhysicalPlanRef,
+        right: LocalPhysicalPlanRef,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        join_type: JoinType,
+        schema: SchemaRef,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        Self::SortMergeJoin(SortMergeJoin {
+            left,
+            right,
+            left_on,
+            right_on,
+            join_type,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn concat(
         input: LocalPhysicalPlanRef,
         other: LocalPhysicalPlanRef,
@@ -380,6 +507,29 @@ impl LocalPhysicalPlan {
         .arced()
     }
 
+    pub(crate) fn recursive_cte(
+        name: Arc<str>,
+        anchor: LocalPhysicalPlanRef,
+        recursive: LocalPhysicalPlanRef,
+        working_table: String,
+        all_rows_table: Option<String>,
+        max_iterations: usize,
+        stats_state: StatsState,
+    ) -> LocalPhysicalPlanRef {
+        let schema = anchor.schema().clone();
+        Self::RecursiveCte(RecursiveCte {
+            name,
+            anchor,
+            recursive,
+            working_table,
+            all_rows_table,
+            max_iterations,
+            schema,
+            stats_state,
+        })
+        .arced()
+    }
+
     pub(crate) fn physical_write(
         input: LocalPhysicalPlanRef,
         data_schema: SchemaRef,
@@ -439,19 +589,26 @@ impl LocalPhysicalPlan {
             | Self::EmptyScan(EmptyScan { schema, .. })
             | Self::Filter(Filter { schema, .. })
             | Self::Limit(Limit { schema, .. })
+            | Self::Offset(Offset { schema, .. })
             | Self::Project(Project { schema, .. })
             | Self::ActorPoolProject(ActorPoolProject { schema, .. })
             | Self::UnGroupedAggregate(UnGroupedAggregate { schema, .. })
             | Self::HashAggregate(HashAggregate { schema, .. })
             | Self::Pivot(Pivot { schema, .. })
             | Self::Sort(Sort { schema, .. })
+            | Self::TopN(TopN { schema, .. })
             | Self::Sample(Sample { schema, .. })
             | Self::HashJoin(HashJoin { schema, .. })
             | Self::CrossJoin(CrossJoin { schema, .. })
+            | Self::AsofJoin(AsofJoin { schema, .. })
+            | Self::SortMergeJoin(SortMergeJoin { schema, .. })
             | Self::Explode(Explode { schema, .. })
             | Self::Unpivot(Unpivot { schema, .. })
             | Self::Concat(Concat { schema, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => schema,
+            | Self::RecursiveCte(RecursiveCte { schema, .. })
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. })
+            | Self::Window(Window { schema, .. })
+            | Self::Repartition(Repartition { schema, .. }) => schema,
             Self::PhysicalWrite(PhysicalWrite { file_schema, .. }) => file_schema,
             Self::InMemoryScan(InMemoryScan { info, .. }) => &info.source_schema,
             #[cfg(feature = "python")]
@@ -514,6 +671,14 @@ pub struct Limit {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct Offset {
+    pub input: LocalPhysicalPlanRef,
+    pub num_rows: u64,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Explode {
     pub input: LocalPhysicalPlanRef,
@@ -532,6 +697,17 @@ pub struct Sort {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct TopN {
+    pub input: LocalPhysicalPlanRef,
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    pub limit: u64,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Sample {
     pub input: LocalPhysicalPlanRef,
@@ -550,6 +726,41 @@ pub struct MonotonicallyIncreasingId {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct Window {
+    pub input: LocalPhysicalPlanRef,
+    pub window_functions: Vec<ExprRef>,
+    pub window_spec: WindowSpec,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// How a [`Repartition`] assigns rows to output partitions.
+#[derive(Clone, Debug)]
+pub enum RepartitionSpec {
+    /// Rows with the same hash of `by` go to the same partition.
+    Hash { by: Vec<ExprRef> },
+    /// Rows are assigned to partitions at random.
+    Random,
+    /// Rows are assigned to partitions by ranges of `by`, with boundaries sampled from the data.
+    Range {
+        by: Vec<ExprRef>,
+        descending: Vec<bool>,
+    },
+    /// Rows are split into partitions of even size, preserving their order.
+    IntoPartitions,
+}
+
+#[derive(Debug)]
+pub struct Repartition {
+    pub input: LocalPhysicalPlanRef,
+    pub repartition_spec: RepartitionSpec,
+    /// The number of output partitions, defaulting to the number of input partitions.
+    pub num_partitions: Option<usize>,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct UnGroupedAggregate {
     pub input: LocalPhysicalPlanRef,
@@ -597,6 +808,7 @@ pub struct HashJoin {
     pub left_on: Vec<ExprRef>,
     pub right_on: Vec<ExprRef>,
     pub null_equals_null: Option<Vec<bool>>,
+    pub residual_predicate: Option<ExprRef>,
     pub join_type: JoinType,
     pub schema: SchemaRef,
     pub stats_state: StatsState,
@@ -610,6 +822,30 @@ pub struct CrossJoin {
     pub stats_state: StatsState,
 }
 
+#[derive(Debug)]
+pub struct AsofJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<ExprRef>,
+    pub right_on: Vec<ExprRef>,
+    pub asof_options: AsofJoinOptions,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+/// A join which merges its inputs in the order of their join keys, which each input must already
+/// be sorted by in ascending order with nulls last.
+#[derive(Debug)]
+pub struct SortMergeJoin {
+    pub left: LocalPhysicalPlanRef,
+    pub right: LocalPhysicalPlanRef,
+    pub left_on: Vec<ExprRef>,
+    pub right_on: Vec<ExprRef>,
+    pub join_type: JoinType,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct Concat {
     pub input: LocalPhysicalPlanRef,
@@ -618,6 +854,20 @@ pub struct Concat {
     pub stats_state: StatsState,
 }
 
+/// A recursive CTE, whose `recursive` plan is evaluated once per iteration on the rows of the
+/// previous iteration, which it reads from an in-memory scan of `working_table`.
+#[derive(Debug)]
+pub struct RecursiveCte {
+    pub name: Arc<str>,
+    pub anchor: LocalPhysicalPlanRef,
+    pub recursive: LocalPhysicalPlanRef,
+    pub working_table: String,
+    pub all_rows_table: Option<String>,
+    pub max_iterations: usize,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
 #[derive(Debug)]
 pub struct PhysicalWrite {
     pub input: LocalPhysicalPlanRef,
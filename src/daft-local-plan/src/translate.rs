@@ -1,14 +1,27 @@
 use std::sync::Arc;
 
+use common_daft_config::DaftExecutionConfig;
 use common_error::{DaftError, DaftResult};
 use common_scan_info::ScanState;
 use daft_core::join::JoinStrategy;
-use daft_dsl::{join::normalize_join_keys, ExprRef};
-use daft_logical_plan::{JoinType, LogicalPlan, LogicalPlanRef, SourceInfo};
+use daft_dsl::{join::normalize_join_keys, lit, ExprRef};
+use daft_logical_plan::{
+    partitioning::{
+        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RangeRepartitionConfig,
+        RepartitionSpec as LogicalRepartitionSpec,
+    },
+    JoinType, LogicalPlan, LogicalPlanRef, SourceInfo,
+};
 
-use super::plan::{LocalPhysicalPlan, LocalPhysicalPlanRef};
+use super::plan::{
+    Filter, Limit, LocalPhysicalPlan, LocalPhysicalPlanRef, Offset, Project, RepartitionSpec, Sort,
+    SortMergeJoin, TopN,
+};
 
-pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
+pub fn translate(
+    plan: &LogicalPlanRef,
+    cfg: &DaftExecutionConfig,
+) -> DaftResult<LocalPhysicalPlanRef> {
     match plan.as_ref() {
         LogicalPlan::Source(source) => {
             match source.source_info.as_ref() {
@@ -41,7 +54,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             }
         }
         LogicalPlan::Filter(filter) => {
-            let input = translate(&filter.input)?;
+            let input = translate(&filter.input, cfg)?;
             Ok(LocalPhysicalPlan::filter(
                 input,
                 filter.predicate.clone(),
@@ -49,15 +62,23 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Limit(limit) => {
-            let input = translate(&limit.input)?;
+            let input = translate(&limit.input, cfg)?;
             Ok(LocalPhysicalPlan::limit(
                 input,
                 limit.limit,
                 limit.stats_state.clone(),
             ))
         }
+        LogicalPlan::Offset(offset) => {
+            let input = translate(&offset.input, cfg)?;
+            Ok(LocalPhysicalPlan::offset(
+                input,
+                offset.offset,
+                offset.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Project(project) => {
-            let input = translate(&project.input)?;
+            let input = translate(&project.input, cfg)?;
             Ok(LocalPhysicalPlan::project(
                 input,
                 project.projection.clone(),
@@ -66,7 +87,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::ActorPoolProject(actor_pool_project) => {
-            let input = translate(&actor_pool_project.input)?;
+            let input = translate(&actor_pool_project.input, cfg)?;
             Ok(LocalPhysicalPlan::actor_pool_project(
                 input,
                 actor_pool_project.projection.clone(),
@@ -75,7 +96,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Sample(sample) => {
-            let input = translate(&sample.input)?;
+            let input = translate(&sample.input, cfg)?;
             Ok(LocalPhysicalPlan::sample(
                 input,
                 sample.fraction,
@@ -85,7 +106,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Aggregate(aggregate) => {
-            let input = translate(&aggregate.input)?;
+            let input = translate(&aggregate.input, cfg)?;
             if aggregate.groupby.is_empty() {
                 Ok(LocalPhysicalPlan::ungrouped_aggregate(
                     input,
@@ -104,7 +125,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             }
         }
         LogicalPlan::Unpivot(unpivot) => {
-            let input = translate(&unpivot.input)?;
+            let input = translate(&unpivot.input, cfg)?;
             Ok(LocalPhysicalPlan::unpivot(
                 input,
                 unpivot.ids.clone(),
@@ -116,7 +137,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Pivot(pivot) => {
-            let input = translate(&pivot.input)?;
+            let input = translate(&pivot.input, cfg)?;
             Ok(LocalPhysicalPlan::pivot(
                 input,
                 pivot.group_by.clone(),
@@ -129,7 +150,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Sort(sort) => {
-            let input = translate(&sort.input)?;
+            let input = translate(&sort.input, cfg)?;
             Ok(LocalPhysicalPlan::sort(
                 input,
                 sort.sort_by.clone(),
@@ -138,14 +159,87 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 sort.stats_state.clone(),
             ))
         }
+        LogicalPlan::TopN(top_n) => {
+            let input = translate(&top_n.input, cfg)?;
+            Ok(LocalPhysicalPlan::top_n(
+                input,
+                top_n.sort_by.clone(),
+                top_n.descending.clone(),
+                top_n.nulls_first.clone(),
+                top_n.limit,
+                top_n.stats_state.clone(),
+            ))
+        }
+        LogicalPlan::Join(join) if join.join_type == JoinType::AsOf => {
+            let left = translate(&join.left, cfg)?;
+            let right = translate(&join.right, cfg)?;
+
+            let (left_on, right_on) = normalize_join_keys(
+                join.left_on.clone(),
+                join.right_on.clone(),
+                join.left.schema(),
+                join.right.schema(),
+            )?;
+
+            Ok(LocalPhysicalPlan::asof_join(
+                left,
+                right,
+                left_on,
+                right_on,
+                join.asof_options
+                    .clone()
+                    .expect("As-of joins should have as-of options"),
+                join.output_schema.clone(),
+                join.stats_state.clone(),
+            ))
+        }
+        // The merge only supports inner joins on equality conditions, so other sort-merge joins
+        // fall back to a hash join below.
+        LogicalPlan::Join(join)
+            if join.join_strategy == Some(JoinStrategy::SortMerge)
+                && join.join_type == JoinType::Inner
+                && !join.left_on.is_empty()
+                && join.residual_predicate.is_none()
+                && !join
+                    .null_equals_nulls
+                    .as_ref()
+                    .is_some_and(|v| v.iter().any(|b| *b)) =>
+        {
+            let left = translate(&join.left, cfg)?;
+            let right = translate(&join.right, cfg)?;
+
+            let (left_on, right_on) = normalize_join_keys(
+                join.left_on.clone(),
+                join.right_on.clone(),
+                join.left.schema(),
+                join.right.schema(),
+            )?;
+            // Like the distributed runner, inputs are sorted by the join keys unless they are known
+            // to be sorted already. The merge still checks the order of the rows it receives.
+            let left = sort_by_join_keys(left, &left_on, cfg);
+            let right = sort_by_join_keys(right, &right_on, cfg);
+
+            Ok(LocalPhysicalPlan::sort_merge_join(
+                left,
+                right,
+                left_on,
+                right_on,
+                join.join_type,
+                join.output_schema.clone(),
+                join.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Join(join) => {
-            if join.join_strategy.is_some_and(|x| x != JoinStrategy::Hash) {
+            if join
+                .join_strategy
+                .is_some_and(|x| !matches!(x, JoinStrategy::Hash | JoinStrategy::SortMerge))
+            {
                 return Err(DaftError::not_implemented(
-                    "Only hash join is supported for now",
+                    "Only hash and sort-merge joins are supported for now",
                 ));
             }
-            let left = translate(&join.left)?;
-            let right = translate(&join.right)?;
+            let left = translate(&join.left, cfg)?;
+            let right = translate(&join.right, cfg)?;
 
             let (left_on, right_on) = normalize_join_keys(
                 join.left_on.clone(),
@@ -154,13 +248,31 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 join.right.schema(),
             )?;
 
-            if left_on.is_empty() && right_on.is_empty() && join.join_type == JoinType::Inner {
+            if left_on.is_empty()
+                && join.join_type == JoinType::Inner
+                && join.residual_predicate.is_none()
+            {
                 Ok(LocalPhysicalPlan::cross_join(
                     left,
                     right,
                     join.output_schema.clone(),
                     join.stats_state.clone(),
                 ))
+            } else if left_on.is_empty() {
+                // Without join keys, fall back to a nested loop join by hashing every row of both
+                // sides to the same key, which matches each probe row with all build rows. The
+                // residual predicate is evaluated on bounded chunks of these pairs while probing.
+                Ok(LocalPhysicalPlan::hash_join(
+                    left,
+                    right,
+                    vec![lit(true)],
+                    vec![lit(true)],
+                    None,
+                    join.residual_predicate.clone(),
+                    join.join_type,
+                    join.output_schema.clone(),
+                    join.stats_state.clone(),
+                ))
             } else {
                 Ok(LocalPhysicalPlan::hash_join(
                     left,
@@ -168,6 +280,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                     left_on,
                     right_on,
                     join.null_equals_nulls.clone(),
+                    join.residual_predicate.clone(),
                     join.join_type,
                     join.output_schema.clone(),
                     join.stats_state.clone(),
@@ -176,7 +289,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         }
         LogicalPlan::Distinct(distinct) => {
             let schema = distinct.input.schema();
-            let input = translate(&distinct.input)?;
+            let input = translate(&distinct.input, cfg)?;
             let col_exprs = input
                 .schema()
                 .names()
@@ -192,20 +305,60 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             ))
         }
         LogicalPlan::Concat(concat) => {
-            let input = translate(&concat.input)?;
-            let other = translate(&concat.other)?;
+            let input = translate(&concat.input, cfg)?;
+            let other = translate(&concat.other, cfg)?;
             Ok(LocalPhysicalPlan::concat(
                 input,
                 other,
                 concat.stats_state.clone(),
             ))
         }
+        LogicalPlan::RecursiveCte(recursive_cte) => {
+            let anchor = translate(&recursive_cte.anchor, cfg)?;
+            let recursive = translate(&recursive_cte.recursive, cfg)?;
+            Ok(LocalPhysicalPlan::recursive_cte(
+                recursive_cte.name.clone(),
+                anchor,
+                recursive,
+                recursive_cte.working_table.clone(),
+                recursive_cte.all_rows_table.clone(),
+                recursive_cte.max_iterations,
+                recursive_cte.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Repartition(repartition) => {
-            log::warn!("Repartition not supported on the NativeRunner. This will be a no-op. Please use the RayRunner instead if you need to repartition");
-            translate(&repartition.input)
+            let input = translate(&repartition.input, cfg)?;
+            let (repartition_spec, num_partitions) = match &repartition.repartition_spec {
+                LogicalRepartitionSpec::Hash(HashRepartitionConfig { num_partitions, by }) => {
+                    (RepartitionSpec::Hash { by: by.clone() }, *num_partitions)
+                }
+                LogicalRepartitionSpec::Random(RandomShuffleConfig { num_partitions }) => {
+                    (RepartitionSpec::Random, *num_partitions)
+                }
+                LogicalRepartitionSpec::Range(RangeRepartitionConfig {
+                    num_partitions,
+                    by,
+                    descending,
+                }) => (
+                    RepartitionSpec::Range {
+                        by: by.clone(),
+                        descending: descending.clone(),
+                    },
+                    *num_partitions,
+                ),
+                LogicalRepartitionSpec::IntoPartitions(IntoPartitionsConfig { num_partitions }) => {
+                    (RepartitionSpec::IntoPartitions, Some(*num_partitions))
+                }
+            };
+            Ok(LocalPhysicalPlan::repartition(
+                input,
+                repartition_spec,
+                num_partitions,
+                repartition.stats_state.clone(),
+            ))
         }
         LogicalPlan::MonotonicallyIncreasingId(monotonically_increasing_id) => {
-            let input = translate(&monotonically_increasing_id.input)?;
+            let input = translate(&monotonically_increasing_id.input, cfg)?;
             Ok(LocalPhysicalPlan::monotonically_increasing_id(
                 input,
                 monotonically_increasing_id.column_name.clone(),
@@ -213,9 +366,19 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
                 monotonically_increasing_id.stats_state.clone(),
             ))
         }
+        LogicalPlan::Window(window) => {
+            let input = translate(&window.input, cfg)?;
+            Ok(LocalPhysicalPlan::window(
+                input,
+                window.window_functions.clone(),
+                window.window_spec.clone(),
+                window.schema.clone(),
+                window.stats_state.clone(),
+            ))
+        }
         LogicalPlan::Sink(sink) => {
             use daft_logical_plan::SinkInfo;
-            let input = translate(&sink.input)?;
+            let input = translate(&sink.input, cfg)?;
             let data_schema = input.schema().clone();
             match sink.sink_info.as_ref() {
                 SinkInfo::OutputFileInfo(info) => Ok(LocalPhysicalPlan::physical_write(
@@ -250,7 +413,7 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
             }
         }
         LogicalPlan::Explode(explode) => {
-            let input = translate(&explode.input)?;
+            let input = translate(&explode.input, cfg)?;
             Ok(LocalPhysicalPlan::explode(
                 input,
                 explode.to_explode.clone(),
@@ -269,3 +432,69 @@ pub fn translate(plan: &LogicalPlanRef) -> DaftResult<LocalPhysicalPlanRef> {
         )),
     }
 }
+
+/// Sorts the input of a sort-merge join by its join keys in ascending order with nulls last, unless
+/// it is known to be ordered that way already.
+fn sort_by_join_keys(
+    input: LocalPhysicalPlanRef,
+    keys: &[ExprRef],
+    cfg: &DaftExecutionConfig,
+) -> LocalPhysicalPlanRef {
+    if is_sorted_by(&input, keys, cfg) {
+        return input;
+    }
+    let stats_state = input.get_stats_state().clone();
+    LocalPhysicalPlan::sort(
+        input,
+        keys.to_vec(),
+        vec![false; keys.len()],
+        vec![false; keys.len()],
+        stats_state,
+    )
+}
+
+/// Returns whether the rows of the plan are known to be sorted by `keys` in ascending order with
+/// nulls last, either from an operator which orders them that way or because the user declared
+/// that scans are sorted by their join keys.
+fn is_sorted_by(plan: &LocalPhysicalPlan, keys: &[ExprRef], cfg: &DaftExecutionConfig) -> bool {
+    let is_prefix = |sort_by: &[ExprRef], descending: &[bool], nulls_first: &[bool]| {
+        sort_by.len() >= keys.len()
+            && sort_by.iter().zip(keys).all(|(by, key)| by == key)
+            && descending.iter().take(keys.len()).all(|desc| !*desc)
+            && nulls_first.iter().take(keys.len()).all(|first| !*first)
+    };
+    match plan {
+        LocalPhysicalPlan::Sort(Sort {
+            sort_by,
+            descending,
+            nulls_first,
+            ..
+        })
+        | LocalPhysicalPlan::TopN(TopN {
+            sort_by,
+            descending,
+            nulls_first,
+            ..
+        }) => is_prefix(sort_by, descending, nulls_first),
+        // The merge emits its rows in the order of the join keys of either side, which are equal.
+        LocalPhysicalPlan::SortMergeJoin(SortMergeJoin {
+            left_on, right_on, ..
+        }) => {
+            let ascending = vec![false; keys.len()];
+            is_prefix(left_on, &ascending, &ascending)
+                || is_prefix(right_on, &ascending, &ascending)
+        }
+        // These operators keep the order of the rows they pass on.
+        LocalPhysicalPlan::Filter(Filter { input, .. })
+        | LocalPhysicalPlan::Limit(Limit { input, .. })
+        | LocalPhysicalPlan::Offset(Offset { input, .. }) => is_sorted_by(input, keys, cfg),
+        // A projection keeps the order if it passes the keys through unchanged.
+        LocalPhysicalPlan::Project(Project {
+            input, projection, ..
+        }) => keys.iter().all(|key| projection.contains(key)) && is_sorted_by(input, keys, cfg),
+        LocalPhysicalPlan::PhysicalScan(..) | LocalPhysicalPlan::InMemoryScan(..) => {
+            cfg.sort_merge_join_presorted_scans
+        }
+        _ => false,
+    }
+}
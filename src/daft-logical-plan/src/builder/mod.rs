@@ -13,7 +13,8 @@ use common_file_formats::FileFormat;
 use common_io_config::IOConfig;
 use common_scan_info::{PhysicalScanInfo, Pushdowns, ScanOperatorRef};
 use daft_core::join::{JoinStrategy, JoinType};
-use daft_dsl::{resolved_col, ExprRef};
+use daft_dsl::{exprs_to_schema, join::AsofJoinOptions, lit, null_lit, resolved_col, ExprRef};
+use daft_functions::grouping::GROUPING_ID_COLUMN;
 use daft_schema::schema::{Schema, SchemaRef};
 use indexmap::IndexSet;
 use resolve_expr::ExprResolver;
@@ -21,7 +22,8 @@ use resolve_expr::ExprResolver;
 use {
     crate::sink_info::{CatalogInfo, IcebergCatalogInfo},
     common_daft_config::PyDaftPlanningConfig,
-    daft_dsl::python::PyExpr,
+    daft_core::join::AsofDirection,
+    daft_dsl::{python::PyExpr, Expr},
     // daft_scan::python::pylib::ScanOperatorHandle,
     daft_schema::python::schema::PySchema,
     pyo3::intern,
@@ -33,7 +35,8 @@ use crate::{
     ops::{self, join::JoinOptions, SetQuantifier, UnionStrategy},
     optimization::OptimizerBuilder,
     partitioning::{
-        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RepartitionSpec,
+        HashRepartitionConfig, IntoPartitionsConfig, RandomShuffleConfig, RangeRepartitionConfig,
+        RepartitionSpec,
     },
     sink_info::{OutputFileInfo, SinkInfo},
     source_info::{InMemoryInfo, SourceInfo},
@@ -154,6 +157,24 @@ impl LogicalPlanBuilder {
         Ok(Self::from(Arc::new(logical_plan)))
     }
 
+    /// Creates an in-memory scan of the working table of a recursive CTE, whose partitions are
+    /// provided by the executor when it evaluates the [`ops::RecursiveCte`] reading it.
+    pub fn working_table_scan(working_table: &str, schema: SchemaRef) -> Self {
+        let source_info = SourceInfo::InMemory(InMemoryInfo::new(
+            schema.clone(),
+            working_table.into(),
+            None,
+            1,
+            0,
+            0,
+            None,
+            None,
+        ));
+        let logical_plan: LogicalPlan = ops::Source::new(schema, source_info.into()).into();
+
+        Self::from(Arc::new(logical_plan))
+    }
+
     /// Creates a `LogicalPlan::Source` from a scan handle.
     pub fn table_scan(
         scan_operator: ScanOperatorRef,
@@ -203,6 +224,16 @@ impl LogicalPlanBuilder {
         } else {
             schema_with_generated_fields
         };
+        // If aggregation pushdown is specified, the scan yields partial aggregation results instead of the data.
+        let output_schema = if let Some(Pushdowns {
+            aggregations: Some(aggregations),
+            ..
+        }) = &pushdowns
+        {
+            exprs_to_schema(aggregations, output_schema)?
+        } else {
+            output_schema
+        };
         let logical_plan: LogicalPlan = ops::Source::new(output_schema, source_info.into()).into();
         Ok(Self::from(Arc::new(logical_plan)))
     }
@@ -211,6 +242,7 @@ impl LogicalPlanBuilder {
         let expr_resolver = ExprResolver::builder()
             .allow_actor_pool_udf(true)
             .allow_monotonic_id(true)
+            .allow_window_function(true)
             .build();
 
         let to_select = expr_resolver.resolve(to_select, self.plan.clone())?;
@@ -223,6 +255,7 @@ impl LogicalPlanBuilder {
         let expr_resolver = ExprResolver::builder()
             .allow_actor_pool_udf(true)
             .allow_monotonic_id(true)
+            .allow_window_function(true)
             .build();
 
         let columns = expr_resolver.resolve(columns, self.plan.clone())?;
@@ -321,6 +354,11 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    pub fn offset(&self, offset: u64) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::Offset::new(self.plan.clone(), offset).into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn explode(&self, to_explode: Vec<ExprRef>) -> DaftResult<Self> {
         let expr_resolver = ExprResolver::default();
 
@@ -395,6 +433,35 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    pub fn range_repartition(
+        &self,
+        num_partitions: Option<usize>,
+        partition_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+    ) -> DaftResult<Self> {
+        let expr_resolver = ExprResolver::default();
+
+        let partition_by = expr_resolver.resolve(partition_by, self.plan.clone())?;
+        if partition_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected as many descending flags as range repartition keys, got {} and {}",
+                descending.len(),
+                partition_by.len()
+            )));
+        }
+
+        let logical_plan: LogicalPlan = ops::Repartition::new(
+            self.plan.clone(),
+            RepartitionSpec::Range(RangeRepartitionConfig::new(
+                num_partitions,
+                partition_by,
+                descending,
+            )),
+        )
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> DaftResult<Self> {
         let logical_plan: LogicalPlan = ops::Repartition::new(
             self.plan.clone(),
@@ -484,6 +551,71 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Aggregates over each of the grouping sets and unions the results, which is how
+    /// `GROUPING SETS`, `ROLLUP` and `CUBE` are planned.
+    ///
+    /// The output has a column for each of the `group_by` expressions, which is null in the rows
+    /// of grouping sets without that expression, followed by the aggregations and the
+    /// [`GROUPING_ID_COLUMN`] identifying the grouping set of each row. Each grouping set must be
+    /// a subset of `group_by`.
+    pub fn grouping_sets_aggregate(
+        &self,
+        agg_exprs: Vec<ExprRef>,
+        group_by: Vec<ExprRef>,
+        grouping_sets: Vec<Vec<ExprRef>>,
+    ) -> DaftResult<Self> {
+        let group_by: IndexSet<ExprRef> = group_by.into_iter().collect();
+        if let Some(expr) = grouping_sets
+            .iter()
+            .flatten()
+            .find(|expr| !group_by.contains(*expr))
+        {
+            return Err(DaftError::ValueError(format!(
+                "Grouping set expression {expr} is not one of the grouping expressions"
+            )));
+        }
+        let schema = self.schema();
+        let group_by_fields = ExprResolver::default()
+            .resolve(group_by.iter().cloned().collect(), self.plan.clone())?
+            .iter()
+            .map(|expr| expr.to_field(&schema))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut result: Option<Self> = None;
+        for grouping_set in grouping_sets {
+            let grouping_set: IndexSet<ExprRef> = grouping_set.into_iter().collect();
+            let aggregated =
+                self.aggregate(agg_exprs.clone(), grouping_set.iter().cloned().collect())?;
+
+            let mut grouping_id = 0i64;
+            let mut projection = Vec::with_capacity(group_by.len() + agg_exprs.len() + 1);
+            for (expr, field) in group_by.iter().zip(&group_by_fields) {
+                grouping_id <<= 1;
+                if grouping_set.contains(expr) {
+                    projection.push(resolved_col(field.name.as_str()));
+                } else {
+                    grouping_id |= 1;
+                    projection.push(null_lit().cast(&field.dtype).alias(field.name.as_str()));
+                }
+            }
+            // the aggregations follow the grouping columns
+            projection.extend(
+                aggregated.schema().names()[grouping_set.len()..]
+                    .iter()
+                    .map(|name| resolved_col(name.as_str())),
+            );
+            projection.push(lit(grouping_id).alias(GROUPING_ID_COLUMN));
+
+            let aggregated = aggregated.select(projection)?;
+            result = Some(match result {
+                Some(result) => result.concat(&aggregated)?,
+                None => aggregated,
+            });
+        }
+        result
+            .ok_or_else(|| DaftError::ValueError("Expected at least one grouping set".to_string()))
+    }
+
     pub fn pivot(
         &self,
         group_by: Vec<ExprRef>,
@@ -561,6 +693,40 @@ impl LogicalPlanBuilder {
         join_strategy: Option<JoinStrategy>,
         options: JoinOptions,
     ) -> DaftResult<Self> {
+        self.join_with_residual_predicate(
+            right,
+            left_on,
+            right_on,
+            null_equals_nulls,
+            None,
+            join_type,
+            join_strategy,
+            options,
+        )
+    }
+
+    /// Join with a predicate which pairs of rows matching on the join keys must also satisfy.
+    ///
+    /// The columns of the residual predicate may refer to either side of the join, and it can be
+    /// used without join keys to join on arbitrary conditions.
+    #[allow(clippy::too_many_arguments)]
+    pub fn join_with_residual_predicate<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        null_equals_nulls: Option<Vec<bool>>,
+        residual_predicate: Option<ExprRef>,
+        join_type: JoinType,
+        join_strategy: Option<JoinStrategy>,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        if join_type == JoinType::AsOf {
+            return Err(DaftError::ValueError(
+                "As-of joins must be created with `asof_join`".to_string(),
+            ));
+        }
+
         let left_plan = self.plan.clone();
         let right_plan = right.into();
 
@@ -568,10 +734,26 @@ impl LogicalPlanBuilder {
 
         let left_on = expr_resolver.resolve(left_on, left_plan.clone())?;
         let right_on = expr_resolver.resolve(right_on, right_plan.clone())?;
+        let residual_predicate = residual_predicate
+            .map(|predicate| {
+                expr_resolver.resolve_join_predicate(
+                    predicate,
+                    left_plan.clone(),
+                    right_plan.clone(),
+                )
+            })
+            .transpose()?;
 
-        let (left_plan, right_plan, left_on, right_on) = ops::join::Join::deduplicate_join_columns(
-            left_plan, right_plan, left_on, right_on, join_type, options,
-        )?;
+        let (left_plan, right_plan, left_on, right_on, residual_predicate) =
+            ops::join::Join::deduplicate_join_columns(
+                left_plan,
+                right_plan,
+                left_on,
+                right_on,
+                residual_predicate,
+                join_type,
+                options,
+            )?;
 
         let logical_plan: LogicalPlan = ops::Join::try_new(
             left_plan,
@@ -582,6 +764,50 @@ impl LogicalPlanBuilder {
             join_type,
             join_strategy,
         )?
+        .with_residual_predicate(residual_predicate)?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
+    /// As-of join, which matches each row with the right row nearest to it on the last join key,
+    /// among the right rows equal to it on the other join keys.
+    pub fn asof_join<Right: Into<LogicalPlanRef>>(
+        &self,
+        right: Right,
+        left_on: Vec<ExprRef>,
+        right_on: Vec<ExprRef>,
+        asof_options: AsofJoinOptions,
+        options: JoinOptions,
+    ) -> DaftResult<Self> {
+        let left_plan = self.plan.clone();
+        let right_plan = right.into();
+
+        let expr_resolver = ExprResolver::default();
+
+        let left_on = expr_resolver.resolve(left_on, left_plan.clone())?;
+        let right_on = expr_resolver.resolve(right_on, right_plan.clone())?;
+
+        let (left_plan, right_plan, left_on, right_on, _) =
+            ops::join::Join::deduplicate_join_columns(
+                left_plan,
+                right_plan,
+                left_on,
+                right_on,
+                None,
+                JoinType::AsOf,
+                options,
+            )?;
+
+        let logical_plan: LogicalPlan = ops::Join::try_new(
+            left_plan,
+            right_plan,
+            left_on,
+            right_on,
+            None,
+            JoinType::AsOf,
+            Some(JoinStrategy::SortMerge),
+        )?
+        .with_asof_options(Some(asof_options))?
         .into();
         Ok(self.with_new_plan(logical_plan))
     }
@@ -600,6 +826,28 @@ impl LogicalPlanBuilder {
         Ok(self.with_new_plan(logical_plan))
     }
 
+    /// Creates a recursive CTE with this plan as its anchor, whose `recursive` term reads the rows
+    /// of the previous iteration from a [`Self::working_table_scan`] of `working_table`.
+    pub fn recursive_cte(
+        &self,
+        name: impl Into<Arc<str>>,
+        recursive: &Self,
+        working_table: String,
+        all_rows_table: Option<String>,
+        max_iterations: usize,
+    ) -> DaftResult<Self> {
+        let logical_plan: LogicalPlan = ops::RecursiveCte::try_new(
+            name.into(),
+            self.plan.clone(),
+            recursive.plan.clone(),
+            working_table,
+            all_rows_table,
+            max_iterations,
+        )?
+        .into();
+        Ok(self.with_new_plan(logical_plan))
+    }
+
     pub fn intersect(&self, other: &Self, is_all: bool) -> DaftResult<Self> {
         let logical_plan: LogicalPlan =
             ops::Intersect::try_new(self.plan.clone(), other.plan.clone(), is_all)?
@@ -992,6 +1240,19 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[pyo3(signature = (partition_by, descending, num_partitions=None))]
+    pub fn range_repartition(
+        &self,
+        partition_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+        num_partitions: Option<usize>,
+    ) -> PyResult<Self> {
+        Ok(self
+            .builder
+            .range_repartition(num_partitions, pyexprs_to_exprs(partition_by), descending)?
+            .into())
+    }
+
     #[pyo3(signature = (num_partitions=None))]
     pub fn random_shuffle(&self, num_partitions: Option<usize>) -> PyResult<Self> {
         Ok(self.builder.random_shuffle(num_partitions)?.into())
@@ -1089,6 +1350,53 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        right,
+        left_on,
+        right_on,
+        direction,
+        tolerance=None,
+        prefix=None,
+        suffix=None,
+    ))]
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_on: Vec<PyExpr>,
+        right_on: Vec<PyExpr>,
+        direction: AsofDirection,
+        tolerance: Option<PyExpr>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+    ) -> PyResult<Self> {
+        let tolerance = tolerance
+            .map(|tolerance| match tolerance.expr.as_ref() {
+                Expr::Literal(value) => Ok(value.clone()),
+                expr => Err(DaftError::ValueError(format!(
+                    "Expected the tolerance of an as-of join to be a literal, received: {expr}"
+                ))),
+            })
+            .transpose()?;
+        Ok(self
+            .builder
+            .asof_join(
+                &right.builder,
+                pyexprs_to_exprs(left_on),
+                pyexprs_to_exprs(right_on),
+                AsofJoinOptions {
+                    direction,
+                    tolerance,
+                },
+                JoinOptions {
+                    prefix,
+                    suffix,
+                    merge_matching_join_keys: true,
+                },
+            )?
+            .into())
+    }
+
     pub fn concat(&self, other: &Self) -> DaftResult<Self> {
         Ok(self.builder.concat(&other.builder)?.into())
     }
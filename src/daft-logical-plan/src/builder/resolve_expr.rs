@@ -2,11 +2,11 @@ use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
 use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
-use daft_core::prelude::*;
+use daft_core::{join::JoinSide, prelude::*};
 use daft_dsl::{
     functions::{struct_::StructExpr, FunctionExpr},
-    has_agg, is_actor_pool_udf, resolved_col, AggExpr, Column, Expr, ExprRef, PlanRef,
-    ResolvedColumn, UnresolvedColumn,
+    has_agg, has_window, is_actor_pool_udf, join_side_col, resolved_col, AggExpr, Column, Expr,
+    ExprRef, PlanRef, ResolvedColumn, UnresolvedColumn,
 };
 use typed_builder::TypedBuilder;
 
@@ -176,6 +176,57 @@ fn resolve_unresolved_columns(expr: ExprRef, plan: LogicalPlanRef) -> DaftResult
     })?.data)
 }
 
+/// Returns whether the column belongs to the plan, erroring if it refers to the plan but is not in it.
+fn column_in_plan(name: &str, plan_ref: &PlanRef, plan: &LogicalPlanRef) -> DaftResult<bool> {
+    let schema = match plan_ref {
+        PlanRef::Alias(alias) => plan.clone().get_schema_for_alias(alias)?,
+        PlanRef::Id(id) => plan.clone().get_schema_for_id(*id)?,
+        PlanRef::Unqualified => return Ok(plan.schema().has_field(name)),
+    };
+    match schema {
+        Some(schema) => schema.get_field(name).map(|_| true),
+        None => Ok(false),
+    }
+}
+
+/// Resolves the columns of a join predicate to the side of the join they belong to.
+fn resolve_join_side_columns(
+    expr: ExprRef,
+    left_plan: LogicalPlanRef,
+    right_plan: LogicalPlanRef,
+) -> DaftResult<ExprRef> {
+    Ok(expr.transform(|e| {
+        let (name, plan_ref) = match e.as_ref() {
+            Expr::Column(Column::Unresolved(UnresolvedColumn { name, plan_ref, .. })) => (name, plan_ref),
+            Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => (name, &PlanRef::Unqualified),
+            Expr::Column(Column::Resolved(ResolvedColumn::OuterRef(Field { name, .. }))) => {
+                return Err(DaftError::ValueError(format!(
+                    "Join predicates cannot reference outer column {name}"
+                )))
+            }
+            _ => return Ok(Transformed::no(e)),
+        };
+        let side = match (
+            column_in_plan(name, plan_ref, &left_plan)?,
+            column_in_plan(name, plan_ref, &right_plan)?,
+        ) {
+            (true, false) => JoinSide::Left,
+            (false, true) => JoinSide::Right,
+            (true, true) => {
+                return Err(DaftError::ValueError(format!(
+                    "Column {name} in join predicate is ambiguous, as it exists on both sides of the join"
+                )))
+            }
+            (false, false) => {
+                return Err(DaftError::FieldNotFound(format!(
+                    "Column {name} in join predicate not found on either side of the join"
+                )))
+            }
+        };
+        Ok(Transformed::yes(join_side_col(name.clone(), side)))
+    })?.data)
+}
+
 fn convert_udfs_to_map_groups(expr: &ExprRef) -> ExprRef {
     expr.clone()
         .transform(|e| match e.as_ref() {
@@ -200,6 +251,8 @@ pub struct ExprResolver<'a> {
     allow_actor_pool_udf: bool,
     #[builder(default)]
     allow_monotonic_id: bool,
+    #[builder(default)]
+    allow_window_function: bool,
     #[builder(via_mutators, mutators(
         pub fn in_agg_context(&mut self, in_agg_context: bool) {
             // workaround since typed_builder can't have defaults for mutator requirements
@@ -217,19 +270,31 @@ pub struct ExprResolver<'a> {
 }
 
 impl ExprResolver<'_> {
-    fn resolve_helper(&self, expr: ExprRef, plan: LogicalPlanRef) -> DaftResult<Vec<ExprRef>> {
+    fn check_allowed(&self, expr: &ExprRef) -> DaftResult<()> {
         if !self.allow_actor_pool_udf && expr.exists(is_actor_pool_udf) {
             return Err(DaftError::ValueError(format!(
                 "UDFs with concurrency set are only allowed in projections: {expr}"
             )));
         }
 
-        if !self.allow_monotonic_id && contains_monotonic_id(&expr) {
+        if !self.allow_monotonic_id && contains_monotonic_id(expr) {
             return Err(DaftError::ValueError(
                 "monotonically_increasing_id() is only allowed in projections".to_string(),
             ));
         }
 
+        if !self.allow_window_function && has_window(expr) {
+            return Err(DaftError::ValueError(format!(
+                "Window functions are only allowed in projections: {expr}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn resolve_helper(&self, expr: ExprRef, plan: LogicalPlanRef) -> DaftResult<Vec<ExprRef>> {
+        self.check_allowed(&expr)?;
+
         expand_wildcard(expr, plan.clone())?
             .into_iter()
             .map(|e| resolve_unresolved_columns(e, plan.clone()))
@@ -266,6 +331,18 @@ impl ExprResolver<'_> {
         }
     }
 
+    /// Resolve a join predicate, whose columns are resolved to the side of the join they belong to.
+    pub fn resolve_join_predicate(
+        &self,
+        expr: ExprRef,
+        left_plan: LogicalPlanRef,
+        right_plan: LogicalPlanRef,
+    ) -> DaftResult<ExprRef> {
+        self.check_allowed(&expr)?;
+        let expr = resolve_join_side_columns(expr, left_plan, right_plan)?;
+        self.validate_expr(expr)
+    }
+
     fn validate_expr(&self, expr: ExprRef) -> DaftResult<ExprRef> {
         if has_agg(&expr) {
             return Err(DaftError::ValueError(format!(
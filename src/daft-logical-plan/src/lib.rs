@@ -18,8 +18,8 @@ mod treenode;
 pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
 #[cfg(feature = "python")]
 use common_file_formats::{
-    python::PyFileFormatConfig, CsvSourceConfig, DatabaseSourceConfig, JsonSourceConfig,
-    ParquetSourceConfig, WarcSourceConfig,
+    python::PyFileFormatConfig, AvroSourceConfig, CsvSourceConfig, DatabaseSourceConfig,
+    IpcSourceConfig, JsonSourceConfig, OrcSourceConfig, ParquetSourceConfig, WarcSourceConfig,
 };
 pub use daft_core::join::{JoinStrategy, JoinType};
 pub use logical_plan::{LogicalPlan, LogicalPlanRef};
@@ -39,6 +39,9 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
     parent.add_class::<ParquetSourceConfig>()?;
     parent.add_class::<JsonSourceConfig>()?;
     parent.add_class::<WarcSourceConfig>()?;
+    parent.add_class::<IpcSourceConfig>()?;
+    parent.add_class::<AvroSourceConfig>()?;
+    parent.add_class::<OrcSourceConfig>()?;
     parent.add_class::<CsvSourceConfig>()?;
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<FileInfos>()?;
@@ -8,7 +8,9 @@ use std::{
 use common_display::ascii::AsciiTreeDisplay;
 use common_error::{DaftError, DaftResult};
 use common_treenode::TreeNodeRecursion;
-use daft_dsl::{optimization::get_required_columns, Subquery, SubqueryPlan};
+use daft_dsl::{
+    join::join_predicate_columns, optimization::get_required_columns, Subquery, SubqueryPlan,
+};
 use daft_schema::schema::SchemaRef;
 use indexmap::IndexSet;
 use snafu::Snafu;
@@ -24,9 +26,11 @@ pub enum LogicalPlan {
     ActorPoolProject(ActorPoolProject),
     Filter(Filter),
     Limit(Limit),
+    Offset(Offset),
     Explode(Explode),
     Unpivot(Unpivot),
     Sort(Sort),
+    TopN(TopN),
     Repartition(Repartition),
     Distinct(Distinct),
     Aggregate(Aggregate),
@@ -38,6 +42,8 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
+    RecursiveCte(RecursiveCte),
     SubqueryAlias(SubqueryAlias),
 }
 
@@ -85,11 +91,13 @@ impl LogicalPlan {
             }) => projected_schema.clone(),
             Self::Filter(Filter { input, .. }) => input.schema(),
             Self::Limit(Limit { input, .. }) => input.schema(),
+            Self::Offset(Offset { input, .. }) => input.schema(),
             Self::Explode(Explode {
                 exploded_schema, ..
             }) => exploded_schema.clone(),
             Self::Unpivot(Unpivot { output_schema, .. }) => output_schema.clone(),
             Self::Sort(Sort { input, .. }) => input.schema(),
+            Self::TopN(TopN { input, .. }) => input.schema(),
             Self::Repartition(Repartition { input, .. }) => input.schema(),
             Self::Distinct(Distinct { input, .. }) => input.schema(),
             Self::Aggregate(Aggregate { output_schema, .. }) => output_schema.clone(),
@@ -103,6 +111,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { schema, .. }) => schema.clone(),
+            Self::RecursiveCte(RecursiveCte { anchor, .. }) => anchor.schema(),
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => input.schema(),
         }
     }
@@ -111,9 +121,11 @@ impl LogicalPlan {
         // TODO: https://github.com/Eventual-Inc/Daft/pull/1288#discussion_r1307820697
         match self {
             Self::Limit(..) => vec![IndexSet::new()],
+            Self::Offset(..) => vec![IndexSet::new()],
             Self::Sample(..) => vec![IndexSet::new()],
             Self::MonotonicallyIncreasingId(..) => vec![IndexSet::new()],
             Self::Concat(..) => vec![IndexSet::new(), IndexSet::new()],
+            Self::RecursiveCte(..) => vec![IndexSet::new(), IndexSet::new()],
             Self::Project(projection) => {
                 let res = projection
                     .projection
@@ -136,6 +148,14 @@ impl LogicalPlan {
                 let res = sort.sort_by.iter().flat_map(get_required_columns).collect();
                 vec![res]
             }
+            Self::TopN(top_n) => {
+                let res = top_n
+                    .sort_by
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Repartition(repartition) => {
                 let res = repartition
                     .repartition_spec
@@ -183,6 +203,14 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::Window(window) => {
+                let res = window
+                    .window_functions
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect();
+                vec![res]
+            }
             Self::Pivot(pivot) => {
                 let res = pivot
                     .group_by
@@ -194,12 +222,18 @@ impl LogicalPlan {
                 vec![res]
             }
             Self::Join(join) => {
-                let left = join.left_on.iter().flat_map(get_required_columns).collect();
-                let right = join
+                let mut left: IndexSet<_> =
+                    join.left_on.iter().flat_map(get_required_columns).collect();
+                let mut right: IndexSet<_> = join
                     .right_on
                     .iter()
                     .flat_map(get_required_columns)
                     .collect();
+                if let Some(predicate) = &join.residual_predicate {
+                    let (predicate_left, predicate_right) = join_predicate_columns(predicate);
+                    left.extend(predicate_left);
+                    right.extend(predicate_right);
+                }
                 vec![left, right]
             }
             Self::Intersect(_) => vec![IndexSet::new(), IndexSet::new()],
@@ -217,9 +251,11 @@ impl LogicalPlan {
             Self::ActorPoolProject(..) => "ActorPoolProject",
             Self::Filter(..) => "Filter",
             Self::Limit(..) => "Limit",
+            Self::Offset(..) => "Offset",
             Self::Explode(..) => "Explode",
             Self::Unpivot(..) => "Unpivot",
             Self::Sort(..) => "Sort",
+            Self::TopN(..) => "TopN",
             Self::Repartition(..) => "Repartition",
             Self::Distinct(..) => "Distinct",
             Self::Aggregate(..) => "Aggregate",
@@ -231,6 +267,8 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
+            Self::RecursiveCte(..) => "RecursiveCte",
             Self::SubqueryAlias(..) => "Alias",
         }
     }
@@ -242,9 +280,11 @@ impl LogicalPlan {
             | Self::ActorPoolProject(ActorPoolProject { stats_state, .. })
             | Self::Filter(Filter { stats_state, .. })
             | Self::Limit(Limit { stats_state, .. })
+            | Self::Offset(Offset { stats_state, .. })
             | Self::Explode(Explode { stats_state, .. })
             | Self::Unpivot(Unpivot { stats_state, .. })
             | Self::Sort(Sort { stats_state, .. })
+            | Self::TopN(TopN { stats_state, .. })
             | Self::Repartition(Repartition { stats_state, .. })
             | Self::Distinct(Distinct { stats_state, .. })
             | Self::Aggregate(Aggregate { stats_state, .. })
@@ -253,9 +293,9 @@ impl LogicalPlan {
             | Self::Join(Join { stats_state, .. })
             | Self::Sink(Sink { stats_state, .. })
             | Self::Sample(Sample { stats_state, .. })
-            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. }) => {
-                stats_state
-            }
+            | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { stats_state, .. })
+            | Self::Window(Window { stats_state, .. })
+            | Self::RecursiveCte(RecursiveCte { stats_state, .. }) => stats_state,
             Self::Intersect(_) => {
                 panic!("Intersect nodes should be optimized away before stats are materialized")
             }
@@ -281,9 +321,11 @@ impl LogicalPlan {
             Self::ActorPoolProject(plan) => Self::ActorPoolProject(plan.with_materialized_stats()),
             Self::Filter(plan) => Self::Filter(plan.with_materialized_stats()),
             Self::Limit(plan) => Self::Limit(plan.with_materialized_stats()),
+            Self::Offset(plan) => Self::Offset(plan.with_materialized_stats()),
             Self::Explode(plan) => Self::Explode(plan.with_materialized_stats()),
             Self::Unpivot(plan) => Self::Unpivot(plan.with_materialized_stats()),
             Self::Sort(plan) => Self::Sort(plan.with_materialized_stats()),
+            Self::TopN(plan) => Self::TopN(plan.with_materialized_stats()),
             Self::Repartition(plan) => Self::Repartition(plan.with_materialized_stats()),
             Self::Distinct(plan) => Self::Distinct(plan.with_materialized_stats()),
             Self::Aggregate(plan) => Self::Aggregate(plan.with_materialized_stats()),
@@ -304,6 +346,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(plan) => {
                 Self::MonotonicallyIncreasingId(plan.with_materialized_stats())
             }
+            Self::Window(plan) => Self::Window(plan.with_materialized_stats()),
+            Self::RecursiveCte(plan) => Self::RecursiveCte(plan.with_materialized_stats()),
         }
     }
 
@@ -314,9 +358,11 @@ impl LogicalPlan {
             Self::ActorPoolProject(projection) => projection.multiline_display(),
             Self::Filter(filter) => filter.multiline_display(),
             Self::Limit(limit) => limit.multiline_display(),
+            Self::Offset(offset) => offset.multiline_display(),
             Self::Explode(explode) => explode.multiline_display(),
             Self::Unpivot(unpivot) => unpivot.multiline_display(),
             Self::Sort(sort) => sort.multiline_display(),
+            Self::TopN(top_n) => top_n.multiline_display(),
             Self::Repartition(repartition) => repartition.multiline_display(),
             Self::Distinct(distinct) => distinct.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
@@ -330,6 +376,8 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
+            Self::RecursiveCte(cte) => cte.multiline_display(),
             Self::SubqueryAlias(alias) => alias.multiline_display(),
         }
     }
@@ -341,9 +389,11 @@ impl LogicalPlan {
             Self::ActorPoolProject(ActorPoolProject { input, .. }) => vec![input],
             Self::Filter(Filter { input, .. }) => vec![input],
             Self::Limit(Limit { input, .. }) => vec![input],
+            Self::Offset(Offset { input, .. }) => vec![input],
             Self::Explode(Explode { input, .. }) => vec![input],
             Self::Unpivot(Unpivot { input, .. }) => vec![input],
             Self::Sort(Sort { input, .. }) => vec![input],
+            Self::TopN(TopN { input, .. }) => vec![input],
             Self::Repartition(Repartition { input, .. }) => vec![input],
             Self::Distinct(Distinct { input, .. }) => vec![input],
             Self::Aggregate(Aggregate { input, .. }) => vec![input],
@@ -357,6 +407,10 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::Window(Window { input, .. }) => vec![input],
+            Self::RecursiveCte(RecursiveCte {
+                anchor, recursive, ..
+            }) => vec![anchor, recursive],
             Self::SubqueryAlias(SubqueryAlias { input, .. }) => vec![input],
         }
     }
@@ -371,19 +425,23 @@ impl LogicalPlan {
                 Self::ActorPoolProject(ActorPoolProject {projection, ..}) => Self::ActorPoolProject(ActorPoolProject::try_new(input.clone(), projection.clone()).unwrap()),
                 Self::Filter(Filter { predicate, .. }) => Self::Filter(Filter::try_new(input.clone(), predicate.clone()).unwrap()),
                 Self::Limit(Limit { limit, eager, .. }) => Self::Limit(Limit::new(input.clone(), *limit, *eager)),
+                Self::Offset(Offset { offset, .. }) => Self::Offset(Offset::new(input.clone(), *offset)),
                 Self::Explode(Explode { to_explode, .. }) => Self::Explode(Explode::try_new(input.clone(), to_explode.clone()).unwrap()),
                 Self::Sort(Sort { sort_by, descending, nulls_first, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone()).unwrap()),
+                Self::TopN(TopN { sort_by, descending, nulls_first, limit, eager, .. }) => Self::TopN(TopN::try_new(input.clone(), sort_by.clone(), descending.clone(), nulls_first.clone(), *limit, *eager).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::new(input.clone(), scheme_config.clone())),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
                 Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
                 Self::Pivot(Pivot { group_by, pivot_column, value_column, aggregation, names, ..}) => Self::Pivot(Pivot::try_new(input.clone(), group_by.clone(), pivot_column.clone(), value_column.clone(), aggregation.into(), names.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId {column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::try_new(input.clone(), Some(column_name)).unwrap()),
+                Self::Window(Window { window_functions, window_spec, .. }) => Self::Window(Window::try_new(input.clone(), window_functions.clone(), window_spec.clone()).unwrap()),
                 Self::Unpivot(Unpivot {ids, values, variable_name, value_name, output_schema, ..}) =>
                     Self::Unpivot(Unpivot::new(input.clone(), ids.clone(), values.clone(), variable_name.clone(), value_name.clone(), output_schema.clone())),
                 Self::Sample(Sample {fraction, with_replacement, seed, ..}) => Self::Sample(Sample::new(input.clone(), *fraction, *with_replacement, *seed)),
                 Self::SubqueryAlias(SubqueryAlias { name: id, .. }) => Self::SubqueryAlias(SubqueryAlias::new(input.clone(), id.clone())),
                 Self::Concat(_) => panic!("Concat ops should never have only one input, but got one"),
+                Self::RecursiveCte(_) => panic!("RecursiveCte ops should never have only one input, but got one"),
                 Self::Intersect(_) => panic!("Intersect ops should never have only one input, but got one"),
                 Self::Union(_) => panic!("Union ops should never have only one input, but got one"),
                 Self::Join(_) => panic!("Join ops should never have only one input, but got one"),
@@ -391,9 +449,10 @@ impl LogicalPlan {
             [input1, input2] => match self {
                 Self::Source(_) => panic!("Source nodes don't have children, with_new_children() should never be called for Source ops"),
                 Self::Concat(_) => Self::Concat(Concat::try_new(input1.clone(), input2.clone()).unwrap()),
+                Self::RecursiveCte(RecursiveCte { name, working_table, all_rows_table, max_iterations, .. }) => Self::RecursiveCte(RecursiveCte::try_new(name.clone(), input1.clone(), input2.clone(), working_table.clone(), all_rows_table.clone(), *max_iterations).unwrap()),
                 Self::Intersect(inner) => Self::Intersect(Intersect::try_new(input1.clone(), input2.clone(), inner.is_all).unwrap()),
                 Self::Union(inner) => Self::Union(Union::try_new(input1.clone(), input2.clone(), inner.quantifier, inner.strategy).unwrap()),
-                Self::Join(Join { left_on, right_on, null_equals_nulls, join_type, join_strategy, .. }) => Self::Join(Join::try_new(
+                Self::Join(Join { left_on, right_on, null_equals_nulls, residual_predicate, asof_options, join_type, join_strategy, .. }) => Self::Join(Join::try_new(
                     input1.clone(),
                     input2.clone(),
                     left_on.clone(),
@@ -401,7 +460,8 @@ impl LogicalPlan {
                     null_equals_nulls.clone(),
                     *join_type,
                     *join_strategy,
-                ).unwrap()),
+                ).and_then(|join| join.with_residual_predicate(residual_predicate.clone()))
+                .and_then(|join| join.with_asof_options(asof_options.clone())).unwrap()),
                 _ => panic!("Logical op {} has one input, but got two", self),
             },
             _ => panic!("Logical ops should never have more than 2 inputs, but got: {}", children.len())
@@ -515,9 +575,11 @@ impl LogicalPlan {
             | Self::ActorPoolProject(ActorPoolProject { plan_id, .. })
             | Self::Filter(Filter { plan_id, .. })
             | Self::Limit(Limit { plan_id, .. })
+            | Self::Offset(Offset { plan_id, .. })
             | Self::Explode(Explode { plan_id, .. })
             | Self::Unpivot(Unpivot { plan_id, .. })
             | Self::Sort(Sort { plan_id, .. })
+            | Self::TopN(TopN { plan_id, .. })
             | Self::Repartition(Repartition { plan_id, .. })
             | Self::Distinct(Distinct { plan_id, .. })
             | Self::Aggregate(Aggregate { plan_id, .. })
@@ -529,6 +591,8 @@ impl LogicalPlan {
             | Self::Sink(Sink { plan_id, .. })
             | Self::Sample(Sample { plan_id, .. })
             | Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { plan_id, .. })
+            | Self::Window(Window { plan_id, .. })
+            | Self::RecursiveCte(RecursiveCte { plan_id, .. })
             | Self::SubqueryAlias(SubqueryAlias { plan_id, .. }) => plan_id,
         }
     }
@@ -542,9 +606,11 @@ impl LogicalPlan {
             }
             Self::Filter(filter) => Self::Filter(filter.clone().with_plan_id(plan_id)),
             Self::Limit(limit) => Self::Limit(limit.clone().with_plan_id(plan_id)),
+            Self::Offset(offset) => Self::Offset(offset.clone().with_plan_id(plan_id)),
             Self::Explode(explode) => Self::Explode(explode.clone().with_plan_id(plan_id)),
             Self::Unpivot(unpivot) => Self::Unpivot(unpivot.clone().with_plan_id(plan_id)),
             Self::Sort(sort) => Self::Sort(sort.clone().with_plan_id(plan_id)),
+            Self::TopN(top_n) => Self::TopN(top_n.clone().with_plan_id(plan_id)),
             Self::Repartition(repartition) => {
                 Self::Repartition(repartition.clone().with_plan_id(plan_id))
             }
@@ -562,6 +628,8 @@ impl LogicalPlan {
                     monotonically_increasing_id.clone().with_plan_id(plan_id),
                 )
             }
+            Self::Window(window) => Self::Window(window.clone().with_plan_id(plan_id)),
+            Self::RecursiveCte(cte) => Self::RecursiveCte(cte.clone().with_plan_id(plan_id)),
             Self::SubqueryAlias(alias) => Self::SubqueryAlias(alias.clone().with_plan_id(plan_id)),
         }
     }
@@ -652,9 +720,11 @@ impl_from_data_struct_for_logical_plan!(Source);
 impl_from_data_struct_for_logical_plan!(Project);
 impl_from_data_struct_for_logical_plan!(Filter);
 impl_from_data_struct_for_logical_plan!(Limit);
+impl_from_data_struct_for_logical_plan!(Offset);
 impl_from_data_struct_for_logical_plan!(Explode);
 impl_from_data_struct_for_logical_plan!(Unpivot);
 impl_from_data_struct_for_logical_plan!(Sort);
+impl_from_data_struct_for_logical_plan!(TopN);
 impl_from_data_struct_for_logical_plan!(Repartition);
 impl_from_data_struct_for_logical_plan!(Distinct);
 impl_from_data_struct_for_logical_plan!(Aggregate);
@@ -666,3 +736,5 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
+impl_from_data_struct_for_logical_plan!(RecursiveCte);
@@ -4,10 +4,13 @@ use std::{
 };
 
 use common_error::{DaftError, DaftResult};
-use daft_core::{prelude::*, utils::supertype::try_get_supertype};
+use daft_core::{join::JoinSide, prelude::*, utils::supertype::try_get_supertype};
 use daft_dsl::{
-    join::infer_join_schema, optimization::replace_columns_with_expressions, resolved_col, Column,
-    Expr, ExprRef, ResolvedColumn,
+    common_treenode::{Transformed, TreeNode},
+    join::{bind_join_predicate, infer_join_schema, join_predicate_schema, AsofJoinOptions},
+    join_side_col,
+    optimization::replace_columns_with_expressions,
+    resolved_col, Column, Expr, ExprRef, ResolvedColumn,
 };
 use indexmap::IndexSet;
 use itertools::Itertools;
@@ -32,6 +35,15 @@ pub struct Join {
     pub left_on: Vec<ExprRef>,
     pub right_on: Vec<ExprRef>,
     pub null_equals_nulls: Option<Vec<bool>>,
+    /// Predicate which pairs of rows matching on the join keys must also satisfy.
+    ///
+    /// Its columns are join side columns, and it is evaluated while joining, so rows without a pair
+    /// satisfying it are treated as unmatched by outer, semi and anti joins.
+    pub residual_predicate: Option<ExprRef>,
+    /// Options of an as-of join, which are set if and only if the join type is as-of.
+    ///
+    /// The last join key is the as-of key, and the other join keys must be equal.
+    pub asof_options: Option<AsofJoinOptions>,
     pub join_type: JoinType,
     pub join_strategy: Option<JoinStrategy>,
     pub output_schema: SchemaRef,
@@ -92,6 +104,8 @@ impl Join {
             left_on,
             right_on,
             null_equals_nulls,
+            residual_predicate: None,
+            asof_options: None,
             join_type,
             join_strategy,
             output_schema,
@@ -99,6 +113,77 @@ impl Join {
         })
     }
 
+    /// Set the residual predicate of the join, checking that it is a boolean expression over the columns of both sides.
+    pub(crate) fn with_residual_predicate(
+        mut self,
+        residual_predicate: Option<ExprRef>,
+    ) -> logical_plan::Result<Self> {
+        if let Some(predicate) = &residual_predicate {
+            let schema =
+                join_predicate_schema(predicate, &self.left.schema(), &self.right.schema())?;
+            let dtype = bind_join_predicate(predicate.clone())
+                .to_field(&schema)?
+                .dtype;
+            if dtype != DataType::Boolean {
+                return Err(DaftError::ValueError(format!(
+                    "Expected join predicate to be a boolean expression, received: {predicate} of type {dtype}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+        self.residual_predicate = residual_predicate;
+        Ok(self)
+    }
+
+    /// Set the options of an as-of join, checking that the join keys can be used for one.
+    pub(crate) fn with_asof_options(
+        mut self,
+        asof_options: Option<AsofJoinOptions>,
+    ) -> logical_plan::Result<Self> {
+        match (&asof_options, self.join_type) {
+            (None, JoinType::AsOf) => {
+                return Err(DaftError::ValueError(
+                    "Expected as-of join options for an as-of join".to_string(),
+                ))
+                .context(CreationSnafu);
+            }
+            (Some(_), join_type) if join_type != JoinType::AsOf => {
+                return Err(DaftError::ValueError(format!(
+                    "Expected as-of join options only for an as-of join, received join type: {join_type}"
+                )))
+                .context(CreationSnafu);
+            }
+            _ => {}
+        }
+        if asof_options.is_some() {
+            let (Some(left_key), Some(right_key)) = (self.left_on.last(), self.right_on.last())
+            else {
+                return Err(DaftError::ValueError(
+                    "Expected an as-of key for an as-of join".to_string(),
+                ))
+                .context(CreationSnafu);
+            };
+            let l_dtype = left_key.to_field(&self.left.schema())?.dtype;
+            let r_dtype = right_key.to_field(&self.right.schema())?.dtype;
+            if !(l_dtype.is_numeric() || l_dtype.is_temporal())
+                || !(r_dtype.is_numeric() || r_dtype.is_temporal())
+            {
+                return Err(DaftError::TypeError(format!(
+                    "Expected as-of keys of an as-of join to be numeric or temporal, received: {l_dtype} vs {r_dtype}"
+                )))
+                .context(CreationSnafu);
+            }
+            if self.residual_predicate.is_some() {
+                return Err(DaftError::ValueError(
+                    "As-of joins do not support join predicates".to_string(),
+                ))
+                .context(CreationSnafu);
+            }
+        }
+        self.asof_options = asof_options;
+        Ok(self)
+    }
+
     pub fn with_plan_id(mut self, plan_id: usize) -> Self {
         self.plan_id = Some(plan_id);
         self
@@ -112,16 +197,25 @@ impl Join {
     /// - updated right
     /// - left_on (unchanged)
     /// - updated right_on
+    /// - updated residual_predicate
+    #[allow(clippy::type_complexity)]
     pub(crate) fn deduplicate_join_columns(
         left: LogicalPlanRef,
         right: LogicalPlanRef,
         left_on: Vec<ExprRef>,
         right_on: Vec<ExprRef>,
+        residual_predicate: Option<ExprRef>,
         join_type: JoinType,
         options: JoinOptions,
-    ) -> DaftResult<(LogicalPlanRef, LogicalPlanRef, Vec<ExprRef>, Vec<ExprRef>)> {
+    ) -> DaftResult<(
+        LogicalPlanRef,
+        LogicalPlanRef,
+        Vec<ExprRef>,
+        Vec<ExprRef>,
+        Option<ExprRef>,
+    )> {
         if matches!(join_type, JoinType::Anti | JoinType::Semi) {
-            Ok((left, right, left_on, right_on))
+            Ok((left, right, left_on, right_on, residual_predicate))
         } else {
             let merged_cols = if options.merge_matching_join_keys {
                 left_on
@@ -178,7 +272,7 @@ impl Join {
                 .collect();
 
             if right_rename_mapping.is_empty() {
-                Ok((left, right, left_on, right_on))
+                Ok((left, right, left_on, right_on, residual_predicate))
             } else {
                 // projection to update the right side with the new column names
                 let new_right_projection: Vec<_> = right_names
@@ -205,7 +299,33 @@ impl Join {
                     .map(|expr| replace_columns_with_expressions(expr, &right_on_replace_map))
                     .collect::<Vec<_>>();
 
-                Ok((left, new_right.into(), left_on, new_right_on))
+                // change any right side column references in the residual predicate to the new column names
+                let new_residual_predicate = residual_predicate.map(|predicate| {
+                    predicate
+                        .transform(|e| match e.as_ref() {
+                            Expr::Column(Column::Resolved(ResolvedColumn::JoinSide(
+                                name,
+                                JoinSide::Right,
+                            ))) => Ok(match right_rename_mapping.get(name.as_ref()) {
+                                Some(new_name) => Transformed::yes(join_side_col(
+                                    new_name.clone(),
+                                    JoinSide::Right,
+                                )),
+                                None => Transformed::no(e),
+                            }),
+                            _ => Ok(Transformed::no(e)),
+                        })
+                        .expect("renaming join predicate columns should not fail")
+                        .data
+                });
+
+                Ok((
+                    left,
+                    new_right.into(),
+                    left_on,
+                    new_right_on,
+                    new_residual_predicate,
+                ))
             }
         }
     }
@@ -270,6 +390,12 @@ impl Join {
                 null_equals_nulls.iter().map(|b| b.to_string()).join(", ")
             ));
         }
+        if let Some(residual_predicate) = &self.residual_predicate {
+            res.push(format!("Residual predicate = {residual_predicate}"));
+        }
+        if let Some(asof_options) = &self.asof_options {
+            res.push(format!("As-of {asof_options}"));
+        }
         res.push(format!(
             "Output schema = {}",
             self.output_schema.short_string()
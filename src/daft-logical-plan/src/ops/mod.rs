@@ -7,8 +7,10 @@ mod filter;
 pub mod join;
 mod limit;
 mod monotonically_increasing_id;
+mod offset;
 mod pivot;
 mod project;
+mod recursive_cte;
 mod repartition;
 mod sample;
 mod set_operations;
@@ -16,7 +18,9 @@ mod sink;
 mod sort;
 mod source;
 mod summarize;
+mod top_n;
 mod unpivot;
+mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
@@ -27,8 +31,10 @@ pub use filter::Filter;
 pub use join::Join;
 pub use limit::Limit;
 pub use monotonically_increasing_id::MonotonicallyIncreasingId;
+pub use offset::Offset;
 pub use pivot::Pivot;
 pub use project::Project;
+pub use recursive_cte::RecursiveCte;
 pub use repartition::Repartition;
 pub use sample::Sample;
 pub use set_operations::{Except, Intersect, SetQuantifier, Union, UnionStrategy};
@@ -36,4 +42,6 @@ pub use sink::Sink;
 pub use sort::Sort;
 pub use source::Source;
 pub use summarize::summarize;
+pub use top_n::TopN;
 pub use unpivot::Unpivot;
+pub use window::Window;
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::{
+    stats::{ApproxStats, PlanStats, StatsState},
+    LogicalPlan,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Offset {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    // Number of rows to skip.
+    pub offset: u64,
+    pub stats_state: StatsState,
+}
+
+impl Offset {
+    pub(crate) fn new(input: Arc<LogicalPlan>, offset: u64) -> Self {
+        Self {
+            plan_id: None,
+            input,
+            offset,
+            stats_state: StatsState::NotMaterialized,
+        }
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        let input_stats = self.input.materialized_stats();
+        let input_rows = input_stats.approx_stats.num_rows;
+        let num_rows = input_rows.saturating_sub(self.offset as usize);
+        let selectivity = if input_rows == 0 {
+            1.0
+        } else {
+            num_rows as f64 / input_rows as f64
+        };
+        let approx_stats = ApproxStats {
+            num_rows,
+            size_bytes: (input_stats.approx_stats.size_bytes as f64 * selectivity) as usize,
+            acc_selectivity: input_stats.approx_stats.acc_selectivity * selectivity,
+        };
+        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!("Offset: {}", self.offset)];
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
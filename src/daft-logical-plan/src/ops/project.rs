@@ -424,6 +424,21 @@ fn replace_column_with_semantic_id(
                     Transformed::yes(Expr::InSubquery(expr.data, subquery.clone()).into())
                 }
             }
+            Expr::WindowFunction(..) | Expr::Over(..) => {
+                let transforms = e
+                    .children()
+                    .into_iter()
+                    .map(|child| {
+                        replace_column_with_semantic_id(child, subexprs_to_replace, schema)
+                    })
+                    .collect::<Vec<_>>();
+                if transforms.iter().all(|e| !e.transformed) {
+                    Transformed::no(e)
+                } else {
+                    let children = transforms.into_iter().map(|t| t.data).collect();
+                    Transformed::yes(e.with_new_children(children).into())
+                }
+            }
         }
     }
 }
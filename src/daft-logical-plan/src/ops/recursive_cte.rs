@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan::{self, CreationSnafu},
+    stats::StatsState,
+    LogicalPlan,
+};
+
+/// A recursive common table expression, which is evaluated as a fixpoint.
+///
+/// Each iteration runs the recursive term on the rows produced by the previous iteration, starting
+/// with the rows of the anchor, until an iteration produces no rows.
+///
+/// The recursive term reads the rows of the previous iteration from an in-memory scan of the
+/// `working_table` cache key, whose partitions are provided by the executor on each iteration.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    pub plan_id: Option<usize>,
+    pub name: Arc<str>,
+    // Upstream nodes.
+    pub anchor: Arc<LogicalPlan>,
+    pub recursive: Arc<LogicalPlan>,
+    /// Cache key of the rows produced by the previous iteration.
+    pub working_table: String,
+    /// Cache key of all the rows produced so far, which the recursive term of a `UNION` reads to
+    /// drop the rows it already produced.
+    pub all_rows_table: Option<String>,
+    /// The number of iterations after which evaluation fails if the recursion hasn't terminated.
+    pub max_iterations: usize,
+    pub stats_state: StatsState,
+}
+
+impl RecursiveCte {
+    pub(crate) fn try_new(
+        name: Arc<str>,
+        anchor: Arc<LogicalPlan>,
+        recursive: Arc<LogicalPlan>,
+        working_table: String,
+        all_rows_table: Option<String>,
+        max_iterations: usize,
+    ) -> logical_plan::Result<Self> {
+        let anchor_schema = anchor.schema();
+        let recursive_schema = recursive.schema();
+        if anchor_schema != recursive_schema {
+            return Err(DaftError::ValueError(format!(
+                "The recursive term of {name} must have the same schema as its anchor, but got: {}, {}",
+                anchor_schema, recursive_schema
+            )))
+            .context(CreationSnafu);
+        }
+
+        Ok(Self {
+            plan_id: None,
+            name,
+            anchor,
+            recursive,
+            working_table,
+            all_rows_table,
+            max_iterations,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // The number of iterations is unknown, so the rows of the anchor are the only estimate.
+        let anchor_stats = self.anchor.materialized_stats();
+        self.stats_state = StatsState::Materialized(anchor_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![
+            format!("RecursiveCte"),
+            format!("Name = {}", self.name),
+            format!("Union all = {}", self.all_rows_table.is_none()),
+            format!("Max iterations = {}", self.max_iterations),
+        ];
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_dsl::ExprRef;
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{
+    logical_plan,
+    logical_plan::CreationSnafu,
+    stats::{ApproxStats, PlanStats, StatsState},
+    LogicalPlan,
+};
+
+/// A Sort followed by a Limit, fused so that only the first `limit` rows in sort order are
+/// ever materialized.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TopN {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    pub sort_by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+    pub nulls_first: Vec<bool>,
+    // Number of rows to keep.
+    pub limit: u64,
+    // Carried over from the fused Limit, see `Limit::eager`.
+    pub eager: bool,
+    pub stats_state: StatsState,
+}
+
+impl TopN {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        sort_by: Vec<ExprRef>,
+        descending: Vec<bool>,
+        nulls_first: Vec<bool>,
+        limit: u64,
+        eager: bool,
+    ) -> logical_plan::Result<Self> {
+        if sort_by.is_empty() {
+            return Err(DaftError::ValueError(
+                "TopN must be given at least one column/expression to sort by".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        Ok(Self {
+            plan_id: None,
+            input,
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            eager,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Same cardinality as a Limit on top of the input.
+        let input_stats = self.input.materialized_stats();
+        let limit = self.limit as usize;
+        let num_rows = input_stats.approx_stats.num_rows;
+        let (size_bytes, selectivity) = if num_rows > limit {
+            let est_bytes_per_row = input_stats.approx_stats.size_bytes / num_rows;
+            (limit * est_bytes_per_row, limit as f64 / num_rows as f64)
+        } else {
+            (input_stats.approx_stats.size_bytes, 1.0)
+        };
+        let approx_stats = ApproxStats {
+            num_rows: limit.min(num_rows),
+            size_bytes,
+            acc_selectivity: input_stats.approx_stats.acc_selectivity * selectivity,
+        };
+        self.stats_state = StatsState::Materialized(PlanStats::new(approx_stats).into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        let pairs = self
+            .sort_by
+            .iter()
+            .zip(self.descending.iter())
+            .zip(self.nulls_first.iter())
+            .map(|((sb, d), nf)| {
+                format!(
+                    "({}, {}, {})",
+                    sb,
+                    if *d { "descending" } else { "ascending" },
+                    if *nf { "nulls first" } else { "nulls last" }
+                )
+            })
+            .join(", ");
+        res.push(format!("TopN: Sort by = {}, Limit = {}", pairs, self.limit));
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
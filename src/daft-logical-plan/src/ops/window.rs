@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::prelude::*;
+use daft_dsl::{Expr, ExprRef, WindowSpec};
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::{logical_plan, logical_plan::CreationSnafu, stats::StatsState, LogicalPlan};
+
+/// Evaluates window functions that share a window specification,
+/// appending one column per function to the input.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    pub plan_id: Option<usize>,
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+    /// Window functions to evaluate, each an aliased `Expr::Over` using `window_spec`.
+    pub window_functions: Vec<ExprRef>,
+    pub window_spec: WindowSpec,
+    pub schema: SchemaRef,
+    pub stats_state: StatsState,
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_functions: Vec<ExprRef>,
+        window_spec: WindowSpec,
+    ) -> logical_plan::Result<Self> {
+        for expr in &window_functions {
+            let spec = match expr.as_ref() {
+                Expr::Alias(inner, _) => match inner.as_ref() {
+                    Expr::Over(_, spec) => Some(spec),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if spec != Some(&window_spec) {
+                return Err(DaftError::ValueError(format!(
+                    "Expected an aliased window function over ({window_spec}) in Window, found: {expr}"
+                )))
+                .context(CreationSnafu);
+            }
+        }
+
+        let input_schema = input.schema();
+        let fields = input_schema
+            .fields
+            .values()
+            .cloned()
+            .map(Ok)
+            .chain(window_functions.iter().map(|e| e.to_field(&input_schema)))
+            .collect::<common_error::DaftResult<Vec<_>>>()?;
+        let schema = Schema::new(fields)?.into();
+
+        Ok(Self {
+            plan_id: None,
+            input,
+            window_functions,
+            window_spec,
+            schema,
+            stats_state: StatsState::NotMaterialized,
+        })
+    }
+
+    pub fn with_plan_id(mut self, plan_id: usize) -> Self {
+        self.plan_id = Some(plan_id);
+        self
+    }
+
+    pub(crate) fn with_materialized_stats(mut self) -> Self {
+        // Window functions do not affect cardinality.
+        let input_stats = self.input.materialized_stats();
+        self.stats_state = StatsState::Materialized(input_stats.clone().into());
+        self
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        )];
+        if !self.window_spec.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.window_spec
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        if !self.window_spec.order_by.is_empty() {
+            res.push(format!(
+                "Order by = {}",
+                self.window_spec
+                    .order_by
+                    .iter()
+                    .zip(self.window_spec.descending.iter())
+                    .zip(self.window_spec.nulls_first.iter())
+                    .map(|((e, desc), nulls_first)| format!(
+                        "({}, {}, {})",
+                        e,
+                        if *desc { "descending" } else { "ascending" },
+                        if *nulls_first {
+                            "nulls first"
+                        } else {
+                            "nulls last"
+                        }
+                    ))
+                    .join(", ")
+            ));
+        }
+        if let Some(frame) = &self.window_spec.frame {
+            res.push(format!("Frame = {frame}"));
+        }
+        if let StatsState::Materialized(stats) = &self.stats_state {
+            res.push(format!("Stats = {}", stats));
+        }
+        res
+    }
+}
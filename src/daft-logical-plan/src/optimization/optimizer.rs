@@ -7,8 +7,9 @@ use super::{
     logical_plan_tracker::LogicalPlanTracker,
     rules::{
         DetectMonotonicId, DropRepartition, EliminateCrossJoin, EliminateSubqueryAliasRule,
-        EnrichWithStats, FilterNullJoinKey, LiftProjectFromAgg, MaterializeScans, OptimizerRule,
-        PushDownFilter, PushDownLimit, PushDownProjection, ReorderJoins, SimplifyExpressionsRule,
+        EnrichWithStats, ExtractWindowFunction, FilterNullJoinKey, FuseSortLimit,
+        LiftProjectFromAgg, MaterializeScans, OptimizerRule, PushDownAggregation, PushDownFilter,
+        PushDownLimit, PushDownProjection, ReorderJoins, SimplifyExpressionsRule,
         SplitActorPoolProjects, UnnestPredicateSubquery, UnnestScalarSubquery,
     },
 };
@@ -100,6 +101,7 @@ impl Default for OptimizerBuilder {
                         Box::new(EliminateSubqueryAliasRule::new()),
                         Box::new(SplitActorPoolProjects::new()),
                         Box::new(DetectMonotonicId::new()),
+                        Box::new(ExtractWindowFunction::new()),
                     ],
                     RuleExecutionStrategy::FixedPoint(None),
                 ),
@@ -136,6 +138,19 @@ impl Default for OptimizerBuilder {
                     vec![Box::new(PushDownLimit::new())],
                     RuleExecutionStrategy::FixedPoint(Some(3)),
                 ),
+                // --- Aggregation pushdowns ---
+                // Runs after the filter and limit pushdowns, which block aggregations from being pushed into scans.
+                RuleBatch::new(
+                    vec![Box::new(PushDownAggregation::new())],
+                    RuleExecutionStrategy::Once,
+                ),
+                // --- Fuse Sort-Limit into TopN ---
+                // Runs after limit pushdown so that Limits which commuted past Projections can
+                // land directly on top of their Sort.
+                RuleBatch::new(
+                    vec![Box::new(FuseSortLimit::new())],
+                    RuleExecutionStrategy::Once,
+                ),
                 // --- Simplify expressions before scans are materialized ---
                 RuleBatch::new(
                     vec![Box::new(SimplifyExpressionsRule::new())],
@@ -43,6 +43,7 @@ impl OptimizerRule for EliminateCrossJoin {
                     join_strategy: None,
                     // TODO: consider support eliminate cross join with null_equals_nulls
                     null_equals_nulls: None,
+                    residual_predicate: None,
                     ..
                 })
             );
@@ -69,6 +70,7 @@ impl OptimizerRule for EliminateCrossJoin {
                 join_strategy: None,
                 // TODO: consider support eliminate cross join with null_equals_nulls
                 null_equals_nulls: None,
+                residual_predicate: None,
                 ..
             })
         ) {
@@ -137,6 +139,7 @@ fn flatten_join_inputs(
         join @ Join {
             join_type: JoinType::Inner,
             join_strategy: None,
+            residual_predicate: None,
             ..
         },
     ) = plan
@@ -167,7 +170,8 @@ fn flatten_join_inputs(
 fn can_flatten_join_inputs(plan: &LogicalPlan) -> bool {
     // can only flatten inner / cross joins
     match plan {
-        LogicalPlan::Join(join) if join.join_type == JoinType::Inner => {}
+        LogicalPlan::Join(join)
+            if join.join_type == JoinType::Inner && join.residual_predicate.is_none() => {}
         _ => return false,
     }
 
@@ -177,6 +181,7 @@ fn can_flatten_join_inputs(plan: &LogicalPlan) -> bool {
             LogicalPlan::Join(Join {
                 join_strategy: None,
                 join_type: JoinType::Inner,
+                residual_predicate: None,
                 ..
             })
         ) && !can_flatten_join_inputs(child)
@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_dsl::{resolved_col, Expr, ExprRef, WindowSpec};
+use indexmap::IndexMap;
+
+use crate::{
+    logical_plan::{LogicalPlan, Project},
+    ops::{Sort, Window},
+    optimization::rules::OptimizerRule,
+};
+
+/// Optimization rule that extracts window functions (`Expr::Over`) out of Project operations
+/// and evaluates them in Window operations beneath the projection.
+///
+/// One Window operation is created per distinct window specification, e.g.
+/// `Project(sum(a) over (partition by b), rank() over (partition by b order by c))`
+/// becomes `Project(col(w1), col(w2)) -> Window(rank() ...) -> Window(sum(a) ...)`.
+///
+/// Sorts directly beneath the projection are kept above the Window operations,
+/// since evaluating a window does not preserve the order of its input.
+#[derive(Debug)]
+pub struct ExtractWindowFunction;
+
+impl Default for ExtractWindowFunction {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl ExtractWindowFunction {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collects the distinct window functions of a projection, grouped by their window spec.
+    fn collect_window_functions(
+        projection: &[ExprRef],
+        input: &LogicalPlan,
+    ) -> IndexMap<WindowSpec, IndexMap<String, ExprRef>> {
+        let schema = input.schema();
+        let mut window_functions: IndexMap<WindowSpec, IndexMap<String, ExprRef>> = IndexMap::new();
+        for expr in projection {
+            expr.apply(|e| {
+                if let Expr::Over(_, window_spec) = e.as_ref() {
+                    let id = e.semantic_id(&schema).id.to_string();
+                    window_functions
+                        .entry(window_spec.clone())
+                        .or_default()
+                        .entry(id.clone())
+                        .or_insert_with(|| e.clone().alias(id));
+                    Ok(TreeNodeRecursion::Jump)
+                } else {
+                    Ok(TreeNodeRecursion::Continue)
+                }
+            })
+            .unwrap();
+        }
+        window_functions
+    }
+
+    /// Replaces the window functions in `expr` with references to the columns computed by the Window operations.
+    fn replace_window_functions(expr: &ExprRef, input: &LogicalPlan) -> DaftResult<ExprRef> {
+        let schema = input.schema();
+        let new_expr = expr
+            .clone()
+            .transform_down(|e| {
+                if matches!(e.as_ref(), Expr::Over(..)) {
+                    let id = e.semantic_id(&schema).id;
+                    Ok(Transformed::new(
+                        resolved_col(id),
+                        true,
+                        TreeNodeRecursion::Jump,
+                    ))
+                } else {
+                    Ok(Transformed::no(e))
+                }
+            })?
+            .data;
+        // Replacing a window function can change the expression's name, so re-alias it if needed.
+        let old_name = expr.name();
+        Ok(if new_expr.name() != old_name {
+            new_expr.alias(old_name)
+        } else {
+            new_expr
+        })
+    }
+
+    fn extract_window_functions(project: &Project) -> DaftResult<Option<Arc<LogicalPlan>>> {
+        // Descend through any sorts beneath the projection so that they are applied after the windows.
+        let mut sorts = vec![];
+        let mut input = project.input.clone();
+        while let LogicalPlan::Sort(sort) = input.as_ref() {
+            sorts.push(sort.clone());
+            input = sort.input.clone();
+        }
+
+        let window_functions = Self::collect_window_functions(&project.projection, &input);
+        if window_functions.is_empty() {
+            return Ok(None);
+        }
+
+        let new_projection = project
+            .projection
+            .iter()
+            .map(|e| Self::replace_window_functions(e, &input))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut plan = input;
+        for (window_spec, functions) in window_functions {
+            plan = Arc::new(LogicalPlan::Window(Window::try_new(
+                plan,
+                functions.into_values().collect(),
+                window_spec,
+            )?));
+        }
+        for sort in sorts.into_iter().rev() {
+            plan = Arc::new(LogicalPlan::Sort(Sort::try_new(
+                plan,
+                sort.sort_by,
+                sort.descending,
+                sort.nulls_first,
+            )?));
+        }
+        Ok(Some(Arc::new(LogicalPlan::Project(Project::try_new(
+            plan,
+            new_projection,
+        )?))))
+    }
+}
+
+impl OptimizerRule for ExtractWindowFunction {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| match node.as_ref() {
+            LogicalPlan::Project(project) => Ok(match Self::extract_window_functions(project)? {
+                Some(new_plan) => Transformed::yes(new_plan),
+                None => Transformed::no(node),
+            }),
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{resolved_col, unresolved_col, Expr, WindowSpec};
+
+    use super::ExtractWindowFunction;
+    use crate::{
+        ops::Window,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder,
+    };
+
+    /// Helper that creates an optimizer with the ExtractWindowFunction rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(ExtractWindowFunction::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// Tests that window functions are evaluated in one Window per window spec, beneath any sort.
+    #[test]
+    fn window_functions_extracted_per_spec() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+            Field::new("c", DataType::Int64),
+        ]);
+        let by_b = WindowSpec::new(vec![resolved_col("b")]);
+        let by_b_ordered =
+            by_b.clone()
+                .with_order_by(vec![resolved_col("c")], vec![false], vec![false]);
+
+        let scan = dummy_scan_node(scan_op);
+        let plan = scan
+            .sort(vec![unresolved_col("c")], vec![true], vec![false])?
+            .select(vec![
+                resolved_col("a"),
+                resolved_col("a").sum().over(by_b.clone())?.alias("total"),
+                Expr::rank().over(by_b_ordered.clone())?,
+            ])?
+            .build();
+
+        let schema = scan.schema();
+        let sum_a = resolved_col("a").sum().over(by_b.clone())?;
+        let rank = Expr::rank().over(by_b_ordered.clone())?;
+        let sum_a_id = sum_a.semantic_id(&schema).id.to_string();
+        let rank_id = rank.semantic_id(&schema).id.to_string();
+        let windowed = Window::try_new(scan.build(), vec![sum_a.alias(sum_a_id.clone())], by_b)?;
+        let windowed = Window::try_new(
+            LogicalPlan::from(windowed).arced(),
+            vec![rank.alias(rank_id.clone())],
+            by_b_ordered,
+        )?;
+        let expected = LogicalPlanBuilder::new(LogicalPlan::from(windowed).arced(), None)
+            .sort(vec![unresolved_col("c")], vec![true], vec![false])?
+            .select(vec![
+                resolved_col("a"),
+                resolved_col(sum_a_id).alias("total"),
+                resolved_col(rank_id).alias("rank"),
+            ])?
+            .build();
+
+        assert_optimized_plan_eq(plan, expected)
+    }
+
+    /// Tests that projections without window functions are left untouched.
+    #[test]
+    fn project_without_window_functions_unchanged() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node(scan_op)
+            .select(vec![resolved_col("a").alias("b")])?
+            .build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)
+    }
+}
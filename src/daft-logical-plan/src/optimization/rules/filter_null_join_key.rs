@@ -111,7 +111,7 @@ impl OptimizerRule for FilterNullJoinKey {
 
                 let (can_filter_left, can_filter_right) = match join_type {
                     JoinType::Inner => (true, true),
-                    JoinType::Left => (false, true),
+                    JoinType::Left | JoinType::AsOf => (false, true),
                     JoinType::Right => (true, false),
                     JoinType::Outer => (false, false),
                     JoinType::Anti => (false, true),
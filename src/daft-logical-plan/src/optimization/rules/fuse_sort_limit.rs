@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Limit, Sort, TopN},
+    LogicalPlan,
+};
+
+/// Optimization rule for fusing a Limit that sits directly on top of a Sort into a TopN.
+///
+/// Limit-Sort -> TopN
+#[derive(Default, Debug)]
+pub struct FuseSortLimit {}
+
+impl FuseSortLimit {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for FuseSortLimit {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| {
+            let LogicalPlan::Limit(Limit {
+                input,
+                limit,
+                eager,
+                ..
+            }) = node.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            let LogicalPlan::Sort(Sort {
+                input: sort_input,
+                sort_by,
+                descending,
+                nulls_first,
+                ..
+            }) = input.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            let top_n = TopN::try_new(
+                sort_input.clone(),
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                *limit as u64,
+                *eager,
+            )?;
+            Ok(Transformed::yes(LogicalPlan::TopN(top_n).arced()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, unresolved_col};
+    use rstest::rstest;
+
+    use crate::{
+        ops::TopN,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::{fuse_sort_limit::FuseSortLimit, OptimizerRule},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan, LogicalPlanBuilder,
+    };
+
+    /// Helper that creates an optimizer with the FuseSortLimit rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(FuseSortLimit::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// Tests that a Limit directly on top of a Sort is fused into a TopN.
+    ///
+    /// Limit-Sort -> TopN
+    #[rstest]
+    fn limit_on_sort_fused_into_top_n(#[values(false, true)] eager: bool) -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let sorted = dummy_scan_node(scan_op)
+            .sort(
+                vec![unresolved_col("a"), unresolved_col("b")],
+                vec![true, false],
+                vec![false, true],
+            )?
+            .build();
+        let LogicalPlan::Sort(sort) = sorted.as_ref() else {
+            unreachable!("builder should produce a Sort");
+        };
+        let expected = LogicalPlan::from(TopN::try_new(
+            sort.input.clone(),
+            sort.sort_by.clone(),
+            vec![true, false],
+            vec![false, true],
+            10,
+            eager,
+        )?)
+        .arced();
+        let plan = LogicalPlanBuilder::from(sorted).limit(10, eager)?.build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that a Limit separated from its Sort by another node is left alone.
+    #[test]
+    fn limit_on_filter_on_sort_not_fused() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(scan_op)
+            .sort(vec![unresolved_col("a")], vec![false], vec![false])?
+            .filter(unresolved_col("a").lt(lit(2)))?
+            .limit(10, false)?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that the TopN a Limit is fused into keeps the Limit's eager flag.
+    #[rstest]
+    fn fused_top_n_keeps_eager(#[values(false, true)] eager: bool) -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan = dummy_scan_node(scan_op)
+            .sort(vec![unresolved_col("a")], vec![false], vec![false])?
+            .limit(10, eager)?
+            .build();
+        let optimized = FuseSortLimit::new().try_optimize(plan)?.data;
+        let LogicalPlan::TopN(top_n) = optimized.as_ref() else {
+            panic!("expected a TopN, got {optimized:?}");
+        };
+        assert_eq!(top_n.eager, eager);
+        assert_eq!(top_n.limit, 10);
+        Ok(())
+    }
+}
@@ -3,9 +3,12 @@ mod drop_repartition;
 mod eliminate_cross_join;
 mod eliminate_subquery_alias;
 mod enrich_with_stats;
+mod extract_window_function;
 mod filter_null_join_key;
+mod fuse_sort_limit;
 mod lift_project_from_agg;
 mod materialize_scans;
+mod push_down_aggregation;
 mod push_down_filter;
 mod push_down_limit;
 mod push_down_projection;
@@ -20,9 +23,12 @@ pub use drop_repartition::DropRepartition;
 pub use eliminate_cross_join::EliminateCrossJoin;
 pub use eliminate_subquery_alias::EliminateSubqueryAliasRule;
 pub use enrich_with_stats::EnrichWithStats;
+pub use extract_window_function::ExtractWindowFunction;
 pub use filter_null_join_key::FilterNullJoinKey;
+pub use fuse_sort_limit::FuseSortLimit;
 pub use lift_project_from_agg::LiftProjectFromAgg;
 pub use materialize_scans::MaterializeScans;
+pub use push_down_aggregation::PushDownAggregation;
 pub use push_down_filter::PushDownFilter;
 pub use push_down_limit::PushDownLimit;
 pub use push_down_projection::PushDownProjection;
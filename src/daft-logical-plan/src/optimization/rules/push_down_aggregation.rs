@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::{lit, resolved_col, AggExpr, Column, Expr, ExprRef, ResolvedColumn};
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Aggregate, Project, Source},
+    source_info::SourceInfo,
+    LogicalPlan,
+};
+
+/// Optimization rule for pushing ungrouped count/min/max aggregations into scans that can compute them, e.g. from
+/// Parquet footers.
+///
+/// Each scan task then yields a single row of partial results, which a new Aggregate merges by summing the counts
+/// and taking the min of the mins and the max of the maxes.
+///
+/// Aggregate-Source -> Project-Aggregate[merge]-Source[with_aggregations]
+#[derive(Default, Debug)]
+pub struct PushDownAggregation {}
+
+impl PushDownAggregation {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for PushDownAggregation {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| {
+            let LogicalPlan::Aggregate(Aggregate {
+                input,
+                aggregations,
+                groupby,
+                ..
+            }) = node.as_ref()
+            else {
+                return Ok(Transformed::no(node));
+            };
+            if !groupby.is_empty() {
+                return Ok(Transformed::no(node));
+            }
+            let LogicalPlan::Source(source) = input.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            let SourceInfo::Physical(external_info) = source.source_info.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            // Filters and limits are applied to the data before it is aggregated, so the scan can't answer the
+            // aggregations by itself.
+            let pushdowns = &external_info.pushdowns;
+            if pushdowns.filters.is_some()
+                || pushdowns.limit.is_some()
+                || pushdowns.aggregations.is_some()
+                || !external_info
+                    .scan_state
+                    .get_scan_op()
+                    .0
+                    .can_absorb_aggregation()
+            {
+                return Ok(Transformed::no(node));
+            }
+            let Some(merge_aggregations) = aggregations
+                .iter()
+                .map(merge_aggregation)
+                .collect::<Option<Vec<_>>>()
+            else {
+                return Ok(Transformed::no(node));
+            };
+
+            let new_pushdowns = pushdowns.with_aggregations(Some(Arc::new(aggregations.clone())));
+            let new_source = LogicalPlan::Source(Source::new(
+                node.schema(),
+                SourceInfo::Physical(external_info.with_pushdowns(new_pushdowns)).into(),
+            ))
+            .arced();
+            let merge =
+                LogicalPlan::Aggregate(Aggregate::try_new(new_source, merge_aggregations, vec![])?)
+                    .arced();
+            // Counts merged by a sum are null rather than zero if the scan yields no partial results at all.
+            let projection = aggregations
+                .iter()
+                .map(|agg| {
+                    let name = agg.name();
+                    if matches!(unalias(agg).as_ref(), Expr::Agg(AggExpr::Count(..))) {
+                        resolved_col(name).fill_null(lit(0u64)).alias(name)
+                    } else {
+                        resolved_col(name)
+                    }
+                })
+                .collect();
+            Ok(Transformed::yes(
+                LogicalPlan::Project(Project::try_new(merge, projection)?).arced(),
+            ))
+        })
+    }
+}
+
+fn unalias(expr: &ExprRef) -> &ExprRef {
+    match expr.as_ref() {
+        Expr::Alias(inner, _) => inner,
+        _ => expr,
+    }
+}
+
+/// Returns the aggregation that merges the partial results of `agg` from each scan task, or `None` if `agg` can't be
+/// pushed into a scan.
+fn merge_aggregation(agg: &ExprRef) -> Option<ExprRef> {
+    let Expr::Agg(
+        agg_expr @ (AggExpr::Count(child, _) | AggExpr::Min(child) | AggExpr::Max(child)),
+    ) = unalias(agg).as_ref()
+    else {
+        return None;
+    };
+    if !matches!(
+        child.as_ref(),
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(_)))
+    ) {
+        return None;
+    }
+    let partial = resolved_col(agg.name());
+    let merged = match agg_expr {
+        AggExpr::Count(..) => partial.sum(),
+        AggExpr::Min(_) => partial.min(),
+        AggExpr::Max(_) => partial.max(),
+        _ => unreachable!("Only count, min and max aggregations are pushed into scans"),
+    };
+    Some(merged.alias(agg.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_scan_info::Pushdowns;
+    use daft_core::{count_mode::CountMode, prelude::*};
+    use daft_dsl::{lit, resolved_col, unresolved_col};
+
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            rules::PushDownAggregation,
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{
+            dummy_scan_node, dummy_scan_node_with_pushdowns, dummy_scan_operator,
+            dummy_scan_operator_with_aggregation_pushdown,
+        },
+        LogicalPlan,
+    };
+
+    /// Helper that creates an optimizer with the PushDownAggregation rule registered, optimizes
+    /// the provided plan with said optimizer, and compares the optimized plan with
+    /// the provided expected plan.
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(PushDownAggregation::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    fn fields() -> Vec<Field> {
+        vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]
+    }
+
+    /// Tests that count, min and max push into a Source that can absorb them, and are merged above it.
+    ///
+    /// Aggregate-Source -> Project-Aggregate[merge]-Source[with_aggregations]
+    #[test]
+    fn count_min_max_push_into_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator_with_aggregation_pushdown(fields());
+        let plan = dummy_scan_node(scan_op.clone())
+            .aggregate(
+                vec![
+                    unresolved_col("b").count(CountMode::All),
+                    unresolved_col("a").min().alias("min_a"),
+                    unresolved_col("a").max().alias("max_a"),
+                ],
+                vec![],
+            )?
+            .build();
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_aggregations(Some(Arc::new(vec![
+                resolved_col("b").count(CountMode::All),
+                resolved_col("a").min().alias("min_a"),
+                resolved_col("a").max().alias("max_a"),
+            ]))),
+        )
+        .aggregate(
+            vec![
+                unresolved_col("b").sum().alias("b"),
+                unresolved_col("min_a").min().alias("min_a"),
+                unresolved_col("max_a").max().alias("max_a"),
+            ],
+            vec![],
+        )?
+        .select(vec![
+            unresolved_col("b").fill_null(lit(0u64)).alias("b"),
+            unresolved_col("min_a"),
+            unresolved_col("max_a"),
+        ])?
+        .build();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Tests that grouped aggregations don't push into the Source.
+    #[test]
+    fn grouped_aggregation_does_not_push_into_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator_with_aggregation_pushdown(fields());
+        let plan = dummy_scan_node(scan_op)
+            .aggregate(vec![unresolved_col("a").max()], vec![unresolved_col("b")])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations other than count, min and max don't push into the Source.
+    #[test]
+    fn unsupported_aggregation_does_not_push_into_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator_with_aggregation_pushdown(fields());
+        let plan = dummy_scan_node(scan_op)
+            .aggregate(
+                vec![
+                    unresolved_col("a").max(),
+                    unresolved_col("a").sum().alias("sum_a"),
+                ],
+                vec![],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations of expressions other than columns don't push into the Source.
+    #[test]
+    fn aggregation_of_expression_does_not_push_into_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator_with_aggregation_pushdown(fields());
+        let plan = dummy_scan_node(scan_op)
+            .aggregate(
+                vec![unresolved_col("a").cast(&DataType::Float64).max()],
+                vec![],
+            )?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations don't push into a Source with a filter pushdown.
+    #[test]
+    fn aggregation_does_not_push_into_filtered_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator_with_aggregation_pushdown(fields());
+        let plan = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_filters(Some(resolved_col("a").lt(lit(2)))),
+        )
+        .aggregate(vec![unresolved_col("a").count(CountMode::All)], vec![])?
+        .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// Tests that aggregations don't push into a Source that can't absorb them.
+    #[test]
+    fn aggregation_does_not_push_into_non_absorbing_source() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(fields());
+        let plan = dummy_scan_node(scan_op)
+            .aggregate(vec![unresolved_col("a").count(CountMode::All)], vec![])?
+            .build();
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
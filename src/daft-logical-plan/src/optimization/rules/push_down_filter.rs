@@ -261,7 +261,7 @@ impl PushDownFilter {
                 // if a filter is pushed down on one side, would it preserve the output of the join+filter?
                 let (left_preserved, right_preserved) = match child_join.join_type {
                     JoinType::Inner => (true, true),
-                    JoinType::Left => (true, false),
+                    JoinType::Left | JoinType::AsOf => (true, false),
                     JoinType::Right => (false, true),
                     JoinType::Outer => (false, false),
                     JoinType::Anti => (true, true),
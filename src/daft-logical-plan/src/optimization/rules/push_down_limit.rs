@@ -339,4 +339,19 @@ mod tests {
         assert_optimized_plan_eq(plan, expected)?;
         Ok(())
     }
+
+    /// Tests that Limit does not push past an Offset, since the offset rows must be read first.
+    ///
+    /// Limit-Offset-Source -> Limit-Offset-Source
+    #[test]
+    fn limit_does_not_push_past_offset() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let plan = dummy_scan_node(scan_op).offset(3)?.limit(5, false)?.build();
+        let expected = plan.clone();
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
 }
@@ -345,8 +345,10 @@ impl PushDownProjection {
                 }
             }
             LogicalPlan::Sort(..)
+            | LogicalPlan::TopN(..)
             | LogicalPlan::Repartition(..)
             | LogicalPlan::Limit(..)
+            | LogicalPlan::Offset(..)
             | LogicalPlan::Filter(..)
             | LogicalPlan::Sample(..)
             | LogicalPlan::Explode(..) => {
@@ -384,6 +386,42 @@ impl PushDownProjection {
                     .or(Transformed::yes(new_plan));
                 Ok(new_plan)
             }
+            LogicalPlan::Window(..) => {
+                // A Window only appends columns to its input, so any input columns that neither
+                // the projection nor the window functions require can be pruned beneath it.
+                let grand_upstream_plan = &upstream_plan.arc_children()[0];
+                let grand_upstream_columns = grand_upstream_plan.schema().names();
+                let combined_dependencies = plan
+                    .required_columns()
+                    .iter()
+                    .flatten()
+                    .chain(upstream_plan.required_columns().iter().flatten())
+                    .filter(|name| grand_upstream_columns.contains(name))
+                    .cloned()
+                    .collect::<IndexSet<_>>();
+
+                // Skip optimization if no columns would be pruned.
+                if grand_upstream_columns.len() == combined_dependencies.len() {
+                    return Ok(Transformed::no(plan));
+                }
+
+                let new_subprojection: LogicalPlan = {
+                    let pushdown_column_exprs = combined_dependencies
+                        .into_iter()
+                        .map(resolved_col)
+                        .collect::<Vec<_>>();
+
+                    Project::try_new(grand_upstream_plan.clone(), pushdown_column_exprs)?.into()
+                };
+
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
+                // Retry optimization now that the upstream node is different.
+                let new_plan = self
+                    .try_optimize_node(new_plan.clone())?
+                    .or(Transformed::yes(new_plan));
+                Ok(new_plan)
+            }
             LogicalPlan::Unpivot(unpivot) => {
                 let combined_dependencies = plan
                     .required_columns()
@@ -544,6 +582,11 @@ impl PushDownProjection {
                 // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
                 Ok(Transformed::no(plan))
             }
+            LogicalPlan::RecursiveCte(_) => {
+                // Cannot push down past a RecursiveCte,
+                // since the recursive term reads back every column of the previous iteration.
+                Ok(Transformed::no(plan))
+            }
             LogicalPlan::Sink(_) => {
                 panic!("Bad projection due to upstream sink node: {:?}", projection)
             }
@@ -1137,6 +1180,7 @@ mod tests {
                 partition_filters: None,
                 columns: Some(Arc::new(vec!["a".to_string()])),
                 filters: None,
+                aggregations: None,
             },
         )
         .build();
@@ -1181,6 +1225,7 @@ mod tests {
                     "Feb".to_string(),
                 ])),
                 filters: None,
+                aggregations: None,
             },
         )
         .build();
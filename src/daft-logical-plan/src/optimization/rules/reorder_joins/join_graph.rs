@@ -773,7 +773,10 @@ impl JoinGraphBuilder {
                 // Since we hit a join, we need to process the linear chain of Projects and Filters that were encountered starting
                 // from the plan at the root of the linear chain to the current plan.
                 LogicalPlan::Join(Join {
-                    left_on, join_type, ..
+                    left_on,
+                    join_type,
+                    residual_predicate: None,
+                    ..
                 }) if *join_type == JoinType::Inner && !left_on.is_empty() => {
                     self.process_linear_chain(root_plan, plan);
                     break;
@@ -851,6 +854,7 @@ impl JoinGraphBuilder {
                 left_on,
                 right_on,
                 join_type,
+                residual_predicate: None,
                 ..
             }) if *join_type == JoinType::Inner && !left_on.is_empty() => {
                 for l in left_on {
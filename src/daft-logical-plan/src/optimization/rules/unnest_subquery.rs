@@ -126,12 +126,13 @@ impl UnnestScalarSubquery {
                     JoinType::Left
                 };
 
-                let (curr_input, decorrelated_subquery, input_on, subquery_on) =
+                let (curr_input, decorrelated_subquery, input_on, subquery_on, _) =
                     Join::deduplicate_join_columns(
                         curr_input,
                         decorrelated_subquery,
                         input_on,
                         subquery_on,
+                        None,
                         join_type,
                         Default::default(),
                     )?;
@@ -500,13 +501,17 @@ fn pull_up_correlated_cols(
         // ops that cannot pull up correlated columns
         LogicalPlan::ActorPoolProject(..)
         | LogicalPlan::Limit(..)
+        | LogicalPlan::Offset(..)
+        | LogicalPlan::TopN(..)
         | LogicalPlan::Sample(..)
         | LogicalPlan::Source(..)
         | LogicalPlan::Explode(..)
         | LogicalPlan::Unpivot(..)
         | LogicalPlan::Pivot(..)
+        | LogicalPlan::Window(..)
         | LogicalPlan::Concat(..)
         | LogicalPlan::Join(..)
+        | LogicalPlan::RecursiveCte(..)
         | LogicalPlan::Sink(..) => {
             if subquery_on.is_empty() {
                 Ok((plan.clone(), vec![], vec![]))
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 pub enum RepartitionSpec {
     Hash(HashRepartitionConfig),
     Random(RandomShuffleConfig),
+    Range(RangeRepartitionConfig),
     IntoPartitions(IntoPartitionsConfig),
 }
 
@@ -18,6 +19,7 @@ impl RepartitionSpec {
         match self {
             Self::Hash(_) => "Hash",
             Self::Random(_) => "Random",
+            Self::Range(_) => "Range",
             Self::IntoPartitions(_) => "IntoPartitions",
         }
     }
@@ -25,6 +27,7 @@ impl RepartitionSpec {
     pub fn repartition_by(&self) -> Vec<ExprRef> {
         match self {
             Self::Hash(HashRepartitionConfig { by, .. }) => by.clone(),
+            Self::Range(RangeRepartitionConfig { by, .. }) => by.clone(),
             _ => vec![],
         }
     }
@@ -33,6 +36,7 @@ impl RepartitionSpec {
         match self {
             Self::Hash(conf) => conf.multiline_display(),
             Self::Random(conf) => conf.multiline_display(),
+            Self::Range(conf) => conf.multiline_display(),
             Self::IntoPartitions(conf) => conf.multiline_display(),
         }
     }
@@ -48,6 +52,15 @@ impl RepartitionSpec {
             Self::Random(RandomShuffleConfig { num_partitions }) => ClusteringSpec::Random(
                 RandomClusteringConfig::new(num_partitions.unwrap_or(upstream_num_partitions)),
             ),
+            Self::Range(RangeRepartitionConfig {
+                num_partitions,
+                by,
+                descending,
+            }) => ClusteringSpec::Range(RangeClusteringConfig::new(
+                num_partitions.unwrap_or(upstream_num_partitions),
+                by.clone(),
+                descending.clone(),
+            )),
             Self::IntoPartitions(IntoPartitionsConfig { num_partitions }) => {
                 ClusteringSpec::Unknown(UnknownClusteringConfig::new(*num_partitions))
             }
@@ -92,6 +105,37 @@ impl RandomShuffleConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub struct RangeRepartitionConfig {
+    pub num_partitions: Option<usize>,
+    pub by: Vec<ExprRef>,
+    pub descending: Vec<bool>,
+}
+
+impl RangeRepartitionConfig {
+    pub fn new(num_partitions: Option<usize>, by: Vec<ExprRef>, descending: Vec<bool>) -> Self {
+        Self {
+            num_partitions,
+            by,
+            descending,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!("Num partitions = {:?}", self.num_partitions));
+        res.push(format!(
+            "By = {}",
+            self.by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(e, d)| format!("({}, {})", e, if *d { "descending" } else { "ascending" }))
+                .join(", ")
+        ));
+        res
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub struct IntoPartitionsConfig {
     pub num_partitions: usize,
@@ -328,8 +372,8 @@ fn translate_clustering_spec_expr(
 
             Ok(expr.in_subquery(subquery.clone()))
         }
-        // Cannot have agg exprs or references to other tables in clustering specs.
-        Expr::Agg(_) | Expr::Column(..) => Err(()),
+        // Cannot have agg exprs, window exprs or references to other tables in clustering specs.
+        Expr::Agg(_) | Expr::WindowFunction(_) | Expr::Over(..) | Expr::Column(..) => Err(()),
     }
 }
 
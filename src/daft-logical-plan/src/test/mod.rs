@@ -21,6 +21,18 @@ pub fn dummy_scan_operator_with_size(
         schema,
         num_scan_tasks: 1,
         num_rows_per_task,
+        can_absorb_aggregation: false,
+    }))
+}
+
+/// Create a dummy scan operator containing the provided fields in its schema, which can absorb aggregations.
+pub fn dummy_scan_operator_with_aggregation_pushdown(fields: Vec<Field>) -> ScanOperatorRef {
+    let schema = Arc::new(Schema::new(fields).unwrap());
+    ScanOperatorRef(Arc::new(DummyScanOperator {
+        schema,
+        num_scan_tasks: 1,
+        num_rows_per_task: None,
+        can_absorb_aggregation: true,
     }))
 }
 
@@ -8,7 +8,7 @@ use daft_dsl::ExprRef;
 
 use crate::{
     ops::Source,
-    partitioning::{HashRepartitionConfig, RepartitionSpec},
+    partitioning::{HashRepartitionConfig, RangeRepartitionConfig, RepartitionSpec},
     LogicalPlan, SourceInfo,
 };
 
@@ -102,6 +102,27 @@ impl LogicalPlan {
                         })
                         .into()
                     }),
+                RepartitionSpec::Range(RangeRepartitionConfig {
+                    num_partitions,
+                    by,
+                    descending,
+                }) => by
+                    .iter()
+                    .cloned()
+                    .map_and_collect(|expr| f(expr, &input.schema()))?
+                    .update_data(|expr| {
+                        Self::Repartition(Repartition {
+                            plan_id: *plan_id,
+                            input: input.clone(),
+                            repartition_spec: RepartitionSpec::Range(RangeRepartitionConfig {
+                                num_partitions: *num_partitions,
+                                by: expr,
+                                descending: descending.clone(),
+                            }),
+                            stats_state: stats_state.clone(),
+                        })
+                        .into()
+                    }),
                 _ => Transformed::no(self.clone()),
             },
             Self::ActorPoolProject(ActorPoolProject {
@@ -173,6 +194,8 @@ impl LogicalPlan {
                 left_on,
                 right_on,
                 null_equals_nulls,
+                residual_predicate,
+                asof_options,
                 join_type,
                 join_strategy,
                 output_schema,
@@ -196,6 +219,8 @@ impl LogicalPlan {
                             left_on: new_left_on.data,
                             right_on: new_right_on.data,
                             null_equals_nulls: null_equals_nulls.clone(),
+                            residual_predicate: residual_predicate.clone(),
+                            asof_options: asof_options.clone(),
                             join_type: *join_type,
                             join_strategy: *join_strategy,
                             output_schema: output_schema.clone(),
@@ -7,7 +7,9 @@ use snafu::Snafu;
 mod micropartition;
 mod ops;
 
-pub use micropartition::{MicroPartition, MicroPartitionRef};
+pub use micropartition::{
+    aggregate_scan_task_from_metadata, aggregate_scanned_data, MicroPartition, MicroPartitionRef,
+};
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -1,6 +1,7 @@
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
     fmt::Display,
+    ops::Range,
     pin::Pin,
     sync::{Arc, Mutex},
     task::{Context, Poll},
@@ -13,11 +14,14 @@ use common_file_formats::DatabaseSourceConfig;
 use common_file_formats::{FileFormatConfig, ParquetSourceConfig};
 use common_runtime::get_io_runtime;
 use common_scan_info::Pushdowns;
+use daft_avro::AvroConvertOptions;
 use daft_core::prelude::*;
 use daft_csv::{CsvConvertOptions, CsvParseOptions, CsvReadOptions};
-use daft_dsl::ExprRef;
+use daft_dsl::{Expr, ExprRef};
 use daft_io::{IOClient, IOConfig, IOStatsContext, IOStatsRef};
+use daft_ipc::IpcConvertOptions;
 use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
+use daft_orc::OrcConvertOptions;
 use daft_parquet::read::{
     read_parquet_bulk, read_parquet_metadata_bulk, ParquetSchemaInferenceOptions,
 };
@@ -218,14 +222,20 @@ fn materialize_scan_task(
             );
             let parse_options = CsvParseOptions::new_with_defaults(
                 cfg.has_headers,
-                cfg.delimiter,
+                cfg.delimiter.as_deref(),
                 cfg.double_quote,
                 cfg.quote,
                 cfg.allow_variable_columns,
                 cfg.escape_char,
                 cfg.comment,
             )
-            .context(DaftCSVSnafu)?;
+            .and_then(|options| options.with_encoding(cfg.encoding.as_deref()))
+            .context(DaftCSVSnafu)?
+            .with_null_values(cfg.null_values.clone())
+            .with_skip_rows(cfg.skip_rows)
+            .with_skip_rows_after_header(cfg.skip_rows_after_header)
+            .with_truncate_ragged_lines(cfg.truncate_ragged_lines)
+            .with_datetime_formats(cfg.datetime_formats.clone());
             let read_options = CsvReadOptions::new_internal(cfg.buffer_size, cfg.chunk_size);
             let uris = urls.collect::<Vec<_>>();
             daft_csv::read_csv_bulk(
@@ -296,6 +306,77 @@ fn materialize_scan_task(
             )
             .context(DaftCoreComputeSnafu)?
         }
+
+        // ****************
+        // Native Arrow IPC Reads
+        // ****************
+        FileFormatConfig::Ipc(_) => {
+            let convert_options = IpcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            let uris = urls.collect::<Vec<_>>();
+            daft_ipc::read_ipc_bulk(
+                uris.as_slice(),
+                convert_options,
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
+
+        // ****************
+        // Native Avro Reads
+        // ****************
+        FileFormatConfig::Avro(_) => {
+            let convert_options = AvroConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            let uris = urls.collect::<Vec<_>>();
+            daft_avro::read_avro_bulk(
+                uris.as_slice(),
+                sources_to_byte_ranges(scan_task.sources.as_slice()),
+                convert_options,
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
+
+        // ****************
+        // Native ORC Reads
+        // ****************
+        FileFormatConfig::Orc(_) => {
+            let convert_options = OrcConvertOptions {
+                limit: scan_task.pushdowns.limit,
+                include_columns: file_column_names
+                    .as_ref()
+                    .map(|cols| cols.iter().map(|col| (*col).to_string()).collect()),
+                predicate: scan_task.pushdowns.filters.clone(),
+            };
+            let uris = urls.collect::<Vec<_>>();
+            daft_orc::read_orc_bulk(
+                uris.as_slice(),
+                sources_to_byte_ranges(scan_task.sources.as_slice()),
+                convert_options,
+                io_client,
+                io_stats,
+                scan_task.storage_config.multithreaded_io,
+                8,
+            )
+            .context(DaftCoreComputeSnafu)?
+        }
         #[cfg(feature = "python")]
         FileFormatConfig::Database(DatabaseSourceConfig { sql, conn }) => {
             let predicate = scan_task
@@ -411,6 +492,9 @@ impl MicroPartition {
     }
 
     pub fn from_scan_task(scan_task: Arc<ScanTask>, io_stats: IOStatsRef) -> crate::Result<Self> {
+        if scan_task.pushdowns.aggregations.is_some() {
+            return Self::from_aggregated_scan_task(scan_task, io_stats);
+        }
         let schema = scan_task.materialized_schema();
         match (
             &scan_task.metadata,
@@ -511,6 +595,34 @@ impl MicroPartition {
         }
     }
 
+    /// Creates a MicroPartition holding the partial aggregation results of a ScanTask with an aggregation pushdown,
+    /// answering it from Parquet footers where possible and by reading and aggregating the data otherwise.
+    fn from_aggregated_scan_task(
+        scan_task: Arc<ScanTask>,
+        io_stats: IOStatsRef,
+    ) -> crate::Result<Self> {
+        let runtime_handle = get_io_runtime(true);
+        let table = match runtime_handle
+            .block_on_current_thread(aggregate_scan_task_from_metadata(
+                &scan_task,
+                Some(io_stats.clone()),
+            ))
+            .context(DaftCoreComputeSnafu)?
+        {
+            Some(table) => table,
+            None => {
+                let data =
+                    Self::from_scan_task(Arc::new(scan_task.without_aggregations()), io_stats)?;
+                aggregate_scanned_data(&scan_task, &data).context(DaftCoreComputeSnafu)?
+            }
+        };
+        Ok(Self::new_loaded(
+            scan_task.materialized_schema(),
+            Arc::new(vec![table]),
+            None,
+        ))
+    }
+
     #[must_use]
     pub fn empty(schema: Option<SchemaRef>) -> Self {
         let schema = schema.unwrap_or_else(|| Schema::empty().into());
@@ -678,6 +790,90 @@ fn prune_fields_from_schema(
     }
 }
 
+/// Answers the aggregation pushdown of a Parquet ScanTask from the row group statistics of its sources, returning
+/// `None` if the ScanTask has to be read and aggregated instead.
+pub async fn aggregate_scan_task_from_metadata(
+    scan_task: &ScanTask,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Option<RecordBatch>> {
+    let Some(aggregations) = &scan_task.pushdowns.aggregations else {
+        return Ok(None);
+    };
+    let FileFormatConfig::Parquet(ParquetSourceConfig {
+        field_id_mapping, ..
+    }) = scan_task.file_format_config.as_ref()
+    else {
+        return Ok(None);
+    };
+    // Filters and deleted rows aren't reflected in the statistics.
+    if scan_task.pushdowns.filters.is_some()
+        || scan_task.sources.iter().any(|source| {
+            source
+                .get_iceberg_delete_files()
+                .is_some_and(|f| !f.is_empty())
+        })
+    {
+        return Ok(None);
+    }
+    let uris = scan_task
+        .sources
+        .iter()
+        .map(DataSource::get_path)
+        .collect::<Vec<_>>();
+    let row_groups = parquet_sources_to_row_groups(scan_task.sources.as_slice());
+    let parquet_metadata = scan_task
+        .sources
+        .iter()
+        .map(|s| s.get_parquet_metadata().cloned())
+        .collect::<Option<Vec<_>>>();
+    let (_, io_client) = scan_task.storage_config.get_io_client_and_runtime()?;
+    daft_parquet::metadata::aggregate_from_metadata(
+        uris.as_slice(),
+        row_groups.as_deref(),
+        aggregations,
+        scan_task
+            .without_aggregations()
+            .materialized_schema()
+            .as_ref(),
+        parquet_metadata,
+        io_client,
+        io_stats,
+        field_id_mapping.clone(),
+    )
+    .await
+}
+
+/// Computes the partial aggregation results of a ScanTask with an aggregation pushdown from `data`, the data it
+/// read without the pushdown.
+pub fn aggregate_scanned_data(
+    scan_task: &ScanTask,
+    data: &MicroPartition,
+) -> DaftResult<RecordBatch> {
+    let Some(aggregations) = &scan_task.pushdowns.aggregations else {
+        return Err(DaftError::InternalError(
+            "Expected a ScanTask with an aggregation pushdown".to_string(),
+        ));
+    };
+    let tables = data.concat_or_get(IOStatsContext::new("aggregate_scanned_data"))?;
+    let table = match tables.as_slice() {
+        [table] => table.clone(),
+        _ => RecordBatch::empty(Some(data.schema()))?,
+    };
+    let columns = aggregations
+        .iter()
+        .map(|agg| {
+            // Evaluate the aggregation without its alias, which would broadcast it to the length of `table`.
+            let unaliased = match agg.as_ref() {
+                Expr::Alias(inner, _) => inner.clone(),
+                _ => agg.clone(),
+            };
+            let result = table.eval_expression_list(&[unaliased])?;
+            Ok(result.get_column_by_index(0)?.rename(agg.name()))
+        })
+        .collect::<DaftResult<Vec<_>>>()?;
+    RecordBatch::from_nonempty_columns(columns)
+}
+
 fn parquet_sources_to_row_groups(sources: &[DataSource]) -> Option<Vec<Option<Vec<i64>>>> {
     let row_groups = sources
         .iter()
@@ -696,6 +892,24 @@ fn parquet_sources_to_row_groups(sources: &[DataSource]) -> Option<Vec<Option<Ve
     }
 }
 
+fn sources_to_byte_ranges(sources: &[DataSource]) -> Option<Vec<Option<Range<usize>>>> {
+    let byte_ranges = sources
+        .iter()
+        .map(|s| {
+            if let Some(ChunkSpec::Bytes { start, end }) = s.get_chunk_spec() {
+                Some(*start..*end)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    if byte_ranges.iter().any(std::option::Option::is_some) {
+        Some(byte_ranges)
+    } else {
+        None
+    }
+}
+
 pub fn read_csv_into_micropartition(
     uris: &[&str],
     convert_options: Option<CsvConvertOptions>,
@@ -1160,6 +1374,7 @@ pub fn read_parquet_into_micropartition<T: AsRef<str>>(
                     )
                 }),
                 num_rows,
+                None,
             ),
             generated_fields,
         );
@@ -0,0 +1,30 @@
+use common_error::DaftResult;
+use daft_dsl::{ExprRef, WindowSpec};
+use daft_io::IOStatsContext;
+use daft_recordbatch::RecordBatch;
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn window(
+        &self,
+        window_functions: &[ExprRef],
+        window_spec: &WindowSpec,
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+
+        let windowed = match tables.as_slice() {
+            [] => RecordBatch::empty(Some(self.schema.clone()))?
+                .window(window_functions, window_spec)?,
+            [t] => t.window(window_functions, window_spec)?,
+            _ => unreachable!(),
+        };
+        Ok(Self::new_loaded(
+            windowed.schema.clone(),
+            vec![windowed].into(),
+            None,
+        ))
+    }
+}
@@ -268,6 +268,33 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn window(&self, py: Python, window_functions: Vec<PyExpr>) -> PyResult<Self> {
+        let converted_window_functions: Vec<daft_dsl::ExprRef> = window_functions
+            .into_iter()
+            .map(std::convert::Into::into)
+            .collect();
+        // All window functions of a Window operation share the same window spec.
+        let window_spec = converted_window_functions
+            .iter()
+            .find_map(|e| match e.as_ref() {
+                daft_dsl::Expr::Over(_, spec) => Some(spec.clone()),
+                daft_dsl::Expr::Alias(inner, _) => match inner.as_ref() {
+                    daft_dsl::Expr::Over(_, spec) => Some(spec.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .ok_or_else(|| {
+                PyValueError::new_err("Expected at least one window function in window")
+            })?;
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .window(converted_window_functions.as_slice(), &window_spec)?
+                .into())
+        })
+    }
+
     #[pyo3(signature = (
         right,
         left_on,
@@ -0,0 +1,28 @@
+//! Reader for ORC files.
+//!
+//! Files can be read whole or in byte ranges: a stripe is read by the range in which it starts, so
+//! a set of ranges that tiles a file reads every stripe exactly once.
+//!
+//! Decoding is done by arrow2, which supports primitive, string and binary columns in files that
+//! are either uncompressed or zlib-compressed.
+mod read;
+mod schema;
+
+use common_error::DaftError;
+pub use read::{read_orc_bulk, stream_orc, OrcConvertOptions};
+pub use schema::read_orc_schema;
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error joining spawned task: {}", source))]
+    JoinError { source: tokio::task::JoinError },
+}
+
+impl From<Error> for DaftError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::JoinError { source } => Self::External(Box::new(source)),
+        }
+    }
+}
@@ -0,0 +1,607 @@
+use std::{
+    io::{self, Cursor, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use arrow2::{
+    datatypes::{DataType, Field as ArrowField, Schema as ArrowSchema},
+    io::orc::{
+        format::{
+            proto::{column_encoding::Kind as EncodingKind, Footer},
+            read::{read_stripe_column, read_stripe_footer, FileMetadata},
+        },
+        read::{deserialize, infer_schema},
+    },
+};
+use bytes::Bytes;
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_core::{
+    prelude::{Schema, SchemaRef},
+    series::Series,
+    utils::arrow::cast_array_for_daft_if_needed,
+};
+use daft_dsl::{optimization::get_required_columns, ExprRef};
+use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use snafu::futures::try_future::TryFutureExt;
+
+use crate::{schema::read_orc_metadata, JoinSnafu};
+
+#[derive(Debug, Clone, Default)]
+pub struct OrcConvertOptions {
+    pub limit: Option<usize>,
+    pub include_columns: Option<Vec<String>>,
+    pub predicate: Option<ExprRef>,
+}
+
+/// Streams the stripes of a single ORC file as record batches, with the projection, predicate and
+/// limit in `convert_options` applied.
+///
+/// If `byte_range` is given, only the stripes that start within that range are read.
+pub async fn stream_orc(
+    uri: String,
+    byte_range: Option<Range<usize>>,
+    convert_options: OrcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<BoxStream<'static, DaftResult<RecordBatch>>> {
+    let metadata = read_orc_metadata(&uri, io_client.clone(), io_stats.clone()).await?;
+    let stripes = stripes_in_range(&metadata.footer, byte_range.as_ref());
+    let limit = convert_options.limit;
+    let reader = OrcStripeReader::try_new(uri, metadata, convert_options, io_client, io_stats)?;
+    // Stripes are fetched one at a time, so that no more of the file is read than the limit needs.
+    let stream = futures::stream::try_unfold(
+        (reader, stripes.into_iter(), limit),
+        |(reader, mut stripes, remaining_rows)| async move {
+            if remaining_rows == Some(0) {
+                return Ok(None);
+            }
+            let Some(stripe) = stripes.next() else {
+                return Ok(None);
+            };
+            let mut table = reader.read_stripe(stripe).await?;
+            if let Some(remaining) = remaining_rows {
+                if table.len() > remaining {
+                    table = table.head(remaining)?;
+                }
+            }
+            let remaining_rows = remaining_rows.map(|remaining| remaining - table.len());
+            Ok(Some((table, (reader, stripes, remaining_rows))))
+        },
+    );
+    Ok(stream.boxed())
+}
+
+pub fn read_orc_bulk(
+    uris: &[&str],
+    byte_ranges: Option<Vec<Option<Range<usize>>>>,
+    convert_options: OrcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    multithreaded_io: bool,
+    num_parallel_tasks: usize,
+) -> DaftResult<Vec<RecordBatch>> {
+    let runtime_handle = get_io_runtime(multithreaded_io);
+    let tables = runtime_handle.block_on_current_thread(async move {
+        // Launch a read task per URI, throttling the number of concurrent file reads to num_parallel tasks.
+        let task_stream = futures::stream::iter(uris.iter().enumerate().map(|(i, uri)| {
+            let (uri, byte_range, convert_options, io_client, io_stats) = (
+                (*uri).to_string(),
+                byte_ranges
+                    .as_ref()
+                    .and_then(|byte_ranges| byte_ranges[i].clone()),
+                convert_options.clone(),
+                io_client.clone(),
+                io_stats.clone(),
+            );
+            tokio::task::spawn(async move {
+                read_orc_single_into_table(uri, byte_range, convert_options, io_client, io_stats)
+                    .await
+            })
+            .context(JoinSnafu {})
+        }));
+        let mut remaining_rows = convert_options.limit.map(|limit| limit as i64);
+        task_stream
+            // Limit the number of file reads we have in flight at any given time.
+            .buffered(num_parallel_tasks)
+            // Terminate the stream if we have already reached the row limit. With the upstream buffering, we will still read up to
+            // num_parallel_tasks redundant files.
+            .try_take_while(|result| {
+                match (result, remaining_rows) {
+                    // Limit has been met, early-terminate.
+                    (_, Some(rows_left)) if rows_left <= 0 => futures::future::ready(Ok(false)),
+                    // Limit has not yet been met, update remaining limit slack and continue.
+                    (Ok(table), Some(rows_left)) => {
+                        remaining_rows = Some(rows_left - table.len() as i64);
+                        futures::future::ready(Ok(true))
+                    }
+                    // (1) No limit, never early-terminate.
+                    // (2) Encountered error, propagate error to try_collect to allow it to short-circuit.
+                    (_, None) | (Err(_), _) => futures::future::ready(Ok(true)),
+                }
+            })
+            .try_collect::<Vec<_>>()
+            .await
+    })?;
+
+    tables.into_iter().collect::<DaftResult<Vec<_>>>()
+}
+
+async fn read_orc_single_into_table(
+    uri: String,
+    byte_range: Option<Range<usize>>,
+    convert_options: OrcConvertOptions,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<RecordBatch> {
+    let metadata = read_orc_metadata(&uri, io_client.clone(), io_stats.clone()).await?;
+    let stripes = stripes_in_range(&metadata.footer, byte_range.as_ref());
+    let limit = convert_options.limit;
+    let reader = OrcStripeReader::try_new(uri, metadata, convert_options, io_client, io_stats)?;
+    let mut tables = vec![];
+    let mut num_rows = 0;
+    for stripe in stripes {
+        if limit.is_some_and(|limit| num_rows >= limit) {
+            break;
+        }
+        let table = reader.read_stripe(stripe).await?;
+        num_rows += table.len();
+        tables.push(table);
+    }
+    let table = if tables.is_empty() {
+        RecordBatch::empty(Some(reader.output_schema.clone()))?
+    } else {
+        RecordBatch::concat(&tables)?
+    };
+    match limit {
+        Some(limit) if table.len() > limit => table.head(limit),
+        _ => Ok(table),
+    }
+}
+
+/// Returns the indices of the stripes that start within `byte_range`, or of all stripes if no range is given.
+fn stripes_in_range(footer: &Footer, byte_range: Option<&Range<usize>>) -> Vec<usize> {
+    footer
+        .stripes
+        .iter()
+        .enumerate()
+        .filter(|(_, stripe)| {
+            byte_range.is_none_or(|range| range.contains(&(stripe.offset() as usize)))
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// A fetched region of a file that can be read from with seeks relative to the start of the file,
+/// which is how the stripe readers address the data.
+struct FileRegion {
+    bytes: Cursor<Bytes>,
+    offset: u64,
+}
+
+impl Read for FileRegion {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.bytes.read(buf)
+    }
+}
+
+impl Seek for FileRegion {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "seek before fetched region")
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.bytes.seek(pos)? + self.offset)
+    }
+}
+
+/// Decodes the stripes of an ORC file into [`RecordBatch`]es, one per stripe.
+///
+/// Only the columns needed for `include_columns` and the predicate are fetched and decoded.
+struct OrcStripeReader {
+    uri: String,
+    metadata: FileMetadata,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    // ORC column id and arrow field of each decoded column, in file order.
+    read_columns: Vec<(u32, ArrowField)>,
+    // Schema of the decoded columns, in file order.
+    read_schema: SchemaRef,
+    // Schema of the emitted tables, after the predicate columns have been dropped again.
+    output_schema: SchemaRef,
+    include_columns: Option<Vec<String>>,
+    predicate: Option<ExprRef>,
+}
+
+impl OrcStripeReader {
+    fn try_new(
+        uri: String,
+        metadata: FileMetadata,
+        convert_options: OrcConvertOptions,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> DaftResult<Self> {
+        let OrcConvertOptions {
+            include_columns,
+            predicate,
+            ..
+        } = convert_options;
+        let arrow_schema = infer_schema(&metadata.footer)?;
+        // The root of an ORC schema is a struct, whose fields are the top-level columns.
+        let column_ids = &metadata.footer.types[0].subtypes;
+
+        let column_index = |name: &str| {
+            arrow_schema
+                .fields
+                .iter()
+                .position(|field| field.name == name)
+        };
+        // Columns missing from this file are left out here and filled with nulls when the table is cast
+        // to the scan's schema.
+        let include_columns = include_columns.map(|include_columns| {
+            include_columns
+                .into_iter()
+                .filter(|name| column_index(name).is_some())
+                .collect::<Vec<_>>()
+        });
+        let projection = match &include_columns {
+            Some(include_columns) => {
+                let mut required = include_columns.clone();
+                if let Some(predicate) = &predicate {
+                    required.extend(get_required_columns(predicate));
+                }
+                let mut projection = required
+                    .iter()
+                    .map(|name| {
+                        column_index(name).ok_or_else(|| {
+                            DaftError::FieldNotFound(format!(
+                                "Column {name} not found in ORC schema"
+                            ))
+                        })
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                projection.sort_unstable();
+                projection.dedup();
+                projection
+            }
+            None => (0..arrow_schema.fields.len()).collect(),
+        };
+        let read_columns = projection
+            .iter()
+            .map(|i| (column_ids[*i], arrow_schema.fields[*i].clone()))
+            .collect::<Vec<_>>();
+        let read_schema = Arc::new(Schema::try_from(&ArrowSchema::from(
+            read_columns
+                .iter()
+                .map(|(_, field)| field.clone())
+                .collect::<Vec<_>>(),
+        ))?);
+        let output_schema = match &include_columns {
+            Some(include_columns) => Arc::new(Schema::new(
+                include_columns
+                    .iter()
+                    .map(|name| read_schema.get_field(name).cloned())
+                    .collect::<DaftResult<Vec<_>>>()?,
+            )?),
+            None => read_schema.clone(),
+        };
+
+        Ok(Self {
+            uri,
+            metadata,
+            io_client,
+            io_stats,
+            read_columns,
+            read_schema,
+            output_schema,
+            include_columns,
+            predicate,
+        })
+    }
+
+    async fn read_stripe(&self, stripe: usize) -> DaftResult<RecordBatch> {
+        let info = &self.metadata.footer.stripes[stripe];
+        let num_rows = info.number_of_rows() as usize;
+        let mut table = if self.read_columns.is_empty() {
+            // Nothing to decode, but the stripe information still knows the row count.
+            RecordBatch::new_with_size(self.read_schema.clone(), vec![], num_rows)?
+        } else {
+            let start = info.offset();
+            let end = start + info.index_length() + info.data_length() + info.footer_length();
+            let bytes = self
+                .io_client
+                .single_url_get(
+                    self.uri.clone(),
+                    Some(start as usize..end as usize),
+                    self.io_stats.clone(),
+                )
+                .await?
+                .bytes()
+                .await?;
+            let mut region = FileRegion {
+                bytes: Cursor::new(bytes),
+                offset: start,
+            };
+            let footer = read_stripe_footer(&mut region, &self.metadata, stripe, &mut vec![])
+                .map_err(arrow2::error::Error::from)?;
+            let columns = self
+                .read_columns
+                .iter()
+                .zip(self.read_schema.fields.values())
+                .map(|((column_id, arrow_field), field)| {
+                    let encoding = footer
+                        .columns
+                        .get(*column_id as usize)
+                        .map(|encoding| encoding.kind());
+                    check_encoding(&arrow_field.data_type, encoding).map_err(|reason| {
+                        DaftError::NotImplemented(format!(
+                            "Unable to read column {} of ORC file {}: {reason}",
+                            field.name, self.uri
+                        ))
+                    })?;
+                    let column = read_stripe_column(
+                        &mut region,
+                        &self.metadata,
+                        stripe,
+                        footer.clone(),
+                        *column_id,
+                        vec![],
+                    )
+                    .map_err(arrow2::error::Error::from)?;
+                    let array = deserialize(arrow_field.data_type.clone(), &column)?;
+                    Series::try_from_field_and_arrow_array(
+                        Arc::new(field.clone()),
+                        cast_array_for_daft_if_needed(array),
+                    )
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            RecordBatch::new_with_size(self.read_schema.clone(), columns, num_rows)?
+        };
+        if let Some(predicate) = &self.predicate {
+            table = table.filter(&[predicate.clone()])?;
+        }
+        if let Some(include_columns) = &self.include_columns {
+            table = table.get_columns(include_columns)?;
+        }
+        Ok(table)
+    }
+}
+
+/// Checks that arrow2 can decode a column of `data_type` written with `encoding`. Integer and
+/// length streams are only decoded in their version 2 run length encoding, which writers have
+/// defaulted to since ORC 0.12.
+fn check_encoding(data_type: &DataType, encoding: Option<EncodingKind>) -> Result<(), String> {
+    match (data_type, encoding) {
+        (DataType::Int8, _) => Err("tinyint columns are not supported".to_string()),
+        (
+            DataType::Int16 | DataType::Int32 | DataType::Int64 | DataType::Utf8 | DataType::Binary,
+            Some(kind @ (EncodingKind::Direct | EncodingKind::Dictionary)),
+        ) => Err(format!(
+            "{kind:?} encoding from ORC versions before 0.12 is not supported"
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::io::orc::format::proto::{
+        column_encoding, r#type, stream, ColumnEncoding, CompressionKind, Footer, PostScript,
+        Stream, StripeFooter, StripeInformation, Type,
+    };
+    use common_error::DaftResult;
+    use daft_core::prelude::AsArrow;
+    use daft_dsl::{lit, resolved_col};
+    use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
+    use prost::Message;
+
+    use super::{read_orc_single_into_table, stripes_in_range, OrcConvertOptions};
+
+    /// Stripes of the test file as (number of rows, value of `a`, value of `b`), each column holding a
+    /// single repeated value so that it can be written as one short-repeat run.
+    const STRIPES: [(u8, i64, &str); 2] = [(5, 7, "x"), (4, 9, "yy")];
+
+    /// Writes an uncompressed ORC file with columns `a: bigint` and `b: string`.
+    fn write_file() -> tempfile::NamedTempFile {
+        let mut bytes = b"ORC".to_vec();
+        let mut stripes = vec![];
+        for (num_rows, a, b) in STRIPES {
+            let offset = bytes.len() as u64;
+            let short_repeat = |value: u8| vec![num_rows - 3, value];
+            let streams = [
+                (
+                    stream::Kind::Data,
+                    1,
+                    short_repeat(((a << 1) ^ (a >> 63)) as u8),
+                ),
+                (stream::Kind::Length, 2, short_repeat(b.len() as u8)),
+                (
+                    stream::Kind::Data,
+                    2,
+                    b.repeat(num_rows as usize).into_bytes(),
+                ),
+            ];
+            for (_, _, data) in &streams {
+                bytes.extend_from_slice(data);
+            }
+            let encoding = |kind: column_encoding::Kind| ColumnEncoding {
+                kind: Some(kind as i32),
+                ..Default::default()
+            };
+            let footer = StripeFooter {
+                streams: streams
+                    .iter()
+                    .map(|(kind, column, data)| Stream {
+                        kind: Some(*kind as i32),
+                        column: Some(*column),
+                        length: Some(data.len() as u64),
+                    })
+                    .collect(),
+                columns: vec![
+                    encoding(column_encoding::Kind::Direct),
+                    encoding(column_encoding::Kind::DirectV2),
+                    encoding(column_encoding::Kind::DirectV2),
+                ],
+                ..Default::default()
+            }
+            .encode_to_vec();
+            let data_length = bytes.len() as u64 - offset;
+            bytes.extend_from_slice(&footer);
+            stripes.push(StripeInformation {
+                offset: Some(offset),
+                index_length: Some(0),
+                data_length: Some(data_length),
+                footer_length: Some(footer.len() as u64),
+                number_of_rows: Some(num_rows as u64),
+            });
+        }
+
+        let type_ = |kind: r#type::Kind| Type {
+            kind: Some(kind as i32),
+            ..Default::default()
+        };
+        let footer = Footer {
+            header_length: Some(3),
+            content_length: Some(bytes.len() as u64 - 3),
+            stripes,
+            types: vec![
+                Type {
+                    subtypes: vec![1, 2],
+                    field_names: vec!["a".to_string(), "b".to_string()],
+                    ..type_(r#type::Kind::Struct)
+                },
+                type_(r#type::Kind::Long),
+                type_(r#type::Kind::String),
+            ],
+            number_of_rows: Some(STRIPES.iter().map(|(n, _, _)| *n as u64).sum()),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        bytes.extend_from_slice(&footer);
+        let postscript = PostScript {
+            footer_length: Some(footer.len() as u64),
+            compression: Some(CompressionKind::None as i32),
+            metadata_length: Some(0),
+            magic: Some("ORC".to_string()),
+            ..Default::default()
+        }
+        .encode_to_vec();
+        bytes.extend_from_slice(&postscript);
+        bytes.push(postscript.len() as u8);
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), bytes).unwrap();
+        file
+    }
+
+    async fn read(
+        file: &tempfile::NamedTempFile,
+        byte_range: Option<std::ops::Range<usize>>,
+        convert_options: OrcConvertOptions,
+    ) -> DaftResult<RecordBatch> {
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let uri = file.path().to_str().unwrap().to_string();
+        read_orc_single_into_table(uri, byte_range, convert_options, io_client, None).await
+    }
+
+    fn column_a(table: &RecordBatch) -> DaftResult<Vec<i64>> {
+        Ok(table
+            .get_column("a")?
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
+
+    #[test]
+    fn test_stripes_in_range() {
+        let footer = Footer {
+            stripes: [3, 100, 200]
+                .into_iter()
+                .map(|offset| StripeInformation {
+                    offset: Some(offset),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(stripes_in_range(&footer, None), vec![0, 1, 2]);
+        assert_eq!(stripes_in_range(&footer, Some(&(0..100))), vec![0]);
+        assert_eq!(stripes_in_range(&footer, Some(&(100..250))), vec![1, 2]);
+        assert!(stripes_in_range(&footer, Some(&(4..100))).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_whole_file() -> DaftResult<()> {
+        let file = write_file();
+        let table = read(&file, None, OrcConvertOptions::default()).await?;
+        assert_eq!(table.schema.names(), vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(column_a(&table)?, vec![7, 7, 7, 7, 7, 9, 9, 9, 9]);
+        let b = table.get_column("b")?.utf8()?.as_arrow().clone();
+        assert_eq!(b.value(0), "x");
+        assert_eq!(b.value(8), "yy");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_byte_ranges_read_every_stripe_once() -> DaftResult<()> {
+        let file = write_file();
+        let file_size = std::fs::metadata(file.path())?.len() as usize;
+        for chunk_size in [1, 5, 16, file_size] {
+            let mut values = vec![];
+            for start in (0..file_size).step_by(chunk_size) {
+                let range = start..(start + chunk_size).min(file_size);
+                let table = read(&file, Some(range), OrcConvertOptions::default()).await?;
+                values.extend(column_a(&table)?);
+            }
+            assert_eq!(
+                values,
+                vec![7, 7, 7, 7, 7, 9, 9, 9, 9],
+                "chunk size {chunk_size}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_with_projection_predicate_and_limit() -> DaftResult<()> {
+        let file = write_file();
+        let table = read(
+            &file,
+            None,
+            OrcConvertOptions {
+                limit: Some(2),
+                include_columns: Some(vec!["b".to_string()]),
+                predicate: Some(resolved_col("a").gt(lit(7i64))),
+            },
+        )
+        .await?;
+        assert_eq!(table.schema.names(), vec!["b".to_string()]);
+        let b = table.get_column("b")?.utf8()?.as_arrow().clone();
+        assert_eq!(b.values_iter().collect::<Vec<_>>(), vec!["yy", "yy"]);
+
+        let table = read(
+            &file,
+            None,
+            OrcConvertOptions {
+                include_columns: Some(vec![]),
+                ..Default::default()
+            },
+        )
+        .await?;
+        assert_eq!(table.num_columns(), 0);
+        assert_eq!(table.len(), 9);
+        Ok(())
+    }
+}
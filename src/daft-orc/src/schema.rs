@@ -0,0 +1,98 @@
+use std::{io::Read, sync::Arc};
+
+use arrow2::io::orc::{
+    format::{
+        proto::{CompressionKind, Footer, Metadata, PostScript},
+        read::{decompress::Decompressor, FileMetadata},
+    },
+    read::infer_schema,
+};
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::Schema;
+use daft_io::{IOClient, IOStatsRef};
+use prost::Message;
+
+/// Number of bytes fetched from the end of the file when reading its tail, which covers the footer
+/// of most files. A second request is only made if the footer turns out to be larger.
+const INITIAL_TAIL_FETCH_SIZE: usize = 16 * 1024;
+
+/// Fetches and decodes the postscript and footer of the ORC file at `uri`.
+///
+/// The file-level column statistics that sit between the stripes and the footer are not needed for
+/// reading and are left empty.
+pub(crate) async fn read_orc_metadata(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<FileMetadata> {
+    let invalid =
+        |reason: &str| DaftError::ValueError(format!("Unable to read ORC file {uri}: {reason}"));
+    let file_size = io_client
+        .single_url_get_size(uri.to_string(), io_stats.clone())
+        .await?;
+    if file_size == 0 {
+        return Err(invalid("file is empty"));
+    }
+    let fetch_tail = |len: usize| {
+        let io_client = io_client.clone();
+        let io_stats = io_stats.clone();
+        async move {
+            io_client
+                .single_url_get(uri.to_string(), Some(file_size - len..file_size), io_stats)
+                .await?
+                .bytes()
+                .await
+        }
+    };
+
+    let mut tail = fetch_tail(file_size.min(INITIAL_TAIL_FETCH_SIZE)).await?;
+    // The file ends with the length of the postscript, which is preceded by the postscript itself and the footer.
+    let postscript_len = tail[tail.len() - 1] as usize;
+    let postscript_start = tail
+        .len()
+        .checked_sub(postscript_len + 1)
+        .ok_or_else(|| invalid("truncated postscript"))?;
+    let postscript = PostScript::decode(&tail[postscript_start..tail.len() - 1])
+        .map_err(|_| invalid("corrupt postscript"))?;
+    let compression = postscript.compression();
+    if !matches!(compression, CompressionKind::None | CompressionKind::Zlib) {
+        return Err(DaftError::NotImplemented(format!(
+            "Reading ORC files with {compression:?} compression is not supported yet: {uri}"
+        )));
+    }
+
+    let footer_len = postscript.footer_length() as usize;
+    let tail_len = footer_len + postscript_len + 1;
+    if tail_len > file_size {
+        return Err(invalid("footer extends past the start of the file"));
+    }
+    if tail_len > tail.len() {
+        tail = fetch_tail(tail_len).await?;
+    }
+    let footer_start = tail.len() - tail_len;
+    let mut footer = vec![];
+    Decompressor::new(
+        &tail[footer_start..footer_start + footer_len],
+        compression,
+        vec![],
+    )
+    .read_to_end(&mut footer)?;
+    let footer = Footer::decode(footer.as_slice()).map_err(|_| invalid("corrupt footer"))?;
+
+    Ok(FileMetadata {
+        postscript,
+        footer,
+        metadata: Metadata::default(),
+    })
+}
+
+/// Reads the schema of the ORC file at `uri` from its footer.
+pub async fn read_orc_schema(
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> DaftResult<Schema> {
+    let metadata = read_orc_metadata(uri, io_client, io_stats).await?;
+    let arrow_schema = infer_schema(&metadata.footer)?;
+    Schema::try_from(&arrow_schema)
+}
@@ -14,8 +14,9 @@ use daft_recordbatch::RecordBatch;
 use daft_stats::TruthValue;
 use futures::{future::try_join_all, stream::BoxStream, StreamExt};
 use parquet2::{
+    metadata::ColumnChunkMetaData,
     page::{CompressedPage, Page},
-    read::get_owned_page_stream_from_column_start,
+    read::{get_owned_page_stream_from_column_start, get_owned_page_stream_from_selected_pages},
     FallibleStreamingIterator,
 };
 use snafu::ResultExt;
@@ -24,6 +25,7 @@ use tokio_stream::wrappers::ReceiverStream;
 use crate::{
     determine_parquet_parallelism,
     metadata::read_parquet_metadata,
+    pruning::{column_byte_ranges, prune_with_indexes, ColumnPageSelections},
     read::ParquetSchemaInferenceOptions,
     read_planner::{CoalescePass, RangesContainer, ReadPlanner, SplitLargeRequestPass},
     statistics,
@@ -61,6 +63,44 @@ fn streaming_decompression<S: futures::Stream<Item = parquet2::error::Result<Com
     }
 }
 
+/// Returns the decompressed pages of `col` to decode. If page indexes ruled out some of its rows,
+/// only its selected pages are read, and each of them only decodes its selected rows.
+async fn column_page_stream(
+    col: &ColumnChunkMetaData,
+    ranges: &RangesContainer,
+    page_selections: &ColumnPageSelections,
+    max_page_size: usize,
+    uri: &str,
+) -> DaftResult<BoxStream<'static, parquet2::error::Result<Page>>> {
+    let range_reader = Box::pin(
+        ranges
+            .get_ranges_reader(column_byte_ranges(col, page_selections))
+            .await?,
+    );
+    let compressed_page_stream = match page_selections.get(&col.byte_range().0) {
+        Some(pages) => get_owned_page_stream_from_selected_pages(
+            col,
+            range_reader,
+            pages.clone(),
+            vec![],
+            max_page_size,
+        )
+        .map(StreamExt::boxed),
+        None => get_owned_page_stream_from_column_start(
+            col,
+            range_reader,
+            vec![],
+            Arc::new(|_, _| true),
+            max_page_size,
+        )
+        .map(StreamExt::boxed),
+    }
+    .with_context(|_| UnableToCreateParquetPageStreamSnafu::<String> {
+        path: uri.to_string(),
+    })?;
+    Ok(streaming_decompression(compressed_page_stream).boxed())
+}
+
 pub struct StreamIterator<S> {
     curr: Option<Page>,
     src: tokio::sync::Mutex<S>,
@@ -299,6 +339,7 @@ impl ParquetReaderBuilder {
             arrow_schema,
             row_ranges,
             self.chunk_size,
+            self.predicate,
         )
     }
 }
@@ -316,6 +357,8 @@ pub struct ParquetFileReader {
     arrow_schema: arrow2::datatypes::SchemaRef,
     row_ranges: Arc<Vec<RowGroupRange>>,
     chunk_size: Option<usize>,
+    predicate: Option<ExprRef>,
+    page_selections: Arc<ColumnPageSelections>,
 }
 
 impl ParquetFileReader {
@@ -330,6 +373,7 @@ impl ParquetFileReader {
         arrow_schema: arrow2::datatypes::Schema,
         row_ranges: Vec<RowGroupRange>,
         chunk_size: Option<usize>,
+        predicate: Option<ExprRef>,
     ) -> super::Result<Self> {
         Ok(Self {
             uri,
@@ -337,6 +381,8 @@ impl ParquetFileReader {
             arrow_schema: arrow_schema.into(),
             row_ranges: Arc::new(row_ranges),
             chunk_size,
+            predicate,
+            page_selections: Default::default(),
         })
     }
 
@@ -344,6 +390,39 @@ impl ParquetFileReader {
         &self.arrow_schema
     }
 
+    /// Prunes the row groups and pages to read with the bloom filters and page indexes of the
+    /// columns in the filter, for equality and `IsIn` lookups that min/max statistics can't rule out.
+    pub async fn prune_with_indexes(
+        mut self,
+        io_client: Arc<IOClient>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Self> {
+        let Some(predicate) = &self.predicate else {
+            return Ok(self);
+        };
+        if self.row_ranges.is_empty() {
+            return Ok(self);
+        }
+        let daft_schema = Schema::try_from(self.arrow_schema.as_ref()).with_context(|_| {
+            UnableToConvertSchemaToDaftSnafu {
+                path: self.uri.to_string(),
+            }
+        })?;
+        let (row_ranges, page_selections) = prune_with_indexes(
+            self.row_ranges.as_ref().clone(),
+            predicate,
+            &daft_schema,
+            &self.metadata,
+            &self.uri,
+            io_client,
+            io_stats,
+        )
+        .await?;
+        self.row_ranges = Arc::new(row_ranges);
+        self.page_selections = Arc::new(page_selections);
+        Ok(self)
+    }
+
     fn naive_read_plan(&self) -> super::Result<ReadPlanner> {
         let arrow_fields = &self.arrow_schema.fields;
 
@@ -365,10 +444,9 @@ impl ParquetFileReader {
                     .collect::<Vec<_>>();
 
                 for col in filtered_cols {
-                    let (start, len) = col.byte_range();
-                    let end = start + len;
-
-                    read_planner.add_range(start as usize, end as usize);
+                    for range in column_byte_ranges(col, &self.page_selections) {
+                        read_planner.add_range(range.start, range.end);
+                    }
                 }
             }
         }
@@ -426,6 +504,7 @@ impl ParquetFileReader {
             .map(move |row_range| {
                 let metadata = self.metadata.clone();
                 let arrow_schema = self.arrow_schema.clone();
+                let page_selections = self.page_selections.clone();
                 let ranges = ranges.clone();
                 let uri = uri.clone();
 
@@ -436,6 +515,7 @@ impl ParquetFileReader {
                         let uri = uri.clone();
                         let field = field.clone();
                         let metadata = metadata.clone();
+                        let page_selections = page_selections.clone();
 
                         tokio::task::spawn(async move {
                             let rg = metadata
@@ -455,30 +535,16 @@ impl ParquetFileReader {
                                 num_values.push(col.metadata().num_values as usize);
                                 ptypes.push(col.descriptor().descriptor.primitive_type.clone());
 
-                                let byte_range = {
-                                    let (start, len) = col.byte_range();
-                                    let end: u64 = start + len;
-                                    start as usize..end as usize
-                                };
-                                let range_reader =
-                                    Box::pin(ranges.get_range_reader(byte_range).await?);
-                                let compressed_page_stream =
-                                    get_owned_page_stream_from_column_start(
-                                        col,
-                                        range_reader,
-                                        vec![],
-                                        Arc::new(|_, _| true),
-                                        Self::MAX_PAGE_SIZE,
-                                    )
-                                    .with_context(|_| {
-                                        UnableToCreateParquetPageStreamSnafu::<String> {
-                                            path: uri.clone(),
-                                        }
-                                    })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
-                                let pinned_stream = Box::pin(page_stream);
+                                let page_stream = column_page_stream(
+                                    col,
+                                    &ranges,
+                                    &page_selections,
+                                    Self::MAX_PAGE_SIZE,
+                                    &uri,
+                                )
+                                .await?;
                                 decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                    .push(StreamIterator::new(page_stream, rt_handle.clone()));
                             }
                             let arr_iter = column_iter_to_arrays(
                                 decompressed_iters,
@@ -589,33 +655,16 @@ impl ParquetFileReader {
 
                         let metadata = metadata.clone();
 
-                        let needed_byte_ranges = filtered_cols_idx
-                            .iter()
-                            .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                start as usize..end as usize
-                            })
-                            .collect::<Vec<_>>();
+                        let page_selections = self.page_selections.clone();
 
                         let ranges = ranges.clone();
 
                         let handle = tokio::task::spawn(async move {
-                            let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
-
-                            for range in needed_byte_ranges {
-                                let range_reader = ranges.get_range_reader(range).await?;
-                                range_readers.push(Box::pin(range_reader));
-                            }
-
                             let mut decompressed_iters =
                                 Vec::with_capacity(filtered_cols_idx.len());
                             let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
                             let mut num_values = Vec::with_capacity(filtered_cols_idx.len());
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
-                            {
+                            for col_idx in filtered_cols_idx {
                                 let col = metadata
                                     .row_groups
                                     .get(&row_range.row_group_index)
@@ -626,23 +675,16 @@ impl ParquetFileReader {
                                 ptypes.push(col.descriptor().descriptor.primitive_type.clone());
                                 num_values.push(col.metadata().num_values as usize);
 
-                                let compressed_page_stream =
-                                    get_owned_page_stream_from_column_start(
-                                        col,
-                                        range_reader,
-                                        vec![],
-                                        Arc::new(|_, _| true),
-                                        Self::MAX_PAGE_SIZE,
-                                    )
-                                    .with_context(|_| {
-                                        UnableToCreateParquetPageStreamSnafu::<String> {
-                                            path: owned_uri.clone(),
-                                        }
-                                    })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
-                                let pinned_stream = Box::pin(page_stream);
+                                let page_stream = column_page_stream(
+                                    col,
+                                    &ranges,
+                                    &page_selections,
+                                    Self::MAX_PAGE_SIZE,
+                                    &owned_uri,
+                                )
+                                .await?;
                                 decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                    .push(StreamIterator::new(page_stream, rt_handle.clone()));
                             }
 
                             let (send, recv) = tokio::sync::oneshot::channel();
@@ -771,33 +813,16 @@ impl ParquetFileReader {
                             .map(|(i, _)| i)
                             .collect::<Vec<_>>();
 
-                        let needed_byte_ranges = filtered_cols_idx
-                            .iter()
-                            .map(|i| {
-                                let c = columns.get(*i).unwrap();
-                                let (start, len) = c.byte_range();
-                                let end: u64 = start + len;
-                                start as usize..end as usize
-                            })
-                            .collect::<Vec<_>>();
+                        let page_selections = self.page_selections.clone();
                         let metadata = metadata.clone();
                         let ranges = ranges.clone();
                         let handle = tokio::task::spawn(async move {
-                            let mut range_readers = Vec::with_capacity(filtered_cols_idx.len());
-
-                            for range in needed_byte_ranges {
-                                let range_reader = ranges.get_range_reader(range).await?;
-                                range_readers.push(Box::pin(range_reader));
-                            }
-
                             let mut decompressed_iters =
                                 Vec::with_capacity(filtered_cols_idx.len());
                             let mut ptypes = Vec::with_capacity(filtered_cols_idx.len());
                             let mut num_values = Vec::with_capacity(filtered_cols_idx.len());
 
-                            for (col_idx, range_reader) in
-                                filtered_cols_idx.into_iter().zip(range_readers)
-                            {
+                            for col_idx in filtered_cols_idx {
                                 let col = metadata
                                     .row_groups
                                     .get(&row_range.row_group_index)
@@ -808,23 +833,16 @@ impl ParquetFileReader {
                                 ptypes.push(col.descriptor().descriptor.primitive_type.clone());
                                 num_values.push(col.metadata().num_values as usize);
 
-                                let compressed_page_stream =
-                                    get_owned_page_stream_from_column_start(
-                                        col,
-                                        range_reader,
-                                        vec![],
-                                        Arc::new(|_, _| true),
-                                        Self::MAX_PAGE_SIZE,
-                                    )
-                                    .with_context(|_| {
-                                        UnableToCreateParquetPageStreamSnafu::<String> {
-                                            path: owned_uri.clone(),
-                                        }
-                                    })?;
-                                let page_stream = streaming_decompression(compressed_page_stream);
-                                let pinned_stream = Box::pin(page_stream);
+                                let page_stream = column_page_stream(
+                                    col,
+                                    &ranges,
+                                    &page_selections,
+                                    Self::MAX_PAGE_SIZE,
+                                    &owned_uri,
+                                )
+                                .await?;
                                 decompressed_iters
-                                    .push(StreamIterator::new(pinned_stream, rt_handle.clone()));
+                                    .push(StreamIterator::new(page_stream, rt_handle.clone()));
                             }
 
                             let (send, recv) = tokio::sync::oneshot::channel();
@@ -9,6 +9,7 @@ use snafu::Snafu;
 
 mod file;
 pub mod metadata;
+mod pruning;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod read;
@@ -80,6 +81,16 @@ pub enum Error {
         source: arrow2::error::Error,
     },
 
+    #[snafu(display(
+        "Unable to read parquet page indexes or bloom filters for file {}: {}",
+        path,
+        source
+    ))]
+    UnableToReadParquetIndexes {
+        path: String,
+        source: parquet2::error::Error,
+    },
+
     #[snafu(display("Unable to create page stream for parquet file {}: {}", path, source))]
     UnableToCreateParquetPageStream {
         path: String,
@@ -1,14 +1,25 @@
 use std::{collections::BTreeMap, sync::Arc};
 
 use common_error::DaftResult;
-use daft_core::datatypes::Field;
-use daft_dsl::common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_core::{count_mode::CountMode, prelude::*};
+use daft_dsl::{
+    common_treenode::{Transformed, TreeNode, TreeNodeRecursion},
+    AggExpr, Column, Expr, ExprRef, ResolvedColumn,
+};
 use daft_io::{IOClient, IOStatsRef};
+use daft_recordbatch::RecordBatch;
+use daft_stats::ColumnRangeStatistics;
+use futures::future::try_join_all;
 pub use parquet2::metadata::{FileMetaData, RowGroupMetaData};
-use parquet2::{read::deserialize_metadata, schema::types::ParquetType};
+use parquet2::{
+    metadata::ColumnChunkMetaData, read::deserialize_metadata, schema::types::ParquetType,
+};
 use snafu::ResultExt;
 
-use crate::{Error, JoinSnafu, UnableToParseMetadataSnafu};
+use crate::{
+    statistics::parquet_statistics_to_column_range_statistics, Error, JoinSnafu,
+    UnableToParseMetadataSnafu,
+};
 
 fn metadata_len(buffer: &[u8], len: usize) -> i32 {
     i32::from_le_bytes(buffer[len - 8..len - 4].try_into().unwrap())
@@ -299,14 +310,208 @@ pub(crate) async fn read_parquet_metadata(
     }
 }
 
+/// Answers ungrouped count/min/max `aggregations` over Parquet files from their footers' row group statistics.
+///
+/// No data pages are read. `row_groups` optionally selects the row groups to aggregate in each file, and
+/// `parquet_metadata` holds the files' footers if they have already been read.
+///
+/// Returns `None` if the statistics can't answer the aggregations, in which case the data has to be read instead.
+#[allow(clippy::too_many_arguments)]
+pub async fn aggregate_from_metadata(
+    uris: &[&str],
+    row_groups: Option<&[Option<Vec<i64>>]>,
+    aggregations: &[ExprRef],
+    schema: &Schema,
+    parquet_metadata: Option<Vec<Arc<FileMetaData>>>,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+    field_id_mapping: Option<Arc<BTreeMap<i32, Field>>>,
+) -> DaftResult<Option<RecordBatch>> {
+    let parquet_metadata = match parquet_metadata {
+        Some(parquet_metadata) => parquet_metadata,
+        None => {
+            try_join_all(uris.iter().map(|uri| {
+                let io_client = io_client.clone();
+                let io_stats = io_stats.clone();
+                let field_id_mapping = field_id_mapping.clone();
+                async move {
+                    let size = io_client
+                        .single_url_get_size((*uri).to_string(), io_stats.clone())
+                        .await?;
+                    read_parquet_metadata(uri, size, io_client, io_stats, field_id_mapping)
+                        .await
+                        .map(Arc::new)
+                }
+            }))
+            .await?
+        }
+    };
+
+    let mut selected_row_groups = vec![];
+    for (i, (uri, file_metadata)) in uris.iter().zip(&parquet_metadata).enumerate() {
+        match row_groups.and_then(|row_groups| row_groups[i].as_ref()) {
+            Some(indices) => {
+                for &index in indices {
+                    let row_group =
+                        file_metadata
+                            .row_groups
+                            .get(&(index as usize))
+                            .ok_or_else(|| Error::ParquetRowGroupOutOfIndex {
+                                path: (*uri).to_string(),
+                                row_group: index,
+                                total_row_groups: file_metadata.row_groups.len() as i64,
+                            })?;
+                    selected_row_groups.push(row_group);
+                }
+            }
+            None => selected_row_groups.extend(file_metadata.row_groups.values()),
+        }
+    }
+    aggregate_row_group_statistics(&selected_row_groups, aggregations, schema)
+}
+
+/// Answers ungrouped count/min/max `aggregations` over `row_groups` from their statistics, returning `None` if an
+/// aggregation is of another kind or a row group lacks the statistics needed to answer it.
+pub fn aggregate_row_group_statistics(
+    row_groups: &[&RowGroupMetaData],
+    aggregations: &[ExprRef],
+    schema: &Schema,
+) -> DaftResult<Option<RecordBatch>> {
+    let mut columns = Vec::with_capacity(aggregations.len());
+    for agg in aggregations {
+        let field = agg.to_field(schema)?;
+        let agg_expr = match agg.as_ref() {
+            Expr::Alias(inner, _) => inner.as_ref(),
+            expr => expr,
+        };
+        let column = match agg_expr {
+            Expr::Agg(AggExpr::Count(_, CountMode::All)) => {
+                let count = row_groups.iter().map(|rg| rg.num_rows() as u64).sum();
+                Some(UInt64Array::from((field.name.as_str(), vec![count])).into_series())
+            }
+            Expr::Agg(AggExpr::Count(child, mode)) => column_name(child)
+                .and_then(|name| count_from_statistics(row_groups, name, *mode))
+                .map(|count| UInt64Array::from((field.name.as_str(), vec![count])).into_series()),
+            Expr::Agg(AggExpr::Min(child) | AggExpr::Max(child)) => match column_name(child) {
+                Some(name) => bound_from_statistics(
+                    row_groups,
+                    schema.get_field(name)?,
+                    matches!(agg_expr, Expr::Agg(AggExpr::Min(_))),
+                )?
+                .map(|bound| bound.cast(&field.dtype))
+                .transpose()?
+                .map(|bound| bound.rename(&field.name)),
+                None => None,
+            },
+            _ => None,
+        };
+        match column {
+            Some(column) => columns.push(column),
+            None => return Ok(None),
+        }
+    }
+    RecordBatch::from_nonempty_columns(columns).map(Some)
+}
+
+fn column_name(expr: &ExprRef) -> Option<&str> {
+    match expr.as_ref() {
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(name),
+        _ => None,
+    }
+}
+
+/// Returns the chunk of the top-level, non-nested column `name` in `row_group`.
+fn column_chunk<'a>(
+    row_group: &'a RowGroupMetaData,
+    name: &str,
+) -> Option<&'a ColumnChunkMetaData> {
+    row_group
+        .columns()
+        .iter()
+        .find(|column| column.descriptor().path_in_schema == [name])
+}
+
+fn null_count(column: &ColumnChunkMetaData) -> Option<u64> {
+    column
+        .statistics()?
+        .ok()?
+        .null_count()
+        .map(|null_count| null_count as u64)
+}
+
+fn count_from_statistics(
+    row_groups: &[&RowGroupMetaData],
+    name: &str,
+    mode: CountMode,
+) -> Option<u64> {
+    row_groups.iter().try_fold(0, |count, rg| {
+        let null_count = null_count(column_chunk(rg, name)?)?;
+        Some(
+            count
+                + match mode {
+                    CountMode::All => rg.num_rows() as u64,
+                    CountMode::Valid => rg.num_rows() as u64 - null_count,
+                    CountMode::Null => null_count,
+                },
+        )
+    })
+}
+
+/// Returns the min (or max) of `field` over `row_groups` from their lower (or upper) bounds, which is null if every
+/// value is null.
+fn bound_from_statistics(
+    row_groups: &[&RowGroupMetaData],
+    field: &Field,
+    lower: bool,
+) -> DaftResult<Option<Series>> {
+    // Writers may truncate the bounds of string and binary columns, so only trust those of fixed-width types.
+    if !(field.dtype.is_numeric() || field.dtype.is_temporal() || field.dtype == DataType::Boolean)
+    {
+        return Ok(None);
+    }
+    let mut bounds = vec![];
+    for rg in row_groups {
+        let Some(column) = column_chunk(rg, &field.name) else {
+            return Ok(None);
+        };
+        if null_count(column) == Some(rg.num_rows() as u64) {
+            continue;
+        }
+        let Some(Ok(statistics)) = column.statistics() else {
+            return Ok(None);
+        };
+        match parquet_statistics_to_column_range_statistics(statistics.as_ref(), &field.dtype) {
+            Ok(ColumnRangeStatistics::Loaded(lower_bound, upper_bound)) => {
+                bounds.push(if lower { lower_bound } else { upper_bound });
+            }
+            _ => return Ok(None),
+        }
+    }
+    if bounds.is_empty() {
+        return Ok(Some(Series::full_null(&field.name, &field.dtype, 1)));
+    }
+    let bounds = Series::concat(&bounds.iter().collect::<Vec<_>>())?;
+    let bound = if lower {
+        bounds.min(None)?
+    } else {
+        bounds.max(None)?
+    };
+    Ok(Some(bound))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use common_error::DaftResult;
+    use common_runtime::get_io_runtime;
+    use daft_core::count_mode::CountMode;
+    use daft_dsl::{resolved_col, Expr};
     use daft_io::{IOClient, IOConfig};
+    use daft_recordbatch::RecordBatch;
 
-    use super::read_parquet_metadata;
+    use super::{aggregate_from_metadata, read_parquet_metadata};
+    use crate::read::read_parquet;
 
     #[tokio::test]
     async fn test_parquet_metadata_from_s3() -> DaftResult<()> {
@@ -322,4 +527,87 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_aggregate_from_metadata_matches_data() -> DaftResult<()> {
+        let file = path_macro::path!(
+            env!("CARGO_MANIFEST_DIR")
+                / ".."
+                / ".."
+                / "tests"
+                / "assets"
+                / "parquet-data"
+                / "sampled-tpch-with-stats.parquet"
+        )
+        .to_str()
+        .unwrap()
+        .to_string();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let table = read_parquet(
+            &file,
+            None,
+            None,
+            None,
+            None,
+            None,
+            io_client.clone(),
+            None,
+            true,
+            Default::default(),
+            None,
+        )?;
+
+        let first_column = table.schema.fields[0].name.as_str();
+        let mut aggregations = vec![
+            resolved_col(first_column)
+                .count(CountMode::All)
+                .alias("count"),
+            resolved_col(first_column)
+                .count(CountMode::Valid)
+                .alias("count_valid"),
+        ];
+        for (name, field) in &table.schema.fields {
+            if field.dtype.is_numeric() || field.dtype.is_temporal() {
+                aggregations.push(
+                    resolved_col(name.as_str())
+                        .min()
+                        .alias(format!("min_{name}")),
+                );
+                aggregations.push(
+                    resolved_col(name.as_str())
+                        .max()
+                        .alias(format!("max_{name}")),
+                );
+            }
+        }
+        assert!(aggregations.len() > 2);
+
+        // Evaluate each aggregation of the data separately, as aliased global aggregations are broadcast.
+        let expected = RecordBatch::from_nonempty_columns(
+            aggregations
+                .iter()
+                .map(|agg| {
+                    let Expr::Alias(unaliased, name) = agg.as_ref() else {
+                        unreachable!()
+                    };
+                    let result = table.eval_expression_list(&[unaliased.clone()])?;
+                    Ok(result.get_column_by_index(0)?.rename(name))
+                })
+                .collect::<DaftResult<Vec<_>>>()?,
+        )?;
+        let actual = get_io_runtime(true)
+            .block_on_current_thread(aggregate_from_metadata(
+                &[&file],
+                None,
+                &aggregations,
+                &table.schema,
+                None,
+                io_client,
+                None,
+                None,
+            ))?
+            .expect("Row group statistics should answer count, min and max");
+        assert_eq!(actual, expected);
+        Ok(())
+    }
 }
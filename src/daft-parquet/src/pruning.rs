@@ -0,0 +1,692 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Cursor, Read, Seek, SeekFrom},
+    ops::Range,
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use daft_core::prelude::*;
+use daft_dsl::{optimization::get_required_columns, Expr, ExprRef, LiteralValue, Operator};
+use daft_io::{IOClient, IOStatsRef};
+use daft_stats::{ColumnRangeStatistics, TableStatistics, TruthValue};
+use futures::future::try_join_all;
+use parquet2::{
+    bloom_filter,
+    indexes::{compute_rows, select_pages, FilteredPage, Interval},
+    metadata::{ColumnChunkMetaData, FileMetaData},
+    read::{read_columns_indexes, read_pages_locations},
+    schema::types::PhysicalType,
+};
+use snafu::ResultExt;
+
+use crate::{
+    file::RowGroupRange, statistics, UnableToReadParquetIndexesSnafu,
+    UnableToRunExpressionOnStatsSnafu,
+};
+
+/// Number of bytes fetched for the header of a bloom filter. Headers are around 15 bytes, and the
+/// bitset that follows is fetched separately if it doesn't fit in the same request.
+const BLOOM_FILTER_HEADER_SIZE_ESTIMATE: usize = 32;
+
+/// The pages to read of column chunks whose row groups had rows ruled out by page indexes, along
+/// with the rows of each page to decode, keyed by the start of the column chunk.
+pub(crate) type ColumnPageSelections = HashMap<u64, Vec<FilteredPage>>;
+
+/// Returns the byte ranges of `column` to read, which only cover its dictionary page and selected
+/// pages if page indexes ruled out some of its rows.
+pub(crate) fn column_byte_ranges(
+    column: &ColumnChunkMetaData,
+    page_selections: &ColumnPageSelections,
+) -> Vec<Range<usize>> {
+    let (start, len) = column.byte_range();
+    match page_selections.get(&start) {
+        Some(pages) => page_byte_ranges(start, pages),
+        None => std::iter::once(start as usize..(start + len) as usize).collect(),
+    }
+}
+
+/// Returns the byte ranges of the dictionary page of the column chunk starting at `column_start`, if
+/// it has one, and of its `pages` that have selected rows.
+fn page_byte_ranges(column_start: u64, pages: &[FilteredPage]) -> Vec<Range<usize>> {
+    let dictionary = pages
+        .first()
+        .map(|page| column_start as usize..page.start as usize)
+        .filter(|range| !range.is_empty());
+    dictionary
+        .into_iter()
+        .chain(
+            pages
+                .iter()
+                .filter(|page| !page.selected_rows.is_empty())
+                .map(|page| page.start as usize..page.start as usize + page.length),
+        )
+        .collect()
+}
+
+/// Collects the columns that the top-level conjunction of `predicate` compares to literals for
+/// equality, i.e. `col = lit` and `col IN (lit, ...)`, along with the values they are compared to.
+fn point_lookups(predicate: &ExprRef, lookups: &mut Vec<(String, Vec<LiteralValue>)>) {
+    match predicate.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            point_lookups(left, lookups);
+            point_lookups(right, lookups);
+        }
+        Expr::BinaryOp {
+            op: Operator::Eq,
+            left,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (Expr::Column(_), Expr::Literal(value)) => {
+                lookups.push((left.name().to_string(), vec![value.clone()]));
+            }
+            (Expr::Literal(value), Expr::Column(_)) => {
+                lookups.push((right.name().to_string(), vec![value.clone()]));
+            }
+            _ => {}
+        },
+        Expr::IsIn(expr, items) if matches!(expr.as_ref(), Expr::Column(_)) => {
+            let values = items
+                .iter()
+                .map(|item| match item.as_ref() {
+                    Expr::Literal(value) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>();
+            if let Some(values) = values {
+                lookups.push((expr.name().to_string(), values));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn literal_as_i64(value: &LiteralValue) -> Option<i64> {
+    match value {
+        LiteralValue::Int8(v) => Some(*v as i64),
+        LiteralValue::UInt8(v) => Some(*v as i64),
+        LiteralValue::Int16(v) => Some(*v as i64),
+        LiteralValue::UInt16(v) => Some(*v as i64),
+        LiteralValue::Int32(v) => Some(*v as i64),
+        LiteralValue::UInt32(v) => Some(*v as i64),
+        LiteralValue::Int64(v) => Some(*v),
+        LiteralValue::UInt64(v) => i64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+/// Hashes `value` the way bloom filters hash the values of a column of `dtype` stored as
+/// `physical_type`, or returns `None` if the value can't be looked up in the filter.
+///
+/// Floats are not supported since values that compare equal, e.g. `0.0` and `-0.0`, hash differently.
+fn bloom_filter_hash(
+    value: &LiteralValue,
+    dtype: &DataType,
+    physical_type: &PhysicalType,
+) -> Option<u64> {
+    match (dtype, physical_type, value) {
+        (DataType::Utf8, PhysicalType::ByteArray, LiteralValue::Utf8(v)) => {
+            Some(bloom_filter::hash_byte(v))
+        }
+        (DataType::Binary, PhysicalType::ByteArray, LiteralValue::Binary(v)) => {
+            Some(bloom_filter::hash_byte(v))
+        }
+        (DataType::Int8 | DataType::Int16 | DataType::Int32, PhysicalType::Int32, _) => {
+            literal_as_i64(value)
+                .and_then(|v| i32::try_from(v).ok())
+                .map(bloom_filter::hash_native)
+        }
+        (DataType::Date, PhysicalType::Int32, LiteralValue::Date(v)) => {
+            Some(bloom_filter::hash_native(*v))
+        }
+        (DataType::Int64, PhysicalType::Int64, _) => {
+            literal_as_i64(value).map(bloom_filter::hash_native)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the chunk of a top-level, non-nested column, which is the only kind of column whose
+/// bloom filter and pages map directly to the values of a field.
+fn flat_column<'a>(
+    columns: &'a [ColumnChunkMetaData],
+    name: &str,
+) -> Option<&'a ColumnChunkMetaData> {
+    let mut matching = columns
+        .iter()
+        .filter(|c| c.descriptor().path_in_schema[0] == name);
+    match (matching.next(), matching.next()) {
+        (Some(column), None)
+            if column.descriptor().path_in_schema.len() == 1
+                && column.descriptor().descriptor.max_rep_level == 0 =>
+        {
+            Some(column)
+        }
+        _ => None,
+    }
+}
+
+async fn fetch(
+    uri: &str,
+    range: Range<usize>,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Bytes> {
+    Ok(io_client
+        .single_url_get(uri.into(), Some(range), io_stats)
+        .await?
+        .bytes()
+        .await?)
+}
+
+/// Fetches the bitset of the bloom filter of `column`, if it has a supported one.
+async fn fetch_bloom_filter(
+    column: &ColumnChunkMetaData,
+    uri: &str,
+    io_client: &IOClient,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<Option<Bytes>> {
+    let Some(offset) = column.metadata().bloom_filter_offset else {
+        return Ok(None);
+    };
+    let offset = offset as usize;
+    let header = fetch(
+        uri,
+        offset..offset + BLOOM_FILTER_HEADER_SIZE_ESTIMATE,
+        io_client,
+        io_stats.clone(),
+    )
+    .await?;
+    let Some((header_len, bitset_len)) = bloom_filter::read_header(&header)
+        .context(UnableToReadParquetIndexesSnafu { path: uri })?
+    else {
+        return Ok(None);
+    };
+    if header_len + bitset_len <= header.len() {
+        Ok(Some(header.slice(header_len..header_len + bitset_len)))
+    } else {
+        let start = offset + header_len;
+        let bitset = fetch(uri, start..start + bitset_len, io_client, io_stats).await?;
+        Ok(Some(bitset))
+    }
+}
+
+/// Bytes of a parquet file that start at `offset`, which parquet2's index readers seek in with
+/// positions relative to the start of the file.
+struct FileSlice {
+    offset: u64,
+    cursor: Cursor<Bytes>,
+}
+
+impl Read for FileSlice {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for FileSlice {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Seeking before the start of the fetched parquet index bytes",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.cursor.seek(pos)? + self.offset)
+    }
+}
+
+fn index_ranges(column: &ColumnChunkMetaData, with_column_index: bool) -> Vec<Range<usize>> {
+    let chunk = column.column_chunk();
+    let column_index = chunk
+        .column_index_offset
+        .zip(chunk.column_index_length)
+        .filter(|_| with_column_index);
+    let offset_index = chunk.offset_index_offset.zip(chunk.offset_index_length);
+    [column_index, offset_index]
+        .into_iter()
+        .flatten()
+        .map(|(offset, len)| offset as usize..offset as usize + len as usize)
+        .collect()
+}
+
+fn intersect(lhs: &[Interval], rhs: &[Interval]) -> Vec<Interval> {
+    lhs.iter()
+        .flat_map(|l| {
+            rhs.iter().filter_map(move |r| {
+                let start = l.start.max(r.start);
+                let end = (l.start + l.length).min(r.start + r.length);
+                (start < end).then(|| Interval::new(start, end - start))
+            })
+        })
+        .collect()
+}
+
+/// Prunes the row groups in `row_ranges` further than their min/max statistics can, using the bloom
+/// filters and page indexes of the columns in `predicate`.
+///
+/// A row group is skipped when the bloom filter of a column rules out every value the column is
+/// compared to for equality, or when the page index of a column rules out all of its pages. When
+/// only some pages are ruled out, the row group's range is narrowed to the rows of the remaining
+/// pages, and only those pages of its column chunks are read. Bytes that are no longer fetched are
+/// reported to `io_stats`.
+pub(crate) async fn prune_with_indexes(
+    row_ranges: Vec<RowGroupRange>,
+    predicate: &ExprRef,
+    schema: &Schema,
+    metadata: &FileMetaData,
+    uri: &str,
+    io_client: Arc<IOClient>,
+    io_stats: Option<IOStatsRef>,
+) -> super::Result<(Vec<RowGroupRange>, ColumnPageSelections)> {
+    let mut lookups = vec![];
+    point_lookups(predicate, &mut lookups);
+    let predicate_columns = get_required_columns(predicate)
+        .into_iter()
+        .filter(|name| schema.has_field(name))
+        .collect::<HashSet<_>>();
+
+    let row_group_bytes = |row_group_index: usize| -> usize {
+        metadata.row_groups[&row_group_index]
+            .columns()
+            .iter()
+            .filter(|c| schema.has_field(&c.descriptor().path_in_schema[0]))
+            .map(|c| c.byte_range().1 as usize)
+            .sum()
+    };
+    let mut bytes_pruned = 0;
+
+    // Skip the row groups whose bloom filters rule out all the values of an equality lookup. The
+    // bloom filters of all row groups are fetched concurrently.
+    let bloom_filter_lookups = row_ranges
+        .iter()
+        .enumerate()
+        .flat_map(|(i, range)| {
+            let columns = metadata.row_groups[&range.row_group_index].columns();
+            lookups.iter().filter_map(move |(name, values)| {
+                let field = schema.get_field(name).ok()?;
+                let column = flat_column(columns, name)?;
+                let physical_type = column.descriptor().descriptor.primitive_type.physical_type;
+                let hashes = values
+                    .iter()
+                    .map(|v| bloom_filter_hash(v, &field.dtype, &physical_type))
+                    .collect::<Option<Vec<_>>>()?;
+                Some((i, column, hashes))
+            })
+        })
+        .collect::<Vec<_>>();
+    let bitsets = try_join_all(
+        bloom_filter_lookups
+            .iter()
+            .map(|(_, column, _)| fetch_bloom_filter(column, uri, &io_client, io_stats.clone())),
+    )
+    .await?;
+    let mut ruled_out = vec![false; row_ranges.len()];
+    for ((i, _, hashes), bitset) in bloom_filter_lookups.iter().zip(bitsets) {
+        if let Some(bitset) = bitset
+            && !bitset.is_empty()
+            && !hashes
+                .iter()
+                .any(|hash| bloom_filter::is_in_set(&bitset, *hash))
+        {
+            ruled_out[*i] = true;
+        }
+    }
+    let remaining = row_ranges
+        .into_iter()
+        .zip(ruled_out)
+        .filter_map(|(range, ruled_out)| {
+            if ruled_out {
+                bytes_pruned += row_group_bytes(range.row_group_index);
+                None
+            } else {
+                Some(range)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Fetch the page indexes of all remaining row groups at once, since writers place them together
+    // at the end of the file. Column indexes are only needed for the columns of the predicate, but
+    // offset indexes are needed for all columns to know where their pages are.
+    let indexed_columns = remaining
+        .iter()
+        .flat_map(|range| {
+            let columns = metadata.row_groups[&range.row_group_index].columns();
+            schema
+                .fields
+                .keys()
+                .filter_map(move |name| flat_column(columns, name).map(|c| (name, c)))
+        })
+        .collect::<Vec<_>>();
+    let index_ranges = indexed_columns
+        .iter()
+        .flat_map(|(name, column)| index_ranges(column, predicate_columns.contains(*name)))
+        .collect::<Vec<_>>();
+    let has_column_indexes = indexed_columns.iter().any(|(name, column)| {
+        predicate_columns.contains(*name) && column.column_chunk().column_index_offset.is_some()
+    });
+    let mut page_selections = ColumnPageSelections::new();
+    if !has_column_indexes {
+        if let Some(io_stats) = io_stats {
+            io_stats.mark_bytes_pruned(bytes_pruned);
+        }
+        return Ok((remaining, page_selections));
+    }
+    let span_start = index_ranges.iter().map(|r| r.start).min().unwrap();
+    let span_end = index_ranges.iter().map(|r| r.end).max().unwrap();
+    let mut index_bytes = FileSlice {
+        offset: span_start as u64,
+        cursor: Cursor::new(fetch(uri, span_start..span_end, &io_client, io_stats.clone()).await?),
+    };
+
+    let mut pruned = Vec::with_capacity(remaining.len());
+    for range in remaining {
+        let rg = &metadata.row_groups[&range.row_group_index];
+        let num_rows = rg.num_rows();
+        let mut selected_rows: Option<Vec<Interval>> = None;
+        for name in &predicate_columns {
+            let Some(column) = flat_column(rg.columns(), name) else {
+                continue;
+            };
+            let chunk = column.column_chunk();
+            if chunk.column_index_offset.is_none() || chunk.offset_index_offset.is_none() {
+                continue;
+            }
+            let column = std::slice::from_ref(column);
+            let indexes = read_columns_indexes(&mut index_bytes, column)
+                .context(UnableToReadParquetIndexesSnafu { path: uri })?;
+            let locations = read_pages_locations(&mut index_bytes, column)
+                .context(UnableToReadParquetIndexesSnafu { path: uri })?;
+            let (Some(index), Some(locations)) = (indexes.first(), locations.first()) else {
+                continue;
+            };
+
+            let dtype = &schema.get_field(name).unwrap().dtype;
+            let selected = statistics::column_index_to_page_stats(index.as_ref(), dtype)
+                .into_iter()
+                .map(|page_stats| {
+                    let columns = schema
+                        .fields
+                        .keys()
+                        .map(|field_name| {
+                            let stats = if field_name == name {
+                                page_stats.clone()
+                            } else {
+                                ColumnRangeStatistics::Missing
+                            };
+                            (field_name.clone(), stats)
+                        })
+                        .collect();
+                    let evaled = TableStatistics { columns }
+                        .eval_expression(predicate)
+                        .with_context(|_| UnableToRunExpressionOnStatsSnafu {
+                            path: uri.to_string(),
+                        })?;
+                    Ok(evaled.to_truth_value() != TruthValue::False)
+                })
+                .collect::<super::Result<Vec<_>>>()?;
+            if selected.len() != locations.len() {
+                continue;
+            }
+            let rows = compute_rows(&selected, locations, num_rows)
+                .context(UnableToReadParquetIndexesSnafu { path: uri })?;
+            selected_rows = Some(match selected_rows {
+                Some(prev) => intersect(&prev, &rows),
+                None => rows,
+            });
+        }
+
+        let Some(selected_rows) = selected_rows else {
+            pruned.push(range);
+            continue;
+        };
+        let range_rows = Interval::new(range.start, range.num_rows.min(num_rows - range.start));
+        let selected_rows = intersect(&selected_rows, &[range_rows]);
+        let num_selected = selected_rows.iter().map(|i| i.length).sum::<usize>();
+        if num_selected == 0 {
+            bytes_pruned += row_group_bytes(range.row_group_index);
+            continue;
+        }
+        if num_selected == range_rows.length {
+            pruned.push(range);
+            continue;
+        }
+
+        // Only select pages when every column read can skip to them, so that all columns decode the
+        // same rows.
+        let mut column_pages = Vec::with_capacity(schema.fields.len());
+        for name in schema.fields.keys() {
+            let Some(column) = flat_column(rg.columns(), name)
+                .filter(|c| c.column_chunk().offset_index_offset.is_some())
+            else {
+                break;
+            };
+            let locations = read_pages_locations(&mut index_bytes, std::slice::from_ref(column))
+                .context(UnableToReadParquetIndexesSnafu { path: uri })?;
+            let Some(locations) = locations.first() else {
+                break;
+            };
+            let pages = select_pages(&selected_rows, locations, num_rows)
+                .context(UnableToReadParquetIndexesSnafu { path: uri })?;
+            column_pages.push((column, pages));
+        }
+        if column_pages.len() != schema.fields.len() {
+            pruned.push(range);
+            continue;
+        }
+        for (column, pages) in column_pages {
+            let (start, len) = column.byte_range();
+            let bytes_read = page_byte_ranges(start, &pages)
+                .iter()
+                .map(|r| r.len())
+                .sum::<usize>();
+            bytes_pruned += (len as usize).saturating_sub(bytes_read);
+            page_selections.insert(start, pages);
+        }
+        // Pages decode only their selected rows, so the range covers the selected rows from its start.
+        pruned.push(RowGroupRange {
+            row_group_index: range.row_group_index,
+            start: 0,
+            num_rows: num_selected,
+        });
+    }
+
+    if let Some(io_stats) = io_stats {
+        io_stats.mark_bytes_pruned(bytes_pruned);
+    }
+    Ok((pruned, page_selections))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::{
+        chunk::Chunk,
+        io::parquet::write::{
+            CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+        },
+    };
+    use common_error::DaftResult;
+    use daft_dsl::{lit, resolved_col};
+    use daft_io::{IOConfig, IOStatsContext};
+    use daft_recordbatch::RecordBatch;
+
+    use super::*;
+    use crate::file::ParquetReaderBuilder;
+
+    /// Writes `0..num_rows` as a single row group of small pages, with a page index.
+    fn write_sorted_ids(path: &std::path::Path, num_rows: i64) -> DaftResult<()> {
+        let ids = Int64Array::from(("id", (0..num_rows).collect::<Vec<_>>())).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![ids])?;
+        let arrow_schema = table.schema.to_arrow()?;
+        let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+        let options = WriteOptions {
+            write_statistics: true,
+            version: Version::V2,
+            compression: CompressionOptions::Uncompressed,
+            data_pagesize_limit: Some(1024),
+        };
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(Ok(chunk)),
+            &arrow_schema,
+            options,
+            vec![vec![Encoding::Plain]],
+        )?;
+        let mut writer = FileWriter::try_new(std::fs::File::create(path)?, arrow_schema, options)?;
+        for row_group in row_groups {
+            writer.write(row_group?)?;
+        }
+        writer.end(None)?;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_page_index_pruning() -> DaftResult<()> {
+        let file = tempfile::NamedTempFile::new()?;
+        write_sorted_ids(file.path(), 100_000)?;
+        let uri = file.path().to_str().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let io_stats = IOStatsContext::new("test_page_index_pruning");
+
+        let predicate = resolved_col("id").eq(lit(123i64));
+        let reader =
+            ParquetReaderBuilder::from_uri(uri, io_client.clone(), Some(io_stats.clone()), None)
+                .await?
+                .set_filter(predicate.clone())
+                .build()?
+                .prune_with_indexes(io_client.clone(), Some(io_stats.clone()))
+                .await?;
+        // The row group's min/max statistics cover the value, but its page index only keeps the
+        // first pages, so the rest of the column chunk is never fetched.
+        assert!(io_stats.load_bytes_pruned() > 0);
+
+        let ranges = reader.prebuffer_ranges(io_client, Some(io_stats))?;
+        let table = reader.read_from_ranges_into_table(ranges).await?;
+        assert!(table.len() < 100_000);
+        let table = table.filter(&[predicate])?;
+        assert_eq!(table.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_page_index_pruning_reads_only_selected_pages() -> DaftResult<()> {
+        let file = tempfile::NamedTempFile::new()?;
+        write_sorted_ids(file.path(), 100_000)?;
+        let uri = file.path().to_str().unwrap();
+        let io_client = Arc::new(IOClient::new(IOConfig::default().into())?);
+        let io_stats = IOStatsContext::new("test_page_index_pruning_reads_only_selected_pages");
+
+        let predicate = resolved_col("id")
+            .eq(lit(40_000i64))
+            .or(resolved_col("id").eq(lit(90_000i64)));
+        let reader =
+            ParquetReaderBuilder::from_uri(uri, io_client.clone(), Some(io_stats.clone()), None)
+                .await?
+                .set_filter(predicate.clone())
+                .build()?
+                .prune_with_indexes(io_client.clone(), Some(io_stats.clone()))
+                .await?;
+
+        // Only the pages holding the two values are read, rather than every page up to the last
+        // one of them.
+        let ranges = reader.prebuffer_ranges(io_client, Some(io_stats))?;
+        let table = reader.read_from_ranges_into_table(ranges).await?;
+        assert!(table.len() < 1_000);
+        let table = table.filter(&[predicate])?;
+        let ids = table.get_column("id")?.i64()?;
+        assert_eq!(
+            ids.into_iter().collect::<Vec<_>>(),
+            vec![Some(&40_000), Some(&90_000)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_lookups() {
+        let predicate = resolved_col("a")
+            .eq(lit(1))
+            .and(lit("x").eq(resolved_col("b")))
+            .and(resolved_col("c").is_in(vec![lit(2), lit(3)]))
+            .and(resolved_col("d").gt(lit(4)));
+        let mut lookups = vec![];
+        point_lookups(&predicate, &mut lookups);
+        assert_eq!(
+            lookups,
+            vec![
+                ("a".to_string(), vec![LiteralValue::Int32(1)]),
+                ("b".to_string(), vec![LiteralValue::Utf8("x".to_string())]),
+                (
+                    "c".to_string(),
+                    vec![LiteralValue::Int32(2), LiteralValue::Int32(3)]
+                ),
+            ]
+        );
+
+        // Equalities under a disjunction don't rule out anything on their own.
+        let predicate = resolved_col("a")
+            .eq(lit(1))
+            .or(resolved_col("b").eq(lit(2)));
+        let mut lookups = vec![];
+        point_lookups(&predicate, &mut lookups);
+        assert!(lookups.is_empty());
+    }
+
+    #[test]
+    fn test_bloom_filter_hash() {
+        let mut bitset = vec![0; 32];
+        for v in 0..10i32 {
+            bloom_filter::insert(&mut bitset, bloom_filter::hash_native(v));
+        }
+        bloom_filter::insert(&mut bitset, bloom_filter::hash_byte("abc"));
+
+        let int_hash = |v: LiteralValue, dtype: DataType| {
+            bloom_filter_hash(&v, &dtype, &PhysicalType::Int32).unwrap()
+        };
+        assert!(bloom_filter::is_in_set(
+            &bitset,
+            int_hash(LiteralValue::Int64(3), DataType::Int32)
+        ));
+        assert!(bloom_filter::is_in_set(
+            &bitset,
+            int_hash(LiteralValue::Int8(3), DataType::Int16)
+        ));
+        assert!(!bloom_filter::is_in_set(
+            &bitset,
+            int_hash(LiteralValue::Int32(10), DataType::Int32)
+        ));
+
+        let str_hash = bloom_filter_hash(
+            &LiteralValue::Utf8("abc".to_string()),
+            &DataType::Utf8,
+            &PhysicalType::ByteArray,
+        )
+        .unwrap();
+        assert!(bloom_filter::is_in_set(&bitset, str_hash));
+
+        // Values that can't be hashed like the column's values are never used to prune.
+        assert_eq!(
+            bloom_filter_hash(
+                &LiteralValue::Float64(1.0),
+                &DataType::Float64,
+                &PhysicalType::Double
+            ),
+            None
+        );
+        assert_eq!(
+            bloom_filter_hash(
+                &LiteralValue::Int64(i64::MAX),
+                &DataType::Int32,
+                &PhysicalType::Int32
+            ),
+            None
+        );
+    }
+}
@@ -235,7 +235,10 @@ async fn read_parquet_single(
 
         let builder = builder.set_chunk_size(chunk_size);
 
-        let parquet_reader = builder.build()?;
+        let parquet_reader = builder
+            .build()?
+            .prune_with_indexes(io_client.clone(), io_stats.clone())
+            .await?;
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             Arc::new(metadata),
@@ -459,6 +462,15 @@ async fn stream_parquet_single(
         };
 
         let parquet_reader = builder.build()?;
+        // Page indexes narrow row groups down to the rows of their selected pages, which would
+        // shift the positions that deleted rows refer to.
+        let parquet_reader = if delete_rows.as_ref().is_none_or(Vec::is_empty) {
+            parquet_reader
+                .prune_with_indexes(io_client.clone(), io_stats.clone())
+                .await?
+        } else {
+            parquet_reader
+        };
         let ranges = parquet_reader.prebuffer_ranges(io_client, io_stats)?;
         Ok((
             Arc::new(metadata),
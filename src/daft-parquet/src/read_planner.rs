@@ -198,16 +198,18 @@ pub struct RangesContainer {
 }
 
 impl RangesContainer {
-    pub async fn get_range_reader(
+    /// Appends the cache entries that hold `range`, and the slices of them to read, to
+    /// `needed_entries` and `ranges_to_slice`.
+    fn slice_range(
         &self,
         range: Range<usize>,
-    ) -> DaftResult<impl futures::AsyncRead> {
+        needed_entries: &mut Vec<Arc<RangeCacheEntry>>,
+        ranges_to_slice: &mut Vec<Range<usize>>,
+    ) {
         let mut current_pos = range.start;
         let mut curr_index;
         let start_point = self.ranges.binary_search_by_key(&current_pos, |e| e.start);
 
-        let mut needed_entries = vec![];
-        let mut ranges_to_slice = vec![];
         match start_point {
             Ok(index) => {
                 let entry = self.ranges[index].clone();
@@ -258,6 +260,18 @@ impl RangesContainer {
         }
 
         assert_eq!(current_pos, range.end);
+    }
+
+    /// Returns a reader of the bytes of `ranges`, one after the other.
+    pub async fn get_ranges_reader(
+        &self,
+        ranges: impl IntoIterator<Item = Range<usize>>,
+    ) -> DaftResult<impl futures::AsyncRead> {
+        let mut needed_entries = vec![];
+        let mut ranges_to_slice = vec![];
+        for range in ranges {
+            self.slice_range(range, &mut needed_entries, &mut ranges_to_slice);
+        }
 
         // We block on the first entry so we can surface up the error. This shouldn't cause any performance issues since we have to wait for this to complete anyways
         if let Some(entry) = needed_entries.first()
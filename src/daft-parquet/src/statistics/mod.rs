@@ -4,8 +4,11 @@ use common_error::DaftError;
 use snafu::Snafu;
 
 mod column_range;
+mod page_stats;
 mod table_stats;
 mod utils;
+pub(crate) use column_range::parquet_statistics_to_column_range_statistics;
+pub use page_stats::column_index_to_page_stats;
 pub use table_stats::row_group_metadata_to_table_stats;
 
 #[derive(Debug, Snafu)]
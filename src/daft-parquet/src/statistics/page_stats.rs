@@ -0,0 +1,115 @@
+use daft_core::prelude::DataType;
+use daft_stats::ColumnRangeStatistics;
+use parquet2::{
+    indexes::{BooleanIndex, ByteIndex, FixedLenByteIndex, Index, NativeIndex},
+    schema::types::PhysicalType,
+    statistics::{
+        BinaryStatistics, BooleanStatistics, FixedLenStatistics, PrimitiveStatistics, Statistics,
+    },
+    types::NativeType,
+};
+
+use super::column_range::parquet_statistics_to_column_range_statistics;
+
+fn native_page_statistics<T: NativeType>(index: &NativeIndex<T>) -> Vec<Box<dyn Statistics>> {
+    index
+        .indexes
+        .iter()
+        .map(|page| {
+            Box::new(PrimitiveStatistics::<T> {
+                primitive_type: index.primitive_type.clone(),
+                null_count: page.null_count,
+                distinct_count: None,
+                min_value: page.min,
+                max_value: page.max,
+            }) as Box<dyn Statistics>
+        })
+        .collect()
+}
+
+/// Converts the column index of a column chunk into the range statistics of each of its pages.
+///
+/// Pages whose statistics can't be converted, e.g. because the page only holds nulls, get
+/// [`ColumnRangeStatistics::Missing`].
+pub fn column_index_to_page_stats(
+    index: &dyn Index,
+    daft_dtype: &DataType,
+) -> Vec<ColumnRangeStatistics> {
+    let physical_type = index.physical_type();
+    let index = index.as_any();
+    let page_statistics: Vec<Box<dyn Statistics>> = match physical_type {
+        PhysicalType::Boolean => index
+            .downcast_ref::<BooleanIndex>()
+            .unwrap()
+            .indexes
+            .iter()
+            .map(|page| {
+                Box::new(BooleanStatistics {
+                    null_count: page.null_count,
+                    distinct_count: None,
+                    max_value: page.max,
+                    min_value: page.min,
+                }) as Box<dyn Statistics>
+            })
+            .collect(),
+        PhysicalType::Int32 => {
+            native_page_statistics(index.downcast_ref::<NativeIndex<i32>>().unwrap())
+        }
+        PhysicalType::Int64 => {
+            native_page_statistics(index.downcast_ref::<NativeIndex<i64>>().unwrap())
+        }
+        PhysicalType::Int96 => {
+            native_page_statistics(index.downcast_ref::<NativeIndex<[u32; 3]>>().unwrap())
+        }
+        PhysicalType::Float => {
+            native_page_statistics(index.downcast_ref::<NativeIndex<f32>>().unwrap())
+        }
+        PhysicalType::Double => {
+            native_page_statistics(index.downcast_ref::<NativeIndex<f64>>().unwrap())
+        }
+        PhysicalType::ByteArray => {
+            let index = index.downcast_ref::<ByteIndex>().unwrap();
+            index
+                .indexes
+                .iter()
+                .map(|page| {
+                    Box::new(BinaryStatistics {
+                        primitive_type: index.primitive_type.clone(),
+                        null_count: page.null_count,
+                        distinct_count: None,
+                        max_value: page.max.clone(),
+                        min_value: page.min.clone(),
+                    }) as Box<dyn Statistics>
+                })
+                .collect()
+        }
+        PhysicalType::FixedLenByteArray(_) => {
+            let index = index.downcast_ref::<FixedLenByteIndex>().unwrap();
+            index
+                .indexes
+                .iter()
+                .map(|page| {
+                    Box::new(FixedLenStatistics {
+                        primitive_type: index.primitive_type.clone(),
+                        null_count: page.null_count,
+                        distinct_count: None,
+                        max_value: page.max.clone(),
+                        min_value: page.min.clone(),
+                    }) as Box<dyn Statistics>
+                })
+                .collect()
+        }
+    };
+
+    page_statistics
+        .iter()
+        .map(|stats| {
+            if ColumnRangeStatistics::supports_dtype(daft_dtype) {
+                parquet_statistics_to_column_range_statistics(stats.as_ref(), daft_dtype)
+                    .unwrap_or(ColumnRangeStatistics::Missing)
+            } else {
+                ColumnRangeStatistics::Missing
+            }
+        })
+        .collect()
+}
@@ -20,6 +20,7 @@ impl TreeDisplay for PhysicalPlan {
             Self::Sort(sort) => sort.display_as(level),
             Self::Sample(sample) => sample.display_as(level),
             Self::MonotonicallyIncreasingId(id) => id.display_as(level),
+            Self::Window(window) => window.display_as(level),
             Self::ShuffleExchange(shuffle_exchange) => shuffle_exchange.display_as(level),
             Self::Aggregate(aggr) => aggr.display_as(level),
             Self::Pivot(pivot) => pivot.display_as(level),
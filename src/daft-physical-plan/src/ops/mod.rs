@@ -28,6 +28,7 @@ mod shuffle_exchange;
 mod sort;
 mod sort_merge_join;
 mod unpivot;
+mod window;
 
 pub use actor_pool_project::ActorPoolProject;
 pub use agg::Aggregate;
@@ -59,6 +60,7 @@ pub use shuffle_exchange::{ShuffleExchange, ShuffleExchangeFactory, ShuffleExcha
 pub use sort::Sort;
 pub use sort_merge_join::SortMergeJoin;
 pub use unpivot::Unpivot;
+pub use window::Window;
 
 #[macro_export]
 /// Implement the `common_display::tree::TreeDisplay` trait for the given struct
@@ -0,0 +1,52 @@
+use daft_dsl::{ExprRef, WindowSpec};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::PhysicalPlanRef;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Window {
+    // Upstream node.
+    pub input: PhysicalPlanRef,
+
+    pub window_functions: Vec<ExprRef>,
+    pub window_spec: WindowSpec,
+}
+
+impl Window {
+    pub(crate) fn new(
+        input: PhysicalPlanRef,
+        window_functions: Vec<ExprRef>,
+        window_spec: WindowSpec,
+    ) -> Self {
+        Self {
+            input,
+            window_functions,
+            window_spec,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        if !self.window_spec.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.window_spec
+                    .partition_by
+                    .iter()
+                    .map(|e| e.to_string())
+                    .join(", ")
+            ));
+        }
+        res
+    }
+}
+
+crate::impl_default_tree_display!(Window);
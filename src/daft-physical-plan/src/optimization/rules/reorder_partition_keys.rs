@@ -145,6 +145,7 @@ impl PhysicalOptimizerRule for ReorderPartitionKeys {
                 PhysicalPlan::Limit(..) |
                 PhysicalPlan::Sample(..) |
                 PhysicalPlan::MonotonicallyIncreasingId(..) |
+                PhysicalPlan::Window(..) |
                 PhysicalPlan::Pivot(..) |
                 PhysicalPlan::TabularWriteCsv(..) |
                 PhysicalPlan::TabularWriteJson(..) |
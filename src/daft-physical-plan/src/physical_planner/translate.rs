@@ -26,7 +26,8 @@ use daft_logical_plan::{
         Join as LogicalJoin, Limit as LogicalLimit,
         MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Pivot as LogicalPivot,
         Project as LogicalProject, Repartition as LogicalRepartition, Sample as LogicalSample,
-        Sink as LogicalSink, Sort as LogicalSort, Source, Unpivot as LogicalUnpivot,
+        Sink as LogicalSink, Sort as LogicalSort, Source, TopN as LogicalTopN,
+        Unpivot as LogicalUnpivot, Window as LogicalWindow,
     },
     partitioning::{
         ClusteringSpec, HashClusteringConfig, RangeClusteringConfig, UnknownClusteringConfig,
@@ -182,6 +183,30 @@ pub(super) fn translate_single_logical_node(
             ))
             .arced())
         }
+        LogicalPlan::TopN(LogicalTopN {
+            sort_by,
+            descending,
+            nulls_first,
+            limit,
+            eager,
+            ..
+        }) => {
+            // The distributed runner has no fused TopN yet, so plan it as a Sort followed by a Limit.
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            let num_partitions = input_physical.clustering_spec().num_partitions();
+            let sorted = PhysicalPlan::Sort(Sort::new(
+                input_physical,
+                sort_by.clone(),
+                descending.clone(),
+                nulls_first.clone(),
+                num_partitions,
+            ))
+            .arced();
+            Ok(
+                PhysicalPlan::Limit(Limit::new(sorted, *limit as i64, *eager, num_partitions))
+                    .arced(),
+            )
+        }
         LogicalPlan::Repartition(LogicalRepartition {
             repartition_spec, ..
         }) => {
@@ -227,7 +252,9 @@ pub(super) fn translate_single_logical_node(
                         )
                     }
                     ClusteringSpec::Range(_) => {
-                        unreachable!("Repartitioning by range is not supported")
+                        return Err(common_error::DaftError::not_implemented(
+                            "Repartitioning by range is only supported by the native runner",
+                        ));
                     }
                 };
                 Ok(repartitioned_plan.arced())
@@ -422,6 +449,51 @@ pub(super) fn translate_single_logical_node(
             ))
             .arced())
         }
+        LogicalPlan::Window(LogicalWindow {
+            window_functions,
+            window_spec,
+            ..
+        }) => {
+            let input_physical = physical_children.pop().expect("requires 1 input");
+            let input_clustering_spec = input_physical.clustering_spec();
+            let num_input_partitions = input_clustering_spec.num_partitions();
+
+            // All rows of a window partition must be colocated, so shuffle by the partition by
+            // expressions unless the input is already partitioned compatibly.
+            let is_hash_partitioned =
+                matches!(input_clustering_spec.as_ref(), ClusteringSpec::Hash(..))
+                    && is_partition_compatible(
+                        &input_clustering_spec.partition_by(),
+                        &window_spec.partition_by,
+                    );
+            let input_physical = if num_input_partitions == 1 || is_hash_partitioned {
+                input_physical
+            } else if window_spec.partition_by.is_empty() {
+                PhysicalPlan::ShuffleExchange(
+                    ShuffleExchangeFactory::new(input_physical).get_split_or_coalesce(1),
+                )
+                .arced()
+            } else {
+                PhysicalPlan::ShuffleExchange(
+                    ShuffleExchangeFactory::new(input_physical).get_hash_partitioning(
+                        window_spec.partition_by.clone(),
+                        min(
+                            num_input_partitions,
+                            cfg.shuffle_aggregation_default_partitions,
+                        ),
+                        Some(cfg),
+                    ),
+                )
+                .arced()
+            };
+
+            Ok(PhysicalPlan::Window(Window::new(
+                input_physical,
+                window_functions.clone(),
+                window_spec.clone(),
+            ))
+            .arced())
+        }
         LogicalPlan::Concat(..) => {
             let other_physical = physical_children.pop().expect("requires 1 inputs");
             let input_physical = physical_children.pop().expect("requires 2 inputs");
@@ -469,6 +541,15 @@ pub(super) fn translate_single_logical_node(
                         FileFormat::Warc => Err(common_error::DaftError::ValueError(
                             "Warc sink not yet implemented".to_string(),
                         )),
+                        FileFormat::Ipc => Err(common_error::DaftError::ValueError(
+                            "Arrow IPC writes are only supported by the native runner".to_string(),
+                        )),
+                        FileFormat::Avro => Err(common_error::DaftError::ValueError(
+                            "Avro sink not yet implemented".to_string(),
+                        )),
+                        FileFormat::Orc => Err(common_error::DaftError::ValueError(
+                            "ORC sink not yet implemented".to_string(),
+                        )),
                     }
                 }
                 #[cfg(feature = "python")]
@@ -508,6 +589,12 @@ pub(super) fn translate_single_logical_node(
                 .arced(),
             )
         }
+        LogicalPlan::RecursiveCte(_) => Err(common_error::DaftError::not_implemented(
+            "Recursive CTEs are only supported on the native runner",
+        )),
+        LogicalPlan::Offset(_) => Err(common_error::DaftError::not_implemented(
+            "Offsets are only supported on the native runner",
+        )),
         LogicalPlan::Intersect(_) => Err(DaftError::InternalError(
             "Intersect should already be optimized away".to_string(),
         )),
@@ -996,6 +1083,7 @@ fn translate_join(
         right_on,
         join_type,
         null_equals_nulls,
+        residual_predicate,
         join_strategy,
         ..
     } = match join_plan {
@@ -1007,6 +1095,17 @@ fn translate_join(
         }
     };
 
+    if let Some(residual_predicate) = residual_predicate {
+        return Err(common_error::DaftError::not_implemented(format!(
+            "Join predicates other than equality conditions are only supported on the native runner, found: {residual_predicate}"
+        )));
+    }
+    if *join_type == JoinType::AsOf {
+        return Err(common_error::DaftError::not_implemented(
+            "As-of joins are only supported on the native runner",
+        ));
+    }
+
     let mut right_physical = physical_children.pop().expect("requires 1 inputs");
     let mut left_physical = physical_children.pop().expect("requires 2 inputs");
 
@@ -1090,7 +1189,7 @@ fn translate_join(
 
         let smaller_side_is_broadcastable = match join_type {
             JoinType::Inner => true,
-            JoinType::Left | JoinType::Anti | JoinType::Semi => left_is_larger,
+            JoinType::Left | JoinType::Anti | JoinType::Semi | JoinType::AsOf => left_is_larger,
             JoinType::Right => !left_is_larger,
             JoinType::Outer => false,
         };
@@ -1135,7 +1234,7 @@ fn translate_join(
     match join_strategy {
         JoinStrategy::Broadcast => {
             let is_swapped = match (join_type, left_is_larger) {
-                (JoinType::Left, _) => true,
+                (JoinType::Left | JoinType::AsOf, _) => true,
                 (JoinType::Right, _) => false,
                 (JoinType::Inner, left_is_larger) => left_is_larger,
                 (JoinType::Outer, _) => {
@@ -29,6 +29,7 @@ pub enum PhysicalPlan {
     Sort(Sort),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
     Aggregate(Aggregate),
     Pivot(Pivot),
     Concat(Concat),
@@ -86,6 +87,8 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 input.clustering_spec()
             }
+            // Window functions only append columns, so the input's partitioning is preserved.
+            Self::Window(Window { input, .. }) => input.clustering_spec(),
 
             Self::Sort(Sort {
                 input,
@@ -291,6 +294,7 @@ impl PhysicalPlan {
             // Propagate child approximation for operations that don't affect cardinality.
             Self::Sort(Sort { input, .. })
             | Self::Pivot(Pivot { input, .. })
+            | Self::Window(Window { input, .. })
             | Self::ShuffleExchange(ShuffleExchange { input, .. }) => input.approximate_stats(),
             Self::Concat(Concat { input, other }) => {
                 &input.approximate_stats() + &other.approximate_stats()
@@ -409,6 +413,7 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => {
                 vec![input]
             }
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -438,6 +443,7 @@ impl PhysicalPlan {
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { column_name, .. }) => Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId::new(input.clone(), column_name)),
+                Self::Window(Window { window_functions, window_spec, .. }) => Self::Window(Window::new(input.clone(), window_functions.clone(), window_spec.clone())),
                 #[cfg(feature = "python")]
                 Self::IcebergWrite(IcebergWrite { schema, iceberg_info, .. }) => Self::IcebergWrite(IcebergWrite::new(schema.clone(), iceberg_info.clone(), input.clone())),
                 #[cfg(feature = "python")]
@@ -495,6 +501,7 @@ impl PhysicalPlan {
             Self::TabularWriteCsv(..) => "TabularWriteCsv",
             Self::TabularWriteJson(..) => "TabularWriteJson",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
             #[cfg(feature = "python")]
             Self::IcebergWrite(..) => "IcebergWrite",
             #[cfg(feature = "python")]
@@ -535,6 +542,7 @@ impl PhysicalPlan {
             Self::MonotonicallyIncreasingId(monotonically_increasing_id) => {
                 monotonically_increasing_id.multiline_display()
             }
+            Self::Window(window) => window.multiline_display(),
             #[cfg(feature = "python")]
             Self::IcebergWrite(iceberg_info) => iceberg_info.multiline_display(),
             #[cfg(feature = "python")]
@@ -11,6 +11,7 @@ pub fn dummy_scan_operator(fields: Vec<Field>) -> ScanOperatorRef {
         schema,
         num_scan_tasks: 1,
         num_rows_per_task: None,
+        can_absorb_aggregation: false,
     }))
 }
 
@@ -35,7 +35,10 @@ mod probeable;
 mod repr_html;
 
 pub use growable::GrowableRecordBatch;
-pub use probeable::{make_probeable_builder, ProbeState, Probeable, ProbeableBuilder};
+pub use ops::AsofJoinRight;
+pub use probeable::{
+    make_probeable_builder, ProbeMatches, ProbeState, Probeable, ProbeableBuilder,
+};
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -648,6 +651,12 @@ impl RecordBatch {
             Expr::Exists(_subquery) => Err(DaftError::ComputeError(
                 "EXISTS <SUBQUERY> should be optimized away before evaluation. This indicates a bug in the query optimizer.".to_string(),
             )),
+            Expr::WindowFunction(window_expr) => Err(DaftError::ValueError(format!(
+                "Window function {window_expr} must be evaluated over a window, e.g. with `.over(...)`"
+            ))),
+            Expr::Over(..) => Err(DaftError::ComputeError(
+                "Window expressions should be extracted into a Window operator before evaluation. This indicates a bug in the query optimizer.".to_string(),
+            )),
             Expr::Column(Column::Resolved(ResolvedColumn::OuterRef(Field { name, .. }))) => Err(DaftError::ComputeError(
                 format!("Outer reference columns should be eliminated before evaluation. This indicates either that column {name} does not exist in the table, or there is a bug in the query optimizer."),
             )),
@@ -1019,10 +1028,16 @@ impl<'a> IntoIterator for &'a RecordBatch {
 #[cfg(test)]
 mod test {
     use common_error::DaftResult;
-    use daft_core::prelude::*;
-    use daft_dsl::resolved_col;
+    use daft_core::{
+        join::{AsofDirection, JoinSide},
+        prelude::*,
+    };
+    use daft_dsl::{
+        join::AsofJoinOptions, join_side_col, lit, resolved_col, Expr, ExprRef, LiteralValue,
+        WindowBoundary, WindowFrame, WindowFrameType, WindowSpec,
+    };
 
-    use crate::RecordBatch;
+    use crate::{make_probeable_builder, AsofJoinRight, ProbeState, RecordBatch};
 
     #[test]
     fn add_int_and_float_expression() -> DaftResult<()> {
@@ -1047,4 +1062,448 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn window_functions_over_partitions() -> DaftResult<()> {
+        let g = Utf8Array::from(("g", ["x", "y", "x", "x", "y"].as_slice())).into_series();
+        let v = Int64Array::from(("v", vec![3, 1, 1, 2, 5])).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![g, v])?;
+
+        let spec = WindowSpec::new(vec![resolved_col("g")]).with_order_by(
+            vec![resolved_col("v")],
+            vec![false],
+            vec![false],
+        );
+        let window_functions = vec![
+            resolved_col("v")
+                .sum()
+                .over(spec.clone())?
+                .alias("running_sum"),
+            Expr::rank().over(spec.clone())?.alias("rank"),
+            resolved_col("v")
+                .lag(1, None)
+                .over(spec.clone())?
+                .alias("prev"),
+        ];
+        let result = table.window(&window_functions, &spec)?;
+
+        // The output is sorted by the partition by and order by expressions.
+        let v = result.get_column("v")?;
+        assert_eq!(v.i64()?.as_slice(), &[1, 2, 3, 1, 5]);
+        let running_sum = result.get_column("running_sum")?;
+        assert_eq!(running_sum.i64()?.as_slice(), &[1, 3, 6, 1, 6]);
+        let rank = result.get_column("rank")?.cast(&DataType::UInt64)?;
+        assert_eq!(rank.u64()?.as_slice(), &[1, 2, 3, 1, 2]);
+        let prev = result.get_column("prev")?;
+        assert_eq!(
+            prev.i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![None, Some(1), Some(2), None, Some(1)]
+        );
+
+        Ok(())
+    }
+
+    fn window_frame_table() -> DaftResult<RecordBatch> {
+        let g =
+            Utf8Array::from(("g", ["x", "x", "x", "x", "x", "y", "y"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![1, 2, 3, 4, 5, 1, 2])).into_series();
+        let v = Int64Array::from_iter(
+            Field::new("v", DataType::Int64),
+            vec![Some(1), None, Some(3), Some(-2), Some(5), Some(4), None].into_iter(),
+        )
+        .into_series();
+        let f = Float64Array::from_iter(
+            Field::new("f", DataType::Float64),
+            vec![
+                Some(1.0),
+                None,
+                Some(f64::INFINITY),
+                Some(2.0),
+                Some(0.5),
+                Some(1.5),
+                None,
+            ]
+            .into_iter(),
+        )
+        .into_series();
+        RecordBatch::from_nonempty_columns(vec![g, t, v, f])
+    }
+
+    /// Evaluates `agg` over windows partitioned by `g` and ordered by `t`, with the given frame.
+    fn window_frame_values(
+        table: &RecordBatch,
+        agg: ExprRef,
+        frame: Option<WindowFrame>,
+    ) -> DaftResult<Vec<Option<f64>>> {
+        let mut spec = WindowSpec::new(vec![resolved_col("g")]).with_order_by(
+            vec![resolved_col("t")],
+            vec![false],
+            vec![false],
+        );
+        if let Some(frame) = frame {
+            spec = spec.with_frame(frame);
+        }
+        let result = table.window(&[agg.over(spec.clone())?.alias("out")], &spec)?;
+        let out = result.get_column("out")?.cast(&DataType::Float64)?;
+        Ok(out.f64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn window_frame_running_aggregations() -> DaftResult<()> {
+        let table = window_frame_table()?;
+        let running = |agg: ExprRef| window_frame_values(&table, agg, None);
+
+        assert_eq!(
+            running(resolved_col("v").sum())?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(4.),
+                Some(2.),
+                Some(7.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            running(resolved_col("v").count(CountMode::Valid))?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(2.),
+                Some(3.),
+                Some(4.),
+                Some(1.),
+                Some(1.)
+            ]
+        );
+        assert_eq!(
+            running(resolved_col("v").min())?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(1.),
+                Some(-2.),
+                Some(-2.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            running(resolved_col("v").max())?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(3.),
+                Some(3.),
+                Some(5.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            running(resolved_col("v").mean())?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(2.),
+                Some(2. / 3.),
+                Some(1.75),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn window_frame_sliding_aggregations() -> DaftResult<()> {
+        let table = window_frame_table()?;
+        let frame = WindowFrame::try_new(
+            WindowFrameType::Rows,
+            WindowBoundary::Preceding(1),
+            WindowBoundary::Following(1),
+        )?;
+        let sliding = |agg: ExprRef| window_frame_values(&table, agg, Some(frame));
+
+        assert_eq!(
+            sliding(resolved_col("v").sum())?,
+            [
+                Some(1.),
+                Some(4.),
+                Some(1.),
+                Some(6.),
+                Some(3.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            sliding(resolved_col("v").count(CountMode::All))?,
+            [
+                Some(2.),
+                Some(3.),
+                Some(3.),
+                Some(3.),
+                Some(2.),
+                Some(2.),
+                Some(2.)
+            ]
+        );
+        assert_eq!(
+            sliding(resolved_col("v").min())?,
+            [
+                Some(1.),
+                Some(1.),
+                Some(-2.),
+                Some(-2.),
+                Some(-2.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            sliding(resolved_col("v").max())?,
+            [
+                Some(1.),
+                Some(3.),
+                Some(3.),
+                Some(5.),
+                Some(5.),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        assert_eq!(
+            sliding(resolved_col("v").mean())?,
+            [
+                Some(1.),
+                Some(2.),
+                Some(0.5),
+                Some(2.),
+                Some(1.5),
+                Some(4.),
+                Some(4.)
+            ]
+        );
+        // Infinity leaves the frame of the last row of the partition, leaving a finite sum again.
+        assert_eq!(
+            sliding(resolved_col("f").sum())?,
+            [
+                Some(1.),
+                Some(f64::INFINITY),
+                Some(f64::INFINITY),
+                Some(f64::INFINITY),
+                Some(2.5),
+                Some(1.5),
+                Some(1.5)
+            ]
+        );
+        // Aggregations without a running form are aggregated frame by frame.
+        assert_eq!(
+            sliding(resolved_col("v").count_distinct())?,
+            [
+                Some(1.),
+                Some(2.),
+                Some(2.),
+                Some(3.),
+                Some(2.),
+                Some(1.),
+                Some(1.)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn window_frame_disjoint_and_empty_frames() -> DaftResult<()> {
+        let table = window_frame_table()?;
+        let frame = WindowFrame::try_new(
+            WindowFrameType::Rows,
+            WindowBoundary::Following(2),
+            WindowBoundary::Following(3),
+        )?;
+        let following = |agg: ExprRef| window_frame_values(&table, agg, Some(frame));
+
+        assert_eq!(
+            following(resolved_col("v").sum())?,
+            [Some(1.), Some(3.), Some(5.), None, None, None, None]
+        );
+        assert_eq!(
+            following(resolved_col("v").count(CountMode::All))?,
+            [
+                Some(2.),
+                Some(2.),
+                Some(1.),
+                Some(0.),
+                Some(0.),
+                Some(0.),
+                Some(0.)
+            ]
+        );
+        assert_eq!(
+            following(resolved_col("v").max())?,
+            [Some(3.), Some(5.), Some(5.), None, None, None, None]
+        );
+        assert_eq!(
+            following(resolved_col("f").mean())?,
+            [
+                Some(f64::INFINITY),
+                Some(1.25),
+                Some(0.5),
+                None,
+                None,
+                None,
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn probe_matches_with_residual_predicate() -> DaftResult<()> {
+        let key = Int64Array::from(("key", vec![1, 1, 2])).into_series();
+        let lo = Int64Array::from(("lo", vec![0, 10, 0])).into_series();
+        let build = RecordBatch::from_nonempty_columns(vec![key, lo])?;
+        let key = Int64Array::from(("key", vec![1, 2, 3])).into_series();
+        let v = Int64Array::from(("v", vec![5, 5, 5])).into_series();
+        let probe = RecordBatch::from_nonempty_columns(vec![key, v])?;
+
+        let on = vec![resolved_col("key")];
+        let build_keys = build.eval_expression_list(&on)?;
+        let mut builder = make_probeable_builder(build_keys.schema.clone(), None, true)?;
+        builder.add_table(&build_keys)?;
+        let state = ProbeState::new(builder.build(), vec![build].into());
+
+        // The build side is on the left, so its rows must start below the probed value.
+        let predicate = join_side_col("lo", JoinSide::Left).lt(join_side_col("v", JoinSide::Right));
+        let probe_keys = probe.eval_expression_list(&on)?;
+        let matches = state.probe_matches(&probe, &probe_keys, Some(&predicate), true)?;
+        assert_eq!(
+            matches.iter().collect::<Vec<_>>(),
+            vec![Some([(0, 0)].as_slice()), Some([(0, 2)].as_slice()), None]
+        );
+
+        let matches = state.probe_matches(&probe, &probe_keys, None, true)?;
+        assert_eq!(
+            matches.iter().collect::<Vec<_>>(),
+            vec![
+                Some([(0, 0), (0, 1)].as_slice()),
+                Some([(0, 2)].as_slice()),
+                None
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn probe_matches_with_residual_predicate_in_chunks() -> DaftResult<()> {
+        // every build row matches every probed row, which spans several chunks of pairs
+        let build = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "b",
+            (0..400).collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+        let probe = RecordBatch::from_nonempty_columns(vec![Int64Array::from((
+            "p",
+            (0..400).collect::<Vec<_>>(),
+        ))
+        .into_series()])?;
+
+        let on = vec![lit(true)];
+        let build_keys = build.eval_expression_list(&on)?;
+        let mut builder = make_probeable_builder(build_keys.schema.clone(), None, true)?;
+        builder.add_table(&build_keys)?;
+        let state = ProbeState::new(builder.build(), vec![build].into());
+
+        let predicate = join_side_col("b", JoinSide::Left).lt(join_side_col("p", JoinSide::Right));
+        let probe_keys = probe.eval_expression_list(&on)?;
+        let matches = state.probe_matches(&probe, &probe_keys, Some(&predicate), true)?;
+        for (p, matches) in matches.iter().enumerate() {
+            let expected = (0..p).map(|b| (0, b)).collect::<Vec<_>>();
+            assert_eq!(matches, (p > 0).then_some(expected.as_slice()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn asof_join_directions() -> DaftResult<()> {
+        let g = Utf8Array::from(("g", ["a", "a", "b", "a"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![1, 5, 3, 10])).into_series();
+        let left = RecordBatch::from_nonempty_columns(vec![g, t])?;
+        let g = Utf8Array::from(("g", ["a", "a", "b"].as_slice())).into_series();
+        let t = Int64Array::from(("t", vec![4, 2, 3])).into_series();
+        let v = Int64Array::from(("v", vec![40, 20, 30])).into_series();
+        let right = RecordBatch::from_nonempty_columns(vec![g, t, v])?;
+
+        let on = vec![resolved_col("g"), resolved_col("t")];
+        let join = |direction, tolerance| -> DaftResult<Vec<Option<i64>>> {
+            let options = AsofJoinOptions {
+                direction,
+                tolerance,
+            };
+            let result = left.asof_join(&right, &on, &on, &options)?;
+            assert_eq!(result.get_column("t")?, left.get_column("t")?);
+            Ok(result
+                .get_column("v")?
+                .i64()?
+                .into_iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+
+        assert_eq!(
+            join(AsofDirection::Backward, None)?,
+            vec![None, Some(40), Some(30), Some(40)]
+        );
+        assert_eq!(
+            join(AsofDirection::Forward, None)?,
+            vec![Some(20), None, Some(30), None]
+        );
+        assert_eq!(
+            join(AsofDirection::Nearest, None)?,
+            vec![Some(20), Some(40), Some(30), Some(40)]
+        );
+        assert_eq!(
+            join(AsofDirection::Backward, Some(LiteralValue::Int64(2)))?,
+            vec![None, Some(40), Some(30), None]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn asof_join_sorted_right_is_reused() -> DaftResult<()> {
+        let t = Int64Array::from(("t", vec![4, 2, 3])).into_series();
+        let v = Int64Array::from(("v", vec![40, 20, 30])).into_series();
+        let right = RecordBatch::from_nonempty_columns(vec![t, v])?;
+
+        // the right keys are cast to the types they are compared as with the left keys
+        let on = vec![resolved_col("t")];
+        let left_schema = Schema::new(vec![Field::new("t", DataType::Int32)])?;
+        let right = AsofJoinRight::try_new(right, &on, &left_schema, &on)?;
+
+        let options = AsofJoinOptions {
+            direction: AsofDirection::Backward,
+            tolerance: None,
+        };
+        for (t, expected) in [
+            (vec![1, 3], vec![None, Some(30)]),
+            (vec![5, 2], vec![Some(40), Some(20)]),
+        ] {
+            let left =
+                RecordBatch::from_nonempty_columns(vec![Int32Array::from(("t", t)).into_series()])?;
+            let result = left.asof_join_sorted(&right, &on, &options)?;
+            let v = result.get_column("v")?.i64()?;
+            assert_eq!(
+                v.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+                expected
+            );
+        }
+        Ok(())
+    }
 }
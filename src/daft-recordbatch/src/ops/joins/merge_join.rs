@@ -2,8 +2,8 @@ use std::cmp::Ordering;
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    array::ops::full::FullNull,
-    datatypes::{DataType, UInt64Array},
+    array::ops::{as_arrow::AsArrow, full::FullNull},
+    datatypes::{DataType, Field, UInt64Array},
     kernels::search_sorted::build_partial_compare_with_nulls,
     series::{IntoSeries, Series},
 };
@@ -195,7 +195,7 @@ pub fn merge_inner_join(left: &RecordBatch, right: &RecordBatch) -> DaftResult<(
                     // If already in a right-side equality run but we've reached the end of the right-side table,
                     // we can't extend the run anymore, so we stage it.
                     MergeJoinState::RightEqualRun(start_right_idx)
-                        if right_idx == left.len() - 1 =>
+                        if right_idx == right.len() - 1 =>
                     {
                         MergeJoinState::StagedRightEqualRun(start_right_idx)
                     }
@@ -243,3 +243,199 @@ pub fn merge_inner_join(left: &RecordBatch, right: &RecordBatch) -> DaftResult<(
     let right_series = UInt64Array::from(("right_indices", right_indices));
     Ok((left_series.into_series(), right_series.into_series()))
 }
+
+/// Returns the order in which the rows of `keys` are visited by an as-of join, which is ascending
+/// with null keys last.
+pub fn asof_order(keys: &RecordBatch) -> DaftResult<Vec<u64>> {
+    let descending = vec![false; keys.num_columns()];
+    let order = Series::argsort_multikey(&keys.columns, &descending, &descending)?;
+    Ok(order.u64()?.as_arrow().values().to_vec())
+}
+
+/// The join keys of the right side of an as-of join, which are sorted once so that they can be
+/// matched with the keys of many left tables.
+pub struct AsofRightKeys {
+    dtypes: Vec<DataType>,
+    arrays: Vec<Box<dyn arrow2::array::Array>>,
+    order: Vec<u64>,
+    /// Whether each row has a null join key, in which case it never matches.
+    has_null_key: Vec<bool>,
+}
+
+impl AsofRightKeys {
+    pub fn try_new(keys: &RecordBatch) -> DaftResult<Self> {
+        if keys.num_columns() == 0 {
+            return Err(DaftError::ValueError(
+                "No columns were passed in to as-of join on".to_string(),
+            ));
+        }
+        let has_null_key = (0..keys.len())
+            .map(|idx| keys.columns.iter().any(|s| !s.is_valid(idx)))
+            .collect();
+        Ok(Self {
+            dtypes: keys.columns.iter().map(|s| s.data_type().clone()).collect(),
+            arrays: keys.columns.iter().map(|s| s.to_arrow()).collect(),
+            order: asof_order(keys)?,
+            has_null_key,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+}
+
+/// Matches each row of `left` with a row of `right` for an as-of join, where the last join key is
+/// the as-of key and the others must be equal. `left_order` is the [`asof_order`] of `left`, and
+/// both sides must have the same key types.
+///
+/// A left row matches the last right row which is not greater on the as-of key, or the first right
+/// row which is not less on it if `forward`, and rows with a null join key never match.
+///
+/// Returns the index of the right row matching each left row, which is null if there is none.
+pub fn merge_asof_join(
+    left: &RecordBatch,
+    left_order: &[u64],
+    right: &AsofRightKeys,
+    forward: bool,
+) -> DaftResult<UInt64Array> {
+    if left.num_columns() != right.arrays.len() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+            left.num_columns(),
+            right.arrays.len()
+        )));
+    }
+    let types_not_match = left
+        .columns
+        .iter()
+        .zip(right.dtypes.iter())
+        .any(|(l, r)| l.data_type() != r);
+    if types_not_match {
+        return Err(DaftError::SchemaMismatch(
+            "Types between left and right do not match".to_string(),
+        ));
+    }
+
+    // Visit both sides in the order of their join keys, with null keys last.
+    let num_keys = left.num_columns();
+    let right_order = right.order.as_slice();
+
+    let mut cmp_list = Vec::with_capacity(num_keys);
+    for (left_series, right_array) in left.columns.iter().zip(right.arrays.iter()) {
+        cmp_list.push(build_partial_compare_with_nulls(
+            left_series.to_arrow().as_ref(),
+            right_array.as_ref(),
+            false,
+        )?);
+    }
+    // Compares a left row with a right row on the first `num_cmp` join keys.
+    let compare = |left_idx: usize, right_idx: usize, num_cmp: usize| -> Option<Ordering> {
+        for comparator in &cmp_list[..num_cmp] {
+            match comparator(left_idx, right_idx) {
+                Some(Ordering::Equal) => {}
+                other => return other,
+            }
+        }
+        Some(Ordering::Equal)
+    };
+
+    let mut right_indices = vec![None; left.len()];
+    let mut right_pos = 0;
+    for &left_idx in left_order {
+        let left_idx = left_idx as usize;
+        if left.columns.iter().any(|s| !s.is_valid(left_idx)) {
+            continue;
+        }
+        // Move past the right rows which are less than the left row, or not greater when looking
+        // backward, so the match is either the right row before or at the pointer.
+        while right_pos < right.len() {
+            let ordering = compare(left_idx, right_order[right_pos] as usize, num_keys);
+            let is_past = match ordering {
+                Some(Ordering::Greater) => true,
+                Some(Ordering::Equal) => !forward,
+                _ => false,
+            };
+            if !is_past {
+                break;
+            }
+            right_pos += 1;
+        }
+        let candidate = if forward {
+            right_order.get(right_pos)
+        } else {
+            right_pos.checked_sub(1).map(|pos| &right_order[pos])
+        };
+        if let Some(&right_idx) = candidate {
+            let right_idx = right_idx as usize;
+            if !right.has_null_key[right_idx]
+                && compare(left_idx, right_idx, num_keys - 1) == Some(Ordering::Equal)
+            {
+                right_indices[left_idx] = Some(right_idx as u64);
+            }
+        }
+    }
+    Ok(UInt64Array::from_iter(
+        Field::new("right_indices", DataType::UInt64),
+        right_indices.into_iter(),
+    ))
+}
+
+/// Builds a comparator of the rows of `left` with the rows of `right` on all of their columns,
+/// ordering nulls last and returning `None` if the first unequal columns are both null.
+fn build_multi_column_comparator(
+    left: &RecordBatch,
+    right: &RecordBatch,
+) -> DaftResult<impl Fn(usize, usize) -> Option<Ordering>> {
+    if left.num_columns() != right.num_columns() {
+        return Err(DaftError::ValueError(format!(
+            "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+            left.num_columns(),
+            right.num_columns()
+        )));
+    }
+    let mut cmp_list = Vec::with_capacity(left.num_columns());
+    for (left_series, right_series) in left.columns.iter().zip(right.columns.iter()) {
+        cmp_list.push(build_partial_compare_with_nulls(
+            left_series.to_arrow().as_ref(),
+            right_series.to_arrow().as_ref(),
+            false,
+        )?);
+    }
+    Ok(move |a_idx: usize, b_idx: usize| -> Option<Ordering> {
+        for comparator in &cmp_list {
+            match comparator(a_idx, b_idx) {
+                Some(Ordering::Equal) => {}
+                other => return other,
+            }
+        }
+        Some(Ordering::Equal)
+    })
+}
+
+/// Returns the number of leading rows of the sorted join keys in `keys` which are less than row
+/// `bound_idx` of `bounds`, with null keys sorting last.
+pub fn num_rows_before(
+    keys: &RecordBatch,
+    bounds: &RecordBatch,
+    bound_idx: usize,
+) -> DaftResult<usize> {
+    let compare = build_multi_column_comparator(keys, bounds)?;
+    let mut lo = 0;
+    let mut hi = keys.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if compare(mid, bound_idx) == Some(Ordering::Less) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/// Returns whether the join keys in `keys` are sorted in ascending order, with null keys last.
+pub fn is_sorted(keys: &RecordBatch) -> DaftResult<bool> {
+    let compare = build_multi_column_comparator(keys, keys)?;
+    Ok((1..keys.len()).all(|idx| compare(idx - 1, idx) != Some(Ordering::Greater)))
+}
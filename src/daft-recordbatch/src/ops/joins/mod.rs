@@ -2,10 +2,16 @@ use std::{collections::HashSet, sync::Arc};
 
 use common_error::{DaftError, DaftResult};
 use daft_core::{
-    array::growable::make_growable, join::JoinSide, prelude::*, utils::supertype::try_get_supertype,
+    array::growable::make_growable,
+    join::{AsofDirection, JoinSide},
+    prelude::*,
+    utils::supertype::try_get_supertype,
 };
 use daft_dsl::{
-    join::{get_common_join_cols, infer_join_schema},
+    join::{
+        bind_join_predicate, get_common_join_cols, infer_join_schema, join_predicate_columns,
+        join_predicate_schema, AsofJoinOptions,
+    },
     ExprRef,
 };
 use hash_join::hash_semi_anti_join;
@@ -41,6 +47,61 @@ fn match_types_for_tables(
     ))
 }
 
+/// The right side of an as-of join, whose join keys are evaluated, cast to the types they are
+/// compared as and sorted once, so that it can be joined with many left tables.
+pub struct AsofJoinRight {
+    table: RecordBatch,
+    keys: RecordBatch,
+    sorted_keys: merge_join::AsofRightKeys,
+}
+
+impl AsofJoinRight {
+    /// Prepares `table` to be joined on `right_on` with the tables of `left_schema` on `left_on`.
+    pub fn try_new(
+        table: RecordBatch,
+        right_on: &[ExprRef],
+        left_schema: &Schema,
+        left_on: &[ExprRef],
+    ) -> DaftResult<Self> {
+        if left_on.len() != right_on.len() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+                left_on.len(),
+                right_on.len()
+            )));
+        }
+        if left_on.is_empty() {
+            return Err(DaftError::ValueError(
+                "No columns were passed in to join on".to_string(),
+            ));
+        }
+
+        let keys = table.eval_expression_list(right_on)?;
+        let keys = left_on
+            .iter()
+            .zip(keys.columns.iter())
+            .map(|(left_expr, rs)| {
+                let left_field = left_expr.to_field(left_schema)?;
+                match try_get_supertype(&left_field.dtype, rs.data_type()) {
+                    Ok(st) => rs.cast(&st),
+                    Err(_) => Err(DaftError::SchemaMismatch(format!(
+                        "Can not perform join between due to mismatch of types of left: {} vs right: {}",
+                        left_field,
+                        rs.field()
+                    ))),
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let keys = RecordBatch::from_nonempty_columns(keys)?;
+        let sorted_keys = merge_join::AsofRightKeys::try_new(&keys)?;
+        Ok(Self {
+            table,
+            keys,
+            sorted_keys,
+        })
+    }
+}
+
 fn add_non_join_key_columns(
     left: &RecordBatch,
     right: &RecordBatch,
@@ -112,6 +173,9 @@ impl RecordBatch {
             JoinType::Anti => {
                 hash_semi_anti_join(self, right, left_on, right_on, null_equals_nulls, true)
             }
+            JoinType::AsOf => Err(DaftError::ValueError(
+                "As-of joins cannot be performed as hash joins".to_string(),
+            )),
         }
     }
 
@@ -192,6 +256,162 @@ impl RecordBatch {
         Self::new_with_size(join_schema, join_series, num_rows)
     }
 
+    /// Returns the number of leading rows of this table of sorted join keys which are less than
+    /// row `idx` of `bounds`, with null keys sorting last.
+    ///
+    /// Both tables must have the same key types, as when evaluated for a sort-merge join.
+    pub fn sorted_keys_num_rows_before(&self, bounds: &Self, idx: usize) -> DaftResult<usize> {
+        merge_join::num_rows_before(self, bounds, idx)
+    }
+
+    /// Returns whether the rows of this table of join keys are sorted in ascending order, with
+    /// null keys last, as required by a sort-merge join.
+    pub fn keys_are_sorted(&self) -> DaftResult<bool> {
+        merge_join::is_sorted(self)
+    }
+
+    /// Joins each row with the row of `right` nearest to it on the last join key, among the rows of
+    /// `right` which are equal to it on the other join keys, keeping the rows without a match.
+    ///
+    /// Rows are kept in their order, and the merged join key columns are taken from this side.
+    pub fn asof_join(
+        &self,
+        right: &Self,
+        left_on: &[ExprRef],
+        right_on: &[ExprRef],
+        options: &AsofJoinOptions,
+    ) -> DaftResult<Self> {
+        let right = AsofJoinRight::try_new(right.clone(), right_on, &self.schema, left_on)?;
+        self.asof_join_sorted(&right, left_on, options)
+    }
+
+    /// Joins each row with the row of a prepared right side nearest to it, as in [`Self::asof_join`],
+    /// so that the right side is sorted once when it is joined with many tables.
+    pub fn asof_join_sorted(
+        &self,
+        right: &AsofJoinRight,
+        left_on: &[ExprRef],
+        options: &AsofJoinOptions,
+    ) -> DaftResult<Self> {
+        if left_on.len() != right.keys.num_columns() {
+            return Err(DaftError::ValueError(format!(
+                "Mismatch of join on clauses: left: {:?} vs right: {:?}",
+                left_on.len(),
+                right.keys.num_columns()
+            )));
+        }
+
+        let join_schema = infer_join_schema(&self.schema, &right.table.schema, JoinType::AsOf)?;
+        let ltable = self.eval_expression_list(left_on)?;
+        let ltable = Self::from_nonempty_columns(
+            ltable
+                .columns
+                .iter()
+                .zip(right.keys.columns.iter())
+                .map(|(ls, rs)| ls.cast(rs.data_type()))
+                .collect::<DaftResult<Vec<_>>>()?,
+        )?;
+        let left_order = merge_join::asof_order(&ltable)?;
+        let rtable = &right.keys;
+        let right_keys = &right.sorted_keys;
+        let right = &right.table;
+
+        // The distance between the as-of keys of each row and its match, which is never negative.
+        let left_key = ltable.columns.last().unwrap();
+        let right_key = rtable.columns.last().unwrap();
+        let distance = |right_idx: &UInt64Array, forward: bool| -> DaftResult<Series> {
+            let matched = right_key.take(&right_idx.clone().into_series())?;
+            if forward {
+                &matched - left_key
+            } else {
+                left_key - &matched
+            }
+        };
+
+        let (right_idx, distance) = match options.direction {
+            AsofDirection::Backward | AsofDirection::Forward => {
+                let forward = options.direction == AsofDirection::Forward;
+                let right_idx =
+                    merge_join::merge_asof_join(&ltable, &left_order, right_keys, forward)?;
+                let distance = match options.tolerance {
+                    Some(_) => Some(distance(&right_idx, forward)?),
+                    None => None,
+                };
+                (right_idx, distance)
+            }
+            AsofDirection::Nearest => {
+                let backward_idx =
+                    merge_join::merge_asof_join(&ltable, &left_order, right_keys, false)?;
+                let forward_idx =
+                    merge_join::merge_asof_join(&ltable, &left_order, right_keys, true)?;
+                let backward_distance = distance(&backward_idx, false)?;
+                let forward_distance = distance(&forward_idx, true)?;
+
+                // ties go to the backward match
+                let forward_is_closer = forward_distance.lt(&backward_distance)?;
+                let use_forward = backward_idx
+                    .into_iter()
+                    .zip(forward_is_closer.into_iter())
+                    .map(|(backward, closer)| Some(backward.is_none() || closer == Some(true)));
+                let use_forward = BooleanArray::from_iter("use_forward", use_forward);
+                let right_idx = backward_idx
+                    .into_iter()
+                    .zip(forward_idx.into_iter())
+                    .zip(use_forward.into_iter())
+                    .map(|((backward, forward), use_forward)| {
+                        if use_forward == Some(true) {
+                            forward.copied()
+                        } else {
+                            backward.copied()
+                        }
+                    });
+                let right_idx = UInt64Array::from_iter(
+                    Field::new("right_indices", DataType::UInt64),
+                    right_idx,
+                );
+                let distance =
+                    forward_distance.if_else(&backward_distance, &use_forward.into_series())?;
+                (right_idx, Some(distance))
+            }
+        };
+
+        // drop the matches which are further away than the tolerance
+        let right_idx = match (&options.tolerance, distance) {
+            (Some(tolerance), Some(distance)) => {
+                let tolerance = tolerance
+                    .to_series()
+                    .cast(distance.data_type())?
+                    .broadcast(distance.len())?;
+                let within_tolerance = distance.lte(&tolerance)?;
+                let right_idx = right_idx
+                    .into_iter()
+                    .zip(within_tolerance.into_iter())
+                    .map(|(idx, within)| idx.copied().filter(|_| within == Some(true)));
+                UInt64Array::from_iter(Field::new("right_indices", DataType::UInt64), right_idx)
+            }
+            _ => right_idx,
+        };
+
+        drop(ltable);
+
+        let right_idx = right_idx.into_series();
+        let join_series = join_schema
+            .fields
+            .values()
+            .map(|field| {
+                if self.schema.has_field(&field.name) {
+                    Ok(self.get_column(&field.name)?.clone())
+                } else if right.is_empty() {
+                    Ok(Series::full_null(&field.name, &field.dtype, self.len()))
+                } else {
+                    right.get_column(&field.name)?.take(&right_idx)
+                }
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        Self::new_with_size(join_schema, join_series, self.len())
+    }
+
     pub fn cross_join(&self, right: &Self, outer_loop_side: JoinSide) -> DaftResult<Self> {
         /// Create a new table by repeating each column of the input table `inner_len` times in a row, thus preserving sort order.
         fn create_outer_loop_table(
@@ -236,4 +456,32 @@ impl RecordBatch {
 
         Self::new_with_size(join_schema, join_columns, num_rows)
     }
+
+    /// Evaluates a join predicate on the rows of `left` and `right` pairwise, returning whether each
+    /// pair of rows satisfies it.
+    pub fn eval_join_predicate(
+        left: &Self,
+        right: &Self,
+        predicate: &ExprRef,
+    ) -> DaftResult<BooleanArray> {
+        let num_rows = left.len();
+        let schema = join_predicate_schema(predicate, &left.schema, &right.schema)?;
+        let (left_columns, right_columns) = join_predicate_columns(predicate);
+        let columns = left_columns
+            .iter()
+            .map(|name| left.get_column(name))
+            .chain(right_columns.iter().map(|name| right.get_column(name)))
+            .zip(schema.fields.keys())
+            .map(|(column, name)| Ok(column?.rename(name)))
+            .collect::<DaftResult<Vec<_>>>()?;
+        let batch = Self::new_with_size(schema, columns, num_rows)?;
+
+        let mask = batch.eval_expression(&bind_join_predicate(predicate.clone()))?;
+        let mask = if mask.len() == 1 && num_rows != 1 {
+            mask.broadcast(num_rows)?
+        } else {
+            mask
+        };
+        Ok(mask.bool()?.clone())
+    }
 }
@@ -8,3 +8,6 @@ mod pivot;
 mod search_sorted;
 mod sort;
 mod unpivot;
+mod window;
+
+pub use joins::AsofJoinRight;
@@ -0,0 +1,713 @@
+use std::{cmp::Ordering, collections::VecDeque, ops::Range};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{arrow2::comparison::build_multi_array_is_equal, as_arrow::AsArrow},
+    kernels::search_sorted::build_compare_with_nan,
+    prelude::*,
+};
+use daft_dsl::{
+    AggExpr, Expr, ExprRef, WindowBoundary, WindowExpr, WindowFrame, WindowFrameType, WindowSpec,
+};
+
+use crate::RecordBatch;
+
+/// The row boundaries of each partition and of each group of peers (rows with equal order by
+/// values) of a table sorted by its window's partition by and order by expressions.
+struct WindowLayout {
+    partitions: Vec<Range<usize>>,
+    /// For each row, the index of the partition it belongs to.
+    partition_ids: Vec<usize>,
+    /// For each row, the rows of its peer group.
+    peers: Vec<Range<usize>>,
+}
+
+impl WindowLayout {
+    fn try_new(sorted: &RecordBatch, window_spec: &WindowSpec) -> DaftResult<Self> {
+        let num_rows = sorted.len();
+        let partitions = split_on_change(sorted, &window_spec.partition_by, 0..num_rows)?;
+        let mut partition_ids = Vec::with_capacity(num_rows);
+        let mut peers = Vec::with_capacity(num_rows);
+        for (partition_id, partition) in partitions.iter().enumerate() {
+            partition_ids.extend(std::iter::repeat_n(partition_id, partition.len()));
+            for peer_group in split_on_change(sorted, &window_spec.order_by, partition.clone())? {
+                peers.extend(std::iter::repeat_n(peer_group.clone(), peer_group.len()));
+            }
+        }
+        Ok(Self {
+            partitions,
+            partition_ids,
+            peers,
+        })
+    }
+
+    fn partition_of(&self, row: usize) -> &Range<usize> {
+        &self.partitions[self.partition_ids[row]]
+    }
+}
+
+/// Splits `rows` into runs of consecutive rows that are equal on all of `exprs`.
+fn split_on_change(
+    table: &RecordBatch,
+    exprs: &[ExprRef],
+    rows: Range<usize>,
+) -> DaftResult<Vec<Range<usize>>> {
+    if rows.is_empty() {
+        return Ok(vec![]);
+    }
+    if exprs.is_empty() {
+        return Ok(vec![rows]);
+    }
+    let columns = table.eval_expression_list(exprs)?;
+    let columns = columns.columns.as_slice();
+    let all_true = vec![true; columns.len()];
+    let is_equal = build_multi_array_is_equal(columns, columns, &all_true, &all_true)?;
+
+    let mut runs = vec![];
+    let mut run_start = rows.start;
+    for row in rows.start + 1..rows.end {
+        if !is_equal(row - 1, row) {
+            runs.push(run_start..row);
+            run_start = row;
+        }
+    }
+    runs.push(run_start..rows.end);
+    Ok(runs)
+}
+
+fn unwrap_window_function(expr: &ExprRef) -> DaftResult<(&WindowExpr, &str)> {
+    match expr.as_ref() {
+        Expr::Alias(inner, name) => match inner.as_ref() {
+            Expr::Over(window_expr, _) => Ok((window_expr, name)),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected a window function in a Window operator, found: {expr}"
+            ))),
+        },
+        Expr::Over(window_expr, _) => Ok((window_expr, window_expr.name())),
+        _ => Err(DaftError::ValueError(format!(
+            "Expected a window function in a Window operator, found: {expr}"
+        ))),
+    }
+}
+
+/// Frames can overlap, so aggregating them one by one materializes up to this many of their rows at a time.
+const MAX_FRAME_ROWS_PER_CHUNK: usize = 1 << 20;
+
+/// The number of valid values before each row, and in total.
+fn prefix_valid_counts(input: &Series) -> Vec<u64> {
+    let mut counts = Vec::with_capacity(input.len() + 1);
+    counts.push(0);
+    let mut count = 0;
+    for row in 0..input.len() {
+        count += u64::from(input.is_valid(row));
+        counts.push(count);
+    }
+    counts
+}
+
+/// The number of valid values and their running sum before each row, and in total.
+fn prefix_sums<T: arrow2::types::NativeType>(
+    values: &arrow2::array::PrimitiveArray<T>,
+    zero: T,
+    add: impl Fn(T, T) -> T,
+) -> (Vec<u64>, Vec<T>) {
+    let mut valid_counts = Vec::with_capacity(values.len() + 1);
+    let mut sums = Vec::with_capacity(values.len() + 1);
+    let (mut valid_count, mut sum) = (0, zero);
+    valid_counts.push(valid_count);
+    sums.push(sum);
+    for value in values {
+        if let Some(value) = value {
+            valid_count += 1;
+            sum = add(sum, *value);
+        }
+        valid_counts.push(valid_count);
+        sums.push(sum);
+    }
+    (valid_counts, sums)
+}
+
+/// The sum of each frame's valid values, or null if it has none.
+fn frame_sums<T: Copy>(
+    frames: &[Range<usize>],
+    valid_counts: &[u64],
+    sums: &[T],
+    sub: impl Fn(T, T) -> T,
+) -> Vec<Option<T>> {
+    frames
+        .iter()
+        .map(|frame| {
+            (valid_counts[frame.end] > valid_counts[frame.start])
+                .then(|| sub(sums[frame.end], sums[frame.start]))
+        })
+        .collect()
+}
+
+/// Whether the frames of each partition's rows only ever move forward, so that they can be aggregated by
+/// adding the rows that enter each frame and removing the ones that leave it.
+fn frames_slide_forward(frames: &[Range<usize>], layout: &WindowLayout) -> bool {
+    layout.partitions.iter().all(|partition| {
+        frames[partition.clone()]
+            .windows(2)
+            .all(|pair| pair[0].start <= pair[1].start && pair[0].end <= pair[1].end)
+    })
+}
+
+/// Slides a running aggregate `state` over the frames of each partition's rows, calling `add` for the rows
+/// that enter each frame and `remove` for the ones that leave it, then `value` for the frame's aggregate.
+///
+/// The frames of each partition must only ever move forward, see [`frames_slide_forward`].
+fn slide_frames<S: Default, T>(
+    frames: &[Range<usize>],
+    layout: &WindowLayout,
+    state: &mut S,
+    mut add: impl FnMut(&mut S, usize),
+    mut remove: impl FnMut(&mut S, usize),
+    value: impl Fn(&S) -> T,
+) -> Vec<T> {
+    let mut values = Vec::with_capacity(frames.len());
+    for partition in &layout.partitions {
+        *state = S::default();
+        let (mut start, mut end) = (partition.start, partition.start);
+        for frame in &frames[partition.clone()] {
+            // Frames that don't overlap the previous one start from scratch.
+            if frame.start >= end {
+                *state = S::default();
+                (start, end) = (frame.start, frame.start);
+            }
+            for row in end..frame.end {
+                add(state, row);
+            }
+            for row in start..frame.start {
+                remove(state, row);
+            }
+            (start, end) = (frame.start, frame.end);
+            values.push(value(state));
+        }
+    }
+    values
+}
+
+/// A sum of floats that values can be both added to and removed from.
+///
+/// Rounding errors are compensated for, and non-finite values are counted rather than summed so that
+/// removing them leaves a finite sum again.
+#[derive(Default)]
+struct SlidingSum {
+    sum: f64,
+    compensation: f64,
+    count: usize,
+    nans: usize,
+    infinities: usize,
+    negative_infinities: usize,
+}
+
+impl SlidingSum {
+    /// Adds `value` to the sum if `sign` is 1, or removes it if `sign` is -1. Nulls are ignored.
+    fn update(&mut self, value: Option<f64>, sign: f64) {
+        let Some(value) = value else {
+            return;
+        };
+        let delta = |count: usize| if sign > 0.0 { count + 1 } else { count - 1 };
+        self.count = delta(self.count);
+        if value.is_nan() {
+            self.nans = delta(self.nans);
+        } else if value == f64::INFINITY {
+            self.infinities = delta(self.infinities);
+        } else if value == f64::NEG_INFINITY {
+            self.negative_infinities = delta(self.negative_infinities);
+        } else {
+            // Neumaier's variant of Kahan summation.
+            let value = sign * value;
+            let sum = self.sum + value;
+            if self.sum.abs() >= value.abs() {
+                self.compensation += (self.sum - sum) + value;
+            } else {
+                self.compensation += (value - sum) + self.sum;
+            }
+            self.sum = sum;
+        }
+    }
+
+    fn value(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else if self.nans > 0 || (self.infinities > 0 && self.negative_infinities > 0) {
+            Some(f64::NAN)
+        } else if self.infinities > 0 {
+            Some(f64::INFINITY)
+        } else if self.negative_infinities > 0 {
+            Some(f64::NEG_INFINITY)
+        } else {
+            Some(self.sum + self.compensation)
+        }
+    }
+}
+
+impl RecordBatch {
+    /// Evaluates `window_functions` over `window_spec`, appending one column per function.
+    ///
+    /// The rows of the returned table are sorted by the partition by and order by expressions of the window.
+    pub fn window(
+        &self,
+        window_functions: &[ExprRef],
+        window_spec: &WindowSpec,
+    ) -> DaftResult<Self> {
+        let window_functions = window_functions
+            .iter()
+            .map(unwrap_window_function)
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let mut sort_keys = window_spec.partition_by.clone();
+        sort_keys.extend(window_spec.order_by.iter().cloned());
+        let mut descending = vec![false; window_spec.partition_by.len()];
+        descending.extend(window_spec.descending.iter().copied());
+        let mut nulls_first = vec![false; window_spec.partition_by.len()];
+        nulls_first.extend(window_spec.nulls_first.iter().copied());
+
+        let sorted = if sort_keys.is_empty() || self.is_empty() {
+            self.clone()
+        } else {
+            self.sort(&sort_keys, &descending, &nulls_first)?
+        };
+        let layout = WindowLayout::try_new(&sorted, window_spec)?;
+
+        let mut fields = self.schema.fields.values().cloned().collect::<Vec<_>>();
+        let mut columns = sorted.columns.iter().cloned().collect::<Vec<_>>();
+        for (window_expr, name) in window_functions {
+            let series = if sorted.is_empty() {
+                let field = window_expr.to_field(&self.schema)?;
+                Series::empty(name, &field.dtype)
+            } else {
+                sorted
+                    .eval_window_expression(window_expr, window_spec, &layout)?
+                    .rename(name)
+            };
+            fields.push(series.field().clone());
+            columns.push(series);
+        }
+        Self::new_with_size(Schema::new(fields)?, columns, sorted.len())
+    }
+
+    fn eval_window_expression(
+        &self,
+        window_expr: &WindowExpr,
+        window_spec: &WindowSpec,
+        layout: &WindowLayout,
+    ) -> DaftResult<Series> {
+        let num_rows = self.len();
+        match window_expr {
+            WindowExpr::Agg(agg_expr) => self.eval_window_agg(agg_expr, window_spec, layout),
+            WindowExpr::RowNumber => {
+                let values = (0..num_rows)
+                    .map(|row| (row - layout.partition_of(row).start + 1) as u64)
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from((window_expr.name(), values)).into_series())
+            }
+            WindowExpr::Rank => {
+                let values = (0..num_rows)
+                    .map(|row| {
+                        (layout.peers[row].start - layout.partition_of(row).start + 1) as u64
+                    })
+                    .collect::<Vec<_>>();
+                Ok(UInt64Array::from((window_expr.name(), values)).into_series())
+            }
+            WindowExpr::DenseRank => {
+                let mut values = Vec::with_capacity(num_rows);
+                let mut rank = 0;
+                for row in 0..num_rows {
+                    if row == layout.partition_of(row).start {
+                        rank = 0;
+                    }
+                    if row == layout.peers[row].start {
+                        rank += 1;
+                    }
+                    values.push(rank);
+                }
+                Ok(UInt64Array::from((window_expr.name(), values)).into_series())
+            }
+            WindowExpr::Offset {
+                input,
+                offset,
+                default,
+            } => {
+                let input = self.eval_expression(input)?;
+                let target_rows = (0..num_rows)
+                    .map(|row| {
+                        let target = row as i64 + offset;
+                        (target >= 0 && layout.partition_of(row).contains(&(target as usize)))
+                            .then_some(target as u64)
+                    })
+                    .collect::<Vec<_>>();
+                let in_bounds = target_rows.iter().map(Option::is_some).collect::<Vec<_>>();
+                let indices = UInt64Array::from_iter(
+                    Field::new("indices", DataType::UInt64),
+                    target_rows.into_iter(),
+                );
+                let shifted = input.take(&indices.into_series())?;
+                let Some(default) = default else {
+                    return Ok(shifted);
+                };
+                let default = self.eval_expression(default)?.cast(input.data_type())?;
+                let default = if default.len() == 1 {
+                    default.broadcast(num_rows)?
+                } else {
+                    default
+                };
+                shifted.if_else(
+                    &default,
+                    &BooleanArray::from(("in_bounds", in_bounds.as_slice())).into_series(),
+                )
+            }
+        }
+    }
+
+    fn eval_window_agg(
+        &self,
+        agg_expr: &AggExpr,
+        window_spec: &WindowSpec,
+        layout: &WindowLayout,
+    ) -> DaftResult<Series> {
+        let frame = match window_spec.frame {
+            Some(frame) if !frame.is_unbounded() => Some(frame),
+            Some(_) => None,
+            // Without an ORDER BY, every row of a partition is a peer of every other row, so the
+            // default frame of `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` is the whole partition.
+            None if window_spec.order_by.is_empty() => None,
+            None => Some(WindowFrame {
+                frame_type: WindowFrameType::Range,
+                start: WindowBoundary::UnboundedPreceding,
+                end: WindowBoundary::CurrentRow,
+            }),
+        };
+
+        let Some(frame) = frame else {
+            // Aggregate each partition once and broadcast the result to all of its rows.
+            let groups = layout
+                .partitions
+                .iter()
+                .map(|partition| (partition.start as u64..partition.end as u64).collect())
+                .collect::<Vec<_>>();
+            let aggregated = self.eval_agg_expression(agg_expr, Some(&groups))?;
+            let indices = layout
+                .partition_ids
+                .iter()
+                .map(|id| *id as u64)
+                .collect::<Vec<_>>();
+            return aggregated.take(&UInt64Array::from(("indices", indices)).into_series());
+        };
+
+        let frames = self.window_frames(&frame, window_spec, layout)?;
+        if let Some(aggregated) = self.eval_incremental_window_agg(agg_expr, &frames, layout)? {
+            return Ok(aggregated);
+        }
+
+        let has_empty_frame = frames.iter().any(Range::is_empty);
+        // Grouped aggregations expect non-empty groups, so empty frames are aggregated over their own
+        // row and then replaced by the value of an aggregation over no rows. Frames can overlap, so their
+        // rows are materialized a bounded number at a time.
+        let mut chunks = vec![];
+        let mut groups = vec![];
+        let mut num_group_rows = 0;
+        for (row, frame) in frames.iter().enumerate() {
+            let group = if frame.is_empty() {
+                vec![row as u64]
+            } else {
+                (frame.start as u64..frame.end as u64).collect::<Vec<_>>()
+            };
+            num_group_rows += group.len();
+            groups.push(group);
+            if num_group_rows >= MAX_FRAME_ROWS_PER_CHUNK {
+                chunks.push(self.eval_agg_expression(agg_expr, Some(&groups))?);
+                groups.clear();
+                num_group_rows = 0;
+            }
+        }
+        if !groups.is_empty() {
+            chunks.push(self.eval_agg_expression(agg_expr, Some(&groups))?);
+        }
+        let aggregated = match chunks.as_slice() {
+            [aggregated] => aggregated.clone(),
+            chunks => Series::concat(&chunks.iter().collect::<Vec<_>>())?,
+        };
+        if !has_empty_frame {
+            return Ok(aggregated);
+        }
+        let empty_value = match agg_expr {
+            AggExpr::Count(..) | AggExpr::CountDistinct(..) => {
+                UInt64Array::from((aggregated.name(), vec![0])).into_series()
+            }
+            _ => Series::full_null(aggregated.name(), aggregated.data_type(), 1),
+        }
+        .cast(aggregated.data_type())?
+        .broadcast(self.len())?;
+        let non_empty = frames
+            .iter()
+            .map(|frame| !frame.is_empty())
+            .collect::<Vec<_>>();
+        aggregated.if_else(
+            &empty_value,
+            &BooleanArray::from(("non_empty", non_empty.as_slice())).into_series(),
+        )
+    }
+
+    /// Computes count, sum, mean, min and max aggregations over the frame of every row in linear time,
+    /// by updating a running aggregate as frames slide forward instead of aggregating each frame from
+    /// scratch.
+    ///
+    /// Returns `None` for other aggregations and dtypes, which have to be aggregated frame by frame.
+    fn eval_incremental_window_agg(
+        &self,
+        agg_expr: &AggExpr,
+        frames: &[Range<usize>],
+        layout: &WindowLayout,
+    ) -> DaftResult<Option<Series>> {
+        let output_field = AggExpr::to_field(agg_expr, &self.schema)?;
+        let name = output_field.name.as_str();
+        match agg_expr {
+            AggExpr::Count(child, mode) => {
+                let input = self.eval_expression(child)?;
+                let valid_counts = prefix_valid_counts(&input);
+                let counts = frames
+                    .iter()
+                    .map(|frame| {
+                        let num_valid = valid_counts[frame.end] - valid_counts[frame.start];
+                        match mode {
+                            CountMode::All => frame.len() as u64,
+                            CountMode::Valid => num_valid,
+                            CountMode::Null => frame.len() as u64 - num_valid,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                Ok(Some(UInt64Array::from((name, counts)).into_series()))
+            }
+            // Integer sums are exact, so each frame's sum is the difference of two prefix sums.
+            AggExpr::Sum(child) if output_field.dtype == DataType::Int64 => {
+                let input = self.eval_expression(child)?.cast(&DataType::Int64)?;
+                let (valid_counts, sums) =
+                    prefix_sums(input.i64()?.as_arrow(), 0i64, i64::wrapping_add);
+                let sums = frame_sums(frames, &valid_counts, &sums, i64::wrapping_sub);
+                Ok(Some(
+                    Int64Array::from_iter(output_field, sums.into_iter()).into_series(),
+                ))
+            }
+            AggExpr::Sum(child) if output_field.dtype == DataType::UInt64 => {
+                let input = self.eval_expression(child)?.cast(&DataType::UInt64)?;
+                let (valid_counts, sums) =
+                    prefix_sums(input.u64()?.as_arrow(), 0u64, u64::wrapping_add);
+                let sums = frame_sums(frames, &valid_counts, &sums, u64::wrapping_sub);
+                Ok(Some(
+                    UInt64Array::from_iter(output_field, sums.into_iter()).into_series(),
+                ))
+            }
+            AggExpr::Sum(child) | AggExpr::Mean(child)
+                if matches!(output_field.dtype, DataType::Float32 | DataType::Float64) =>
+            {
+                if !frames_slide_forward(frames, layout) {
+                    return Ok(None);
+                }
+                let input = self.eval_expression(child)?.cast(&DataType::Float64)?;
+                let values = input.f64()?.as_arrow();
+                let is_mean = matches!(agg_expr, AggExpr::Mean(_));
+                let mut sum = SlidingSum::default();
+                let results = slide_frames(
+                    frames,
+                    layout,
+                    &mut sum,
+                    |sum, row| sum.update(values.get(row), 1.0),
+                    |sum, row| sum.update(values.get(row), -1.0),
+                    |sum| {
+                        let total = sum.value()?;
+                        Some(if is_mean {
+                            total / sum.count as f64
+                        } else {
+                            total
+                        })
+                    },
+                );
+                let results = Float64Array::from_iter(
+                    Field::new(name, DataType::Float64),
+                    results.into_iter(),
+                )
+                .into_series();
+                Ok(Some(results.cast(&output_field.dtype)?))
+            }
+            AggExpr::Min(child) | AggExpr::Max(child) => {
+                if !frames_slide_forward(frames, layout) {
+                    return Ok(None);
+                }
+                let input = self.eval_expression(child)?;
+                let physical = input.as_physical()?.to_arrow();
+                let Ok(compare) = build_compare_with_nan(physical.as_ref(), physical.as_ref())
+                else {
+                    return Ok(None);
+                };
+                // The rows that can still be a frame's min (or max) as it slides forward, in row order and
+                // with their values increasing (or decreasing), so that the frame's min is at the front.
+                let dominated = if matches!(agg_expr, AggExpr::Min(_)) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+                let mut candidates = VecDeque::new();
+                let indices = slide_frames(
+                    frames,
+                    layout,
+                    &mut candidates,
+                    |candidates, row| {
+                        if !input.is_valid(row) {
+                            return;
+                        }
+                        while candidates
+                            .back()
+                            .is_some_and(|back| compare(*back, row) == dominated)
+                        {
+                            candidates.pop_back();
+                        }
+                        candidates.push_back(row);
+                    },
+                    |candidates, row| {
+                        if candidates.front() == Some(&row) {
+                            candidates.pop_front();
+                        }
+                    },
+                    |candidates| candidates.front().map(|row| *row as u64),
+                );
+                let indices = UInt64Array::from_iter(
+                    Field::new("indices", DataType::UInt64),
+                    indices.into_iter(),
+                );
+                let result = input.take(&indices.into_series())?.rename(name);
+                Ok(Some(result.cast(&output_field.dtype)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Computes the rows of the frame of every row.
+    fn window_frames(
+        &self,
+        frame: &WindowFrame,
+        window_spec: &WindowSpec,
+        layout: &WindowLayout,
+    ) -> DaftResult<Vec<Range<usize>>> {
+        let num_rows = self.len();
+        match frame.frame_type {
+            WindowFrameType::Rows => Ok((0..num_rows)
+                .map(|row| {
+                    let partition = layout.partition_of(row);
+                    let clamp = |position: i128| {
+                        position.clamp(partition.start as i128, partition.end as i128) as usize
+                    };
+                    let row = row as i128;
+                    let start = match frame.start {
+                        WindowBoundary::UnboundedPreceding => partition.start,
+                        WindowBoundary::Preceding(n) => clamp(row - i128::from(n)),
+                        WindowBoundary::CurrentRow => clamp(row),
+                        WindowBoundary::Following(n) => clamp(row + i128::from(n)),
+                        WindowBoundary::UnboundedFollowing => partition.end,
+                    };
+                    let end = match frame.end {
+                        WindowBoundary::UnboundedPreceding => partition.start,
+                        WindowBoundary::Preceding(n) => clamp(row - i128::from(n) + 1),
+                        WindowBoundary::CurrentRow => clamp(row + 1),
+                        WindowBoundary::Following(n) => clamp(row + i128::from(n) + 1),
+                        WindowBoundary::UnboundedFollowing => partition.end,
+                    };
+                    start..end.max(start)
+                })
+                .collect()),
+            WindowFrameType::Range => {
+                let has_offset = [frame.start, frame.end].iter().any(|boundary| {
+                    matches!(
+                        boundary,
+                        WindowBoundary::Preceding(_) | WindowBoundary::Following(_)
+                    )
+                });
+                let (values, descending) = if has_offset {
+                    let [order_by] = window_spec.order_by.as_slice() else {
+                        return Err(DaftError::ValueError(format!(
+                            "Window frame {frame} with an offset requires exactly one ORDER BY expression"
+                        )));
+                    };
+                    let values = self.eval_expression(order_by)?.cast(&DataType::Float64)?;
+                    let values = values
+                        .f64()?
+                        .as_arrow()
+                        .iter()
+                        .map(|v| v.copied())
+                        .collect::<Vec<_>>();
+                    (values, window_spec.descending[0])
+                } else {
+                    (vec![], false)
+                };
+
+                // Nulls are sorted to one end of each partition, leaving the non-null values in order.
+                // Only needed to resolve offsets, so there are no values to look at otherwise.
+                let non_null_ranges = layout
+                    .partitions
+                    .iter()
+                    .filter(|_| has_offset)
+                    .map(|partition| {
+                        let is_non_null = |row: &usize| values[*row].is_some();
+                        match (
+                            partition.clone().find(is_non_null),
+                            partition.clone().rev().find(is_non_null),
+                        ) {
+                            (Some(first), Some(last)) => {
+                                let end = last + 1;
+                                first..end
+                            }
+                            _ => partition.start..partition.start,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok((0..num_rows)
+                    .map(|row| {
+                        let partition = layout.partition_of(row);
+                        let peers = &layout.peers[row];
+                        // Finds the first row of the partition at or after `offset` from the current row's value.
+                        // Null values only ever have their peers within an offset of them.
+                        let find = |offset: f64, inclusive: bool| {
+                            let Some(value) = values[row] else {
+                                return if inclusive { peers.start } else { peers.end };
+                            };
+                            let target = if descending {
+                                value - offset
+                            } else {
+                                value + offset
+                            };
+                            let non_null = &non_null_ranges[layout.partition_ids[row]];
+                            non_null.start
+                                + values[non_null.clone()].partition_point(|v| {
+                                    let v = v.expect("nulls are sorted to the ends of a partition");
+                                    match (descending, inclusive) {
+                                        (false, true) => v < target,
+                                        (false, false) => v <= target,
+                                        (true, true) => v > target,
+                                        (true, false) => v >= target,
+                                    }
+                                })
+                        };
+                        let start = match frame.start {
+                            WindowBoundary::UnboundedPreceding => partition.start,
+                            WindowBoundary::Preceding(n) => find(-(n as f64), true),
+                            WindowBoundary::CurrentRow => peers.start,
+                            WindowBoundary::Following(n) => find(n as f64, true),
+                            WindowBoundary::UnboundedFollowing => partition.end,
+                        };
+                        let end = match frame.end {
+                            WindowBoundary::UnboundedPreceding => partition.start,
+                            WindowBoundary::Preceding(n) => find(-(n as f64), false),
+                            WindowBoundary::CurrentRow => peers.end,
+                            WindowBoundary::Following(n) => find(n as f64, false),
+                            WindowBoundary::UnboundedFollowing => partition.end,
+                        };
+                        start..end.max(start)
+                    })
+                    .collect())
+            }
+        }
+    }
+}
@@ -4,11 +4,18 @@ mod probe_table;
 use std::sync::Arc;
 
 use common_error::DaftResult;
-use daft_core::prelude::SchemaRef;
+use daft_core::{
+    prelude::{SchemaRef, UInt64Array},
+    series::IntoSeries,
+};
+use daft_dsl::ExprRef;
 use probe_set::{ProbeSet, ProbeSetBuilder};
 use probe_table::{ProbeTable, ProbeTableBuilder};
 
-use crate::RecordBatch;
+use crate::{GrowableRecordBatch, RecordBatch};
+
+/// The maximum number of probe and build row pairs on which a join predicate is evaluated at once.
+const PREDICATE_CHUNK_SIZE: usize = 64 * 1024;
 
 struct ArrowTableEntry(Vec<Box<dyn arrow2::array::Array>>);
 
@@ -71,6 +78,142 @@ impl<'a> IndicesMapper<'a> {
     }
 }
 
+/// The build side rows which match each row of a probed table.
+pub struct ProbeMatches {
+    /// Start of the matches of each probed row in `indices`, followed by the total number of matches.
+    offsets: Vec<usize>,
+    /// Table and row index of each matching build side row.
+    indices: Vec<(usize, usize)>,
+}
+
+impl ProbeMatches {
+    fn new(idx_mapper: IndicesMapper) -> Self {
+        let mut offsets = vec![0];
+        let mut indices = vec![];
+        for matches in idx_mapper.make_iter() {
+            if let Some(matches) = matches {
+                indices.extend(
+                    matches.map(|(table_idx, row_idx)| (table_idx as usize, row_idx as usize)),
+                );
+            }
+            offsets.push(indices.len());
+        }
+        Self { offsets, indices }
+    }
+
+    /// Collects only the matches which satisfy the join predicate, which is evaluated on each
+    /// probed row of `probe_table` paired with its matching rows of `build_tables`.
+    ///
+    /// The pairs are evaluated in chunks of at most [`PREDICATE_CHUNK_SIZE`] rows, so that a probed
+    /// row which matches many build rows, such as in a nested loop join, doesn't materialize all
+    /// of its pairs at once.
+    fn filtered(
+        idx_mapper: IndicesMapper,
+        build_tables: &[RecordBatch],
+        probe_table: &RecordBatch,
+        predicate: &ExprRef,
+        build_on_left: bool,
+    ) -> DaftResult<Self> {
+        let build_tables = build_tables.iter().collect::<Vec<_>>();
+        let mut kept_probe_rows = vec![];
+        let mut indices = vec![];
+        let mut chunk = Vec::with_capacity(PREDICATE_CHUNK_SIZE);
+        for (probe_row, matches) in idx_mapper.make_iter().enumerate() {
+            let Some(matches) = matches else {
+                continue;
+            };
+            for (table_idx, row_idx) in matches {
+                chunk.push((probe_row, table_idx as usize, row_idx as usize));
+                if chunk.len() == PREDICATE_CHUNK_SIZE {
+                    Self::filter_chunk(
+                        &mut chunk,
+                        &build_tables,
+                        probe_table,
+                        predicate,
+                        build_on_left,
+                        &mut kept_probe_rows,
+                        &mut indices,
+                    )?;
+                }
+            }
+        }
+        Self::filter_chunk(
+            &mut chunk,
+            &build_tables,
+            probe_table,
+            predicate,
+            build_on_left,
+            &mut kept_probe_rows,
+            &mut indices,
+        )?;
+
+        // the pairs are produced in the order of the probed rows, so the kept rows are sorted
+        let mut offsets = Vec::with_capacity(probe_table.len() + 1);
+        offsets.push(0);
+        let mut kept = kept_probe_rows.iter().peekable();
+        for probe_row in 0..probe_table.len() {
+            let mut end = *offsets.last().unwrap();
+            while kept.next_if(|row| **row == probe_row).is_some() {
+                end += 1;
+            }
+            offsets.push(end);
+        }
+        Ok(Self { offsets, indices })
+    }
+
+    /// Evaluates the join predicate on a chunk of `(probe row, table index, row index)` pairs,
+    /// appending the pairs which satisfy it and clearing the chunk.
+    #[allow(clippy::too_many_arguments)]
+    fn filter_chunk(
+        chunk: &mut Vec<(usize, usize, usize)>,
+        build_tables: &[&RecordBatch],
+        probe_table: &RecordBatch,
+        predicate: &ExprRef,
+        build_on_left: bool,
+        kept_probe_rows: &mut Vec<usize>,
+        indices: &mut Vec<(usize, usize)>,
+    ) -> DaftResult<()> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+
+        let mut build_side_growable = GrowableRecordBatch::new(build_tables, false, chunk.len())?;
+        for &(_, table_idx, row_idx) in chunk.iter() {
+            build_side_growable.extend(table_idx, row_idx, 1);
+        }
+        let build_side_table = build_side_growable.build()?;
+
+        let probe_idx = chunk
+            .iter()
+            .map(|(probe_row, _, _)| *probe_row as u64)
+            .collect::<Vec<_>>();
+        let probe_idx = UInt64Array::from(("probe_indices", probe_idx)).into_series();
+        let probe_side_table = probe_table.take(&probe_idx)?;
+
+        let mask = if build_on_left {
+            RecordBatch::eval_join_predicate(&build_side_table, &probe_side_table, predicate)?
+        } else {
+            RecordBatch::eval_join_predicate(&probe_side_table, &build_side_table, predicate)?
+        };
+
+        for (idx, (probe_row, table_idx, row_idx)) in chunk.drain(..).enumerate() {
+            if mask.get(idx) == Some(true) {
+                kept_probe_rows.push(probe_row);
+                indices.push((table_idx, row_idx));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the matches of each probed row, which are none if the row has no matches.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&[(usize, usize)]>> {
+        self.offsets.windows(2).map(|range| {
+            let matches = &self.indices[range[0]..range[1]];
+            (!matches.is_empty()).then_some(matches)
+        })
+    }
+}
+
 pub trait Probeable: Send + Sync {
     /// Probe_indices returns an iterator of optional iterators. The outer iterator iterates over the rows of the right table.
     /// The inner iterator, if present, iterates over the rows of the left table that match the right row.
@@ -103,4 +246,22 @@ impl ProbeState {
     pub fn get_tables(&self) -> &Arc<Vec<RecordBatch>> {
         &self.tables
     }
+
+    /// Probes the build side with the join keys of `table`, keeping only the matches which also
+    /// satisfy the residual predicate of the join, if there is one.
+    pub fn probe_matches(
+        &self,
+        table: &RecordBatch,
+        join_keys: &RecordBatch,
+        residual_predicate: Option<&ExprRef>,
+        build_on_left: bool,
+    ) -> DaftResult<ProbeMatches> {
+        let idx_mapper = self.probeable.probe_indices(join_keys)?;
+        match residual_predicate {
+            Some(predicate) => {
+                ProbeMatches::filtered(idx_mapper, &self.tables, table, predicate, build_on_left)
+            }
+            None => Ok(ProbeMatches::new(idx_mapper)),
+        }
+    }
 }
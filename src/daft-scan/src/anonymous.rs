@@ -60,6 +60,9 @@ impl ScanOperator for AnonymousScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_aggregation(&self) -> bool {
+        false
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let mut lines = vec![
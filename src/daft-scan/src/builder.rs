@@ -2,7 +2,8 @@ use std::{collections::BTreeMap, sync::Arc};
 
 use common_error::DaftResult;
 use common_file_formats::{
-    CsvSourceConfig, FileFormatConfig, JsonSourceConfig, ParquetSourceConfig,
+    AvroSourceConfig, CsvSourceConfig, FileFormatConfig, JsonSourceConfig, OrcSourceConfig,
+    ParquetSourceConfig,
 };
 use common_io_config::IOConfig;
 use common_scan_info::ScanOperatorRef;
@@ -135,13 +136,19 @@ pub struct CsvScanBuilder {
     pub schema: Option<SchemaRef>,
     pub file_path_column: Option<String>,
     pub hive_partitioning: bool,
-    pub delimiter: Option<char>,
+    pub delimiter: Option<String>,
     pub has_headers: bool,
     pub double_quote: bool,
     pub quote: Option<char>,
     pub escape_char: Option<char>,
     pub comment: Option<char>,
     pub allow_variable_columns: bool,
+    pub encoding: Option<String>,
+    pub null_values: Option<Vec<String>>,
+    pub skip_rows: usize,
+    pub skip_rows_after_header: usize,
+    pub truncate_ragged_lines: bool,
+    pub datetime_formats: Option<BTreeMap<String, String>>,
     pub buffer_size: Option<usize>,
     pub chunk_size: Option<usize>,
     pub schema_hints: Option<SchemaRef>,
@@ -169,6 +176,12 @@ impl CsvScanBuilder {
             escape_char: None,
             comment: None,
             allow_variable_columns: false,
+            encoding: None,
+            null_values: None,
+            skip_rows: 0,
+            skip_rows_after_header: 0,
+            truncate_ragged_lines: false,
+            datetime_formats: None,
             buffer_size: None,
             chunk_size: None,
             schema_hints: None,
@@ -194,8 +207,8 @@ impl CsvScanBuilder {
         self.hive_partitioning = hive_partitioning;
         self
     }
-    pub fn delimiter(mut self, delimiter: char) -> Self {
-        self.delimiter = Some(delimiter);
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.delimiter = Some(delimiter.into());
         self
     }
     pub fn has_headers(mut self, has_headers: bool) -> Self {
@@ -222,6 +235,30 @@ impl CsvScanBuilder {
         self.allow_variable_columns = allow_variable_columns;
         self
     }
+    pub fn encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+    pub fn null_values(mut self, null_values: Vec<String>) -> Self {
+        self.null_values = Some(null_values);
+        self
+    }
+    pub fn skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+    pub fn skip_rows_after_header(mut self, skip_rows_after_header: usize) -> Self {
+        self.skip_rows_after_header = skip_rows_after_header;
+        self
+    }
+    pub fn truncate_ragged_lines(mut self, truncate_ragged_lines: bool) -> Self {
+        self.truncate_ragged_lines = truncate_ragged_lines;
+        self
+    }
+    pub fn datetime_formats(mut self, datetime_formats: BTreeMap<String, String>) -> Self {
+        self.datetime_formats = Some(datetime_formats);
+        self
+    }
     pub fn buffer_size(mut self, buffer_size: usize) -> Self {
         self.buffer_size = Some(buffer_size);
         self
@@ -244,6 +281,12 @@ impl CsvScanBuilder {
             escape_char: self.escape_char,
             comment: self.comment,
             allow_variable_columns: self.allow_variable_columns,
+            encoding: self.encoding,
+            null_values: self.null_values,
+            skip_rows: self.skip_rows,
+            skip_rows_after_header: self.skip_rows_after_header,
+            truncate_ragged_lines: self.truncate_ragged_lines,
+            datetime_formats: self.datetime_formats,
             buffer_size: self.buffer_size,
             chunk_size: self.chunk_size,
         };
@@ -360,6 +403,136 @@ impl JsonScanBuilder {
     }
 }
 
+/// An argument builder for an Avro scan operator.
+pub struct AvroScanBuilder {
+    pub glob_paths: Vec<String>,
+    pub infer_schema: bool,
+    pub io_config: Option<IOConfig>,
+    pub schema: Option<SchemaRef>,
+    pub file_path_column: Option<String>,
+    pub hive_partitioning: bool,
+}
+
+impl AvroScanBuilder {
+    pub fn new<T: IntoGlobPath>(glob_paths: T) -> Self {
+        Self {
+            glob_paths: glob_paths.into_glob_path(),
+            infer_schema: true,
+            io_config: None,
+            schema: None,
+            file_path_column: None,
+            hive_partitioning: false,
+        }
+    }
+
+    pub fn infer_schema(mut self, infer_schema: bool) -> Self {
+        self.infer_schema = infer_schema;
+        self
+    }
+
+    pub fn io_config(mut self, io_config: IOConfig) -> Self {
+        self.io_config = Some(io_config);
+        self
+    }
+
+    pub fn schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    pub fn file_path_column(mut self, file_path_column: String) -> Self {
+        self.file_path_column = Some(file_path_column);
+        self
+    }
+
+    pub fn hive_partitioning(mut self, hive_partitioning: bool) -> Self {
+        self.hive_partitioning = hive_partitioning;
+        self
+    }
+
+    /// Creates a logical table scan backed by an Avro scan operator.
+    pub async fn finish(self) -> DaftResult<LogicalPlanBuilder> {
+        let operator = Arc::new(
+            GlobScanOperator::try_new(
+                self.glob_paths,
+                Arc::new(FileFormatConfig::Avro(AvroSourceConfig::default())),
+                Arc::new(StorageConfig::new_internal(true, self.io_config)),
+                self.infer_schema,
+                self.schema,
+                self.file_path_column,
+                self.hive_partitioning,
+            )
+            .await?,
+        );
+        LogicalPlanBuilder::table_scan(ScanOperatorRef(operator), None)
+    }
+}
+
+/// An argument builder for an ORC scan operator.
+pub struct OrcScanBuilder {
+    pub glob_paths: Vec<String>,
+    pub infer_schema: bool,
+    pub io_config: Option<IOConfig>,
+    pub schema: Option<SchemaRef>,
+    pub file_path_column: Option<String>,
+    pub hive_partitioning: bool,
+}
+
+impl OrcScanBuilder {
+    pub fn new<T: IntoGlobPath>(glob_paths: T) -> Self {
+        Self {
+            glob_paths: glob_paths.into_glob_path(),
+            infer_schema: true,
+            io_config: None,
+            schema: None,
+            file_path_column: None,
+            hive_partitioning: false,
+        }
+    }
+
+    pub fn infer_schema(mut self, infer_schema: bool) -> Self {
+        self.infer_schema = infer_schema;
+        self
+    }
+
+    pub fn io_config(mut self, io_config: IOConfig) -> Self {
+        self.io_config = Some(io_config);
+        self
+    }
+
+    pub fn schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    pub fn file_path_column(mut self, file_path_column: String) -> Self {
+        self.file_path_column = Some(file_path_column);
+        self
+    }
+
+    pub fn hive_partitioning(mut self, hive_partitioning: bool) -> Self {
+        self.hive_partitioning = hive_partitioning;
+        self
+    }
+
+    /// Creates a logical table scan backed by an ORC scan operator.
+    pub async fn finish(self) -> DaftResult<LogicalPlanBuilder> {
+        let operator = Arc::new(
+            GlobScanOperator::try_new(
+                self.glob_paths,
+                Arc::new(FileFormatConfig::Orc(OrcSourceConfig::default())),
+                Arc::new(StorageConfig::new_internal(true, self.io_config)),
+                self.infer_schema,
+                self.schema,
+                self.file_path_column,
+                self.hive_partitioning,
+            )
+            .await?,
+        );
+        LogicalPlanBuilder::table_scan(ScanOperatorRef(operator), None)
+    }
+}
+
 #[cfg(feature = "python")]
 pub fn delta_scan<T: AsRef<str>>(
     glob_path: T,
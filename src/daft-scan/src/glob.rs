@@ -240,19 +240,33 @@ impl GlobScanOperator {
                         escape_char,
                         comment,
                         allow_variable_columns,
+                        encoding,
+                        null_values,
+                        skip_rows,
+                        skip_rows_after_header,
+                        truncate_ragged_lines,
+                        datetime_formats,
                         ..
                     }) => {
                         let (schema, _) = daft_csv::metadata::read_csv_schema(
                             first_filepath.as_str(),
-                            Some(CsvParseOptions::new_with_defaults(
-                                *has_headers,
-                                *delimiter,
-                                *double_quote,
-                                *quote,
-                                *allow_variable_columns,
-                                *escape_char,
-                                *comment,
-                            )?),
+                            Some(
+                                CsvParseOptions::new_with_defaults(
+                                    *has_headers,
+                                    delimiter.as_deref(),
+                                    *double_quote,
+                                    *quote,
+                                    *allow_variable_columns,
+                                    *escape_char,
+                                    *comment,
+                                )?
+                                .with_encoding(encoding.as_deref())?
+                                .with_null_values(null_values.clone())
+                                .with_skip_rows(*skip_rows)
+                                .with_skip_rows_after_header(*skip_rows_after_header)
+                                .with_truncate_ragged_lines(*truncate_ragged_lines)
+                                .with_datetime_formats(datetime_formats.clone()),
+                            ),
                             None,
                             io_client,
                             Some(io_stats),
@@ -271,6 +285,33 @@ impl GlobScanOperator {
                         .await?;
                         (schema, None)
                     }
+                    FileFormatConfig::Ipc(_) => {
+                        let schema = daft_ipc::read_ipc_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        (schema, None)
+                    }
+                    FileFormatConfig::Avro(_) => {
+                        let schema = daft_avro::read_avro_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        (schema, None)
+                    }
+                    FileFormatConfig::Orc(_) => {
+                        let schema = daft_orc::read_orc_schema(
+                            first_filepath.as_str(),
+                            io_client,
+                            Some(io_stats),
+                        )
+                        .await?;
+                        (schema, None)
+                    }
                     FileFormatConfig::Warc(_) => {
                         return Err(DaftError::ValueError(
                             "Warc schemas do not need to be inferred".to_string(),
@@ -346,6 +387,13 @@ impl ScanOperator for GlobScanOperator {
     fn can_absorb_limit(&self) -> bool {
         false
     }
+    fn can_absorb_aggregation(&self) -> bool {
+        // Parquet scan tasks can answer aggregations from their footers' row group statistics.
+        matches!(
+            self.file_format_config.as_ref(),
+            FileFormatConfig::Parquet(_)
+        )
+    }
 
     fn multiline_display(&self) -> Vec<String> {
         let condensed_glob_paths = if self.glob_paths.len() <= 7 {
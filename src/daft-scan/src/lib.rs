@@ -12,6 +12,7 @@ use common_display::DisplayAs;
 use common_error::DaftError;
 use common_file_formats::FileFormatConfig;
 use common_scan_info::{Pushdowns, ScanTaskLike, ScanTaskLikeRef};
+use daft_dsl::exprs_to_schema;
 use daft_schema::schema::{Schema, SchemaRef};
 use daft_stats::{PartitionSpec, TableMetadata, TableStatistics};
 use itertools::Itertools;
@@ -110,6 +111,9 @@ impl From<Error> for pyo3::PyErr {
 pub enum ChunkSpec {
     /// Selection of Parquet row groups.
     Parquet(Vec<i64>),
+    /// Byte range of a file whose format can be split at arbitrary offsets, such as Avro or ORC. The
+    /// reader picks out the blocks or stripes that belong to it.
+    Bytes { start: usize, end: usize },
 }
 
 impl ChunkSpec {
@@ -120,6 +124,9 @@ impl ChunkSpec {
             Self::Parquet(chunks) => {
                 res.push(format!("Chunks = {chunks:?}"));
             }
+            Self::Bytes { start, end } => {
+                res.push(format!("Byte range = {start}..{end}"));
+            }
         }
         res
     }
@@ -604,9 +611,25 @@ impl ScanTask {
         ))
     }
 
+    /// Returns a copy of this ScanTask that yields the scanned data rather than its aggregation pushdown.
+    #[must_use]
+    pub fn without_aggregations(&self) -> Self {
+        Self {
+            sources: self.sources.clone(),
+            schema: self.schema.clone(),
+            file_format_config: self.file_format_config.clone(),
+            storage_config: self.storage_config.clone(),
+            pushdowns: self.pushdowns.with_aggregations(None),
+            size_bytes_on_disk: self.size_bytes_on_disk,
+            metadata: self.metadata.clone(),
+            statistics: self.statistics.clone(),
+            generated_fields: self.generated_fields.clone(),
+        }
+    }
+
     #[must_use]
     pub fn materialized_schema(&self) -> SchemaRef {
-        match (&self.generated_fields, &self.pushdowns.columns) {
+        let schema = match (&self.generated_fields, &self.pushdowns.columns) {
             (None, None) => self.schema.clone(),
             _ => {
                 let mut fields = self.schema.fields.clone();
@@ -628,13 +651,22 @@ impl ScanTask {
                 }
                 Arc::new(Schema { fields })
             }
+        };
+        // Aggregation pushdowns yield partial aggregation results instead of the data.
+        match &self.pushdowns.aggregations {
+            Some(aggregations) => exprs_to_schema(aggregations, schema)
+                .expect("Aggregation pushdowns should resolve against the scanned schema"),
+            None => schema,
         }
     }
 
     /// Obtain an accurate, exact num_rows from the ScanTask, or `None` if this is not possible
     #[must_use]
     pub fn num_rows(&self) -> Option<usize> {
-        if self.pushdowns.filters.is_some() {
+        if self.pushdowns.aggregations.is_some() {
+            // Aggregation pushdowns yield a single row of partial results
+            Some(1)
+        } else if self.pushdowns.filters.is_some() {
             // Cannot obtain an accurate num_rows if there are filters
             None
         } else {
@@ -653,6 +685,9 @@ impl ScanTask {
     /// Obtain an approximate num_rows from the ScanTask, or `None` if this is not possible
     #[must_use]
     pub fn approx_num_rows(&self, config: Option<&DaftExecutionConfig>) -> Option<f64> {
+        if self.pushdowns.aggregations.is_some() {
+            return Some(1.0);
+        }
         let approx_total_num_rows_before_pushdowns = self
             .metadata
             .as_ref()
@@ -673,6 +708,12 @@ impl ScanTask {
                         }
                         // TODO(desmond): We can do a lot better here.
                         FileFormatConfig::Warc(_) => 1.0,
+                        // IPC buffers are laid out the same way on disk as in memory.
+                        FileFormatConfig::Ipc(_) => 1.0,
+                        // Compact binary encodings that are usually compressed, much like Parquet.
+                        FileFormatConfig::Avro(_) | FileFormatConfig::Orc(_) => {
+                            config.parquet_inflation_factor
+                        }
                         #[cfg(feature = "python")]
                         FileFormatConfig::Database(_) => 1.0,
                         #[cfg(feature = "python")]
@@ -703,6 +744,9 @@ impl ScanTask {
     /// Obtain the absolute maximum number of rows this ScanTask can give, or None if not possible to derive
     #[must_use]
     pub fn upper_bound_rows(&self) -> Option<usize> {
+        if self.pushdowns.aggregations.is_some() {
+            return Some(1);
+        }
         self.metadata.as_ref().map(|m| {
             if let Some(limit) = self.pushdowns.limit {
                 limit.min(m.length)
@@ -276,6 +276,9 @@ pub mod pylib {
         fn can_absorb_limit(&self) -> bool {
             self.can_absorb_limit
         }
+        fn can_absorb_aggregation(&self) -> bool {
+            false
+        }
         fn can_absorb_select(&self) -> bool {
             self.can_absorb_select
         }
@@ -600,7 +603,7 @@ pub mod pylib {
             Arc::new(FileFormatConfig::Parquet(default::Default::default())),
             Arc::new(schema),
             Arc::new(Default::default()),
-            Pushdowns::new(None, None, columns.map(Arc::new), None),
+            Pushdowns::new(None, None, columns.map(Arc::new), None, None),
             None,
         );
         Ok(st.estimate_in_memory_size_bytes(None).unwrap())
@@ -314,6 +314,74 @@ fn split_by_row_groups(
     }
 }
 
+/// Splits large Avro and ORC files into scan tasks over byte ranges of `min_size_bytes` each.
+///
+/// Unlike Parquet row groups, the block and stripe boundaries aren't looked up here: each reader
+/// works out which blocks or stripes start within its range, so splitting needs no I/O.
+#[must_use]
+fn split_by_byte_ranges(
+    scan_tasks: BoxScanTaskIter,
+    max_tasks: usize,
+    min_size_bytes: usize,
+    max_size_bytes: usize,
+) -> BoxScanTaskIter {
+    let mut scan_tasks = itertools::peek_nth(scan_tasks);
+
+    // only split if we have a small amount of files
+    if scan_tasks.peek_nth(max_tasks).is_some() || min_size_bytes == 0 {
+        return Box::new(scan_tasks);
+    }
+    Box::new(
+        scan_tasks
+            .map(move |t| -> DaftResult<BoxScanTaskIter> {
+                let t = t?;
+                let file_size = match (
+                    t.file_format_config.as_ref(),
+                    &t.sources[..],
+                    t.pushdowns.limit,
+                ) {
+                    (
+                        FileFormatConfig::Avro(_) | FileFormatConfig::Orc(_),
+                        [DataSource::File {
+                            chunk_spec: None,
+                            size_bytes: Some(size_bytes),
+                            ..
+                        }],
+                        None,
+                    ) if *size_bytes > max_size_bytes as u64 => *size_bytes as usize,
+                    _ => return Ok(Box::new(std::iter::once(Ok(t)))),
+                };
+                let new_tasks = (0..file_size).step_by(min_size_bytes).map(move |start| {
+                    let end = (start + min_size_bytes).min(file_size);
+                    let mut new_source = t.sources[0].clone();
+                    if let DataSource::File {
+                        chunk_spec,
+                        size_bytes,
+                        metadata,
+                        ..
+                    } = &mut new_source
+                    {
+                        *chunk_spec = Some(ChunkSpec::Bytes { start, end });
+                        *size_bytes = Some((end - start) as u64);
+                        // The row count of the whole file no longer applies.
+                        *metadata = None;
+                    }
+                    Ok(ScanTask::new(
+                        vec![new_source],
+                        t.file_format_config.clone(),
+                        t.schema.clone(),
+                        t.storage_config.clone(),
+                        t.pushdowns.clone(),
+                        t.generated_fields.clone(),
+                    )
+                    .into())
+                });
+                Ok(Box::new(new_tasks))
+            })
+            .flat_map(|t| t.unwrap_or_else(|e| Box::new(std::iter::once(Err(e))))),
+    )
+}
+
 fn split_and_merge_pass(
     scan_tasks: Arc<Vec<ScanTaskLikeRef>>,
     pushdowns: &Pushdowns,
@@ -333,6 +401,12 @@ fn split_and_merge_pass(
                 .downcast::<ScanTask>()
                 .map_err(|e| DaftError::TypeError(format!("Expected Arc<ScanTask>, found {:?}", e)))
         }));
+        let iter = split_by_byte_ranges(
+            iter,
+            cfg.parquet_split_row_groups_max_files,
+            cfg.scan_tasks_min_size_bytes,
+            cfg.scan_tasks_max_size_bytes,
+        );
         if cfg.scantask_splitting_level == 1 {
             let split_tasks = split_by_row_groups(
                 iter,
@@ -371,3 +445,65 @@ fn split_and_merge_pass(
 fn set_pass() {
     let _ = SPLIT_AND_MERGE_PASS.set(&split_and_merge_pass);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use common_file_formats::{AvroSourceConfig, FileFormatConfig};
+    use common_scan_info::Pushdowns;
+    use daft_schema::schema::Schema;
+
+    use super::{split_by_byte_ranges, BoxScanTaskIter};
+    use crate::{storage_config::StorageConfig, ChunkSpec, DataSource, ScanTask};
+
+    fn make_avro_scan_task(size_bytes: u64) -> ScanTask {
+        ScanTask::new(
+            vec![DataSource::File {
+                path: "test.avro".to_string(),
+                chunk_spec: None,
+                size_bytes: Some(size_bytes),
+                iceberg_delete_files: None,
+                metadata: None,
+                partition_spec: None,
+                statistics: None,
+                parquet_metadata: None,
+            }],
+            Arc::new(FileFormatConfig::Avro(AvroSourceConfig::default())),
+            Arc::new(Schema::empty()),
+            Arc::new(StorageConfig::new_internal(false, None)),
+            Pushdowns::default(),
+            None,
+        )
+    }
+
+    fn chunk_specs(size_bytes: u64) -> DaftResult<Vec<Option<ChunkSpec>>> {
+        let tasks: BoxScanTaskIter =
+            Box::new(std::iter::once(Ok(make_avro_scan_task(size_bytes).into())));
+        split_by_byte_ranges(tasks, 16, 100, 150)
+            .map(|task| Ok(task?.sources[0].get_chunk_spec().cloned()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_by_byte_ranges() -> DaftResult<()> {
+        assert_eq!(
+            chunk_specs(250)?,
+            vec![
+                Some(ChunkSpec::Bytes { start: 0, end: 100 }),
+                Some(ChunkSpec::Bytes {
+                    start: 100,
+                    end: 200
+                }),
+                Some(ChunkSpec::Bytes {
+                    start: 200,
+                    end: 250
+                }),
+            ]
+        );
+        // Files below the maximum task size are left whole.
+        assert_eq!(chunk_specs(150)?, vec![None]);
+        Ok(())
+    }
+}
@@ -16,7 +16,7 @@ use daft_physical_plan::{
         ActorPoolProject, Aggregate, BroadcastJoin, Concat, EmptyScan, Explode, Filter, HashJoin,
         InMemoryScan, Limit, MonotonicallyIncreasingId, Pivot, Project, Sample, Sort,
         SortMergeJoin, TabularScan, TabularWriteCsv, TabularWriteJson, TabularWriteParquet,
-        Unpivot,
+        Unpivot, Window,
     },
     PhysicalPlan, PhysicalPlanRef, QueryStageOutput,
 };
@@ -439,6 +439,23 @@ fn physical_plan_to_partition_tasks(
                 ))?;
             Ok(py_iter.into())
         }
+        PhysicalPlan::Window(Window {
+            input,
+            window_functions,
+            ..
+        }) => {
+            let upstream_iter =
+                physical_plan_to_partition_tasks(input, py, psets, actor_pool_manager)?;
+            let window_functions_pyexprs: Vec<PyExpr> = window_functions
+                .iter()
+                .map(|expr| PyExpr::from(expr.clone()))
+                .collect();
+            let py_iter = py
+                .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                .getattr(pyo3::intern!(py, "window"))?
+                .call1((upstream_iter, window_functions_pyexprs))?;
+            Ok(py_iter.into())
+        }
         PhysicalPlan::Sample(Sample {
             input,
             fraction,
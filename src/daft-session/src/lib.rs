@@ -1,8 +1,10 @@
 mod error;
 mod options;
 mod session;
+mod table_function;
 
 pub use session::*;
+pub use table_function::*;
 
 #[cfg(feature = "python")]
 pub mod python;
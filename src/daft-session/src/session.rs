@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     error::Result, obj_already_exists_err, obj_not_found_err, options::Options, unsupported_err,
+    TableFunctionRef,
 };
 
 /// Session holds all state for query planning and execution (e.g. connection).
@@ -25,6 +26,8 @@ struct SessionState {
     catalogs: Bindings<CatalogRef>,
     /// Bindings for the attached tables.
     tables: Bindings<TableRef>,
+    /// Bindings for the registered table functions.
+    table_functions: Bindings<TableFunctionRef>,
     // TODO execution context
     // TODO identifier matcher for case-insensitive matching
 }
@@ -37,6 +40,7 @@ impl Session {
             options: Options::default(),
             catalogs: Bindings::empty(),
             tables: Bindings::empty(),
+            table_functions: Bindings::empty(),
         };
         let state = RwLock::new(state);
         let state = Arc::new(state);
@@ -76,6 +80,20 @@ impl Session {
         Ok(())
     }
 
+    /// Registers a table function scoped to this session, err if already exists.
+    pub fn attach_table_function(
+        &self,
+        function: TableFunctionRef,
+        name: impl Into<String>,
+    ) -> Result<()> {
+        let name = name.into();
+        if self.state().table_functions.exists(&name) {
+            obj_already_exists_err!("Table function", &name.into())
+        }
+        self.state_mut().table_functions.insert(name, function);
+        Ok(())
+    }
+
     /// Creates a temp table scoped to this session from an existing view.
     ///
     /// TODO feat: consider making a CreateTableSource object for more complicated options.
@@ -102,6 +120,31 @@ impl Session {
         Ok(table)
     }
 
+    /// Creates a table in the current catalog, qualified by the current namespace if unqualified.
+    ///
+    /// ```text
+    /// CREATE [OR REPLACE] TABLE <name> <source>;
+    /// ```
+    pub fn create_table(
+        &self,
+        name: &Identifier,
+        source: &TableSource,
+        replace: bool,
+    ) -> Result<TableRef> {
+        let catalog = match self.current_catalog()? {
+            Some(catalog) => catalog,
+            None => unsupported_err!("Cannot create table {name} without a current catalog, did you mean CREATE TEMP TABLE?"),
+        };
+        let name = self.qualify_with_namespace(name)?;
+        if catalog.get_table(&name)?.is_some() {
+            if !replace {
+                obj_already_exists_err!("Table", &name)
+            }
+            catalog.drop_table(&name)?;
+        }
+        Ok(catalog.create_table(&name, source)?.into())
+    }
+
     /// Returns the session's current catalog.
     pub fn current_catalog(&self) -> Result<Option<CatalogRef>> {
         if let Some(catalog) = &self.state().options.curr_catalog {
@@ -125,6 +168,15 @@ impl Session {
         Ok(())
     }
 
+    /// Detaches a table function from this session, err if does not exist.
+    pub fn detach_table_function(&self, name: &str) -> Result<()> {
+        if !self.state().table_functions.exists(name) {
+            obj_not_found_err!("Table function", &name.into())
+        }
+        self.state_mut().table_functions.remove(name);
+        Ok(())
+    }
+
     /// Detaches a catalog from this session, err if does not exist.
     pub fn detach_catalog(&self, alias: &str) -> Result<()> {
         if !self.state().catalogs.exists(alias) {
@@ -138,6 +190,51 @@ impl Session {
         Ok(())
     }
 
+    /// Drops a temp table or a table of the current catalog, err if does not exist.
+    ///
+    /// ```text
+    /// DROP TABLE <name>;
+    /// ```
+    pub fn drop_table(&self, name: &Identifier) -> Result<()> {
+        //
+        // Rule 0: check temp tables.
+        if !name.has_qualifier() && self.state().tables.exists(&name.name) {
+            self.state_mut().tables.remove(&name.name);
+            return Ok(());
+        }
+        //
+        // Otherwise drop from the current catalog using the current namespace.
+        let catalog = match self.current_catalog()? {
+            Some(catalog) => catalog,
+            None => obj_not_found_err!("Table", name),
+        };
+        let name = self.qualify_with_namespace(name)?;
+        if catalog.get_table(&name)?.is_none() {
+            obj_not_found_err!("Table", &name)
+        }
+        catalog.drop_table(&name)
+    }
+
+    /// Drops a view scoped to this session, err if does not exist or is not a view.
+    ///
+    /// ```text
+    /// DROP VIEW <name>;
+    /// ```
+    pub fn drop_view(&self, name: &str) -> Result<()> {
+        let is_view = self.state().tables.get(name).map(|table| table.is_view());
+        match is_view {
+            Some(true) => {
+                self.state_mut().tables.remove(name);
+                Ok(())
+            }
+            Some(false) => unsupported_err!("Table {name} is not a view"),
+            None if self.has_table(&Identifier::simple(name)) => {
+                unsupported_err!("Table {name} is not a view")
+            }
+            None => obj_not_found_err!("View", &name.into()),
+        }
+    }
+
     /// Returns the catalog or an object not found error.
     pub fn get_catalog(&self, name: &str) -> Result<CatalogRef> {
         if let Some(catalog) = self.state().catalogs.get(name) {
@@ -147,6 +244,15 @@ impl Session {
         }
     }
 
+    /// Returns the table function or an object not found error.
+    pub fn get_table_function(&self, name: &str) -> Result<TableFunctionRef> {
+        if let Some(function) = self.state().table_functions.get(name) {
+            Ok(function.clone())
+        } else {
+            obj_not_found_err!("Table function", &name.into())
+        }
+    }
+
     /// Returns the table or an object not found error.
     pub fn get_table(&self, name: &Identifier) -> Result<TableRef> {
         //
@@ -200,14 +306,31 @@ impl Session {
         self.get_table(name).is_ok()
     }
 
+    /// Returns true iff the session has the table function.
+    pub fn has_table_function(&self, name: &str) -> bool {
+        self.state().table_functions.exists(name)
+    }
+
     /// Lists all catalogs matching the pattern.
     pub fn list_catalogs(&self, pattern: Option<&str>) -> Result<Vec<String>> {
         Ok(self.state().catalogs.list(pattern))
     }
 
-    /// Lists all tables matching the pattern.
+    /// Lists all temporary tables and the current catalog's tables matching the pattern.
     pub fn list_tables(&self, pattern: Option<&str>) -> Result<Vec<String>> {
-        Ok(self.state().tables.list(pattern))
+        let mut tables = self.state().tables.list(pattern);
+        if let Some(catalog) = self.current_catalog()? {
+            tables.extend(catalog.list_tables(pattern)?);
+        }
+        Ok(tables)
+    }
+
+    /// Qualifies the identifier with the current namespace unless it is already qualified.
+    fn qualify_with_namespace(&self, name: &Identifier) -> Result<Identifier> {
+        match self.current_namespace()? {
+            Some(namespace) if !name.has_qualifier() => Ok(name.qualify(namespace)),
+            _ => Ok(name.clone()),
+        }
     }
 
     /// Sets the current_catalog
@@ -236,6 +359,22 @@ impl Session {
         }
         Ok(())
     }
+
+    /// Sets a session option by name.
+    ///
+    /// ```text
+    /// SET <option> = <value>;
+    /// ```
+    pub fn set_option(&self, option: &str, value: &str) -> Result<()> {
+        match option.to_lowercase().as_str() {
+            "catalog" | "current_catalog" => self.set_catalog(Some(value)),
+            "namespace" | "current_namespace" | "schema" => {
+                let namespace = Identifier::from_sql(value, false)?;
+                self.set_namespace(Some(&namespace))
+            }
+            _ => unsupported_err!("Unknown session option '{option}'"),
+        }
+    }
 }
 
 impl Default for Session {
@@ -249,7 +388,7 @@ impl Default for Session {
 mod tests {
     use std::sync::Arc;
 
-    use daft_catalog::View;
+    use daft_catalog::{MemoryCatalog, View};
     use daft_core::prelude::*;
     use daft_logical_plan::{
         ops::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan, LogicalPlanBuilder,
@@ -300,4 +439,81 @@ mod tests {
             .get_table(&Identifier::simple("non_existent_table"))
             .is_err());
     }
+
+    #[test]
+    fn test_drop_temp_table() {
+        let sess = Session::empty();
+        let source = TableSource::View(mock_plan());
+        sess.create_temp_table("test_table", &source, false)
+            .expect("failed to create temp table");
+
+        let ident = Identifier::simple("test_table");
+        assert!(sess.drop_table(&ident).is_ok());
+        assert!(!sess.has_table(&ident));
+        assert!(sess.drop_table(&ident).is_err());
+    }
+
+    #[test]
+    fn test_drop_view() {
+        let sess = Session::empty();
+        let source = TableSource::View(mock_plan());
+        sess.create_temp_table("view", &source, false)
+            .expect("failed to create temp table");
+        sess.attach_catalog(MemoryCatalog::new("memory").arced(), "memory".to_string())
+            .expect("failed to attach catalog");
+        sess.create_table(&Identifier::simple("table"), &source, false)
+            .expect("failed to create table");
+
+        assert!(sess.drop_view("view").is_ok());
+        assert!(!sess.has_table(&Identifier::simple("view")));
+        assert!(sess.drop_view("view").is_err());
+        // tables of a catalog are not views
+        assert!(sess.drop_view("table").is_err());
+        assert!(sess.has_table(&Identifier::simple("table")));
+    }
+
+    #[test]
+    fn test_create_table_requires_catalog() {
+        let sess = Session::empty();
+        let source = TableSource::View(mock_plan());
+        assert!(sess
+            .create_table(&Identifier::simple("test_table"), &source, false)
+            .is_err());
+    }
+
+    #[test]
+    fn test_list_tables() {
+        let sess = Session::empty();
+        let source = TableSource::View(mock_plan());
+        sess.create_temp_table("temp_table", &source, false)
+            .expect("failed to create temp table");
+        sess.attach_catalog(MemoryCatalog::new("memory").arced(), "memory".to_string())
+            .expect("failed to attach catalog");
+
+        // tables are created in the current catalog
+        sess.create_table(&Identifier::simple("table"), &source, false)
+            .expect("failed to create table");
+        assert_eq!(
+            sess.list_tables(None).unwrap(),
+            vec!["temp_table".to_string(), "table".to_string()]
+        );
+        assert_eq!(
+            sess.list_tables(Some("temp")).unwrap(),
+            vec!["temp_table".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_set_option() {
+        let sess = Session::empty();
+        sess.set_option("namespace", "a.b")
+            .expect("failed to set namespace");
+        assert_eq!(
+            sess.current_namespace().unwrap(),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+        // catalogs must be attached before they can be used
+        assert!(sess.set_option("catalog", "missing").is_err());
+        assert!(sess.set_option("unknown", "value").is_err());
+    }
 }
@@ -0,0 +1,75 @@
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use daft_dsl::{ExprRef, LiteralValue};
+use daft_logical_plan::LogicalPlanBuilder;
+
+use crate::error::{Error, Result};
+
+/// Reference for a dynamic table function.
+pub type TableFunctionRef = Arc<dyn TableFunction>;
+
+/// A table-valued function, which is called in the FROM clause of a query to produce a relation.
+///
+/// ```text
+/// SELECT * FROM <name>(<arg>, <param> => <arg>, ..);
+/// ```
+pub trait TableFunction: Sync + Send + Debug {
+    /// Returns the parameters of the function, positional arguments are bound to them in order.
+    fn params(&self) -> &[TableFunctionParam];
+
+    /// Returns a plan producing the function's relation for the arguments bound to its parameters.
+    fn plan(&self, args: &TableFunctionArgs) -> Result<LogicalPlanBuilder>;
+}
+
+/// A named parameter of a [`TableFunction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableFunctionParam {
+    pub name: String,
+    pub required: bool,
+}
+
+impl TableFunctionParam {
+    /// Creates a parameter which must be given an argument.
+    pub fn required(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            required: true,
+        }
+    }
+
+    /// Creates a parameter which may be omitted.
+    pub fn optional(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            required: false,
+        }
+    }
+}
+
+/// The arguments of a [`TableFunction`] call by parameter name.
+#[derive(Debug, Clone, Default)]
+pub struct TableFunctionArgs(HashMap<String, ExprRef>);
+
+impl TableFunctionArgs {
+    pub fn new(args: HashMap<String, ExprRef>) -> Self {
+        Self(args)
+    }
+
+    /// Returns the argument of the parameter, if given.
+    pub fn get(&self, param: &str) -> Option<&ExprRef> {
+        self.0.get(param)
+    }
+
+    /// Returns the literal argument of the parameter if given, err if it is not a literal.
+    pub fn get_literal(&self, param: &str) -> Result<Option<&LiteralValue>> {
+        self.get(param)
+            .map(|arg| {
+                arg.as_literal().ok_or_else(|| {
+                    Error::unsupported(format!(
+                        "Expected a literal for argument {param}, found {arg}"
+                    ))
+                })
+            })
+            .transpose()
+    }
+}
@@ -15,7 +15,7 @@ use crate::{
         coalesce::SQLCoalesce, hashing::SQLModuleHashing, SQLModule, SQLModuleAggs,
         SQLModuleConfig, SQLModuleFloat, SQLModuleImage, SQLModuleJson, SQLModuleList,
         SQLModuleMap, SQLModuleNumeric, SQLModulePartitioning, SQLModulePython, SQLModuleSketch,
-        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8,
+        SQLModuleStructs, SQLModuleTemporal, SQLModuleUri, SQLModuleUtf8, SQLModuleWindow,
     },
     planner::SQLPlanner,
     unsupported_sql_err,
@@ -39,6 +39,7 @@ pub(crate) static SQL_FUNCTIONS: LazyLock<SQLFunctions> = LazyLock::new(|| {
     functions.register::<SQLModuleTemporal>();
     functions.register::<SQLModuleUri>();
     functions.register::<SQLModuleUtf8>();
+    functions.register::<SQLModuleWindow>();
     functions.register::<SQLModuleConfig>();
     functions.add_fn("coalesce", SQLCoalesce {});
     functions
@@ -50,10 +51,6 @@ fn check_features(func: &Function) -> SQLPlannerResult<()> {
         // <agg>(..) FILTER (WHERE ..)
         unsupported_sql_err!("Aggregation `FILTER`");
     }
-    if func.over.is_some() {
-        // WINDOW functions
-        unsupported_sql_err!("Window functions `OVER`");
-    }
     if !func.within_group.is_empty() {
         // <agg>(...) WITHIN GROUP
         unsupported_sql_err!("Aggregation `WITHIN GROUP`");
@@ -334,7 +331,13 @@ impl SQLPlanner<'_> {
         };
 
         // validate input argument arity and return the validated expression.
-        fn_match.to_expr(&args, self)
+        let expr = fn_match.to_expr(&args, self)?;
+
+        // <func>(..) OVER (..)
+        match &func.over {
+            Some(window_type) => Ok(expr.over(self.plan_window_spec(window_type)?)?),
+            None => Ok(expr),
+        }
     }
 
     pub(crate) fn plan_function_args<T>(
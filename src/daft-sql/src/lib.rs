@@ -12,9 +12,9 @@ pub use planner::*;
 #[cfg(feature = "python")]
 pub mod python;
 mod table_provider;
-
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+pub use table_provider::register_table_function;
 
 #[cfg(feature = "python")]
 pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
@@ -30,13 +30,13 @@ pub fn register_modules(parent: &Bound<PyModule>) -> PyResult<()> {
 mod tests {
     use std::sync::Arc;
 
-    use daft_core::prelude::*;
-    use daft_dsl::{lit, unresolved_col, Expr, Subquery};
+    use daft_core::{join::JoinSide, prelude::*};
+    use daft_dsl::{join_side_col, lit, unresolved_col, Expr, LiteralValue, Subquery};
     use daft_logical_plan::{
         logical_plan::Source, source_info::PlaceHolderInfo, ClusteringSpec, JoinOptions,
         LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
     };
-    use daft_session::Session;
+    use daft_session::{Session, TableFunction, TableFunctionArgs, TableFunctionParam};
     use error::SQLPlannerResult;
     use rstest::{fixture, rstest};
 
@@ -143,6 +143,10 @@ mod tests {
     #[case::join("select * from tbl2 join tbl3 on tbl2.id = tbl3.id")]
     #[case::null_safe_join("select * from tbl2 left join tbl3 on tbl2.id <=> tbl3.id")]
     #[case::join_with_filter("select * from tbl2 join tbl3 on tbl2.id = tbl3.id and tbl2.val > 0")]
+    #[case::join_with_residual("select * from tbl2 join tbl3 on tbl2.id = tbl3.id and tbl2.val between tbl3.id and tbl3.id + 10")]
+    #[case::left_join_with_residual("select * from tbl2 left join tbl3 on tbl2.id = tbl3.id and (tbl2.text = tbl3.first_name or tbl2.text = tbl3.last_name)")]
+    #[case::non_equi_join("select * from tbl2 join tbl3 on tbl2.val < tbl3.id")]
+    #[case::non_equi_anti_join("select * from tbl2 left anti join tbl3 on tbl2.val + tbl3.id = 0")]
     #[case::from("select tbl2.text from tbl2")]
     #[case::using("select tbl2.text from tbl2 join tbl3 using (id)")]
     #[case(
@@ -171,10 +175,58 @@ mod tests {
     #[case::orderby("select * from tbl1 order by i32 asc")]
     #[case::orderby_multi("select * from tbl1 order by i32 desc, f32 asc")]
     #[case::whenthen("select case when i32 = 1 then 'a' else 'b' end from tbl1")]
+    #[case::whenthen_operand("select case i32 when 1 then 'a' when 2 then 'b' end from tbl1")]
+    #[case::is_distinct_from("select * from tbl1 where i32 is distinct from i64")]
+    #[case::is_not_distinct_from("select * from tbl1 where i32 is not distinct from null")]
+    #[case::trim(
+        "select trim(utf8) as a, trim(both 'x' from utf8) as b, trim(trailing 'x' from utf8) as c from tbl1"
+    )]
+    #[case::position("select position('a' in utf8) from tbl1")]
+    #[case::overlay("select overlay(utf8 placing 'abc' from 2 for 1) from tbl1")]
+    #[case::rlike("select * from tbl1 where utf8 rlike '^a.*' and utf8 not regexp 'b$'")]
+    #[case::similar_to("select * from tbl1 where utf8 similar to '(a|b)%'")]
+    #[case::any_list("select * from tbl1 where i32 = any((1, 2, 3))")]
+    #[case::all_list("select * from tbl1 where i32 > all([1, 2, i64])")]
+    #[case::any_subquery("select * from tbl2 where id = any((select id from tbl3))")]
+    #[case::all_subquery("select * from tbl2 where val > all((select id from tbl3))")]
+    #[case::extract_fields("select extract(doy from date) as doy, extract(quarter from date) as q, extract(week from date) as w, extract(epoch from date) as e, extract(isodow from date) as dow, extract(century from date) as c from tbl1")]
+    #[case::at_time_zone("select date at time zone 'America/New_York' as d from tbl1")]
+    #[case::convert("select convert(i32, text) from tbl1")]
+    #[case::typed_literals("select date '2024-01-01' as d, time '12:30:00' as t, timestamp '2024-01-01 10:00:00' as ts, timestamp with time zone '2024-01-01 10:00:00+05:00' as tstz from tbl1")]
+    #[case::interval_range("select date + interval '1-6' year to month as d from tbl1")]
+    #[case::interval_negated("select date + -interval '1' day as d from tbl1")]
+    #[case::interval_sum("select date + (interval '1' day + interval '2' month) as d from tbl1")]
+    #[case::temporal_functions("select strftime(date, '%Y/%m/%d') as s, unix_timestamp(date, 'ms') as u, from_unix_timestamp(i64) as f, add_months(date, i32) as a, date_diff('month', date, date) as m, datediff(day, date, date) as d, dayofyear(date) as doy, weekofyear(date) as w, quarter(date) as q from tbl1")]
     #[case::globalagg("select max(i32) from tbl1")]
     #[case::cte("with cte as (select * from tbl1) select * from cte")]
     #[case::double_alias("select * from tbl1 as tbl2, tbl2 as tbl1")]
     #[case::double_alias_qualified("select tbl1.val from tbl1 as tbl2, tbl2 as tbl1")]
+    #[case::window_partition_by("select i32, sum(i64) over (partition by utf8) from tbl1")]
+    #[case::window_row_number(
+        "select row_number() over (partition by utf8 order by i32) from tbl1"
+    )]
+    #[case::window_rank("select rank() over (order by i32 desc nulls last) as r from tbl1")]
+    #[case::window_dense_rank(
+        "select dense_rank() over (partition by utf8 order by f32) from tbl1"
+    )]
+    #[case::window_lag_lead(
+        "select lag(i32, 2, 0) over (order by i32) as prev, lead(i32) over (order by i32) as next from tbl1"
+    )]
+    #[case::window_rows_frame("select avg(f64) over (partition by utf8 order by i32 rows between 2 preceding and current row) from tbl1")]
+    #[case::window_range_frame(
+        "select max(i64) over (order by i32 range between 1 preceding and 1 following) from tbl1"
+    )]
+    #[case::window_orderby("select utf8, row_number() over (partition by utf8 order by i32) as n from tbl1 order by utf8")]
+    #[case::rollup("select utf8, bool, sum(i64) from tbl1 group by rollup (utf8, bool)")]
+    #[case::cube("select utf8, bool, sum(i64) from tbl1 group by cube (utf8, bool)")]
+    #[case::with_rollup("select utf8, sum(i64) from tbl1 group by utf8 with rollup")]
+    #[case::grouping_sets(
+        "select utf8, bool, max(i32) from tbl1 group by grouping sets ((utf8, bool), (utf8), ())"
+    )]
+    #[case::grouping_sets_mixed(
+        "select utf8, bool, count(*) from tbl1 group by i32, rollup (utf8, bool)"
+    )]
+    #[case::grouping("select utf8, grouping(utf8) as g, grouping_id(utf8, bool) as gid from tbl1 group by cube (utf8, bool) order by g")]
     fn test_compiles(mut planner: SQLPlanner, #[case] query: &str) -> SQLPlannerResult<()> {
         let plan = planner.plan_sql(query);
         assert!(&plan.is_ok(), "query: {query}\nerror: {plan:?}");
@@ -316,6 +368,39 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    fn test_left_join_with_residual_predicate(
+        mut planner: SQLPlanner,
+        tbl_2: LogicalPlanRef,
+        tbl_3: LogicalPlanRef,
+    ) -> SQLPlannerResult<()> {
+        let sql =
+            "select * from tbl2 left join tbl3 on tbl2.id = tbl3.id and tbl2.val > 0 and tbl2.val < tbl3.id";
+        let plan = planner.plan_sql(sql)?;
+
+        // the filter on the left table must not remove its rows, so it is part of the residual predicate
+        let expected = LogicalPlanBuilder::from(tbl_2)
+            .alias("tbl2")
+            .join_with_residual_predicate(
+                LogicalPlanBuilder::from(tbl_3).alias("tbl3"),
+                vec![unresolved_col("id")],
+                vec![unresolved_col("id")],
+                Some(vec![false]),
+                Some(
+                    unresolved_col("val")
+                        .lt(join_side_col("id", JoinSide::Right))
+                        .and(unresolved_col("val").gt(lit(0_i64))),
+                ),
+                JoinType::Left,
+                None,
+                JoinOptions::default().prefix("tbl3."),
+            )?
+            .select(vec![unresolved_col("*")])?
+            .build();
+        assert_eq!(plan, expected);
+        Ok(())
+    }
+
     #[rstest]
     #[case::abs("select abs(i32) as abs from tbl1")]
     #[case::ceil("select ceil(i32) as ceil from tbl1")]
@@ -532,6 +617,128 @@ mod tests {
 
         assert!(result.is_err_and(|e| { matches!(e, PlannerError::ColumnNotFound { .. }) }));
 
+        Ok(())
+    }
+    #[rstest]
+    #[case::union_all("union all", Some(true))]
+    #[case::union("union", Some(false))]
+    #[case::non_recursive_union("union all", None)]
+    fn test_recursive_cte(
+        mut planner: SQLPlanner,
+        #[case] union: &str,
+        #[case] expected_union_all: Option<bool>,
+    ) -> SQLPlannerResult<()> {
+        use daft_dsl::common_treenode::TreeNode;
+
+        let recursive_term = match expected_union_all {
+            Some(_) => "select tbl2.id, r.val from tbl2 join r on tbl2.val = r.id",
+            None => "select id, id as val from tbl3",
+        };
+        let query = format!(
+            "with recursive r(id, val) as (select id, val from tbl2 {union} {recursive_term}) select * from r"
+        );
+        let plan = planner.plan_sql(&query)?;
+
+        let mut recursive_ctes = vec![];
+        plan.apply(|node| {
+            if let LogicalPlan::RecursiveCte(recursive_cte) = node.as_ref() {
+                recursive_ctes.push(recursive_cte.clone());
+            }
+            Ok(daft_dsl::common_treenode::TreeNodeRecursion::Continue)
+        })?;
+        match expected_union_all {
+            Some(union_all) => {
+                assert_eq!(recursive_ctes.len(), 1);
+                assert_eq!(recursive_ctes[0].all_rows_table.is_none(), union_all);
+            }
+            None => assert!(recursive_ctes.is_empty()),
+        }
+        // the working table is only bound while planning the recursive term
+        assert!(planner
+            .plan_sql("select * from tbl2 join r on tbl2.val = r.id")
+            .is_err());
+
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::positional_after_named(
+        "select * from range(start => 0, 5)",
+        "positional arguments of `range` must come before named arguments"
+    )]
+    #[case::too_many_rows(
+        "select * from range(0, 1000000000)",
+        "`range` produces 1000000000 rows, which is more than the maximum of 100000000"
+    )]
+    #[case::too_many_rows_inclusive(
+        "select * from generate_series(9223372036854775807, -9223372036854775807, -1)",
+        "`generate_series` produces 18446744073709551615 rows"
+    )]
+    fn test_range_errors(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] expected_error: &str,
+    ) {
+        let err = planner.plan_sql(query).unwrap_err();
+        assert!(err.to_string().contains(expected_error), "{err}");
+    }
+
+    #[derive(Debug)]
+    struct LimitTbl2 {
+        params: Vec<TableFunctionParam>,
+    }
+
+    impl TableFunction for LimitTbl2 {
+        fn params(&self) -> &[TableFunctionParam] {
+            &self.params
+        }
+
+        fn plan(
+            &self,
+            args: &TableFunctionArgs,
+        ) -> daft_catalog::error::Result<LogicalPlanBuilder> {
+            let limit = args.get_literal("limit")?.and_then(LiteralValue::as_i64);
+            Ok(LogicalPlanBuilder::from(tbl_2()).limit(limit.unwrap(), false)?)
+        }
+    }
+
+    #[rstest]
+    #[case::positional("select * from limit_tbl2(2)", Some(2))]
+    #[case::named("select * from limit_tbl2(limit => 2)", Some(2))]
+    #[case::table("select * from table(limit_tbl2(2))", Some(2))]
+    #[case::missing("select * from limit_tbl2()", None)]
+    #[case::unknown_named("select * from limit_tbl2(2, offset => 1)", None)]
+    #[case::too_many("select * from limit_tbl2(2, 3)", None)]
+    #[case::repeated("select * from limit_tbl2(2, limit => 3)", None)]
+    #[case::positional_after_named("select * from limit_tbl2(limit => 2, 3)", None)]
+    fn test_session_table_function(
+        mut planner: SQLPlanner,
+        #[case] query: &str,
+        #[case] expected_limit: Option<i64>,
+    ) -> SQLPlannerResult<()> {
+        let function = LimitTbl2 {
+            params: vec![TableFunctionParam::required("limit")],
+        };
+        planner
+            .session()
+            .attach_table_function(Arc::new(function), "limit_tbl2")?;
+
+        let result = planner.plan_sql(query);
+        match expected_limit {
+            Some(limit) => {
+                let expected = LogicalPlanBuilder::from(tbl_2())
+                    .limit(limit, false)?
+                    .select(vec![
+                        unresolved_col("text"),
+                        unresolved_col("id"),
+                        unresolved_col("val"),
+                    ])?
+                    .build();
+                assert_eq!(result?, expected);
+            }
+            None => assert!(result.is_err()),
+        }
+
         Ok(())
     }
 }
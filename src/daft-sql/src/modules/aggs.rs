@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use daft_core::prelude::CountMode;
 use daft_dsl::{unresolved_col, AggExpr, Expr, ExprRef, LiteralValue};
+use daft_functions::grouping::grouping_id;
 use sqlparser::ast::{FunctionArg, FunctionArgExpr};
 
 use super::SQLModule;
@@ -31,6 +32,42 @@ impl SQLModule for SQLModuleAggs {
         parent.add_fn("bool_or", AggExpr::BoolOr(nil.clone()));
         parent.add_fn("stddev", AggExpr::Stddev(nil.clone()));
         parent.add_fn("stddev_samp", AggExpr::Stddev(nil));
+        parent.add_fn("grouping", SQLGroupingFunction::Grouping);
+        parent.add_fn("grouping_id", SQLGroupingFunction::GroupingId);
+    }
+}
+
+/// Functions which identify the grouping set of a row with `GROUPING SETS`, `ROLLUP` or `CUBE`.
+enum SQLGroupingFunction {
+    Grouping,
+    GroupingId,
+}
+
+impl SQLFunction for SQLGroupingFunction {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let inputs = self.args_to_expr_unnamed(inputs, planner)?;
+        match self {
+            Self::Grouping => {
+                ensure!(inputs.len() == 1, "grouping takes exactly one argument");
+                Ok(grouping_id(inputs))
+            }
+            Self::GroupingId if inputs.is_empty() => Ok(grouping_id(inputs).alias("grouping_id")),
+            Self::GroupingId => Ok(grouping_id(inputs)),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        match self {
+            Self::Grouping => static_docs::GROUPING_DOCSTRING.to_string(),
+            Self::GroupingId => static_docs::GROUPING_ID_DOCSTRING.to_string(),
+        }
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Grouping => &["input"],
+            Self::GroupingId => &["inputs"],
+        }
     }
 }
 
@@ -511,4 +548,24 @@ Example:
     │ true    │
     ╰─────────╯
     (Showing first 1 of 1 rows)";
+
+    pub(crate) const GROUPING_DOCSTRING: &str =
+        "Returns 1 if the row is aggregated over the input grouping expression, and 0 if it is grouped by it.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT a, grouping(a), count(*) FROM tbl GROUP BY ROLLUP (a)";
+
+    pub(crate) const GROUPING_ID_DOCSTRING: &str =
+        "Returns the bitmask of the grouping expressions that the row is aggregated over, with the first expression as the most significant bit.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT a, b, grouping_id(), count(*) FROM tbl GROUP BY CUBE (a, b)";
 }
@@ -1,13 +1,23 @@
-use daft_dsl::ExprRef;
+use std::str::FromStr;
+
+use daft_core::prelude::TimeUnit;
+use daft_dsl::{ExprRef, LiteralValue};
 use daft_functions::temporal::{
-    dt_date, dt_day, dt_day_of_week, dt_hour, dt_minute, dt_month, dt_second, dt_time, dt_year,
+    add_months::dt_add_months,
+    date_diff::dt_date_diff,
+    dt_date, dt_day, dt_day_of_week, dt_day_of_year, dt_hour, dt_minute, dt_month, dt_quarter,
+    dt_second, dt_time, dt_week_of_year, dt_year,
+    strftime::dt_strftime,
+    unix_epoch::{dt_from_unix_epoch, dt_to_unix_epoch},
 };
-use sqlparser::ast::FunctionArg;
+use sqlparser::ast::{Expr, FunctionArg, FunctionArgExpr};
 
 use super::SQLModule;
 use crate::{
-    error::SQLPlannerResult,
+    error::{PlannerError, SQLPlannerResult},
     functions::{SQLFunction, SQLFunctions},
+    invalid_operation_err,
+    planner::SQLPlanner,
     unsupported_sql_err,
 };
 
@@ -18,12 +28,23 @@ impl SQLModule for SQLModuleTemporal {
         parent.add_fn("date", SQLDate);
         parent.add_fn("day", SQLDay);
         parent.add_fn("dayofweek", SQLDayOfWeek);
+        parent.add_fn("dayofyear", SQLDayOfYear);
+        parent.add_fn("day_of_year", SQLDayOfYear);
         parent.add_fn("hour", SQLHour);
         parent.add_fn("minute", SQLMinute);
         parent.add_fn("month", SQLMonth);
+        parent.add_fn("quarter", SQLQuarter);
         parent.add_fn("second", SQLSecond);
+        parent.add_fn("weekofyear", SQLWeekOfYear);
+        parent.add_fn("week_of_year", SQLWeekOfYear);
         parent.add_fn("year", SQLYear);
         parent.add_fn("time", SQLTime);
+        parent.add_fn("strftime", SQLStrftime);
+        parent.add_fn("unix_timestamp", SQLUnixTimestamp);
+        parent.add_fn("from_unix_timestamp", SQLFromUnixTimestamp);
+        parent.add_fn("add_months", SQLAddMonths);
+        parent.add_fn("date_diff", SQLDateDiff);
+        parent.add_fn("datediff", SQLDateDiff);
 
         // TODO: Add truncate
         // Our `dt_truncate` function has vastly different semantics than SQL `DATE_TRUNCATE` function.
@@ -69,9 +90,173 @@ macro_rules! temporal {
 temporal!(SQLDate, dt_date);
 temporal!(SQLDay, dt_day);
 temporal!(SQLDayOfWeek, dt_day_of_week);
+temporal!(SQLDayOfYear, dt_day_of_year);
 temporal!(SQLHour, dt_hour);
 temporal!(SQLMinute, dt_minute);
 temporal!(SQLMonth, dt_month);
+temporal!(SQLQuarter, dt_quarter);
 temporal!(SQLSecond, dt_second);
+temporal!(SQLWeekOfYear, dt_week_of_year);
 temporal!(SQLYear, dt_year);
 temporal!(SQLTime, dt_time);
+
+fn plan_string_arg(
+    planner: &SQLPlanner,
+    arg: &FunctionArg,
+    function: &str,
+    name: &str,
+) -> SQLPlannerResult<String> {
+    planner
+        .plan_function_arg(arg)?
+        .as_literal()
+        .and_then(LiteralValue::as_str)
+        .map(ToString::to_string)
+        .ok_or_else(|| {
+            PlannerError::invalid_operation(format!("{function} {name} must be a string"))
+        })
+}
+
+fn plan_time_unit_arg(
+    planner: &SQLPlanner,
+    arg: Option<&FunctionArg>,
+    function: &str,
+) -> SQLPlannerResult<TimeUnit> {
+    match arg {
+        None => Ok(TimeUnit::Seconds),
+        Some(arg) => {
+            let time_unit = plan_string_arg(planner, arg, function, "time unit")?;
+            TimeUnit::from_str(&time_unit).map_err(|_| {
+                PlannerError::invalid_operation(format!(
+                    "Invalid {function} time unit '{time_unit}', expected one of: s, ms, us, ns"
+                ))
+            })
+        }
+    }
+}
+
+pub struct SQLStrftime;
+
+impl SQLFunction for SQLStrftime {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input] => Ok(dt_strftime(planner.plan_function_arg(input)?, None)),
+            [input, format] => {
+                let input = planner.plan_function_arg(input)?;
+                let format = plan_string_arg(planner, format, "strftime", "format")?;
+                Ok(dt_strftime(input, Some(&format)))
+            }
+            _ => invalid_operation_err!("strftime takes either one or two arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Formats a datetime expression as a string using a strftime-style format, defaulting to ISO 8601.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "format"]
+    }
+}
+
+pub struct SQLUnixTimestamp;
+
+impl SQLFunction for SQLUnixTimestamp {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, rest @ ..] if rest.len() <= 1 => {
+                let input = planner.plan_function_arg(input)?;
+                let time_unit = plan_time_unit_arg(planner, rest.first(), "unix_timestamp")?;
+                Ok(dt_to_unix_epoch(input, time_unit))
+            }
+            _ => invalid_operation_err!("unix_timestamp takes either one or two arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Converts a datetime expression to the number of time units (seconds by default) since the Unix epoch.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "time_unit"]
+    }
+}
+
+pub struct SQLFromUnixTimestamp;
+
+impl SQLFunction for SQLFromUnixTimestamp {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, rest @ ..] if rest.len() <= 1 => {
+                let input = planner.plan_function_arg(input)?;
+                let time_unit = plan_time_unit_arg(planner, rest.first(), "from_unix_timestamp")?;
+                Ok(dt_from_unix_epoch(input, time_unit))
+            }
+            _ => invalid_operation_err!("from_unix_timestamp takes either one or two arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Converts a number of time units (seconds by default) since the Unix epoch to a datetime."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "time_unit"]
+    }
+}
+
+pub struct SQLAddMonths;
+
+impl SQLFunction for SQLAddMonths {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [input, months] => {
+                let input = planner.plan_function_arg(input)?;
+                let months = planner.plan_function_arg(months)?;
+                Ok(dt_add_months(input, months))
+            }
+            _ => invalid_operation_err!("add_months takes exactly two arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Adds a number of calendar months to a datetime expression, clamping the day to the end of the month.".to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["input", "months"]
+    }
+}
+
+pub struct SQLDateDiff;
+
+impl SQLFunction for SQLDateDiff {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        match inputs {
+            [unit, start, end] => {
+                // the unit may be given as a keyword, as in `date_diff(day, start, end)`
+                let unit = match unit {
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(ident)))
+                        if ident.quote_style.is_none() =>
+                    {
+                        ident.value.to_lowercase()
+                    }
+                    _ => plan_string_arg(planner, unit, "date_diff", "unit")?.to_lowercase(),
+                };
+                let start = planner.plan_function_arg(start)?;
+                let end = planner.plan_function_arg(end)?;
+                Ok(dt_date_diff(unit, start, end))
+            }
+            _ => invalid_operation_err!("date_diff takes exactly three arguments"),
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        "Counts the number of unit boundaries crossed going from the start to the end datetime."
+            .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        &["unit", "start", "end"]
+    }
+}
@@ -0,0 +1,151 @@
+use daft_dsl::{Expr, ExprRef, LiteralValue};
+use sqlparser::ast::FunctionArg;
+
+use super::SQLModule;
+use crate::{
+    ensure,
+    error::{PlannerError, SQLPlannerResult},
+    functions::{SQLFunction, SQLFunctions},
+    planner::SQLPlanner,
+};
+
+pub struct SQLModuleWindow;
+
+impl SQLModule for SQLModuleWindow {
+    fn register(parent: &mut SQLFunctions) {
+        parent.add_fn("row_number", SQLWindowFunction::RowNumber);
+        parent.add_fn("rank", SQLWindowFunction::Rank);
+        parent.add_fn("dense_rank", SQLWindowFunction::DenseRank);
+        parent.add_fn("lag", SQLWindowFunction::Lag);
+        parent.add_fn("lead", SQLWindowFunction::Lead);
+    }
+}
+
+/// Functions that can only be evaluated over a window, i.e. `<func>(..) OVER (..)`.
+enum SQLWindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag,
+    Lead,
+}
+
+impl SQLFunction for SQLWindowFunction {
+    fn to_expr(&self, inputs: &[FunctionArg], planner: &SQLPlanner) -> SQLPlannerResult<ExprRef> {
+        let args = self.args_to_expr_unnamed(inputs, planner)?;
+        match self {
+            Self::RowNumber => {
+                ensure!(args.is_empty(), "row_number takes no arguments");
+                Ok(Expr::row_number())
+            }
+            Self::Rank => {
+                ensure!(args.is_empty(), "rank takes no arguments");
+                Ok(Expr::rank())
+            }
+            Self::DenseRank => {
+                ensure!(args.is_empty(), "dense_rank takes no arguments");
+                Ok(Expr::dense_rank())
+            }
+            Self::Lag | Self::Lead => {
+                let name = if matches!(self, Self::Lag) {
+                    "lag"
+                } else {
+                    "lead"
+                };
+                ensure!(
+                    (1..=3).contains(&args.len()),
+                    "{name} takes between one and three arguments"
+                );
+                let offset = match args.get(1) {
+                    Some(offset) => offset
+                        .as_literal()
+                        .and_then(LiteralValue::as_i64)
+                        .and_then(|n| u64::try_from(n).ok())
+                        .ok_or_else(|| {
+                            PlannerError::invalid_operation(format!(
+                                "Expected a non-negative integer offset for {name}, found: {offset}"
+                            ))
+                        })?,
+                    None => 1,
+                };
+                let input = args[0].clone();
+                let default = args.get(2).cloned();
+                Ok(if matches!(self, Self::Lag) {
+                    input.lag(offset, default)
+                } else {
+                    input.lead(offset, default)
+                })
+            }
+        }
+    }
+
+    fn docstrings(&self, _alias: &str) -> String {
+        match self {
+            Self::RowNumber => static_docs::ROW_NUMBER_DOCSTRING,
+            Self::Rank => static_docs::RANK_DOCSTRING,
+            Self::DenseRank => static_docs::DENSE_RANK_DOCSTRING,
+            Self::Lag => static_docs::LAG_DOCSTRING,
+            Self::Lead => static_docs::LEAD_DOCSTRING,
+        }
+        .to_string()
+    }
+
+    fn arg_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::RowNumber | Self::Rank | Self::DenseRank => &[],
+            Self::Lag | Self::Lead => &["input", "offset", "default"],
+        }
+    }
+}
+
+mod static_docs {
+    pub(crate) const ROW_NUMBER_DOCSTRING: &str =
+        "Returns the 1-based number of the current row within its window partition.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, row_number() OVER (ORDER BY x) AS n FROM tbl";
+
+    pub(crate) const RANK_DOCSTRING: &str =
+        "Returns the rank of the current row within its window partition, with gaps after ties.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, rank() OVER (PARTITION BY y ORDER BY x) AS r FROM tbl";
+
+    pub(crate) const DENSE_RANK_DOCSTRING: &str =
+        "Returns the rank of the current row within its window partition, without gaps after ties.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, dense_rank() OVER (PARTITION BY y ORDER BY x) AS r FROM tbl";
+
+    pub(crate) const LAG_DOCSTRING: &str =
+        "Returns the value of the input `offset` rows before the current row within its window partition, or `default` if there is no such row.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, lag(x, 1, 0) OVER (ORDER BY x) AS prev_x FROM tbl";
+
+    pub(crate) const LEAD_DOCSTRING: &str =
+        "Returns the value of the input `offset` rows after the current row within its window partition, or `default` if there is no such row.
+
+Example:
+
+.. code-block:: sql
+    :caption: SQL
+
+    SELECT x, lead(x, 1, 0) OVER (ORDER BY x) AS next_x FROM tbl";
+}
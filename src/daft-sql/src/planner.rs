@@ -6,30 +6,35 @@ use std::{
     sync::Arc,
 };
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use common_error::DaftResult;
 use daft_algebra::boolean::combine_conjunction;
 use daft_catalog::Identifier;
-use daft_core::prelude::*;
+use daft_core::{datatypes::IntervalValue, prelude::*, utils::supertype::try_get_supertype};
 use daft_dsl::{
     has_agg, lit, literals_to_series, null_lit, resolved_col, unresolved_col, Column, Expr,
-    ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn,
+    ExprRef, LiteralValue, Operator, PlanRef, Subquery, UnresolvedColumn, WindowBoundary,
+    WindowFrame, WindowFrameType, WindowSpec,
 };
 use daft_functions::{
+    grouping::{cube, resolve_grouping_id, rollup},
+    list::count as list_count,
     numeric::{ceil::ceil, floor::floor},
-    utf8::{ilike, like, to_date, to_datetime},
+    temporal::convert_time_zone::dt_convert_time_zone,
+    utf8::{find, ilike, length, like, lstrip, match_, replace, rstrip, substr},
 };
 use daft_logical_plan::{
     ops::{SetQuantifier, UnionStrategy},
-    JoinOptions, LogicalPlanBuilder, LogicalPlanRef,
+    JoinOptions, LogicalPlan, LogicalPlanBuilder, LogicalPlanRef, SourceInfo,
 };
 use daft_session::Session;
 use itertools::Itertools;
 use sqlparser::{
     ast::{
-        self, BinaryOperator, CastKind, ColumnDef, DateTimeField, Distinct, ExcludeSelectItem,
+        self, BinaryOperator, CastKind, ColumnDef, Cte, DateTimeField, Distinct, ExcludeSelectItem,
         FunctionArg, FunctionArgExpr, GroupByExpr, Ident, ObjectName, Query, SelectItem, SetExpr,
-        Subscript, TableAlias, TableFunctionArgs, TableWithJoins, TimezoneInfo, UnaryOperator,
-        Value, WildcardAdditionalOptions, With,
+        Subscript, TableAlias, TableFactor, TableFunctionArgs, TableWithJoins, TimezoneInfo,
+        TrimWhereField, UnaryOperator, Value, Values, WildcardAdditionalOptions, With,
     },
     dialect::GenericDialect,
     parser::{Parser, ParserOptions},
@@ -43,6 +48,7 @@ use crate::{
 
 /// Bindings are used to lookup in-scope tables, views, and columns (targets T).
 /// This is an incremental step towards proper name resolution.
+#[derive(Clone)]
 struct Bindings<T>(HashMap<String, T>);
 
 impl<T> Bindings<T> {
@@ -70,6 +76,10 @@ impl<T> Default for Bindings<T> {
     }
 }
 
+/// Distinguishes the working tables of recursive CTEs, which are bound to in-memory scans.
+static NEXT_RECURSIVE_CTE_ID: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
 struct OrderByExprs {
     exprs: Vec<ExprRef>,
     descending: Vec<bool>,
@@ -79,7 +89,7 @@ struct OrderByExprs {
 /// Context for the planning the statement.
 /// TODO consolidate SQLPlanner state to the single context.
 /// TODO move bound_ctes into per-planner scope since these are a scoped concept.
-#[derive(Default)]
+#[derive(Clone, Default)]
 struct PlannerContext {
     /// Session provides access to metadata and the path for name resolution.
     /// TODO move into SQLPlanner once state is flipped.
@@ -187,7 +197,7 @@ impl<'a> SQLPlanner<'a> {
     }
 
     /// Borrow the planning session
-    fn session(&self) -> Ref<'_, Rc<Session>> {
+    pub(crate) fn session(&self) -> Ref<'_, Rc<Session>> {
         Ref::map(self.context.borrow(), |i| &i.session)
     }
 
@@ -197,23 +207,25 @@ impl<'a> SQLPlanner<'a> {
         self.context_mut().clear();
     }
 
-    fn plan_ctes(&self, with: &With) -> SQLPlannerResult<()> {
-        if with.recursive {
-            unsupported_sql_err!("Recursive CTEs are not supported");
-        }
-
+    pub(crate) fn plan_ctes(&self, with: &With) -> SQLPlannerResult<()> {
         for cte in &with.cte_tables {
             if cte.materialized.is_some() {
                 unsupported_sql_err!("MATERIALIZED is not supported");
             }
 
             if cte.from.is_some() {
+                if with.recursive {
+                    unsupported_sql_err!("FROM in recursive CTEs");
+                }
                 invalid_operation_err!("FROM should only exist in recursive CTEs");
             }
 
-            let plan = self.new_with_context().plan_query(&cte.query)?;
-
-            let plan = apply_table_alias(plan, &cte.alias)?;
+            let plan = if with.recursive {
+                self.plan_recursive_cte(cte)?
+            } else {
+                let plan = self.new_with_context().plan_query(&cte.query)?;
+                apply_table_alias(plan, &cte.alias)?
+            };
 
             self.context_mut()
                 .bound_ctes
@@ -222,6 +234,110 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
+    /// Plans a CTE of a `WITH RECURSIVE` clause.
+    ///
+    /// A CTE which references itself must be a `UNION [ALL]` of an anchor term and a recursive
+    /// term. The recursive term reads the CTE as a working table, which the executor provides with
+    /// the rows of the previous iteration, starting with the anchor's, until no new rows are
+    /// produced.
+    fn plan_recursive_cte(&self, cte: &Cte) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let name = cte.alias.name.value.clone();
+        let plan_non_recursive = || -> SQLPlannerResult<LogicalPlanBuilder> {
+            let plan = self.new_with_context().plan_query(&cte.query)?;
+            apply_table_alias(plan, &cte.alias)
+        };
+
+        let SetExpr::SetOperation {
+            op: ast::SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = cte.query.body.as_ref()
+        else {
+            return plan_non_recursive();
+        };
+
+        let anchor = self
+            .new_with_context()
+            .plan_query(&set_expr_to_query(left))?;
+        let anchor = apply_table_alias(anchor, &cte.alias)?;
+        let schema = anchor.schema();
+
+        // the recursive term is planned in its own context where the CTE is bound to the working
+        // table, so that the binding doesn't leak into the rest of the statement.
+        let id = NEXT_RECURSIVE_CTE_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let working_table = format!("{name}-{id}-working");
+        let mut context = self.context.borrow().clone();
+        context.bound_ctes.insert(
+            name.clone(),
+            LogicalPlanBuilder::working_table_scan(&working_table, schema.clone())
+                .alias(name.clone()),
+        );
+        let recursive = SQLPlanner {
+            context: Rc::new(RefCell::new(context)),
+            ..Default::default()
+        }
+        .plan_query(&set_expr_to_query(right))?;
+
+        // the CTE is recursive if its recursive term reads the working table
+        use daft_dsl::common_treenode::TreeNode;
+        if !recursive.plan.exists(|node| {
+            matches!(
+                node.as_ref(),
+                LogicalPlan::Source(source) if matches!(
+                    source.source_info.as_ref(),
+                    SourceInfo::InMemory(info) if info.cache_key == working_table
+                )
+            )
+        }) {
+            return plan_non_recursive();
+        }
+
+        let is_all = match set_quantifier {
+            ast::SetQuantifier::All => true,
+            ast::SetQuantifier::None | ast::SetQuantifier::Distinct => false,
+            _ => unsupported_sql_err!("UNION {set_quantifier} in recursive CTEs"),
+        };
+        let columns = recursive.schema().names();
+        if columns.len() != schema.len() {
+            invalid_operation_err!(
+                "The recursive term of {name} must have {} columns, found {}",
+                schema.len(),
+                columns.len()
+            );
+        }
+        // rows of the recursive term take the column names and types of the anchor's
+        let projection = columns
+            .into_iter()
+            .zip(schema.fields.values())
+            .map(|(column, field)| {
+                unresolved_col(column)
+                    .cast(&field.dtype)
+                    .alias(field.name.as_str())
+            })
+            .collect();
+        let recursive = recursive.select(projection)?;
+
+        // with `UNION`, rather than `UNION ALL`, duplicate rows are removed, including the ones
+        // produced by previous iterations, so that recursions over cyclic data terminate.
+        let (anchor, recursive, all_rows_table) = if is_all {
+            (anchor, recursive, None)
+        } else {
+            let all_rows_table = format!("{name}-{id}-all-rows");
+            let all_rows = LogicalPlanBuilder::working_table_scan(&all_rows_table, schema);
+            let recursive = recursive.distinct()?.except(&all_rows, false)?;
+            (anchor.distinct()?, recursive, Some(all_rows_table))
+        };
+        let plan = anchor.recursive_cte(
+            name.clone(),
+            &recursive,
+            working_table,
+            all_rows_table,
+            recursive_cte_max_iterations(),
+        )?;
+        Ok(plan.alias(name))
+    }
+
     pub fn plan(&mut self, input: &str) -> SQLPlannerResult<Statement> {
         let tokens = Tokenizer::new(&GenericDialect {}, input).tokenize()?;
 
@@ -272,24 +388,13 @@ impl<'a> SQLPlanner<'a> {
                     SetOperator::{Intersect, Union},
                     SetQuantifier as SQLSetQuantifier,
                 };
-                fn make_query(expr: &SetExpr) -> Query {
-                    Query {
-                        with: None,
-                        body: Box::new(expr.clone()),
-                        order_by: None,
-                        limit: None,
-                        limit_by: vec![],
-                        offset: None,
-                        fetch: None,
-                        locks: vec![],
-                        for_clause: None,
-                        settings: None,
-                        format_clause: None,
-                    }
-                }
 
-                let left = self.new_with_context().plan_query(&make_query(left))?;
-                let right = self.new_with_context().plan_query(&make_query(right))?;
+                let left = self
+                    .new_with_context()
+                    .plan_query(&set_expr_to_query(left))?;
+                let right = self
+                    .new_with_context()
+                    .plan_query(&set_expr_to_query(right))?;
 
                 return match (op, set_quantifier) {
                     (Union, set_quantifier) => {
@@ -322,8 +427,8 @@ impl<'a> SQLPlanner<'a> {
                     }
                 };
             }
-            SetExpr::Values(..) => unsupported_sql_err!("VALUES are not supported"),
-            SetExpr::Insert(..) => unsupported_sql_err!("INSERT is not supported"),
+            SetExpr::Values(values) => return self.plan_values(values),
+            SetExpr::Insert(..) => unsupported_sql_err!("INSERT is not supported in a subquery"),
             SetExpr::Update(..) => unsupported_sql_err!("UPDATE is not supported"),
             SetExpr::Table(..) => unsupported_sql_err!("TABLE is not supported"),
         };
@@ -353,6 +458,7 @@ impl<'a> SQLPlanner<'a> {
 
         // GROUP BY
         let mut groupby_exprs = Vec::new();
+        let mut grouping_sets = None;
 
         match &selection.group_by {
             GroupByExpr::All(s) => {
@@ -360,11 +466,22 @@ impl<'a> SQLPlanner<'a> {
                     unsupported_sql_err!("GROUP BY ALL");
                 }
             }
-            GroupByExpr::Expressions(expressions, _) => {
-                groupby_exprs = expressions
-                    .iter()
-                    .map(|expr| self.plan_expr(expr))
-                    .collect::<SQLPlannerResult<Vec<_>>>()?;
+            GroupByExpr::Expressions(expressions, modifiers) => {
+                let is_grouping_sets = !modifiers.is_empty()
+                    || expressions.iter().any(|expr| {
+                        matches!(
+                            expr,
+                            ast::Expr::GroupingSets(_) | ast::Expr::Rollup(_) | ast::Expr::Cube(_)
+                        ) || matches!(expr, ast::Expr::Tuple(exprs) if exprs.is_empty())
+                    });
+                if is_grouping_sets {
+                    grouping_sets = Some(self.plan_grouping_sets(expressions, modifiers)?);
+                } else {
+                    groupby_exprs = expressions
+                        .iter()
+                        .map(|expr| self.plan_expr(expr))
+                        .collect::<SQLPlannerResult<Vec<_>>>()?;
+                }
             }
         }
 
@@ -381,7 +498,8 @@ impl<'a> SQLPlanner<'a> {
             })
             .transpose()?;
 
-        let has_aggs = projections.iter().any(has_agg) || !groupby_exprs.is_empty();
+        let has_aggs =
+            projections.iter().any(has_agg) || !groupby_exprs.is_empty() || grouping_sets.is_some();
 
         if has_aggs {
             let having = selection
@@ -390,7 +508,7 @@ impl<'a> SQLPlanner<'a> {
                 .map(|h| self.plan_expr(h))
                 .transpose()?;
 
-            self.plan_aggregate_query(projections, order_by, groupby_exprs, having)?;
+            self.plan_aggregate_query(projections, order_by, groupby_exprs, grouping_sets, having)?;
         } else {
             self.plan_non_agg_query(projections, order_by)?;
         }
@@ -442,12 +560,40 @@ impl<'a> SQLPlanner<'a> {
         projections: Vec<Arc<Expr>>,
         order_by: Option<OrderByExprs>,
         groupby_exprs: Vec<Arc<Expr>>,
+        grouping_sets: Option<Vec<Vec<ExprRef>>>,
         having: Option<Arc<Expr>>,
     ) -> Result<(), PlannerError> {
         let mut aggs = HashSet::new();
 
         let schema = self.current_plan_ref().schema();
 
+        // grouping functions read the grouping id of the grouping sets aggregation
+        let grouping_sets_group_by: Vec<_> = grouping_sets
+            .iter()
+            .flatten()
+            .flatten()
+            .unique()
+            .cloned()
+            .collect();
+        let (projections, having, order_by) = match &grouping_sets {
+            Some(_) => {
+                let group_by = &grouping_sets_group_by;
+                let resolve = |expr| resolve_grouping_id(expr, group_by);
+                let projections = projections.into_iter().map(resolve).try_collect()?;
+                let having = having.map(resolve).transpose()?;
+                let order_by = order_by
+                    .map(|order_by| {
+                        DaftResult::Ok(OrderByExprs {
+                            exprs: order_by.exprs.into_iter().map(resolve).try_collect()?,
+                            ..order_by
+                        })
+                    })
+                    .transpose()?;
+                (projections, having, order_by)
+            }
+            None => (projections, having, order_by),
+        };
+
         let projections = projections
             .into_iter()
             .map(|expr| {
@@ -505,7 +651,15 @@ impl<'a> SQLPlanner<'a> {
             },
         );
 
-        self.update_plan(|plan| plan.aggregate(aggs.into_iter().collect(), groupby_exprs))?;
+        let aggs = aggs.into_iter().collect();
+        match grouping_sets {
+            Some(grouping_sets) => {
+                self.update_plan(|plan| {
+                    plan.grouping_sets_aggregate(aggs, grouping_sets_group_by, grouping_sets)
+                })?;
+            }
+            None => self.update_plan(|plan| plan.aggregate(aggs, groupby_exprs))?,
+        }
 
         if let Some(having) = having {
             self.update_plan(|plan| plan.filter(having))?;
@@ -525,6 +679,65 @@ impl<'a> SQLPlanner<'a> {
         Ok(())
     }
 
+    /// Plans the grouping sets of a `GROUP BY` with `GROUPING SETS`, `ROLLUP` or `CUBE`.
+    ///
+    /// Each item of the `GROUP BY` is a list of grouping sets, where a plain expression is a single
+    /// set, and the grouping sets of the clause are the cross product of the items' sets.
+    fn plan_grouping_sets(
+        &self,
+        expressions: &[ast::Expr],
+        modifiers: &[ast::GroupByWithModifier],
+    ) -> SQLPlannerResult<Vec<Vec<ExprRef>>> {
+        let plan_exprs = |exprs: &[ast::Expr]| -> SQLPlannerResult<Vec<ExprRef>> {
+            exprs.iter().map(|expr| self.plan_expr(expr)).collect()
+        };
+
+        let mut items = Vec::with_capacity(expressions.len());
+        for expr in expressions {
+            let sets = match expr {
+                ast::Expr::GroupingSets(sets) => {
+                    sets.iter().map(|set| plan_exprs(set)).try_collect()?
+                }
+                ast::Expr::Rollup(elements) => {
+                    let elements: Vec<_> = elements.iter().map(|e| plan_exprs(e)).try_collect()?;
+                    rollup(&elements)
+                }
+                ast::Expr::Cube(elements) => {
+                    let elements: Vec<_> = elements.iter().map(|e| plan_exprs(e)).try_collect()?;
+                    cube(&elements)
+                }
+                ast::Expr::Tuple(exprs) if exprs.is_empty() => vec![vec![]],
+                expr => vec![vec![self.plan_expr(expr)?]],
+            };
+            items.push(sets);
+        }
+
+        // `GROUP BY a, b WITH ROLLUP` rolls up all the expressions of the clause
+        for modifier in modifiers {
+            let elements: Vec<_> = items
+                .iter()
+                .map(|sets| match sets.as_slice() {
+                    [set] => Ok(set.clone()),
+                    _ => unsupported_sql_err!("{modifier} with grouping sets"),
+                })
+                .try_collect()?;
+            items = match modifier {
+                ast::GroupByWithModifier::Rollup => vec![rollup(&elements)],
+                ast::GroupByWithModifier::Cube => vec![cube(&elements)],
+                ast::GroupByWithModifier::Totals => unsupported_sql_err!("{modifier}"),
+            };
+        }
+
+        Ok(items
+            .into_iter()
+            .fold(vec![vec![]], |sets: Vec<Vec<ExprRef>>, item_sets| {
+                sets.iter()
+                    .cartesian_product(&item_sets)
+                    .map(|(set, item_set)| [set.as_slice(), item_set.as_slice()].concat())
+                    .collect()
+            }))
+    }
+
     fn plan_order_by_exprs(
         &self,
         expr: &[sqlparser::ast::OrderByExpr],
@@ -593,6 +806,82 @@ impl<'a> SQLPlanner<'a> {
         })
     }
 
+    /// Plans the window specification of an `OVER (PARTITION BY .. ORDER BY .. <frame>)` clause.
+    pub(crate) fn plan_window_spec(
+        &self,
+        window_type: &ast::WindowType,
+    ) -> SQLPlannerResult<WindowSpec> {
+        let ast::WindowType::WindowSpec(spec) = window_type else {
+            unsupported_sql_err!("Named windows `OVER {window_type}`");
+        };
+        if spec.window_name.is_some() {
+            unsupported_sql_err!("Named windows `OVER ({spec})`");
+        }
+
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|expr| self.plan_expr(expr))
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        let mut window_spec = WindowSpec::new(partition_by);
+
+        if !spec.order_by.is_empty() {
+            let OrderByExprs {
+                exprs,
+                descending,
+                nulls_first,
+            } = self.plan_order_by_exprs(&spec.order_by)?;
+            window_spec = window_spec.with_order_by(exprs, descending, nulls_first);
+        }
+
+        if let Some(frame) = &spec.window_frame {
+            let frame_type = match frame.units {
+                ast::WindowFrameUnits::Rows => WindowFrameType::Rows,
+                ast::WindowFrameUnits::Range => WindowFrameType::Range,
+                ast::WindowFrameUnits::Groups => unsupported_sql_err!("GROUPS window frames"),
+            };
+            let start = self.plan_window_frame_bound(&frame.start_bound)?;
+            // `ROWS <start>` is shorthand for `ROWS BETWEEN <start> AND CURRENT ROW`.
+            let end = frame
+                .end_bound
+                .as_ref()
+                .map(|bound| self.plan_window_frame_bound(bound))
+                .transpose()?
+                .unwrap_or(WindowBoundary::CurrentRow);
+            window_spec = window_spec.with_frame(WindowFrame::try_new(frame_type, start, end)?);
+        }
+
+        Ok(window_spec)
+    }
+
+    fn plan_window_frame_bound(
+        &self,
+        bound: &ast::WindowFrameBound,
+    ) -> SQLPlannerResult<WindowBoundary> {
+        let plan_offset = |expr: &sqlparser::ast::Expr| -> SQLPlannerResult<u64> {
+            self.plan_expr(expr)?
+                .as_literal()
+                .and_then(LiteralValue::as_i64)
+                .and_then(|n| u64::try_from(n).ok())
+                .ok_or_else(|| {
+                    PlannerError::invalid_operation(format!(
+                        "Expected a non-negative integer window frame offset, found: {expr}"
+                    ))
+                })
+        };
+        Ok(match bound {
+            ast::WindowFrameBound::CurrentRow => WindowBoundary::CurrentRow,
+            ast::WindowFrameBound::Preceding(None) => WindowBoundary::UnboundedPreceding,
+            ast::WindowFrameBound::Following(None) => WindowBoundary::UnboundedFollowing,
+            ast::WindowFrameBound::Preceding(Some(expr)) => {
+                WindowBoundary::Preceding(plan_offset(expr)?)
+            }
+            ast::WindowFrameBound::Following(Some(expr)) => {
+                WindowBoundary::Following(plan_offset(expr)?)
+            }
+        })
+    }
+
     /// Plans a single set of table and joins in a FROM clause.
     fn plan_single_from(&self, from: &TableWithJoins) -> SQLPlannerResult<LogicalPlanBuilder> {
         macro_rules! return_non_ident_errors {
@@ -611,11 +900,13 @@ impl<'a> SQLPlanner<'a> {
             sql_expr: &sqlparser::ast::Expr,
             left_planner: &SQLPlanner,
             right_planner: &SQLPlanner,
+            join_planner: &SQLPlanner,
             left_on: &mut Vec<ExprRef>,
             right_on: &mut Vec<ExprRef>,
             null_eq_nulls: &mut Vec<bool>,
             left_filters: &mut Vec<ExprRef>,
             right_filters: &mut Vec<ExprRef>,
+            residual_filters: &mut Vec<ExprRef>,
         ) -> SQLPlannerResult<()> {
             // check if join expression is actually a filter on one of the tables
             match (
@@ -656,30 +947,30 @@ impl<'a> SQLPlanner<'a> {
                 } => {
                     let null_equals_null = *op == BinaryOperator::Spaceship;
 
-                    let mut last_error = None;
-
                     for (left, right) in [(left, right), (right, left)] {
                         let left_expr = left_planner.plan_expr(left);
                         let right_expr = right_planner.plan_expr(right);
 
-                        if let Ok(left_expr) = &left_expr && let Ok(right_expr) = &right_expr {
+                        if let Ok(left_expr) = &left_expr
+                            && let Ok(right_expr) = &right_expr
+                        {
                             left_on.push(left_expr.clone());
                             right_on.push(right_expr.clone());
                             null_eq_nulls.push(null_equals_null);
 
-                            return Ok(())
+                            return Ok(());
                         }
 
                         for expr_result in [left_expr, right_expr] {
                             if let Err(e) = expr_result {
                                 return_non_ident_errors!(e);
-
-                                last_error = Some(e);
                             }
                         }
                     }
 
-                    Err(last_error.unwrap())
+                    // the sides of the equality reference both tables, so it is evaluated while joining
+                    residual_filters.push(join_planner.plan_expr(sql_expr)?);
+                    Ok(())
                 }
                 // multiple expressions
                 sqlparser::ast::Expr::BinaryOp {
@@ -687,8 +978,30 @@ impl<'a> SQLPlanner<'a> {
                     right,
                     op: BinaryOperator::And,
                 } => {
-                    process_join_on(left, left_planner, right_planner, left_on, right_on, null_eq_nulls, left_filters, right_filters)?;
-                    process_join_on(right, left_planner, right_planner, left_on, right_on, null_eq_nulls, left_filters, right_filters)?;
+                    process_join_on(
+                        left,
+                        left_planner,
+                        right_planner,
+                        join_planner,
+                        left_on,
+                        right_on,
+                        null_eq_nulls,
+                        left_filters,
+                        right_filters,
+                        residual_filters,
+                    )?;
+                    process_join_on(
+                        right,
+                        left_planner,
+                        right_planner,
+                        join_planner,
+                        left_on,
+                        right_on,
+                        null_eq_nulls,
+                        left_filters,
+                        right_filters,
+                        residual_filters,
+                    )?;
 
                     Ok(())
                 }
@@ -697,13 +1010,19 @@ impl<'a> SQLPlanner<'a> {
                     expr,
                     left_planner,
                     right_planner,
+                    join_planner,
                     left_on,
                     right_on,
                     null_eq_nulls,
                     left_filters,
                     right_filters,
+                    residual_filters,
                 ),
-                _ => unsupported_sql_err!("JOIN clauses support '=' constraints and filter predicates combined with 'AND'; found expression = {:?}", sql_expr)
+                // any other predicate which references both tables is evaluated while joining
+                _ => {
+                    residual_filters.push(join_planner.plan_expr(sql_expr)?);
+                    Ok(())
+                }
             }
         }
 
@@ -715,9 +1034,32 @@ impl<'a> SQLPlanner<'a> {
         for join in &from.joins {
             use sqlparser::ast::{
                 JoinConstraint,
-                JoinOperator::{FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi, RightOuter},
+                JoinOperator::{
+                    CrossApply, CrossJoin, FullOuter, Inner, LeftAnti, LeftOuter, LeftSemi,
+                    OuterApply, RightOuter,
+                },
             };
 
+            // UNNEST can reference the columns of the preceding tables, so it is planned on top of
+            // them rather than joined
+            if matches!(join.relation, TableFactor::UNNEST { .. }) {
+                let is_true =
+                    |expr: &ast::Expr| matches!(expr, ast::Expr::Value(Value::Boolean(true)));
+                let keep_empty = match &join.join_operator {
+                    CrossJoin | CrossApply => false,
+                    Inner(JoinConstraint::On(expr)) if is_true(expr) => false,
+                    OuterApply => true,
+                    LeftOuter(JoinConstraint::On(expr)) if is_true(expr) => true,
+                    _ => unsupported_sql_err!(
+                        "Unsupported join type with UNNEST: {:?}",
+                        join.join_operator
+                    ),
+                };
+                let left_plan = left_planner.current_plan.take().unwrap();
+                left_planner.set_plan(self.plan_unnest(left_plan, &join.relation, keep_empty)?);
+                continue;
+            }
+
             let right_plan = self.plan_relation(&join.relation)?;
 
             let mut join_options = JoinOptions::default();
@@ -725,6 +1067,11 @@ impl<'a> SQLPlanner<'a> {
                 join_options = join_options.prefix(format!("{id}."));
             }
 
+            if matches!(join.join_operator, CrossJoin) {
+                left_planner.update_plan(|plan| plan.cross_join(right_plan, join_options))?;
+                continue;
+            }
+
             // construct a planner with the right table to use for expr planning
             let mut right_planner = self.new_with_context();
             right_planner.set_plan(right_plan);
@@ -744,20 +1091,27 @@ impl<'a> SQLPlanner<'a> {
             let mut right_on = Vec::new();
             let mut left_filters = Vec::new();
             let mut right_filters = Vec::new();
+            let mut residual_filters = Vec::new();
 
             let (merge_matching_join_keys, null_eq_nulls) = match &constraint {
                 JoinConstraint::On(expr) => {
                     let mut null_eq_nulls = Vec::new();
 
+                    // planner which resolves identifiers against the left table, then the right table
+                    let mut join_planner = right_planner.new_child();
+                    join_planner.set_plan(left_planner.current_plan.clone().unwrap());
+
                     process_join_on(
                         expr,
                         &left_planner,
                         &right_planner,
+                        &join_planner,
                         &mut left_on,
                         &mut right_on,
                         &mut null_eq_nulls,
                         &mut left_filters,
                         &mut right_filters,
+                        &mut residual_filters,
                     )?;
 
                     (false, Some(null_eq_nulls))
@@ -775,6 +1129,21 @@ impl<'a> SQLPlanner<'a> {
                 JoinConstraint::None => unsupported_sql_err!("JOIN without ON/USING not supported"),
             };
 
+            // filtering the side whose unmatched rows are kept by the join would drop those rows,
+            // so predicates on it are evaluated while joining instead
+            let (left_preserved, right_preserved) = match join_type {
+                JoinType::Inner | JoinType::Semi => (false, false),
+                JoinType::Left | JoinType::Anti | JoinType::AsOf => (true, false),
+                JoinType::Right => (false, true),
+                JoinType::Outer => (true, true),
+            };
+            if left_preserved {
+                residual_filters.append(&mut left_filters);
+            }
+            if right_preserved {
+                residual_filters.append(&mut right_filters);
+            }
+
             if let Some(left_predicate) = combine_conjunction(left_filters) {
                 left_planner.update_plan(|plan| plan.filter(left_predicate))?;
             }
@@ -784,11 +1153,12 @@ impl<'a> SQLPlanner<'a> {
             }
 
             left_planner.update_plan(|plan| {
-                plan.join_with_null_safe_equal(
+                plan.join_with_residual_predicate(
                     right_planner.current_plan.unwrap(),
                     left_on,
                     right_on,
                     null_eq_nulls,
+                    combine_conjunction(residual_filters),
                     join_type,
                     None,
                     join_options.merge_matching_join_keys(merge_matching_join_keys),
@@ -802,26 +1172,34 @@ impl<'a> SQLPlanner<'a> {
     /// Plans the FROM clause of a query and populates `self.current_relation`.
     /// Should only be called once per query.
     fn plan_from(&mut self, from: &[TableWithJoins]) -> SQLPlannerResult<()> {
-        let plan = if let Some(plan) = from
-            .iter()
-            .map(|f| self.plan_single_from(f))
-            .reduce(|left, right| {
-                let left = left?;
-                let right = right?;
-
-                let mut join_options = JoinOptions::default();
-                if let [id] = right.plan.clone().get_aliases().as_slice() {
-                    join_options = join_options.prefix(format!("{id}."));
+        let mut plan: Option<LogicalPlanBuilder> = None;
+        for from in from {
+            plan = Some(match plan {
+                // `FROM t, UNNEST(t.x)` is a lateral cross join like `FROM t CROSS JOIN UNNEST(t.x)`
+                Some(left)
+                    if matches!(from.relation, TableFactor::UNNEST { .. })
+                        && from.joins.is_empty() =>
+                {
+                    self.plan_unnest(left, &from.relation, false)?
                 }
+                Some(left) => {
+                    let right = self.plan_single_from(from)?;
 
-                Ok(left.cross_join(right, join_options)?)
-            })
-            .transpose()?
-        {
-            plan
-        } else {
-            // singleton plan for SELECT without FROM
-            singleton_plan()?
+                    let mut join_options = JoinOptions::default();
+                    if let [id] = right.plan.clone().get_aliases().as_slice() {
+                        join_options = join_options.prefix(format!("{id}."));
+                    }
+
+                    left.cross_join(right, join_options)?
+                }
+                None => self.plan_single_from(from)?,
+            });
+        }
+
+        // singleton plan for SELECT without FROM
+        let plan = match plan {
+            Some(plan) => plan,
+            None => singleton_plan()?,
         };
 
         self.set_plan(plan);
@@ -857,32 +1235,62 @@ impl<'a> SQLPlanner<'a> {
 
                 (plan, alias)
             }
+            // LATERAL subqueries are planned like any other subquery, so only uncorrelated ones
+            // are supported
             sqlparser::ast::TableFactor::Derived {
-                lateral,
+                lateral: _,
                 subquery,
                 alias,
             } => {
-                if *lateral {
-                    unsupported_sql_err!("LATERAL");
-                }
                 let subquery = self.new_with_context().plan_query(subquery)?;
                 (subquery, alias)
             }
-            sqlparser::ast::TableFactor::TableFunction { .. } => {
-                unsupported_sql_err!("Unsupported table factor: TableFunction")
+            // TABLE(<function>(<args>))
+            sqlparser::ast::TableFactor::TableFunction { expr, alias } => {
+                let ast::Expr::Function(ast::Function {
+                    name,
+                    args: ast::FunctionArguments::List(list),
+                    ..
+                }) = expr
+                else {
+                    unsupported_sql_err!("Unsupported table function expression: {expr}")
+                };
+                let tbl_fn = name.0.first().unwrap().value.as_str();
+                let args = TableFunctionArgs {
+                    args: list.args.clone(),
+                    settings: None,
+                };
+                (self.plan_table_function(tbl_fn, &args)?, alias)
             }
-            sqlparser::ast::TableFactor::Function { .. } => {
-                unsupported_sql_err!("Unsupported table factor: Function")
+            // LATERAL table functions are planned like any other table function, so their
+            // arguments can not reference the preceding tables
+            sqlparser::ast::TableFactor::Function {
+                lateral: _,
+                name,
+                args,
+                alias,
+            } => {
+                let tbl_fn = name.0.first().unwrap().value.as_str();
+                let args = TableFunctionArgs {
+                    args: args.clone(),
+                    settings: None,
+                };
+                (self.plan_table_function(tbl_fn, &args)?, alias)
             }
-            sqlparser::ast::TableFactor::UNNEST { .. } => {
-                unsupported_sql_err!("Unsupported table factor: UNNEST")
+            sqlparser::ast::TableFactor::UNNEST { alias, .. } => {
+                // the arrays are unnested on top of a single row, which is dropped afterwards
+                let plan = self
+                    .plan_unnest(singleton_plan()?, rel, false)?
+                    .exclude(vec![String::new()])?;
+                (plan, alias)
             }
             sqlparser::ast::TableFactor::JsonTable { .. } => {
                 unsupported_sql_err!("Unsupported table factor: JsonTable")
             }
-            sqlparser::ast::TableFactor::NestedJoin { .. } => {
-                unsupported_sql_err!("Unsupported table factor: NestedJoin")
-            }
+            sqlparser::ast::TableFactor::NestedJoin {
+                table_with_joins,
+                alias,
+            } => (self.plan_single_from(table_with_joins)?, alias),
             sqlparser::ast::TableFactor::Pivot { .. } => {
                 unsupported_sql_err!("Unsupported table factor: Pivot")
             }
@@ -903,9 +1311,11 @@ impl<'a> SQLPlanner<'a> {
     /// Plan a `FROM <path>` table factor by rewriting to relevant table-value function.
     fn plan_relation_path(&self, path: &str) -> SQLPlannerResult<LogicalPlanBuilder> {
         let func = match Path::new(path).extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("avro") => "read_avro",
             Some(ext) if ext.eq_ignore_ascii_case("csv") => "read_csv",
             Some(ext) if ext.eq_ignore_ascii_case("json") => "read_json",
             Some(ext) if ext.eq_ignore_ascii_case("jsonl") => "read_json",
+            Some(ext) if ext.eq_ignore_ascii_case("orc") => "read_orc",
             Some(ext) if ext.eq_ignore_ascii_case("parquet") => "read_parquet",
             Some(_) => invalid_operation_err!("unsupported file path extension: {}", path),
             None => invalid_operation_err!("unsupported file path, no extension: {}", path),
@@ -919,6 +1329,119 @@ impl<'a> SQLPlanner<'a> {
         self.plan_table_function(func, &args)
     }
 
+    /// Plans a `VALUES` list as an in-memory table with the columns `column1`, `column2`, ..
+    fn plan_values(&self, values: &Values) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let Some(num_columns) = values.rows.first().map(Vec::len) else {
+            invalid_operation_err!("VALUES must have at least one row");
+        };
+        if values.rows.iter().any(|row| row.len() != num_columns) {
+            invalid_operation_err!("All rows of VALUES must have the same number of values");
+        }
+
+        let rows = values
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|expr| match self.plan_expr(expr)?.as_literal() {
+                        Some(value) => Ok(value.to_series()),
+                        None => unsupported_sql_err!("VALUES with non-literal value: {expr}"),
+                    })
+                    .collect::<SQLPlannerResult<Vec<_>>>()
+            })
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+
+        // each column has the supertype of its values
+        let columns = (0..num_columns)
+            .map(|i| {
+                let dtype = rows.iter().try_fold(DataType::Null, |dtype, row| {
+                    try_get_supertype(&dtype, row[i].data_type())
+                })?;
+                let values = rows
+                    .iter()
+                    .map(|row| row[i].cast(&dtype))
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let column = Series::concat(&values.iter().collect::<Vec<_>>())?;
+                Ok(column.rename(format!("column{}", i + 1)))
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        Ok(in_memory_plan(columns)?)
+    }
+
+    /// Plans an `UNNEST(..)` table factor on top of `plan`, repeating each of its rows for every
+    /// element of the arrays, which may reference the columns of `plan`.
+    ///
+    /// Rows whose arrays are empty or null are kept with null elements if `keep_empty` is set, as
+    /// in a `LEFT JOIN`, and dropped otherwise. Since the elements are added to the columns of
+    /// `plan`, they are referenced by their column aliases rather than the table alias.
+    fn plan_unnest(
+        &self,
+        plan: LogicalPlanBuilder,
+        unnest: &TableFactor,
+        keep_empty: bool,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let TableFactor::UNNEST {
+            alias,
+            array_exprs,
+            with_offset,
+            with_ordinality,
+            ..
+        } = unnest
+        else {
+            unreachable!("plan_unnest must be called with an UNNEST table factor")
+        };
+        if *with_offset {
+            unsupported_sql_err!("UNNEST WITH OFFSET");
+        }
+        if *with_ordinality {
+            unsupported_sql_err!("UNNEST WITH ORDINALITY");
+        }
+
+        let names = match alias {
+            Some(alias) if !alias.columns.is_empty() => {
+                if alias.columns.len() != array_exprs.len() {
+                    invalid_operation_err!(
+                        "Column count mismatch: expected {} columns, found {}",
+                        alias.columns.len(),
+                        array_exprs.len()
+                    );
+                }
+                alias.columns.iter().map(|c| c.value.clone()).collect()
+            }
+            _ if array_exprs.len() == 1 => vec!["unnest".to_string()],
+            _ => invalid_operation_err!("UNNEST of multiple arrays requires column aliases"),
+        };
+
+        let mut planner = self.new_with_context();
+        planner.set_plan(plan.clone());
+        let mut projection = plan
+            .schema()
+            .names()
+            .into_iter()
+            .map(unresolved_col)
+            .collect::<Vec<_>>();
+        for (expr, name) in array_exprs.iter().zip(&names) {
+            projection.push(planner.plan_expr(expr)?.alias(name.as_str()));
+        }
+        let mut plan = plan.select(projection)?;
+
+        if !keep_empty {
+            let non_empty = names
+                .iter()
+                .map(|name| list_count(unresolved_col(name.as_str()), CountMode::All).gt(lit(0)))
+                .reduce(|left, right| left.or(right))
+                .unwrap();
+            plan = plan.filter(non_empty)?;
+        }
+
+        let to_explode = names
+            .iter()
+            .map(|name| unresolved_col(name.as_str()))
+            .collect();
+        Ok(plan.explode(to_explode)?)
+    }
+
     /// Plan a `FROM <table>` table factor.
     ///
     /// All plans returned by plan_relation_table should have a SubqueryAlias with the table's name.
@@ -1200,11 +1723,12 @@ impl<'a> SQLPlanner<'a> {
             SQLExpr::IsNotUnknown(_) => {
                 unsupported_sql_err!("IS NOT UNKNOWN")
             }
-            SQLExpr::IsDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS DISTINCT FROM")
-            }
-            SQLExpr::IsNotDistinctFrom(_, _) => {
-                unsupported_sql_err!("IS NOT DISTINCT FROM")
+            SQLExpr::IsDistinctFrom(left, right) => Ok(self
+                .plan_expr(left)?
+                .eq_null_safe(self.plan_expr(right)?)
+                .not()),
+            SQLExpr::IsNotDistinctFrom(left, right) => {
+                Ok(self.plan_expr(left)?.eq_null_safe(self.plan_expr(right)?))
             }
             SQLExpr::InList {
                 expr,
@@ -1293,13 +1817,75 @@ impl<'a> SQLPlanner<'a> {
                     Ok(expr)
                 }
             }
-            SQLExpr::SimilarTo { .. } => unsupported_sql_err!("SIMILAR TO"),
-            SQLExpr::RLike { .. } => unsupported_sql_err!("RLIKE"),
-            SQLExpr::AnyOp { .. } => unsupported_sql_err!("ANY"),
-            SQLExpr::AllOp { .. } => unsupported_sql_err!("ALL"),
-            SQLExpr::Convert { .. } => unsupported_sql_err!("CONVERT"),
+            SQLExpr::SimilarTo {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let pattern = match self.plan_expr(pattern)?.as_literal() {
+                    Some(LiteralValue::Utf8(pattern)) => {
+                        similar_to_regex(pattern, escape_char.as_deref())?
+                    }
+                    _ => unsupported_sql_err!("SIMILAR TO with a non-string-literal pattern"),
+                };
+                let expr = match_(expr, lit(pattern));
+                if *negated {
+                    Ok(expr.not())
+                } else {
+                    Ok(expr)
+                }
+            }
+            SQLExpr::RLike {
+                negated,
+                expr,
+                pattern,
+                regexp: _,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let pattern = self.plan_expr(pattern)?;
+                let expr = match_(expr, pattern);
+                if *negated {
+                    Ok(expr.not())
+                } else {
+                    Ok(expr)
+                }
+            }
+            SQLExpr::AnyOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, false),
+            SQLExpr::AllOp {
+                left,
+                compare_op,
+                right,
+            } => self.plan_quantified_comparison(left, compare_op, right, true),
+            SQLExpr::Convert {
+                expr,
+                data_type: Some(data_type),
+                charset: None,
+                target_before_value: _,
+                styles,
+            } if styles.is_empty() => {
+                let dtype = sql_dtype_to_dtype(data_type)?;
+                let expr = self.plan_expr(expr)?;
+                Ok(expr.cast(&dtype))
+            }
+            SQLExpr::Convert { .. } => unsupported_sql_err!("CONVERT with a charset or styles"),
             SQLExpr::Cast { .. } => unsupported_sql_err!("CAST"),
-            SQLExpr::AtTimeZone { .. } => unsupported_sql_err!("AT TIME ZONE"),
+            SQLExpr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => {
+                let timestamp = self.plan_expr(timestamp)?;
+                let time_zone = match self.plan_expr(time_zone)?.as_literal() {
+                    Some(LiteralValue::Utf8(time_zone)) => time_zone.clone(),
+                    _ => unsupported_sql_err!("AT TIME ZONE with a non-string-literal timezone"),
+                };
+                Ok(dt_convert_time_zone(timestamp, time_zone))
+            }
             SQLExpr::Extract {
                 field,
                 syntax: _,
@@ -1308,21 +1894,55 @@ impl<'a> SQLPlanner<'a> {
                 use daft_functions::temporal::{self as dt};
                 let expr = self.plan_expr(expr)?;
 
+                let floor_div = |expr: ExprRef, divisor: i32| {
+                    Expr::BinaryOp {
+                        left: expr,
+                        op: Operator::FloorDivide,
+                        right: lit(divisor),
+                    }
+                    .arced()
+                };
+
                 match field {
+                    DateTimeField::Millennium | DateTimeField::Millenium => {
+                        Ok(floor_div(dt::dt_year(expr).add(lit(999)), 1000))
+                    }
+                    DateTimeField::Century => Ok(floor_div(dt::dt_year(expr).add(lit(99)), 100)),
+                    DateTimeField::Decade => Ok(floor_div(dt::dt_year(expr), 10)),
                     DateTimeField::Year => Ok(dt::dt_year(expr)),
+                    DateTimeField::Quarter => Ok(dt::dt_quarter(expr)),
                     DateTimeField::Month => Ok(dt::dt_month(expr)),
+                    DateTimeField::Week(None) | DateTimeField::IsoWeek => {
+                        Ok(dt::dt_week_of_year(expr))
+                    }
                     DateTimeField::Day => Ok(dt::dt_day(expr)),
                     DateTimeField::DayOfWeek => Ok(dt::dt_day_of_week(expr)),
+                    // DOW starts at 0 for Sunday and ISODOW at 1 for Monday
+                    DateTimeField::Dow => Ok(Expr::BinaryOp {
+                        left: dt::dt_day_of_week(expr).add(lit(1)),
+                        op: Operator::Modulus,
+                        right: lit(7),
+                    }
+                    .arced()),
+                    DateTimeField::Isodow => Ok(dt::dt_day_of_week(expr).add(lit(1))),
+                    DateTimeField::DayOfYear | DateTimeField::Doy => Ok(dt::dt_day_of_year(expr)),
                     DateTimeField::Date => Ok(dt::dt_date(expr)),
+                    DateTimeField::Time => Ok(dt::dt_time(expr)),
                     DateTimeField::Hour => Ok(dt::dt_hour(expr)),
                     DateTimeField::Minute => Ok(dt::dt_minute(expr)),
                     DateTimeField::Second => Ok(dt::dt_second(expr)),
+                    DateTimeField::Epoch => Ok(dt::dt_epoch(expr)),
                     other => unsupported_sql_err!("EXTRACT ({other})"),
                 }
             }
             SQLExpr::Ceil { expr, .. } => Ok(ceil(self.plan_expr(expr)?)),
             SQLExpr::Floor { expr, .. } => Ok(floor(self.plan_expr(expr)?)),
-            SQLExpr::Position { .. } => unsupported_sql_err!("POSITION"),
+            SQLExpr::Position { expr, r#in } => {
+                let substr = self.plan_expr(expr)?;
+                let expr = self.plan_expr(r#in)?;
+                // SQL positions are one indexed, and zero when the substring is not found
+                Ok(find(expr, substr).add(lit(1)))
+            }
             SQLExpr::Substring {
                 expr,
                 substring_from,
@@ -1341,28 +1961,51 @@ impl<'a> SQLPlanner<'a> {
                 // SQL substring is one indexed
                 let start = start.sub(lit(1));
 
-                Ok(daft_functions::utf8::substr(expr, start, length))
+                Ok(substr(expr, start, length))
             }
             SQLExpr::Substring { special: false, .. } => {
                 unsupported_sql_err!("`SUBSTRING(expr [FROM start] [FOR len])` syntax")
             }
-            SQLExpr::Trim { .. } => unsupported_sql_err!("TRIM"),
-            SQLExpr::Overlay { .. } => unsupported_sql_err!("OVERLAY"),
+            SQLExpr::Trim {
+                expr,
+                trim_where,
+                trim_what,
+                trim_characters,
+            } => {
+                let characters = match (trim_what, trim_characters.as_deref()) {
+                    (Some(what), None) => Some(what.as_ref()),
+                    (None, Some([characters])) => Some(characters),
+                    (None, None) => None,
+                    _ => unsupported_sql_err!("TRIM with multiple character arguments"),
+                };
+                self.plan_trim(expr, trim_where.as_ref(), characters)
+            }
+            SQLExpr::Overlay {
+                expr,
+                overlay_what,
+                overlay_from,
+                overlay_for,
+            } => {
+                let expr = self.plan_expr(expr)?;
+                let what = self.plan_expr(overlay_what)?;
+                // SQL positions are one indexed
+                let start = self.plan_expr(overlay_from)?.sub(lit(1));
+                let count = match overlay_for {
+                    Some(count) => self.plan_expr(count)?,
+                    None => length(what.clone()).cast(&DataType::Int64),
+                };
+
+                // substr is null rather than empty for an empty range
+                let prefix = substr(expr.clone(), lit(0), start.clone()).fill_null(lit(""));
+                let suffix = substr(expr.clone(), start.add(count), null_lit()).fill_null(lit(""));
+                Ok(expr
+                    .is_null()
+                    .if_else(null_lit(), prefix.add(what).add(suffix)))
+            }
             SQLExpr::Collate { .. } => unsupported_sql_err!("COLLATE"),
             SQLExpr::Nested(e) => self.plan_expr(e),
             SQLExpr::IntroducedString { .. } => unsupported_sql_err!("INTRODUCED STRING"),
-            SQLExpr::TypedString { data_type, value } => match data_type {
-                sqlparser::ast::DataType::Date => Ok(to_date(lit(value.as_str()), "%Y-%m-%d")),
-                sqlparser::ast::DataType::Timestamp(None, TimezoneInfo::None)
-                | sqlparser::ast::DataType::Datetime(None) => Ok(to_datetime(
-                    lit(value.as_str()),
-                    "%Y-%m-%d %H:%M:%S %z",
-                    None,
-                )),
-                dtype => {
-                    unsupported_sql_err!("TypedString with data type {:?}", dtype)
-                }
-            },
+            SQLExpr::TypedString { data_type, value } => typed_string_to_lit(data_type, value),
             SQLExpr::Function(func) => self.plan_function(func),
             SQLExpr::Case {
                 operand,
@@ -1370,24 +2013,30 @@ impl<'a> SQLPlanner<'a> {
                 results,
                 else_result,
             } => {
-                if operand.is_some() {
-                    unsupported_sql_err!("CASE with operand not yet supported");
-                }
                 if results.len() != conditions.len() {
                     unsupported_sql_err!("CASE with different number of conditions and results");
                 }
 
+                // a CASE without an ELSE evaluates to null when no condition matches
                 let else_expr = match else_result {
                     Some(expr) => self.plan_expr(expr)?,
-                    None => unsupported_sql_err!("CASE with no else result"),
+                    None => null_lit(),
                 };
+                // `CASE <operand> WHEN <value> ..` compares the operand with each value
+                let operand = operand
+                    .as_ref()
+                    .map(|operand| self.plan_expr(operand))
+                    .transpose()?;
 
                 // we need to traverse from back to front to build the if else chain
                 // because we need to start with the else expression
                 conditions.iter().zip(results.iter()).rev().try_fold(
                     else_expr,
                     |else_expr, (condition, result)| {
-                        let cond = self.plan_expr(condition)?;
+                        let cond = match &operand {
+                            Some(operand) => operand.clone().eq(self.plan_expr(condition)?),
+                            None => self.plan_expr(condition)?,
+                        };
                         let res = self.plan_expr(result)?;
                         Ok(cond.if_else(res, else_expr))
                     },
@@ -1565,18 +2214,24 @@ impl<'a> SQLPlanner<'a> {
                     sqlparser::ast::Interval {
                         value,
                         leading_field: Some(time_unit),
+                        last_field,
                         ..
                     } => {
                         let expr = self.plan_expr(value)?;
 
-                        let expr =
-                            expr.as_literal()
-                                .and_then(|lit| lit.as_str())
-                                .ok_or_else(|| {
-                                    PlannerError::invalid_operation(
-                                        "Interval value must be a string",
-                                    )
-                                })?;
+                        // the value may also be a number, e.g. INTERVAL 3 MONTH
+                        let expr = match expr.as_literal() {
+                            Some(LiteralValue::Utf8(value)) => value.clone(),
+                            Some(LiteralValue::Int64(value)) => value.to_string(),
+                            _ => invalid_operation_err!("Interval value must be a string"),
+                        };
+
+                        // e.g., INTERVAL '1-6' YEAR TO MONTH
+                        if let Some(last_field) = last_field {
+                            return parse_interval_fields(&expr, time_unit, last_field).map(
+                                |interval| Expr::Literal(LiteralValue::Interval(interval)).arced(),
+                            );
+                        }
 
                         let count = expr.parse::<i64>().map_err(|e| {
                             PlannerError::unsupported_sql(format!("Invalid interval count: {e}"))
@@ -1599,11 +2254,7 @@ impl<'a> SQLPlanner<'a> {
                         };
 
                         Ok(Arc::new(Expr::Literal(LiteralValue::Interval(
-                            daft_core::datatypes::IntervalValue::new(
-                                months as i32,
-                                days as i32,
-                                nanoseconds,
-                            ),
+                            IntervalValue::new(months as i32, days as i32, nanoseconds),
                         ))))
                     }
 
@@ -1631,11 +2282,7 @@ impl<'a> SQLPlanner<'a> {
                         let (months, days, nanoseconds) = interval_parts_to_values(parts);
 
                         Ok(Arc::new(Expr::Literal(LiteralValue::Interval(
-                            daft_core::datatypes::IntervalValue::new(
-                                months as i32,
-                                days as i32,
-                                nanoseconds,
-                            ),
+                            IntervalValue::new(months as i32, days as i32, nanoseconds),
                         ))))
                     }
                 }
@@ -1673,6 +2320,140 @@ impl<'a> SQLPlanner<'a> {
         }
     }
 
+    /// Plans `TRIM([BOTH | LEADING | TRAILING] [<characters> FROM] <expr>)`, which trims
+    /// whitespace unless the characters to trim are given.
+    fn plan_trim(
+        &self,
+        expr: &sqlparser::ast::Expr,
+        trim_where: Option<&TrimWhereField>,
+        characters: Option<&sqlparser::ast::Expr>,
+    ) -> SQLPlannerResult<ExprRef> {
+        let expr = self.plan_expr(expr)?;
+        let trim_where = trim_where.unwrap_or(&TrimWhereField::Both);
+        let Some(characters) = characters else {
+            return Ok(match trim_where {
+                TrimWhereField::Both => rstrip(lstrip(expr)),
+                TrimWhereField::Leading => lstrip(expr),
+                TrimWhereField::Trailing => rstrip(expr),
+            });
+        };
+
+        let characters = match self.plan_expr(characters)?.as_literal() {
+            Some(LiteralValue::Utf8(characters)) if !characters.is_empty() => characters
+                .chars()
+                .map(|c| regex::escape(&c.to_string()))
+                .collect::<String>(),
+            _ => unsupported_sql_err!("TRIM with characters other than a non-empty string literal"),
+        };
+        let pattern = match trim_where {
+            TrimWhereField::Both => format!("^[{characters}]+|[{characters}]+$"),
+            TrimWhereField::Leading => format!("^[{characters}]+"),
+            TrimWhereField::Trailing => format!("[{characters}]+$"),
+        };
+        Ok(replace(expr, lit(pattern), lit(""), true))
+    }
+
+    /// Plans `<left> <op> ANY(<right>)` and `<left> <op> ALL(<right>)`, where the right side is
+    /// either a list of values or a subquery.
+    fn plan_quantified_comparison(
+        &self,
+        left: &sqlparser::ast::Expr,
+        compare_op: &BinaryOperator,
+        right: &sqlparser::ast::Expr,
+        is_all: bool,
+    ) -> SQLPlannerResult<ExprRef> {
+        use sqlparser::ast::Expr as SQLExpr;
+
+        let quantifier = if is_all { "ALL" } else { "ANY" };
+        let left = self.plan_expr(left)?;
+        let op = self.sql_operator_to_operator(compare_op)?;
+
+        let items = match right {
+            SQLExpr::Array(array) => &array.elem,
+            SQLExpr::Tuple(items) => items,
+            SQLExpr::Nested(item) => std::slice::from_ref(item.as_ref()),
+            SQLExpr::Subquery(subquery) => {
+                let mut child_planner = self.new_child();
+                let plan = child_planner.plan_query(subquery)?;
+                return self.plan_quantified_subquery(left, op, plan, is_all);
+            }
+            other => {
+                unsupported_sql_err!("{quantifier} over `{other}`, expected a list or a subquery")
+            }
+        };
+        if items.is_empty() {
+            invalid_operation_err!("{quantifier} requires at least one item")
+        }
+
+        let comparisons = items
+            .iter()
+            .map(|item| {
+                Ok(Expr::BinaryOp {
+                    left: left.clone(),
+                    op,
+                    right: self.plan_expr(item)?,
+                }
+                .arced())
+            })
+            .collect::<SQLPlannerResult<Vec<_>>>()?;
+        Ok(comparisons
+            .into_iter()
+            .reduce(|acc, e| if is_all { acc.and(e) } else { acc.or(e) })
+            .unwrap())
+    }
+
+    /// Plans a quantified comparison against the single column of a subquery.
+    ///
+    /// `= ANY` and `<> ALL` are set membership, and the ordered comparisons compare against the
+    /// minimum or maximum of the subquery, which is checked for being non-empty.
+    fn plan_quantified_subquery(
+        &self,
+        left: ExprRef,
+        op: Operator,
+        plan: LogicalPlanBuilder,
+        is_all: bool,
+    ) -> SQLPlannerResult<ExprRef> {
+        let columns = plan.schema().names();
+        let [column] = columns.as_slice() else {
+            invalid_operation_err!(
+                "Subquery of ANY or ALL must return a single column, found {}",
+                columns.len()
+            )
+        };
+        let column = resolved_col(column.as_str());
+        let subquery = Subquery { plan: plan.build() };
+
+        let bound = match (op, is_all) {
+            (Operator::Eq, false) => return Ok(left.in_subquery(subquery)),
+            (Operator::NotEq, true) => return Ok(left.in_subquery(subquery).not()),
+            (Operator::Gt | Operator::GtEq, false) | (Operator::Lt | Operator::LtEq, true) => {
+                column.min()
+            }
+            (Operator::Gt | Operator::GtEq, true) | (Operator::Lt | Operator::LtEq, false) => {
+                column.max()
+            }
+            (op, true) => unsupported_sql_err!("{op} ALL with a subquery"),
+            (op, false) => unsupported_sql_err!("{op} ANY with a subquery"),
+        };
+        let bound = Subquery {
+            plan: plan.aggregate(vec![bound], vec![])?.build(),
+        };
+        let comparison = Expr::BinaryOp {
+            left,
+            op,
+            right: Expr::Subquery(bound).arced(),
+        }
+        .arced();
+
+        // ALL holds over an empty subquery while ANY does not
+        let exists = Expr::Exists(subquery).arced();
+        if is_all {
+            Ok(exists.not().or(comparison))
+        } else {
+            Ok(exists.and(comparison))
+        }
+    }
+
     /// Visit a SQL unary operator.
     ///
     /// e.g. +column or -column
@@ -1688,6 +2469,9 @@ impl<'a> SQLPlanner<'a> {
             (UnaryOperator::Plus, Expr::Literal(LiteralValue::Float64(n))) => lit(*n),
             (UnaryOperator::Minus, Expr::Literal(LiteralValue::Int64(n))) => lit(-n),
             (UnaryOperator::Minus, Expr::Literal(LiteralValue::Float64(n))) => lit(-n),
+            (UnaryOperator::Minus, Expr::Literal(LiteralValue::Interval(interval))) => {
+                Expr::Literal(LiteralValue::Interval(-interval.clone())).arced()
+            }
             // general case
             (UnaryOperator::Plus, _) => lit(0).add(expr),
             (UnaryOperator::Minus, _) => lit(0).sub(expr),
@@ -1942,6 +2726,204 @@ fn is_table_path(name: &ObjectName) -> bool {
     matches!(name.0[0].quote_style, Some('\''))
 }
 
+/// Parses a typed string literal such as `DATE '2024-01-01'` or `TIMESTAMP '2024-01-01 12:00:00'`,
+/// casting the string to the data type if it is not a date, time or timestamp.
+fn typed_string_to_lit(data_type: &ast::DataType, value: &str) -> SQLPlannerResult<ExprRef> {
+    use sqlparser::ast::DataType as SQLDataType;
+
+    let invalid =
+        |kind: &str| PlannerError::invalid_operation(format!("Invalid {kind} literal: '{value}'"));
+    let parse_naive_datetime = |value: &str| {
+        [
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M",
+        ]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+    };
+    let parse_datetime = |value: &str| {
+        [
+            "%Y-%m-%d %H:%M:%S%.f%#z",
+            "%Y-%m-%d %H:%M:%S%.f %#z",
+            "%Y-%m-%dT%H:%M:%S%.f%#z",
+        ]
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(value, format).ok())
+    };
+
+    let literal = match data_type {
+        SQLDataType::Date => {
+            let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| invalid("DATE"))?;
+            LiteralValue::Date((date - NaiveDate::default()).num_days() as i32)
+        }
+        SQLDataType::Time(_, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone) => {
+            let time = ["%H:%M:%S%.f", "%H:%M"]
+                .iter()
+                .find_map(|format| NaiveTime::parse_from_str(value, format).ok())
+                .ok_or_else(|| invalid("TIME"))?;
+            let micros = (time - NaiveTime::MIN).num_microseconds().unwrap();
+            LiteralValue::Time(micros, TimeUnit::Microseconds)
+        }
+        SQLDataType::Timestamp(_, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone)
+        | SQLDataType::Datetime(_) => {
+            // an offset is accepted for compatibility, and the timestamp is converted to UTC
+            let datetime = parse_naive_datetime(value)
+                .or_else(|| parse_datetime(value).map(|datetime| datetime.naive_utc()))
+                .ok_or_else(|| invalid("TIMESTAMP"))?;
+            LiteralValue::Timestamp(
+                datetime.and_utc().timestamp_micros(),
+                TimeUnit::Microseconds,
+                None,
+            )
+        }
+        SQLDataType::Timestamp(_, TimezoneInfo::WithTimeZone | TimezoneInfo::Tz) => {
+            // a timestamp without an offset is in UTC
+            let (micros, offset) = match parse_datetime(value) {
+                Some(datetime) => (datetime.timestamp_micros(), datetime.offset().to_string()),
+                None => {
+                    let datetime =
+                        parse_naive_datetime(value).ok_or_else(|| invalid("TIMESTAMP"))?;
+                    (datetime.and_utc().timestamp_micros(), "UTC".to_string())
+                }
+            };
+            LiteralValue::Timestamp(micros, TimeUnit::Microseconds, Some(offset))
+        }
+        data_type => {
+            let dtype = sql_dtype_to_dtype(data_type)?;
+            return Ok(lit(value).cast(&dtype));
+        }
+    };
+    Ok(Expr::Literal(literal).arced())
+}
+
+/// Parses the value of an interval over a range of fields in the SQL standard format, such as
+/// `INTERVAL '1-6' YEAR TO MONTH` or `INTERVAL '3 12:30:15.5' DAY TO SECOND`.
+fn parse_interval_fields(
+    value: &str,
+    leading_field: &DateTimeField,
+    last_field: &DateTimeField,
+) -> SQLPlannerResult<IntervalValue> {
+    const FIELDS: [DateTimeField; 6] = [
+        DateTimeField::Year,
+        DateTimeField::Month,
+        DateTimeField::Day,
+        DateTimeField::Hour,
+        DateTimeField::Minute,
+        DateTimeField::Second,
+    ];
+    let position = |field| FIELDS.iter().position(|f| f == field);
+    let (Some(start), Some(end)) = (position(leading_field), position(last_field)) else {
+        unsupported_sql_err!("INTERVAL {leading_field} TO {last_field}")
+    };
+    // year-month intervals cannot be mixed with day-time intervals
+    if start >= end || (start <= 1 && end >= 2) {
+        invalid_operation_err!("Invalid interval fields: {leading_field} TO {last_field}")
+    }
+
+    let (negative, unsigned) = match value.trim().strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.trim()),
+    };
+    let parts = unsigned.split(['-', ' ', ':']).collect::<Vec<_>>();
+    if parts.len() != end - start + 1 {
+        invalid_operation_err!(
+            "Invalid interval value '{value}' for {leading_field} TO {last_field}"
+        )
+    }
+
+    let invalid = || {
+        PlannerError::invalid_operation(format!(
+            "Invalid interval value '{value}' for {leading_field} TO {last_field}"
+        ))
+    };
+    let (mut months, mut days, mut nanoseconds) = (0i32, 0i32, 0i64);
+    for (field, part) in FIELDS[start..=end].iter().zip(parts) {
+        if *field == DateTimeField::Second {
+            let seconds = part.parse::<f64>().map_err(|_| invalid())?;
+            nanoseconds += (seconds * 1_000_000_000.0).round() as i64;
+            continue;
+        }
+        let count = part.parse::<i32>().map_err(|_| invalid())?;
+        match field {
+            DateTimeField::Year => months += 12 * count,
+            DateTimeField::Month => months += count,
+            DateTimeField::Day => days += count,
+            DateTimeField::Hour => nanoseconds += i64::from(count) * 3_600_000_000_000,
+            DateTimeField::Minute => nanoseconds += i64::from(count) * 60_000_000_000,
+            _ => unreachable!("interval fields are years through seconds"),
+        }
+    }
+
+    let interval = IntervalValue::new(months, days, nanoseconds);
+    Ok(if negative { -interval } else { interval })
+}
+
+/// Translates a `SIMILAR TO` pattern into a regex matching the whole string, where `%` and `_`
+/// are the wildcards of LIKE, the escape character (`\` by default) makes the next character a
+/// literal, and the remaining regex operators keep their meaning.
+fn similar_to_regex(pattern: &str, escape_char: Option<&str>) -> SQLPlannerResult<String> {
+    let escape_char = match escape_char {
+        None => '\\',
+        Some(escape) => match escape.chars().exactly_one() {
+            Ok(c) => c,
+            Err(_) => invalid_operation_err!("SIMILAR TO escape must be a single character"),
+        },
+    };
+
+    let mut regex = String::from("(?s)^(?:");
+    let mut in_brackets = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == escape_char => match chars.next() {
+                Some(next) => regex.push_str(&regex::escape(&next.to_string())),
+                None => invalid_operation_err!(
+                    "SIMILAR TO pattern must not end with the escape character"
+                ),
+            },
+            ']' if in_brackets => {
+                in_brackets = false;
+                regex.push(c);
+            }
+            // the members of a bracket expression are passed through as is
+            _ if in_brackets => regex.push(c),
+            '[' => {
+                in_brackets = true;
+                regex.push(c);
+            }
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '|' | '*' | '+' | '?' | '{' | '}' | '(' | ')' => regex.push(c),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
+/// Wraps the body of a set operation, so it can be planned as a query.
+fn set_expr_to_query(expr: &SetExpr) -> Query {
+    Query {
+        with: None,
+        body: Box::new(expr.clone()),
+        order_by: None,
+        limit: None,
+        limit_by: vec![],
+        offset: None,
+        fetch: None,
+        locks: vec![],
+        for_clause: None,
+        settings: None,
+        format_clause: None,
+    }
+}
+
 /// Add the relevant projection and alias plan nodes to reflect the TableAlias
 fn apply_table_alias(
     mut plan: LogicalPlanBuilder,
@@ -1974,21 +2956,43 @@ fn apply_table_alias(
 /// Helper to do create a singleton plan for SELECT without FROM.
 #[cfg(feature = "python")]
 fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
+    in_memory_plan(vec![
+        Utf8Array::from_iter("", std::iter::once(Some(""))).into_series()
+    ])
+}
+
+/// Helper to do create a singleton plan for SELECT without FROM.
+#[cfg(not(feature = "python"))]
+fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
+    Err(common_error::DaftError::InternalError(
+        "SELECT without FROM requires 'python' feature".to_string(),
+    ))
+}
+
+/// Helper to create a plan which scans an in-memory table of the columns.
+#[cfg(feature = "python")]
+pub(crate) fn in_memory_plan(columns: Vec<Series>) -> DaftResult<LogicalPlanBuilder> {
+    use daft_core::python::PySeries;
     use daft_logical_plan::PyLogicalPlanBuilder;
-    use pyo3::{
-        intern,
-        prelude::*,
-        types::{IntoPyDict, PyList},
-    };
+    use pyo3::{intern, prelude::*, types::PyDict};
     Python::with_gil(|py| {
-        // df = DataFrame._from_pydict({"":[""]})
+        // data = {name: Series._from_pyseries(column) for column in columns}
+        let series = py
+            .import(intern!(py, "daft.series"))?
+            .getattr(intern!(py, "Series"))?;
+        let data = PyDict::new(py);
+        for column in columns {
+            let name = column.name().to_string();
+            let column =
+                series.call_method1(intern!(py, "_from_pyseries"), (PySeries::from(column),))?;
+            data.set_item(name, column)?;
+        }
+        // df = DataFrame._from_pydict(data)
         let df = py
             .import(intern!(py, "daft.dataframe.dataframe"))?
             .getattr(intern!(py, "DataFrame"))?
             .getattr(intern!(py, "_from_pydict"))?
-            .call1(([("", PyList::new(py, [""]).unwrap())]
-                .into_py_dict(py)
-                .unwrap(),))?;
+            .call1((data,))?;
         // builder = df._builder._builder
         let builder: PyLogicalPlanBuilder = df
             .getattr(intern!(py, "_builder"))?
@@ -1999,20 +3003,37 @@ fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
     })
 }
 
-/// Helper to do create a singleton plan for SELECT without FROM.
+/// Helper to create a plan which scans an in-memory table of the columns.
 #[cfg(not(feature = "python"))]
-fn singleton_plan() -> DaftResult<LogicalPlanBuilder> {
+pub(crate) fn in_memory_plan(_: Vec<Series>) -> DaftResult<LogicalPlanBuilder> {
     Err(common_error::DaftError::InternalError(
-        "SELECT without FROM requires 'python' feature".to_string(),
+        "In-memory tables such as VALUES require 'python' feature".to_string(),
     ))
 }
 
+/// The number of iterations after which a recursive CTE fails if it hasn't terminated.
+#[cfg(feature = "python")]
+fn recursive_cte_max_iterations() -> usize {
+    daft_context::get_context()
+        .planning_config()
+        .recursive_cte_max_iterations
+}
+
+/// The number of iterations after which a recursive CTE fails if it hasn't terminated.
+#[cfg(not(feature = "python"))]
+fn recursive_cte_max_iterations() -> usize {
+    common_daft_config::DaftPlanningConfig::default().recursive_cte_max_iterations
+}
+
 #[cfg(test)]
 mod tests {
-    use daft_core::prelude::*;
-    use sqlparser::ast::{Ident, ObjectName};
+    use daft_core::{datatypes::IntervalValue, prelude::*};
+    use sqlparser::ast::{DateTimeField, Ident, ObjectName};
 
-    use crate::{planner::is_table_path, sql_schema};
+    use crate::{
+        planner::{is_table_path, parse_interval_fields, similar_to_regex},
+        sql_schema,
+    };
 
     #[test]
     fn test_sql_schema_creates_expected_schema() {
@@ -2078,4 +3099,48 @@ mod tests {
             "path/to/file.ext"
         )])));
     }
+
+    #[test]
+    fn test_similar_to_regex() {
+        let regex = |pattern: &str, escape: Option<&str>| {
+            regex::Regex::new(&similar_to_regex(pattern, escape).unwrap()).unwrap()
+        };
+
+        assert!(regex("a%", None).is_match("abc"));
+        assert!(!regex("a%", None).is_match("cab"));
+        assert!(regex("_b_", None).is_match("abc"));
+        assert!(regex("(a|b)+", None).is_match("abba"));
+        assert!(!regex("(a|b)+", None).is_match("abc"));
+        assert!(regex("[a-c]*.d", None).is_match("cab.d"));
+        assert!(!regex("[a-c]*.d", None).is_match("cabxd"));
+        assert!(regex("100\\%", None).is_match("100%"));
+        assert!(!regex("100#%", Some("#")).is_match("1000"));
+        assert!(similar_to_regex("a\\", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_interval_fields() {
+        use DateTimeField::{Day, Hour, Minute, Month, Second, Year};
+
+        assert_eq!(
+            parse_interval_fields("1-6", &Year, &Month).unwrap(),
+            IntervalValue::new(18, 0, 0)
+        );
+        assert_eq!(
+            parse_interval_fields("2 03:04", &Day, &Minute).unwrap(),
+            IntervalValue::new(0, 2, 11_040_000_000_000)
+        );
+        assert_eq!(
+            parse_interval_fields("-3 12:30:15.5", &Day, &Second).unwrap(),
+            IntervalValue::new(0, -3, -45_015_500_000_000)
+        );
+        assert_eq!(
+            parse_interval_fields("01:30", &Hour, &Minute).unwrap(),
+            IntervalValue::new(0, 0, 5_400_000_000_000)
+        );
+        assert!(parse_interval_fields("1-6", &Year, &Day).is_err());
+        assert!(parse_interval_fields("1 2", &Month, &Year).is_err());
+        assert!(parse_interval_fields("1 2", &Day, &Second).is_err());
+        assert!(parse_interval_fields("a 2", &Day, &Hour).is_err());
+    }
 }
@@ -5,10 +5,12 @@ use daft_catalog::TableSource;
 use daft_dsl::python::PyExpr;
 use daft_logical_plan::{LogicalPlan, LogicalPlanBuilder, PyLogicalPlanBuilder};
 use daft_session::{python::PySession, Session};
-use pyo3::{prelude::*, IntoPyObjectExt};
+use pyo3::{intern, prelude::*, types::PyDict, IntoPyObjectExt};
 
 use crate::{
-    error::PlannerError, functions::SQL_FUNCTIONS, planner::SQLPlanner, statement::Statement,
+    functions::SQL_FUNCTIONS,
+    planner::SQLPlanner,
+    statement::{Show, Statement},
 };
 
 #[pyclass]
@@ -53,14 +55,80 @@ pub fn sql_exec(
             let builder = builder.into_py_any(py)?;
             Ok(Some(builder))
         }
-        Statement::Set(_) => Err(PlannerError::unsupported_sql(
-            "SET statement is not yet supported.".to_string(),
-        ))?,
+        Statement::Set(set) => {
+            sess.set_option(&set.option, &set.value)?;
+            Ok(None)
+        }
         Statement::Use(use_) => {
             sess.set_catalog(Some(&use_.catalog))?;
             sess.set_namespace(use_.namespace.as_ref())?;
             Ok(None)
         }
+        Statement::CreateTable(create) => {
+            if create.if_not_exists && sess.has_table(&create.name) {
+                return Ok(None);
+            }
+            if create.temporary {
+                sess.create_temp_table(create.name.name, &create.source, create.replace)?;
+            } else {
+                sess.create_table(&create.name, &create.source, create.replace)?;
+            }
+            Ok(None)
+        }
+        Statement::CreateView(create) => {
+            if create.if_not_exists && sess.list_tables(None)?.contains(&create.name) {
+                return Ok(None);
+            }
+            let source = TableSource::View(create.view);
+            sess.create_temp_table(create.name, &source, create.replace)?;
+            Ok(None)
+        }
+        Statement::Insert(insert) => {
+            // the table's write executes the plan with its own sink, e.g. an iceberg write.
+            if insert.overwrite {
+                insert.table.overwrite(insert.source)?;
+            } else {
+                insert.table.append(insert.source)?;
+            }
+            Ok(None)
+        }
+        Statement::DropTable(drop) => {
+            for name in &drop.names {
+                if drop.if_exists && !sess.has_table(name) {
+                    continue;
+                }
+                sess.drop_table(name)?;
+            }
+            Ok(None)
+        }
+        Statement::DropView(drop) => {
+            for name in &drop.names {
+                if drop.if_exists && !sess.has_table(name) {
+                    continue;
+                }
+                sess.drop_view(&name.name)?;
+            }
+            Ok(None)
+        }
+        Statement::Show(show) => {
+            // listing matches on substrings, so the LIKE pattern is applied to all names.
+            let (column, mut names) = match show {
+                Show::Tables { .. } => ("table", sess.list_tables(None)?),
+                Show::Catalogs { .. } => ("catalog", sess.list_catalogs(None)?),
+            };
+            names.retain(|name| show.matches(name));
+            names.sort();
+            // df = daft.from_pydict({column: names})
+            let data = PyDict::new(py);
+            data.set_item(column, names)?;
+            let df = py
+                .import(intern!(py, "daft"))?
+                .getattr(intern!(py, "from_pydict"))?
+                .call1((data,))?;
+            // builder = df._builder._builder
+            let builder = df.getattr("_builder")?.getattr("_builder")?;
+            Ok(Some(builder.unbind()))
+        }
     }
 }
 
@@ -1,8 +1,15 @@
-use daft_catalog::Identifier;
+use std::sync::Arc;
+
+use daft_catalog::{Identifier, TableRef, TableSource};
+use daft_core::prelude::{Field, Schema};
+use daft_dsl::{null_lit, resolved_col};
 use daft_logical_plan::{LogicalPlanBuilder, LogicalPlanRef};
 use sqlparser::ast;
 
-use crate::{error::SQLPlannerResult, unsupported_sql_err, SQLPlanner};
+use crate::{
+    column_not_found_err, error::SQLPlannerResult, invalid_operation_err, normalize,
+    schema::sql_dtype_to_dtype, unsupported_sql_err, SQLPlanner,
+};
 
 /// Top-level planning structure
 #[derive(Debug, Clone)]
@@ -13,6 +20,18 @@ pub enum Statement {
     Set(Set),
     /// use a catalog and optional namespace
     Use(Use),
+    /// create a table or temp table
+    CreateTable(CreateTable),
+    /// create a view scoped to the session
+    CreateView(CreateView),
+    /// insert the rows of a query into a table
+    Insert(Insert),
+    /// drop tables
+    DropTable(Drop),
+    /// drop views
+    DropView(Drop),
+    /// show the catalogs or tables of the session
+    Show(Show),
 }
 
 /// SELECT ...
@@ -32,6 +51,59 @@ pub struct Use {
     pub namespace: Option<Identifier>,
 }
 
+/// CREATE [OR REPLACE] [TEMP] TABLE [IF NOT EXISTS] <name> [(<columns>) | AS <query>]
+#[derive(Debug, Clone)]
+pub struct CreateTable {
+    pub name: Identifier,
+    pub source: TableSource,
+    pub temporary: bool,
+    pub replace: bool,
+    pub if_not_exists: bool,
+}
+
+/// CREATE [OR REPLACE] [TEMP] VIEW [IF NOT EXISTS] <name> AS <query>
+#[derive(Debug, Clone)]
+pub struct CreateView {
+    pub name: String,
+    pub view: LogicalPlanRef,
+    pub replace: bool,
+    pub if_not_exists: bool,
+}
+
+/// INSERT [INTO | OVERWRITE] <table> [(<columns>)] <query>
+#[derive(Debug, Clone)]
+pub struct Insert {
+    pub table: TableRef,
+    /// Source rows, already aligned with the table's schema.
+    pub source: LogicalPlanRef,
+    pub overwrite: bool,
+}
+
+/// DROP TABLE | VIEW [IF EXISTS] <name> [, <name> ...]
+#[derive(Debug, Clone)]
+pub struct Drop {
+    pub names: Vec<Identifier>,
+    pub if_exists: bool,
+}
+
+/// SHOW TABLES | CATALOGS [LIKE <pattern>]
+#[derive(Debug, Clone)]
+pub enum Show {
+    Tables { pattern: Option<String> },
+    Catalogs { pattern: Option<String> },
+}
+
+impl Show {
+    /// Returns true iff `name` matches the LIKE pattern of the statement, if it has one.
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Tables { pattern } | Self::Catalogs { pattern } => pattern
+                .as_deref()
+                .is_none_or(|pattern| like_matches(pattern, name)),
+        }
+    }
+}
+
 /// Daft-SQL statement planning.
 impl SQLPlanner<'_> {
     /// Generates a logical plan for an ast statement.
@@ -40,7 +112,16 @@ impl SQLPlanner<'_> {
         statement: &ast::Statement,
     ) -> SQLPlannerResult<Statement> {
         match statement {
-            ast::Statement::Query(query) => self.plan_select(query).map(Statement::Select),
+            ast::Statement::Query(query) => match query.body.as_ref() {
+                // WITH <ctes> INSERT INTO ...
+                ast::SetExpr::Insert(insert) => {
+                    if let Some(with) = &query.with {
+                        self.plan_ctes(with)?;
+                    }
+                    self.plan_statement(insert)
+                }
+                _ => self.plan_select(query).map(Statement::Select),
+            },
             ast::Statement::Explain {
                 describe_alias,
                 analyze,
@@ -59,10 +140,46 @@ impl SQLPlanner<'_> {
                 *has_table_keyword,
                 table_name,
             ),
-            ast::Statement::SetVariable { .. } => {
-                todo!("set_variable")
-            }
+            ast::Statement::SetVariable {
+                local,
+                hivevar,
+                variables,
+                value,
+            } => self.plan_set(*local, *hivevar, variables, value),
             ast::Statement::Use(use_) => self.plan_use(use_),
+            ast::Statement::CreateTable(create) => self.plan_create_table(create),
+            ast::Statement::CreateView {
+                or_replace,
+                materialized,
+                name,
+                columns,
+                query,
+                if_not_exists,
+                ..
+            } => self.plan_create_view(
+                *or_replace,
+                *materialized,
+                name,
+                columns,
+                query,
+                *if_not_exists,
+            ),
+            ast::Statement::Insert(insert) => self.plan_insert(insert),
+            ast::Statement::Drop {
+                object_type,
+                if_exists,
+                names,
+                cascade,
+                purge,
+                ..
+            } => self.plan_drop(object_type, *if_exists, names, *cascade, *purge),
+            ast::Statement::ShowTables {
+                extended,
+                full,
+                db_name,
+                filter,
+            } => self.plan_show_tables(*extended, *full, db_name.as_ref(), filter.as_ref()),
+            ast::Statement::ShowVariable { variable } => self.plan_show_variable(variable),
             other => unsupported_sql_err!("unsupported statement, {}", other),
         }
     }
@@ -131,9 +248,38 @@ impl SQLPlanner<'_> {
         Ok(Statement::Select(describe.build()))
     }
 
-    #[allow(dead_code)]
-    fn plan_set(&self, _: &ast::SetConfigValue) -> SQLPlannerResult<Statement> {
-        unsupported_sql_err!("SET statement is not yet supported.")
+    /// SET <option> [=|TO] <value>
+    fn plan_set(
+        &self,
+        local: bool,
+        hivevar: bool,
+        variables: &ast::OneOrManyWithParens<ast::ObjectName>,
+        value: &[ast::Expr],
+    ) -> SQLPlannerResult<Statement> {
+        if local || hivevar {
+            unsupported_sql_err!("SET LOCAL and SET HIVEVAR are not supported")
+        }
+        let ([variable], [value]) = (variables.as_ref(), value) else {
+            unsupported_sql_err!("SET only supports a single option and value")
+        };
+        let option = variable.to_string();
+        let value = match value {
+            ast::Expr::Identifier(ident) => ident.value.clone(),
+            ast::Expr::CompoundIdentifier(idents) => idents
+                .iter()
+                .map(|ident| ident.value.as_str())
+                .collect::<Vec<_>>()
+                .join("."),
+            ast::Expr::Value(
+                ast::Value::SingleQuotedString(value) | ast::Value::DoubleQuotedString(value),
+            ) => value.clone(),
+            ast::Expr::Value(ast::Value::Number(value, _)) => value.clone(),
+            ast::Expr::Value(ast::Value::Boolean(value)) => value.to_string(),
+            other => {
+                unsupported_sql_err!("SET value must be an identifier or literal, found {other}")
+            }
+        };
+        Ok(Statement::Set(Set { option, value }))
     }
 
     fn plan_use(&self, use_: &ast::Use) -> SQLPlannerResult<Statement> {
@@ -150,10 +296,301 @@ impl SQLPlanner<'_> {
         };
         unsupported_sql_err!("Expected `USE <catalog>` or USE <catalog>.<namespace>")
     }
+
+    /// CREATE [OR REPLACE] [TEMP] TABLE [IF NOT EXISTS] <name> [(<columns>) | AS <query>]
+    fn plan_create_table(&mut self, create: &ast::CreateTable) -> SQLPlannerResult<Statement> {
+        if create.external || create.like.is_some() || create.clone.is_some() {
+            unsupported_sql_err!("CREATE EXTERNAL TABLE, LIKE and CLONE are not supported")
+        }
+        if create.or_replace && create.if_not_exists {
+            invalid_operation_err!("CREATE TABLE cannot have both OR REPLACE and IF NOT EXISTS")
+        }
+        let name = normalize(&create.name);
+        if create.temporary && name.has_qualifier() {
+            invalid_operation_err!("Temporary table {name} cannot be qualified")
+        }
+        let source = match &create.query {
+            Some(_) if !create.columns.is_empty() => {
+                unsupported_sql_err!("CREATE TABLE AS with a column list is not supported")
+            }
+            Some(query) => TableSource::View(self.plan_select(query)?),
+            None => {
+                let fields = create
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        Ok(Field::new(
+                            column.name.value.clone(),
+                            sql_dtype_to_dtype(&column.data_type)?,
+                        ))
+                    })
+                    .collect::<SQLPlannerResult<Vec<_>>>()?;
+                TableSource::Schema(Arc::new(Schema::new(fields)?))
+            }
+        };
+        Ok(Statement::CreateTable(CreateTable {
+            name,
+            source,
+            temporary: create.temporary,
+            replace: create.or_replace,
+            if_not_exists: create.if_not_exists,
+        }))
+    }
+
+    /// CREATE [OR REPLACE] [TEMP] VIEW [IF NOT EXISTS] <name> AS <query>
+    ///
+    /// Catalogs do not hold views, so all views are scoped to the session like temp tables.
+    fn plan_create_view(
+        &mut self,
+        or_replace: bool,
+        materialized: bool,
+        name: &ast::ObjectName,
+        columns: &[ast::ViewColumnDef],
+        query: &ast::Query,
+        if_not_exists: bool,
+    ) -> SQLPlannerResult<Statement> {
+        if materialized {
+            unsupported_sql_err!("CREATE MATERIALIZED VIEW is not supported")
+        }
+        if or_replace && if_not_exists {
+            invalid_operation_err!("CREATE VIEW cannot have both OR REPLACE and IF NOT EXISTS")
+        }
+        let name = normalize(name);
+        if name.has_qualifier() {
+            invalid_operation_err!(
+                "View {name} cannot be qualified, views are scoped to the session"
+            )
+        }
+        let mut view = self.plan_query(query)?;
+        if !columns.is_empty() {
+            let schema = view.schema();
+            if columns.len() != schema.len() {
+                invalid_operation_err!(
+                    "View {name} has {} columns but its query returns {} columns",
+                    columns.len(),
+                    schema.len()
+                )
+            }
+            let aliases = schema
+                .names()
+                .iter()
+                .zip(columns)
+                .map(|(source, column)| {
+                    resolved_col(source.as_str()).alias(column.name.value.as_str())
+                })
+                .collect();
+            view = view.select(aliases)?;
+        }
+        Ok(Statement::CreateView(CreateView {
+            name: name.name,
+            view: view.build(),
+            replace: or_replace,
+            if_not_exists,
+        }))
+    }
+
+    /// INSERT [INTO | OVERWRITE] <table> [(<columns>)] <query>
+    ///
+    /// The query's columns are matched to the given columns (or all of the table's columns) by
+    /// position and cast to the table's types; table columns without a value are filled with nulls.
+    fn plan_insert(&mut self, insert: &ast::Insert) -> SQLPlannerResult<Statement> {
+        if insert.or.is_some()
+            || insert.ignore
+            || insert.replace_into
+            || insert.on.is_some()
+            || insert.returning.is_some()
+        {
+            unsupported_sql_err!("INSERT with conflict handling or RETURNING is not supported")
+        }
+        if insert.partitioned.is_some() || !insert.after_columns.is_empty() {
+            unsupported_sql_err!("INSERT with PARTITION is not supported")
+        }
+        let Some(query) = &insert.source else {
+            unsupported_sql_err!("INSERT requires a query or VALUES")
+        };
+        let ident = normalize(&insert.table_name);
+        let table = self.session().get_table(&ident)?;
+        let table_schema = table.get_logical_plan()?.schema();
+        let source = self.plan_query(query)?;
+        let source_schema = source.schema();
+        let columns = if insert.columns.is_empty() {
+            table_schema.names()
+        } else {
+            insert
+                .columns
+                .iter()
+                .map(|column| column.value.clone())
+                .collect()
+        };
+        if columns.len() != source_schema.len() {
+            invalid_operation_err!(
+                "INSERT into {ident} has {} target columns but its query returns {} columns",
+                columns.len(),
+                source_schema.len()
+            )
+        }
+        if let Some(column) = columns
+            .iter()
+            .find(|column| !table_schema.has_field(column))
+        {
+            column_not_found_err!(column.clone(), ident.to_string())
+        }
+        let source_names = source_schema.names();
+        let projection = table_schema
+            .fields
+            .values()
+            .map(|field| {
+                let value = match columns.iter().position(|column| column == &field.name) {
+                    Some(idx) => resolved_col(source_names[idx].as_str()),
+                    None => null_lit(),
+                };
+                value.cast(&field.dtype).alias(field.name.as_str())
+            })
+            .collect();
+        Ok(Statement::Insert(Insert {
+            table,
+            source: source.select(projection)?.build(),
+            overwrite: insert.overwrite,
+        }))
+    }
+
+    /// DROP TABLE | VIEW [IF EXISTS] <name> [, <name> ...]
+    fn plan_drop(
+        &self,
+        object_type: &ast::ObjectType,
+        if_exists: bool,
+        names: &[ast::ObjectName],
+        cascade: bool,
+        purge: bool,
+    ) -> SQLPlannerResult<Statement> {
+        if cascade || purge {
+            unsupported_sql_err!("DROP with CASCADE or PURGE is not supported")
+        }
+        let drop = Drop {
+            names: names.iter().map(normalize).collect(),
+            if_exists,
+        };
+        match object_type {
+            ast::ObjectType::Table => Ok(Statement::DropTable(drop)),
+            ast::ObjectType::View => {
+                if let Some(name) = drop.names.iter().find(|name| name.has_qualifier()) {
+                    invalid_operation_err!(
+                        "View {name} cannot be qualified, views are scoped to the session"
+                    )
+                }
+                Ok(Statement::DropView(drop))
+            }
+            other => unsupported_sql_err!("DROP {other} is not supported"),
+        }
+    }
+
+    /// SHOW TABLES [LIKE <pattern>]
+    fn plan_show_tables(
+        &self,
+        extended: bool,
+        full: bool,
+        db_name: Option<&ast::Ident>,
+        filter: Option<&ast::ShowStatementFilter>,
+    ) -> SQLPlannerResult<Statement> {
+        if extended || full || db_name.is_some() {
+            unsupported_sql_err!("SHOW TABLES only supports an optional LIKE pattern")
+        }
+        let pattern = match filter {
+            None => None,
+            Some(ast::ShowStatementFilter::Like(pattern)) => Some(pattern.clone()),
+            Some(other) => unsupported_sql_err!("SHOW TABLES {other} is not supported"),
+        };
+        Ok(Statement::Show(Show::Tables { pattern }))
+    }
+
+    /// SHOW CATALOGS [LIKE <pattern>]
+    fn plan_show_variable(&self, variable: &[ast::Ident]) -> SQLPlannerResult<Statement> {
+        match variable {
+            [object] if object.value.eq_ignore_ascii_case("catalogs") => {
+                Ok(Statement::Show(Show::Catalogs { pattern: None }))
+            }
+            [object, like, pattern]
+                if object.value.eq_ignore_ascii_case("catalogs")
+                    && like.value.eq_ignore_ascii_case("like") =>
+            {
+                Ok(Statement::Show(Show::Catalogs {
+                    pattern: Some(pattern.value.clone()),
+                }))
+            }
+            _ => unsupported_sql_err!(
+                "SHOW {} is not supported",
+                variable
+                    .iter()
+                    .map(|ident| ident.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        }
+    }
+}
+
+/// Returns true iff `name` matches the LIKE `pattern`, where `%` matches any sequence of characters,
+/// `_` matches any single character, and `\` escapes the character after it.
+fn like_matches(pattern: &str, name: &str) -> bool {
+    enum Token {
+        Any,
+        One,
+        Char(char),
+    }
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => Token::Any,
+            '_' => Token::One,
+            '\\' => Token::Char(chars.next().unwrap_or('\\')),
+            c => Token::Char(c),
+        });
+    }
+    let name = name.chars().collect::<Vec<_>>();
+    // match greedily, and on a mismatch retry from the last `%` with it matching one more character.
+    let (mut p, mut n) = (0, 0);
+    let mut last_any = None;
+    while n < name.len() {
+        match tokens.get(p) {
+            Some(Token::Any) => {
+                last_any = Some((p, n));
+                p += 1;
+                continue;
+            }
+            Some(Token::One) => {
+                p += 1;
+                n += 1;
+                continue;
+            }
+            Some(Token::Char(c)) if *c == name[n] => {
+                p += 1;
+                n += 1;
+                continue;
+            }
+            _ => {}
+        }
+        match last_any {
+            Some((any_p, any_n)) => {
+                last_any = Some((any_p, any_n + 1));
+                p = any_p + 1;
+                n = any_n + 1;
+            }
+            None => return false,
+        }
+    }
+    tokens[p..].iter().all(|token| matches!(token, Token::Any))
 }
 
 #[cfg(test)]
 mod test {
+    use std::rc::Rc;
+
+    use daft_core::prelude::DataType;
+    use daft_logical_plan::{
+        ops::Source, source_info::PlaceHolderInfo, ClusteringSpec, LogicalPlan, SourceInfo,
+    };
+    use daft_session::Session;
     use sqlparser::{dialect::GenericDialect, parser::Parser};
 
     use super::*;
@@ -165,6 +602,164 @@ mod test {
         parsed.remove(0)
     }
 
+    fn mock_plan(fields: Vec<Field>) -> LogicalPlanRef {
+        let schema = Arc::new(Schema::new(fields).unwrap());
+        LogicalPlan::Source(Source::new(
+            schema.clone(),
+            Arc::new(SourceInfo::PlaceHolder(PlaceHolderInfo {
+                source_schema: schema,
+                clustering_spec: Arc::new(ClusteringSpec::unknown()),
+            })),
+        ))
+        .arced()
+    }
+
+    /// Plans the statement with temp tables t(a int64, b utf8) and s(x int32).
+    fn plan(sql: &str) -> SQLPlannerResult<Statement> {
+        let session = Session::empty();
+        let t = mock_plan(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let s = mock_plan(vec![Field::new("x", DataType::Int32)]);
+        session.create_temp_table("t", &TableSource::View(t), false)?;
+        session.create_temp_table("s", &TableSource::View(s), false)?;
+        SQLPlanner::new(Rc::new(session)).plan(sql)
+    }
+
+    #[test]
+    fn test_set() -> SQLPlannerResult<()> {
+        let Statement::Set(set) = plan("SET catalog = my_catalog")? else {
+            panic!("Expected Set statement");
+        };
+        assert_eq!(set.option, "catalog");
+        assert_eq!(set.value, "my_catalog");
+
+        let Statement::Set(set) = plan("SET namespace TO 'a.b'")? else {
+            panic!("Expected Set statement");
+        };
+        assert_eq!(set.option, "namespace");
+        assert_eq!(set.value, "a.b");
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_temp_table_as() -> SQLPlannerResult<()> {
+        let Statement::CreateTable(create) = plan("CREATE TEMP TABLE u AS SELECT a FROM t")? else {
+            panic!("Expected CreateTable statement");
+        };
+        assert_eq!(create.name, Identifier::simple("u"));
+        assert!(create.temporary);
+        let TableSource::View(view) = create.source else {
+            panic!("Expected a view source");
+        };
+        assert_eq!(view.schema().names(), vec!["a"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_table_with_columns() -> SQLPlannerResult<()> {
+        let Statement::CreateTable(create) =
+            plan("CREATE TABLE IF NOT EXISTS ns.u (a BIGINT, b VARCHAR)")?
+        else {
+            panic!("Expected CreateTable statement");
+        };
+        assert_eq!(create.name, Identifier::from_path(vec!["ns", "u"])?);
+        assert!(!create.temporary);
+        assert!(create.if_not_exists);
+        let TableSource::Schema(schema) = create.source else {
+            panic!("Expected a schema source");
+        };
+        assert_eq!(schema.get_field("a")?.dtype, DataType::Int64);
+        assert_eq!(schema.get_field("b")?.dtype, DataType::Utf8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_view_with_columns() -> SQLPlannerResult<()> {
+        let Statement::CreateView(create) = plan("CREATE VIEW v (c) AS SELECT a FROM t")? else {
+            panic!("Expected CreateView statement");
+        };
+        assert_eq!(create.name, "v");
+        assert_eq!(create.view.schema().names(), vec!["c"]);
+        assert!(plan("CREATE VIEW ns.v AS SELECT a FROM t").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_aligns_with_table_schema() -> SQLPlannerResult<()> {
+        let Statement::Insert(insert) = plan("INSERT INTO t (b) SELECT x FROM s")? else {
+            panic!("Expected Insert statement");
+        };
+        assert!(!insert.overwrite);
+        assert_eq!(insert.source.schema(), insert.table.get_schema());
+
+        let Statement::Insert(insert) =
+            plan("WITH c AS (SELECT a, b FROM t) INSERT INTO t SELECT * FROM c")?
+        else {
+            panic!("Expected Insert statement");
+        };
+        assert_eq!(insert.source.schema(), insert.table.get_schema());
+
+        assert!(plan("INSERT INTO t SELECT x FROM s").is_err());
+        assert!(plan("INSERT INTO t (z) SELECT x FROM s").is_err());
+        assert!(plan("INSERT INTO missing SELECT x FROM s").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop() -> SQLPlannerResult<()> {
+        let Statement::DropTable(drop) = plan("DROP TABLE IF EXISTS ns.a, b")? else {
+            panic!("Expected DropTable statement");
+        };
+        assert!(drop.if_exists);
+        assert_eq!(
+            drop.names,
+            vec![
+                Identifier::from_path(vec!["ns", "a"])?,
+                Identifier::simple("b")
+            ]
+        );
+        assert!(matches!(plan("DROP VIEW v")?, Statement::DropView(_)));
+        assert!(plan("DROP VIEW ns.v").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_show() -> SQLPlannerResult<()> {
+        assert!(matches!(
+            plan("SHOW TABLES")?,
+            Statement::Show(Show::Tables { pattern: None })
+        ));
+        let Statement::Show(show) = plan("SHOW TABLES LIKE 'tbl_%'")? else {
+            panic!("Expected Show statement");
+        };
+        assert!(show.matches("tbl_a"));
+        assert!(show.matches("tbl1"));
+        assert!(!show.matches("tbl"));
+        assert!(!show.matches("my_tbl_a"));
+        assert!(matches!(
+            plan("SHOW CATALOGS")?,
+            Statement::Show(Show::Catalogs { pattern: None })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_like_matches() {
+        assert!(like_matches("abc", "abc"));
+        assert!(!like_matches("abc", "abcd"));
+        assert!(like_matches("a%", "a"));
+        assert!(like_matches("%b%", "abc"));
+        assert!(!like_matches("%b%", "ac"));
+        assert!(like_matches("a_c", "abc"));
+        assert!(!like_matches("a_c", "ac"));
+        assert!(like_matches("%a%b", "aab_ab"));
+        assert!(like_matches("%%", ""));
+        assert!(like_matches("t\\_%", "t_1"));
+        assert!(!like_matches("t\\_%", "tx1"));
+    }
+
     #[test]
     fn test_use_catalog() {
         let sql = "USE mycatalog";
@@ -1,22 +1,29 @@
+mod range;
+mod read_avro;
 mod read_csv;
 mod read_deltalake;
 mod read_iceberg;
 mod read_json;
+mod read_orc;
 mod read_parquet;
 
 use std::{
     collections::HashMap,
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, RwLock},
 };
 
 use daft_dsl::{Expr, ExprRef};
 use daft_logical_plan::LogicalPlanBuilder;
+use daft_session::TableFunctionRef;
+use range::RangeFunction;
+use read_avro::ReadAvroFunction;
 use read_csv::ReadCsvFunction;
 use read_deltalake::ReadDeltalakeFunction;
 use read_iceberg::SqlReadIceberg;
 use read_json::ReadJsonFunction;
+use read_orc::ReadOrcFunction;
 use read_parquet::ReadParquetFunction;
-use sqlparser::ast::TableFunctionArgs;
+use sqlparser::ast::{FunctionArg, FunctionArgOperator, TableFunctionArgs};
 
 use crate::{
     error::{PlannerError, SQLPlannerResult},
@@ -27,16 +34,33 @@ use crate::{
     unsupported_sql_err,
 };
 
-pub(crate) static SQL_TABLE_FUNCTIONS: LazyLock<SQLTableFunctions> = LazyLock::new(|| {
+pub(crate) static SQL_TABLE_FUNCTIONS: LazyLock<RwLock<SQLTableFunctions>> = LazyLock::new(|| {
     let mut functions = SQLTableFunctions::new();
+    functions.add_fn("read_avro", ReadAvroFunction);
     functions.add_fn("read_csv", ReadCsvFunction);
     functions.add_fn("read_deltalake", ReadDeltalakeFunction);
     functions.add_fn("read_iceberg", SqlReadIceberg);
     functions.add_fn("read_json", ReadJsonFunction);
+    functions.add_fn("read_orc", ReadOrcFunction);
     functions.add_fn("read_parquet", ReadParquetFunction);
-    functions
+    functions.register("range", Arc::new(RangeFunction::new("range", false)));
+    functions.register(
+        "generate_series",
+        Arc::new(RangeFunction::new("generate_series", true)),
+    );
+    RwLock::new(functions)
 });
 
+/// Registers a table function for all SQL queries, replacing any function with the same name.
+///
+/// Table functions registered with a [`daft_session::Session`] take precedence over these.
+pub fn register_table_function(name: &str, function: TableFunctionRef) {
+    SQL_TABLE_FUNCTIONS
+        .write()
+        .unwrap()
+        .register(name, function);
+}
+
 /// TODO chore: cleanup table_provider module
 /// TODO feat: use multimap for function variants.
 pub struct SQLTableFunctions {
@@ -55,6 +79,11 @@ impl SQLTableFunctions {
         self.map.insert(name.to_string(), Arc::new(func));
     }
 
+    /// Add a [TableFunction](daft_session::TableFunction) to the [SQLTableFunctions] instance.
+    pub(crate) fn register(&mut self, name: &str, function: TableFunctionRef) {
+        self.add_fn(name, RegisteredTableFunction::new(name, function));
+    }
+
     /// Get a function by name from the [SQLFunctions] instance.
     pub(crate) fn get(&self, name: &str) -> Option<&Arc<dyn SQLTableFunction>> {
         self.map.get(name)
@@ -67,10 +96,17 @@ impl SQLPlanner<'_> {
         fn_name: &str,
         args: &TableFunctionArgs,
     ) -> SQLPlannerResult<LogicalPlanBuilder> {
-        let fns = &SQL_TABLE_FUNCTIONS;
-
-        let Some(func) = fns.get(fn_name) else {
-            unsupported_sql_err!("Function `{}` not found", fn_name);
+        // functions of the session shadow the global ones
+        let session_fn = self.session().get_table_function(fn_name).ok();
+        let func = match session_fn {
+            Some(function) => Arc::new(RegisteredTableFunction::new(fn_name, function)),
+            None => {
+                let fns = SQL_TABLE_FUNCTIONS.read().unwrap();
+                let Some(func) = fns.get(fn_name) else {
+                    unsupported_sql_err!("Function `{}` not found", fn_name);
+                };
+                func.clone()
+            }
         };
 
         let builder = func.plan(self, args)?;
@@ -79,6 +115,88 @@ impl SQLPlanner<'_> {
     }
 }
 
+/// A [TableFunction](daft_session::TableFunction) which is called from SQL by binding the
+/// arguments of the call to its parameters, positionally or by name.
+struct RegisteredTableFunction {
+    name: String,
+    function: TableFunctionRef,
+}
+
+impl RegisteredTableFunction {
+    fn new(name: &str, function: TableFunctionRef) -> Self {
+        Self {
+            name: name.to_string(),
+            function,
+        }
+    }
+}
+
+impl SQLTableFunction for RegisteredTableFunction {
+    fn plan(
+        &self,
+        planner: &SQLPlanner,
+        args: &TableFunctionArgs,
+    ) -> SQLPlannerResult<LogicalPlanBuilder> {
+        let params = self.function.params();
+        let mut bound = HashMap::new();
+        let mut has_named = false;
+        for (idx, arg) in args.args.iter().enumerate() {
+            let (param, arg) = match arg {
+                FunctionArg::Named {
+                    name,
+                    arg,
+                    operator: FunctionArgOperator::RightArrow | FunctionArgOperator::Assignment,
+                } => {
+                    let Some(param) = params.iter().find(|param| param.name == name.value) else {
+                        unsupported_sql_err!("unexpected named argument: {}", name);
+                    };
+                    has_named = true;
+                    (param, arg)
+                }
+                FunctionArg::Unnamed(arg) => {
+                    if has_named {
+                        invalid_operation_err!(
+                            "positional arguments of `{}` must come before named arguments",
+                            self.name
+                        );
+                    }
+                    let Some(param) = params.get(idx) else {
+                        invalid_operation_err!(
+                            "`{}` expects at most {} arguments",
+                            self.name,
+                            params.len()
+                        );
+                    };
+                    (param, arg)
+                }
+                other => unsupported_sql_err!("unsupported function argument type: {other}"),
+            };
+            let arg = planner.try_unwrap_function_arg_expr(arg)?;
+            if bound.insert(param.name.clone(), arg).is_some() {
+                invalid_operation_err!(
+                    "argument {} of `{}` is given more than once",
+                    param.name,
+                    self.name
+                );
+            }
+        }
+
+        if let Some(missing) = params
+            .iter()
+            .find(|param| param.required && !bound.contains_key(&param.name))
+        {
+            invalid_operation_err!(
+                "missing required argument {} of `{}`",
+                missing.name,
+                self.name
+            );
+        }
+
+        let args = daft_session::TableFunctionArgs::new(bound);
+        Ok(self.function.plan(&args)?)
+    }
+}
+
 // nit cleanup: switch param order and rename to `to_logical_plan` for consistency with SQLFunction.
 pub(crate) trait SQLTableFunction: Send + Sync {
     fn plan(
@@ -0,0 +1,100 @@
+use common_error::DaftError;
+use daft_catalog::error::{Error, Result};
+use daft_core::prelude::*;
+use daft_logical_plan::LogicalPlanBuilder;
+use daft_session::{TableFunction, TableFunctionArgs, TableFunctionParam};
+
+use crate::planner::in_memory_plan;
+
+/// The maximum number of rows of a range, since its values are materialized when it is planned.
+const MAX_RANGE_ROWS: u128 = 100_000_000;
+
+/// Produces a single `Int64` column of the values from `start` to `stop` by `step`.
+///
+/// ```sql
+/// SELECT * FROM range(0, 10, 2);
+/// SELECT * FROM generate_series(1, 3);
+/// ```
+#[derive(Debug)]
+pub(super) struct RangeFunction {
+    /// The name of the function, which is also the name of its column.
+    name: &'static str,
+    /// Whether `stop` is part of the range, as in `generate_series`.
+    inclusive: bool,
+    params: Vec<TableFunctionParam>,
+}
+
+impl RangeFunction {
+    pub(super) fn new(name: &'static str, inclusive: bool) -> Self {
+        Self {
+            name,
+            inclusive,
+            params: vec![
+                TableFunctionParam::required("start"),
+                TableFunctionParam::required("stop"),
+                TableFunctionParam::optional("step"),
+            ],
+        }
+    }
+
+    fn get_i64(&self, args: &TableFunctionArgs, param: &str) -> Result<Option<i64>> {
+        args.get_literal(param)?
+            .map(|value| {
+                value.as_i64().ok_or_else(|| {
+                    Error::unsupported(format!(
+                        "Expected an integer for argument {param} of `{}`, found {value}",
+                        self.name
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// The number of values from `start` to `stop` by `step`, which must not be zero.
+    fn num_rows(&self, start: i64, stop: i64, step: i64) -> u128 {
+        // the distance is in the direction of the step, so that both are positive.
+        let (distance, step) = if step > 0 {
+            (stop as i128 - start as i128, step as i128)
+        } else {
+            (start as i128 - stop as i128, -(step as i128))
+        };
+        match (distance, self.inclusive) {
+            (distance, true) if distance >= 0 => (distance / step + 1) as u128,
+            (distance, false) if distance > 0 => ((distance + step - 1) / step) as u128,
+            _ => 0,
+        }
+    }
+}
+
+impl TableFunction for RangeFunction {
+    fn params(&self) -> &[TableFunctionParam] {
+        &self.params
+    }
+
+    fn plan(&self, args: &TableFunctionArgs) -> Result<LogicalPlanBuilder> {
+        let start = self.get_i64(args, "start")?.unwrap();
+        let stop = self.get_i64(args, "stop")?.unwrap();
+        let step = self.get_i64(args, "step")?.unwrap_or(1);
+        if step == 0 {
+            return Err(
+                DaftError::ValueError(format!("step of `{}` must not be zero", self.name)).into(),
+            );
+        }
+
+        let num_rows = self.num_rows(start, stop, step);
+        if num_rows > MAX_RANGE_ROWS {
+            return Err(DaftError::ValueError(format!(
+                "`{}` produces {num_rows} rows, which is more than the maximum of {MAX_RANGE_ROWS}",
+                self.name
+            ))
+            .into());
+        }
+        // every value lies between start and stop, so none of them overflow.
+        let values = (0..num_rows as i64)
+            .map(|i| start + i * step)
+            .collect::<Vec<_>>();
+
+        let column = Int64Array::from((self.name, values)).into_series();
+        Ok(in_memory_plan(vec![column])?)
+    }
+}
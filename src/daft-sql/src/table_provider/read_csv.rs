@@ -41,6 +41,16 @@ impl TryFrom<SQLFunctionArguments> for CsvScanBuilder {
         let allow_variable_columns = args
             .try_get_named("allow_variable_columns")?
             .unwrap_or(false);
+        let encoding = args.try_get_named("encoding")?;
+        let null_values = args
+            .get_named("null_values")
+            .map(|arg| try_coerce_list(arg.clone()))
+            .transpose()?;
+        let skip_rows = args.try_get_named("skip_rows")?.unwrap_or(0);
+        let skip_rows_after_header = args.try_get_named("skip_rows_after_header")?.unwrap_or(0);
+        let truncate_ragged_lines = args
+            .try_get_named("truncate_ragged_lines")?
+            .unwrap_or(false);
         let infer_schema = args.try_get_named("infer_schema")?.unwrap_or(true);
         let chunk_size = args.try_get_named("chunk_size")?;
         let buffer_size = args.try_get_named("buffer_size")?;
@@ -68,6 +78,12 @@ impl TryFrom<SQLFunctionArguments> for CsvScanBuilder {
             escape_char,
             comment,
             allow_variable_columns,
+            encoding,
+            null_values,
+            skip_rows,
+            skip_rows_after_header,
+            truncate_ragged_lines,
+            datetime_formats: None, // TODO
             buffer_size,
             chunk_size,
             schema_hints,
@@ -94,6 +110,11 @@ impl SQLTableFunction for ReadCsvFunction {
                 "escape_char",
                 "comment",
                 "allow_variable_columns",
+                "encoding",
+                "null_values",
+                "skip_rows",
+                "skip_rows_after_header",
+                "truncate_ragged_lines",
                 "io_config",
                 "file_path_column",
                 "hive_partitioning",
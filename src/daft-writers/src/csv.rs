@@ -0,0 +1,97 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    io::csv::write::{write_chunk, write_header, SerializeOptions},
+};
+use common_error::DaftResult;
+use daft_io::IOConfig;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{build_file_path, make_file_metadata, OutputFile},
+    FileWriter,
+};
+
+/// Native CSV writer that writes a header row followed by the rows of every input.
+pub(crate) struct CsvWriter {
+    root_dir: String,
+    file_idx: usize,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: SerializeOptions,
+    file: Option<OutputFile>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl CsvWriter {
+    pub fn new(
+        root_dir: &str,
+        file_idx: usize,
+        io_config: Option<&IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> Self {
+        Self {
+            root_dir: root_dir.to_string(),
+            file_idx,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.cloned(),
+            options: SerializeOptions::default(),
+            file: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn open(&mut self, data: &MicroPartition) -> DaftResult<&mut OutputFile> {
+        if self.file.is_none() {
+            let path = build_file_path(
+                &self.root_dir,
+                self.partition_values.as_ref(),
+                self.file_idx,
+                "csv",
+            )?;
+            let mut output = OutputFile::try_new(path, self.io_config.as_ref())?;
+            self.bytes_written = output.bytes_written_counter();
+            let names = data.schema().fields.keys().cloned().collect::<Vec<_>>();
+            write_header(&mut output, &names, &self.options)?;
+            self.file = Some(output);
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+}
+
+impl FileWriter for CsvWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        let bytes_before = self.bytes_written();
+        let options = self.options.clone();
+        let file = self.open(&data)?;
+        for table in data.get_tables()?.iter().filter(|table| !table.is_empty()) {
+            let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+            write_chunk(file, &chunk, &options)?;
+        }
+        Ok(self.bytes_written() - bytes_before)
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(file) = self.file.take() else {
+            return Ok(None);
+        };
+        let path = file.path().to_string();
+        file.finish()?;
+        Ok(Some(make_file_metadata(
+            &path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    io::ipc::write::{Compression, FileWriter as IpcFileWriter, WriteOptions},
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::IOConfig;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{build_file_path, make_file_metadata, OutputFile},
+    FileWriter,
+};
+
+fn parse_compression(compression: Option<&str>) -> DaftResult<Option<Compression>> {
+    let compression = compression.map(str::to_lowercase);
+    Ok(match compression.as_deref() {
+        None | Some("none" | "uncompressed") => None,
+        Some("lz4") => Some(Compression::LZ4),
+        Some("zstd") => Some(Compression::ZSTD),
+        Some(other) => {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported Arrow IPC compression: {other}"
+            )))
+        }
+    })
+}
+
+struct OpenIpcFile {
+    writer: IpcFileWriter<OutputFile>,
+    path: String,
+}
+
+/// Native Arrow IPC writer that writes files in the IPC file format, one record batch per input table.
+pub(crate) struct IpcWriter {
+    root_dir: String,
+    file_idx: usize,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: WriteOptions,
+    file: Option<OpenIpcFile>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl IpcWriter {
+    pub fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        compression: Option<&str>,
+        io_config: Option<&IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        Ok(Self {
+            root_dir: root_dir.to_string(),
+            file_idx,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.cloned(),
+            options: WriteOptions {
+                compression: parse_compression(compression)?,
+            },
+            file: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    fn open(&mut self, data: &MicroPartition) -> DaftResult<&mut OpenIpcFile> {
+        if self.file.is_none() {
+            let path = build_file_path(
+                &self.root_dir,
+                self.partition_values.as_ref(),
+                self.file_idx,
+                "arrow",
+            )?;
+            let output = OutputFile::try_new(path, self.io_config.as_ref())?;
+            self.bytes_written = output.bytes_written_counter();
+            let path = output.path().to_string();
+            let writer =
+                IpcFileWriter::try_new(output, data.schema().to_arrow()?, None, self.options)?;
+            self.file = Some(OpenIpcFile { writer, path });
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+}
+
+impl FileWriter for IpcWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        let bytes_before = self.bytes_written();
+        let file = self.open(&data)?;
+        for table in data.get_tables()?.iter() {
+            let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+            file.writer.write(&chunk, None)?;
+        }
+        Ok(self.bytes_written() - bytes_before)
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(mut file) = self.file.take() else {
+            return Ok(None);
+        };
+        file.writer.finish()?;
+        file.writer.into_inner().finish()?;
+        Ok(Some(make_file_metadata(
+            &file.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use arrow2::{
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::json::write::{FallibleStreamingIterator, RecordSerializer},
+};
+use common_error::DaftResult;
+use daft_io::IOConfig;
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{build_file_path, make_file_metadata, OutputFile},
+    FileWriter,
+};
+
+/// Native JSON writer that writes newline-delimited JSON, i.e. one object per row.
+pub(crate) struct JsonWriter {
+    root_dir: String,
+    file_idx: usize,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    file: Option<(OutputFile, ArrowSchema)>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl JsonWriter {
+    pub fn new(
+        root_dir: &str,
+        file_idx: usize,
+        io_config: Option<&IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> Self {
+        Self {
+            root_dir: root_dir.to_string(),
+            file_idx,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.cloned(),
+            file: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn open(&mut self, data: &MicroPartition) -> DaftResult<&mut (OutputFile, ArrowSchema)> {
+        if self.file.is_none() {
+            let path = build_file_path(
+                &self.root_dir,
+                self.partition_values.as_ref(),
+                self.file_idx,
+                "json",
+            )?;
+            let output = OutputFile::try_new(path, self.io_config.as_ref())?;
+            self.bytes_written = output.bytes_written_counter();
+            self.file = Some((output, data.schema().to_arrow()?));
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+}
+
+impl FileWriter for JsonWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        let bytes_before = self.bytes_written();
+        let (file, arrow_schema) = self.open(&data)?;
+        for table in data.get_tables()?.iter() {
+            let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+            let mut serializer = RecordSerializer::new(arrow_schema.clone(), &chunk, Vec::new());
+            while let Some(row) = serializer.next()? {
+                file.write_all(row)?;
+                file.write_all(b"\n")?;
+            }
+        }
+        Ok(self.bytes_written() - bytes_before)
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some((file, _)) = self.file.take() else {
+            return Ok(None);
+        };
+        let path = file.path().to_string();
+        file.finish()?;
+        Ok(Some(make_file_metadata(
+            &path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+}
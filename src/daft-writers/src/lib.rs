@@ -1,9 +1,14 @@
 #![feature(hash_raw_entry)]
 #![feature(let_chains)]
 mod batch;
+mod csv;
 mod file;
+mod ipc;
+mod json;
+mod parquet;
 mod partition;
 mod physical;
+mod storage;
 
 #[cfg(test)]
 mod test;
@@ -103,7 +108,7 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        FileFormat::Csv => {
+        FileFormat::Csv | FileFormat::Json | FileFormat::Ipc => {
             let file_size_calculator = TargetInMemorySizeBytesCalculator::new(
                 cfg.csv_target_filesize,
                 cfg.csv_inflation_factor,
@@ -124,7 +129,7 @@ pub fn make_physical_writer_factory(
                 Arc::new(file_writer_factory)
             }
         }
-        _ => unreachable!("Physical write should only support Parquet and CSV"),
+        _ => unreachable!("Physical write should only support Parquet, CSV, JSON and Arrow IPC"),
     }
 }
 
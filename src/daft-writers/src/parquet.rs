@@ -0,0 +1,150 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use arrow2::{
+    chunk::Chunk,
+    datatypes::Schema as ArrowSchema,
+    io::parquet::write::{
+        transverse, BrotliLevel, CompressionOptions, Encoding, FileWriter as ParquetFileWriter,
+        GzipLevel, RowGroupIterator, Version, WriteOptions, ZstdLevel,
+    },
+};
+use common_error::{DaftError, DaftResult};
+use daft_io::{IOConfig, IOStatsContext};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{
+    storage::{build_file_path, make_file_metadata, OutputFile},
+    FileWriter,
+};
+
+fn parse_compression(compression: Option<&str>) -> DaftResult<CompressionOptions> {
+    let compression = compression.map(str::to_lowercase);
+    Ok(match compression.as_deref() {
+        None | Some("none" | "uncompressed") => CompressionOptions::Uncompressed,
+        Some("snappy") => CompressionOptions::Snappy,
+        Some("gzip") => CompressionOptions::Gzip(Some(GzipLevel::default())),
+        Some("brotli") => CompressionOptions::Brotli(Some(BrotliLevel::default())),
+        Some("lz4") => CompressionOptions::Lz4Raw,
+        Some("zstd") => CompressionOptions::Zstd(Some(ZstdLevel::default())),
+        Some(other) => {
+            return Err(DaftError::ValueError(format!(
+                "Unsupported parquet compression: {other}"
+            )))
+        }
+    })
+}
+
+struct OpenParquetFile {
+    writer: ParquetFileWriter<OutputFile>,
+    arrow_schema: ArrowSchema,
+    path: String,
+}
+
+/// Native parquet writer that writes each input as a single row group.
+pub(crate) struct ParquetWriter {
+    root_dir: String,
+    file_idx: usize,
+    partition_values: Option<RecordBatch>,
+    io_config: Option<IOConfig>,
+    options: WriteOptions,
+    file: Option<OpenParquetFile>,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl ParquetWriter {
+    pub fn try_new(
+        root_dir: &str,
+        file_idx: usize,
+        compression: Option<&str>,
+        io_config: Option<&IOConfig>,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Self> {
+        Ok(Self {
+            root_dir: root_dir.to_string(),
+            file_idx,
+            partition_values: partition_values.cloned(),
+            io_config: io_config.cloned(),
+            options: WriteOptions {
+                write_statistics: true,
+                version: Version::V2,
+                compression: parse_compression(compression)?,
+                data_pagesize_limit: None,
+            },
+            file: None,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    fn open(&mut self, data: &MicroPartition) -> DaftResult<&mut OpenParquetFile> {
+        if self.file.is_none() {
+            let path = build_file_path(
+                &self.root_dir,
+                self.partition_values.as_ref(),
+                self.file_idx,
+                "parquet",
+            )?;
+            let output = OutputFile::try_new(path, self.io_config.as_ref())?;
+            self.bytes_written = output.bytes_written_counter();
+            let path = output.path().to_string();
+            let arrow_schema = data.schema().to_arrow()?;
+            let writer = ParquetFileWriter::try_new(output, arrow_schema.clone(), self.options)?;
+            self.file = Some(OpenParquetFile {
+                writer,
+                arrow_schema,
+                path,
+            });
+        }
+        Ok(self.file.as_mut().unwrap())
+    }
+}
+
+impl FileWriter for ParquetWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, data: Self::Input) -> DaftResult<usize> {
+        let bytes_before = self.bytes_written();
+        let options = self.options;
+        let file = self.open(&data)?;
+        let tables = data.concat_or_get(IOStatsContext::new("ParquetWriter::write"))?;
+        if let Some(table) = tables.first() {
+            let chunk = Chunk::new(table.get_inner_arrow_arrays().collect());
+            let encodings = file
+                .arrow_schema
+                .fields
+                .iter()
+                .map(|f| transverse(&f.data_type, |_| Encoding::Plain))
+                .collect();
+            let row_groups = RowGroupIterator::try_new(
+                std::iter::once(Ok(chunk)),
+                &file.arrow_schema,
+                options,
+                encodings,
+            )?;
+            for row_group in row_groups {
+                file.writer.write(row_group?)?;
+            }
+        }
+        Ok(self.bytes_written() - bytes_before)
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        let Some(mut file) = self.file.take() else {
+            return Ok(None);
+        };
+        file.writer.end(None)?;
+        file.writer.into_inner().finish()?;
+        Ok(Some(make_file_metadata(
+            &file.path,
+            self.partition_values.as_ref(),
+        )?))
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+}
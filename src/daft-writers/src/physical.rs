@@ -6,20 +6,19 @@ use daft_logical_plan::OutputFileInfo;
 use daft_micropartition::MicroPartition;
 use daft_recordbatch::RecordBatch;
 
-use crate::{FileWriter, WriterFactory};
+use crate::{
+    csv::CsvWriter, ipc::IpcWriter, json::JsonWriter, parquet::ParquetWriter, FileWriter,
+    WriterFactory,
+};
 
-/// PhysicalWriterFactory is a factory for creating physical writers, i.e. parquet, csv writers.
+/// PhysicalWriterFactory is a factory for creating physical writers, i.e. parquet, csv, json and arrow ipc writers.
 pub struct PhysicalWriterFactory {
     output_file_info: OutputFileInfo,
-    native: bool, // TODO: Implement native writer
 }
 
 impl PhysicalWriterFactory {
     pub fn new(output_file_info: OutputFileInfo) -> Self {
-        Self {
-            output_file_info,
-            native: false,
-        }
+        Self { output_file_info }
     }
 }
 
@@ -32,46 +31,192 @@ impl WriterFactory for PhysicalWriterFactory {
         file_idx: usize,
         partition_values: Option<&RecordBatch>,
     ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
-        match self.native {
-            true => unimplemented!(),
-            false => {
-                let writer = create_pyarrow_file_writer(
-                    &self.output_file_info.root_dir,
-                    file_idx,
-                    self.output_file_info.compression.as_ref(),
-                    self.output_file_info.io_config.as_ref(),
-                    self.output_file_info.file_format,
-                    partition_values,
-                )?;
-                Ok(writer)
-            }
-        }
+        create_native_file_writer(
+            &self.output_file_info.root_dir,
+            file_idx,
+            self.output_file_info.compression.as_deref(),
+            self.output_file_info.io_config.as_ref(),
+            self.output_file_info.file_format,
+            partition_values,
+        )
     }
 }
 
-pub fn create_pyarrow_file_writer(
+pub fn create_native_file_writer(
     root_dir: &str,
     file_idx: usize,
-    compression: Option<&String>,
+    compression: Option<&str>,
     io_config: Option<&daft_io::IOConfig>,
     format: FileFormat,
     partition: Option<&RecordBatch>,
 ) -> DaftResult<Box<dyn FileWriter<Input = Arc<MicroPartition>, Result = Option<RecordBatch>>>> {
     match format {
-        #[cfg(feature = "python")]
-        FileFormat::Parquet => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_parquet_writer(
+        FileFormat::Parquet => Ok(Box::new(ParquetWriter::try_new(
             root_dir,
             file_idx,
             compression,
             io_config,
             partition,
         )?)),
-        #[cfg(feature = "python")]
-        FileFormat::Csv => Ok(Box::new(crate::pyarrow::PyArrowWriter::new_csv_writer(
+        FileFormat::Csv => Ok(Box::new(CsvWriter::new(
+            root_dir, file_idx, io_config, partition,
+        ))),
+        FileFormat::Json => Ok(Box::new(JsonWriter::new(
             root_dir, file_idx, io_config, partition,
+        ))),
+        FileFormat::Ipc => Ok(Box::new(IpcWriter::try_new(
+            root_dir,
+            file_idx,
+            compression,
+            io_config,
+            partition,
         )?)),
         _ => Err(DaftError::ComputeError(
             "Unsupported file format for physical write".to_string(),
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::io::ipc::read::{read_file_metadata, FileReader};
+    use common_file_formats::FileFormat;
+    use daft_core::{
+        prelude::{AsArrow, Int64Array, Utf8Array},
+        series::IntoSeries,
+    };
+    use daft_micropartition::MicroPartition;
+    use daft_recordbatch::RecordBatch;
+
+    use super::create_native_file_writer;
+
+    fn make_mp() -> Arc<MicroPartition> {
+        let a = Int64Array::from(("a", vec![1, 2])).into_series();
+        let b = Utf8Array::from(("b", &["x", "y"][..])).into_series();
+        let table = RecordBatch::from_nonempty_columns(vec![a, b]).unwrap();
+        Arc::new(MicroPartition::new_loaded(
+            table.schema.clone(),
+            Arc::new(vec![table]),
+            None,
+        ))
+    }
+
+    /// Writes the test micropartition twice and returns the path of the written file.
+    fn write_file(root_dir: &str, format: FileFormat, partition: Option<&RecordBatch>) -> String {
+        let mut writer =
+            create_native_file_writer(root_dir, 0, None, None, format, partition).unwrap();
+        let first = writer.write(make_mp()).unwrap();
+        let second = writer.write(make_mp()).unwrap();
+        assert!(first > 0 && second > 0);
+        assert_eq!(writer.bytes_written(), first + second);
+
+        let result = writer.close().unwrap().unwrap();
+        let path = result.get_column("path").unwrap().utf8().unwrap();
+        let path = path.as_arrow().value(0).to_string();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().len() as usize,
+            writer.bytes_written()
+        );
+        path
+    }
+
+    #[test]
+    fn test_native_csv_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_file(dir.path().to_str().unwrap(), FileFormat::Csv, None);
+        assert!(path.ends_with("-0.csv"));
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "a,b\n1,x\n2,y\n1,x\n2,y\n"
+        );
+    }
+
+    #[test]
+    fn test_native_json_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_file(dir.path().to_str().unwrap(), FileFormat::Json, None);
+        assert!(path.ends_with("-0.json"));
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "{\"a\":1,\"b\":\"x\"}\n{\"a\":2,\"b\":\"y\"}\n".repeat(2)
+        );
+    }
+
+    #[test]
+    fn test_native_ipc_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_file(dir.path().to_str().unwrap(), FileFormat::Ipc, None);
+        assert!(path.ends_with("-0.arrow"));
+
+        let mut file = std::fs::File::open(path).unwrap();
+        let metadata = read_file_metadata(&mut file).unwrap();
+        let names = metadata
+            .schema
+            .fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "b"]);
+        let num_rows = FileReader::new(file, metadata, None, None)
+            .map(|chunk| chunk.unwrap().len())
+            .collect::<Vec<_>>();
+        assert_eq!(num_rows, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_native_parquet_writer_partitioned() {
+        let dir = tempfile::tempdir().unwrap();
+        let partition =
+            RecordBatch::from_nonempty_columns(vec![
+                Utf8Array::from(("part", &["p"][..])).into_series()
+            ])
+            .unwrap();
+        let path = write_file(
+            dir.path().to_str().unwrap(),
+            FileFormat::Parquet,
+            Some(&partition),
+        );
+        let file_name = std::path::Path::new(&path);
+        assert_eq!(file_name.parent().unwrap(), dir.path().join("part=p"));
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"PAR1") && bytes.ends_with(b"PAR1"));
+    }
+
+    #[test]
+    fn test_native_writer_encodes_partition_values() {
+        let dir = tempfile::tempdir().unwrap();
+        let partition =
+            RecordBatch::from_nonempty_columns(vec![
+                Utf8Array::from(("part", &["a/b c=d"][..])).into_series()
+            ])
+            .unwrap();
+        let path = write_file(
+            dir.path().to_str().unwrap(),
+            FileFormat::Csv,
+            Some(&partition),
+        );
+        let file_name = std::path::Path::new(&path);
+        assert_eq!(
+            file_name.parent().unwrap(),
+            dir.path().join("part=a%2Fb%20c%3Dd")
+        );
+    }
+
+    #[test]
+    fn test_native_writer_without_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut writer = create_native_file_writer(
+            dir.path().to_str().unwrap(),
+            0,
+            None,
+            None,
+            FileFormat::Parquet,
+            None,
+        )
+        .unwrap();
+        assert!(writer.close().unwrap().is_none());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
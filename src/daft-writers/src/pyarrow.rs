@@ -14,81 +14,6 @@ pub struct PyArrowWriter {
 }
 
 impl PyArrowWriter {
-    pub fn new_parquet_writer(
-        root_dir: &str,
-        file_idx: usize,
-        compression: Option<&String>,
-        io_config: Option<&daft_io::IOConfig>,
-        partition_values: Option<&RecordBatch>,
-    ) -> DaftResult<Self> {
-        Python::with_gil(|py| {
-            let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
-            let file_writer_class = file_writer_module.getattr("ParquetFileWriter")?;
-            let _from_pytable = py
-                .import(pyo3::intern!(py, "daft.recordbatch"))?
-                .getattr(pyo3::intern!(py, "RecordBatch"))?
-                .getattr(pyo3::intern!(py, "_from_pytable"))?;
-            let partition_values = match partition_values {
-                Some(pv) => {
-                    let py_table = _from_pytable.call1((PyRecordBatch::from(pv.clone()),))?;
-                    Some(py_table)
-                }
-                None => None,
-            };
-
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                compression.map(|c| c.as_str()),
-                io_config.map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
-            Ok(Self {
-                py_writer: py_writer.into(),
-                is_closed: false,
-                bytes_written: 0,
-            })
-        })
-    }
-
-    pub fn new_csv_writer(
-        root_dir: &str,
-        file_idx: usize,
-        io_config: Option<&daft_io::IOConfig>,
-        partition_values: Option<&RecordBatch>,
-    ) -> DaftResult<Self> {
-        Python::with_gil(|py| {
-            let file_writer_module = py.import(pyo3::intern!(py, "daft.io.writer"))?;
-            let file_writer_class = file_writer_module.getattr("CSVFileWriter")?;
-            let _from_pytable = py
-                .import(pyo3::intern!(py, "daft.recordbatch"))?
-                .getattr(pyo3::intern!(py, "RecordBatch"))?
-                .getattr(pyo3::intern!(py, "_from_pytable"))?;
-            let partition_values = match partition_values {
-                Some(pv) => {
-                    let py_table = _from_pytable.call1((PyRecordBatch::from(pv.clone()),))?;
-                    Some(py_table)
-                }
-                None => None,
-            };
-            let py_writer = file_writer_class.call1((
-                root_dir,
-                file_idx,
-                partition_values,
-                io_config.map(|cfg| daft_io::python::IOConfig {
-                    config: cfg.clone(),
-                }),
-            ))?;
-            Ok(Self {
-                py_writer: py_writer.into(),
-                is_closed: false,
-                bytes_written: 0,
-            })
-        })
-    }
-
     pub fn new_iceberg_writer(
         root_dir: &str,
         file_idx: usize,
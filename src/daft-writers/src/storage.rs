@@ -0,0 +1,180 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use common_error::{DaftError, DaftResult};
+use common_runtime::get_io_runtime;
+use daft_core::{prelude::Utf8Array, series::IntoSeries};
+use daft_io::{get_io_client, parse_url, IOConfig, MultipartWriter, SourceType};
+use daft_recordbatch::RecordBatch;
+
+const DEFAULT_PARTITION_FALLBACK: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Builds the path of a new output file, i.e. `{root_dir}/{partition dirs}/{uuid}-{file_idx}.{extension}`,
+/// where the partition dirs are hive-style `key=value` directories for each partition value.
+/// Values are percent-encoded, which hive-style partition parsing decodes when the files are read.
+pub(crate) fn build_file_path(
+    root_dir: &str,
+    partition_values: Option<&RecordBatch>,
+    file_idx: usize,
+    extension: &str,
+) -> DaftResult<String> {
+    let mut dir = root_dir.trim_end_matches('/').to_string();
+    if let Some(partition_values) = partition_values {
+        for (idx, name) in partition_values.column_names().iter().enumerate() {
+            let value = partition_values.get_column_by_index(idx)?.to_str_values()?;
+            let value = match value.utf8()?.get(0) {
+                Some(value) => urlencoding::encode(value),
+                None => DEFAULT_PARTITION_FALLBACK.into(),
+            };
+            dir = format!("{dir}/{name}={value}");
+        }
+    }
+    Ok(format!(
+        "{dir}/{}-{file_idx}.{extension}",
+        uuid::Uuid::new_v4()
+    ))
+}
+
+/// Builds the result of a written file, i.e. its path and the partition values of its data.
+pub(crate) fn make_file_metadata(
+    path: &str,
+    partition_values: Option<&RecordBatch>,
+) -> DaftResult<RecordBatch> {
+    let path_series = Utf8Array::from_values("path", std::iter::once(path)).into_series();
+    let mut columns = vec![path_series];
+    if let Some(partition_values) = partition_values {
+        for idx in 0..partition_values.num_columns() {
+            columns.push(partition_values.get_column_by_index(idx)?.clone());
+        }
+    }
+    RecordBatch::from_nonempty_columns(columns)
+}
+
+enum OutputTarget {
+    Local(BufWriter<File>),
+    // Object stores are written to in parts as they fill up. The writer is only missing after an upload failed.
+    Remote(Option<MultipartWriter>),
+}
+
+/// A file being written to local disk or an object store.
+///
+/// Format writers take ownership of the file they write to, so the number of bytes written
+/// is shared through a counter that stays accessible to the writer's owner.
+pub(crate) struct OutputFile {
+    path: String,
+    target: OutputTarget,
+    bytes_written: Arc<AtomicUsize>,
+}
+
+impl OutputFile {
+    pub fn try_new(path: String, io_config: Option<&IOConfig>) -> DaftResult<Self> {
+        let (source_type, url) = parse_url(&path)?;
+        let (path, target) = match source_type {
+            SourceType::File => {
+                let local_path = url.trim_start_matches("file://");
+                let local_path = std::path::absolute(local_path)?;
+                if let Some(parent) = local_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let file = File::create(&local_path)?;
+                (
+                    local_path.to_string_lossy().to_string(),
+                    OutputTarget::Local(BufWriter::new(file)),
+                )
+            }
+            _ => {
+                let url = url.to_string();
+                let io_client =
+                    get_io_client(true, Arc::new(io_config.cloned().unwrap_or_default()))?;
+                let writer = {
+                    let url = url.clone();
+                    get_io_runtime(true).block_on(async move {
+                        io_client
+                            .multipart_writer(&url, None)
+                            .await
+                            .map_err(DaftError::from)
+                    })??
+                };
+                (url, OutputTarget::Remote(Some(writer)))
+            }
+        };
+        Ok(Self {
+            path,
+            target,
+            bytes_written: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// The path of the file, made absolute for local files.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn bytes_written_counter(&self) -> Arc<AtomicUsize> {
+        self.bytes_written.clone()
+    }
+
+    /// Flushes the file, completing its upload if it is written to an object store.
+    pub fn finish(self) -> DaftResult<()> {
+        match self.target {
+            OutputTarget::Local(mut writer) => {
+                writer.flush()?;
+                Ok(())
+            }
+            OutputTarget::Remote(writer) => {
+                let writer = writer.ok_or_else(|| upload_failed_error(&self.path))?;
+                get_io_runtime(true)
+                    .block_on(async move { writer.finish().await.map_err(DaftError::from) })?
+            }
+        }
+    }
+}
+
+fn upload_failed_error(path: &str) -> DaftError {
+    DaftError::InternalError(format!("The upload of {path} already failed"))
+}
+
+impl Write for OutputFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match &mut self.target {
+            OutputTarget::Local(writer) => {
+                let written = writer.write(buf)?;
+                self.bytes_written.fetch_add(written, Ordering::Relaxed);
+                Ok(written)
+            }
+            OutputTarget::Remote(writer) => {
+                let Some(mut part_writer) = writer.take() else {
+                    return Err(std::io::Error::other(upload_failed_error(&self.path)));
+                };
+                if part_writer.write(buf) {
+                    part_writer = get_io_runtime(true)
+                        .block_on(async move {
+                            part_writer
+                                .upload_part()
+                                .await
+                                .map(|()| part_writer)
+                                .map_err(DaftError::from)
+                        })
+                        .and_then(|result| result)
+                        .map_err(std::io::Error::other)?;
+                }
+                *writer = Some(part_writer);
+                self.bytes_written.fetch_add(buf.len(), Ordering::Relaxed);
+                Ok(buf.len())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.target {
+            OutputTarget::Local(writer) => writer.flush(),
+            OutputTarget::Remote(_) => Ok(()),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use parquet_format_safe::{
     thrift::protocol::TCompactInputProtocol, BloomFilterAlgorithm, BloomFilterCompression,
@@ -47,3 +47,25 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the [`BloomFilterHeader`] at the start of `data`, e.g. when the filter is fetched
+/// with range requests instead of through a [`Read`]er.
+/// Returns the length of the header and the length of the bitset that follows it, or `None` if the
+/// algorithm or compression of the filter is not supported.
+/// # Error
+/// Errors if the header can't be deserialized, e.g. because `data` is too short to contain it.
+pub fn read_header(data: &[u8]) -> Result<Option<(usize, usize)>, Error> {
+    let mut reader = Cursor::new(data);
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(None);
+    }
+
+    let header_length = reader.position().try_into()?;
+    let length = header.num_bytes.try_into()?;
+    Ok(Some((header_length, length)))
+}
@@ -5,8 +5,15 @@ use crate::schema::types::{PhysicalType, PrimitiveType};
 
 use crate::indexes::{BooleanIndex, ByteIndex, FixedLenByteIndex, Index, NativeIndex};
 
+/// The maximum number of bytes that deserializing the index in `data` may allocate.
+/// Indexes hold one small item per page, e.g. a min and max value or a page location, whose
+/// in-memory size (at least the size of a `Vec`) can be many times its encoded size.
+pub(super) fn max_index_allocation(data: &[u8]) -> usize {
+    data.len() * 32 + 1024
+}
+
 pub fn deserialize(data: &[u8], primitive_type: PrimitiveType) -> Result<Box<dyn Index>, Error> {
-    let mut prot = TCompactInputProtocol::new(data, data.len() * 2 + 1024);
+    let mut prot = TCompactInputProtocol::new(data, max_index_allocation(data));
 
     let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
 
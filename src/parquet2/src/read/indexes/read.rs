@@ -7,7 +7,7 @@ use crate::error::Error;
 use crate::indexes::Index;
 use crate::metadata::ColumnChunkMetaData;
 
-use super::deserialize::deserialize;
+use super::deserialize::{deserialize, max_index_allocation};
 
 fn prepare_read<F: Fn(&ColumnChunk) -> Option<i64>, G: Fn(&ColumnChunk) -> Option<i32>>(
     chunks: &[ColumnChunkMetaData],
@@ -99,7 +99,7 @@ fn deserialize_page_locations(
     data: &[u8],
     column_number: usize,
 ) -> Result<Vec<Vec<PageLocation>>, Error> {
-    let len = data.len() * 2 + 1024;
+    let len = max_index_allocation(data);
     let mut reader = Cursor::new(data);
 
     (0..column_number)
@@ -16,7 +16,8 @@ pub use metadata::{deserialize_metadata, read_metadata, read_metadata_with_size}
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub use page::{
-    get_owned_page_stream_from_column_start, get_page_stream, get_page_stream_from_column_start,
+    get_owned_page_stream_from_column_start, get_owned_page_stream_from_selected_pages,
+    get_page_stream, get_page_stream_from_column_start,
 };
 pub use page::{IndexedPageReader, PageFilter, PageIterator, PageMetaData, PageReader};
 
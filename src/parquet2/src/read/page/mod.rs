@@ -15,5 +15,6 @@ pub trait PageIterator: Iterator<Item = Result<CompressedPage, Error>> {
 #[cfg(feature = "async")]
 #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
 pub use stream::{
-    get_owned_page_stream_from_column_start, get_page_stream, get_page_stream_from_column_start,
+    get_owned_page_stream_from_column_start, get_owned_page_stream_from_selected_pages,
+    get_page_stream, get_page_stream_from_column_start,
 };
@@ -7,6 +7,7 @@ use parquet_format_safe::thrift::protocol::TCompactInputStreamProtocol;
 
 use crate::compression::Compression;
 use crate::error::{Error, Result};
+use crate::indexes::FilteredPage;
 use crate::metadata::{ColumnChunkMetaData, Descriptor};
 use crate::page::{CompressedPage, ParquetPageHeader};
 
@@ -70,6 +71,44 @@ pub fn get_owned_page_stream_from_column_start<R: AsyncRead + Unpin + Send>(
     ))
 }
 
+/// Returns a stream of the compressed pages of `pages` that have selected rows, from a reader that
+/// holds the dictionary page of the column, if any, followed by those pages back to back.
+/// Each data page is yielded with its [`crate::page::CompressedDataPage::selected_rows()`].
+pub fn get_owned_page_stream_from_selected_pages<R: AsyncRead + Unpin + Send>(
+    column_metadata: &ColumnChunkMetaData,
+    mut reader: R,
+    pages: Vec<FilteredPage>,
+    mut scratch: Vec<u8>,
+    max_page_size: usize,
+) -> Result<impl Stream<Item = Result<CompressedPage>>> {
+    let page_metadata: PageMetaData = column_metadata.into();
+    // a dictionary page exists iff the first data page is not at the start of the column
+    let has_dict = pages
+        .first()
+        .is_some_and(|page| page.start > page_metadata.column_start);
+    let compression = page_metadata.compression;
+    let descriptor = page_metadata.descriptor;
+    Ok(try_stream! {
+        let reader_ref = &mut reader;
+        if has_dict {
+            let page_header = read_page_header(reader_ref, max_page_size).await?;
+            read_page_data(reader_ref, &page_header, &mut scratch, max_page_size).await?;
+            yield finish_page(page_header, &mut scratch, compression, &descriptor, None)?;
+        }
+        for page in pages.into_iter().filter(|page| !page.selected_rows.is_empty()) {
+            let page_header = read_page_header(reader_ref, max_page_size).await?;
+            read_page_data(reader_ref, &page_header, &mut scratch, max_page_size).await?;
+            yield finish_page(
+                page_header,
+                &mut scratch,
+                compression,
+                &descriptor,
+                Some(page.selected_rows),
+            )?;
+        }
+    })
+}
+
 /// Returns a stream of compressed data pages with [`PageMetaData`]
 pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + AsyncSeek>(
     page_metadata: PageMetaData,
@@ -204,6 +243,28 @@ fn _get_owned_page_stream<R: AsyncRead + Unpin + Send>(
     }
 }
 
+/// Reads the data of the page of `page_header` into `scratch`.
+async fn read_page_data<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+    page_header: &ParquetPageHeader,
+    scratch: &mut Vec<u8>,
+    max_page_size: usize,
+) -> Result<()> {
+    let read_size: usize = page_header.compressed_page_size.try_into()?;
+    if read_size > max_page_size {
+        return Err(Error::WouldOverAllocate);
+    }
+    scratch.clear();
+    scratch.try_reserve(read_size)?;
+    let bytes_read = reader.take(read_size as u64).read_to_end(scratch).await?;
+    if bytes_read != read_size {
+        return Err(Error::oos(
+            "The page header reported the wrong page size".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Reads Page header from Thrift.
 async fn read_page_header<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
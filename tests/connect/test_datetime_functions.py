@@ -0,0 +1,35 @@
+from __future__ import annotations
+
+import datetime
+
+from pyspark.sql import Row
+from pyspark.sql import functions as F
+
+
+def test_datetime_functions(spark_session):
+    data = [(datetime.date(2024, 1, 31), datetime.date(2024, 3, 1), 1719964800)]
+    df = spark_session.createDataFrame(data, ["start", "end", "epoch"])
+
+    df = df.select(
+        F.add_months("start", 1).alias("next_month"),
+        F.datediff("end", "start").alias("days"),
+        F.dayofyear("start").alias("doy"),
+        F.weekofyear("start").alias("week"),
+        F.quarter("end").alias("quarter"),
+        F.date_format("start", "dd/MM/yyyy").alias("formatted"),
+        F.from_unixtime("epoch").alias("from_unixtime"),
+        F.timestamp_seconds("epoch").alias("timestamp"),
+    )
+
+    assert df.collect() == [
+        Row(
+            next_month=datetime.date(2024, 2, 29),
+            days=30,
+            doy=31,
+            week=5,
+            quarter=1,
+            formatted="31/01/2024",
+            from_unixtime="2024-07-03 00:00:00",
+            timestamp=datetime.datetime(2024, 7, 3),
+        )
+    ]
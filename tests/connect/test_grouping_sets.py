@@ -0,0 +1,50 @@
+from __future__ import annotations
+
+from pyspark.sql import Row
+from pyspark.sql import functions as F
+
+
+def make_df(spark_session):
+    return spark_session.createDataFrame([("x", 1, 1), ("x", 2, 2), ("y", 1, 3)], ["a", "b", "v"])
+
+
+def test_rollup(spark_session):
+    df = make_df(spark_session)
+
+    rolled = df.rollup("a", "b").agg(F.sum("v").alias("s"), F.grouping_id().alias("gid")).sort("gid", "a", "b")
+    assert rolled.collect() == [
+        Row(a="x", b=1, s=1, gid=0),
+        Row(a="x", b=2, s=2, gid=0),
+        Row(a="y", b=1, s=3, gid=0),
+        Row(a="x", b=None, s=3, gid=1),
+        Row(a="y", b=None, s=3, gid=1),
+        Row(a=None, b=None, s=6, gid=3),
+    ]
+
+
+def test_cube(spark_session):
+    df = make_df(spark_session)
+
+    cubed = df.cube("a", "b").agg(F.sum("v").alias("s"), F.grouping("a").alias("ga"))
+    rows = [(row.a, row.b, row.s, row.ga) for row in cubed.collect()]
+    assert sorted(rows, key=repr) == sorted(
+        [
+            ("x", 1, 1, 0),
+            ("x", 2, 2, 0),
+            ("y", 1, 3, 0),
+            ("x", None, 3, 0),
+            ("y", None, 3, 0),
+            (None, 1, 4, 1),
+            (None, 2, 2, 1),
+            (None, None, 6, 1),
+        ],
+        key=repr,
+    )
+
+
+def test_pivot(spark_session):
+    df = make_df(spark_session)
+
+    pivoted = df.groupBy("a").pivot("b", [1, 2]).sum("v").sort("a")
+    assert pivoted.columns == ["a", "1", "2"]
+    assert [tuple(row) for row in pivoted.collect()] == [("x", 1, 2), ("y", 3, None)]
@@ -0,0 +1,40 @@
+from __future__ import annotations
+
+from pyspark.sql import Row
+
+
+def test_fill_na(spark_session):
+    df = spark_session.createDataFrame(
+        [(1, None, "a"), (None, 2.0, None)],
+        "a long, b double, c string",
+    )
+
+    # numbers only fill numeric columns
+    assert df.na.fill(0).sort("c").collect() == [
+        Row(a=0, b=2.0, c=None),
+        Row(a=1, b=0.0, c="a"),
+    ]
+    # strings only fill string columns
+    assert df.fillna("x").sort("c").collect() == [
+        Row(a=1, b=None, c="a"),
+        Row(a=None, b=2.0, c="x"),
+    ]
+
+
+def test_fill_na_subset(spark_session):
+    df = spark_session.createDataFrame([(None, None)], "a long, b long")
+
+    assert df.na.fill(5, subset=["a"]).collect() == [Row(a=5, b=None)]
+    assert df.na.fill({"a": 1, "b": 2}).collect() == [Row(a=1, b=2)]
+
+
+def test_drop_na(spark_session):
+    df = spark_session.createDataFrame(
+        [(1, 1.0), (None, 2.0), (None, None), (4, float("nan"))],
+        "a long, b double",
+    )
+
+    assert df.na.drop().collect() == [Row(a=1, b=1.0)]
+    assert df.na.drop(how="all").count() == 3
+    assert df.dropna(subset=["a"]).count() == 2
+    assert df.dropna(thresh=1).count() == 3
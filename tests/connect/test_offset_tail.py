@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+from pyspark.sql import Row
+
+
+def test_offset(spark_session):
+    df = spark_session.range(10)
+
+    assert df.offset(7).collect() == [Row(id=7), Row(id=8), Row(id=9)]
+    assert df.offset(10).collect() == []
+
+
+def test_offset_after_limit(spark_session):
+    df = spark_session.range(10).limit(5).offset(2)
+
+    assert df.collect() == [Row(id=2), Row(id=3), Row(id=4)]
+
+
+def test_tail(spark_session):
+    df = spark_session.range(10)
+
+    assert df.tail(3) == [Row(id=7), Row(id=8), Row(id=9)]
+    assert len(df.tail(20)) == 10
+
+
+def test_tail_after_filter(spark_session):
+    df = spark_session.range(10).filter("id % 2 = 0")
+
+    assert df.tail(2) == [Row(id=6), Row(id=8)]
+    assert df.tail(0) == []
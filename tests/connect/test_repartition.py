@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+
+def test_repartition(spark_session):
+    df = spark_session.range(10)
+
+    repartitioned = df.repartition(3)
+    assert sorted(row.id for row in repartitioned.collect()) == list(range(10))
+
+
+def test_repartition_by_column(spark_session):
+    df = spark_session.createDataFrame([(1, "a"), (2, "b"), (3, "a")], ["id", "key"])
+
+    repartitioned = df.repartition(2, "key")
+    assert sorted(row.id for row in repartitioned.collect()) == [1, 2, 3]
+
+
+def test_coalesce(spark_session):
+    df = spark_session.range(10).repartition(4).coalesce(1)
+
+    assert sorted(row.id for row in df.collect()) == list(range(10))
+
+
+def test_repartition_by_range(spark_session):
+    df = spark_session.range(10)
+
+    repartitioned = df.repartitionByRange(3, "id")
+    assert sorted(row.id for row in repartitioned.collect()) == list(range(10))
+
+    repartitioned = df.repartitionByRange(2, df.id.desc())
+    assert sorted(row.id for row in repartitioned.collect()) == list(range(10))
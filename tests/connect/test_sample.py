@@ -0,0 +1,28 @@
+from __future__ import annotations
+
+
+def test_sample(spark_session):
+    df = spark_session.range(100)
+
+    sampled = df.sample(fraction=0.5, seed=42)
+    count = sampled.count()
+    assert 0 < count < 100, "Sample should keep some but not all rows"
+
+    # the same seed yields the same sample
+    assert sorted(sampled.collect()) == sorted(df.sample(fraction=0.5, seed=42).collect())
+
+
+def test_sample_with_replacement(spark_session):
+    df = spark_session.range(10)
+
+    sampled = df.sample(withReplacement=True, fraction=1.0, seed=0).collect()
+    assert all(0 <= row.id < 10 for row in sampled), "Sample should only contain rows of the input"
+
+
+def test_random_split(spark_session):
+    df = spark_session.range(100)
+
+    splits = df.randomSplit([0.3, 0.7], seed=42)
+    ids = [sorted(row.id for row in split.collect()) for split in splits]
+    assert all(ids), "Each split should keep some rows"
+    assert sorted(ids[0] + ids[1]) == list(range(100)), "The splits should partition the rows"
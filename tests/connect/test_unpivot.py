@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from pyspark.sql import Row
+
+
+def test_unpivot(spark_session):
+    df = spark_session.createDataFrame([(1, 11, 12), (2, 21, 22)], ["id", "x", "y"])
+
+    unpivoted = df.unpivot("id", ["x", "y"], "variable", "value").sort("id", "variable")
+    assert unpivoted.collect() == [
+        Row(id=1, variable="x", value=11),
+        Row(id=1, variable="y", value=12),
+        Row(id=2, variable="x", value=21),
+        Row(id=2, variable="y", value=22),
+    ]
+
+
+def test_unpivot_all_values(spark_session):
+    df = spark_session.createDataFrame([(1, 11, 12)], ["id", "x", "y"])
+
+    # without values, every non-id column is unpivoted
+    unpivoted = df.melt("id", None, "variable", "value").sort("variable")
+    assert unpivoted.collect() == [
+        Row(id=1, variable="x", value=11),
+        Row(id=1, variable="y", value=12),
+    ]
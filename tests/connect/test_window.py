@@ -0,0 +1,25 @@
+from __future__ import annotations
+
+from pyspark.sql import Row, Window
+from pyspark.sql import functions as F
+
+
+def test_window_functions(spark_session):
+    data = [("g1", 1), ("g1", 2), ("g2", 3), ("g1", 100)]
+    df = spark_session.createDataFrame(data, ["text", "n"])
+
+    window = Window.partitionBy("text").orderBy("n")
+    df = df.select(
+        "text",
+        "n",
+        F.sum("n").over(window).alias("running_sum"),
+        F.row_number().over(window).alias("rn"),
+        F.lag("n", 1).over(window).alias("prev"),
+    ).sort("text", "n")
+
+    assert df.collect() == [
+        Row(text="g1", n=1, running_sum=1, rn=1, prev=None),
+        Row(text="g1", n=2, running_sum=3, rn=2, prev=1),
+        Row(text="g1", n=100, running_sum=103, rn=3, prev=2),
+        Row(text="g2", n=3, running_sum=3, rn=1, prev=None),
+    ]
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import contextlib
 import csv
+import datetime
 import decimal
 import json
 import os
@@ -519,6 +520,62 @@ def test_create_dataframe_csv_custom_delimiter(valid_data: list[dict[str, float]
         assert len(pd_df) == len(valid_data)
 
 
+@pytest.mark.parametrize("encoding", ["latin-1", "utf-16"])
+def test_create_dataframe_csv_encoding_multi_byte_delimiter_skip_rows(encoding) -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w", encoding=encoding, newline="") as f:
+            f.write("# exported by the légacy system\n")
+            f.write("# 2 rows\n")
+            f.write("name||city||score\n")
+            f.write('José||"São||Paulo"||1.5\n')
+            f.write("Zoë||Zürich||2\n")
+
+        df = daft.read_csv(fname, delimiter="||", encoding=encoding, skip_rows=2)
+        assert df.to_pydict() == {
+            "name": ["José", "Zoë"],
+            "city": ["São||Paulo", "Zürich"],
+            "score": [1.5, 2.0],
+        }
+
+
+def test_create_dataframe_csv_null_values_datetime_formats() -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write("id,name,born,seen\n")
+            f.write("1,alice,03/01/2024,2024/01/03 10:11:12\n")
+            f.write("NA,\\N,NA,NA\n")
+
+        df = daft.read_csv(
+            fname,
+            null_values=["NA", "\\N"],
+            datetime_formats={"born": "%d/%m/%Y", "seen": "%Y/%m/%d %H:%M:%S"},
+        )
+        assert df.schema()["id"].dtype == DataType.int64()
+        assert df.schema()["born"].dtype == DataType.date()
+        assert df.schema()["seen"].dtype == DataType.timestamp("s")
+        assert df.to_pydict() == {
+            "id": [1, None],
+            "name": ["alice", None],
+            "born": [datetime.date(2024, 1, 3), None],
+            "seen": [datetime.datetime(2024, 1, 3, 10, 11, 12), None],
+        }
+
+
+def test_create_dataframe_csv_skip_rows_after_header_truncate_ragged_lines() -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            f.write("id,name\n")
+            f.write("--,--\n")
+            f.write("1,alice\n")
+            f.write("2,bob,extra\n")
+
+        df = daft.read_csv(fname, skip_rows_after_header=1, truncate_ragged_lines=True)
+        assert df.to_pydict() == {"id": [1, 2], "name": ["alice", "bob"]}
+
+        with pytest.raises(Exception, match="found record with 3 fields"):
+            daft.read_csv(fname, skip_rows_after_header=1).collect()
+
+
 def test_create_dataframe_csv_provided_schema(valid_data: list[dict[str, float]]) -> None:
     with create_temp_filename() as fname:
         with open(fname, "w") as f:
@@ -696,6 +753,26 @@ def test_create_dataframe_json(valid_data: list[dict[str, float]]) -> None:
         assert len(pd_df) == len(valid_data)
 
 
+@pytest.mark.parametrize("layout", ["array", "pretty_array", "pretty_objects"])
+def test_create_dataframe_json_documents(valid_data: list[dict[str, float]], layout: str) -> None:
+    with create_temp_filename() as fname:
+        with open(fname, "w") as f:
+            if layout == "array":
+                json.dump(valid_data, f)
+            elif layout == "pretty_array":
+                json.dump(valid_data, f, indent=2)
+            else:
+                for data in valid_data:
+                    f.write(json.dumps(data, indent=2))
+                    f.write("\n")
+            f.flush()
+
+        df = daft.read_json(fname)
+        assert df.column_names == COL_NAMES
+
+        assert df.to_pydict() == {col: [data[col] for data in valid_data] for col in COL_NAMES}
+
+
 def test_create_dataframe_multiple_jsons(valid_data: list[dict[str, float]]) -> None:
     with create_temp_filename() as f1name, create_temp_filename() as f2name:
         with open(f1name, "w") as f1, open(f2name, "w") as f2:
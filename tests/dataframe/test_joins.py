@@ -1,5 +1,8 @@
 from __future__ import annotations
 
+import io
+import re
+
 import pyarrow as pa
 import pytest
 
@@ -11,14 +14,13 @@ from tests.utils import sort_arrow_table
 
 
 def skip_invalid_join_strategies(join_strategy, join_type):
+    if (join_strategy == "sort_merge" or join_strategy == "sort_merge_aligned_boundaries") and join_type != "inner":
+        pytest.skip("Sort merge currently only supports inner joins")
     if get_tests_daft_runner_name() == "native":
-        if join_strategy not in [None, "hash"]:
+        if join_strategy not in [None, "hash", "sort_merge"]:
             pytest.skip("Native executor fails for these tests")
-    else:
-        if (join_strategy == "sort_merge" or join_strategy == "sort_merge_aligned_boundaries") and join_type != "inner":
-            pytest.skip("Sort merge currently only supports inner joins")
-        elif join_strategy == "broadcast" and join_type == "outer":
-            pytest.skip("Broadcast join does not support outer joins")
+    elif join_strategy == "broadcast" and join_type == "outer":
+        pytest.skip("Broadcast join does not support outer joins")
 
 
 def test_invalid_join_strategies(make_df):
@@ -1356,3 +1358,136 @@ def test_join_different_join_key_types(join_type, expected, repartition_nparts,
     assert sort_arrow_table(pa.Table.from_pydict(result.to_pydict()), *sort_by) == sort_arrow_table(
         pa.Table.from_pydict(expected), *sort_by
     )
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="As-of joins are only supported on the native runner"
+)
+@pytest.mark.parametrize(
+    "direction,expected",
+    [
+        ("backward", [None, 40, 30, 40]),
+        ("forward", [20, None, 30, None]),
+        ("nearest", [20, 40, 30, 40]),
+    ],
+)
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_join_asof(direction, expected, repartition_nparts, make_df):
+    left_df = make_df(
+        {"id": [0, 1, 2, 3], "g": ["a", "a", "b", "a"], "t": [1, 5, 3, 10]},
+        repartition=repartition_nparts,
+    )
+    right_df = make_df(
+        {"g": ["a", "a", "b", None], "t": [4, 2, 3, 3], "v": [40, 20, 30, 50]},
+        repartition=repartition_nparts,
+    )
+
+    result = left_df.join_asof(right_df, on="t", by="g", direction=direction).sort("id").to_pydict()
+    assert result == {"g": ["a", "a", "b", "a"], "t": [1, 5, 3, 10], "id": [0, 1, 2, 3], "v": expected}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="As-of joins are only supported on the native runner"
+)
+def test_join_asof_with_tolerance(make_df):
+    import datetime
+
+    ts = [datetime.datetime(2024, 1, 1, 0, minute) for minute in [0, 5, 10]]
+    left_df = make_df({"time": ts})
+    right_df = make_df({"quote_time": [ts[0] - datetime.timedelta(minutes=1), ts[1]], "price": [1.0, 2.0]})
+
+    result = left_df.join_asof(
+        right_df, left_on="time", right_on="quote_time", tolerance=datetime.timedelta(minutes=2)
+    ).sort("time")
+    assert result.to_pydict()["price"] == [1.0, 2.0, None]
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="As-of joins are only supported on the native runner"
+)
+def test_join_asof_invalid_arguments(make_df):
+    df = make_df({"t": [1, 2, 3], "s": ["a", "b", "c"]})
+
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="t", left_on="t")
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="t", by="s", left_by="s")
+    with pytest.raises(ValueError):
+        df.join_asof(df, on="t", direction="sideways")
+    with pytest.raises(Exception):
+        df.join_asof(df, on="s")
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streaming sort-merge joins are only supported on the native runner"
+)
+def test_sort_merge_join_sorted_inputs(make_df, with_morsel_size):
+    left_df = make_df({"A": [1, 1, 2, 4, 5, 5, 7, None], "B": ["a", "b", "c", "d", "e", "f", "g", "h"]})
+    right_df = make_df({"A": [0, 1, 2, 2, 5, 5, 5, 6, 7, None], "C": list(range(10))})
+
+    joined = left_df.join(right_df, on="A", strategy="sort_merge").to_pydict()
+    # The join streams both sides in the order of the join keys.
+    assert joined["A"] == sorted(joined["A"])
+
+    joined = left_df.join(right_df, on="A", strategy="sort_merge").sort(["A", "B", "C"]).to_pydict()
+    assert joined == {
+        "A": [1, 1, 2, 2, 5, 5, 5, 5, 5, 5, 7],
+        "B": ["a", "b", "c", "c", "e", "e", "e", "f", "f", "f", "g"],
+        "C": [1, 1, 2, 3, 4, 5, 6, 4, 5, 6, 8],
+    }
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streaming sort-merge joins are only supported on the native runner"
+)
+def test_sort_merge_join_after_sort(make_df, with_morsel_size):
+    left_df = make_df({"A": [3, None, 1, 2, 1], "B": ["a", "b", "c", "d", "e"]}).sort("A")
+    right_df = make_df({"A": [2, 1, 3, 4], "C": [20, 10, 30, 40]}).sort("A")
+
+    joined = left_df.join(right_df, on="A", strategy="sort_merge").sort(["A", "B"]).to_pydict()
+    assert joined == {"A": [1, 1, 2, 3], "B": ["c", "e", "d", "a"], "C": [10, 10, 20, 30]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streaming sort-merge joins are only supported on the native runner"
+)
+def test_sort_merge_join_unsorted_inputs(make_df, with_morsel_size):
+    left_df = make_df({"A": [2, None, 1, 3, 1], "B": ["a", "b", "c", "d", "e"]})
+    right_df = make_df({"A": [3, 1, 2, None], "C": [30, 10, 20, 0]})
+
+    # Both sides are sorted by the join keys before they are merged.
+    joined = left_df.join(right_df, on="A", strategy="sort_merge").to_pydict()
+    assert joined["A"] == [1, 1, 2, 3]
+
+    joined = left_df.join(right_df, on="A", strategy="sort_merge").sort(["A", "B"]).to_pydict()
+    assert joined == {"A": [1, 1, 2, 3], "B": ["c", "e", "a", "d"], "C": [10, 10, 20, 30]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streaming sort-merge joins are only supported on the native runner"
+)
+def test_sort_merge_join_presorted_scans(make_df, with_morsel_size):
+    left_df = make_df({"A": [1, 1, 2, 3, None], "B": ["a", "b", "c", "d", "e"]})
+    right_df = make_df({"A": [1, 2, 4, None], "C": [10, 20, 40, 0]})
+
+    with daft.execution_config_ctx(sort_merge_join_presorted_scans=True):
+        joined = left_df.join(right_df, on="A", strategy="sort_merge")
+
+        # The scans are merged as they are read, without sorting them first.
+        plan = io.StringIO()
+        joined.explain(show_all=True, file=plan)
+        assert re.search(r"\bSort\b", plan.getvalue()) is None
+
+        assert joined.sort(["A", "B"]).to_pydict() == {"A": [1, 1, 2], "B": ["a", "b", "c"], "C": [10, 10, 20]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Streaming sort-merge joins are only supported on the native runner"
+)
+def test_sort_merge_join_presorted_scans_checks_order(make_df):
+    left_df = make_df({"A": [2, 1, 3], "B": ["a", "b", "c"]})
+    right_df = make_df({"A": [1, 2, 3], "C": [10, 20, 30]})
+
+    with daft.execution_config_ctx(sort_merge_join_presorted_scans=True):
+        with pytest.raises(Exception, match="sorted by the join keys"):
+            left_df.join(right_df, on="A", strategy="sort_merge").collect()
@@ -280,6 +280,28 @@ def test_sort_desc_nulls_first(make_df):
     assert result["A"] == [None, None, 3, 2, 1]
 
 
+def test_sort_desc_nulls_last(make_df):
+    df = make_df({"A": [1, None, 3, None, 2]})
+
+    result = df.sort("A", desc=True, nulls_first=False).to_pydict()
+    assert result["A"] == [3, 2, 1, None, None]
+
+
+@pytest.mark.parametrize("desc,nulls_first", [(False, False), (False, True), (True, False), (True, True)])
+@pytest.mark.parametrize("limit", [0, 1, 3, 10])
+@pytest.mark.parametrize("repartition_nparts", [1, 3])
+def test_sort_then_limit(make_df, repartition_nparts, with_morsel_size, desc, nulls_first, limit):
+    data = [5, None, 3, 8, 1, None, 9, 2]
+    df = make_df({"A": data, "B": [str(i) for i in range(len(data))]}, repartition=repartition_nparts)
+    result = df.sort("A", desc=desc, nulls_first=nulls_first).limit(limit).to_pydict()
+
+    values = sorted((v for v in data if v is not None), reverse=desc)
+    nulls = [None] * data.count(None)
+    expected = (nulls + values if nulls_first else values + nulls)[:limit]
+    assert result["A"] == expected
+    assert [data[int(b)] for b in result["B"]] == expected
+
+
 @pytest.mark.parametrize(
     "cast_to",
     [
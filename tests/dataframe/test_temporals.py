@@ -10,6 +10,7 @@ import pytz
 
 import daft
 from daft import DataType, col
+from daft.functions import from_unix_epoch
 
 PYARROW_GE_7_0_0 = tuple(int(s) for s in pa.__version__.split(".") if s.isnumeric()) >= (7, 0, 0)
 
@@ -481,3 +482,152 @@ def test_date_comparison(value):
     expected = {"date": [date(2020, 1, 1)]}
 
     assert actual == expected
+
+
+def test_calendar_components():
+    df = daft.from_pydict({"date": [date(2024, 1, 1), date(2024, 7, 4), date(2024, 12, 30), None]})
+    actual = df.select(
+        col("date").dt.day_of_year().alias("day_of_year"),
+        col("date").dt.week_of_year().alias("week_of_year"),
+        col("date").dt.quarter().alias("quarter"),
+    ).to_pydict()
+
+    assert actual == {
+        "day_of_year": [1, 186, 365, None],
+        "week_of_year": [1, 27, 1, None],
+        "quarter": [1, 3, 4, None],
+    }
+
+
+def test_strftime():
+    df = daft.from_pydict(
+        {
+            "datetime": [datetime(2024, 7, 3, 12, 30, 45, 123000), datetime(2024, 1, 1), None],
+            "date": [date(2024, 1, 1), date(2024, 7, 3), None],
+        }
+    )
+    actual = df.select(
+        col("datetime").dt.strftime().alias("default"),
+        col("datetime").dt.strftime("%Y/%m/%d %H:%M").alias("custom"),
+        col("datetime").dt.convert_time_zone("+05:00").dt.strftime().alias("tz"),
+        col("date").dt.strftime().alias("date_default"),
+        col("date").dt.strftime("%A %d %B").alias("date_custom"),
+    ).to_pydict()
+
+    assert actual == {
+        "default": ["2024-07-03T12:30:45.123", "2024-01-01T00:00:00", None],
+        "custom": ["2024/07/03 12:30", "2024/01/01 00:00", None],
+        "tz": ["2024-07-03T12:30:45.123+05:00", "2024-01-01T00:00:00+05:00", None],
+        "date_default": ["2024-01-01", "2024-07-03", None],
+        "date_custom": ["Monday 01 January", "Wednesday 03 July", None],
+    }
+
+
+def test_strftime_invalid_format():
+    df = daft.from_pydict({"date": [date(2024, 1, 1)]})
+    with pytest.raises(Exception, match="strftime format"):
+        df.select(col("date").dt.strftime("%H:%M")).collect()
+
+
+def test_unix_epoch_roundtrip():
+    df = daft.from_pydict(
+        {
+            "datetime": [datetime(2024, 7, 3, 0, 0, 0, 500000), datetime(1969, 12, 31, 23, 59, 59, 500000), None],
+            "date": [date(2024, 7, 3), date(1969, 12, 31), None],
+        }
+    )
+    actual = df.select(
+        col("datetime").dt.to_unix_epoch().alias("seconds"),
+        col("datetime").dt.to_unix_epoch("ms").alias("millis"),
+        col("date").dt.to_unix_epoch().alias("date_seconds"),
+    ).to_pydict()
+
+    assert actual == {
+        "seconds": [1719964800, -1, None],
+        "millis": [1719964800500, -500, None],
+        "date_seconds": [1719964800, -86400, None],
+    }
+
+    roundtrip = daft.from_pydict({"epoch": [0, 1719964800, None]}).select(
+        from_unix_epoch(col("epoch")).alias("datetime"),
+        from_unix_epoch(col("epoch") * 1000, "ms").alias("datetime_ms"),
+    )
+    assert roundtrip.schema()["datetime"].dtype == DataType.timestamp("s")
+    assert roundtrip.to_pydict() == {
+        "datetime": [datetime(1970, 1, 1), datetime(2024, 7, 3), None],
+        "datetime_ms": [datetime(1970, 1, 1), datetime(2024, 7, 3), None],
+    }
+
+
+def test_add_months():
+    df = daft.from_pydict(
+        {
+            "date": [date(2024, 1, 31), date(2024, 3, 31), date(2023, 12, 15), None],
+            "months": [1, -13, None, 2],
+            "datetime": [datetime(2024, 1, 31, 10, 30), datetime(2024, 3, 1, 12), None, None],
+        }
+    )
+    actual = df.select(
+        col("date").dt.add_months(1).alias("plus_one"),
+        col("date").dt.add_months(-1).alias("minus_one"),
+        col("date").dt.add_months(col("months")).alias("by_column"),
+        col("datetime").dt.add_months(1).alias("datetime_plus_one"),
+        # the wall-clock time is kept across a daylight saving time change
+        col("datetime").dt.convert_time_zone("America/New_York").dt.add_months(1).dt.hour().alias("local_hour"),
+    ).to_pydict()
+
+    assert actual == {
+        "plus_one": [date(2024, 2, 29), date(2024, 4, 30), date(2024, 1, 15), None],
+        "minus_one": [date(2023, 12, 31), date(2024, 2, 29), date(2023, 11, 15), None],
+        "by_column": [date(2024, 2, 29), date(2023, 2, 28), None, None],
+        "datetime_plus_one": [datetime(2024, 2, 29, 10, 30), datetime(2024, 4, 1, 12), None, None],
+        "local_hour": [10, 12, None, None],
+    }
+
+
+@pytest.mark.parametrize(
+    "unit, expected",
+    [
+        ("year", [0, 1, 0]),
+        ("quarter", [0, 3, 0]),
+        ("month", [1, 11, -1]),
+        ("week", [0, 52, -4]),
+        ("day", [1, 365, -29]),
+    ],
+)
+def test_date_diff_calendar_units(unit, expected):
+    df = daft.from_pydict(
+        {
+            "start": [date(2024, 1, 31), date(2023, 7, 1), date(2024, 3, 1)],
+            "end": [date(2024, 2, 1), date(2024, 6, 30), date(2024, 2, 1)],
+        }
+    )
+    actual = df.select(col("end").dt.date_diff(col("start"), unit).alias("diff")).to_pydict()
+    assert actual == {"diff": expected}
+
+
+def test_date_diff_time_units():
+    df = daft.from_pydict(
+        {
+            "start": [datetime(2024, 1, 1, 10, 59, 59), None],
+            "end": [datetime(2024, 1, 1, 11, 0, 0), datetime(2024, 1, 1)],
+        }
+    )
+    actual = df.select(
+        col("end").dt.date_diff(col("start"), "hour").alias("hours"),
+        col("end").dt.date_diff(col("start"), "minute").alias("minutes"),
+        col("end").dt.date_diff(col("start"), "second").alias("seconds"),
+        col("end").dt.date_diff(col("start"), "millisecond").alias("millis"),
+        col("end").dt.date_diff(daft.lit(date(2023, 12, 31)), "day").alias("days_from_date"),
+    ).to_pydict()
+
+    assert actual == {
+        "hours": [1, None],
+        "minutes": [1, None],
+        "seconds": [1, None],
+        "millis": [1000, None],
+        "days_from_date": [1, 1],
+    }
+
+    with pytest.raises(Exception, match="Invalid date_diff unit"):
+        df.select(col("end").dt.date_diff(col("start"), "fortnight")).collect()
@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import daft
+
+fastavro = pytest.importorskip("fastavro")
+
+AVRO_SCHEMA = {
+    "type": "record",
+    "name": "Row",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "name", "type": "string"},
+        {"name": "score", "type": ["null", "double"]},
+    ],
+}
+
+RECORDS = [{"id": i, "name": f"row-{i}", "score": i * 0.5 if i % 3 else None} for i in range(100)]
+
+TABLE = pa.table(
+    {
+        "id": pa.array([r["id"] for r in RECORDS], type=pa.int64()),
+        "name": pa.array([r["name"] for r in RECORDS], type=pa.large_string()),
+        "score": pa.array([r["score"] for r in RECORDS], type=pa.float64()),
+    }
+)
+
+
+def write_avro_file(path, records, codec="null", sync_interval=256):
+    with open(path, "wb") as f:
+        fastavro.writer(f, AVRO_SCHEMA, records, codec=codec, sync_interval=sync_interval)
+
+
+@pytest.mark.parametrize("codec", ["null", "deflate", "snappy"])
+def test_read_avro(tmp_path, codec):
+    if codec == "snappy":
+        pytest.importorskip("cramjam")
+    path = tmp_path / "data.avro"
+    write_avro_file(path, RECORDS, codec=codec)
+    df = daft.read_avro(str(path))
+    assert df.schema().column_names() == ["id", "name", "score"]
+    assert df.sort("id").to_arrow() == TABLE
+
+
+def test_read_avro_split_by_byte_ranges(tmp_path):
+    path = tmp_path / "data.avro"
+    write_avro_file(path, RECORDS, sync_interval=64)
+    with daft.execution_config_ctx(scan_tasks_min_size_bytes=128, scan_tasks_max_size_bytes=256):
+        df = daft.read_avro(str(path))
+        assert df.sort("id").to_arrow() == TABLE
+
+
+def test_read_avro_projection_filter_and_limit(tmp_path):
+    write_avro_file(tmp_path / "a.avro", RECORDS[:50])
+    write_avro_file(tmp_path / "b.avro", RECORDS[50:])
+    df = daft.read_avro(str(tmp_path / "*.avro"))
+    assert df.count_rows() == 100
+
+    result = df.where(daft.col("id") % 25 == 0).select("name").sort("name").to_pydict()
+    assert result == {"name": ["row-0", "row-25", "row-50", "row-75"]}
+    assert len(df.limit(3).to_pydict()["id"]) == 3
+
+
+def test_read_avro_with_schema(tmp_path):
+    path = tmp_path / "data.avro"
+    write_avro_file(path, RECORDS)
+    schema = {"id": daft.DataType.int32(), "missing": daft.DataType.string()}
+    df = daft.read_avro(str(path), infer_schema=False, schema=schema)
+    assert df.schema().column_names() == ["id", "missing"]
+    assert df.sort("id").to_pydict() == {"id": list(range(100)), "missing": [None] * 100}
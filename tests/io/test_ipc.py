@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pyarrow.feather as feather
+import pytest
+
+import daft
+from tests.conftest import get_tests_daft_runner_name
+
+TABLE = pa.table(
+    {
+        "id": pa.array(range(10), type=pa.int64()),
+        "name": pa.array([f"row-{i}" for i in range(10)], type=pa.large_string()),
+        "score": pa.array([i * 0.5 if i % 3 else None for i in range(10)], type=pa.float64()),
+    }
+)
+
+
+def write_ipc_file(path, table, compression="uncompressed"):
+    feather.write_feather(table, str(path), compression=compression, chunksize=4)
+
+
+def write_ipc_stream(path, table):
+    with pa.OSFile(str(path), "wb") as sink, pa.ipc.new_stream(sink, table.schema) as writer:
+        for batch in table.to_batches(max_chunksize=4):
+            writer.write_batch(batch)
+
+
+@pytest.mark.parametrize("compression", ["uncompressed", "lz4", "zstd"])
+def test_read_ipc_file(tmp_path, compression):
+    path = tmp_path / "data.arrow"
+    write_ipc_file(path, TABLE, compression=compression)
+    df = daft.read_ipc(str(path))
+    assert df.schema().column_names() == ["id", "name", "score"]
+    assert df.to_arrow() == TABLE
+
+
+def test_read_ipc_stream(tmp_path):
+    path = tmp_path / "data.arrows"
+    write_ipc_stream(path, TABLE)
+    df = daft.read_ipc(str(path))
+    assert df.to_arrow() == TABLE
+
+
+def test_read_ipc_projection_filter_and_limit(tmp_path):
+    write_ipc_file(tmp_path / "a.arrow", TABLE.slice(0, 5))
+    write_ipc_stream(tmp_path / "b.arrows", TABLE.slice(5, 5))
+    df = daft.read_ipc(str(tmp_path / "*"))
+    assert df.count_rows() == 10
+
+    result = df.where(daft.col("id") % 2 == 0).select("name").sort("name").to_pydict()
+    assert result == {"name": ["row-0", "row-2", "row-4", "row-6", "row-8"]}
+    assert len(df.limit(3).to_pydict()["id"]) == 3
+
+
+def test_read_ipc_with_schema(tmp_path):
+    path = tmp_path / "data.arrow"
+    write_ipc_file(path, TABLE)
+    schema = {"id": daft.DataType.int32(), "missing": daft.DataType.string()}
+    df = daft.read_ipc(str(path), infer_schema=False, schema=schema)
+    assert df.schema().column_names() == ["id", "missing"]
+    assert df.to_pydict() == {"id": list(range(10)), "missing": [None] * 10}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Arrow IPC writes are only supported on the native runner"
+)
+@pytest.mark.parametrize("compression", [None, "lz4", "zstd"])
+def test_write_ipc_roundtrip(tmp_path, compression):
+    df = daft.from_arrow(TABLE).into_partitions(2)
+    written = df.write_ipc(str(tmp_path), compression=compression).to_pydict()["path"]
+    assert len(written) > 0
+    assert all(path.endswith(".arrow") for path in written)
+
+    # Files written by Daft are readable by other Arrow tools.
+    assert pa.concat_tables([feather.read_table(path) for path in written]).sort_by("id") == TABLE
+
+    assert daft.read_ipc(str(tmp_path)).sort("id").to_arrow() == TABLE
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Arrow IPC writes are only supported on the native runner"
+)
+def test_write_ipc_partitioned(tmp_path):
+    df = daft.from_arrow(TABLE).with_column("part", daft.col("id") % 2)
+    df.write_ipc(str(tmp_path), partition_cols=["part"])
+    after = daft.read_ipc(str(tmp_path / "**" / "*.arrow"), hive_partitioning=True)
+    assert sorted(after.to_pydict()["id"]) == list(range(10))
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+import pyarrow as pa
+import pytest
+
+import daft
+
+orc = pytest.importorskip("pyarrow.orc")
+
+TABLE = pa.table(
+    {
+        "id": pa.array(range(100), type=pa.int64()),
+        "name": pa.array([f"row-{i % 7}" for i in range(100)], type=pa.string()),
+        "score": pa.array([i * 0.5 if i % 3 else None for i in range(100)], type=pa.float64()),
+    }
+)
+
+
+def write_orc_file(path, table, **kwargs):
+    orc.write_table(table, str(path), **kwargs)
+
+
+@pytest.mark.parametrize("compression", ["uncompressed", "zlib"])
+def test_read_orc(tmp_path, compression):
+    path = tmp_path / "data.orc"
+    write_orc_file(path, TABLE, compression=compression)
+    df = daft.read_orc(str(path))
+    assert df.schema().column_names() == ["id", "name", "score"]
+    assert df.sort("id").to_arrow() == TABLE
+
+
+def test_read_orc_dictionary_encoded_strings(tmp_path):
+    path = tmp_path / "data.orc"
+    write_orc_file(path, TABLE, dictionary_key_size_threshold=1.0)
+    assert daft.read_orc(str(path)).sort("id").to_pydict()["name"] == TABLE["name"].to_pylist()
+
+
+def test_read_orc_multiple_stripes(tmp_path):
+    path = tmp_path / "data.orc"
+    write_orc_file(path, TABLE, stripe_size=1024, batch_size=10)
+    assert daft.read_orc(str(path)).sort("id").to_arrow() == TABLE
+
+
+def test_read_orc_projection_filter_and_limit(tmp_path):
+    write_orc_file(tmp_path / "a.orc", TABLE.slice(0, 50))
+    write_orc_file(tmp_path / "b.orc", TABLE.slice(50, 50))
+    df = daft.read_orc(str(tmp_path / "*.orc"))
+    assert df.count_rows() == 100
+
+    result = df.where(daft.col("id") < 4).select("name").sort("name").to_pydict()
+    assert result == {"name": ["row-0", "row-1", "row-2", "row-3"]}
+    assert len(df.limit(3).to_pydict()["id"]) == 3
+
+
+def test_read_orc_with_schema(tmp_path):
+    path = tmp_path / "data.orc"
+    write_orc_file(path, TABLE)
+    schema = {"id": daft.DataType.int32(), "missing": daft.DataType.string()}
+    df = daft.read_orc(str(path), infer_schema=False, schema=schema)
+    assert df.schema().column_names() == ["id", "missing"]
+    assert df.sort("id").to_pydict() == {"id": list(range(100)), "missing": [None] * 100}
@@ -432,3 +432,18 @@ def test_parquet_nested_optional_or_required_fields(tmpdir, optional_outer_struc
     expected = MicroPartition.from_arrow(expected)
     df = daft.read_parquet(output_file)
     assert df.to_arrow() == expected.to_arrow(), f"Expected:\n{expected.to_arrow()}\n\nReceived:\n{df.to_arrow()}"
+
+
+def test_parquet_aggregation_pushdown_across_row_groups(tmpdir):
+    data = {"x": [3, None, 7, 1, None, 9, 4, 2], "y": ["a", "b", None, "d", "e", "f", "g", None]}
+    output_file = f"{tmpdir}/{uuid.uuid4()!s}.parquet"
+    papq.write_table(pa.Table.from_pydict(data), output_file, row_group_size=3)
+
+    df = daft.read_parquet(output_file)
+    assert df.count_rows() == 8
+    result = df.agg(col("x").count().alias("count_x"), col("x").min().alias("min_x"), col("x").max().alias("max_x"))
+    assert result.to_pydict() == {"count_x": [6], "min_x": [1], "max_x": [9]}
+
+    # Filters are applied before aggregating, so they block the pushdown but must still be honored.
+    filtered = daft.read_parquet(output_file).where(col("x") > 3)
+    assert filtered.agg(col("x").min().alias("min_x")).to_pydict() == {"min_x": [4]}
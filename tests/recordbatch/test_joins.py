@@ -70,6 +70,12 @@ def skip_null_safe_equal_for_smj(func):
                 [(5, 0), (3, 1), (4, 2), (2, 3), (0, 4), (1, 4)],
             ),
             ([None, 4, 5, 6, 7], [0, 1, 2, 3, None], [], [(0, 4)]),
+            (
+                [1, 1],
+                [1, 1, 1, 1],
+                [(i, j) for i in range(2) for j in range(4)],
+                [(i, j) for i in range(2) for j in range(4)],
+            ),
             (
                 [None, 0, 0, 0, 1, None],
                 [0, 1, 2, 3, None],
@@ -112,3 +112,62 @@ def test_having_non_grouped():
     ).to_pydict()
 
     assert actual == {"count": [10]}
+
+
+def test_rollup_with_grouping_id():
+    df = daft.from_pydict({"a": ["x", "x", "y"], "b": [1, 2, 1], "v": [1, 2, 3]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        """
+    SELECT a, b, sum(v) AS s, grouping_id(a, b) AS gid
+    FROM df
+    GROUP BY ROLLUP (a, b)
+    ORDER BY gid, a, b
+    """,
+        catalog,
+    ).to_pydict()
+
+    assert actual == {
+        "a": ["x", "x", "y", "x", "y", None],
+        "b": [1, 2, 1, None, None, None],
+        "s": [1, 2, 3, 3, 3, 6],
+        "gid": [0, 0, 0, 1, 1, 3],
+    }
+
+
+@pytest.mark.parametrize(
+    "group_by",
+    [
+        "CUBE (a, b)",
+        "GROUPING SETS ((a, b), (a), (b), ())",
+    ],
+)
+def test_cube_and_grouping_sets(group_by):
+    df = daft.from_pydict({"a": ["x", "x", "y"], "b": [1, 2, 1], "v": [1, 2, 3]})
+    catalog = SQLCatalog({"df": df})
+
+    actual = daft.sql(
+        f"""
+    SELECT a, b, count(*) AS n, grouping(a) AS ga, grouping(b) AS gb
+    FROM df
+    GROUP BY {group_by}
+    """,
+        catalog,
+    ).to_pydict()
+
+    rows = sorted(zip(*actual.values()), key=repr)
+    assert rows == sorted(
+        [
+            ("x", 1, 1, 0, 0),
+            ("x", 2, 1, 0, 0),
+            ("y", 1, 1, 0, 0),
+            ("x", None, 2, 0, 1),
+            ("y", None, 1, 0, 1),
+            (None, 1, 2, 1, 0),
+            (None, 2, 1, 1, 0),
+            (None, None, 3, 1, 1),
+        ],
+        key=repr,
+    )
+
@@ -268,3 +268,62 @@ def test_round(precision, value, expected):
     expected = {"literal": [expected]}
 
     assert actual == expected
+
+
+def test_case_with_operand():
+    df = daft.from_pydict({"x": [1, 2, 3]})
+    actual = daft.sql(
+        """
+    SELECT
+        CASE x WHEN 1 THEN 'one' WHEN 2 THEN 'two' ELSE 'many' END as with_else,
+        CASE x WHEN 1 THEN 'one' END as without_else
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {"with_else": ["one", "two", "many"], "without_else": ["one", None, None]}
+
+
+def test_is_distinct_from():
+    df = daft.from_pydict({"a": [1, 1, None, None], "b": [1, 2, 1, None]})
+    actual = daft.sql(
+        """
+    SELECT
+        a IS DISTINCT FROM b as is_distinct,
+        a IS NOT DISTINCT FROM b as is_not_distinct
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {"is_distinct": [False, True, True, False], "is_not_distinct": [True, False, False, True]}
+
+
+def test_any_all_list():
+    df = daft.from_pydict({"x": [1, 2, 3, 4]})
+
+    actual = daft.sql("SELECT x FROM df WHERE x = ANY((1, 3))").to_pydict()
+    assert actual == {"x": [1, 3]}
+
+    actual = daft.sql("SELECT x FROM df WHERE x > ALL([1, 2])").to_pydict()
+    assert actual == {"x": [3, 4]}
+
+    actual = daft.sql("SELECT x FROM df WHERE x < ANY([2, 3])").to_pydict()
+    assert actual == {"x": [1, 2]}
+
+
+def test_any_all_subquery():
+    df = daft.from_pydict({"x": [1, 2, 3, 4]})
+    other = daft.from_pydict({"y": [2, 3]})
+    empty = daft.from_pydict({"y": [2, 3]}).where(col("y") > 5)
+    catalog = SQLCatalog({"df": df, "other": other, "empty": empty})
+
+    def query(predicate):
+        return daft.sql(f"SELECT x FROM df WHERE {predicate} ORDER BY x", catalog).to_pydict()["x"]
+
+    assert query("x = ANY((SELECT y FROM other))") == [2, 3]
+    assert query("x <> ALL((SELECT y FROM other))") == [1, 4]
+    assert query("x > ANY((SELECT y FROM other))") == [3, 4]
+    assert query("x >= ALL((SELECT y FROM other))") == [3, 4]
+    assert query("x < ALL((SELECT y FROM other))") == [1]
+    assert query("x > ALL((SELECT y FROM empty))") == [1, 2, 3, 4]
+    assert query("x > ANY((SELECT y FROM empty))") == []
@@ -195,3 +195,110 @@ def test_cross_join():
         "C": [2, 4, 6, 8, 2, 4, 6, 8, 2, 4, 6, 8],
         "D": ["d", "e", "f", "g", "d", "e", "f", "g", "d", "e", "f", "g"],
     }
+
+
+def test_join_with_range_predicate():
+    events = daft.from_pydict({"id": [1, 1, 2, 3], "ts": [5, 15, 7, 1]})
+    windows = daft.from_pydict({"key": [1, 2, 3], "lo": [0, 0, 2], "hi": [10, 5, 4]})
+
+    catalog = SQLCatalog({"events": events, "windows": windows})
+    df = daft.sql(
+        "select id, ts from events join windows on events.id = windows.key and events.ts between windows.lo and windows.hi order by ts",
+        catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1], "ts": [5]}
+
+
+
+@pytest.mark.parametrize(
+    "join_type,selection,sort_by,expected",
+    [
+        ("left", "id, ts, key", "ts", {"id": [3, 1, 2, 1], "ts": [1, 5, 7, 15], "key": [None, 1, None, None]}),
+        ("right", "id, ts, key", "key", {"id": [1, None, None], "ts": [5, None, None], "key": [1, 2, 3]}),
+        ("left anti", "id, ts", "ts", {"id": [3, 2, 1], "ts": [1, 7, 15]}),
+        ("left semi", "id, ts", "ts", {"id": [1], "ts": [5]}),
+    ],
+)
+def test_outer_join_with_range_predicate(join_type, selection, sort_by, expected):
+    events = daft.from_pydict({"id": [1, 1, 2, 3], "ts": [5, 15, 7, 1]})
+    windows = daft.from_pydict({"key": [1, 2, 3], "lo": [0, 0, 2], "hi": [10, 5, 4]})
+
+    catalog = SQLCatalog({"events": events, "windows": windows})
+    df = daft.sql(
+        f"select {selection} from events {join_type} join windows on events.id = windows.key and events.ts between windows.lo and windows.hi order by {sort_by}",
+        catalog,
+    )
+
+    # rows without a match satisfying the predicate are still unmatched for outer, anti and semi joins
+    assert df.to_pydict() == expected
+
+
+def test_non_equi_join():
+    events = daft.from_pydict({"id": [1, 1, 2, 3], "ts": [5, 15, 7, 1]})
+    windows = daft.from_pydict({"key": [1, 2, 3], "lo": [0, 0, 2], "hi": [10, 5, 4]})
+
+    catalog = SQLCatalog({"events": events, "windows": windows})
+    df = daft.sql(
+        "select id, key from events join windows on events.ts >= windows.lo and events.ts <= windows.hi order by id, key",
+        catalog,
+    )
+
+    assert df.to_pydict() == {"id": [1, 1, 2, 3, 3], "key": [1, 2, 1, 1, 2]}
+
+
+def test_explicit_cross_join():
+    x = daft.from_pydict({"A": [1, 3], "B": ["a", "b"]})
+    y = daft.from_pydict({"C": [2, 4]})
+
+    catalog = SQLCatalog({"x": x, "y": y})
+    df = daft.sql("select * from x cross join y order by A, C", catalog)
+
+    assert df.to_pydict() == {"A": [1, 1, 3, 3], "B": ["a", "a", "b", "b"], "C": [2, 4, 2, 4]}
+
+
+def test_nested_join():
+    x = daft.from_pydict({"a": [1, 2, 3]})
+    y = daft.from_pydict({"b": [1, 2], "c": [10, 20]})
+    z = daft.from_pydict({"d": [10, 20], "e": ["p", "q"]})
+
+    catalog = SQLCatalog({"x": x, "y": y, "z": z})
+    df = daft.sql("select a, e from x join (y join z on y.c = z.d) on x.a = y.b order by a", catalog)
+
+    assert df.to_pydict() == {"a": [1, 2], "e": ["p", "q"]}
+
+
+@pytest.mark.parametrize(
+    "query,expected",
+    [
+        (
+            "select id, v from df cross join unnest(df.vals) as u(v) order by id, v",
+            {"id": [1, 1, 4], "v": [1, 2, 3]},
+        ),
+        (
+            "select id, v from df join unnest(vals) as u(v) on true order by id, v",
+            {"id": [1, 1, 4], "v": [1, 2, 3]},
+        ),
+        (
+            "select id, unnest from df, unnest(vals) order by id, unnest",
+            {"id": [1, 1, 4], "unnest": [1, 2, 3]},
+        ),
+        (
+            # rows with empty or null lists are kept by a left join
+            "select id, v from df left join unnest(vals) as u(v) on true order by id, v",
+            {"id": [1, 1, 2, 3, 4], "v": [1, 2, None, None, 3]},
+        ),
+    ],
+)
+def test_join_unnest(query, expected):
+    df = daft.from_pydict({"id": [1, 2, 3, 4], "vals": [[1, 2], [], None, [3]]})
+
+    catalog = SQLCatalog({"df": df})
+
+    assert daft.sql(query, catalog).to_pydict() == expected
+
+
+def test_unnest_without_table():
+    df = daft.sql("select * from unnest([3, 1, 2]) as u(v) order by v")
+
+    assert df.to_pydict() == {"v": [1, 2, 3]}
@@ -142,3 +142,41 @@ def test_pydict_catalog():
     table_t = sess.sql("SELECT * FROM T")
     table_s = sess.read_table("S")
     assert_eq(table_t, table_s)
+
+
+def test_create_insert_drop_table(sess: Session):
+    sess.create_temp_table("src", daft.from_pydict({"a": [1, 2, 3]}))
+    sess.sql("USE cat_1.ns_1")
+    sess.sql("CREATE TABLE tbl_new AS SELECT a FROM src")
+    assert sess.has_table("ns_1.tbl_new")
+    sess.sql("INSERT INTO tbl_new SELECT a * 10 FROM src")
+    assert sorted(sess.sql("SELECT a FROM tbl_new").to_pydict()["a"]) == [1, 2, 3, 10, 20, 30]
+    sess.sql("INSERT OVERWRITE tbl_new SELECT a FROM src")
+    assert sorted(sess.sql("SELECT a FROM tbl_new").to_pydict()["a"]) == [1, 2, 3]
+    sess.sql("DROP TABLE tbl_new")
+    assert not sess.has_table("ns_1.tbl_new")
+    sess.sql("DROP TABLE IF EXISTS tbl_new")
+
+
+def test_create_temp_table_and_view():
+    sess = Session()
+    sess.create_temp_table("src", daft.from_pydict({"a": [1, 2, 3]}))
+    sess.sql("CREATE TEMP TABLE t AS SELECT a FROM src WHERE a > 1")
+    sess.sql("CREATE VIEW v (b) AS SELECT a FROM t")
+    assert sess.sql("SELECT b FROM v").to_pydict() == {"b": [2, 3]}
+    assert sess.sql("SHOW TABLES").to_pydict() == {"table": ["src", "t", "v"]}
+    assert sess.sql("SHOW TABLES LIKE 's%'").to_pydict() == {"table": ["src"]}
+    assert sess.sql("SHOW TABLES LIKE '_'").to_pydict() == {"table": ["t", "v"]}
+    assert sess.sql("SHOW TABLES LIKE 'r%'").to_pydict() == {"table": []}
+    sess.sql("DROP VIEW v")
+    sess.sql("DROP TABLE t")
+    assert sess.sql("SHOW TABLES").to_pydict() == {"table": ["src"]}
+
+
+def test_set_and_show_catalogs(sess: Session):
+    assert sess.sql("SHOW CATALOGS").to_pydict() == {"catalog": ["cat_1", "cat_2"]}
+    sess.sql("SET catalog = cat_2")
+    sess.sql("SET namespace = ns_2")
+    assert sess.sql("select * from tbl_cat_2_21") is not None
+    with pytest.raises(Exception):
+        sess.sql("SET unknown_option = 1")
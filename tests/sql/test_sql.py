@@ -8,6 +8,7 @@ from daft import DataType, Series, col
 from daft.exceptions import DaftCoreException
 from daft.sql.sql import SQLCatalog
 from tests.assets import TPCH_QUERIES
+from tests.conftest import get_tests_daft_runner_name
 
 
 def load_tpch_queries():
@@ -292,6 +293,21 @@ def test_sql_cte_column_aliases():
     assert actual == expected
 
 
+def test_sql_values():
+    df = daft.sql("SELECT * FROM (VALUES (2, 'b'), (1, 'a'), (3, NULL)) AS t(x, y) ORDER BY x")
+    assert df.to_pydict() == {"x": [1, 2, 3], "y": ["a", "b", None]}
+
+
+def test_sql_values_default_names_and_supertype():
+    df = daft.sql("SELECT * FROM (VALUES (1, true), (2.5, NULL))")
+    assert df.to_pydict() == {"column1": [1.0, 2.5], "column2": [True, None]}
+
+
+def test_sql_values_as_query():
+    df = daft.sql("VALUES (1), (2)")
+    assert df.to_pydict() == {"column1": [1, 2]}
+
+
 def test_sql_multiple_ctes():
     df1 = daft.from_pydict({"a": [1, 2, 3], "b": [4, 5, 6], "c": ["a", "b", "c"]})
     df2 = daft.from_pydict({"x": [1, 0, 3], "y": [True, None, False], "z": [1.0, 2.0, 3.0]})
@@ -312,6 +328,87 @@ def test_sql_multiple_ctes():
     assert actual == expected
 
 
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Recursive CTEs are only supported on the native runner"
+)
+def test_sql_recursive_cte():
+    df = daft.sql("""
+        WITH RECURSIVE nums (n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM nums WHERE n < 5
+        )
+        SELECT n FROM nums ORDER BY n
+        """)
+    assert df.to_pydict() == {"n": [1, 2, 3, 4, 5]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Recursive CTEs are only supported on the native runner"
+)
+def test_sql_recursive_cte_hierarchy():
+    employees = daft.from_pydict({"id": [1, 2, 3, 4, 5], "manager_id": [None, 1, 1, 2, None]})
+    catalog = SQLCatalog({"employees": employees})
+    df = daft.sql(
+        """
+        WITH RECURSIVE reports AS (
+            SELECT id, 0 AS depth FROM employees WHERE id = 1
+            UNION ALL
+            SELECT e.id, r.depth + 1 FROM employees e JOIN reports r ON e.manager_id = r.id
+        )
+        SELECT id, depth FROM reports ORDER BY id
+        """,
+        catalog,
+    )
+    assert df.to_pydict() == {"id": [1, 2, 3, 4], "depth": [0, 1, 1, 2]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Recursive CTEs are only supported on the native runner"
+)
+def test_sql_recursive_cte_union_terminates_on_cycles():
+    edges = daft.from_pydict({"src": [1, 2, 3, 4], "dst": [2, 3, 1, 5]})
+    catalog = SQLCatalog({"edges": edges})
+    df = daft.sql(
+        """
+        WITH RECURSIVE reachable (node) AS (
+            SELECT 1
+            UNION
+            SELECT dst FROM edges JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+        """,
+        catalog,
+    )
+    assert df.to_pydict() == {"node": [1, 2, 3]}
+
+
+@pytest.mark.skipif(
+    get_tests_daft_runner_name() != "native", reason="Recursive CTEs are only supported on the native runner"
+)
+def test_sql_recursive_cte_max_iterations():
+    query = """
+        WITH RECURSIVE nums (n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM nums
+        )
+        SELECT n FROM nums
+        """
+    with daft.context.planning_config_ctx(recursive_cte_max_iterations=3):
+        df = daft.sql(query)
+    with pytest.raises(Exception, match="did not terminate within 3 iterations"):
+        df.collect()
+
+
+def test_sql_with_recursive_non_recursive_cte():
+    df = daft.sql("""
+        WITH RECURSIVE cte (x) AS (SELECT 1 UNION ALL SELECT 2)
+        SELECT x FROM cte ORDER BY x
+        """)
+    assert df.to_pydict() == {"x": [1, 2]}
+
+
 def test_cast_image():
     channels = 3
 
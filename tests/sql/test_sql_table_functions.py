@@ -163,3 +163,30 @@ def test_sql_read_path_no_alias(sample_csv_path):
     # don't allow using paths as table names
     with pytest.raises(Exception, match="Table not found"):
         daft.sql(f""" SELECT "{sample_csv_path}".* FROM '{sample_csv_path}' """)
+
+
+def test_sql_range():
+    assert daft.sql("SELECT * FROM range(0, 5)").to_pydict() == {"range": [0, 1, 2, 3, 4]}
+    assert daft.sql("SELECT * FROM range(0, 5, 2)").to_pydict() == {"range": [0, 2, 4]}
+    assert daft.sql("SELECT * FROM range(5, 0, -2)").to_pydict() == {"range": [5, 3, 1]}
+    assert daft.sql("SELECT * FROM range(start => 1, stop => 3)").to_pydict() == {"range": [1, 2]}
+
+
+def test_sql_generate_series():
+    assert daft.sql("SELECT * FROM generate_series(1, 3)").to_pydict() == {"generate_series": [1, 2, 3]}
+    assert daft.sql("SELECT * FROM generate_series(3, 1, -1)").to_pydict() == {"generate_series": [3, 2, 1]}
+
+
+def test_sql_range_zero_step():
+    with pytest.raises(Exception, match="must not be zero"):
+        daft.sql("SELECT * FROM range(0, 5, 0)").collect()
+
+
+def test_sql_range_too_many_rows():
+    with pytest.raises(Exception, match="more than the maximum"):
+        daft.sql("SELECT * FROM range(0, 1000000000)").collect()
+
+
+def test_sql_range_positional_after_named():
+    with pytest.raises(Exception, match="must come before named arguments"):
+        daft.sql("SELECT * FROM range(start => 0, 5)").collect()
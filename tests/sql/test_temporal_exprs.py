@@ -96,3 +96,118 @@ def test_date_comparison():
     expected = date_df.filter(daft.col("date") == "2020-01-01").select("date").to_pydict()
     actual = daft.sql("select date from date_df where date == '2020-01-01'").to_pydict()
     assert actual == expected
+
+
+def test_extract_calendar_fields():
+    df = daft.from_pydict(
+        {
+            "dates": [
+                datetime.date(2024, 1, 1),
+                datetime.date(2024, 3, 31),
+                datetime.date(2023, 12, 31),
+                datetime.date(1999, 7, 15),
+                None,
+            ]
+        }
+    )
+
+    actual = daft.sql("""
+    SELECT
+        extract(doy from dates) as doy,
+        extract(quarter from dates) as quarter,
+        extract(week from dates) as week,
+        extract(dow from dates) as dow,
+        extract(isodow from dates) as isodow,
+        extract(epoch from dates) as epoch,
+        extract(decade from dates) as decade,
+        extract(century from dates) as century,
+        extract(millennium from dates) as millennium,
+    FROM df
+    """).to_pydict()
+
+    assert actual == {
+        "doy": [1, 91, 365, 196, None],
+        "quarter": [1, 1, 4, 3, None],
+        "week": [1, 13, 52, 28, None],
+        "dow": [1, 0, 0, 4, None],
+        "isodow": [1, 7, 7, 4, None],
+        "epoch": [1704067200.0, 1711843200.0, 1703980800.0, 931996800.0, None],
+        "decade": [202, 202, 202, 199, None],
+        "century": [21, 21, 21, 20, None],
+        "millennium": [3, 3, 3, 2, None],
+    }
+
+
+def test_at_time_zone():
+    df = daft.from_pydict({"ts": [datetime.datetime(2024, 7, 3, 12, 0, 0), None]})
+
+    actual = daft.sql("""
+    SELECT
+        extract(hour from ts at time zone 'UTC' at time zone 'America/New_York') as new_york,
+        extract(hour from ts at time zone '+05:00') as local,
+    FROM df
+    """).to_pydict()
+
+    assert actual == {"new_york": [8, None], "local": [12, None]}
+
+
+def test_typed_literals_and_intervals():
+    actual = daft.sql("""
+    SELECT
+        DATE '2024-01-15' + INTERVAL '3' MONTH as plus_months,
+        DATE '2024-01-15' + INTERVAL '1-6' YEAR TO MONTH as plus_year_month,
+        DATE '2024-03-15' + -INTERVAL '1' MONTH as minus_month,
+        TIMESTAMP '2024-01-01 10:00:00' + INTERVAL '1 02:30:00' DAY TO SECOND as plus_day_time,
+        TIMESTAMP '2024-01-01 10:00:00' + (INTERVAL '1' DAY + INTERVAL '2' HOUR) as plus_sum,
+        TIME '12:30:00' as t,
+        extract(hour from TIMESTAMP WITH TIME ZONE '2024-01-01 10:00:00+02:00') as tz_hour,
+        CONVERT('42', INT) as converted,
+    """).to_pydict()
+
+    assert actual == {
+        "plus_months": [datetime.date(2024, 4, 15)],
+        "plus_year_month": [datetime.date(2025, 7, 15)],
+        "minus_month": [datetime.date(2024, 2, 15)],
+        "plus_day_time": [datetime.datetime(2024, 1, 2, 12, 30)],
+        "plus_sum": [datetime.datetime(2024, 1, 2, 12, 0)],
+        "t": [datetime.time(12, 30)],
+        "tz_hour": [10],
+        "converted": [42],
+    }
+
+
+def test_temporal_functions():
+    df = daft.from_pydict(
+        {
+            "dates": [datetime.date(2024, 1, 31), datetime.date(2024, 7, 4), None],
+            "epochs": [0, 1719964800, None],
+        }
+    )
+
+    actual = daft.sql("""
+    SELECT
+        dayofyear(dates) as doy,
+        weekofyear(dates) as week,
+        quarter(dates) as quarter,
+        strftime(dates, '%d/%m/%Y') as formatted,
+        unix_timestamp(dates) as seconds,
+        unix_timestamp(dates, 'ms') as millis,
+        from_unix_timestamp(epochs) as from_epoch,
+        add_months(dates, 1) as next_month,
+        date_diff('month', DATE '2024-01-01', dates) as months,
+        datediff(day, dates, DATE '2024-12-31') as days,
+    FROM df
+    """).to_pydict()
+
+    assert actual == {
+        "doy": [31, 186, None],
+        "week": [5, 27, None],
+        "quarter": [1, 3, None],
+        "formatted": ["31/01/2024", "04/07/2024", None],
+        "seconds": [1706659200, 1720051200, None],
+        "millis": [1706659200000, 1720051200000, None],
+        "from_epoch": [datetime.datetime(1970, 1, 1), datetime.datetime(2024, 7, 3), None],
+        "next_month": [datetime.date(2024, 2, 29), datetime.date(2024, 8, 4), None],
+        "months": [0, 6, None],
+        "days": [335, 180, None],
+    }
@@ -120,3 +120,50 @@ def test_utf8_exprs():
     )
     actual = actual.to_pydict()
     assert actual == expected
+
+
+def test_utf8_standard_syntax():
+    df = daft.from_pydict({"a": ["  foo  ", "xxbarxx", "baz"]})
+    actual = daft.sql(
+        """
+    SELECT
+        trim(a) as trim,
+        trim(leading 'x' from a) as ltrim,
+        trim(trailing 'x' from a) as rtrim,
+        trim(both 'x' from a) as btrim,
+        position('a' in a) as position,
+        overlay(a placing 'Z' from 2 for 2) as overlay,
+        overlay(a placing 'ZZ' from 1) as overlay_default_length
+    FROM df
+    """
+    ).to_pydict()
+
+    assert actual == {
+        "trim": ["foo", "xxbarxx", "baz"],
+        "ltrim": ["  foo  ", "barxx", "baz"],
+        "rtrim": ["  foo  ", "xxbar", "baz"],
+        "btrim": ["  foo  ", "bar", "baz"],
+        "position": [0, 4, 2],
+        "overlay": [" Zoo  ", "xZarxx", "bZ"],
+        "overlay_default_length": ["ZZfoo  ", "ZZbarxx", "ZZz"],
+    }
+
+
+def test_utf8_regex_predicates():
+    df = daft.from_pydict({"a": ["abc", "abd", "a.c", "xbc"]})
+
+    actual = daft.sql("SELECT a FROM df WHERE a RLIKE '^ab'").to_pydict()
+    assert actual == {"a": ["abc", "abd"]}
+
+    actual = daft.sql("SELECT a FROM df WHERE a NOT REGEXP 'c$'").to_pydict()
+    assert actual == {"a": ["abd"]}
+
+    actual = daft.sql("SELECT a FROM df WHERE a SIMILAR TO '(a|x)_c'").to_pydict()
+    assert actual == {"a": ["abc", "a.c", "xbc"]}
+
+    # `.` is a literal in SIMILAR TO patterns
+    actual = daft.sql("SELECT a FROM df WHERE a SIMILAR TO 'a.%'").to_pydict()
+    assert actual == {"a": ["a.c"]}
+
+    actual = daft.sql("SELECT a FROM df WHERE a NOT SIMILAR TO '[ax]bc'").to_pydict()
+    assert actual == {"a": ["abd", "a.c"]}
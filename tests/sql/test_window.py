@@ -0,0 +1,107 @@
+import pytest
+
+import daft
+
+
+@pytest.fixture()
+def df():
+    return daft.from_pydict(
+        {
+            "text": ["g1", "g1", "g2", "g3", "g3", "g1"],
+            "n": [1, 2, 3, 3, 4, 100],
+        }
+    )
+
+
+def test_window_running_sum(df):
+    df = daft.sql("""
+        SELECT text, n, sum(n) OVER (PARTITION BY text ORDER BY n) AS s FROM df ORDER BY text, n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "text": ["g1", "g1", "g1", "g2", "g3", "g3"],
+        "n": [1, 2, 100, 3, 3, 4],
+        "s": [1, 3, 103, 3, 3, 7],
+    }
+
+
+def test_window_partition_total(df):
+    df = daft.sql("""
+        SELECT text, n, sum(n) OVER (PARTITION BY text) AS total FROM df ORDER BY text, n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "text": ["g1", "g1", "g1", "g2", "g3", "g3"],
+        "n": [1, 2, 100, 3, 3, 4],
+        "total": [103, 103, 103, 3, 7, 7],
+    }
+
+
+def test_window_rank(df):
+    df = daft.sql("""
+        SELECT n, rank() OVER (ORDER BY n) AS r, dense_rank() OVER (ORDER BY n) AS dr FROM df ORDER BY n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "n": [1, 2, 3, 3, 4, 100],
+        "r": [1, 2, 3, 3, 5, 6],
+        "dr": [1, 2, 3, 3, 4, 5],
+    }
+
+
+def test_window_row_number(df):
+    df = daft.sql("""
+        SELECT text, n, row_number() OVER (PARTITION BY text ORDER BY n DESC) AS rn FROM df ORDER BY text, n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "text": ["g1", "g1", "g1", "g2", "g3", "g3"],
+        "n": [1, 2, 100, 3, 3, 4],
+        "rn": [3, 2, 1, 1, 2, 1],
+    }
+
+
+def test_window_lag_lead(df):
+    df = daft.sql("""
+        SELECT
+            text,
+            n,
+            lag(n, 1, 0) OVER (PARTITION BY text ORDER BY n) AS prev,
+            lead(n) OVER (PARTITION BY text ORDER BY n) AS next
+        FROM df ORDER BY text, n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "text": ["g1", "g1", "g1", "g2", "g3", "g3"],
+        "n": [1, 2, 100, 3, 3, 4],
+        "prev": [0, 1, 2, 0, 0, 3],
+        "next": [2, 100, None, None, 4, None],
+    }
+
+
+def test_window_rows_frame(df):
+    df = daft.sql("""
+        SELECT
+            n,
+            sum(n) OVER (ORDER BY n, text ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS s
+        FROM df ORDER BY n, text
+    """)
+
+    assert df.collect().to_pydict() == {
+        "n": [1, 2, 3, 3, 4, 100],
+        "s": [3, 6, 8, 10, 107, 104],
+    }
+
+
+def test_window_range_frame(df):
+    df = daft.sql("""
+        SELECT
+            n,
+            count(n) OVER (ORDER BY n RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS c
+        FROM df ORDER BY n
+    """)
+
+    assert df.collect().to_pydict() == {
+        "n": [1, 2, 3, 3, 4, 100],
+        "c": [1, 2, 3, 3, 3, 1],
+    }